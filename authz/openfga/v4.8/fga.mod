@@ -10,3 +10,4 @@ contents:
   - components/lakekeeper_table.fga
   - components/lakekeeper_view.fga
   - components/lakekeeper_generic_table.fga
+  - components/lakekeeper_column.fga
@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+
+use lakekeeper::{
+    ProjectId, WarehouseId,
+    api::{ApiContext, IcebergErrorResponse, RequestMetadata},
+    async_trait::async_trait,
+    axum::Router,
+    service::{
+        ArcProjectId, AuthZGenericTableInfo, AuthZNamespaceInfo, AuthZTableInfo, AuthZViewInfo,
+        CatalogStore, GenericTableId, NamespaceId, NamespaceWithParent, ResolvedWarehouse, Role,
+        RoleId, SecretStore, ServerId, State, TableId, UserId, ViewId,
+        authz::{
+            ActionOnGenericTable, ActionOnTable, ActionOnView, AuthorizationDecision, Authorizer,
+            AuthzBackendErrorOrBadRequest, CatalogUserAction, IsAllowedActionError,
+            ListProjectsResponse, ManagesRoleAssignments, NamespaceParent, UserOrRole,
+        },
+        health::{Health, HealthExt},
+    },
+};
+
+use crate::{
+    OpenFGAAuthorizer,
+    relations::{
+        GenericTableRelation, NamespaceRelation, ProjectRelation, ReadRelation, RoleRelation,
+        ServerRelation, TableRelation, ViewRelation, WarehouseRelation,
+    },
+};
+
+type AuthorizerResult<T> = std::result::Result<T, IcebergErrorResponse>;
+
+/// Migration aid wrapping an [`OpenFGAAuthorizer`]: read actions on projects,
+/// warehouses, namespaces, tables, views and generic tables are allowed without
+/// contacting `OpenFGA`. All other checks and all relation writes are delegated
+/// to the wrapped authorizer.
+///
+/// Reads are only allowed without a check if no one else's permissions are
+/// inspected, so introspection keeps reporting the enforced grants.
+#[derive(Clone, Debug)]
+pub struct AllowAllReadsAuthorizer {
+    inner: OpenFGAAuthorizer,
+}
+
+impl AllowAllReadsAuthorizer {
+    #[must_use]
+    pub fn new(inner: OpenFGAAuthorizer) -> Self {
+        tracing::warn!(
+            "OpenFGA authorizer is running with ALL READS ALLOWED. Read access to every project, warehouse, namespace, table and view is granted without checking permissions. Only use this while migrating to OpenFGA and disable it as soon as all grants are in place."
+        );
+        Self { inner }
+    }
+}
+
+impl AsRef<OpenFGAAuthorizer> for AllowAllReadsAuthorizer {
+    fn as_ref(&self) -> &OpenFGAAuthorizer {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl HealthExt for AllowAllReadsAuthorizer {
+    async fn health(&self) -> Vec<Health> {
+        self.inner.health().await
+    }
+    async fn update_health(&self) {
+        self.inner.update_health().await;
+    }
+}
+
+/// Allows the actions for which `allowed_without_check` holds and decides the
+/// remaining ones with `check`, which is not called if no action remains.
+async fn allow_reads<T, Fut>(
+    actions: &[T],
+    allowed_without_check: impl Fn(&T) -> bool,
+    check: impl FnOnce(Vec<T>) -> Fut,
+) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError>
+where
+    T: Clone,
+    Fut: Future<Output = Result<Vec<AuthorizationDecision>, IsAllowedActionError>>,
+{
+    let (checked_indices, checked): (Vec<_>, Vec<_>) = actions
+        .iter()
+        .enumerate()
+        .filter(|(_, action)| !allowed_without_check(action))
+        .map(|(idx, action)| (idx, action.clone()))
+        .unzip();
+
+    let mut decisions = vec![AuthorizationDecision::from(true); actions.len()];
+    if checked.is_empty() {
+        return Ok(decisions);
+    }
+    for (idx, decision) in checked_indices.into_iter().zip(check(checked).await?) {
+        decisions[idx] = decision;
+    }
+    Ok(decisions)
+}
+
+#[async_trait]
+impl Authorizer for AllowAllReadsAuthorizer {
+    type ServerAction = ServerRelation;
+    type ProjectAction = ProjectRelation;
+    type WarehouseAction = WarehouseRelation;
+    type NamespaceAction = NamespaceRelation;
+    type TableAction = TableRelation;
+    type ViewAction = ViewRelation;
+    type GenericTableAction = GenericTableRelation;
+    type UserAction = CatalogUserAction;
+    type RoleAction = RoleRelation;
+
+    fn implementation_name() -> &'static str {
+        OpenFGAAuthorizer::implementation_name()
+    }
+
+    fn server_id(&self) -> ServerId {
+        self.inner.server_id()
+    }
+
+    #[cfg(feature = "open-api")]
+    fn api_doc() -> utoipa::openapi::OpenApi {
+        OpenFGAAuthorizer::api_doc()
+    }
+
+    fn new_router<C: CatalogStore, S: SecretStore>(&self) -> Router<ApiContext<State<Self, C, S>>> {
+        crate::api::new_v1_router()
+    }
+
+    async fn check_assume_role_impl(
+        &self,
+        principal: &UserId,
+        assumed_role: &Role,
+        request_metadata: &RequestMetadata,
+    ) -> Result<bool, AuthzBackendErrorOrBadRequest> {
+        self.inner
+            .check_assume_role_impl(principal, assumed_role, request_metadata)
+            .await
+    }
+
+    async fn can_bootstrap(&self, metadata: &RequestMetadata) -> AuthorizerResult<()> {
+        self.inner.can_bootstrap(metadata).await
+    }
+
+    async fn bootstrap(
+        &self,
+        metadata: &RequestMetadata,
+        is_operator: bool,
+    ) -> AuthorizerResult<()> {
+        self.inner.bootstrap(metadata, is_operator).await
+    }
+
+    async fn list_projects_impl(
+        &self,
+        metadata: &RequestMetadata,
+    ) -> Result<ListProjectsResponse, AuthzBackendErrorOrBadRequest> {
+        self.inner.list_projects_impl(metadata).await
+    }
+
+    async fn can_search_users_impl(
+        &self,
+        metadata: &RequestMetadata,
+    ) -> Result<bool, AuthzBackendErrorOrBadRequest> {
+        self.inner.can_search_users_impl(metadata).await
+    }
+
+    async fn are_allowed_role_actions_impl(
+        &self,
+        metadata: &RequestMetadata,
+        for_user: Option<&UserOrRole>,
+        roles_with_actions: &[(&Role, Self::RoleAction)],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        self.inner
+            .are_allowed_role_actions_impl(metadata, for_user, roles_with_actions)
+            .await
+    }
+
+    async fn are_allowed_user_actions_impl(
+        &self,
+        metadata: &RequestMetadata,
+        for_user: Option<&UserOrRole>,
+        users_with_actions: &[(&UserId, Self::UserAction)],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        self.inner
+            .are_allowed_user_actions_impl(metadata, for_user, users_with_actions)
+            .await
+    }
+
+    async fn are_allowed_server_actions_impl(
+        &self,
+        metadata: &RequestMetadata,
+        for_user: Option<&UserOrRole>,
+        actions: &[Self::ServerAction],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        self.inner
+            .are_allowed_server_actions_impl(metadata, for_user, actions)
+            .await
+    }
+
+    async fn are_allowed_project_actions_impl(
+        &self,
+        metadata: &RequestMetadata,
+        for_user: Option<&UserOrRole>,
+        projects_with_actions: &[(&ArcProjectId, Self::ProjectAction)],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        allow_reads(
+            projects_with_actions,
+            |(_, action)| for_user.is_none() && action.is_read(),
+            |checked| async move {
+                self.inner
+                    .are_allowed_project_actions_impl(metadata, for_user, &checked)
+                    .await
+            },
+        )
+        .await
+    }
+
+    async fn are_allowed_warehouse_actions_impl(
+        &self,
+        metadata: &RequestMetadata,
+        for_user: Option<&UserOrRole>,
+        warehouses_with_actions: &[(&ResolvedWarehouse, Self::WarehouseAction)],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        allow_reads(
+            warehouses_with_actions,
+            |(_, action)| for_user.is_none() && action.is_read(),
+            |checked| async move {
+                self.inner
+                    .are_allowed_warehouse_actions_impl(metadata, for_user, &checked)
+                    .await
+            },
+        )
+        .await
+    }
+
+    async fn are_allowed_namespace_actions_impl(
+        &self,
+        metadata: &RequestMetadata,
+        for_user: Option<&UserOrRole>,
+        warehouse: &ResolvedWarehouse,
+        parent_namespaces: &HashMap<NamespaceId, NamespaceWithParent>,
+        actions: &[(&impl AuthZNamespaceInfo, Self::NamespaceAction)],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        allow_reads(
+            actions,
+            |(_, action)| for_user.is_none() && action.is_read(),
+            |checked| async move {
+                self.inner
+                    .are_allowed_namespace_actions_impl(
+                        metadata,
+                        for_user,
+                        warehouse,
+                        parent_namespaces,
+                        &checked,
+                    )
+                    .await
+            },
+        )
+        .await
+    }
+
+    async fn are_allowed_table_actions_impl<A: Into<Self::TableAction> + Send + Clone + Sync>(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse: &ResolvedWarehouse,
+        parent_namespaces: &HashMap<NamespaceId, NamespaceWithParent>,
+        actions: &[(
+            &NamespaceWithParent,
+            ActionOnTable<'_, '_, impl AuthZTableInfo, A>,
+        )],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        allow_reads(
+            actions,
+            |(_, action)| {
+                let relation: TableRelation = action.action.clone().into();
+                (action.user.is_none() || action.is_delegated_execution) && relation.is_read()
+            },
+            |checked| async move {
+                self.inner
+                    .are_allowed_table_actions_impl(
+                        metadata,
+                        warehouse,
+                        parent_namespaces,
+                        &checked,
+                    )
+                    .await
+            },
+        )
+        .await
+    }
+
+    async fn are_allowed_view_actions_impl<A: Into<Self::ViewAction> + Send + Clone + Sync>(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse: &ResolvedWarehouse,
+        parent_namespaces: &HashMap<NamespaceId, NamespaceWithParent>,
+        actions: &[(
+            &NamespaceWithParent,
+            ActionOnView<'_, '_, impl AuthZViewInfo, A>,
+        )],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        allow_reads(
+            actions,
+            |(_, action)| {
+                let relation: ViewRelation = action.action.clone().into();
+                (action.user.is_none() || action.is_delegated_execution) && relation.is_read()
+            },
+            |checked| async move {
+                self.inner
+                    .are_allowed_view_actions_impl(metadata, warehouse, parent_namespaces, &checked)
+                    .await
+            },
+        )
+        .await
+    }
+
+    async fn are_allowed_generic_table_actions_impl<
+        A: Into<Self::GenericTableAction> + Send + Clone + Sync,
+    >(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse: &ResolvedWarehouse,
+        parent_namespaces: &HashMap<NamespaceId, NamespaceWithParent>,
+        actions: &[(
+            &NamespaceWithParent,
+            ActionOnGenericTable<'_, '_, impl AuthZGenericTableInfo, A>,
+        )],
+    ) -> Result<Vec<AuthorizationDecision>, IsAllowedActionError> {
+        allow_reads(
+            actions,
+            |(_, action)| {
+                let relation: GenericTableRelation = action.action.clone().into();
+                (action.user.is_none() || action.is_delegated_execution) && relation.is_read()
+            },
+            |checked| async move {
+                self.inner
+                    .are_allowed_generic_table_actions_impl(
+                        metadata,
+                        warehouse,
+                        parent_namespaces,
+                        &checked,
+                    )
+                    .await
+            },
+        )
+        .await
+    }
+
+    async fn create_generic_table(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse_id: WarehouseId,
+        generic_table_id: GenericTableId,
+        parent: NamespaceId,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .create_generic_table(metadata, warehouse_id, generic_table_id, parent)
+            .await
+    }
+
+    async fn delete_generic_table(
+        &self,
+        warehouse_id: WarehouseId,
+        generic_table_id: GenericTableId,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .delete_generic_table(warehouse_id, generic_table_id)
+            .await
+    }
+
+    async fn delete_user(
+        &self,
+        metadata: &RequestMetadata,
+        user_id: UserId,
+    ) -> AuthorizerResult<()> {
+        self.inner.delete_user(metadata, user_id).await
+    }
+
+    async fn create_role(
+        &self,
+        metadata: &RequestMetadata,
+        role_id: RoleId,
+        parent_project_id: ArcProjectId,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .create_role(metadata, role_id, parent_project_id)
+            .await
+    }
+
+    async fn delete_role(
+        &self,
+        metadata: &RequestMetadata,
+        role_id: RoleId,
+    ) -> AuthorizerResult<()> {
+        self.inner.delete_role(metadata, role_id).await
+    }
+
+    async fn create_project(
+        &self,
+        metadata: &RequestMetadata,
+        project_id: &ProjectId,
+    ) -> AuthorizerResult<()> {
+        self.inner.create_project(metadata, project_id).await
+    }
+
+    async fn delete_project(
+        &self,
+        metadata: &RequestMetadata,
+        project_id: &ProjectId,
+    ) -> AuthorizerResult<()> {
+        self.inner.delete_project(metadata, project_id).await
+    }
+
+    async fn create_warehouse(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse_id: WarehouseId,
+        parent_project_id: &ProjectId,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .create_warehouse(metadata, warehouse_id, parent_project_id)
+            .await
+    }
+
+    async fn delete_warehouse(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse_id: WarehouseId,
+    ) -> AuthorizerResult<()> {
+        self.inner.delete_warehouse(metadata, warehouse_id).await
+    }
+
+    async fn create_namespace(
+        &self,
+        metadata: &RequestMetadata,
+        namespace_id: NamespaceId,
+        parent: NamespaceParent,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .create_namespace(metadata, namespace_id, parent)
+            .await
+    }
+
+    async fn delete_namespace(
+        &self,
+        metadata: &RequestMetadata,
+        namespace_id: NamespaceId,
+    ) -> AuthorizerResult<()> {
+        self.inner.delete_namespace(metadata, namespace_id).await
+    }
+
+    async fn move_namespace(
+        &self,
+        metadata: &RequestMetadata,
+        namespace_id: NamespaceId,
+        previous_parent: NamespaceParent,
+        new_parent: NamespaceParent,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .move_namespace(metadata, namespace_id, previous_parent, new_parent)
+            .await
+    }
+
+    async fn create_table(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse_id: WarehouseId,
+        table_id: TableId,
+        parent: NamespaceId,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .create_table(metadata, warehouse_id, table_id, parent)
+            .await
+    }
+
+    async fn delete_table(
+        &self,
+        warehouse_id: WarehouseId,
+        table_id: TableId,
+    ) -> AuthorizerResult<()> {
+        self.inner.delete_table(warehouse_id, table_id).await
+    }
+
+    async fn create_view(
+        &self,
+        metadata: &RequestMetadata,
+        warehouse_id: WarehouseId,
+        view_id: ViewId,
+        parent: NamespaceId,
+    ) -> AuthorizerResult<()> {
+        self.inner
+            .create_view(metadata, warehouse_id, view_id, parent)
+            .await
+    }
+
+    async fn delete_view(
+        &self,
+        warehouse_id: WarehouseId,
+        view_id: ViewId,
+    ) -> AuthorizerResult<()> {
+        self.inner.delete_view(warehouse_id, view_id).await
+    }
+
+    fn role_assignments(&self) -> Option<&dyn ManagesRoleAssignments> {
+        self.inner.role_assignments()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use lakekeeper::tokio;
+    use openfga_client::client::{BasicOpenFgaClient, BasicOpenFgaServiceClient};
+
+    use super::*;
+
+    /// Authorizer whose `OpenFGA` endpoint is unreachable, so every check that
+    /// contacts `OpenFGA` fails.
+    fn unreachable_authorizer() -> OpenFGAAuthorizer {
+        let service_client = BasicOpenFgaServiceClient::new_unauthenticated(
+            "http://127.0.0.1:1".parse::<url::Url>().unwrap(),
+        )
+        .unwrap();
+        let client = BasicOpenFgaClient::new(service_client, "store", "model");
+        OpenFGAAuthorizer::new(client, ServerId::new_random())
+    }
+
+    fn reader_metadata() -> RequestMetadata {
+        RequestMetadata::test_user(UserId::new_unchecked("oidc", "reader"))
+    }
+
+    #[tokio::test]
+    async fn test_reads_allowed_without_contacting_openfga() {
+        let authorizer = AllowAllReadsAuthorizer::new(unreachable_authorizer());
+        let project_id = Arc::new(ProjectId::from(uuid::Uuid::now_v7()));
+
+        let decisions = authorizer
+            .are_allowed_project_actions_impl(
+                &reader_metadata(),
+                None,
+                &[
+                    (&project_id, ProjectRelation::CanGetMetadata),
+                    (&project_id, ProjectRelation::CanListWarehouses),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(decisions, vec![true, true]);
+    }
+
+    #[tokio::test]
+    async fn test_writes_still_consult_openfga() {
+        let authorizer = AllowAllReadsAuthorizer::new(unreachable_authorizer());
+        let project_id = Arc::new(ProjectId::from(uuid::Uuid::now_v7()));
+
+        let result = authorizer
+            .are_allowed_project_actions_impl(
+                &reader_metadata(),
+                None,
+                &[
+                    (&project_id, ProjectRelation::CanGetMetadata),
+                    (&project_id, ProjectRelation::CanCreateWarehouse),
+                ],
+            )
+            .await;
+        assert!(result.is_err(), "write check must contact OpenFGA");
+    }
+
+    #[tokio::test]
+    async fn test_reads_consult_openfga_without_wrapper() {
+        let authorizer = unreachable_authorizer();
+        let project_id = Arc::new(ProjectId::from(uuid::Uuid::now_v7()));
+
+        let result = authorizer
+            .are_allowed_project_actions_impl(
+                &reader_metadata(),
+                None,
+                &[(&project_id, ProjectRelation::CanGetMetadata)],
+            )
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -39,7 +39,8 @@ use strum::IntoEnumIterator;
 use utoipa::OpenApi;
 
 use super::{
-    check::check,
+    check::{check, check_batch, get_table_permissions},
+    ownership::reassign_table_owner,
     relations::{
         APIGenericTableRelation as GenericTableRelation, APINamespaceAction as NamespaceAction,
         APINamespaceRelation as NamespaceRelation, APIProjectAction as ProjectAction,
@@ -59,9 +60,11 @@ use super::{
     },
 };
 #[cfg(feature = "open-api")]
-use crate::check::__path_check;
+use crate::check::{__path_check, __path_check_batch, __path_get_table_permissions};
+#[cfg(feature = "open-api")]
+use crate::ownership::__path_reassign_table_owner;
 use crate::{
-    OpenFGAAuthorizer, OpenFGAError, OpenFGAResult,
+    OpenFGAAuthorizer, OpenFGABacked, OpenFGAError, OpenFGAResult,
     entities::OpenFgaEntity,
     relations::{
         OpenFGAGenericTableAction, OpenFGANamespaceAction, OpenFGAProjectAction, OpenFGARoleAction,
@@ -474,13 +477,13 @@ struct SetManagedAccessRequest {
     since = "0.11.0",
     note = "Use /management/v1/permissions/role/{role_id}/authorizer-actions and /management/v1/role/{role_id}/actions instead"
 )]
-async fn get_role_access_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_role_access_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(role_id): Path<RoleId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetRoleAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_role(
@@ -523,13 +526,13 @@ async fn get_role_access_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetOpenFGARoleActionsResponse),
     )
 ))]
-async fn get_authorizer_role_actions<C: CatalogStore, S: SecretStore>(
+async fn get_authorizer_role_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(role_id): Path<RoleId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGARoleActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_role(
@@ -573,12 +576,12 @@ async fn get_authorizer_role_actions<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/server/actions and /management/v1/permissions/server/authorizer-actions instead"
 )]
-async fn get_server_access<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn get_server_access<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetServerAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
     let openfga_server = authorizer.openfga_server().clone();
 
@@ -619,12 +622,12 @@ async fn get_server_access<C: CatalogStore, S: SecretStore>(
             (status = 200, description = "Server Access", body = GetOpenFGAServerActionsResponse),
     )
 ))]
-async fn get_authorizer_server_actions<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn get_authorizer_server_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGAServerActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
     let openfga_server = authorizer.openfga_server().clone();
 
@@ -669,12 +672,12 @@ async fn get_authorizer_server_actions<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/project/actions and /management/v1/permissions/project/authorizer-actions instead"
 )]
-async fn get_project_access<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn get_project_access<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetProjectAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
     let project_id = metadata
         .preferred_project_id()
@@ -718,12 +721,12 @@ async fn get_project_access<C: CatalogStore, S: SecretStore>(
         (status = 200, description = "Project Authorizer Actions", body = GetOpenFGAProjectActionsResponse),
     )
 ))]
-async fn get_authorizer_project_actions<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn get_authorizer_project_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGAProjectActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
     let project_id = metadata
         .preferred_project_id()
@@ -774,13 +777,13 @@ async fn get_authorizer_project_actions<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/project/actions and /management/v1/permissions/project/authorizer-actions instead"
 )]
-async fn get_project_access_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_project_access_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(project_id): Path<ProjectId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetProjectAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_project(
@@ -827,13 +830,13 @@ async fn get_project_access_by_id<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/warehouse/{warehouse_id}/actions and /management/v1/permissions/warehouse/{warehouse_id}/authorizer-actions instead"
 )]
-async fn get_warehouse_access_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_warehouse_access_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(warehouse_id): Path<WarehouseId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetWarehouseAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_warehouse(
@@ -876,13 +879,13 @@ async fn get_warehouse_access_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, description = "Warehouse Authorizer Actions", body = GetOpenFGAWarehouseActionsResponse),
     )
 ))]
-async fn get_authorizer_warehouse_actions<C: CatalogStore, S: SecretStore>(
+async fn get_authorizer_warehouse_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(warehouse_id): Path<WarehouseId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGAWarehouseActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_warehouse(
@@ -921,12 +924,12 @@ async fn get_authorizer_warehouse_actions<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetWarehouseAuthPropertiesResponse),
     )
 ))]
-async fn get_warehouse_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_warehouse_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(warehouse_id): Path<WarehouseId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
 ) -> Result<(StatusCode, Json<GetWarehouseAuthPropertiesResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_warehouse(
         Arc::new(metadata),
@@ -967,13 +970,13 @@ async fn get_warehouse_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200),
     )
 ))]
-async fn set_warehouse_managed_access<C: CatalogStore, S: SecretStore>(
+async fn set_warehouse_managed_access<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(warehouse_id): Path<WarehouseId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<SetManagedAccessRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_warehouse(
         Arc::new(metadata),
@@ -1012,13 +1015,13 @@ async fn set_warehouse_managed_access<C: CatalogStore, S: SecretStore>(
             (status = 200),
     )
 ))]
-async fn set_namespace_managed_access<C: CatalogStore, S: SecretStore>(
+async fn set_namespace_managed_access<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(namespace_id): Path<NamespaceId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<SetManagedAccessRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_namespace_only_id(
         Arc::new(metadata),
@@ -1056,12 +1059,12 @@ async fn set_namespace_managed_access<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetNamespaceAuthPropertiesResponse),
     )
 ))]
-async fn get_namespace_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_namespace_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(namespace_id): Path<NamespaceId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
 ) -> Result<(StatusCode, Json<GetNamespaceAuthPropertiesResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_namespace_only_id(
         Arc::new(metadata),
@@ -1121,13 +1124,13 @@ async fn get_namespace_by_id<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/actions and /management/v1/permissions/namespace/{namespace_id}/authorizer-actions instead"
 )]
-async fn get_namespace_access_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_namespace_access_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(namespace_id): Path<NamespaceId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetNamespaceAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_namespace_only_id(
@@ -1171,13 +1174,13 @@ async fn get_namespace_access_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, description = "Namespace Authorizer Actions", body = GetOpenFGANamespaceActionsResponse),
     )
 ))]
-async fn get_authorizer_namespace_actions<C: CatalogStore, S: SecretStore>(
+async fn get_authorizer_namespace_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(namespace_id): Path<NamespaceId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGANamespaceActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_namespace_only_id(
@@ -1226,13 +1229,13 @@ async fn get_authorizer_namespace_actions<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/warehouse/{warehouse_id}/table/{table_id}/actions and /management/v1/permissions/warehouse/{warehouse_id}/table/{table_id}/authorizer-actions instead"
 )]
-async fn get_table_access_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_table_access_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, table_id)): Path<(WarehouseId, TableId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetTableAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_table(
@@ -1278,13 +1281,13 @@ async fn get_table_access_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, description = "Table Authorizer Actions", body = GetOpenFGATableActionsResponse),
     )
 ))]
-async fn get_authorizer_table_actions<C: CatalogStore, S: SecretStore>(
+async fn get_authorizer_table_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, table_id)): Path<(WarehouseId, TableId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGATableActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_table(
@@ -1334,13 +1337,13 @@ async fn get_authorizer_table_actions<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/warehouse/{warehouse_id}/view/{view_id}/actions and /management/v1/permissions/warehouse/{warehouse_id}/view/{view_id}/authorizer-actions instead"
 )]
-async fn get_view_access_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_view_access_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, view_id)): Path<(WarehouseId, ViewId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetViewAccessResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_view(
@@ -1386,13 +1389,13 @@ async fn get_view_access_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, description = "View Authorizer Actions", body = GetOpenFGAViewActionsResponse),
     )
 ))]
-async fn get_authorizer_view_actions<C: CatalogStore, S: SecretStore>(
+async fn get_authorizer_view_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, view_id)): Path<(WarehouseId, ViewId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGAViewActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_view(
@@ -1438,13 +1441,13 @@ async fn get_authorizer_view_actions<C: CatalogStore, S: SecretStore>(
             (status = 200, description = "Generic Table Authorizer Actions", body = GetOpenFGAGenericTableActionsResponse),
     )
 ))]
-async fn get_authorizer_generic_table_actions<C: CatalogStore, S: SecretStore>(
+async fn get_authorizer_generic_table_actions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, generic_table_id)): Path<(WarehouseId, GenericTableId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetAccessQuery>,
 ) -> Result<(StatusCode, Json<GetOpenFGAGenericTableActionsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let query = ParsedAccessQuery::try_from(query)?;
 
     let event_ctx = APIEventContext::for_generic_table(
@@ -1486,13 +1489,13 @@ async fn get_authorizer_generic_table_actions<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetRoleAssignmentsResponse),
     )
 ))]
-async fn get_role_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_role_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(role_id): Path<RoleId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetRoleAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetRoleAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_role(
         Arc::new(metadata),
@@ -1531,12 +1534,12 @@ async fn get_role_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetServerAssignmentsResponse),
     )
 ))]
-async fn get_server_assignments<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn get_server_assignments<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetServerAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetServerAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let server_id = authorizer.openfga_server().clone();
 
     let event_ctx = APIEventContext::for_server(
@@ -1576,12 +1579,12 @@ async fn get_server_assignments<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetProjectAssignmentsResponse),
     )
 ))]
-async fn get_project_assignments<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn get_project_assignments<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetProjectAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetProjectAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let project_id = metadata
         .preferred_project_id()
         .ok_or(OpenFGAError::NoProjectId)
@@ -1637,13 +1640,13 @@ async fn get_project_assignments<C: CatalogStore, S: SecretStore>(
     since = "0.11.0",
     note = "Use /management/v1/permissions/project/assignments instead"
 )]
-async fn get_project_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_project_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(project_id): Path<ProjectId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetProjectAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetProjectAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_project(
         Arc::new(metadata),
@@ -1689,13 +1692,13 @@ async fn get_project_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetWarehouseAssignmentsResponse),
     )
 ))]
-async fn get_warehouse_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_warehouse_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(warehouse_id): Path<WarehouseId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetWarehouseAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetWarehouseAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let object = warehouse_id.to_openfga();
 
     let event_ctx = APIEventContext::for_warehouse(
@@ -1734,13 +1737,13 @@ async fn get_warehouse_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetNamespaceAssignmentsResponse),
     )
 ))]
-async fn get_namespace_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_namespace_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(namespace_id): Path<NamespaceId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetNamespaceAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetNamespaceAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let object = namespace_id.to_openfga();
 
     let event_ctx = APIEventContext::for_namespace_only_id(
@@ -1780,13 +1783,13 @@ async fn get_namespace_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetTableAssignmentsResponse),
     )
 ))]
-async fn get_table_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_table_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, table_id)): Path<(WarehouseId, TableId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetTableAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetTableAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let object = (warehouse_id, table_id).to_openfga();
 
     let event_ctx = APIEventContext::for_table(
@@ -1827,13 +1830,13 @@ async fn get_table_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetViewAssignmentsResponse),
     )
 ))]
-async fn get_view_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_view_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, view_id)): Path<(WarehouseId, ViewId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetViewAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetViewAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let object = (warehouse_id, view_id).to_openfga();
 
     let event_ctx = APIEventContext::for_view(
@@ -1870,12 +1873,12 @@ async fn get_view_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_server_assignments<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn update_server_assignments<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateServerAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let server_id = authorizer.openfga_server().clone();
 
     let event_ctx = APIEventContext::for_server(
@@ -1907,12 +1910,12 @@ async fn update_server_assignments<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_project_assignments<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+async fn update_project_assignments<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateProjectAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let project_id = metadata
         .preferred_project_id()
         .ok_or(OpenFGAError::NoProjectId)
@@ -1950,13 +1953,13 @@ async fn update_project_assignments<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_project_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn update_project_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(project_id): Path<ProjectId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateProjectAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_project(
         Arc::new(metadata),
@@ -1990,13 +1993,13 @@ async fn update_project_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_warehouse_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn update_warehouse_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(warehouse_id): Path<WarehouseId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateWarehouseAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_warehouse(
         Arc::new(metadata),
@@ -2030,13 +2033,13 @@ async fn update_warehouse_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_namespace_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn update_namespace_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(namespace_id): Path<NamespaceId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateNamespaceAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_namespace_only_id(
         Arc::new(metadata),
@@ -2071,13 +2074,13 @@ async fn update_namespace_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_table_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn update_table_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, table_id)): Path<(WarehouseId, TableId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateTableAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_table(
         Arc::new(metadata),
@@ -2113,13 +2116,13 @@ async fn update_table_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_view_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn update_view_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, view_id)): Path<(WarehouseId, ViewId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateViewAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_view(
         Arc::new(metadata),
@@ -2155,13 +2158,13 @@ async fn update_view_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 200, body = GetGenericTableAssignmentsResponse),
     )
 ))]
-async fn get_generic_table_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn get_generic_table_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path((warehouse_id, generic_table_id)): Path<(WarehouseId, GenericTableId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Query(query): Query<GetGenericTableAssignmentsQuery>,
 ) -> Result<(StatusCode, Json<GetGenericTableAssignmentsResponse>)> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let object = (warehouse_id, generic_table_id).to_openfga();
 
     let event_ctx = APIEventContext::for_generic_table(
@@ -2202,13 +2205,17 @@ async fn get_generic_table_assignments_by_id<C: CatalogStore, S: SecretStore>(
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_generic_table_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn update_generic_table_assignments_by_id<
+    A: OpenFGABacked,
+    C: CatalogStore,
+    S: SecretStore,
+>(
     Path((warehouse_id, generic_table_id)): Path<(WarehouseId, GenericTableId)>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateGenericTableAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_generic_table(
         Arc::new(metadata),
@@ -2243,13 +2250,13 @@ async fn update_generic_table_assignments_by_id<C: CatalogStore, S: SecretStore>
             (status = 204, description = "Permissions updated successfully"),
     )
 ))]
-async fn update_role_assignments_by_id<C: CatalogStore, S: SecretStore>(
+async fn update_role_assignments_by_id<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
     Path(role_id): Path<RoleId>,
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<UpdateRoleAssignmentsRequest>,
 ) -> Result<StatusCode> {
-    let authorizer = api_context.v1_state.authz;
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
 
     let event_ctx = APIEventContext::for_role(
         Arc::new(metadata),
@@ -2289,6 +2296,7 @@ async fn update_role_assignments_by_id<C: CatalogStore, S: SecretStore>(
     ),
     paths(
         check,
+        check_batch,
         get_authorizer_generic_table_actions,
         get_authorizer_namespace_actions,
         get_authorizer_project_actions,
@@ -2311,11 +2319,13 @@ async fn update_role_assignments_by_id<C: CatalogStore, S: SecretStore>(
         get_server_assignments,
         get_table_access_by_id,
         get_table_assignments_by_id,
+        get_table_permissions,
         get_view_access_by_id,
         get_view_assignments_by_id,
         get_warehouse_access_by_id,
         get_warehouse_assignments_by_id,
         get_warehouse_by_id,
+        reassign_table_owner,
         set_namespace_managed_access,
         set_warehouse_managed_access,
         update_generic_table_assignments_by_id,
@@ -2343,8 +2353,8 @@ async fn update_role_assignments_by_id<C: CatalogStore, S: SecretStore>(
 pub(crate) struct ApiDoc;
 
 #[allow(clippy::too_many_lines)]
-pub(super) fn new_v1_router<C: CatalogStore, S: SecretStore>()
--> Router<ApiContext<State<OpenFGAAuthorizer, C, S>>> {
+pub(super) fn new_v1_router<A: OpenFGABacked, C: CatalogStore, S: SecretStore>()
+-> Router<ApiContext<State<A, C, S>>> {
     Router::new()
         .route(
             "/permissions/role/{role_id}/access",
@@ -2457,6 +2467,15 @@ pub(super) fn new_v1_router<C: CatalogStore, S: SecretStore>()
             get(get_generic_table_assignments_by_id).post(update_generic_table_assignments_by_id),
         )
         .route("/permissions/check", post(check))
+        .route("/permissions/check-batch", post(check_batch))
+        .route(
+            "/warehouse/{warehouse_id}/table/{table_id}/permissions",
+            get(get_table_permissions),
+        )
+        .route(
+            "/warehouse/{warehouse_id}/table/{table_id}/reassign-owner",
+            post(reassign_table_owner),
+        )
 }
 
 async fn get_relations<RA: Assignment>(
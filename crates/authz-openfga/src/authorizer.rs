@@ -48,10 +48,11 @@ use crate::{
     },
     models::OpenFgaType,
     relations::{
-        self, GenericTableRelation, NamespaceRelation, OpenFgaRelation, ProjectRelation,
-        ReducedRelation, RoleRelation, ServerRelation, TableRelation, ViewRelation,
-        WarehouseRelation,
+        self, ColumnRelation, GenericTableRelation, NamespaceRelation, OpenFgaRelation,
+        ProjectRelation, ReducedRelation, RoleRelation, ServerRelation, TableRelation,
+        ViewRelation, WarehouseRelation,
     },
+    request_metrics::{OpenFgaOperation, observe},
 };
 
 type AuthorizerResult<T> = std::result::Result<T, IcebergErrorResponse>;
@@ -86,6 +87,18 @@ impl OpenFGAAuthorizer {
     }
 }
 
+impl AsRef<OpenFGAAuthorizer> for OpenFGAAuthorizer {
+    fn as_ref(&self) -> &OpenFGAAuthorizer {
+        self
+    }
+}
+
+/// Authorizer backed by an [`OpenFGAAuthorizer`]. The `OpenFGA` permission API
+/// always operates on the backing authorizer.
+pub(crate) trait OpenFGABacked: Authorizer + AsRef<OpenFGAAuthorizer> {}
+
+impl<T: Authorizer + AsRef<OpenFGAAuthorizer>> OpenFGABacked for T {}
+
 /// Implements batch checks for the `are_allowed_x_actions` methods.
 #[async_trait::async_trait]
 impl Authorizer for OpenFGAAuthorizer {
@@ -854,6 +867,28 @@ impl Authorizer for OpenFGAAuthorizer {
         self.delete_all_relations(&namespace_id).await
     }
 
+    async fn move_namespace(
+        &self,
+        _metadata: &RequestMetadata,
+        namespace_id: NamespaceId,
+        previous_parent: NamespaceParent,
+        new_parent: NamespaceParent,
+    ) -> AuthorizerResult<()> {
+        let deletes = crate::tuples::hierarchy_tuples_for_namespace(&previous_parent, namespace_id)
+            .into_iter()
+            .map(|t| TupleKeyWithoutCondition {
+                user: t.user,
+                relation: t.relation,
+                object: t.object,
+            })
+            .collect::<Vec<_>>();
+        let writes = crate::tuples::hierarchy_tuples_for_namespace(&new_parent, namespace_id);
+        self.write(Some(writes), Some(deletes))
+            .await
+            .map_err(authz_to_error_no_audit)
+            .map_err(Into::into)
+    }
+
     async fn create_table(
         &self,
         metadata: &RequestMetadata,
@@ -1137,6 +1172,33 @@ impl OpenFGAAuthorizer {
         Ok(ListProjectsResponse::Projects(projects))
     }
 
+    /// Check which of the given columns of a table `actor` may read.
+    ///
+    /// Columns are identified by their Iceberg field id. Reading a column requires
+    /// `can_read_data` on the table and a `select` grant on the column itself;
+    /// `select` on the table alone is not sufficient. Actors that may modify the
+    /// table can read all of its columns.
+    ///
+    /// Returns one decision per entry of `field_ids`, in the same order.
+    pub async fn check_column_access(
+        &self,
+        actor: &Actor,
+        warehouse_id: WarehouseId,
+        table_id: TableId,
+        field_ids: &[i32],
+    ) -> Result<Vec<bool>, OpenFGABackendUnavailable> {
+        let user = actor.to_openfga();
+        let items = field_ids
+            .iter()
+            .map(|field_id| CheckRequestTupleKey {
+                user: user.clone(),
+                relation: ColumnRelation::CanReadData.to_string(),
+                object: (warehouse_id, table_id, *field_id).to_openfga(),
+            })
+            .collect::<Vec<_>>();
+        self.batch_check(items).await
+    }
+
     /// A convenience wrapper around write.
     /// All writes happen in a single transaction.
     /// At most 100 writes can be performed in a single transaction.
@@ -1145,9 +1207,11 @@ impl OpenFGAAuthorizer {
         writes: impl Into<Option<Vec<TupleKey>>>,
         deletes: impl Into<Option<Vec<TupleKeyWithoutCondition>>>,
     ) -> OpenFGAResult<()> {
-        self.client.write(writes, deletes).await.inspect_err(|e| {
-            tracing::error!("Failed to write to OpenFGA: {e}");
-        })?;
+        observe(OpenFgaOperation::Write, self.client.write(writes, deletes))
+            .await
+            .inspect_err(|e| {
+                tracing::error!("Failed to write to OpenFGA: {e}");
+            })?;
         Ok(())
     }
 
@@ -1159,12 +1223,14 @@ impl OpenFGAAuthorizer {
         writes: impl Into<Option<Vec<TupleKey>>>,
         deletes: impl Into<Option<Vec<TupleKeyWithoutCondition>>>,
     ) -> OpenFGAResult<()> {
-        self.client_higher_consistency
-            .write(writes, deletes)
-            .await
-            .inspect_err(|e| {
-                tracing::error!("Failed to write to OpenFGA: {e}");
-            })?;
+        observe(
+            OpenFgaOperation::Write,
+            self.client_higher_consistency.write(writes, deletes),
+        )
+        .await
+        .inspect_err(|e| {
+            tracing::error!("Failed to write to OpenFGA: {e}");
+        })?;
         Ok(())
     }
 
@@ -1226,13 +1292,15 @@ impl OpenFGAAuthorizer {
         &self,
         tuple_key: impl Into<CheckRequestTupleKey>,
     ) -> Result<bool, OpenFGABackendUnavailable> {
-        self.client
-            .check(tuple_key, None, None, false)
-            .await
-            .inspect_err(|e| {
-                tracing::error!("Failed to check with OpenFGA: {e}");
-            })
-            .map_err(Into::into)
+        observe(
+            OpenFgaOperation::Check,
+            self.client.check(tuple_key, None, None, false),
+        )
+        .await
+        .inspect_err(|e| {
+            tracing::error!("Failed to check with OpenFGA: {e}");
+        })
+        .map_err(Into::into)
     }
 
     /// Helper method to check actions with permission guards when inspecting another user's permissions.
@@ -1275,7 +1343,7 @@ impl OpenFGAAuthorizer {
             .collect())
     }
     /// A convenience wrapper around `batch_check`.
-    async fn batch_check(
+    pub(crate) async fn batch_check(
         &self,
         tuple_keys: Vec<impl Into<CheckRequestTupleKey>>,
     ) -> Result<Vec<bool>, OpenFGABackendUnavailable> {
@@ -1293,8 +1361,13 @@ impl OpenFGAAuthorizer {
             .collect();
 
         let chunks: Vec<_> = items.chunks(AUTH_CONFIG.max_batch_check_size).collect();
-        let chunked_raw_results =
-            try_join_all(chunks.iter().map(|&c| self.client.batch_check(c.to_vec()))).await?;
+        let chunked_raw_results = try_join_all(chunks.iter().map(|&c| {
+            observe(
+                OpenFgaOperation::BatchCheck,
+                self.client.batch_check(c.to_vec()),
+            )
+        }))
+        .await?;
 
         let mut results = vec![false; num_tuples];
         let mut idxs_seen = vec![false; num_tuples];
@@ -1622,6 +1695,125 @@ pub(crate) mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn test_check_records_request_duration() {
+            use axum_prometheus::metrics_exporter_prometheus::PrometheusBuilder;
+
+            use crate::request_metrics::METRIC_OPENFGA_REQUEST_DURATION_SECONDS;
+
+            let authorizer = new_authorizer_in_empty_store().await;
+            let recorder = PrometheusBuilder::new().build_recorder();
+            let handle = recorder.handle();
+            let check_count = || {
+                let prefix = format!(
+                    "{METRIC_OPENFGA_REQUEST_DURATION_SECONDS}_count{{operation=\"check\"}} "
+                );
+                handle
+                    .render()
+                    .lines()
+                    .find_map(|line| line.strip_prefix(&prefix).map(str::to_string))
+                    .map_or(0, |count| count.parse::<u64>().unwrap())
+            };
+            // `tokio::test` runs on a current-thread runtime, so the thread-local
+            // recorder stays in place across awaits.
+            let _guard = metrics::set_default_local_recorder(&recorder);
+            assert_eq!(check_count(), 0);
+
+            let allowed = authorizer
+                .check(CheckRequestTupleKey {
+                    user: UserId::new_unchecked("oidc", "this_user").to_openfga(),
+                    relation: ServerRelation::CanListAllProjects.to_string(),
+                    object: authorizer.openfga_server(),
+                })
+                .await
+                .unwrap();
+            assert!(!allowed);
+            assert_eq!(check_count(), 1);
+        }
+
+        #[tokio::test]
+        async fn test_check_column_access() {
+            let authorizer = new_authorizer_in_empty_store().await;
+            let reader = UserId::new_unchecked("oidc", &uuid::Uuid::now_v7().to_string());
+            let column_only = UserId::new_unchecked("oidc", &uuid::Uuid::now_v7().to_string());
+            let owner = UserId::new_unchecked("oidc", &uuid::Uuid::now_v7().to_string());
+            let warehouse_id = WarehouseId::new(uuid::Uuid::now_v7());
+            let table_id = TableId::new(uuid::Uuid::now_v7());
+            let table = (warehouse_id, table_id).to_openfga();
+            let granted_column = (warehouse_id, table_id, 1).to_openfga();
+            let hidden_column = (warehouse_id, table_id, 2).to_openfga();
+
+            let tuple = |user: String, relation: String, object: &String| TupleKey {
+                user,
+                relation,
+                object: object.clone(),
+                condition: None,
+            };
+            authorizer
+                .write(
+                    Some(vec![
+                        tuple(
+                            table.clone(),
+                            ColumnRelation::Parent.to_string(),
+                            &granted_column,
+                        ),
+                        tuple(
+                            table.clone(),
+                            ColumnRelation::Parent.to_string(),
+                            &hidden_column,
+                        ),
+                        tuple(
+                            reader.to_openfga(),
+                            TableRelation::Select.to_string(),
+                            &table,
+                        ),
+                        tuple(
+                            reader.to_openfga(),
+                            ColumnRelation::Select.to_string(),
+                            &granted_column,
+                        ),
+                        tuple(
+                            column_only.to_openfga(),
+                            ColumnRelation::Select.to_string(),
+                            &granted_column,
+                        ),
+                        tuple(
+                            owner.to_openfga(),
+                            TableRelation::Ownership.to_string(),
+                            &table,
+                        ),
+                    ]),
+                    None,
+                )
+                .await
+                .unwrap();
+
+            // Table read alone does not grant access to a column.
+            let allowed = authorizer
+                .check_column_access(&Actor::Principal(reader), warehouse_id, table_id, &[1, 2])
+                .await
+                .unwrap();
+            assert_eq!(allowed, vec![true, false]);
+
+            // A column grant does not bypass the table's read permission.
+            let allowed = authorizer
+                .check_column_access(
+                    &Actor::Principal(column_only),
+                    warehouse_id,
+                    table_id,
+                    &[1, 2],
+                )
+                .await
+                .unwrap();
+            assert_eq!(allowed, vec![false, false]);
+
+            let allowed = authorizer
+                .check_column_access(&Actor::Principal(owner), warehouse_id, table_id, &[1, 2])
+                .await
+                .unwrap();
+            assert_eq!(allowed, vec![true, true]);
+        }
+
         #[tokio::test]
         async fn test_require_no_relations_own_relations() {
             let authorizer = new_authorizer_in_empty_store().await;
@@ -2212,6 +2404,51 @@ pub(crate) mod tests {
             assert_eq!(results, vec![true, false]);
         }
 
+        #[tokio::test]
+        async fn test_move_namespace_replaces_parent() {
+            let authorizer = new_authorizer_in_empty_store().await;
+            let metadata =
+                RequestMetadata::test_user(UserId::new_unchecked("oidc", "ns_move_user"));
+            let warehouse_id = WarehouseId::from(uuid::Uuid::now_v7());
+            let new_parent_id = NamespaceId::from(uuid::Uuid::now_v7());
+            let namespace_id = NamespaceId::from(uuid::Uuid::now_v7());
+
+            for id in [new_parent_id, namespace_id] {
+                authorizer
+                    .create_namespace(&metadata, id, NamespaceParent::Warehouse(warehouse_id))
+                    .await
+                    .unwrap();
+            }
+
+            authorizer
+                .move_namespace(
+                    &metadata,
+                    namespace_id,
+                    NamespaceParent::Warehouse(warehouse_id),
+                    NamespaceParent::Namespace(new_parent_id),
+                )
+                .await
+                .unwrap();
+
+            let is_parent = |parent: String| CheckRequestTupleKey {
+                user: parent,
+                relation: NamespaceRelation::Parent.to_string(),
+                object: namespace_id.to_openfga(),
+            };
+            assert!(
+                authorizer
+                    .check(is_parent(new_parent_id.to_openfga()))
+                    .await
+                    .unwrap()
+            );
+            assert!(
+                !authorizer
+                    .check(is_parent(warehouse_id.to_openfga()))
+                    .await
+                    .unwrap()
+            );
+        }
+
         #[tokio::test]
         async fn test_generic_table_permissions_lifecycle() {
             use std::collections::HashMap;
@@ -0,0 +1,302 @@
+//! Export and restore of the `OpenFGA` tuples belonging to a single warehouse.
+//!
+//! An export contains every tuple whose object is the warehouse or one of its
+//! descendants (namespaces, tables, views, generic tables), plus the tuples in
+//! which the warehouse itself appears as the user (e.g. `project:<id>#warehouse`).
+//! Descendants are discovered by following the structural `warehouse#namespace`
+//! and `namespace#child` relations, and the `lakekeeper_column#parent` relation
+//! for columns of tables, so the export reflects `OpenFGA` state and never
+//! consults the catalog.
+//!
+//! Restoring writes the exported tuples back idempotently, so a backup can be
+//! replayed into a store that still holds some or all of them.
+
+use std::collections::{HashSet, VecDeque};
+
+use lakekeeper::service::WarehouseId;
+use openfga_client::client::{ReadRequestTupleKey, TupleKey, WriteOptions};
+
+use crate::{
+    FgaType, MAX_TUPLES_PER_WRITE, OpenFGAAuthorizer, OpenFGAResult,
+    entities::OpenFgaEntity,
+    models::OpenFgaType,
+    relations::{NamespaceRelation, WarehouseRelation},
+};
+
+/// Minimal tuple storage operations needed to export and restore a warehouse.
+pub(crate) trait TupleStore {
+    /// Read all tuples matching `key`, following continuation tokens.
+    async fn read_tuples(&self, key: ReadRequestTupleKey) -> OpenFGAResult<Vec<TupleKey>>;
+
+    /// Idempotently write `tuples`. Callers chunk to [`MAX_TUPLES_PER_WRITE`].
+    async fn write_tuples(&self, tuples: Vec<TupleKey>) -> OpenFGAResult<()>;
+}
+
+impl TupleStore for OpenFGAAuthorizer {
+    async fn read_tuples(&self, key: ReadRequestTupleKey) -> OpenFGAResult<Vec<TupleKey>> {
+        Ok(self
+            .read_all(Some(key))
+            .await?
+            .into_iter()
+            .filter_map(|t| t.key)
+            .collect())
+    }
+
+    async fn write_tuples(&self, tuples: Vec<TupleKey>) -> OpenFGAResult<()> {
+        self.client
+            .write_with_options(Some(tuples), None, WriteOptions::new_idempotent())
+            .await
+            .inspect_err(|e| {
+                tracing::error!("Failed to restore tuples to OpenFGA: {e}");
+            })?;
+        Ok(())
+    }
+}
+
+impl OpenFGAAuthorizer {
+    /// Export all tuples referencing `warehouse_id` or any of its descendants.
+    ///
+    /// The result can be passed to [`Self::restore_warehouse_tuples`] to recreate
+    /// the warehouse's authorization state.
+    pub async fn export_warehouse_tuples(
+        &self,
+        warehouse_id: WarehouseId,
+    ) -> OpenFGAResult<Vec<TupleKey>> {
+        export_warehouse_tuples(self, warehouse_id).await
+    }
+
+    /// Write back tuples previously obtained from [`Self::export_warehouse_tuples`].
+    ///
+    /// Tuples that already exist in the store are left untouched.
+    pub async fn restore_warehouse_tuples(&self, tuples: Vec<TupleKey>) -> OpenFGAResult<()> {
+        restore_warehouse_tuples(self, tuples).await
+    }
+}
+
+pub(crate) async fn export_warehouse_tuples(
+    store: &impl TupleStore,
+    warehouse_id: WarehouseId,
+) -> OpenFGAResult<Vec<TupleKey>> {
+    let warehouse = warehouse_id.to_openfga();
+    let mut seen = HashSet::new();
+    let mut exported = Vec::new();
+    let mut collect = |tuples: Vec<TupleKey>| {
+        for tuple in tuples {
+            if seen.insert(ident(&tuple)) {
+                exported.push(tuple);
+            }
+        }
+    };
+
+    // Tuples in which the warehouse is the user, e.g. `project:<id>#warehouse@warehouse:<id>`.
+    for object_type in FgaType::Warehouse.user_of() {
+        let tuples = store
+            .read_tuples(ReadRequestTupleKey {
+                user: warehouse.clone(),
+                relation: String::new(),
+                object: format!("{object_type}:"),
+            })
+            .await?;
+        collect(tuples);
+    }
+
+    let mut visited = HashSet::from([warehouse.clone()]);
+    let mut queue = VecDeque::from([warehouse]);
+    while let Some(object) = queue.pop_front() {
+        let tuples = store
+            .read_tuples(ReadRequestTupleKey {
+                user: String::new(),
+                relation: String::new(),
+                object: object.clone(),
+            })
+            .await?;
+        let mut children = tuples
+            .iter()
+            .filter_map(descendant_of)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let is_table = object.starts_with(&format!("{}:", FgaType::Table));
+        collect(tuples);
+
+        // Columns point to their table via `parent` only; there is no `child` edge.
+        if is_table {
+            let columns = store
+                .read_tuples(ReadRequestTupleKey {
+                    user: object,
+                    relation: String::new(),
+                    object: format!("{}:", FgaType::Column),
+                })
+                .await?;
+            children.extend(columns.iter().map(|t| t.object.clone()));
+            collect(columns);
+        }
+
+        for child in children {
+            if visited.insert(child.clone()) {
+                queue.push_back(child);
+            }
+        }
+    }
+
+    Ok(exported)
+}
+
+pub(crate) async fn restore_warehouse_tuples(
+    store: &impl TupleStore,
+    tuples: Vec<TupleKey>,
+) -> OpenFGAResult<()> {
+    let chunk_size = usize::try_from(MAX_TUPLES_PER_WRITE).unwrap_or(1);
+    for chunk in tuples.chunks(chunk_size) {
+        store.write_tuples(chunk.to_vec()).await?;
+    }
+    Ok(())
+}
+
+/// If `tuple` links its object to a structural child, return that child.
+fn descendant_of(tuple: &TupleKey) -> Option<&str> {
+    let (object_type, _) = tuple.object.split_once(':')?;
+    let is_child_edge = (object_type == FgaType::Warehouse.as_ref()
+        && tuple.relation == WarehouseRelation::Namespace.to_string())
+        || (object_type == FgaType::Namespace.as_ref()
+            && tuple.relation == NamespaceRelation::Child.to_string());
+    is_child_edge.then_some(tuple.user.as_str())
+}
+
+fn ident(tuple: &TupleKey) -> (String, String, String) {
+    (
+        tuple.user.clone(),
+        tuple.relation.clone(),
+        tuple.object.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// In-memory tuple store supporting the read filters used by the export.
+    #[derive(Debug, Default)]
+    struct MockTupleStore {
+        tuples: Mutex<Vec<TupleKey>>,
+    }
+
+    impl MockTupleStore {
+        fn with_tuples(tuples: Vec<TupleKey>) -> Self {
+            Self {
+                tuples: Mutex::new(tuples),
+            }
+        }
+
+        fn idents(&self) -> HashSet<(String, String, String)> {
+            self.tuples.lock().unwrap().iter().map(ident).collect()
+        }
+    }
+
+    impl TupleStore for MockTupleStore {
+        async fn read_tuples(&self, key: ReadRequestTupleKey) -> OpenFGAResult<Vec<TupleKey>> {
+            let matches_object = |object: &str| {
+                if key.object.ends_with(':') {
+                    object.starts_with(&key.object)
+                } else {
+                    object == key.object
+                }
+            };
+            Ok(self
+                .tuples
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| key.user.is_empty() || t.user == key.user)
+                .filter(|t| key.relation.is_empty() || t.relation == key.relation)
+                .filter(|t| matches_object(&t.object))
+                .cloned()
+                .collect())
+        }
+
+        async fn write_tuples(&self, tuples: Vec<TupleKey>) -> OpenFGAResult<()> {
+            assert!(tuples.len() <= usize::try_from(MAX_TUPLES_PER_WRITE).unwrap());
+            let mut stored = self.tuples.lock().unwrap();
+            for tuple in tuples {
+                if !stored.iter().any(|t| ident(t) == ident(&tuple)) {
+                    stored.push(tuple);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn tuple(user: &str, relation: &str, object: &str) -> TupleKey {
+        TupleKey {
+            user: user.to_string(),
+            relation: relation.to_string(),
+            object: object.to_string(),
+            condition: None,
+        }
+    }
+
+    fn warehouse_tuples(warehouse: &str, suffix: &str) -> Vec<TupleKey> {
+        let namespace = format!("namespace:ns-{suffix}");
+        let nested = format!("namespace:nested-{suffix}");
+        let table = format!("lakekeeper_table:tab-{suffix}");
+        let column = format!("lakekeeper_column:tab-{suffix}/1");
+        vec![
+            tuple(&format!("project:p-{suffix}"), "project", warehouse),
+            tuple(warehouse, "warehouse", &format!("project:p-{suffix}")),
+            tuple("user:owner", "ownership", warehouse),
+            tuple(&namespace, "namespace", warehouse),
+            tuple(warehouse, "parent", &namespace),
+            tuple(&nested, "child", &namespace),
+            tuple(&namespace, "parent", &nested),
+            tuple(&table, "child", &nested),
+            tuple(&nested, "parent", &table),
+            tuple("role:readers#assignee", "select", &table),
+            tuple(&table, "parent", &column),
+            tuple("role:readers#assignee", "select", &column),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_export_then_restore_round_trips_warehouse_tuples() {
+        let warehouse_id = WarehouseId::new(uuid::Uuid::now_v7());
+        let other_id = WarehouseId::new(uuid::Uuid::now_v7());
+        let warehouse = warehouse_id.to_openfga();
+        let expected = warehouse_tuples(&warehouse, "a");
+
+        let mut all = expected.clone();
+        all.extend(warehouse_tuples(&other_id.to_openfga(), "b"));
+        all.push(tuple("user:admin", "admin", "server:srv"));
+        let source = MockTupleStore::with_tuples(all);
+
+        let exported = export_warehouse_tuples(&source, warehouse_id)
+            .await
+            .unwrap();
+        let exported_idents = exported.iter().map(ident).collect::<HashSet<_>>();
+        assert_eq!(exported.len(), exported_idents.len(), "no duplicates");
+        assert_eq!(
+            exported_idents,
+            expected.iter().map(ident).collect::<HashSet<_>>()
+        );
+
+        let target = MockTupleStore::default();
+        restore_warehouse_tuples(&target, exported.clone())
+            .await
+            .unwrap();
+        assert_eq!(target.idents(), exported_idents);
+
+        // Restoring again is a no-op.
+        restore_warehouse_tuples(&target, exported).await.unwrap();
+        assert_eq!(target.idents(), exported_idents);
+    }
+
+    #[tokio::test]
+    async fn test_restore_chunks_large_exports() {
+        let tuples = (0..250)
+            .map(|i| tuple(&format!("user:u{i}"), "describe", "namespace:ns"))
+            .collect::<Vec<_>>();
+        let target = MockTupleStore::default();
+        restore_warehouse_tuples(&target, tuples).await.unwrap();
+        assert_eq!(target.idents().len(), 250);
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use futures::stream::{self, StreamExt as _, TryStreamExt as _};
 use http::StatusCode;
 use lakekeeper::{
     ProjectId, WarehouseId,
@@ -7,17 +8,20 @@ use lakekeeper::{
         ApiContext, RequestMetadata,
         management::v1::check::{NamespaceIdentOrUuid, TabularIdentOrUuid, UserOrRole},
     },
-    axum::{Extension, Json, extract::State as AxumState},
+    axum::{
+        Extension, Json,
+        extract::{Path, Query, State as AxumState},
+    },
     iceberg::TableIdent,
     service::{
         AuthZGenericTableInfo as _, AuthZTableInfo, AuthZViewInfo as _, CatalogNamespaceOps,
-        CatalogStore, CatalogTabularOps, CatalogWarehouseOps, GenericTableId,
+        CatalogStore, CatalogTabularOps, CatalogWarehouseOps, ErrorModel, GenericTableId,
         GenericTableIdentOrId, NamespaceIdentOrId, Result, SecretStore, State, TableId,
-        TableIdentOrId, TabularListFlags, ViewId, ViewIdentOrId,
+        TableIdentOrId, TabularListFlags, UserId, ViewId, ViewIdentOrId,
         authz::{
             AuthZError, AuthZGenericTableOps, AuthZTableOps, AuthZViewOps, AuthzNamespaceOps as _,
-            AuthzWarehouseOps, RequireGenericTableActionError, RequireTableActionError,
-            RequireViewActionError,
+            AuthzWarehouseOps, CatalogTableAction, CatalogTableActionKind,
+            RequireGenericTableActionError, RequireTableActionError, RequireViewActionError,
         },
         events::{APIEventContext, EventDispatcher, context::authz_to_error_no_audit},
     },
@@ -27,7 +31,7 @@ use openfga_client::client::CheckRequestTupleKey;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    OpenFGAAuthorizer, OpenFGAError,
+    OpenFGAAuthorizer, OpenFGABacked, OpenFGAError,
     relations::{
         APIGenericTableAction as GenericTableAction, APINamespaceAction as NamespaceAction,
         APIProjectAction as ProjectAction, APIProjectAction, APIServerAction as ServerAction,
@@ -41,6 +45,11 @@ use super::{
 };
 use crate::entities::OpenFgaEntity;
 
+/// Maximum number of checks in a single `check-batch` request.
+const MAX_CHECKS_PER_BATCH: usize = 1000;
+/// Maximum number of checks of a `check-batch` request resolved concurrently.
+const MAX_CONCURRENT_CHECK_RESOLUTIONS: usize = 16;
+
 /// Check if a specific action is allowed on the given object
 #[cfg_attr(feature = "open-api", utoipa::path(
     post,
@@ -51,8 +60,8 @@ use crate::entities::OpenFgaEntity;
             (status = 200, body = CheckResponse),
     )
 ))]
-pub(super) async fn check<C: CatalogStore, S: SecretStore>(
-    AxumState(api_context): AxumState<ApiContext<State<OpenFGAAuthorizer, C, S>>>,
+pub(super) async fn check<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
     Extension(metadata): Extension<RequestMetadata>,
     Json(request): Json<CheckRequest>,
 ) -> Result<(StatusCode, Json<CheckResponse>)> {
@@ -60,12 +69,191 @@ pub(super) async fn check<C: CatalogStore, S: SecretStore>(
     Ok((StatusCode::OK, Json(CheckResponse { allowed })))
 }
 
-async fn check_internal<C: CatalogStore, S: SecretStore>(
-    api_context: ApiContext<State<OpenFGAAuthorizer, C, S>>,
+/// Check multiple actions in a single request
+///
+/// Returns one decision per entry of `checks`, in the same order.
+/// At most 1000 checks can be performed in a single request.
+#[cfg_attr(feature = "open-api", utoipa::path(
+    post,
+    tag = "permissions-openfga",
+    path = "/management/v1/permissions/check-batch",
+    request_body = CheckBatchRequest,
+    responses(
+            (status = 200, body = CheckBatchResponse),
+    )
+))]
+pub(super) async fn check_batch<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    Extension(metadata): Extension<RequestMetadata>,
+    Json(request): Json<CheckBatchRequest>,
+) -> Result<(StatusCode, Json<CheckBatchResponse>)> {
+    let allowed = check_batch_internal(api_context, Arc::new(metadata), request).await?;
+    Ok((StatusCode::OK, Json(CheckBatchResponse { allowed })))
+}
+
+/// Get the effective catalog permissions on a table
+///
+/// Evaluates every catalog action on the table and returns whether it is allowed,
+/// including grants inherited from roles and parent objects.
+/// If `user_id` is not specified, the permissions of the current user are returned.
+/// Only server admins and operators can query the permissions of other users.
+#[cfg_attr(feature = "open-api", utoipa::path(
+    get,
+    tag = "permissions-openfga",
+    path = "/management/v1/warehouse/{warehouse_id}/table/{table_id}/permissions",
+    params(
+        GetTablePermissionsQuery,
+        ("warehouse_id" = Uuid, Path, description = "Warehouse ID"),
+        ("table_id" = Uuid, Path, description = "Table ID")
+    ),
+    responses(
+            (status = 200, body = GetTablePermissionsResponse),
+    )
+))]
+pub(super) async fn get_table_permissions<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    Path((warehouse_id, table_id)): Path<(WarehouseId, TableId)>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    Extension(metadata): Extension<RequestMetadata>,
+    Query(query): Query<GetTablePermissionsQuery>,
+) -> Result<(StatusCode, Json<GetTablePermissionsResponse>)> {
+    let permissions = get_table_permissions_internal(
+        api_context,
+        Arc::new(metadata),
+        warehouse_id,
+        table_id,
+        query.user_id,
+    )
+    .await?;
+    Ok((
+        StatusCode::OK,
+        Json(GetTablePermissionsResponse { permissions }),
+    ))
+}
+
+async fn check_internal<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
     metadata: Arc<RequestMetadata>,
     request: CheckRequest,
 ) -> Result<bool> {
-    let authorizer = api_context.v1_state.authz.clone();
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
+    let tuple_key = resolve_check(api_context, metadata, request).await?;
+
+    let allowed = authorizer
+        .check(tuple_key)
+        .await
+        .map_err(authz_to_error_no_audit)?;
+
+    Ok(allowed)
+}
+
+async fn check_batch_internal<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
+    metadata: Arc<RequestMetadata>,
+    request: CheckBatchRequest,
+) -> Result<Vec<bool>> {
+    let CheckBatchRequest { checks } = request;
+    if checks.len() > MAX_CHECKS_PER_BATCH {
+        return Err(ErrorModel::bad_request(
+            format!(
+                "Too many checks requested: {}. Maximum allowed is {MAX_CHECKS_PER_BATCH}",
+                checks.len(),
+            ),
+            "TooManyChecks",
+            None,
+        )
+        .into());
+    }
+
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
+    // Resolving a check may hit the catalog, so limit how many run at once.
+    let tuple_keys: Vec<CheckRequestTupleKey> = stream::iter(checks)
+        .map(|check| resolve_check(api_context.clone(), metadata.clone(), check))
+        .buffered(MAX_CONCURRENT_CHECK_RESOLUTIONS)
+        .try_collect()
+        .await?;
+
+    // `batch_check` splits the items into chunks of at most
+    // `max_batch_check_size`, the limit of the OpenFGA `BatchCheck` API.
+    let allowed = authorizer
+        .batch_check(tuple_keys)
+        .await
+        .map_err(authz_to_error_no_audit)?;
+
+    Ok(allowed)
+}
+
+async fn get_table_permissions_internal<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
+    metadata: Arc<RequestMetadata>,
+    warehouse_id: WarehouseId,
+    table_id: TableId,
+    user_id: Option<UserId>,
+) -> Result<Vec<TablePermission>> {
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
+
+    // Set for_principal to None if the user is querying their own permissions
+    let mut for_principal = user_id.map(UserOrRole::User);
+    if let Some(user_or_role) = &metadata.actor().api_user_or_role() {
+        for_principal = for_principal.filter(|p| p != user_or_role);
+    }
+
+    // Permissions of other users may only be inspected by server admins and operators.
+    if for_principal.is_some() {
+        check_server(
+            metadata.clone(),
+            &authorizer,
+            &mut for_principal,
+            &ServerAction::ReadAssignments,
+            api_context.v1_state.events.clone(),
+        )
+        .await?;
+    }
+
+    let object = check_table(
+        api_context,
+        metadata.clone(),
+        &TabularIdentOrUuid::IdInWarehouse {
+            warehouse_id,
+            table_id: *table_id,
+        },
+        for_principal.as_ref(),
+    )
+    .await?;
+    let user = if let Some(for_principal) = &for_principal {
+        for_principal.to_openfga()
+    } else {
+        metadata.actor().to_openfga()
+    };
+
+    let actions = CatalogTableAction::variants();
+    let allowed = futures::future::try_join_all(actions.iter().map(|action| {
+        authorizer.check(CheckRequestTupleKey {
+            user: user.clone(),
+            relation: action.to_openfga().to_string(),
+            object: object.clone(),
+        })
+    }))
+    .await
+    .map_err(authz_to_error_no_audit)?;
+
+    Ok(actions
+        .iter()
+        .zip(allowed)
+        .map(|(action, allowed)| TablePermission {
+            action: action.into(),
+            allowed,
+        })
+        .collect())
+}
+
+/// Authorize the caller to perform `request` and resolve it into the `OpenFGA`
+/// tuple that needs to be checked.
+async fn resolve_check<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
+    metadata: Arc<RequestMetadata>,
+    request: CheckRequest,
+) -> Result<CheckRequestTupleKey> {
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let event_dispatcher = api_context.v1_state.events.clone();
 
     let CheckRequest {
@@ -159,16 +347,11 @@ async fn check_internal<C: CatalogStore, S: SecretStore>(
         metadata.actor().to_openfga()
     };
 
-    let allowed = authorizer
-        .check(CheckRequestTupleKey {
-            user,
-            relation: action,
-            object,
-        })
-        .await
-        .map_err(authz_to_error_no_audit)?;
-
-    Ok(allowed)
+    Ok(CheckRequestTupleKey {
+        user,
+        relation: action,
+        object,
+    })
 }
 
 async fn check_warehouse(
@@ -271,8 +454,8 @@ async fn check_server(
     Ok((action.to_openfga().to_string(), openfga_server))
 }
 
-async fn check_namespace<C: CatalogStore, S: SecretStore>(
-    api_context: ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn check_namespace<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
     metadata: Arc<RequestMetadata>,
     namespace: &NamespaceIdentOrUuid,
     for_principal: Option<&UserOrRole>,
@@ -300,7 +483,7 @@ async fn check_namespace<C: CatalogStore, S: SecretStore>(
         action,
     );
 
-    let authz_result = authorize_check_namespace::<C, S>(
+    let authz_result = authorize_check_namespace::<A, C, S>(
         &api_context,
         event_ctx.request_metadata(),
         warehouse_id,
@@ -313,14 +496,14 @@ async fn check_namespace<C: CatalogStore, S: SecretStore>(
     Ok(ns_openfga)
 }
 
-async fn authorize_check_namespace<C: CatalogStore, S: SecretStore>(
-    api_context: &ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn authorize_check_namespace<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: &ApiContext<State<A, C, S>>,
     metadata: &RequestMetadata,
     warehouse_id: WarehouseId,
     user_provided_ns: NamespaceIdentOrId,
     action: AllNamespaceRelations,
 ) -> Result<String, AuthZError> {
-    let authorizer = api_context.v1_state.authz.clone();
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let (warehouse, namespace) = tokio::join!(
         C::get_active_warehouse_by_id(warehouse_id, api_context.v1_state.catalog.clone(),),
         C::get_namespace(
@@ -337,8 +520,8 @@ async fn authorize_check_namespace<C: CatalogStore, S: SecretStore>(
     Ok(namespace.namespace_id().to_openfga())
 }
 
-async fn check_table<C: CatalogStore, S: SecretStore>(
-    api_context: ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn check_table<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
     metadata: Arc<RequestMetadata>,
     table: &TabularIdentOrUuid,
     for_principal: Option<&UserOrRole>,
@@ -373,7 +556,7 @@ async fn check_table<C: CatalogStore, S: SecretStore>(
         action,
     );
 
-    let authz_result = authorize_check_table::<C, S>(
+    let authz_result = authorize_check_table::<A, C, S>(
         &api_context,
         event_ctx.request_metadata(),
         warehouse_id,
@@ -386,14 +569,14 @@ async fn check_table<C: CatalogStore, S: SecretStore>(
     Ok(table_openfga)
 }
 
-async fn authorize_check_table<C: CatalogStore, S: SecretStore>(
-    api_context: &ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn authorize_check_table<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: &ApiContext<State<A, C, S>>,
     metadata: &RequestMetadata,
     warehouse_id: WarehouseId,
     table: TableIdentOrId,
     action: AllTableRelations,
 ) -> Result<String, AuthZError> {
-    let authorizer = api_context.v1_state.authz.clone();
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let (warehouse, table_info) = tokio::join!(
         C::get_active_warehouse_by_id(warehouse_id, api_context.v1_state.catalog.clone()),
         C::get_table_info(
@@ -433,8 +616,8 @@ async fn authorize_check_table<C: CatalogStore, S: SecretStore>(
     Ok((warehouse_id, table_info.table_id()).to_openfga())
 }
 
-async fn check_view<C: CatalogStore, S: SecretStore>(
-    api_context: ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn check_view<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
     metadata: Arc<RequestMetadata>,
     view: &TabularIdentOrUuid,
     for_principal: Option<&UserOrRole>,
@@ -469,7 +652,7 @@ async fn check_view<C: CatalogStore, S: SecretStore>(
         action,
     );
 
-    let authz_result = authorize_check_view::<C, S>(
+    let authz_result = authorize_check_view::<A, C, S>(
         &api_context,
         event_ctx.request_metadata(),
         warehouse_id,
@@ -482,14 +665,14 @@ async fn check_view<C: CatalogStore, S: SecretStore>(
     Ok(view_openfga)
 }
 
-async fn authorize_check_view<C: CatalogStore, S: SecretStore>(
-    api_context: &ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn authorize_check_view<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: &ApiContext<State<A, C, S>>,
     metadata: &RequestMetadata,
     warehouse_id: WarehouseId,
     view: ViewIdentOrId,
     action: AllViewRelations,
 ) -> Result<String, AuthZError> {
-    let authorizer = api_context.v1_state.authz.clone();
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let (warehouse, table_info) = tokio::join!(
         C::get_active_warehouse_by_id(warehouse_id, api_context.v1_state.catalog.clone()),
         C::get_view_info(
@@ -527,8 +710,8 @@ async fn authorize_check_view<C: CatalogStore, S: SecretStore>(
     Ok((warehouse_id, view_info.view_id()).to_openfga())
 }
 
-async fn check_generic_table<C: CatalogStore, S: SecretStore>(
-    api_context: ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn check_generic_table<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: ApiContext<State<A, C, S>>,
     metadata: Arc<RequestMetadata>,
     generic_table: &TabularIdentOrUuid,
     for_principal: Option<&UserOrRole>,
@@ -566,7 +749,7 @@ async fn check_generic_table<C: CatalogStore, S: SecretStore>(
         action,
     );
 
-    let authz_result = authorize_check_generic_table::<C, S>(
+    let authz_result = authorize_check_generic_table::<A, C, S>(
         &api_context,
         event_ctx.request_metadata(),
         warehouse_id,
@@ -579,14 +762,14 @@ async fn check_generic_table<C: CatalogStore, S: SecretStore>(
     Ok(gt_openfga)
 }
 
-async fn authorize_check_generic_table<C: CatalogStore, S: SecretStore>(
-    api_context: &ApiContext<State<OpenFGAAuthorizer, C, S>>,
+async fn authorize_check_generic_table<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    api_context: &ApiContext<State<A, C, S>>,
     metadata: &RequestMetadata,
     warehouse_id: WarehouseId,
     generic_table: GenericTableIdentOrId,
     action: AllGenericTableRelations,
 ) -> Result<String, AuthZError> {
-    let authorizer = api_context.v1_state.authz.clone();
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
     let (warehouse, gt_info) = tokio::join!(
         C::get_active_warehouse_by_id(warehouse_id, api_context.v1_state.catalog.clone()),
         C::get_generic_table_info(
@@ -686,6 +869,51 @@ pub(super) struct CheckResponse {
     allowed: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+/// Check multiple actions in a single request
+pub(super) struct CheckBatchRequest {
+    /// The checks to perform.
+    checks: Vec<CheckRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct CheckBatchResponse {
+    /// Whether each action is allowed, in the order of the requested checks.
+    allowed: Vec<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+pub(super) struct GetTablePermissionsQuery {
+    /// The user to show permissions for.
+    /// If not specified, shows permissions for the current user.
+    #[serde(default)]
+    #[cfg_attr(feature = "open-api", param(required = false, value_type = String))]
+    user_id: Option<UserId>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct GetTablePermissionsResponse {
+    /// Decision for each catalog action on the table.
+    permissions: Vec<TablePermission>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct TablePermission {
+    #[serde(flatten)]
+    action: CatalogTableActionKind,
+    /// Whether the action is allowed.
+    allowed: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use lakekeeper::service::{NamespaceId, NamespaceIdent, UserId};
@@ -878,7 +1106,10 @@ mod tests {
         use lakekeeper::{
             api::{
                 CreateNamespaceRequest,
-                iceberg::v1::{Prefix, namespace::NamespaceService},
+                iceberg::v1::{
+                    DataAccess, NamespaceParameters, Prefix, namespace::NamespaceService,
+                    tables::TablesService,
+                },
                 management::v1::{
                     ApiServer,
                     role::{CreateRoleRequest, Service as RoleService},
@@ -887,14 +1118,19 @@ mod tests {
             server::{CatalogServer, NAMESPACE_ID_PROPERTY},
             service::{CreateNamespaceResponse, NamespaceId, NamespaceIdent, authn::UserId},
         };
-        use lakekeeper_integration_tests::{SetupTestCatalog, TestWarehouseResponse};
+        use lakekeeper_integration_tests::{
+            SetupTestCatalog, TestWarehouseResponse, create_table_request,
+        };
         use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
         use openfga_client::client::TupleKey;
         use strum::IntoEnumIterator;
         use uuid::Uuid;
 
         use super::super::*;
-        use crate::migration::tests::authorizer_for_empty_store;
+        use crate::{
+            migration::tests::authorizer_for_empty_store,
+            relations::RoleRelation as AllRoleRelations,
+        };
 
         async fn setup(
             operator_id: UserId,
@@ -972,6 +1208,219 @@ mod tests {
             assert!(!allowed);
         }
 
+        #[sqlx::test]
+        async fn test_check_batch(pool: sqlx::PgPool) {
+            let operator_id = UserId::new_unchecked("oidc", &Uuid::now_v7().to_string());
+            let (ctx, warehouse, namespace) = setup(operator_id.clone(), pool).await;
+            let namespace_id = NamespaceId::from_str_or_internal(
+                namespace
+                    .properties
+                    .unwrap()
+                    .get(NAMESPACE_ID_PROPERTY)
+                    .unwrap(),
+            )
+            .unwrap();
+            let namespace = NamespaceIdentOrUuid::Id {
+                namespace_id,
+                warehouse_id: warehouse.warehouse_id,
+            };
+
+            let user_1_id = UserId::new_unchecked("oidc", &Uuid::now_v7().to_string());
+            let user_1_metadata = Arc::new(RequestMetadata::test_user(user_1_id.clone()));
+            let operator_metadata = Arc::new(RequestMetadata::test_user(operator_id.clone()));
+            ctx.v1_state
+                .authz
+                .write(
+                    Some(vec![TupleKey {
+                        condition: None,
+                        object: namespace_id.to_openfga(),
+                        relation: AllNamespaceRelations::Select.to_string(),
+                        user: user_1_id.to_openfga(),
+                    }]),
+                    None,
+                )
+                .await
+                .unwrap();
+
+            let check = |identity: Option<UserOrRole>, action: NamespaceAction| CheckRequest {
+                identity,
+                operation: CheckOperation::Namespace {
+                    action,
+                    namespace: namespace.clone(),
+                },
+            };
+
+            // User 1 checks own access: select grants metadata access but no modifications.
+            let request = CheckBatchRequest {
+                checks: vec![
+                    check(None, NamespaceAction::GetMetadata),
+                    check(None, NamespaceAction::CreateTable),
+                    check(None, NamespaceAction::Delete),
+                    check(None, NamespaceAction::GrantSelect),
+                ],
+            };
+            let allowed = check_batch_internal(ctx.clone(), user_1_metadata.clone(), request)
+                .await
+                .unwrap();
+            assert_eq!(allowed, vec![true, false, false, false]);
+
+            // Operator mixes checks for user 1 and for itself.
+            let user_1 = Some(UserOrRole::User(user_1_id.clone()));
+            let request = CheckBatchRequest {
+                checks: vec![
+                    check(user_1.clone(), NamespaceAction::GetMetadata),
+                    check(user_1.clone(), NamespaceAction::CreateTable),
+                    check(None, NamespaceAction::CreateTable),
+                    check(user_1, NamespaceAction::Delete),
+                    check(None, NamespaceAction::Delete),
+                ],
+            };
+            let allowed = check_batch_internal(ctx.clone(), operator_metadata.clone(), request)
+                .await
+                .unwrap();
+            assert_eq!(allowed, vec![true, false, true, false, true]);
+
+            // User 1 cannot check operator access, which fails the whole batch.
+            let request = CheckBatchRequest {
+                checks: vec![
+                    check(None, NamespaceAction::GetMetadata),
+                    check(
+                        Some(UserOrRole::User(operator_id.clone())),
+                        NamespaceAction::GetMetadata,
+                    ),
+                ],
+            };
+            check_batch_internal(ctx.clone(), user_1_metadata, request)
+                .await
+                .unwrap_err();
+
+            // Empty batches are allowed, oversized batches are rejected.
+            let allowed = check_batch_internal(
+                ctx.clone(),
+                operator_metadata.clone(),
+                CheckBatchRequest { checks: vec![] },
+            )
+            .await
+            .unwrap();
+            assert!(allowed.is_empty());
+            let request = CheckBatchRequest {
+                checks: vec![check(None, NamespaceAction::GetMetadata); MAX_CHECKS_PER_BATCH + 1],
+            };
+            let err = check_batch_internal(ctx, operator_metadata, request)
+                .await
+                .unwrap_err();
+            assert_eq!(err.error.code, StatusCode::BAD_REQUEST.as_u16());
+            assert_eq!(err.error.r#type, "TooManyChecks");
+        }
+
+        #[sqlx::test]
+        async fn test_get_table_permissions(pool: sqlx::PgPool) {
+            let operator_id = UserId::new_unchecked("oidc", &Uuid::now_v7().to_string());
+            let (ctx, warehouse, namespace) = setup(operator_id.clone(), pool).await;
+            let operator_metadata = Arc::new(RequestMetadata::test_user(operator_id.clone()));
+            let user_id = UserId::new_unchecked("oidc", &Uuid::now_v7().to_string());
+            let user_metadata = Arc::new(RequestMetadata::test_user(user_id.clone()));
+
+            let table = CatalogServer::create_table(
+                NamespaceParameters {
+                    prefix: Some(Prefix::from(warehouse.warehouse_id.to_string())),
+                    namespace: namespace.namespace,
+                },
+                create_table_request(Some("tab1".to_string()), None),
+                DataAccess::not_specified(),
+                ctx.clone(),
+                (*operator_metadata).clone(),
+            )
+            .await
+            .unwrap();
+            let table_id = TableId::from(table.metadata.uuid());
+
+            // The user only receives `select` on the table through a role.
+            let role_id = ApiServer::create_role(
+                CreateRoleRequest::builder()
+                    .name("readers".to_string())
+                    .build(),
+                ctx.clone(),
+                (*operator_metadata).clone(),
+            )
+            .await
+            .unwrap()
+            .id;
+            ctx.v1_state
+                .authz
+                .write(
+                    Some(vec![
+                        TupleKey {
+                            condition: None,
+                            object: role_id.to_openfga(),
+                            relation: AllRoleRelations::Assignee.to_string(),
+                            user: user_id.to_openfga(),
+                        },
+                        TupleKey {
+                            condition: None,
+                            object: (warehouse.warehouse_id, table_id).to_openfga(),
+                            relation: AllTableRelations::Select.to_string(),
+                            user: UserOrRole::Role(role_id.into_api_assignee()).to_openfga(),
+                        },
+                    ]),
+                    None,
+                )
+                .await
+                .unwrap();
+
+            let allowed_actions = |permissions: Vec<TablePermission>| {
+                permissions
+                    .into_iter()
+                    .filter(|p| p.allowed)
+                    .map(|p| p.action)
+                    .collect::<Vec<_>>()
+            };
+            let expected = vec![
+                CatalogTableActionKind::ReadData,
+                CatalogTableActionKind::GetMetadata,
+                CatalogTableActionKind::IncludeInList,
+                CatalogTableActionKind::GetTasks,
+            ];
+
+            // Operator queries the user's permissions.
+            let permissions = get_table_permissions_internal(
+                ctx.clone(),
+                operator_metadata.clone(),
+                warehouse.warehouse_id,
+                table_id,
+                Some(user_id.clone()),
+            )
+            .await
+            .unwrap();
+            assert_eq!(permissions.len(), CatalogTableAction::variants().len());
+            assert_eq!(allowed_actions(permissions), expected);
+
+            // User queries own permissions, with and without specifying themselves.
+            for query_user in [None, Some(user_id.clone())] {
+                let permissions = get_table_permissions_internal(
+                    ctx.clone(),
+                    user_metadata.clone(),
+                    warehouse.warehouse_id,
+                    table_id,
+                    query_user,
+                )
+                .await
+                .unwrap();
+                assert_eq!(allowed_actions(permissions), expected);
+            }
+
+            // User cannot query the operator's permissions.
+            get_table_permissions_internal(
+                ctx,
+                user_metadata,
+                warehouse.warehouse_id,
+                table_id,
+                Some(operator_id),
+            )
+            .await
+            .unwrap_err();
+        }
+
         #[sqlx::test]
         async fn test_check(pool: sqlx::PgPool) {
             let operator_id = UserId::new_unchecked("oidc", &Uuid::now_v7().to_string());
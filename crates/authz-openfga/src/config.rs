@@ -78,6 +78,10 @@ pub struct OpenFGAConfig {
     /// [configuration option]: https://openfga.dev/docs/getting-started/setup-openfga/configuration#OPENFGA_MAX_CHECKS_PER_BATCH_CHECK
     #[serde(default = "default_openfga_max_batch_check_size")]
     pub max_batch_check_size: usize,
+    /// Allow all read actions without checking `OpenFGA`, while write actions
+    /// are enforced. Strictly a migration aid, defaults to `false`.
+    #[serde(default)]
+    pub allow_all_reads: bool,
 }
 
 #[derive(Clone, Default, Serialize, Deserialize, PartialEq, veil::Redact)]
@@ -111,6 +115,7 @@ where
         authorization_model_prefix,
         authorization_model_version,
         max_batch_check_size,
+        allow_all_reads,
     }) = Option::<OpenFGAConfigSerde>::deserialize(deserializer)?
     else {
         return Ok(None);
@@ -150,6 +155,7 @@ where
         authorization_model_prefix,
         authorization_model_version,
         max_batch_check_size,
+        allow_all_reads,
     }))
 }
 
@@ -193,6 +199,7 @@ where
         authorization_model_prefix: value.authorization_model_prefix.clone(),
         authorization_model_version: value.authorization_model_version.clone(),
         max_batch_check_size: value.max_batch_check_size,
+        allow_all_reads: value.allow_all_reads,
     }
     .serialize(serializer)
 }
@@ -221,6 +228,8 @@ struct OpenFGAConfigSerde {
     token_endpoint: Option<Url>,
     #[serde(default = "default_openfga_max_batch_check_size")]
     max_batch_check_size: usize,
+    #[serde(default)]
+    allow_all_reads: bool,
 }
 
 fn default_openfga_store_name() -> String {
@@ -252,6 +261,7 @@ mod test {
             assert_eq!(authz_config.store_name, "store_name");
 
             assert_eq!(authz_config.auth, OpenFGAAuth::Anonymous);
+            assert!(!authz_config.allow_all_reads);
 
             Ok(())
         });
@@ -317,6 +317,19 @@ impl OpenFgaEntity for (WarehouseId, ViewId) {
     }
 }
 
+/// `OpenFga` entity for a single column of a `table`, identified by its Iceberg field id.
+///
+/// Field ids are only unique within a table, so the column carries the full table context.
+impl OpenFgaEntity for (WarehouseId, TableId, i32) {
+    fn to_openfga(&self) -> String {
+        format!("{}:{}/{}/{}", self.openfga_type(), self.0, self.1, self.2)
+    }
+
+    fn openfga_type(&self) -> FgaType {
+        FgaType::Column
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
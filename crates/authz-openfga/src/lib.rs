@@ -9,7 +9,9 @@
 
 use std::{str::FromStr as _, sync::LazyLock};
 
+pub use allow_all_reads::AllowAllReadsAuthorizer;
 pub use authorizer::OpenFGAAuthorizer;
+pub(crate) use authorizer::OpenFGABacked;
 #[cfg(any(test, feature = "test-utils"))]
 pub use client::new_authorizer_in_empty_store_from_default_config;
 pub use client::{
@@ -19,8 +21,10 @@ pub use client::{
 pub(crate) use error::{OpenFGAError, OpenFGAResult, ParseOpenFgaEntityError};
 use openfga_client::migration::AuthorizationModelVersion;
 
+mod allow_all_reads;
 mod api;
 mod authorizer;
+mod backup;
 mod check;
 mod client;
 mod config;
@@ -29,12 +33,15 @@ pub mod error;
 mod health;
 mod migration;
 mod models;
+mod ownership;
 mod reconcile;
 mod relations;
+mod request_metrics;
 mod tuples;
 
 pub use config::CONFIG;
-pub use migration::migrate;
+pub use migration::{MigrationVerification, migrate, verify_migration};
+pub use ownership::OwnershipTransferReport;
 pub use reconcile::{
     RECONCILE_LOCK_KEY, RebuildReport, ReconcileMode, ReconcileReport,
     rebuild_hierarchy_tuples_from_catalog, reconcile_hierarchy_tuples_from_catalog,
@@ -82,6 +89,8 @@ pub enum FgaType {
     View,
     #[strum(serialize = "lakekeeper_generic_table")]
     GenericTable,
+    #[strum(serialize = "lakekeeper_column")]
+    Column,
     ModelVersion,
     AuthModelId,
 }
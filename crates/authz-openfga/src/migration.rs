@@ -2,7 +2,7 @@ use std::sync::LazyLock;
 
 use lakekeeper::service::ServerId;
 use openfga_client::{
-    client::{BasicAuthLayer, BasicOpenFgaServiceClient},
+    client::{AuthorizationModel, BasicAuthLayer, BasicOpenFgaServiceClient},
     migration::{AuthorizationModelVersion, MigrationFn, TupleModelManager},
 };
 
@@ -15,7 +15,14 @@ pub(super) static V4_0_MODEL_VERSION: LazyLock<AuthorizationModelVersion> =
     LazyLock::new(|| AuthorizationModelVersion::new(4, 0));
 
 pub(super) static V4_CURRENT_MODEL_VERSION: LazyLock<AuthorizationModelVersion> =
-    LazyLock::new(|| AuthorizationModelVersion::new(4, 7));
+    LazyLock::new(|| AuthorizationModelVersion::new(4, 8));
+
+// Change these for backward compatible changes.
+// For non-backward compatible changes that require tuple migrations, add another model.
+#[cfg(test)]
+const MODEL_V3_JSON: &str = include_str!("../../../authz/openfga/v3.4/schema.json");
+const MODEL_V4_0_JSON: &str = include_str!("../../../authz/openfga/v4.0/schema.json");
+const MODEL_V4_CURRENT_JSON: &str = include_str!("../../../authz/openfga/v4.8/schema.json");
 
 #[cfg(test)]
 pub(super) static V3_MODEL_VERSION: LazyLock<AuthorizationModelVersion> =
@@ -45,13 +52,9 @@ pub(crate) fn add_model_v3(
     manager: TupleModelManager<BasicAuthLayer, MigrationState>,
 ) -> TupleModelManager<BasicAuthLayer, MigrationState> {
     manager.add_model(
-        serde_json::from_str(include_str!(
-            // Change this for backward compatible changes.
-            // For non-backward compatible changes that require tuple migrations, add another `add_model` call.
-            "../../../authz/openfga/v3.4/schema.json"
-        ))
-        // Change also the model version in this string:
-        .expect("Model v3.4 is a valid AuthorizationModel in JSON format."),
+        serde_json::from_str(MODEL_V3_JSON)
+            // Change also the model version in this string:
+            .expect("Model v3.4 is a valid AuthorizationModel in JSON format."),
         *V3_MODEL_VERSION,
         // For major version upgrades, this is where tuple migrations go.
         None::<MigrationFn<_, _>>,
@@ -64,13 +67,9 @@ pub(crate) fn add_model_v4_0(
     manager: TupleModelManager<BasicAuthLayer, MigrationState>,
 ) -> TupleModelManager<BasicAuthLayer, MigrationState> {
     manager.add_model(
-        serde_json::from_str(include_str!(
-            // Change this for backward compatible changes.
-            // For non-backward compatible changes that require tuple migrations, add another `add_model` call.
-            "../../../authz/openfga/v4.0/schema.json"
-        ))
-        // Change also the model version in this string:
-        .expect("Model v4.0 is a valid AuthorizationModel in JSON format."),
+        serde_json::from_str(MODEL_V4_0_JSON)
+            // Change also the model version in this string:
+            .expect("Model v4.0 is a valid AuthorizationModel in JSON format."),
         *V4_0_MODEL_VERSION,
         // For major version upgrades, this is where tuple migrations go.
         None::<MigrationFn<_, _>>,
@@ -83,13 +82,9 @@ pub(crate) fn add_model_v4_current(
     manager: TupleModelManager<BasicAuthLayer, MigrationState>,
 ) -> TupleModelManager<BasicAuthLayer, MigrationState> {
     manager.add_model(
-        serde_json::from_str(include_str!(
-            // Change this for backward compatible changes.
-            // For non-backward compatible changes that require tuple migrations, add another `add_model` call.
-            "../../../authz/openfga/v4.7/schema.json"
-        ))
-        // Change also the model version in this string:
-        .expect("Model v4.7 is a valid AuthorizationModel in JSON format."),
+        serde_json::from_str(MODEL_V4_CURRENT_JSON)
+            // Change also the model version in this string:
+            .expect("Model v4.8 is a valid AuthorizationModel in JSON format."),
         *V4_CURRENT_MODEL_VERSION,
         // For major version upgrades, this is where tuple migrations go.
         None::<MigrationFn<_, _>>,
@@ -152,16 +147,145 @@ pub async fn migrate(
     }
     let store_name = store_name.unwrap_or(AUTH_CONFIG.store_name.clone());
     tracing::info!("Starting OpenFGA Migration for store {store_name} and server {server_id}");
-    let mut manager = get_model_manager(client, Some(store_name.clone()));
     let state = MigrationState {
         store_name,
         server_id,
     };
-    manager.migrate(state).await?;
+    client.apply_migrations(state).await?;
     tracing::info!("OpenFGA Migration finished");
     Ok(())
 }
 
+/// Outcome of a verify-only migration run, see [`verify_migration`].
+#[derive(Debug, Clone)]
+pub struct MigrationVerification {
+    /// Name of the `OpenFGA` store that was inspected.
+    pub store_name: String,
+    /// Model version a migration would bring the store to.
+    pub target_version: AuthorizationModelVersion,
+    /// Explicitly configured model version. If set, `migrate` is a no-op.
+    pub configured_version: Option<AuthorizationModelVersion>,
+    /// Whether the store exists. A migration creates missing stores.
+    pub store_exists: bool,
+    /// ID of the authorization model applied for `target_version`, if any.
+    pub applied_model_id: Option<String>,
+    /// Problems found in the embedded authorization models. Empty if all models are valid.
+    pub model_errors: Vec<String>,
+}
+
+impl MigrationVerification {
+    /// Whether running `migrate` would write to `OpenFGA`.
+    #[must_use]
+    pub fn migration_required(&self) -> bool {
+        self.configured_version.is_none() && self.applied_model_id.is_none()
+    }
+
+    /// Whether all embedded authorization models are syntactically valid.
+    #[must_use]
+    pub fn models_valid(&self) -> bool {
+        self.model_errors.is_empty()
+    }
+}
+
+/// `OpenFGA` operations needed to plan and apply model migrations.
+pub(crate) trait ModelMigrationBackend {
+    async fn store_exists(&self, store_name: &str) -> OpenFGAResult<bool>;
+
+    async fn applied_model_id(
+        &self,
+        store_name: &str,
+        version: AuthorizationModelVersion,
+    ) -> OpenFGAResult<Option<String>>;
+
+    async fn apply_migrations(&self, state: MigrationState) -> OpenFGAResult<()>;
+}
+
+impl ModelMigrationBackend for BasicOpenFgaServiceClient {
+    async fn store_exists(&self, store_name: &str) -> OpenFGAResult<bool> {
+        Ok(self.clone().get_store_by_name(store_name).await?.is_some())
+    }
+
+    async fn applied_model_id(
+        &self,
+        store_name: &str,
+        version: AuthorizationModelVersion,
+    ) -> OpenFGAResult<Option<String>> {
+        let mut manager = get_model_manager(self, Some(store_name.to_string()));
+        Ok(manager.get_authorization_model_id(version).await?)
+    }
+
+    async fn apply_migrations(&self, state: MigrationState) -> OpenFGAResult<()> {
+        let mut manager = get_model_manager(self, Some(state.store_name.clone()));
+        manager.migrate(state).await?;
+        Ok(())
+    }
+}
+
+/// Parse all embedded authorization models without panicking, collecting problems.
+fn embedded_model_errors() -> Vec<String> {
+    let models = [
+        (*V4_0_MODEL_VERSION, MODEL_V4_0_JSON),
+        (*V4_CURRENT_MODEL_VERSION, MODEL_V4_CURRENT_JSON),
+    ];
+    models
+        .into_iter()
+        .filter_map(
+            |(version, json)| match serde_json::from_str::<AuthorizationModel>(json) {
+                Ok(model) if model.schema_version.is_empty() => {
+                    Some(format!("Model {version} has no schema version"))
+                }
+                Ok(model) if model.type_definitions.is_empty() => {
+                    Some(format!("Model {version} has no type definitions"))
+                }
+                Ok(_) => None,
+                Err(e) => Some(format!(
+                    "Model {version} is not a valid AuthorizationModel: {e}"
+                )),
+            },
+        )
+        .collect()
+}
+
+async fn verify_migration_with<B: ModelMigrationBackend>(
+    backend: &B,
+    store_name: String,
+) -> OpenFGAResult<MigrationVerification> {
+    let store_exists = backend.store_exists(&store_name).await?;
+    let applied_model_id = if store_exists {
+        backend
+            .applied_model_id(&store_name, *ACTIVE_MODEL_VERSION)
+            .await?
+    } else {
+        None
+    };
+
+    Ok(MigrationVerification {
+        store_name,
+        target_version: *ACTIVE_MODEL_VERSION,
+        configured_version: *super::CONFIGURED_MODEL_VERSION,
+        store_exists,
+        applied_model_id,
+        model_errors: embedded_model_errors(),
+    })
+}
+
+/// Check whether [`migrate`] would change the authorization model, without writing
+/// anything to `OpenFGA`.
+///
+/// Validates the embedded authorization models and reads the model version that is
+/// currently applied in the store.
+///
+/// # Errors
+/// - Failed to read the store or the applied model versions
+pub async fn verify_migration(
+    client: &BasicOpenFgaServiceClient,
+    store_name: Option<String>,
+) -> OpenFGAResult<MigrationVerification> {
+    let store_name = store_name.unwrap_or(AUTH_CONFIG.store_name.clone());
+    tracing::info!("Verifying OpenFGA Migration for store {store_name} (verify only)");
+    verify_migration_with(client, store_name).await
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use openfga_client::client::ConsistencyPreference;
@@ -194,6 +318,109 @@ pub(crate) mod tests {
         (client, authorizer)
     }
 
+    mod verify_only {
+        use std::{
+            collections::{BTreeMap, HashMap},
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+
+        use lakekeeper::tokio;
+
+        use super::super::*;
+
+        /// In-memory stand-in for OpenFGA that counts reads and writes.
+        #[derive(Debug, Default)]
+        struct MockOpenFga {
+            applied: HashMap<String, BTreeMap<AuthorizationModelVersion, String>>,
+            reads: AtomicUsize,
+            writes: AtomicUsize,
+        }
+
+        impl ModelMigrationBackend for MockOpenFga {
+            async fn store_exists(&self, store_name: &str) -> OpenFGAResult<bool> {
+                self.reads.fetch_add(1, Ordering::SeqCst);
+                Ok(self.applied.contains_key(store_name))
+            }
+
+            async fn applied_model_id(
+                &self,
+                store_name: &str,
+                version: AuthorizationModelVersion,
+            ) -> OpenFGAResult<Option<String>> {
+                self.reads.fetch_add(1, Ordering::SeqCst);
+                Ok(self
+                    .applied
+                    .get(store_name)
+                    .and_then(|models| models.get(&version).cloned()))
+            }
+
+            async fn apply_migrations(&self, _state: MigrationState) -> OpenFGAResult<()> {
+                self.writes.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn test_embedded_models_are_valid() {
+            assert_eq!(embedded_model_errors(), Vec::<String>::new());
+        }
+
+        #[tokio::test]
+        async fn test_verify_only_missing_store_performs_no_writes() {
+            let mock = MockOpenFga::default();
+
+            let report = verify_migration_with(&mock, "missing".to_string())
+                .await
+                .unwrap();
+
+            assert!(!report.store_exists);
+            assert!(report.migration_required());
+            assert!(report.models_valid());
+            assert_eq!(report.target_version, *ACTIVE_MODEL_VERSION);
+            assert_eq!(mock.writes.load(Ordering::SeqCst), 0);
+        }
+
+        #[tokio::test]
+        async fn test_verify_only_outdated_store_performs_no_writes() {
+            let mock = MockOpenFga {
+                applied: HashMap::from([(
+                    "store".to_string(),
+                    BTreeMap::from([(*V4_0_MODEL_VERSION, "model-v4-0".to_string())]),
+                )]),
+                ..Default::default()
+            };
+
+            let report = verify_migration_with(&mock, "store".to_string())
+                .await
+                .unwrap();
+
+            assert!(report.store_exists);
+            assert_eq!(report.applied_model_id, None);
+            assert!(report.migration_required());
+            assert_eq!(mock.reads.load(Ordering::SeqCst), 2);
+            assert_eq!(mock.writes.load(Ordering::SeqCst), 0);
+        }
+
+        #[tokio::test]
+        async fn test_verify_only_current_store_requires_no_migration() {
+            let mock = MockOpenFga {
+                applied: HashMap::from([(
+                    "store".to_string(),
+                    BTreeMap::from([(*ACTIVE_MODEL_VERSION, "model-current".to_string())]),
+                )]),
+                ..Default::default()
+            };
+
+            let report = verify_migration_with(&mock, "store".to_string())
+                .await
+                .unwrap();
+
+            assert_eq!(report.applied_model_id.as_deref(), Some("model-current"));
+            assert!(!report.migration_required());
+            assert_eq!(mock.writes.load(Ordering::SeqCst), 0);
+        }
+    }
+
     mod openfga_integration_tests {
         use lakekeeper::tokio;
         use openfga_client::client::ReadAuthorizationModelsRequest;
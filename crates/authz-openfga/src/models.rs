@@ -19,6 +19,7 @@ impl OpenFgaType for FgaType {
                 FgaType::Table,
                 FgaType::View,
                 FgaType::GenericTable,
+                FgaType::Column,
             ],
             FgaType::Project => &[FgaType::Server, FgaType::Warehouse],
             FgaType::Warehouse => &[FgaType::Project, FgaType::Namespace],
@@ -29,7 +30,9 @@ impl OpenFgaType for FgaType {
                 FgaType::View,
                 FgaType::GenericTable,
             ],
-            FgaType::View | FgaType::Table | FgaType::GenericTable => &[FgaType::Namespace],
+            FgaType::Table => &[FgaType::Namespace, FgaType::Column],
+            FgaType::View | FgaType::GenericTable => &[FgaType::Namespace],
+            FgaType::Column => &[],
             FgaType::ModelVersion => &[],
             FgaType::AuthModelId => &[FgaType::ModelVersion],
         }
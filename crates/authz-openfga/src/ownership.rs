@@ -0,0 +1,599 @@
+//! Reassignment of object ownership.
+//!
+//! Ownership of an object is stored as `user -[ownership]-> lakekeeper_table:<id>`
+//! tuples. Reassigning replaces the owner tuple(s) with one for the new owner, so
+//! that objects of a principal who left the organization remain manageable.
+//!
+//! Besides single tables, all namespaces, tables and views owned by a principal
+//! within a project can be transferred at once. Objects of the project are
+//! discovered by following the structural `project#warehouse`,
+//! `warehouse#namespace` and `namespace#child` relations.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::Arc,
+};
+
+use http::StatusCode;
+use lakekeeper::{
+    ProjectId, WarehouseId,
+    api::{ApiContext, RequestMetadata, management::v1::check::UserOrRole},
+    axum::{
+        Extension, Json,
+        extract::{Path, State as AxumState},
+    },
+    service::{
+        CatalogStore, Result, SecretStore, State, TableId,
+        authz::ActionDescriptor,
+        events::{
+            APIEventContext,
+            context::{APIEventActions, authz_to_error_no_audit},
+        },
+    },
+};
+use openfga_client::client::{
+    ReadRequestTupleKey, TupleKey, TupleKeyWithoutCondition, WriteOptions,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    FgaType, MAX_TUPLES_PER_WRITE, OpenFGAAuthorizer, OpenFGABacked, OpenFGAResult,
+    entities::{OpenFgaEntity, ParseOpenFgaEntity},
+    relations::{
+        NamespaceRelation, ProjectRelation, ServerRelation, TableRelation, WarehouseRelation,
+    },
+};
+
+/// Minimal tuple storage operations needed to reassign ownership.
+pub(crate) trait OwnershipTupleStore {
+    /// Read all tuples matching `key`, following continuation tokens.
+    async fn read_tuples(&self, key: ReadRequestTupleKey) -> OpenFGAResult<Vec<TupleKey>>;
+
+    /// Apply `writes` and `deletes` in a single write request. Callers ensure that
+    /// both together do not exceed [`MAX_TUPLES_PER_WRITE`].
+    async fn write_tuples(
+        &self,
+        writes: Vec<TupleKey>,
+        deletes: Vec<TupleKeyWithoutCondition>,
+    ) -> OpenFGAResult<()>;
+}
+
+impl OwnershipTupleStore for OpenFGAAuthorizer {
+    async fn read_tuples(&self, key: ReadRequestTupleKey) -> OpenFGAResult<Vec<TupleKey>> {
+        Ok(self
+            .read_all(Some(key))
+            .await?
+            .into_iter()
+            .filter_map(|t| t.key)
+            .collect())
+    }
+
+    async fn write_tuples(
+        &self,
+        writes: Vec<TupleKey>,
+        deletes: Vec<TupleKeyWithoutCondition>,
+    ) -> OpenFGAResult<()> {
+        let writes = (!writes.is_empty()).then_some(writes);
+        let deletes = (!deletes.is_empty()).then_some(deletes);
+        self.client
+            .write_with_options(writes, deletes, WriteOptions::new_idempotent())
+            .await
+            .inspect_err(|e| {
+                tracing::error!("Failed to reassign ownership in OpenFGA: {e}");
+            })?;
+        Ok(())
+    }
+}
+
+/// Number of objects whose ownership was transferred, per object type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnershipTransferReport {
+    pub namespaces: u64,
+    pub tables: u64,
+    pub views: u64,
+    pub write_requests: u64,
+}
+
+impl OwnershipTransferReport {
+    fn record(&mut self, object_type: &FgaType) {
+        match object_type {
+            FgaType::Namespace => self.namespaces += 1,
+            FgaType::Table => self.tables += 1,
+            FgaType::View => self.views += 1,
+            _ => {}
+        }
+    }
+}
+
+impl OpenFGAAuthorizer {
+    /// Transfer ownership of all namespaces, tables and views in `project_id`
+    /// owned by `from` to `to`.
+    ///
+    /// Principals are given in `OpenFGA` notation, i.e. `user:<user-id>` or
+    /// `role:<role-id>#assignee`. Every write request swaps the ownership of a
+    /// batch of objects, so an interrupted run can simply be repeated: objects
+    /// already transferred are no longer owned by `from` and are skipped.
+    pub async fn transfer_project_ownership(
+        &self,
+        project_id: &ProjectId,
+        from: &str,
+        to: &str,
+    ) -> OpenFGAResult<OwnershipTransferReport> {
+        let from = UserOrRole::parse_from_openfga(from)?;
+        let to = UserOrRole::parse_from_openfga(to)?;
+        transfer_project_ownership(self, project_id, &from, &to).await
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub(super) struct ReassignTableOwnerRequest {
+    /// Principal that becomes the owner of the table.
+    new_owner: UserOrRole,
+    /// Owner whose ownership is revoked. If not specified, all current owners
+    /// of the table are replaced by `new-owner`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    previous_owner: Option<UserOrRole>,
+}
+
+impl APIEventActions for ReassignTableOwnerRequest {
+    fn event_actions(&self) -> Vec<ActionDescriptor> {
+        vec![
+            ActionDescriptor::builder()
+                .action_name("reassign_table_owner")
+                .build(),
+        ]
+    }
+}
+
+/// Reassign the owner of a table
+///
+/// Revokes the ownership of `previous-owner` (or of all current owners if not
+/// specified) and grants ownership to `new-owner`.
+/// Only server admins can reassign ownership.
+#[cfg_attr(feature = "open-api", utoipa::path(
+    post,
+    tag = "permissions-openfga",
+    path = "/management/v1/warehouse/{warehouse_id}/table/{table_id}/reassign-owner",
+    request_body = ReassignTableOwnerRequest,
+    params(
+        ("warehouse_id" = Uuid, Path, description = "Warehouse ID"),
+        ("table_id" = Uuid, Path, description = "Table ID"),
+    ),
+    responses(
+            (status = 204, description = "Owner reassigned successfully"),
+    )
+))]
+pub(super) async fn reassign_table_owner<A: OpenFGABacked, C: CatalogStore, S: SecretStore>(
+    Path((warehouse_id, table_id)): Path<(WarehouseId, TableId)>,
+    AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    Extension(metadata): Extension<RequestMetadata>,
+    Json(request): Json<ReassignTableOwnerRequest>,
+) -> Result<StatusCode> {
+    let authorizer = api_context.v1_state.authz.as_ref().clone();
+
+    let event_ctx = APIEventContext::for_table(
+        Arc::new(metadata),
+        api_context.v1_state.events,
+        warehouse_id,
+        table_id,
+        request.clone(),
+    );
+    let authz_result = authorizer
+        .require_action(
+            event_ctx.request_metadata(),
+            ServerRelation::Admin,
+            &authorizer.openfga_server(),
+        )
+        .await;
+    let _ = event_ctx.emit_authz(authz_result)?;
+
+    reassign_owner(
+        &authorizer,
+        &(warehouse_id, table_id).to_openfga(),
+        &request.new_owner,
+        request.previous_owner.as_ref(),
+    )
+    .await
+    .map_err(authz_to_error_no_audit)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Replace the ownership tuples of `object` with one for `new_owner`.
+///
+/// The new owner is written in the same request as the first deletions, so
+/// replacing a single owner is atomic. Further deletions are chunked to
+/// [`MAX_TUPLES_PER_WRITE`].
+pub(crate) async fn reassign_owner(
+    store: &impl OwnershipTupleStore,
+    object: &str,
+    new_owner: &UserOrRole,
+    previous_owner: Option<&UserOrRole>,
+) -> OpenFGAResult<()> {
+    let relation = TableRelation::Ownership.to_string();
+    let new_owner = new_owner.to_openfga();
+    let previous_owner = previous_owner.map(OpenFgaEntity::to_openfga);
+
+    let owners = store
+        .read_tuples(ReadRequestTupleKey {
+            user: previous_owner.clone().unwrap_or_default(),
+            relation: relation.clone(),
+            object: object.to_string(),
+        })
+        .await?;
+    let mut deletes = owners
+        .into_iter()
+        .filter(|t| t.user != new_owner)
+        .map(|t| TupleKeyWithoutCondition {
+            user: t.user,
+            relation: t.relation,
+            object: t.object,
+        })
+        .collect::<Vec<_>>();
+
+    let mut writes = vec![TupleKey {
+        user: new_owner,
+        relation,
+        object: object.to_string(),
+        condition: None,
+    }];
+    let chunk_size = usize::try_from(MAX_TUPLES_PER_WRITE).unwrap_or(1);
+    loop {
+        let remaining = deletes.split_off(deletes.len().min(chunk_size - writes.len()));
+        store
+            .write_tuples(std::mem::take(&mut writes), deletes)
+            .await?;
+        if remaining.is_empty() {
+            return Ok(());
+        }
+        deletes = remaining;
+    }
+}
+
+pub(crate) async fn transfer_project_ownership(
+    store: &impl OwnershipTupleStore,
+    project_id: &ProjectId,
+    from: &UserOrRole,
+    to: &UserOrRole,
+) -> OpenFGAResult<OwnershipTransferReport> {
+    let mut report = OwnershipTransferReport::default();
+    let from = from.to_openfga();
+    let to = to.to_openfga();
+    if from == to {
+        return Ok(report);
+    }
+
+    let project_objects = project_objects(store, project_id).await?;
+    let relation = TableRelation::Ownership.to_string();
+    let mut owned = Vec::new();
+    for object_type in [FgaType::Namespace, FgaType::Table, FgaType::View] {
+        let tuples = store
+            .read_tuples(ReadRequestTupleKey {
+                user: from.clone(),
+                relation: relation.clone(),
+                object: format!("{object_type}:"),
+            })
+            .await?;
+        owned.extend(
+            tuples
+                .into_iter()
+                .filter(|t| project_objects.contains(&t.object))
+                .map(|t| (object_type.clone(), t.object)),
+        );
+    }
+
+    // Each transferred object needs one write and one delete.
+    let objects_per_write = usize::try_from(MAX_TUPLES_PER_WRITE / 2).unwrap_or(1);
+    for chunk in owned.chunks(objects_per_write) {
+        let writes = chunk
+            .iter()
+            .map(|(_, object)| TupleKey {
+                user: to.clone(),
+                relation: relation.clone(),
+                object: object.clone(),
+                condition: None,
+            })
+            .collect();
+        let deletes = chunk
+            .iter()
+            .map(|(_, object)| TupleKeyWithoutCondition {
+                user: from.clone(),
+                relation: relation.clone(),
+                object: object.clone(),
+            })
+            .collect();
+        store.write_tuples(writes, deletes).await?;
+
+        report.write_requests += 1;
+        for (object_type, _) in chunk {
+            report.record(object_type);
+        }
+        tracing::debug!(
+            "Transferred ownership of {} object(s) in project {project_id} from {from} to {to}",
+            chunk.len()
+        );
+    }
+
+    tracing::info!(
+        "Transferred ownership in project {project_id} from {from} to {to}: {} namespace(s), {} table(s), {} view(s)",
+        report.namespaces,
+        report.tables,
+        report.views
+    );
+    Ok(report)
+}
+
+/// Collect all warehouses and their descendants belonging to `project_id`.
+async fn project_objects(
+    store: &impl OwnershipTupleStore,
+    project_id: &ProjectId,
+) -> OpenFGAResult<HashSet<String>> {
+    let project_prefix = format!("{}:", FgaType::Project);
+    let warehouse_prefix = format!("{}:", FgaType::Warehouse);
+    let namespace_prefix = format!("{}:", FgaType::Namespace);
+
+    let mut objects = HashSet::new();
+    let mut queue = VecDeque::from([project_id.to_openfga()]);
+    while let Some(object) = queue.pop_front() {
+        let child_relation = if object.starts_with(&project_prefix) {
+            ProjectRelation::Warehouse.to_string()
+        } else if object.starts_with(&warehouse_prefix) {
+            WarehouseRelation::Namespace.to_string()
+        } else if object.starts_with(&namespace_prefix) {
+            NamespaceRelation::Child.to_string()
+        } else {
+            // Tables and views have no structural children.
+            continue;
+        };
+        let children = store
+            .read_tuples(ReadRequestTupleKey {
+                user: String::new(),
+                relation: child_relation,
+                object,
+            })
+            .await?;
+        for child in children {
+            if objects.insert(child.user.clone()) {
+                queue.push_back(child.user);
+            }
+        }
+    }
+    Ok(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Mutex};
+
+    use lakekeeper::service::UserId;
+
+    use super::*;
+
+    /// In-memory tuple store recording the size of every write request.
+    #[derive(Debug, Default)]
+    struct MockTupleStore {
+        tuples: Mutex<Vec<TupleKey>>,
+        write_sizes: Mutex<Vec<usize>>,
+    }
+
+    impl MockTupleStore {
+        fn with_tuples(tuples: Vec<TupleKey>) -> Self {
+            Self {
+                tuples: Mutex::new(tuples),
+                write_sizes: Mutex::default(),
+            }
+        }
+
+        fn idents(&self) -> HashSet<(String, String, String)> {
+            self.tuples
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|t| (t.user.clone(), t.relation.clone(), t.object.clone()))
+                .collect()
+        }
+    }
+
+    impl OwnershipTupleStore for MockTupleStore {
+        async fn read_tuples(&self, key: ReadRequestTupleKey) -> OpenFGAResult<Vec<TupleKey>> {
+            Ok(self
+                .tuples
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|t| key.user.is_empty() || t.user == key.user)
+                .filter(|t| key.relation.is_empty() || t.relation == key.relation)
+                .filter(|t| {
+                    if key.object.ends_with(':') {
+                        t.object.starts_with(&key.object)
+                    } else {
+                        t.object == key.object
+                    }
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn write_tuples(
+            &self,
+            writes: Vec<TupleKey>,
+            deletes: Vec<TupleKeyWithoutCondition>,
+        ) -> OpenFGAResult<()> {
+            let size = writes.len() + deletes.len();
+            assert!(size <= usize::try_from(MAX_TUPLES_PER_WRITE).unwrap());
+            self.write_sizes.lock().unwrap().push(size);
+            let mut stored = self.tuples.lock().unwrap();
+            stored.retain(|t| {
+                !deletes
+                    .iter()
+                    .any(|d| d.user == t.user && d.relation == t.relation && d.object == t.object)
+            });
+            for tuple in writes {
+                if !stored.contains(&tuple) {
+                    stored.push(tuple);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn tuple(user: &str, relation: &str, object: &str) -> TupleKey {
+        TupleKey {
+            user: user.to_string(),
+            relation: relation.to_string(),
+            object: object.to_string(),
+            condition: None,
+        }
+    }
+
+    fn user(name: &str) -> UserOrRole {
+        UserOrRole::User(UserId::new_unchecked("oidc", name))
+    }
+
+    const TABLE: &str = "lakekeeper_table:wh/tab";
+
+    #[tokio::test]
+    async fn test_reassign_owner_swaps_ownership_tuple() {
+        let old_owner = user("old").to_openfga();
+        let other_owner = user("other").to_openfga();
+        let new_owner = user("new").to_openfga();
+        let store = MockTupleStore::with_tuples(vec![
+            tuple(&old_owner, "ownership", TABLE),
+            tuple(&other_owner, "ownership", TABLE),
+            tuple(&old_owner, "select", TABLE),
+            tuple(&old_owner, "ownership", "lakekeeper_table:wh/other"),
+        ]);
+
+        reassign_owner(&store, TABLE, &user("new"), Some(&user("old")))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.idents(),
+            HashSet::from([
+                (new_owner, "ownership".to_string(), TABLE.to_string()),
+                (other_owner, "ownership".to_string(), TABLE.to_string()),
+                (old_owner.clone(), "select".to_string(), TABLE.to_string()),
+                (
+                    old_owner,
+                    "ownership".to_string(),
+                    "lakekeeper_table:wh/other".to_string()
+                ),
+            ])
+        );
+        // The swap happens in a single write request.
+        assert_eq!(*store.write_sizes.lock().unwrap(), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_reassign_owner_replaces_all_owners_in_chunks() {
+        let owners = (0..250)
+            .map(|i| tuple(&user(&format!("u{i}")).to_openfga(), "ownership", TABLE))
+            .collect::<Vec<_>>();
+        let store = MockTupleStore::with_tuples(owners);
+
+        reassign_owner(&store, TABLE, &user("new"), None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.idents(),
+            HashSet::from([(
+                user("new").to_openfga(),
+                "ownership".to_string(),
+                TABLE.to_string()
+            )])
+        );
+        assert_eq!(*store.write_sizes.lock().unwrap(), vec![100, 100, 51]);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_project_ownership() {
+        let project_id = ProjectId::new(uuid::Uuid::now_v7());
+        let project = project_id.to_openfga();
+        let from = user("from").to_openfga();
+        let to = user("to").to_openfga();
+        let mut tuples = vec![
+            // Structure: project -> warehouse -> ns1 -> ns2, table and views
+            tuple("warehouse:wh", "warehouse", &project),
+            tuple("namespace:ns1", "namespace", "warehouse:wh"),
+            tuple("namespace:ns2", "child", "namespace:ns1"),
+            tuple("lakekeeper_table:wh/t1", "child", "namespace:ns1"),
+            tuple("lakekeeper_view:wh/v1", "child", "namespace:ns2"),
+            tuple("lakekeeper_view:wh/v2", "child", "namespace:ns2"),
+            // Owned by `from`
+            tuple(&from, "ownership", "namespace:ns1"),
+            tuple(&from, "ownership", "namespace:ns2"),
+            tuple(&from, "ownership", "lakekeeper_table:wh/t1"),
+            tuple(&from, "ownership", "lakekeeper_view:wh/v1"),
+            // Unrelated grant and an object with another owner
+            tuple(&from, "select", "lakekeeper_table:wh/t1"),
+            tuple(
+                &user("other").to_openfga(),
+                "ownership",
+                "lakekeeper_view:wh/v2",
+            ),
+            // Object in another project
+            tuple("warehouse:other-wh", "warehouse", "project:other"),
+            tuple("namespace:other-ns", "namespace", "warehouse:other-wh"),
+            tuple("lakekeeper_table:other-wh/t", "child", "namespace:other-ns"),
+            tuple(&from, "ownership", "lakekeeper_table:other-wh/t"),
+        ];
+        // Enough tables to require several write requests.
+        for i in 0..60 {
+            let table = format!("lakekeeper_table:wh/bulk-{i}");
+            tuples.push(tuple(&table, "child", "namespace:ns2"));
+            tuples.push(tuple(&from, "ownership", &table));
+        }
+        let store = MockTupleStore::with_tuples(tuples);
+
+        let report = transfer_project_ownership(&store, &project_id, &user("from"), &user("to"))
+            .await
+            .unwrap();
+        assert_eq!(
+            report,
+            OwnershipTransferReport {
+                namespaces: 2,
+                tables: 61,
+                views: 1,
+                write_requests: 2,
+            }
+        );
+
+        let idents = store.idents();
+        let owner_of = |object: &str| {
+            idents
+                .iter()
+                .filter(|(_, relation, o)| relation == "ownership" && o == object)
+                .map(|(user, _, _)| user.clone())
+                .collect::<Vec<_>>()
+        };
+        for object in [
+            "namespace:ns1",
+            "namespace:ns2",
+            "lakekeeper_table:wh/t1",
+            "lakekeeper_table:wh/bulk-59",
+            "lakekeeper_view:wh/v1",
+        ] {
+            assert_eq!(owner_of(object), vec![to.clone()], "{object}");
+        }
+        assert_eq!(
+            owner_of("lakekeeper_view:wh/v2"),
+            vec![user("other").to_openfga()]
+        );
+        assert_eq!(owner_of("lakekeeper_table:other-wh/t"), vec![from.clone()]);
+        assert!(idents.contains(&(
+            from,
+            "select".to_string(),
+            "lakekeeper_table:wh/t1".to_string()
+        )));
+
+        // Re-running is a no-op, which makes interrupted transfers resumable.
+        let report = transfer_project_ownership(&store, &project_id, &user("from"), &user("to"))
+            .await
+            .unwrap();
+        assert_eq!(report, OwnershipTransferReport::default());
+    }
+}
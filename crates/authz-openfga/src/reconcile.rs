@@ -504,7 +504,7 @@ impl CatalogIndex {
                     .map(GenericTableId::new)
                     .map(|g| self.generic_tables.contains_key(&g))
             }
-            FgaType::User | FgaType::ModelVersion | FgaType::AuthModelId => None,
+            FgaType::User | FgaType::Column | FgaType::ModelVersion | FgaType::AuthModelId => None,
         }
     }
 }
@@ -44,6 +44,11 @@ pub(super) trait ReducedRelation: Clone + Sized + Eq + PartialEq {
     fn to_openfga(&self) -> Self::OpenFgaRelation;
 }
 
+/// Relations that only read catalog data or metadata.
+pub(crate) trait ReadRelation {
+    fn is_read(&self) -> bool;
+}
+
 pub(super) trait GrantableRelation: ReducedRelation {
     fn grant_relation(&self) -> Self::OpenFgaRelation;
 }
@@ -476,6 +481,17 @@ impl CatalogAction for ProjectRelation {
 impl ProjectAction for ProjectRelation {}
 impl OpenFgaRelation for ProjectRelation {}
 
+impl ReadRelation for ProjectRelation {
+    fn is_read(&self) -> bool {
+        matches!(
+            self,
+            ProjectRelation::CanGetMetadata
+                | ProjectRelation::CanListWarehouses
+                | ProjectRelation::CanIncludeInList
+        )
+    }
+}
+
 impl From<CatalogProjectAction> for ProjectRelation {
     fn from(action: CatalogProjectAction) -> Self {
         action.to_openfga()
@@ -701,6 +717,12 @@ impl ReducedRelation for CatalogProjectAction {
             CatalogProjectAction::GetTaskQueueConfig => ProjectRelation::CanGetTaskQueueConfig,
             CatalogProjectAction::GetProjectTasks => ProjectRelation::CanGetProjectTasks,
             CatalogProjectAction::ControlProjectTasks => ProjectRelation::CanControlProjectTasks,
+            // The OpenFGA model has no dedicated relation for the audit log or for
+            // wiping statistics. Granting project admin is only possible for project
+            // and server admins, which is exactly who may perform these actions.
+            CatalogProjectAction::ReadAuditLog | CatalogProjectAction::DeleteEndpointStatistics => {
+                ProjectRelation::CanGrantProjectAdmin
+            }
         }
     }
 }
@@ -770,6 +792,7 @@ pub enum WarehouseRelation {
     CanControlAllTasks,
     CanSetProtection,
     CanSetFormatVersionPolicy,
+    CanModifySettings,
     CanGetEndpointStatistics,
 }
 impl WarehouseAction for WarehouseRelation {}
@@ -781,6 +804,21 @@ impl CatalogAction for WarehouseRelation {
 
 impl OpenFgaRelation for WarehouseRelation {}
 
+impl ReadRelation for WarehouseRelation {
+    fn is_read(&self) -> bool {
+        matches!(
+            self,
+            WarehouseRelation::CanGetMetadata
+                | WarehouseRelation::CanGetConfig
+                | WarehouseRelation::CanListNamespaces
+                | WarehouseRelation::CanListEverything
+                | WarehouseRelation::CanUse
+                | WarehouseRelation::CanIncludeInList
+                | WarehouseRelation::CanListDeletedTabulars
+        )
+    }
+}
+
 impl From<CatalogWarehouseAction> for WarehouseRelation {
     fn from(action: CatalogWarehouseAction) -> Self {
         action.to_openfga()
@@ -924,6 +962,7 @@ pub(super) enum APIWarehouseAction {
     ControlAllTasks,
     SetProtection,
     SetFormatVersionPolicy,
+    ModifySettings,
     GetEndpointStatistics,
 }
 
@@ -990,6 +1029,7 @@ impl ReducedRelation for APIWarehouseAction {
             APIWarehouseAction::SetFormatVersionPolicy => {
                 WarehouseRelation::CanSetFormatVersionPolicy
             }
+            APIWarehouseAction::ModifySettings => WarehouseRelation::CanModifySettings,
             APIWarehouseAction::GetEndpointStatistics => {
                 WarehouseRelation::CanGetEndpointStatistics
             }
@@ -1031,6 +1071,7 @@ impl ReducedRelation for CatalogWarehouseAction {
             CatalogWarehouseAction::SetFormatVersionPolicy => {
                 WarehouseRelation::CanSetFormatVersionPolicy
             }
+            CatalogWarehouseAction::ModifySettings => WarehouseRelation::CanModifySettings,
             CatalogWarehouseAction::GetEndpointStatistics => {
                 WarehouseRelation::CanGetEndpointStatistics
             }
@@ -1099,6 +1140,21 @@ pub enum NamespaceRelation {
 }
 
 impl OpenFgaRelation for NamespaceRelation {}
+
+impl ReadRelation for NamespaceRelation {
+    fn is_read(&self) -> bool {
+        matches!(
+            self,
+            NamespaceRelation::CanGetMetadata
+                | NamespaceRelation::CanListTables
+                | NamespaceRelation::CanListViews
+                | NamespaceRelation::CanListNamespaces
+                | NamespaceRelation::CanListGenericTables
+                | NamespaceRelation::CanListEverything
+                | NamespaceRelation::CanIncludeInList
+        )
+    }
+}
 impl CatalogAction for NamespaceRelation {
     fn action_descriptor(&self) -> ActionDescriptor {
         ActionDescriptor::builder().action_name(self.into()).build()
@@ -1389,6 +1445,17 @@ impl CatalogAction for TableRelation {
 }
 impl OpenFgaRelation for TableRelation {}
 
+impl ReadRelation for TableRelation {
+    fn is_read(&self) -> bool {
+        matches!(
+            self,
+            TableRelation::CanReadData
+                | TableRelation::CanGetMetadata
+                | TableRelation::CanIncludeInList
+        )
+    }
+}
+
 impl From<CatalogTableAction> for TableRelation {
     fn from(action: CatalogTableAction) -> Self {
         action.to_openfga()
@@ -1653,6 +1720,15 @@ impl CatalogAction for ViewRelation {
 }
 impl OpenFgaRelation for ViewRelation {}
 
+impl ReadRelation for ViewRelation {
+    fn is_read(&self) -> bool {
+        matches!(
+            self,
+            ViewRelation::CanGetMetadata | ViewRelation::CanSelect | ViewRelation::CanIncludeInList
+        )
+    }
+}
+
 impl From<CatalogViewAction> for ViewRelation {
     fn from(action: CatalogViewAction) -> Self {
         action.to_openfga()
@@ -1919,6 +1995,17 @@ impl CatalogAction for GenericTableRelation {
 }
 impl OpenFgaRelation for GenericTableRelation {}
 
+impl ReadRelation for GenericTableRelation {
+    fn is_read(&self) -> bool {
+        matches!(
+            self,
+            GenericTableRelation::CanReadData
+                | GenericTableRelation::CanGetMetadata
+                | GenericTableRelation::CanIncludeInList
+        )
+    }
+}
+
 impl From<CatalogGenericTableAction> for GenericTableRelation {
     fn from(action: CatalogGenericTableAction) -> Self {
         action.to_openfga()
@@ -2153,6 +2240,27 @@ impl ReducedRelation for APIGenericTableAction {
     }
 }
 
+// =================== Column Relations ===================
+
+/// Relations of the `lakekeeper_column` type.
+///
+/// Columns are not (yet) exposed through the catalog's authorization actions or the
+/// permissions API; they are checked directly via
+/// [`OpenFGAAuthorizer::check_column_access`](crate::OpenFGAAuthorizer::check_column_access).
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, strum_macros::Display, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub(crate) enum ColumnRelation {
+    // -- Hierarchical relations --
+    Parent,
+    // -- Direct relations --
+    Select,
+    // -- Actions --
+    CanReadData,
+    CanGrantSelect,
+}
+
+impl OpenFgaRelation for ColumnRelation {}
+
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
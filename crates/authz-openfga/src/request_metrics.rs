@@ -0,0 +1,60 @@
+//! Prometheus metrics for requests to the OpenFGA server.
+//!
+//! Every check, batch check and write round-trip is timed, labelled by
+//! `operation`. Failed requests additionally increment the error counter.
+//! The metrics go to the global recorder, so they are served on the
+//! `/metrics` endpoint together with the HTTP metrics.
+
+use std::{future::Future, sync::LazyLock, time::Instant};
+
+use metrics::{counter, describe_counter, describe_histogram, histogram};
+
+pub(crate) const METRIC_OPENFGA_REQUEST_DURATION_SECONDS: &str =
+    "lakekeeper_openfga_request_duration_seconds";
+pub(crate) const METRIC_OPENFGA_ERRORS_TOTAL: &str = "lakekeeper_openfga_errors_total";
+pub(crate) const METRIC_OPERATION_LABEL: &str = "operation";
+
+static METRICS_INITIALIZED: LazyLock<()> = LazyLock::new(|| {
+    describe_histogram!(
+        METRIC_OPENFGA_REQUEST_DURATION_SECONDS,
+        "Round-trip duration of OpenFGA requests in seconds, by operation (check/batch_check/write)"
+    );
+    describe_counter!(
+        METRIC_OPENFGA_ERRORS_TOTAL,
+        "Total OpenFGA requests that failed, by operation (check/batch_check/write)"
+    );
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OpenFgaOperation {
+    Check,
+    BatchCheck,
+    Write,
+}
+
+impl OpenFgaOperation {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Check => "check",
+            Self::BatchCheck => "batch_check",
+            Self::Write => "write",
+        }
+    }
+}
+
+/// Await `request` and record its duration and, if it fails, an error for `operation`.
+pub(crate) async fn observe<T, E>(
+    operation: OpenFgaOperation,
+    request: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+    let () = *METRICS_INITIALIZED;
+    let start = Instant::now();
+    let result = request.await;
+    histogram!(METRIC_OPENFGA_REQUEST_DURATION_SECONDS, METRIC_OPERATION_LABEL => operation.as_str())
+        .record(start.elapsed().as_secs_f64());
+    if result.is_err() {
+        counter!(METRIC_OPENFGA_ERRORS_TOTAL, METRIC_OPERATION_LABEL => operation.as_str())
+            .increment(1);
+    }
+    result
+}
@@ -31,6 +31,12 @@ pub mod rest {
         RenameTableRequest, StorageCredential, TableETag, create_etag,
     };
 
+    mod scan_planning;
+    pub use scan_planning::{
+        CompletedPlanningResult, ContentFile, ContentFileType, FetchScanTasksRequest,
+        FetchScanTasksResult, FileScanTask, PlanTableScanRequest, PlanTableScanResult, ScanTasks,
+    };
+
     mod view;
     pub use view::{CommitViewRequest, CreateViewRequest, LoadViewResult};
 
@@ -0,0 +1,177 @@
+use super::StorageCredential;
+#[cfg(feature = "axum")]
+use super::impl_into_response;
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PlanTableScanRequest {
+    /// Identifier for the snapshot to scan in a point-in-time scan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<i64>,
+    /// List of selected schema fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub select: Option<Vec<String>>,
+    /// Expression used to filter the table data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+    /// Hint for the minimum number of rows requested for the scan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_rows_requested: Option<i64>,
+    /// Enables case sensitive field matching for filter and select
+    #[serde(default = "default_true")]
+    pub case_sensitive: bool,
+    /// Whether to use the schema at the time the snapshot was written
+    #[serde(default)]
+    pub use_snapshot_schema: bool,
+    /// Starting snapshot ID for an incremental scan (exclusive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_snapshot_id: Option<i64>,
+    /// Ending snapshot ID for an incremental scan (inclusive)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_snapshot_id: Option<i64>,
+    /// List of fields for which the service should send column stats
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats_fields: Option<Vec<String>>,
+}
+
+impl Default for PlanTableScanRequest {
+    fn default() -> Self {
+        Self {
+            snapshot_id: None,
+            select: None,
+            filter: None,
+            min_rows_requested: None,
+            case_sensitive: true,
+            use_snapshot_schema: false,
+            start_snapshot_id: None,
+            end_snapshot_id: None,
+            stats_fields: None,
+        }
+    }
+}
+
+/// Result of server-side scan planning. Only synchronous planning is
+/// supported, so the result is always `completed`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
+pub enum PlanTableScanResult {
+    Completed(CompletedPlanningResult),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CompletedPlanningResult {
+    /// ID used to track a planning request
+    pub plan_id: String,
+    #[serde(flatten)]
+    pub scan_tasks: ScanTasks,
+    /// Storage credentials for accessing the files returned in the scan result
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_credentials: Option<Vec<StorageCredential>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ScanTasks {
+    /// Delete files referenced by file scan tasks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delete_files: Vec<ContentFile>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_scan_tasks: Vec<FileScanTask>,
+    /// Opaque units of planning work, to be passed to `fetchScanTasks`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plan_tasks: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FileScanTask {
+    pub data_file: ContentFile,
+    /// A list of indices in the delete files array (0-based)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_file_references: Option<Vec<u32>>,
+    /// Filter to be applied to rows in this file scan task. If absent, the
+    /// client must apply the original filter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub residual_filter: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ContentFileType {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+/// A data or delete file as returned by scan planning. Column statistics are
+/// not included.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ContentFile {
+    pub content: ContentFileType,
+    pub file_path: String,
+    /// File format in lowercase, e.g. `parquet`
+    pub file_format: String,
+    pub spec_id: i32,
+    /// Partition values ordered by the fields of the partition spec `spec-id`,
+    /// in the Iceberg single-value JSON serialization
+    pub partition: Vec<serde_json::Value>,
+    pub file_size_in_bytes: u64,
+    pub record_count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FetchScanTasksRequest {
+    pub plan_task: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FetchScanTasksResult {
+    #[serde(flatten)]
+    pub scan_tasks: ScanTasks,
+}
+
+#[cfg(feature = "axum")]
+impl_into_response!(PlanTableScanResult);
+#[cfg(feature = "axum")]
+impl_into_response!(FetchScanTasksResult);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_table_scan_request_defaults() {
+        let request: PlanTableScanRequest = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(request, PlanTableScanRequest::default());
+        assert!(request.case_sensitive);
+    }
+
+    #[test]
+    fn test_completed_planning_result_serialization() {
+        let result = PlanTableScanResult::Completed(CompletedPlanningResult {
+            plan_id: "plan".to_string(),
+            scan_tasks: ScanTasks {
+                plan_tasks: vec!["task-1".to_string()],
+                ..Default::default()
+            },
+            storage_credentials: None,
+        });
+
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!({
+                "status": "completed",
+                "plan-id": "plan",
+                "plan-tasks": ["task-1"]
+            })
+        );
+    }
+}
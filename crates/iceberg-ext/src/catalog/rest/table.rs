@@ -70,6 +70,10 @@ pub struct CreateTableRequest {
     pub write_order: Option<SortOrder>,
     pub stage_create: Option<bool>,
     pub properties: Option<HashMap<String, String>>,
+    /// Id to assign to the new table instead of generating one.
+    /// Must be a version 4 or version 7 UUID that is not yet used in the warehouse.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_uuid: Option<uuid::Uuid>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TypedBuilder)]
@@ -28,7 +28,7 @@ pub use adls_location::{
 };
 pub use adls_storage::AdlsStorage;
 
-use crate::InitializeClientError;
+use crate::{InitializeClientError, StorageRetryPolicy};
 
 /// Wraps a [`TokenCredential`] to retry transient failures when acquiring a
 /// bearer token. The Azure storage data-plane retry policy ([`RetryOptions`])
@@ -83,7 +83,7 @@ const RETRY_MAX_TOTAL_ELAPSED: Duration = Duration::from_secs(30);
 static DEFAULT_CLIENT_OPTIONS: LazyLock<azure_core::ClientOptions> = LazyLock::new(|| {
     azure_core::ClientOptions::default().retry(RetryOptions::fixed(
         FixedRetryOptions::default()
-            .max_retries(3u32)
+            .max_retries(StorageRetryPolicy::current().retries())
             .max_total_elapsed(RETRY_MAX_TOTAL_ELAPSED),
     ))
 });
@@ -17,7 +17,7 @@ use reqwest_middleware::ClientBuilder;
 use reqwest_retry::{Jitter, RetryTransientMiddleware, policies::ExponentialBackoff};
 use veil::Redact;
 
-use crate::InitializeClientError;
+use crate::{InitializeClientError, StorageRetryPolicy};
 
 static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
 
@@ -120,10 +120,19 @@ impl GCSSettings {
         &self,
         auth: &GcsAuth,
     ) -> Result<Client, InitializeClientError> {
+        let storage_retry_policy = StorageRetryPolicy::current();
         let retry_policy = ExponentialBackoff::builder()
+            .retry_bounds(
+                storage_retry_policy.initial_backoff(),
+                storage_retry_policy.max_backoff(),
+            )
             .base(2)
-            .jitter(Jitter::Full)
-            .build_with_max_retries(3);
+            .jitter(if storage_retry_policy.jitter() {
+                Jitter::Full
+            } else {
+                Jitter::None
+            })
+            .build_with_max_retries(storage_retry_policy.retries());
         let mid_client = ClientBuilder::new(HTTP_CLIENT.clone())
             .with(RetryTransientMiddleware::new_with_policy(retry_policy))
             .build();
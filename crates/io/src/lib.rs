@@ -8,8 +8,9 @@
 #![forbid(unsafe_code)]
 
 mod error;
+mod retry;
 
-use std::{future::Future, sync::Arc, time::Duration};
+use std::{future::Future, sync::Arc};
 
 use bytes::Bytes;
 use chrono::{DateTime, Utc};
@@ -19,9 +20,9 @@ pub use error::{
 };
 use futures::{TryStreamExt as _, stream::BoxStream};
 pub use location::{Location, LocationParseError};
+pub use retry::StorageRetryPolicy;
 pub use tokio;
 pub use tryhard;
-use tryhard::{RetryPolicy, backoff_strategies::BackoffStrategy};
 
 #[cfg(feature = "storage-adls")]
 pub mod adls;
@@ -162,53 +163,6 @@ pub enum StorageBackend {
     Gcs(crate::gcs::GcsStorage),
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct RetryConfig<B, E>
-where
-    for<'a> B: BackoffStrategy<'a, E>,
-    for<'a> <B as BackoffStrategy<'a, E>>::Output: Into<RetryPolicy>,
-    B: Send,
-{
-    retries: u32,
-    backoff_strategy: B,
-    max_delay: Option<Duration>,
-    phantom: std::marker::PhantomData<E>,
-}
-
-impl<B, E> RetryConfig<B, E>
-where
-    for<'a> B: BackoffStrategy<'a, E>,
-    for<'a> <B as BackoffStrategy<'a, E>>::Output: Into<RetryPolicy>,
-    B: Send + Clone,
-{
-    pub fn new(retries: u32, backoff_strategy: B) -> Self {
-        Self {
-            retries,
-            backoff_strategy,
-            max_delay: None,
-            phantom: std::marker::PhantomData,
-        }
-    }
-
-    #[must_use]
-    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
-        self.max_delay = Some(max_delay);
-        self
-    }
-
-    pub fn retries(&self) -> u32 {
-        self.retries
-    }
-
-    pub fn backoff_strategy(&self) -> B {
-        self.backoff_strategy.clone()
-    }
-
-    pub fn max_delay(&self) -> Option<Duration> {
-        self.max_delay
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct FileInfo {
     last_modified: Option<DateTime<Utc>>,
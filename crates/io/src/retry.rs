@@ -0,0 +1,91 @@
+use std::{sync::OnceLock, time::Duration};
+
+static STORAGE_RETRY_POLICY: OnceLock<StorageRetryPolicy> = OnceLock::new();
+
+/// Retry policy for requests to an object store.
+///
+/// The policy configures the retries of the storage clients themselves, so that
+/// requests are not retried by several layers. Requests failing with a transient
+/// error are retried with exponential backoff by the S3 and GCS clients. ADLS
+/// clients retry with a fixed delay and only use the number of retries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageRetryPolicy {
+    retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl Default for StorageRetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(100), Duration::from_secs(10))
+    }
+}
+
+impl StorageRetryPolicy {
+    /// Create a new policy with jitter enabled.
+    ///
+    /// The backoff starts at `initial_backoff` and doubles with every retry
+    /// up to `max_backoff`.
+    #[must_use]
+    pub fn new(retries: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            retries,
+            initial_backoff,
+            max_backoff,
+            jitter: true,
+        }
+    }
+
+    /// Policy that never retries.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Enable or disable jitter. With jitter, each backoff is drawn randomly
+    /// so that concurrent requests do not retry in lockstep.
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Use this policy for all storage clients created afterwards.
+    ///
+    /// Must be called before the first storage client is created, as the S3 and
+    /// ADLS clients share their retry configuration.
+    ///
+    /// # Errors
+    /// Returns the policy if a policy was installed before.
+    pub fn install(self) -> Result<(), Self> {
+        STORAGE_RETRY_POLICY.set(self)
+    }
+
+    /// The installed policy, or the default policy if none was installed.
+    #[must_use]
+    pub fn current() -> Self {
+        STORAGE_RETRY_POLICY.get().copied().unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    #[must_use]
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Upper bound of the backoff, never below the initial backoff.
+    #[must_use]
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff.max(self.initial_backoff)
+    }
+
+    #[must_use]
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+}
@@ -27,6 +27,8 @@ use aws_smithy_runtime_api::{
 use aws_smithy_types::{base64, config_bag::ConfigBag};
 use veil::Redact;
 
+use crate::StorageRetryPolicy;
+
 mod s3_error;
 mod s3_location;
 mod s3_storage;
@@ -63,7 +65,8 @@ static SMITHY_HTTP_CLIENT: LazyLock<SharedHttpClient> = LazyLock::new(|| {
         .build_https()
 });
 
-static RETRY_CONFIG: LazyLock<RetryConfig> = LazyLock::new(RetryConfig::adaptive);
+static RETRY_CONFIG: LazyLock<RetryConfig> =
+    LazyLock::new(|| retry_config(&StorageRetryPolicy::current()));
 static TIMEOUT_CONFIG: LazyLock<TimeoutConfig> = LazyLock::new(|| TimeoutConfig::builder().build());
 static TIME_SOURCE: LazyLock<SharedTimeSource> = LazyLock::new(SharedTimeSource::default);
 static TOKIO_SLEEP: LazyLock<Arc<dyn sleep::AsyncSleep>> =
@@ -71,6 +74,17 @@ static TOKIO_SLEEP: LazyLock<Arc<dyn sleep::AsyncSleep>> =
 static SLEEP_IMPL: LazyLock<SharedAsyncSleep> =
     LazyLock::new(|| SharedAsyncSleep::from(TOKIO_SLEEP.clone()));
 
+fn retry_config(policy: &StorageRetryPolicy) -> RetryConfig {
+    if policy.retries() == 0 {
+        return RetryConfig::disabled();
+    }
+    RetryConfig::adaptive()
+        .with_max_attempts(policy.retries().saturating_add(1))
+        .with_initial_backoff(policy.initial_backoff())
+        .with_max_backoff(policy.max_backoff())
+        .with_use_static_exponential_base(!policy.jitter())
+}
+
 const S3_CUSTOM_SCHEMES: [&str; 2] = ["s3a", "s3n"];
 
 /// Shared identity cache for system identity (IMDS/ECS task role).
@@ -381,3 +395,23 @@ pub fn validate_region(region: &str) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_config_from_policy() {
+        let policy = StorageRetryPolicy::new(5, Duration::from_millis(50), Duration::from_secs(2));
+        let config = retry_config(&policy);
+        assert_eq!(config.max_attempts(), 6);
+        assert_eq!(config.initial_backoff(), Duration::from_millis(50));
+        assert_eq!(config.max_backoff(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_retry_config_disabled_policy() {
+        let config = retry_config(&StorageRetryPolicy::disabled());
+        assert_eq!(config.max_attempts(), 1);
+    }
+}
@@ -1,20 +1,32 @@
 use lakekeeper::service::{ServerId, authz::AllowAllAuthorizer};
 use lakekeeper_authz_openfga::{
-    CONFIG as OPENFGA_CONFIG, OpenFGAAuthorizer, migrate as openfga_migrate,
+    AllowAllReadsAuthorizer, CONFIG as OPENFGA_CONFIG, MigrationVerification, OpenFGAAuthorizer,
+    migrate as openfga_migrate, verify_migration as openfga_verify_migration,
 };
 
 #[derive(Debug)]
 pub(crate) enum AuthorizerEnum {
     AllowAll(AllowAllAuthorizer),
     OpenFGA(Box<OpenFGAAuthorizer>),
+    OpenFGAAllowAllReads(Box<AllowAllReadsAuthorizer>),
 }
 
 impl AuthorizerEnum {
     pub(crate) async fn init_from_env(server_id: ServerId) -> anyhow::Result<Self> {
         if OPENFGA_CONFIG.is_openfga_enabled() {
-            Ok(AuthorizerEnum::OpenFGA(Box::new(
-                lakekeeper_authz_openfga::new_authorizer_from_default_config(server_id).await?,
-            )))
+            let authorizer =
+                lakekeeper_authz_openfga::new_authorizer_from_default_config(server_id).await?;
+            if OPENFGA_CONFIG
+                .openfga
+                .as_ref()
+                .is_some_and(|config| config.allow_all_reads)
+            {
+                Ok(AuthorizerEnum::OpenFGAAllowAllReads(Box::new(
+                    AllowAllReadsAuthorizer::new(authorizer),
+                )))
+            } else {
+                Ok(AuthorizerEnum::OpenFGA(Box::new(authorizer)))
+            }
         } else {
             Ok(AuthorizerEnum::AllowAll(AllowAllAuthorizer { server_id }))
         }
@@ -30,3 +42,10 @@ pub(crate) async fn migrate(server_id: ServerId) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+pub(crate) async fn verify_migration() -> anyhow::Result<MigrationVerification> {
+    let client = lakekeeper_authz_openfga::new_client_from_default_config().await?;
+    // Passing None here will use the store name from the config
+    let store_name_override = None;
+    Ok(openfga_verify_migration(&client, store_name_override).await?)
+}
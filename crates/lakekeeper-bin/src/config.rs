@@ -10,6 +10,27 @@ pub(crate) struct DynAppConfig {
     /// We do not recommend enabling this in production, especially if
     /// multiple instances of Lakekeeper are running.
     pub(crate) debug: DebugConfig,
+    /// Export of traces to an OpenTelemetry collector. Only effective if
+    /// Lakekeeper is built with the `otlp` feature.
+    pub(crate) otlp: OtlpConfig,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub(crate) struct OtlpConfig {
+    /// Export spans via OTLP. The collector is configured with the standard
+    /// `OTEL_EXPORTER_OTLP_*` environment variables.
+    pub(crate) enabled: bool,
+    /// Value of the `service.name` resource attribute of exported spans.
+    pub(crate) service_name: String,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            service_name: "lakekeeper".to_string(),
+        }
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize, Debug, Default)]
@@ -22,7 +43,7 @@ pub(crate) struct DebugConfig {
     pub(crate) extended_logs: bool,
 }
 
-fn get_config() -> DynAppConfig {
+pub(crate) fn get_config() -> DynAppConfig {
     let defaults = figment::providers::Serialized::defaults(DynAppConfig::default());
 
     #[cfg(not(test))]
@@ -87,4 +108,23 @@ mod tests {
             Ok(())
         });
     }
+
+    #[test]
+    fn test_otlp_env_vars() {
+        figment::Jail::expect_with(|_jail| {
+            let config = get_config();
+            assert!(!config.otlp.enabled);
+            assert_eq!(config.otlp.service_name, "lakekeeper");
+            Ok(())
+        });
+
+        figment::Jail::expect_with(|jail| {
+            jail.set_env("LAKEKEEPER_TEST__OTLP__ENABLED", "true");
+            jail.set_env("LAKEKEEPER_TEST__OTLP__SERVICE_NAME", "catalog-eu");
+            let config = get_config();
+            assert!(config.otlp.enabled);
+            assert_eq!(config.otlp.service_name, "catalog-eu");
+            Ok(())
+        });
+    }
 }
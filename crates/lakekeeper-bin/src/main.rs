@@ -16,16 +16,23 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 use clap::{Parser, Subcommand, ValueEnum};
 use lakekeeper::{CONFIG, tokio, tracing};
 use lakekeeper_storage_postgres::{CatalogState, PostgresBackend};
-use tracing_subscriber::{EnvFilter, filter::LevelFilter};
+#[cfg(not(feature = "otlp"))]
+use tracing_subscriber::util::SubscriberInitExt as _;
+use tracing_subscriber::{EnvFilter, filter::LevelFilter, layer::SubscriberExt as _};
 
 mod authorizer;
 mod config;
 mod events;
 mod healthcheck;
+#[cfg(feature = "otlp")]
+mod otlp;
+mod print_config;
 mod secrets;
 mod serve;
 #[cfg(feature = "ui")]
 mod ui;
+#[cfg(feature = "open-api")]
+mod validate_openapi;
 mod wait_for_db;
 
 pub(crate) use config::CONFIG_BIN;
@@ -106,12 +113,23 @@ enum Commands {
     },
     /// Print the version of the server
     Version {},
+    /// Print the effective configuration as JSON, with secrets redacted.
+    ///
+    /// Shows the value and source (`default` or `env`) of every field after
+    /// all environment variables have been applied.
+    PrintConfig {},
     #[cfg(feature = "open-api")]
     /// Get the `OpenAPI` specification of the Management API as yaml
     ManagementOpenapi {},
     #[cfg(feature = "open-api")]
     /// Get the `OpenAPI` specification of the Generic Table API as yaml
     GenericTableOpenapi {},
+    #[cfg(feature = "open-api")]
+    /// Check that the `OpenAPI` specifications match the routes of the server.
+    ///
+    /// Fails if a registered route is not documented or a documented operation
+    /// is not routed. Does not require a database.
+    ValidateOpenapi {},
     /// OpenFGA authorizer maintenance operations.
     Openfga {
         #[command(subcommand)]
@@ -139,6 +157,15 @@ enum Commands {
 
 #[derive(Subcommand)]
 enum OpenfgaCommands {
+    /// Migrate the OpenFGA authorization model to the version embedded in this binary.
+    Migrate {
+        #[clap(
+            long,
+            default_value_t = false,
+            help = "Validate the embedded model and report whether a migration would occur, without writing to OpenFGA."
+        )]
+        verify_only: bool,
+    },
     /// Reconcile structural OpenFGA hierarchy tuples against the Postgres
     /// catalog. Catalog is the source of truth: missing edges are added,
     /// and (in `add-and-delete-drift` mode) drift is removed.
@@ -160,6 +187,22 @@ enum OpenfgaCommands {
         )]
         dry_run: bool,
     },
+    /// Transfer ownership of all namespaces, tables and views in a project
+    /// from one principal to another.
+    ///
+    /// Safe to re-run: if a run is interrupted, running it again transfers
+    /// the remaining objects.
+    TransferOwnership {
+        #[clap(long, help = "Project whose objects are transferred.")]
+        project_id: String,
+        #[clap(
+            long,
+            help = "Current owner in OpenFGA notation, e.g. `user:oidc~<id>` or `role:<role-id>#assignee`."
+        )]
+        from: String,
+        #[clap(long, help = "New owner in OpenFGA notation.")]
+        to: String,
+    },
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -183,19 +226,25 @@ impl From<ReconcileModeArg> for lakekeeper_authz_openfga::ReconcileMode {
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
-    tracing_subscriber::fmt()
-        .json()
-        .flatten_event(true)
-        .with_current_span(false)
-        .with_span_list(true)
-        .with_file(CONFIG_BIN.debug.extended_logs)
-        .with_line_number(CONFIG_BIN.debug.extended_logs)
-        .with_env_filter(
+    let subscriber = tracing_subscriber::registry()
+        .with(
             EnvFilter::builder()
                 .with_default_directive(LevelFilter::INFO.into())
                 .from_env_lossy(),
         )
-        .init();
+        .with(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .flatten_event(true)
+                .with_current_span(false)
+                .with_span_list(true)
+                .with_file(CONFIG_BIN.debug.extended_logs)
+                .with_line_number(CONFIG_BIN.debug.extended_logs),
+        );
+    #[cfg(feature = "otlp")]
+    let _otlp_guard = otlp::init(subscriber)?;
+    #[cfg(not(feature = "otlp"))]
+    subscriber.init();
 
     match cli.command {
         Some(Commands::WaitForDB {
@@ -228,11 +277,26 @@ async fn main() -> anyhow::Result<()> {
         Some(Commands::Version {}) => {
             println!("{}", env!("CARGO_PKG_VERSION"));
         }
+        Some(Commands::PrintConfig {}) => {
+            print_config::print_config()?;
+        }
         Some(Commands::Openfga { command }) => match command {
+            OpenfgaCommands::Migrate { verify_only } => {
+                print_info();
+                openfga_migrate(verify_only).await?;
+            }
             OpenfgaCommands::Reconcile { mode, dry_run } => {
                 print_info();
                 openfga_reconcile(mode.into(), dry_run).await?;
             }
+            OpenfgaCommands::TransferOwnership {
+                project_id,
+                from,
+                to,
+            } => {
+                print_info();
+                openfga_transfer_ownership(&project_id, &from, &to).await?;
+            }
         },
         Some(Commands::ReopenBootstrap { yes }) => {
             print_info();
@@ -266,6 +330,10 @@ async fn main() -> anyhow::Result<()> {
             let doc = lakekeeper::api::data::v1::generic_tables::api_doc();
             println!("{}", doc.to_yaml()?);
         }
+        #[cfg(feature = "open-api")]
+        Some(Commands::ValidateOpenapi {}) => {
+            validate_openapi::validate_openapi().await?;
+        }
         None => {
             if CONFIG_BIN.debug.auto_serve {
                 print_info();
@@ -319,6 +387,65 @@ async fn reopen_bootstrap(yes: bool) -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn openfga_migrate(verify_only: bool) -> anyhow::Result<()> {
+    if !lakekeeper_authz_openfga::CONFIG.is_openfga_enabled() {
+        anyhow::bail!(
+            "openfga migrate requires LAKEKEEPER__AUTHZ_BACKEND=openfga; current backend is {:?}",
+            CONFIG.authz_backend
+        );
+    }
+
+    if !verify_only {
+        let read_pool = lakekeeper_storage_postgres::get_reader_pool(
+            lakekeeper_storage_postgres::config::CONFIG.to_pool_opts(),
+        )
+        .await?;
+        let catalog_state = CatalogState::from_pools(read_pool.clone(), read_pool);
+        let server_id =
+            <PostgresBackend as lakekeeper::service::CatalogStore>::get_server_info(catalog_state)
+                .await?
+                .server_id();
+        tracing::info!("Migrating authorizer...");
+        authorizer::migrate(server_id).await?;
+        tracing::info!("Authorizer migration complete.");
+        return Ok(());
+    }
+
+    let report = authorizer::verify_migration().await?;
+    println!();
+    println!("OpenFGA migration verification (verify only, nothing written)");
+    println!("  store:          {}", report.store_name);
+    println!("  store exists:   {}", report.store_exists);
+    println!("  target version: {}", report.target_version);
+    if let Some(configured) = report.configured_version {
+        println!("  configured version: {configured} (migration is skipped)");
+    }
+    println!(
+        "  applied model:  {}",
+        report.applied_model_id.as_deref().unwrap_or("<none>")
+    );
+    println!(
+        "  migration would occur: {}",
+        if report.migration_required() {
+            "yes"
+        } else {
+            "no"
+        }
+    );
+    println!(
+        "  embedded models valid: {}",
+        if report.models_valid() { "yes" } else { "no" }
+    );
+    for error in &report.model_errors {
+        println!("    {error}");
+    }
+
+    if !report.models_valid() {
+        anyhow::bail!("Embedded OpenFGA authorization model is invalid");
+    }
+    Ok(())
+}
+
 async fn openfga_reconcile(
     mode: lakekeeper_authz_openfga::ReconcileMode,
     dry_run: bool,
@@ -409,6 +536,46 @@ async fn openfga_reconcile(
     Ok(())
 }
 
+async fn openfga_transfer_ownership(project_id: &str, from: &str, to: &str) -> anyhow::Result<()> {
+    if !lakekeeper_authz_openfga::CONFIG.is_openfga_enabled() {
+        anyhow::bail!(
+            "openfga transfer-ownership requires LAKEKEEPER__AUTHZ_BACKEND=openfga; current backend is {:?}",
+            CONFIG.authz_backend
+        );
+    }
+    let project_id = project_id
+        .parse::<lakekeeper::ProjectId>()
+        .map_err(|e| anyhow::anyhow!("Invalid project id `{project_id}`: {}", e.message))?;
+
+    let read_pool = lakekeeper_storage_postgres::get_reader_pool(
+        lakekeeper_storage_postgres::config::CONFIG.to_pool_opts(),
+    )
+    .await?;
+    let catalog_state = CatalogState::from_pools(read_pool.clone(), read_pool);
+    let server_id =
+        <PostgresBackend as lakekeeper::service::CatalogStore>::get_server_info(catalog_state)
+            .await?
+            .server_id();
+    let authorizer =
+        lakekeeper_authz_openfga::new_authorizer_from_default_config(server_id).await?;
+
+    let report = authorizer
+        .transfer_project_ownership(&project_id, from, to)
+        .await?;
+
+    println!();
+    println!("OpenFGA ownership transfer report");
+    println!("  project: {project_id}");
+    println!("  from:    {from}");
+    println!("  to:      {to}");
+    println!("  namespaces transferred: {}", report.namespaces);
+    println!("  tables transferred:     {}", report.tables);
+    println!("  views transferred:      {}", report.views);
+    println!("  write requests:         {}", report.write_requests);
+
+    Ok(())
+}
+
 async fn migrate() -> anyhow::Result<()> {
     tracing::info!("Migrating database...");
     let write_pool = lakekeeper_storage_postgres::get_writer_pool(
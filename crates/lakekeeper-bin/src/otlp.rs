@@ -0,0 +1,56 @@
+//! Export of tracing spans to an OpenTelemetry collector via OTLP.
+//!
+//! Request spans carry the `request_id` of the `x-request-id` header, catalog
+//! operations and DB round-trips are exported as their children.
+use lakekeeper::tracing::Subscriber;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::SpanExporter;
+use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
+use tracing_subscriber::{
+    layer::SubscriberExt as _, registry::LookupSpan, util::SubscriberInitExt as _,
+};
+
+use crate::CONFIG_BIN;
+
+/// Flushes pending spans to the collector when dropped.
+#[derive(Debug)]
+pub(crate) struct OtlpGuard(SdkTracerProvider);
+
+impl Drop for OtlpGuard {
+    fn drop(&mut self) {
+        if let Err(e) = self.0.shutdown() {
+            eprintln!("Failed to shut down OTLP trace exporter: {e}");
+        }
+    }
+}
+
+/// Install `subscriber` as the global default, extended by an OTLP export
+/// layer if enabled in the configuration.
+///
+/// # Errors
+/// Fails if the OTLP exporter cannot be created.
+pub(crate) fn init<S>(subscriber: S) -> anyhow::Result<Option<OtlpGuard>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span> + Send + Sync + 'static,
+{
+    if !CONFIG_BIN.otlp.enabled {
+        subscriber.init();
+        return Ok(None);
+    }
+
+    let exporter = SpanExporter::builder().with_tonic().build()?;
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(CONFIG_BIN.otlp.service_name.clone())
+                .build(),
+        )
+        .build();
+    let tracer = provider.tracer("lakekeeper");
+    subscriber
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .init();
+
+    Ok(Some(OtlpGuard(provider)))
+}
@@ -10,12 +10,13 @@ use lakekeeper::{
         authz::Authorizer,
         endpoint_statistics::EndpointStatisticsSink,
         events::EventDispatcher,
+        health::HealthExt,
     },
     tracing,
 };
 use lakekeeper_storage_postgres::{
     CatalogState, PostgresBackend, PostgresStatisticsSink, SecretsState as PgSecretsState,
-    get_reader_pool, get_writer_pool,
+    get_reader_pool, get_writer_pool, migrations::MigrationHealth, with_statement_timeout,
 };
 
 #[cfg(feature = "ui")]
@@ -30,6 +31,12 @@ pub(crate) async fn serve_default(bind_addr: std::net::SocketAddr) -> anyhow::Re
     let server_id = <PostgresBackend as CatalogStore>::get_server_info(catalog.clone())
         .await?
         .server_id();
+    // `/readyz` stays unavailable until all migrations of this binary are
+    // applied. Checked against the primary, like `wait-for-db`.
+    let readiness_checks: Vec<(&'static str, Arc<dyn HealthExt + Sync + Send>)> = vec![(
+        "migrations",
+        Arc::new(MigrationHealth::new(catalog.write_pool())),
+    )];
     // Events implement interior mutability.
     let events = EventDispatcher::new(vec![]);
     let authorizer = AuthorizerEnum::init_from_env(server_id).await?;
@@ -39,14 +46,39 @@ pub(crate) async fn serve_default(bind_addr: std::net::SocketAddr) -> anyhow::Re
         AuthorizerEnum::AllowAll(authz) => {
             tracing::info!("Using AllowAll authorizer");
             serve_with_authn::<PostgresBackend, _, _>(
-                bind_addr, secrets, catalog, authz, stats, events,
+                bind_addr,
+                secrets,
+                catalog,
+                authz,
+                stats,
+                events,
+                readiness_checks,
             )
             .await
         }
         AuthorizerEnum::OpenFGA(authz) => {
             tracing::info!("Using OpenFGA authorizer");
             serve_with_authn::<PostgresBackend, _, _>(
-                bind_addr, secrets, catalog, *authz, stats, events,
+                bind_addr,
+                secrets,
+                catalog,
+                *authz,
+                stats,
+                events,
+                readiness_checks,
+            )
+            .await
+        }
+        AuthorizerEnum::OpenFGAAllowAllReads(authz) => {
+            tracing::info!("Using OpenFGA authorizer with all reads allowed");
+            serve_with_authn::<PostgresBackend, _, _>(
+                bind_addr,
+                secrets,
+                catalog,
+                *authz,
+                stats,
+                events,
+                readiness_checks,
             )
             .await
         }
@@ -60,6 +92,7 @@ async fn serve_with_authn<C: CatalogStore, S: SecretStore, A: Authorizer>(
     authz: A,
     stats: Vec<Arc<dyn EndpointStatisticsSink + 'static>>,
     events: EventDispatcher,
+    readiness_checks: Vec<(&'static str, Arc<dyn HealthExt + Sync + Send>)>,
 ) -> anyhow::Result<()> {
     // Use the upstream config-driven authenticator
     // Supports both single-provider (OPENID_PROVIDER_URI) and multi-provider (OPENID_PROVIDERS) modes
@@ -68,21 +101,47 @@ async fn serve_with_authn<C: CatalogStore, S: SecretStore, A: Authorizer>(
     match authentication {
         None => {
             serve_inner::<C, _, _, AuthenticatorEnum>(
-                bind, secret, catalog, authz, None, stats, events,
+                bind,
+                secret,
+                catalog,
+                authz,
+                None,
+                stats,
+                events,
+                readiness_checks,
             )
             .await
         }
         Some(BuiltInAuthenticators::Chain(authn)) => {
-            serve_inner::<C, _, _, _>(bind, secret, catalog, authz, Some(authn), stats, events)
-                .await
+            serve_inner::<C, _, _, _>(
+                bind,
+                secret,
+                catalog,
+                authz,
+                Some(authn),
+                stats,
+                events,
+                readiness_checks,
+            )
+            .await
         }
         Some(BuiltInAuthenticators::Single(authn)) => {
-            serve_inner::<C, _, _, _>(bind, secret, catalog, authz, Some(authn), stats, events)
-                .await
+            serve_inner::<C, _, _, _>(
+                bind,
+                secret,
+                catalog,
+                authz,
+                Some(authn),
+                stats,
+                events,
+                readiness_checks,
+            )
+            .await
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn serve_inner<C: CatalogStore, S: SecretStore, A: Authorizer, N: Authenticator + 'static>(
     bind: std::net::SocketAddr,
     secrets: S,
@@ -91,6 +150,7 @@ async fn serve_inner<C: CatalogStore, S: SecretStore, A: Authorizer, N: Authenti
     authenticator: Option<N>,
     stats: Vec<Arc<dyn EndpointStatisticsSink + 'static>>,
     events: EventDispatcher,
+    readiness_checks: Vec<(&'static str, Arc<dyn HealthExt + Sync + Send>)>,
 ) -> anyhow::Result<()> {
     let cloud_event_sinks = get_default_cloud_event_backends_from_config().await?;
 
@@ -101,6 +161,7 @@ async fn serve_inner<C: CatalogStore, S: SecretStore, A: Authorizer, N: Authenti
         .authorizer(authorizer)
         .authenticator(authenticator)
         .stats(stats)
+        .readiness_checks(readiness_checks)
         .modify_router_fn(Some(add_ui_routes))
         .cloud_event_sinks(cloud_event_sinks)
         .event_dispatcher(Some(events))
@@ -138,17 +199,19 @@ async fn get_default_catalog_from_config() -> anyhow::Result<(
         );
     }
 
-    let read_pool = get_reader_pool(
+    let read_pool = get_reader_pool(with_statement_timeout(
         PG_CONFIG
             .to_pool_opts()
             .max_connections(PG_CONFIG.pg_read_pool_connections),
-    )
+        PG_CONFIG.read_statement_timeout(),
+    ))
     .await?;
-    let write_pool = get_writer_pool(
+    let write_pool = get_writer_pool(with_statement_timeout(
         PG_CONFIG
             .to_pool_opts()
             .max_connections(PG_CONFIG.pg_write_pool_connections),
-    )
+        PG_CONFIG.write_statement_timeout(),
+    ))
     .await?;
 
     let catalog_state = CatalogState::from_pools(read_pool.clone(), write_pool.clone());
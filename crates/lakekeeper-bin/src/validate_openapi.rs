@@ -0,0 +1,96 @@
+//! `validate-openapi`: check the embedded `OpenAPI` documentation against the router.
+//!
+//! Builds the Management and Generic Table API routers as they are served and
+//! fails if a registered route is not documented or a documented operation is
+//! not routed. No database connection is required: requests are answered
+//! before any handler runs.
+
+use lakekeeper::{
+    api::{
+        ApiContext,
+        data::v1::generic_tables,
+        management::v1::{
+            ApiServer, api_doc,
+            server::{APACHE_LICENSE_STATUS, DEFAULT_BUILD_INFO},
+        },
+        openapi_validation::{registered_routes, validate_openapi as validate},
+    },
+    axum::Router,
+    server::CatalogServer,
+    service::{
+        ServerId, State,
+        authz::AllowAllAuthorizer,
+        contract_verification::ContractVerifiers,
+        events::EventDispatcher,
+        tasks::{BUILT_IN_API_CONFIGS, BUILT_IN_PROJECT_API_CONFIGS, RegisteredTaskQueues},
+    },
+};
+use lakekeeper_storage_postgres::{CatalogState, PostgresBackend, SecretsState};
+
+type ValidationState = State<AllowAllAuthorizer, PostgresBackend, SecretsState>;
+
+pub(crate) async fn validate_openapi() -> anyhow::Result<()> {
+    let context = api_context()?;
+    let routes = registered_routes();
+
+    let queue_configs = BUILT_IN_API_CONFIGS.iter().collect::<Vec<_>>();
+    let project_queue_configs = BUILT_IN_PROJECT_API_CONFIGS.iter().collect::<Vec<_>>();
+    let management_router = Router::new()
+        .nest(
+            "/management/v1",
+            ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::new_v1_router(
+                &context.v1_state.authz,
+            ),
+        )
+        .with_state(context.clone());
+    let management_report = validate(
+        management_router,
+        &api_doc::<AllowAllAuthorizer>(&queue_configs, &project_queue_configs),
+        routes.iter().cloned(),
+    )
+    .await;
+
+    let generic_table_router = Router::new()
+        .nest(
+            "/lakekeeper/v1",
+            generic_tables::router::<
+                CatalogServer<PostgresBackend, AllowAllAuthorizer, SecretsState>,
+                ValidationState,
+            >(),
+        )
+        .with_state(context);
+    let generic_table_report = validate(
+        generic_table_router,
+        &generic_tables::api_doc(),
+        routes.iter().cloned(),
+    )
+    .await;
+
+    println!("Management API: {management_report}");
+    println!("Generic Table API: {generic_table_report}");
+    if !management_report.is_ok() || !generic_table_report.is_ok() {
+        anyhow::bail!("OpenAPI documentation does not match the router");
+    }
+    Ok(())
+}
+
+fn api_context() -> anyhow::Result<ApiContext<ValidationState>> {
+    // The pool is never connected, handlers are not called during validation.
+    let pool = lakekeeper_storage_postgres::config::CONFIG
+        .to_pool_opts()
+        .connect_lazy("postgres://localhost")?;
+    Ok(ApiContext {
+        v1_state: State {
+            authz: AllowAllAuthorizer {
+                server_id: ServerId::new_random(),
+            },
+            catalog: CatalogState::from_pools(pool.clone(), pool.clone()),
+            secrets: SecretsState::from_pools(pool.clone(), pool),
+            contract_verifiers: ContractVerifiers::new(vec![]),
+            events: EventDispatcher::new(vec![]),
+            registered_task_queues: RegisteredTaskQueues::default(),
+            license_status: &APACHE_LICENSE_STATUS,
+            build_info: &DEFAULT_BUILD_INFO,
+        },
+    })
+}
@@ -213,6 +213,7 @@ pub fn create_table_request(
         write_order: None,
         stage_create,
         properties: None,
+        table_uuid: None,
     }
 }
 
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use axum::{Router, body::Body, extract::Request, middleware::Next};
+use chrono::{DateTime, Duration, Utc};
+use http::{Method, StatusCode};
+use lakekeeper::{
+    api::{
+        RequestMetadata,
+        audit::management_audit_middleware_fn,
+        endpoints::EndpointFlat,
+        management::v1::{
+            ApiServer,
+            audit::{QueryAuditLogRequest, Service as _},
+            warehouse::TabularDeleteProfile,
+        },
+    },
+    service::{
+        UserId, authz::AllowAllAuthorizer, events::backends::audit_store::CatalogAuditLogListener,
+    },
+};
+use lakekeeper_integration_tests::{memory_io_profile, setup_simple};
+use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
+use sqlx::PgPool;
+use tower::ServiceExt as _;
+
+type Server = ApiServer<PostgresBackend, AllowAllAuthorizer, SecretsState>;
+
+#[sqlx::test]
+async fn test_warehouse_deletion_produces_one_audit_record_with_actor(pool: PgPool) {
+    let (ctx, warehouse) = setup_simple(
+        pool.clone(),
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    ctx.v1_state
+        .events
+        .append(Arc::new(CatalogAuditLogListener::<PostgresBackend>::new(
+            ctx.v1_state.catalog.clone(),
+        )))
+        .await;
+
+    let user_id = UserId::new_unchecked("oidc", "auditor");
+    let request_metadata = RequestMetadata::test_user(user_id.clone());
+    let management_routes =
+        ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::new_v1_router(
+            &ctx.v1_state.authz,
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            ctx.v1_state.events.clone(),
+            management_audit_middleware_fn,
+        ));
+    let router = Router::new()
+        .nest("/management/v1", management_routes)
+        .layer(axum::middleware::from_fn(
+            move |mut request: Request, next: Next| {
+                request.extensions_mut().insert(request_metadata.clone());
+                next.run(request)
+            },
+        ))
+        .with_state(ctx.clone());
+
+    let uri = format!("/management/v1/warehouse/{}", warehouse.warehouse_id);
+    for method in [Method::GET, Method::DELETE] {
+        let response = router
+            .clone()
+            .oneshot(
+                http::Request::builder()
+                    .method(method)
+                    .uri(&uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success(), "{}", response.status());
+    }
+
+    // Only the deletion is audited, reads are not.
+    let records = sqlx::query!(
+        r#"SELECT operation::text as "operation!", actor, targets, outcome, http_status FROM audit_log"#
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(records.len(), 1);
+    let record = &records[0];
+    assert_eq!(record.operation, "management-v1-delete-warehouse");
+    assert_eq!(
+        record.actor,
+        serde_json::json!({ "actor_type": "principal", "principal": user_id.to_string() })
+    );
+    assert_eq!(
+        record.targets["warehouse_id"],
+        warehouse.warehouse_id.to_string()
+    );
+    assert_eq!(record.outcome, "success");
+    assert_eq!(
+        record.http_status,
+        i32::from(StatusCode::NO_CONTENT.as_u16())
+    );
+}
+
+async fn insert_audit_row(
+    pool: &PgPool,
+    project_id: &str,
+    actor: &UserId,
+    operation: EndpointFlat,
+    created_at: DateTime<Utc>,
+) {
+    sqlx::query!(
+        r#"
+        INSERT INTO audit_log (audit_log_id, request_id, project_id, operation, actor, targets, outcome, http_status, created_at)
+        VALUES ($1, $2, $3, $4, $5, '{}', 'success', 200, $6)
+        "#,
+        uuid::Uuid::now_v7(),
+        uuid::Uuid::now_v7(),
+        project_id,
+        operation as EndpointFlat,
+        serde_json::json!({ "actor_type": "principal", "principal": actor.to_string() }),
+        created_at,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test]
+async fn test_query_audit_log_filters_by_actor_and_time_window(pool: PgPool) {
+    let (ctx, _warehouse) = setup_simple(
+        pool.clone(),
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    let alice = UserId::new_unchecked("oidc", "alice");
+    let bob = UserId::new_unchecked("oidc", "bob");
+    let request_metadata = RequestMetadata::test_user(alice.clone());
+    let project_id = request_metadata.require_project_id(None).unwrap();
+
+    let now = Utc::now();
+    for (project, actor, operation, age) in [
+        (
+            project_id.as_str(),
+            &alice,
+            EndpointFlat::ManagementV1DeleteWarehouse,
+            Duration::hours(3),
+        ),
+        (
+            project_id.as_str(),
+            &alice,
+            EndpointFlat::ManagementV1CreateWarehouse,
+            Duration::hours(2),
+        ),
+        (
+            project_id.as_str(),
+            &bob,
+            EndpointFlat::ManagementV1DeleteWarehouse,
+            Duration::hours(1),
+        ),
+        (
+            project_id.as_str(),
+            &alice,
+            EndpointFlat::ManagementV1RenameWarehouse,
+            Duration::minutes(30),
+        ),
+        (
+            "other-project",
+            &alice,
+            EndpointFlat::ManagementV1RenameWarehouse,
+            Duration::minutes(10),
+        ),
+    ] {
+        insert_audit_row(&pool, project, actor, operation, now - age).await;
+    }
+
+    let query = |page_token: Option<String>| {
+        QueryAuditLogRequest::builder()
+            .actor(Some(alice.clone()))
+            .created_after(Some(now - Duration::minutes(150)))
+            .created_before(Some(now))
+            .page_size(Some(1))
+            .page_token(page_token)
+            .build()
+    };
+
+    // Newest first, one entry per page. Bob's operation, the operation outside
+    // of the window and the other project are filtered out.
+    let mut operations = vec![];
+    let mut page_token = None;
+    loop {
+        let page =
+            Server::query_audit_log(query(page_token), ctx.clone(), request_metadata.clone())
+                .await
+                .unwrap();
+        if page.entries.is_empty() {
+            break;
+        }
+        assert_eq!(page.entries.len(), 1);
+        let entry = &page.entries[0];
+        assert_eq!(entry.project_id.as_deref(), Some(project_id.as_str()));
+        assert_eq!(entry.actor["principal"], alice.to_string());
+        operations.push(entry.operation.clone());
+        page_token = page.next_page_token;
+    }
+    assert_eq!(
+        operations,
+        vec![
+            "management-v1-rename-warehouse",
+            "management-v1-create-warehouse"
+        ]
+    );
+
+    let bob_only = Server::query_audit_log(
+        QueryAuditLogRequest::builder().actor(Some(bob)).build(),
+        ctx,
+        request_metadata,
+    )
+    .await
+    .unwrap();
+    assert_eq!(bob_only.entries.len(), 1);
+    assert_eq!(
+        bob_only.entries[0].operation,
+        "management-v1-delete-warehouse"
+    );
+}
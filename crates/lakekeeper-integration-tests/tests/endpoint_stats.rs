@@ -32,8 +32,8 @@ mod test {
             management::v1::{
                 ApiServer, DeleteWarehouseQuery,
                 project::{
-                    GetEndpointStatisticsRequest, Service as OtherService, TimeWindowSelector,
-                    WarehouseFilter,
+                    DeleteEndpointStatisticsQuery, GetEndpointStatisticsRequest,
+                    Service as OtherService, TimeWindowSelector, WarehouseFilter,
                 },
                 warehouse::Service,
             },
@@ -94,6 +94,57 @@ mod test {
         setup.tracker_handle.await.unwrap();
     }
 
+    #[sqlx::test]
+    async fn test_delete_endpoint_statistics(pool: PgPool) {
+        let setup = super::setup_stats_test(pool, FlushMode::Automatic, 1).await;
+        send_all_endpoints(&setup).await;
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let get_stats = || {
+            ApiServer::get_endpoint_statistics(
+                setup.ctx.clone(),
+                GetEndpointStatisticsRequest {
+                    warehouse: WarehouseFilter::All,
+                    status_codes: None,
+                    range_specifier: None,
+                },
+                RequestMetadata::new_unauthenticated(),
+            )
+        };
+        assert_eq!(get_stats().await.unwrap().timestamps.len(), 1);
+
+        // A range before the statistics were recorded does not delete anything
+        ApiServer::delete_endpoint_statistics(
+            setup.ctx.clone(),
+            DeleteEndpointStatisticsQuery {
+                from: None,
+                until: Some(Utc::now() - chrono::Duration::hours(1)),
+            },
+            RequestMetadata::new_unauthenticated(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(get_stats().await.unwrap().timestamps.len(), 1);
+
+        ApiServer::delete_endpoint_statistics(
+            setup.ctx.clone(),
+            DeleteEndpointStatisticsQuery::default(),
+            RequestMetadata::new_unauthenticated(),
+        )
+        .await
+        .unwrap();
+        let stats = get_stats().await.unwrap();
+        assert!(stats.timestamps.is_empty(), "{stats:?}");
+        assert!(stats.called_endpoints.is_empty(), "{stats:?}");
+
+        setup
+            .tx
+            .send(EndpointStatisticsMessage::Shutdown)
+            .await
+            .unwrap();
+        setup.tracker_handle.await.unwrap();
+    }
+
     #[sqlx::test]
     async fn test_pagination_endpoints_statistics(pool: sqlx::PgPool) {
         let setup = super::setup_stats_test(pool, FlushMode::Automatic, 1).await;
@@ -539,6 +590,75 @@ mod test {
         .unwrap();
     }
 
+    #[sqlx::test]
+    async fn test_warehouse_filter_separates_warehouses(pg_pool: PgPool) {
+        let setup = super::setup_stats_test(pg_pool, FlushMode::Manual, 2).await;
+        let first_warehouse = setup.warehouse.warehouse_id;
+        let second_warehouse = setup.warehouse.additional_warehouses.first().unwrap().1;
+        let drop_table: Endpoint = CatalogV1Endpoint::DropTable.into();
+        let load_table: Endpoint = CatalogV1Endpoint::LoadTable.into();
+
+        for (ep, warehouse_id, calls) in [
+            (&drop_table, first_warehouse, 3),
+            (&load_table, second_warehouse, 2),
+        ] {
+            let request_metadata = RequestMetadataTestBuilder::builder()
+                .project_id(DEFAULT_PROJECT_ID.clone())
+                .matched_path(Some(Arc::from(ep.path())))
+                .request_method(ep.method())
+                .build();
+            for _ in 0..calls {
+                setup
+                    .tx
+                    .send(EndpointStatisticsMessage::EndpointCalled {
+                        request_metadata: request_metadata.clone(),
+                        response_status: http::StatusCode::OK,
+                        path_params: hashmap! {
+                            "warehouse_id".to_string() => warehouse_id.to_string(),
+                        },
+                        query_params: HashMap::default(),
+                    })
+                    .await
+                    .unwrap();
+            }
+        }
+
+        setup
+            .tx
+            .send(EndpointStatisticsMessage::Flush)
+            .await
+            .unwrap();
+        setup
+            .tx
+            .send(EndpointStatisticsMessage::Shutdown)
+            .await
+            .unwrap();
+        setup.tracker_handle.await.unwrap();
+
+        for (ep, warehouse_id, calls) in [
+            (&drop_table, first_warehouse, 3),
+            (&load_table, second_warehouse, 2),
+        ] {
+            let stats = ApiServer::get_endpoint_statistics(
+                setup.ctx.clone(),
+                GetEndpointStatisticsRequest {
+                    warehouse: WarehouseFilter::WarehouseId { id: *warehouse_id },
+                    status_codes: None,
+                    range_specifier: None,
+                },
+                RequestMetadata::new_unauthenticated(),
+            )
+            .await
+            .unwrap();
+            assert_eq!(stats.called_endpoints.len(), 1, "{stats:?}");
+            assert_eq!(stats.called_endpoints[0].len(), 1, "{stats:?}");
+            let stat = &stats.called_endpoints[0][0];
+            assert_eq!(stat.http_route, ep.as_http_route());
+            assert_eq!(stat.warehouse_id, Some(*warehouse_id));
+            assert_eq!(stat.count, calls);
+        }
+    }
+
     async fn send_all_endpoints(setup: &StatsSetup) {
         // send each endpoint once
         for ep in Endpoint::iter() {
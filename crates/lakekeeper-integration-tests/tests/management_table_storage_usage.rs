@@ -0,0 +1,273 @@
+use std::{collections::HashMap, sync::Arc};
+
+use iceberg::{
+    NamespaceIdent, TableIdent, TableUpdate,
+    io::Storage as _,
+    spec::{
+        DataContentType, DataFileBuilder, DataFileFormat, MAIN_BRANCH, ManifestListWriter,
+        ManifestWriterBuilder, NestedField, Operation, PrimitiveType, Schema, Snapshot,
+        SnapshotReference, SnapshotRetention, Struct, Summary, Type, UnboundPartitionSpec,
+    },
+};
+use iceberg_ext::catalog::rest::{CommitTableRequest, CreateTableRequest};
+use lakekeeper::{
+    api::{
+        iceberg::v1::{
+            NamespaceParameters, TableParameters,
+            namespace::NamespaceService as _,
+            tables::{DataAccess, TablesService as _},
+        },
+        management::v1::{
+            ApiServer, table::TableManagementService as _, warehouse::TabularDeleteProfile,
+        },
+    },
+    server::CatalogServer,
+    service::{TableId, authz::AllowAllAuthorizer},
+};
+use lakekeeper_integration_tests::{random_request_metadata, setup_simple};
+use lakekeeper_io::iceberg_bridge::IcebergStorageBridge;
+use sqlx::PgPool;
+
+fn create_test_schema() -> Schema {
+    Schema::builder()
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+            NestedField::required(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()
+        .unwrap()
+}
+
+/// Write a data manifest referencing files of the given sizes and return its manifest list entry.
+async fn write_manifest(
+    storage: &IcebergStorageBridge,
+    path: &str,
+    schema: &Schema,
+    snapshot_id: i64,
+    data_files: &[(String, u64)],
+) -> iceberg::spec::ManifestFile {
+    let mut writer = ManifestWriterBuilder::new(
+        storage.new_output(path).unwrap(),
+        Some(snapshot_id),
+        None,
+        Arc::new(schema.clone()),
+        UnboundPartitionSpec::builder()
+            .build()
+            .bind(Arc::new(schema.clone()))
+            .unwrap(),
+    )
+    .build_v2_data();
+    for (data_file, size) in data_files {
+        writer
+            .add_file(
+                DataFileBuilder::default()
+                    .content(DataContentType::Data)
+                    .file_path(data_file.clone())
+                    .file_format(DataFileFormat::Parquet)
+                    .partition(Struct::empty())
+                    .partition_spec_id(0)
+                    .record_count(10)
+                    .file_size_in_bytes(*size)
+                    .build()
+                    .unwrap(),
+                1,
+            )
+            .unwrap();
+    }
+    writer.write_manifest_file().await.unwrap()
+}
+
+fn snapshot(
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    manifest_list: &str,
+    schema_id: i32,
+    summary: HashMap<String, String>,
+) -> Vec<TableUpdate> {
+    let snapshot = Snapshot::builder()
+        .with_snapshot_id(snapshot_id)
+        .with_parent_snapshot_id(parent_snapshot_id)
+        .with_timestamp_ms(chrono::Utc::now().timestamp_millis())
+        .with_sequence_number(snapshot_id)
+        .with_manifest_list(manifest_list)
+        .with_summary(Summary {
+            operation: Operation::Append,
+            additional_properties: summary,
+        })
+        .with_schema_id(schema_id)
+        .build();
+    vec![
+        TableUpdate::AddSnapshot { snapshot },
+        TableUpdate::SetSnapshotRef {
+            ref_name: MAIN_BRANCH.to_string(),
+            reference: SnapshotReference {
+                snapshot_id,
+                retention: SnapshotRetention::Branch {
+                    min_snapshots_to_keep: None,
+                    max_snapshot_age_ms: None,
+                    max_ref_age_ms: None,
+                },
+            },
+        },
+    ]
+}
+
+#[sqlx::test]
+async fn test_table_storage_usage(pool: PgPool) {
+    let storage_profile = lakekeeper_integration_tests::memory_io_profile();
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        storage_profile.clone(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    let warehouse_id = warehouse.warehouse_id;
+
+    let ns_name = NamespaceIdent::new("test_namespace".to_string());
+    let ns_params = NamespaceParameters {
+        namespace: ns_name.clone(),
+        prefix: Some(warehouse_id.to_string().into()),
+    };
+    CatalogServer::create_namespace(
+        ns_params.prefix.clone(),
+        lakekeeper::api::iceberg::v1::CreateNamespaceRequest {
+            namespace: ns_name.clone(),
+            properties: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let table = CatalogServer::create_table(
+        ns_params.clone(),
+        CreateTableRequest {
+            name: "measured".to_string(),
+            location: None,
+            schema: create_test_schema(),
+            partition_spec: Some(UnboundPartitionSpec::builder().build()),
+            write_order: None,
+            stage_create: Some(false),
+            properties: None,
+            table_uuid: None,
+        },
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let table_id = TableId::from(table.metadata.uuid());
+    let table_location = table.metadata.location().trim_end_matches('/').to_string();
+    let schema = table.metadata.current_schema().as_ref().clone();
+
+    // A table without snapshots does not use any storage
+    let usage = ApiServer::get_table_storage_usage(
+        table_id,
+        warehouse_id,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(usage.snapshot_id, None);
+    assert_eq!(usage.total_bytes, 0);
+    assert_eq!(usage.file_count, 0);
+
+    // Two manifests referencing three files of known sizes
+    let storage = IcebergStorageBridge::new(Arc::new(storage_profile.file_io(None).await.unwrap()));
+    let data_files = [
+        vec![
+            (format!("{table_location}/data/file-1.parquet"), 100),
+            (format!("{table_location}/data/file-2.parquet"), 250),
+        ],
+        vec![(format!("{table_location}/data/file-3.parquet"), 4096)],
+    ];
+    let mut manifests = Vec::new();
+    for (i, files) in data_files.iter().enumerate() {
+        let path = format!("{table_location}/metadata/manifest-{i}.avro");
+        manifests.push(write_manifest(&storage, &path, &schema, 1, files).await);
+    }
+    let manifest_list_path = format!("{table_location}/metadata/snap-1.avro");
+    let mut manifest_list_writer =
+        ManifestListWriter::v2(storage.new_output(&manifest_list_path).unwrap(), 1, None, 1);
+    manifest_list_writer
+        .add_manifests(manifests.into_iter())
+        .unwrap();
+    manifest_list_writer.close().await.unwrap();
+
+    let table_ident = TableIdent::new(ns_name, "measured".to_string());
+    let table_params = TableParameters {
+        prefix: Some(warehouse_id.to_string().into()),
+        table: table_ident.clone(),
+    };
+    let commit = |updates| CommitTableRequest {
+        identifier: Some(table_ident.clone()),
+        requirements: vec![],
+        updates,
+    };
+
+    // Without totals in the summary, the usage is computed from the manifests
+    CatalogServer::commit_table(
+        table_params.clone(),
+        commit(snapshot(
+            1,
+            None,
+            &manifest_list_path,
+            schema.schema_id(),
+            HashMap::new(),
+        )),
+        vec![],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let usage = ApiServer::get_table_storage_usage(
+        table_id,
+        warehouse_id,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(usage.snapshot_id, Some(1));
+    assert_eq!(usage.total_bytes, 100 + 250 + 4096);
+    assert_eq!(usage.file_count, 3);
+
+    // Totals of the snapshot summary are used if present
+    CatalogServer::commit_table(
+        table_params,
+        commit(snapshot(
+            2,
+            Some(1),
+            &manifest_list_path,
+            schema.schema_id(),
+            HashMap::from([
+                ("total-files-size".to_string(), "8192".to_string()),
+                ("total-data-files".to_string(), "4".to_string()),
+                ("total-delete-files".to_string(), "1".to_string()),
+            ]),
+        )),
+        vec![],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let usage = ApiServer::get_table_storage_usage(
+        table_id,
+        warehouse_id,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(usage.snapshot_id, Some(2));
+    assert_eq!(usage.total_bytes, 8192);
+    assert_eq!(usage.file_count, 5);
+}
@@ -147,6 +147,7 @@ async fn schedule_then_409_then_runnow(pool: PgPool) {
             write_order: None,
             stage_create: Some(false),
             properties: None,
+            table_uuid: None,
         },
         DataAccess {
             vended_credentials: false,
@@ -261,6 +262,7 @@ async fn schedule_eligibility_rejection_surfaces_as_400(pool: PgPool) {
                 REJECTION_MARKER_PROPERTY.to_string(),
                 "reject".to_string(),
             )])),
+            table_uuid: None,
         },
         DataAccess {
             vended_credentials: false,
@@ -329,6 +331,7 @@ async fn schedule_unknown_queue_returns_404(pool: PgPool) {
             write_order: None,
             stage_create: Some(false),
             properties: None,
+            table_uuid: None,
         },
         DataAccess {
             vended_credentials: false,
@@ -390,6 +393,7 @@ async fn schedule_non_user_schedulable_queue_returns_400(pool: PgPool) {
             write_order: None,
             stage_create: Some(false),
             properties: None,
+            table_uuid: None,
         },
         DataAccess {
             vended_credentials: false,
@@ -487,6 +491,7 @@ async fn schedule_invalid_payload_returns_400(pool: PgPool) {
             write_order: None,
             stage_create: Some(false),
             properties: None,
+            table_uuid: None,
         },
         DataAccess {
             vended_credentials: false,
@@ -0,0 +1,218 @@
+use std::{collections::HashMap, time::Duration};
+
+use iceberg::{NamespaceIdent, TableIdent, TableUpdate, spec::TableMetadata};
+use iceberg_ext::catalog::rest::CommitTableRequest;
+use lakekeeper::{
+    api::{
+        ApiContext,
+        iceberg::{
+            types::Prefix,
+            v1::{
+                TableParameters,
+                tables::{LoadTableRequest, LoadTableResultOrNotModified, TablesService as _},
+            },
+        },
+        management::v1::{
+            ApiServer,
+            task_queue::{QueueConfig, ScheduleTaskRequest, SetTaskQueueConfigRequest},
+            tasks::Service as _,
+            warehouse::TabularDeleteProfile,
+        },
+    },
+    server::CatalogServer,
+    service::{
+        CatalogStore, CatalogTaskOps, State, TableId, Transaction,
+        authz::AllowAllAuthorizer,
+        tasks::{
+            WarehouseTaskEntityId,
+            metadata_log_compaction_queue::{MetadataLogCompactionQueueConfig, QUEUE_NAME},
+        },
+    },
+};
+use lakekeeper_integration_tests::{
+    TestWarehouseResponse, create_ns, create_table, memory_io_profile, random_request_metadata,
+    setup_simple, spawn_build_in_queues,
+};
+use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
+use sqlx::PgPool;
+
+type Ctx = ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>;
+
+fn table_params(warehouse: &TestWarehouseResponse) -> TableParameters {
+    TableParameters {
+        prefix: Some(Prefix(warehouse.warehouse_id.to_string())),
+        table: TableIdent::new(NamespaceIdent::new("ns1".to_string()), "tab1".to_string()),
+    }
+}
+
+async fn load_metadata(ctx: &Ctx, warehouse: &TestWarehouseResponse) -> (TableMetadata, String) {
+    let LoadTableResultOrNotModified::LoadTableResult(result) = CatalogServer::load_table(
+        table_params(warehouse),
+        LoadTableRequest::builder().build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap() else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    (
+        (*result.metadata).clone(),
+        result.metadata_location.unwrap(),
+    )
+}
+
+async fn set_queue_config(
+    ctx: &Ctx,
+    warehouse: &TestWarehouseResponse,
+    config: MetadataLogCompactionQueueConfig,
+) {
+    let mut transaction =
+        <PostgresBackend as CatalogStore>::Transaction::begin_write(ctx.v1_state.catalog.clone())
+            .await
+            .unwrap();
+    <PostgresBackend as CatalogTaskOps>::set_task_queue_config(
+        warehouse.project_id.clone(),
+        Some(warehouse.warehouse_id),
+        &QUEUE_NAME,
+        &SetTaskQueueConfigRequest {
+            queue_config: QueueConfig::from_json(serde_json::to_value(config).unwrap()),
+            max_seconds_since_last_heartbeat: None,
+        },
+        transaction.transaction(),
+    )
+    .await
+    .unwrap();
+    transaction.commit().await.unwrap();
+}
+
+#[sqlx::test]
+async fn test_metadata_log_is_pruned_to_configured_maximum(pool: PgPool) {
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    let prefix = warehouse.warehouse_id.to_string();
+    create_ns(ctx.clone(), prefix.clone(), "ns1".to_string()).await;
+    let created = create_table(ctx.clone(), &prefix, "ns1", "tab1", false)
+        .await
+        .unwrap();
+
+    for i in 0..50 {
+        CatalogServer::commit_table(
+            table_params(&warehouse),
+            CommitTableRequest {
+                identifier: Some(table_params(&warehouse).table),
+                requirements: vec![],
+                updates: vec![TableUpdate::SetProperties {
+                    updates: HashMap::from([("change".to_string(), i.to_string())]),
+                }],
+            },
+            vec![],
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+    }
+    let (before, location_before) = load_metadata(&ctx, &warehouse).await;
+    assert_eq!(before.metadata_log().len(), 50);
+
+    set_queue_config(
+        &ctx,
+        &warehouse,
+        MetadataLogCompactionQueueConfig {
+            max_metadata_log_entries: Some(10),
+            ..Default::default()
+        },
+    )
+    .await;
+    ApiServer::schedule_task(
+        warehouse.warehouse_id,
+        &QUEUE_NAME,
+        ScheduleTaskRequest {
+            entity: WarehouseTaskEntityId::Table {
+                table_id: TableId::from(created.metadata.uuid()),
+            },
+            scheduled_for: None,
+            payload: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let cancellation_token = lakekeeper::CancellationToken::new();
+    let queues_handle = spawn_build_in_queues(
+        &ctx,
+        Some(Duration::from_millis(100)),
+        cancellation_token.clone(),
+    )
+    .await;
+
+    let deadline = std::time::Instant::now() + Duration::from_secs(10);
+    let (after, location_after) = loop {
+        let (metadata, location) = load_metadata(&ctx, &warehouse).await;
+        if location != location_before {
+            break (metadata, location);
+        }
+        assert!(
+            std::time::Instant::now() < deadline,
+            "Metadata log was not compacted within 10s"
+        );
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    };
+    cancellation_token.cancel();
+    queues_handle.await.unwrap();
+
+    // The oldest entries are dropped; the previous metadata file becomes the newest entry.
+    assert_eq!(after.metadata_log().len(), 10);
+    assert_eq!(after.metadata_log()[..9], before.metadata_log()[41..]);
+    assert_eq!(after.metadata_log()[9].metadata_file, location_before);
+    assert_ne!(location_after, location_before);
+    assert_eq!(after.properties(), before.properties());
+    assert_eq!(after.current_snapshot_id(), before.current_snapshot_id());
+}
+
+#[sqlx::test]
+async fn test_schedule_metadata_log_compaction_rejects_views(pool: PgPool) {
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    let prefix = warehouse.warehouse_id.to_string();
+    create_ns(ctx.clone(), prefix.clone(), "ns1".to_string()).await;
+    let view =
+        lakekeeper_integration_tests::create_view(ctx.clone(), &prefix, "ns1", "view1", None)
+            .await
+            .unwrap();
+
+    let err = ApiServer::schedule_task(
+        warehouse.warehouse_id,
+        &QUEUE_NAME,
+        ScheduleTaskRequest {
+            entity: WarehouseTaskEntityId::View {
+                view_id: view.metadata.uuid().into(),
+            },
+            scheduled_for: None,
+            payload: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, http::StatusCode::BAD_REQUEST.as_u16());
+    assert_eq!(err.error.r#type, "UnsupportedTaskEntity");
+}
@@ -1,12 +1,27 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use iceberg::NamespaceIdent;
+use iceberg::{
+    NamespaceIdent,
+    spec::{NullOrder, SortDirection, SortField, SortOrder, Transform, UnboundPartitionSpec},
+};
 use iceberg_ext::catalog::rest::UpdateNamespacePropertiesRequest;
+use itertools::Itertools as _;
 use lakekeeper::{
     api::{
         RequestMetadata,
-        iceberg::v1::{NamespaceParameters, namespace::NamespaceService},
-        management::v1::{ApiServer, namespace::NamespaceManagementService as _},
+        iceberg::v1::{
+            DataAccess, NamespaceParameters, Prefix, namespace::NamespaceService,
+            tables::TablesService as _,
+        },
+        management::v1::{
+            ApiServer,
+            namespace::{
+                CreateNamespacesBatchEntry, CreateNamespacesBatchRequest,
+                CreateNamespacesBatchStatus, ListNamespaceTreeQuery, MoveNamespaceRequest,
+                NamespaceManagementService as _, NamespaceTableDefaults, ProtectAllTabularsRequest,
+            },
+            table::TableManagementService as _,
+        },
     },
     server::CatalogServer,
     service::{
@@ -14,7 +29,9 @@ use lakekeeper::{
         NamespaceVersion, Transaction, authz::AllowAllAuthorizer, namespace_cache::NAMESPACE_CACHE,
     },
 };
-use lakekeeper_integration_tests::{SetupTestCatalog, memory_io_profile, random_request_metadata};
+use lakekeeper_integration_tests::{
+    SetupTestCatalog, create_table_request, memory_io_profile, random_request_metadata,
+};
 use lakekeeper_storage_postgres::PostgresBackend;
 use sqlx::PgPool;
 
@@ -1342,3 +1359,605 @@ async fn test_root_namespace_null_parent(pool: PgPool) {
     assert_eq!(hierarchy.depth(), 0, "Root should have depth 0");
     assert!(hierarchy.parent().is_none(), "Root should have no parent");
 }
+
+/// Test that the namespace tree is listed recursively with the full path of each namespace
+#[sqlx::test]
+async fn test_list_namespace_tree(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = Some(Prefix(warehouse_id.to_string()));
+
+    // Three levels: a -> a.b -> a.b.c, plus a sibling a.d and a second top-level namespace e
+    let paths = vec![
+        vec!["a"],
+        vec!["a", "b"],
+        vec!["a", "b", "c"],
+        vec!["a", "d"],
+        vec!["e"],
+    ];
+    let mut ids = Vec::new();
+    for path in &paths {
+        let namespace = NamespaceIdent::from_strs(path).unwrap();
+        CatalogServer::create_namespace(
+            prefix.clone(),
+            iceberg_ext::catalog::rest::CreateNamespaceRequest {
+                namespace: namespace.clone(),
+                properties: None,
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        let namespace =
+            PostgresBackend::get_namespace(warehouse_id, namespace, ctx.v1_state.catalog.clone())
+                .await
+                .unwrap()
+                .unwrap();
+        ids.push(*namespace.namespace_id());
+    }
+
+    // Whole warehouse, paginated
+    let mut entries = Vec::new();
+    let mut page_token = None;
+    loop {
+        let page = ApiServer::list_namespace_tree(
+            warehouse_id,
+            ListNamespaceTreeQuery {
+                root: None,
+                page_token: page_token.clone(),
+                page_size: Some(2),
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        assert!(page.namespaces.len() <= 2);
+        entries.extend(page.namespaces);
+        page_token = page.next_page_token;
+        if page_token.is_none() {
+            break;
+        }
+    }
+    let listed_paths = entries.iter().map(|e| e.namespace.clone()).collect_vec();
+    assert_eq!(
+        listed_paths,
+        paths
+            .iter()
+            .map(|p| p.iter().map(ToString::to_string).collect_vec())
+            .collect_vec()
+    );
+    assert_eq!(entries.iter().map(|e| e.namespace_id).collect_vec(), ids);
+    let parent_ids = entries.iter().map(|e| e.parent_namespace_id).collect_vec();
+    assert_eq!(
+        parent_ids,
+        vec![None, Some(ids[0]), Some(ids[1]), Some(ids[0]), None]
+    );
+
+    // Subtree below a
+    let subtree = ApiServer::list_namespace_tree(
+        warehouse_id,
+        ListNamespaceTreeQuery {
+            root: Some(NamespaceIdent::new("a".to_string())),
+            page_token: None,
+            page_size: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(subtree.next_page_token.is_none());
+    assert_eq!(
+        subtree
+            .namespaces
+            .iter()
+            .map(|e| e.namespace.join("."))
+            .collect_vec(),
+        vec!["a.b", "a.b.c", "a.d"]
+    );
+}
+
+/// Test that moved namespaces are validated like new ones and invalid moves change nothing.
+#[sqlx::test]
+async fn test_move_namespace_to_reserved_name_is_rejected(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = Some(Prefix(warehouse_id.to_string()));
+
+    let mut namespace_ids = Vec::new();
+    for path in [vec!["a"], vec!["a", "system"], vec!["b"]] {
+        let namespace = NamespaceIdent::from_strs(path).unwrap();
+        CatalogServer::create_namespace(
+            prefix.clone(),
+            iceberg_ext::catalog::rest::CreateNamespaceRequest {
+                namespace: namespace.clone(),
+                properties: None,
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        let namespace =
+            PostgresBackend::get_namespace(warehouse_id, namespace, ctx.v1_state.catalog.clone())
+                .await
+                .unwrap()
+                .unwrap();
+        namespace_ids.push(namespace.namespace_id());
+    }
+
+    // `system` is reserved at the root of the warehouse
+    let err = ApiServer::move_namespace(
+        namespace_ids[1],
+        warehouse_id,
+        MoveNamespaceRequest { new_parent: None },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 400);
+    assert_eq!(err.error.r#type, "ReservedNamespace");
+    let namespace = PostgresBackend::get_namespace(
+        warehouse_id,
+        NamespaceIdent::from_strs(["a", "system"]).unwrap(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap();
+    assert!(namespace.is_some());
+
+    ApiServer::move_namespace(
+        namespace_ids[2],
+        warehouse_id,
+        MoveNamespaceRequest {
+            new_parent: Some(NamespaceIdent::from_strs(["a"]).unwrap()),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let namespace = PostgresBackend::get_namespace(
+        warehouse_id,
+        NamespaceIdent::from_strs(["a", "b"]).unwrap(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(namespace.namespace_id(), namespace_ids[2]);
+}
+
+/// Test that a batch create reports existing namespaces, creates the others in
+/// request order and creates nothing if a parent is missing.
+#[sqlx::test]
+async fn test_create_namespaces_batch(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = Some(Prefix(warehouse_id.to_string()));
+
+    let mut existing_ids = Vec::new();
+    for path in [vec!["a"], vec!["a", "b"]] {
+        let namespace = NamespaceIdent::from_strs(path).unwrap();
+        CatalogServer::create_namespace(
+            prefix.clone(),
+            iceberg_ext::catalog::rest::CreateNamespaceRequest {
+                namespace: namespace.clone(),
+                properties: None,
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        let namespace =
+            PostgresBackend::get_namespace(warehouse_id, namespace, ctx.v1_state.catalog.clone())
+                .await
+                .unwrap()
+                .unwrap();
+        existing_ids.push(*namespace.namespace_id());
+    }
+
+    let entry =
+        |path: &[&str], properties: Option<HashMap<String, String>>| CreateNamespacesBatchEntry {
+            namespace: NamespaceIdent::from_strs(path).unwrap(),
+            properties,
+        };
+    let response = ApiServer::create_namespaces_batch(
+        warehouse_id,
+        CreateNamespacesBatchRequest {
+            namespaces: vec![
+                entry(&["a"], None),
+                entry(&["c"], None),
+                entry(&["a", "b"], None),
+                // Parent is created by the same request
+                entry(&["c", "d"], None),
+                entry(
+                    &["a", "e"],
+                    Some(HashMap::from([("owner".to_string(), "me".to_string())])),
+                ),
+            ],
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        response
+            .results
+            .iter()
+            .map(|r| (r.namespace.join("."), r.status))
+            .collect_vec(),
+        vec![
+            ("a".to_string(), CreateNamespacesBatchStatus::AlreadyExists),
+            ("c".to_string(), CreateNamespacesBatchStatus::Created),
+            (
+                "a.b".to_string(),
+                CreateNamespacesBatchStatus::AlreadyExists
+            ),
+            ("c.d".to_string(), CreateNamespacesBatchStatus::Created),
+            ("a.e".to_string(), CreateNamespacesBatchStatus::Created),
+        ]
+    );
+    assert_eq!(response.results[0].namespace_id, existing_ids[0]);
+    assert_eq!(response.results[2].namespace_id, existing_ids[1]);
+
+    let get = |path: &[&str]| {
+        PostgresBackend::get_namespace(
+            warehouse_id,
+            NamespaceIdent::from_strs(path).unwrap(),
+            ctx.v1_state.catalog.clone(),
+        )
+    };
+    let c_d = get(&["c", "d"]).await.unwrap().unwrap();
+    assert_eq!(*c_d.namespace_id(), response.results[3].namespace_id);
+    assert_eq!(
+        c_d.parent().map(|parent| *parent.namespace_id()),
+        Some(response.results[1].namespace_id)
+    );
+    let a_e = get(&["a", "e"]).await.unwrap().unwrap();
+    assert_eq!(*a_e.namespace_id(), response.results[4].namespace_id);
+    assert_eq!(
+        a_e.properties().and_then(|p| p.get("owner")).cloned(),
+        Some("me".to_string())
+    );
+
+    // A missing parent fails the whole batch.
+    let err = ApiServer::create_namespaces_batch(
+        warehouse_id,
+        CreateNamespacesBatchRequest {
+            namespaces: vec![entry(&["f"], None), entry(&["x", "y"], None)],
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 404);
+    assert!(get(&["f"]).await.unwrap().is_none());
+}
+
+#[sqlx::test]
+async fn test_protect_all_namespace_tabulars(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = Some(Prefix(warehouse_id.to_string()));
+
+    // Three tables in `prot`, one in its child `prot.child`
+    let mut namespace_ids = Vec::new();
+    let mut table_ids = Vec::new();
+    for (path, n_tables) in [(vec!["prot"], 3), (vec!["prot", "child"], 1)] {
+        let namespace = NamespaceIdent::from_strs(path).unwrap();
+        CatalogServer::create_namespace(
+            prefix.clone(),
+            iceberg_ext::catalog::rest::CreateNamespaceRequest {
+                namespace: namespace.clone(),
+                properties: None,
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        let ns = PostgresBackend::get_namespace(
+            warehouse_id,
+            namespace.clone(),
+            ctx.v1_state.catalog.clone(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        namespace_ids.push(ns.namespace_id());
+
+        for i in 0..n_tables {
+            let table = CatalogServer::create_table(
+                NamespaceParameters {
+                    prefix: prefix.clone(),
+                    namespace: namespace.clone(),
+                },
+                create_table_request(Some(format!("tab_{i}")), Some(false)),
+                DataAccess::not_specified(),
+                ctx.clone(),
+                random_request_metadata(),
+            )
+            .await
+            .unwrap();
+            table_ids.push(table.metadata.uuid().into());
+        }
+    }
+
+    let response = ApiServer::protect_all_tabulars(
+        namespace_ids[0],
+        warehouse_id,
+        ProtectAllTabularsRequest {
+            protected: true,
+            recursive: false,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.protected);
+    assert_eq!(response.tables, 3);
+    assert_eq!(response.views, 0);
+    assert_eq!(response.generic_tables, 0);
+
+    let mut protected = Vec::new();
+    for table_id in &table_ids {
+        let protection = ApiServer::get_table_protection(
+            *table_id,
+            warehouse_id,
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        protected.push(protection.protected);
+    }
+    assert_eq!(protected, vec![true, true, true, false]);
+
+    // Recursive unprotect includes the table of the child namespace
+    let response = ApiServer::protect_all_tabulars(
+        namespace_ids[0],
+        warehouse_id,
+        ProtectAllTabularsRequest {
+            protected: false,
+            recursive: true,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(!response.protected);
+    assert_eq!(response.tables, 4);
+
+    for table_id in &table_ids {
+        let protection = ApiServer::get_table_protection(
+            *table_id,
+            warehouse_id,
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        assert!(!protection.protected);
+    }
+
+    // The namespaces themselves are not protected
+    let namespace = PostgresBackend::get_namespace(
+        warehouse_id,
+        namespace_ids[0],
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(!namespace.is_protected());
+}
+
+#[sqlx::test]
+async fn test_namespace_table_defaults(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = Some(Prefix(warehouse_id.to_string()));
+    let namespace = NamespaceIdent::new("defaults".to_string());
+    CatalogServer::create_namespace(
+        prefix.clone(),
+        iceberg_ext::catalog::rest::CreateNamespaceRequest {
+            namespace: namespace.clone(),
+            properties: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let namespace_id = PostgresBackend::get_namespace(
+        warehouse_id,
+        namespace.clone(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .namespace_id();
+    let ns_params = NamespaceParameters {
+        prefix: prefix.clone(),
+        namespace: namespace.clone(),
+    };
+
+    let table_defaults = ApiServer::get_namespace_table_defaults(
+        namespace_id,
+        warehouse_id,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(table_defaults, NamespaceTableDefaults::default());
+
+    // Bucket by `id`, sort by `name`
+    let table_defaults = NamespaceTableDefaults {
+        partition_spec: Some(
+            UnboundPartitionSpec::builder()
+                .add_partition_field(1, "id_bucket", Transform::Bucket(4))
+                .unwrap()
+                .build(),
+        ),
+        write_order: Some(
+            SortOrder::builder()
+                .with_order_id(1)
+                .with_sort_field(SortField {
+                    source_id: 2,
+                    transform: Transform::Identity,
+                    direction: SortDirection::Ascending,
+                    null_order: NullOrder::First,
+                })
+                .build_unbound()
+                .unwrap(),
+        ),
+    };
+    let response = ApiServer::set_namespace_table_defaults(
+        namespace_id,
+        warehouse_id,
+        table_defaults.clone(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response, table_defaults);
+    let response = ApiServer::get_namespace_table_defaults(
+        namespace_id,
+        warehouse_id,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response, table_defaults);
+
+    // A table created with an empty spec and without sort order inherits the defaults
+    let table = CatalogServer::create_table(
+        ns_params.clone(),
+        create_table_request(Some("inherited".to_string()), Some(false)),
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let spec = table.metadata.default_partition_spec();
+    assert_eq!(spec.fields().len(), 1);
+    assert_eq!(spec.fields()[0].name, "id_bucket");
+    assert_eq!(spec.fields()[0].transform, Transform::Bucket(4));
+    let sort_order = table.metadata.default_sort_order();
+    assert_eq!(sort_order.fields.len(), 1);
+    assert_eq!(sort_order.fields[0].direction, SortDirection::Ascending);
+
+    // An explicit spec and sort order override the defaults
+    let mut request = create_table_request(Some("explicit".to_string()), Some(false));
+    request.partition_spec = Some(
+        UnboundPartitionSpec::builder()
+            .add_partition_field(2, "name", Transform::Identity)
+            .unwrap()
+            .build(),
+    );
+    request.write_order = Some(
+        SortOrder::builder()
+            .with_order_id(1)
+            .with_sort_field(SortField {
+                source_id: 1,
+                transform: Transform::Identity,
+                direction: SortDirection::Descending,
+                null_order: NullOrder::Last,
+            })
+            .build_unbound()
+            .unwrap(),
+    );
+    let table = CatalogServer::create_table(
+        ns_params.clone(),
+        request,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let spec = table.metadata.default_partition_spec();
+    assert_eq!(spec.fields().len(), 1);
+    assert_eq!(spec.fields()[0].name, "name");
+    assert_eq!(spec.fields()[0].transform, Transform::Identity);
+    let sort_order = table.metadata.default_sort_order();
+    assert_eq!(sort_order.fields.len(), 1);
+    assert_eq!(sort_order.fields[0].direction, SortDirection::Descending);
+
+    // Without defaults, new tables are unpartitioned and unsorted again
+    ApiServer::set_namespace_table_defaults(
+        namespace_id,
+        warehouse_id,
+        NamespaceTableDefaults::default(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let table = CatalogServer::create_table(
+        ns_params,
+        create_table_request(Some("plain".to_string()), Some(false)),
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(table.metadata.default_partition_spec().fields().is_empty());
+    assert!(table.metadata.default_sort_order().is_unsorted());
+}
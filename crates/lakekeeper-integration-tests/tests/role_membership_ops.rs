@@ -15,7 +15,7 @@ use lakekeeper::{
         },
     },
     service::{
-        CatalogCreateRoleRequest, CatalogRoleOps, CatalogStore, RoleId, RoleProviderId,
+        Actor, CatalogCreateRoleRequest, CatalogRoleOps, CatalogStore, RoleId, RoleProviderId,
         RoleSourceId, State, Transaction, UserId, UserUpsertMode, authz::AllowAllAuthorizer,
     },
 };
@@ -559,6 +559,58 @@ async fn list_user_roles_existing_user_no_roles_returns_empty(pool: PgPool) {
         .unwrap();
     assert_eq!(page.roles.len(), 0);
     assert_eq!(page.next_page_token, None);
+    assert!(page.group_roles.is_none());
+}
+
+/// Direct assignments and group-mapped roles are reported separately. The groups
+/// are only known from the caller's own token, so `group-roles` is only
+/// populated when users list their own roles.
+#[sqlx::test]
+async fn list_user_roles_includes_group_roles_for_self(pool: PgPool) {
+    let (ctx, project_id) = setup(pool).await;
+    let direct = make_role(&ctx, &project_id, "Direct", "direct-src").await;
+    let grouped = make_role(&ctx, &project_id, "Grouped", "grouped-src").await;
+    let alice = UserId::new_unchecked("oidc", "alice");
+    provision_user(&ctx, &alice, "Alice").await;
+    ApiServer::add_role_members(
+        ctx.clone(),
+        metadata(&project_id),
+        direct,
+        AddRoleMembersRequest {
+            members: vec![user_member(&alice)],
+        },
+    )
+    .await
+    .unwrap();
+
+    let alice_metadata = RequestMetadataTestBuilder::builder()
+        .project_id(Some((*project_id).clone().into()))
+        .actor(Actor::Principal(alice.clone()))
+        .group_roles(vec![grouped])
+        .build();
+    let page = ApiServer::list_user_roles(ctx.clone(), alice_metadata, alice.clone(), page_query())
+        .await
+        .unwrap();
+    assert_eq!(
+        page.roles.iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![direct]
+    );
+    let group_roles = page.group_roles.expect("group roles listed for self");
+    assert_eq!(
+        group_roles.iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![grouped]
+    );
+    assert_eq!(group_roles[0].name, "Grouped");
+
+    // Another caller cannot see alice's groups
+    let page = ApiServer::list_user_roles(ctx.clone(), metadata(&project_id), alice, page_query())
+        .await
+        .unwrap();
+    assert_eq!(
+        page.roles.iter().map(|r| r.id).collect::<Vec<_>>(),
+        vec![direct]
+    );
+    assert!(page.group_roles.is_none());
 }
 
 /// The mixed-batch wrapper is genuinely atomic: when a later member fails, an
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use axum::{Json, Router, body::Body, http::Request, routing::get};
+use http::StatusCode;
+use http_body_util::BodyExt as _;
+use lakekeeper::{
+    api::{
+        management::v1::warehouse::TabularDeleteProfile,
+        router::{RouterArgs, new_full_router},
+    },
+    limes::jwks::JWKSWebAuthenticator,
+    service::{
+        AdmissionGates, EndpointStatisticsTrackerTx,
+        authz::{AllowAllAuthorizer, ConfiguredInstanceAdmins},
+        group_roles::GroupRoleMapping,
+        health::{Health, HealthExt, HealthStatus, ServiceHealthProvider},
+    },
+};
+use lakekeeper_integration_tests::{memory_io_profile, setup_simple};
+use serde_json::json;
+use sqlx::PgPool;
+use tokio::net::TcpListener;
+use tower::ServiceExt as _;
+
+#[derive(Debug)]
+struct FixedHealth(&'static str, HealthStatus);
+
+#[lakekeeper::async_trait::async_trait]
+impl HealthExt for FixedHealth {
+    async fn health(&self) -> Vec<Health> {
+        vec![Health::now(self.0, self.1)]
+    }
+
+    async fn update_health(&self) {}
+}
+
+/// Serves an OpenID configuration without any signing keys, so that every
+/// token is rejected.
+async fn spawn_oidc_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}/", listener.local_addr().unwrap());
+    let config = json!({
+        "issuer": base,
+        "jwks_uri": format!("{base}jwks"),
+    });
+    let app = Router::new()
+        .route(
+            "/.well-known/openid-configuration",
+            get(move || async move { Json(config) }),
+        )
+        .route("/jwks", get(|| async { Json(json!({ "keys": [] })) }));
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    base
+}
+
+/// Builds the full router with authentication enabled. The catalog health and
+/// the migration readiness check report the given states.
+async fn full_router(pool: PgPool, catalog: HealthStatus, migrations: HealthStatus) -> Router {
+    let (ctx, _warehouse) = setup_simple(
+        pool,
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    let authenticator = JWKSWebAuthenticator::new(&spawn_oidc_server().await, None)
+        .await
+        .unwrap();
+    let catalog: Arc<dyn HealthExt + Sync + Send> = Arc::new(FixedHealth("read_pool", catalog));
+    let migrations: Arc<dyn HealthExt + Sync + Send> =
+        Arc::new(FixedHealth("migrations", migrations));
+    let service_health_provider = ServiceHealthProvider::new(vec![("catalog", catalog)], 10)
+        .with_readiness_providers(vec![("migrations", migrations)]);
+    let (tx, _rx) = tokio::sync::mpsc::channel(100);
+
+    new_full_router(RouterArgs {
+        authenticator: Some(authenticator),
+        state: ctx,
+        service_health_provider,
+        cors_origins: None,
+        metrics_layer: None,
+        endpoint_statistics_tracker_tx: EndpointStatisticsTrackerTx::new(tx),
+        instance_admin_membership: Arc::new(ConfiguredInstanceAdmins::from_config()),
+        admission_gates: AdmissionGates::default(),
+        group_role_mapping: Arc::new(GroupRoleMapping::default()),
+        rate_limiter: None,
+    })
+    .await
+    .unwrap()
+}
+
+async fn status_of(app: Router, uri: &str) -> StatusCode {
+    app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+        .status()
+}
+
+#[sqlx::test]
+async fn ping_returns_ok_when_database_is_unavailable(pool: PgPool) {
+    // The database is reported unhealthy, so `/health` fails while `ping` must not.
+    let app = full_router(pool, HealthStatus::Unhealthy, HealthStatus::Healthy).await;
+
+    assert_eq!(
+        status_of(app.clone(), "/health").await,
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+    // Other management endpoints require authentication, ping does not.
+    assert_eq!(
+        status_of(app.clone(), "/management/v1/info").await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    let ping = app
+        .oneshot(
+            Request::builder()
+                .uri("/management/v1/ping")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(ping.status(), StatusCode::OK);
+    let body = ping.into_body().collect().await.unwrap().to_bytes();
+    assert!(body.is_empty());
+}
+
+#[sqlx::test]
+async fn readyz_is_unavailable_before_migration(pool: PgPool) {
+    let app = full_router(pool, HealthStatus::Healthy, HealthStatus::Unhealthy).await;
+
+    assert_eq!(
+        status_of(app.clone(), "/readyz").await,
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+    // Migrations only gate readiness, the process itself is alive.
+    assert_eq!(status_of(app.clone(), "/livez").await, StatusCode::OK);
+    assert_eq!(status_of(app, "/health").await, StatusCode::OK);
+}
+
+#[sqlx::test]
+async fn readyz_is_unavailable_before_first_migration_check(pool: PgPool) {
+    let app = full_router(pool, HealthStatus::Healthy, HealthStatus::Unknown).await;
+
+    assert_eq!(
+        status_of(app, "/readyz").await,
+        StatusCode::SERVICE_UNAVAILABLE
+    );
+}
+
+#[sqlx::test]
+async fn readyz_is_ok_after_migration(pool: PgPool) {
+    let app = full_router(pool, HealthStatus::Healthy, HealthStatus::Healthy).await;
+
+    assert_eq!(status_of(app.clone(), "/readyz").await, StatusCode::OK);
+    assert_eq!(status_of(app, "/livez").await, StatusCode::OK);
+}
@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+
+use iceberg::{NamespaceIdent, TableIdent};
+use iceberg_ext::catalog::rest::{CreateNamespaceRequest, LoadTableResult};
+use lakekeeper::{
+    api::{
+        ApiContext, RequestMetadata,
+        iceberg::{
+            types::Prefix,
+            v1::{
+                DataAccess, DataAccessMode, NamespaceParameters, TableParameters,
+                namespace::NamespaceService as _,
+                tables::{LoadTableRequest, LoadTableResultOrNotModified, TablesService as _},
+            },
+        },
+        management::v1::{
+            ApiServer,
+            warehouse::{
+                MergeWarehouseStorageRequest, Service as _, TabularDeleteProfile,
+                UpdateWarehouseStorageRequest,
+            },
+        },
+    },
+    server::CatalogServer,
+    service::{
+        CatalogStore as _, CatalogWarehouseOps as _, State, StorageProfileSlot, WarehouseStatus,
+        authz::AllowAllAuthorizer, secrets::SecretStore as _, storage::StorageProfile,
+    },
+};
+use lakekeeper_integration_tests::{
+    TestWarehouseResponse, create_table_request, memory_io_profile, random_request_metadata,
+    s3_compatible_profile, setup_simple,
+};
+use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
+use sqlx::PgPool;
+
+type Ctx = ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>;
+
+const DATA_ACCESS: DataAccess = DataAccess {
+    vended_credentials: true,
+    remote_signing: false,
+};
+
+async fn create_namespace(
+    ctx: &Ctx,
+    warehouse: &TestWarehouseResponse,
+    name: &str,
+    storage_profile_slot: Option<StorageProfileSlot>,
+) -> NamespaceParameters {
+    let namespace = NamespaceIdent::new(name.to_string());
+    let prefix = Some(Prefix(warehouse.warehouse_id.to_string()));
+    CatalogServer::create_namespace(
+        prefix.clone(),
+        CreateNamespaceRequest {
+            namespace: namespace.clone(),
+            properties: storage_profile_slot.map(|slot| {
+                HashMap::from([(StorageProfileSlot::PROPERTY.to_string(), slot.to_string())])
+            }),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    NamespaceParameters { prefix, namespace }
+}
+
+async fn create_and_load_table(
+    ctx: &Ctx,
+    ns_params: &NamespaceParameters,
+    name: &str,
+    storage_profile_slot: Option<StorageProfileSlot>,
+) -> LoadTableResult {
+    let mut request = create_table_request(Some(name.to_string()), Some(false));
+    request.properties = storage_profile_slot
+        .map(|slot| HashMap::from([(StorageProfileSlot::PROPERTY.to_string(), slot.to_string())]));
+    CatalogServer::create_table(
+        ns_params.clone(),
+        request,
+        DATA_ACCESS,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let loaded = CatalogServer::load_table(
+        TableParameters {
+            prefix: ns_params.prefix.clone(),
+            table: TableIdent::new(ns_params.namespace.clone(), name.to_string()),
+        },
+        LoadTableRequest::builder()
+            .data_access(DataAccessMode::ServerDelegated(DATA_ACCESS))
+            .build(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    let LoadTableResultOrNotModified::LoadTableResult(loaded) = loaded else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    loaded
+}
+
+fn assert_stored_on(table: &LoadTableResult, storage_profile: &StorageProfile) {
+    let base_location = storage_profile.base_location().unwrap();
+    assert!(
+        table
+            .metadata
+            .location()
+            .starts_with(base_location.as_str().trim_end_matches('/')),
+        "Table location '{}' is not below '{base_location}'",
+        table.metadata.location()
+    );
+}
+
+#[sqlx::test]
+async fn test_create_tables_on_primary_and_secondary_storage(pool: PgPool) {
+    let primary = memory_io_profile();
+    let secondary = memory_io_profile();
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        primary.clone(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    let response = ApiServer::update_secondary_storage(
+        warehouse.warehouse_id,
+        UpdateWarehouseStorageRequest {
+            storage_profile: secondary.clone(),
+            storage_credential: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.secondary_storage_profile, Some(secondary.clone()));
+
+    let primary_ns = create_namespace(&ctx, &warehouse, "primary_ns", None).await;
+    let secondary_ns = create_namespace(
+        &ctx,
+        &warehouse,
+        "secondary_ns",
+        Some(StorageProfileSlot::Secondary),
+    )
+    .await;
+
+    // The storage profile of the namespace is inherited by its tables
+    let table = create_and_load_table(&ctx, &primary_ns, "primary_tab", None).await;
+    assert_stored_on(&table, &primary);
+    let table = create_and_load_table(&ctx, &secondary_ns, "secondary_tab", None).await;
+    assert_stored_on(&table, &secondary);
+
+    // The storage profile of the table takes precedence over its namespace
+    let table = create_and_load_table(
+        &ctx,
+        &primary_ns,
+        "secondary_in_primary_ns",
+        Some(StorageProfileSlot::Secondary),
+    )
+    .await;
+    assert_stored_on(&table, &secondary);
+    let table = create_and_load_table(
+        &ctx,
+        &secondary_ns,
+        "primary_in_secondary_ns",
+        Some(StorageProfileSlot::Primary),
+    )
+    .await;
+    assert_stored_on(&table, &primary);
+}
+
+#[sqlx::test]
+async fn test_secondary_storage_requires_secondary_profile(pool: PgPool) {
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    let err = CatalogServer::create_namespace(
+        Some(Prefix(warehouse.warehouse_id.to_string())),
+        CreateNamespaceRequest {
+            namespace: NamespaceIdent::new("secondary_ns".to_string()),
+            properties: Some(HashMap::from([(
+                StorageProfileSlot::PROPERTY.to_string(),
+                StorageProfileSlot::Secondary.to_string(),
+            )])),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 400);
+    assert_eq!(err.error.r#type, "SecondaryStorageProfileMissing");
+
+    let ns_params = create_namespace(&ctx, &warehouse, "primary_ns", None).await;
+    let mut request = create_table_request(Some("tab".to_string()), Some(false));
+    request.properties = Some(HashMap::from([(
+        StorageProfileSlot::PROPERTY.to_string(),
+        "cold".to_string(),
+    )]));
+    let err = CatalogServer::create_table(
+        ns_params,
+        request,
+        DATA_ACCESS,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 400);
+    assert_eq!(err.error.r#type, "InvalidStorageProfileSlot");
+}
+
+#[sqlx::test]
+async fn test_load_table_vends_credentials_of_its_storage_profile(pool: PgPool) {
+    let (primary, primary_credential) = s3_compatible_profile();
+    let (secondary, secondary_credential) = s3_compatible_profile();
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        primary.clone(),
+        Some(primary_credential),
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    ApiServer::update_secondary_storage(
+        warehouse.warehouse_id,
+        UpdateWarehouseStorageRequest {
+            storage_profile: secondary.clone(),
+            storage_credential: Some(secondary_credential.clone()),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let resolved = PostgresBackend::get_warehouse_by_id(
+        warehouse.warehouse_id,
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let (_, secondary_secret_id) = resolved.storage(StorageProfileSlot::Secondary).unwrap();
+    assert_ne!(secondary_secret_id, resolved.storage_secret_id);
+    let stored_credential = ctx
+        .v1_state
+        .secrets
+        .require_storage_secret_by_id(secondary_secret_id.unwrap())
+        .await
+        .unwrap()
+        .secret;
+    assert_eq!(*stored_credential, secondary_credential);
+
+    let ns_params = create_namespace(&ctx, &warehouse, "ns", None).await;
+    let tables = [
+        (None, &primary),
+        (Some(StorageProfileSlot::Secondary), &secondary),
+    ];
+    for (i, (slot, storage_profile)) in tables.into_iter().enumerate() {
+        let table = create_and_load_table(&ctx, &ns_params, &format!("tab_{i}"), slot).await;
+        assert_stored_on(&table, storage_profile);
+
+        let credentials = table.storage_credentials.unwrap();
+        assert_eq!(credentials.len(), 1);
+        assert_eq!(
+            credentials[0].prefix.trim_end_matches('/'),
+            table.metadata.location().trim_end_matches('/')
+        );
+        assert!(!credentials[0].config.is_empty());
+    }
+}
+
+/// Merging the secondary profile keeps its other fields and re-uses its credential
+#[sqlx::test]
+async fn test_merge_secondary_storage_profile_keeps_credential(pool: PgPool) {
+    let (primary, primary_credential) = s3_compatible_profile();
+    let (secondary, secondary_credential) = s3_compatible_profile();
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        primary.clone(),
+        Some(primary_credential),
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    let merge_request = || MergeWarehouseStorageRequest {
+        storage_profile: serde_json::json!({"region": "other-region"})
+            .as_object()
+            .unwrap()
+            .clone(),
+        storage_credential: None,
+    };
+
+    let err = ApiServer::merge_secondary_storage(
+        warehouse.warehouse_id,
+        merge_request(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "SecondaryStorageProfileMissing");
+
+    ApiServer::update_secondary_storage(
+        warehouse.warehouse_id,
+        UpdateWarehouseStorageRequest {
+            storage_profile: secondary.clone(),
+            storage_credential: Some(secondary_credential),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let resolved = PostgresBackend::get_warehouse_by_id(
+        warehouse.warehouse_id,
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let (_, secondary_secret_id) = resolved.storage(StorageProfileSlot::Secondary).unwrap();
+
+    ApiServer::merge_secondary_storage(
+        warehouse.warehouse_id,
+        merge_request(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let resolved = PostgresBackend::get_warehouse_by_id(
+        warehouse.warehouse_id,
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let (merged, merged_secret_id) = resolved.storage(StorageProfileSlot::Secondary).unwrap();
+    assert_eq!(merged_secret_id, secondary_secret_id);
+    let mut expected = secondary.try_into_s3().unwrap();
+    expected.region = "other-region".to_string();
+    assert_eq!(merged.clone().try_into_s3().unwrap(), expected);
+    // The primary profile is not touched
+    assert_eq!(resolved.storage_profile, primary);
+}
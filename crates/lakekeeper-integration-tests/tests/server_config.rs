@@ -1,15 +1,21 @@
 use lakekeeper::{
     ProjectId,
     api::{
-        RequestMetadata,
+        RequestMetadata, RequestMetadataTestBuilder,
         iceberg::v1::{
             ErrorModel,
             config::{GetConfigQueryParams, Service as _},
         },
-        management::v1::warehouse::TabularDeleteProfile,
+        management::v1::{
+            ApiServer,
+            warehouse::{
+                Service as _, TabularDeleteProfile, UpdateWarehouseSettingsRequest,
+                WarehouseCredentialMode, WarehouseSettings,
+            },
+        },
     },
     server::CatalogServer,
-    service::{WarehouseNameNotFound, authz::tests::HidingAuthorizer},
+    service::{Actor, UserId, WarehouseId, WarehouseNameNotFound, authz::tests::HidingAuthorizer},
 };
 use lakekeeper_integration_tests::{memory_io_profile, setup};
 use sqlx::PgPool;
@@ -112,3 +118,111 @@ async fn test_get_config_hidden_warehouse_indistinguishable_from_missing(pool: P
     assert_eq!(missing_err.code, 404);
     assert_eq!(missing_err.r#type, hidden_err.r#type);
 }
+
+/// The management view of a warehouse's catalog config must be exactly what
+/// `GET /config` returns to clients of the warehouse.
+#[sqlx::test]
+async fn test_management_catalog_config_matches_get_config(pool: PgPool) {
+    let (ctx, warehouse) = setup(
+        pool,
+        memory_io_profile(),
+        None,
+        HidingAuthorizer::new(),
+        TabularDeleteProfile::Hard {},
+        None,
+        1,
+        None,
+    )
+    .await;
+
+    ApiServer::update_warehouse_settings(
+        warehouse.warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                credential_mode: WarehouseCredentialMode::Vended,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let config = CatalogServer::get_config(
+        config_query(&warehouse.project_id, &warehouse.warehouse_name),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    let management_config = ApiServer::get_warehouse_catalog_config(
+        warehouse.warehouse_id,
+        ctx,
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(management_config.warehouse_id, *warehouse.warehouse_id);
+    assert_eq!(
+        management_config.credential_mode,
+        WarehouseCredentialMode::Vended
+    );
+    assert_eq!(
+        Some(&management_config.prefix),
+        config.defaults.get("prefix")
+    );
+    assert_eq!(
+        config.overrides.get("header.X-Iceberg-Access-Delegation"),
+        Some(&"vended-credentials".to_string())
+    );
+    assert_eq!(management_config.defaults, config.defaults);
+    assert_eq!(management_config.overrides, config.overrides);
+    assert_eq!(management_config.endpoints, config.endpoints);
+}
+
+/// Requests admitted without token are limited to the warehouses listed for
+/// the endpoint in `LAKEKEEPER__ANONYMOUS_ACCESS__ALLOW`.
+#[sqlx::test]
+async fn test_get_config_anonymous_access(pool: PgPool) {
+    let (ctx, warehouse) = setup(
+        pool,
+        memory_io_profile(),
+        None,
+        HidingAuthorizer::new(),
+        TabularDeleteProfile::Hard {},
+        None,
+        1,
+        None,
+    )
+    .await;
+    let anonymous = |warehouses: Vec<WarehouseId>| {
+        RequestMetadataTestBuilder::builder()
+            .actor(Actor::Principal(UserId::new_unchecked(
+                "lakekeeper",
+                "anonymous",
+            )))
+            .anonymous_access(warehouses)
+            .build()
+    };
+
+    let config = CatalogServer::get_config(
+        config_query(&warehouse.project_id, &warehouse.warehouse_name),
+        ctx.clone(),
+        anonymous(vec![warehouse.warehouse_id]),
+    )
+    .await
+    .unwrap();
+    assert!(config.defaults.contains_key("prefix"));
+
+    CatalogServer::get_config(
+        config_query(&warehouse.project_id, &warehouse.warehouse_name),
+        ctx,
+        anonymous(vec![WarehouseId::new_random()]),
+    )
+    .await
+    .expect_err("warehouse not listed for anonymous access");
+}
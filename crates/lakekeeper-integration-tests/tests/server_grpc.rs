@@ -0,0 +1,195 @@
+#![cfg(feature = "grpc")]
+
+use std::collections::HashMap;
+
+use http::uri::PathAndQuery;
+use iceberg::{
+    NamespaceIdent, TableIdent, TableUpdate,
+    spec::{NestedField, PrimitiveType, Schema, TableMetadata, Type, UnboundPartitionSpec},
+};
+use iceberg_ext::catalog::rest::{CommitTableRequest, CreateTableRequest};
+use lakekeeper::{
+    api::{
+        grpc::{
+            CatalogGrpcService,
+            proto::{self, COMMIT_TABLE_PATH, LOAD_TABLE_PATH},
+        },
+        iceberg::v1::{
+            CreateNamespaceRequest, NamespaceParameters, TableParameters,
+            avro::{from_avro, to_avro},
+            namespace::NamespaceService as _,
+            tables::{
+                DataAccess, LoadTableRequest, LoadTableResultOrNotModified, TablesService as _,
+            },
+        },
+        management::v1::warehouse::TabularDeleteProfile,
+    },
+    server::CatalogServer,
+    service::authz::AllowAllAuthorizer,
+};
+use lakekeeper_integration_tests::{random_request_metadata, setup_simple};
+use sqlx::PgPool;
+use tonic_prost::ProstCodec;
+
+fn grpc_request<T>(message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    request.extensions_mut().insert(random_request_metadata());
+    request
+}
+
+#[sqlx::test]
+async fn test_grpc_load_and_commit_table(pool: PgPool) {
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    let prefix = warehouse.warehouse_id.to_string();
+    let namespace = NamespaceIdent::new("grpc_ns".to_string());
+    CatalogServer::create_namespace(
+        Some(prefix.clone().into()),
+        CreateNamespaceRequest {
+            namespace: namespace.clone(),
+            properties: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    CatalogServer::create_table(
+        NamespaceParameters {
+            prefix: Some(prefix.clone().into()),
+            namespace: namespace.clone(),
+        },
+        CreateTableRequest {
+            name: "grpc_table".to_string(),
+            location: None,
+            schema: Schema::builder()
+                .with_fields(vec![
+                    NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+                ])
+                .build()
+                .unwrap(),
+            partition_spec: Some(UnboundPartitionSpec::builder().build()),
+            write_order: None,
+            stage_create: Some(false),
+            properties: None,
+            table_uuid: None,
+        },
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let mut client = tonic::client::Grpc::new(CatalogGrpcService::new(ctx.clone()));
+    let table = proto::TableIdentifier {
+        namespace: namespace.clone().inner(),
+        name: "grpc_table".to_string(),
+    };
+
+    // Loading via gRPC returns the same metadata as the REST API
+    client.ready().await.unwrap();
+    let loaded = client
+        .unary(
+            grpc_request(proto::LoadTableRequest {
+                prefix: prefix.clone(),
+                table: Some(table.clone()),
+                ..Default::default()
+            }),
+            PathAndQuery::from_static(LOAD_TABLE_PATH),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+    let table_params = TableParameters {
+        prefix: Some(prefix.clone().into()),
+        table: TableIdent::new(namespace.clone(), "grpc_table".to_string()),
+    };
+    let LoadTableResultOrNotModified::LoadTableResult(rest_loaded) = CatalogServer::load_table(
+        table_params.clone(),
+        LoadTableRequest::builder().build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap() else {
+        panic!("Expected table to be loaded");
+    };
+    assert_eq!(loaded.metadata_location, rest_loaded.metadata_location);
+    assert_eq!(
+        from_avro::<TableMetadata>(&loaded.metadata).unwrap(),
+        *rest_loaded.metadata
+    );
+
+    // Commits via gRPC are visible to the REST API
+    client.ready().await.unwrap();
+    let committed = client
+        .unary(
+            grpc_request(proto::CommitTableRequest {
+                prefix: prefix.clone(),
+                table: Some(table.clone()),
+                commit: to_avro(&CommitTableRequest {
+                    identifier: None,
+                    requirements: vec![],
+                    updates: vec![TableUpdate::SetProperties {
+                        updates: HashMap::from([("owner".to_string(), "grpc".to_string())]),
+                    }],
+                })
+                .unwrap(),
+            }),
+            PathAndQuery::from_static(COMMIT_TABLE_PATH),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+    let LoadTableResultOrNotModified::LoadTableResult(rest_loaded) = CatalogServer::load_table(
+        table_params,
+        LoadTableRequest::builder().build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap() else {
+        panic!("Expected table to be loaded");
+    };
+    assert_eq!(
+        rest_loaded.metadata_location.as_deref(),
+        Some(committed.metadata_location.as_str())
+    );
+    assert_eq!(
+        rest_loaded
+            .metadata
+            .properties()
+            .get("owner")
+            .map(String::as_str),
+        Some("grpc")
+    );
+
+    // Errors map to gRPC status codes
+    client.ready().await.unwrap();
+    let status = client
+        .unary::<_, proto::LoadTableResponse, _>(
+            grpc_request(proto::LoadTableRequest {
+                prefix,
+                table: Some(proto::TableIdentifier {
+                    namespace: namespace.inner(),
+                    name: "missing".to_string(),
+                }),
+                ..Default::default()
+            }),
+            PathAndQuery::from_static(LOAD_TABLE_PATH),
+            ProstCodec::default(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
@@ -1,7 +1,10 @@
 // Extracted from crates/lakekeeper/src/server/tables/load_table.rs.
 // Original location was `#[cfg(any())] mod tests` (VAK-437 split).
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 use iceberg::{
     NamespaceIdent, TableIdent, TableUpdate,
@@ -12,24 +15,37 @@ use iceberg::{
 };
 use iceberg_ext::catalog::rest::{CreateTableRequest, LoadTableResult, create_etag};
 use lakekeeper::{
+    WarehouseId,
     api::{
         ApiContext,
         iceberg::v1::{
             NamespaceParameters, TableParameters,
             namespace::NamespaceService as _,
             tables::{
-                DataAccess, LoadTableFilters, LoadTableRequest, LoadTableResultOrNotModified,
-                SnapshotsQuery, TablesService as _,
+                DataAccess, DataAccessMode, LoadTableFilters, LoadTableRequest,
+                LoadTableResultOrNotModified, SnapshotsQuery, TablesService as _,
+            },
+        },
+        management::v1::{
+            ApiServer,
+            table::{
+                ListTableSnapshotsQuery, LoadTablesBatchRequest, RenamedColumn, RetypedColumn,
+                SchemaDiffColumn, TableManagementService as _, TableSchemaDiffQuery,
             },
+            warehouse::TabularDeleteProfile,
         },
-        management::v1::warehouse::TabularDeleteProfile,
     },
     server::{CatalogServer, tables::load_table::load_table},
-    service::{State, authz::AllowAllAuthorizer},
+    service::{State, TableId, authz::AllowAllAuthorizer},
 };
 use lakekeeper_integration_tests::{random_request_metadata, setup_simple};
-use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
-use sqlx::PgPool;
+use lakekeeper_storage_postgres::{CatalogState, PostgresBackend, SecretsState};
+use sqlx::{PgPool, postgres::PgPoolOptions};
+use tracing::span::{Attributes, Id};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt as _},
+    registry::LookupSpan,
+};
 
 fn create_test_schema() -> Schema {
     Schema::builder()
@@ -50,6 +66,7 @@ fn create_table_request(table_name: &str) -> CreateTableRequest {
         write_order: None,
         stage_create: Some(false),
         properties: None,
+        table_uuid: None,
     }
 }
 
@@ -196,6 +213,7 @@ async fn setup_table_with_snapshots(
     CatalogServer::commit_table(
         table_params.clone(),
         commit_request1,
+        vec![],
         ctx.clone(),
         random_request_metadata(),
     )
@@ -226,6 +244,7 @@ async fn setup_table_with_snapshots(
     CatalogServer::commit_table(
         table_params.clone(),
         commit_request2,
+        vec![],
         ctx.clone(),
         random_request_metadata(),
     )
@@ -256,6 +275,7 @@ async fn setup_table_with_snapshots(
     CatalogServer::commit_table(
         table_params.clone(),
         commit_request3,
+        vec![],
         ctx.clone(),
         random_request_metadata(),
     )
@@ -296,6 +316,7 @@ async fn setup_table_with_snapshots(
     CatalogServer::commit_table(
         table_params.clone(),
         commit_request_refs,
+        vec![],
         ctx.clone(),
         random_request_metadata(),
     )
@@ -529,6 +550,7 @@ async fn test_load_table_snapshots_filter_with_no_refs(pool: PgPool) {
     CatalogServer::commit_table(
         table_params.clone(),
         commit_request,
+        vec![],
         ctx.clone(),
         random_request_metadata(),
     )
@@ -669,6 +691,166 @@ async fn test_load_table_snapshots_filter_behavior_difference(pool: PgPool) {
     assert_eq!(diff, vec![1]); // Only snapshot 1 should be filtered out
 }
 
+#[sqlx::test]
+async fn test_load_table_snapshots_filter_none(pool: PgPool) {
+    let (ctx, ns_params, table_ident, _) = setup_table_with_snapshots(pool).await;
+
+    let table_params = TableParameters {
+        prefix: ns_params.prefix.clone(),
+        table: table_ident.clone(),
+    };
+
+    let result = CatalogServer::load_table(
+        table_params,
+        LoadTableRequest::builder()
+            .filters(LoadTableFilters {
+                snapshots: SnapshotsQuery::None,
+            })
+            .build(),
+        ctx,
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let LoadTableResultOrNotModified::LoadTableResult(result) = result else {
+        panic!("Expected LoadTableResult");
+    };
+
+    // Neither snapshots nor anything referencing them is returned
+    assert_eq!(result.metadata.snapshots().count(), 0);
+    assert!(result.metadata.current_snapshot().is_none());
+    assert!(result.metadata.refs().is_empty());
+    assert!(result.metadata.history().is_empty());
+    // The rest of the metadata is unaffected
+    assert_eq!(
+        result.metadata.current_schema().as_ref(),
+        &create_test_schema()
+    );
+}
+
+#[sqlx::test]
+async fn test_load_table_snapshots_filter_current(pool: PgPool) {
+    let (ctx, ns_params, table_ident, _) = setup_table_with_snapshots(pool).await;
+
+    let table_params = TableParameters {
+        prefix: ns_params.prefix.clone(),
+        table: table_ident.clone(),
+    };
+    let load = |snapshots| {
+        CatalogServer::load_table(
+            table_params.clone(),
+            LoadTableRequest::builder()
+                .filters(LoadTableFilters { snapshots })
+                .build(),
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+    let LoadTableResultOrNotModified::LoadTableResult(full) =
+        load(SnapshotsQuery::All).await.unwrap()
+    else {
+        panic!("Expected LoadTableResult");
+    };
+    let LoadTableResultOrNotModified::LoadTableResult(current) =
+        load(SnapshotsQuery::Current).await.unwrap()
+    else {
+        panic!("Expected LoadTableResult");
+    };
+
+    // Only the head of `main` is returned, the other snapshots and refs are omitted
+    let snapshots: Vec<i64> = current
+        .metadata
+        .snapshots()
+        .map(|s| s.snapshot_id())
+        .collect();
+    assert_eq!(snapshots, vec![2]);
+    assert_eq!(
+        current.metadata.refs().keys().collect::<Vec<_>>(),
+        vec![MAIN_BRANCH]
+    );
+    assert!(
+        current
+            .metadata
+            .history()
+            .iter()
+            .all(|log| log.snapshot_id == 2)
+    );
+
+    // The current state matches the full load
+    assert_eq!(current.metadata_location, full.metadata_location);
+    assert_eq!(
+        current.metadata.current_snapshot(),
+        full.metadata.current_snapshot()
+    );
+    assert_eq!(
+        current.metadata.current_schema(),
+        full.metadata.current_schema()
+    );
+    assert_eq!(
+        current.metadata.default_partition_spec(),
+        full.metadata.default_partition_spec()
+    );
+    assert_eq!(
+        current.metadata.default_sort_order(),
+        full.metadata.default_sort_order()
+    );
+    assert_eq!(current.metadata.properties(), full.metadata.properties());
+    assert_eq!(
+        current.metadata.last_sequence_number(),
+        full.metadata.last_sequence_number()
+    );
+}
+
+#[sqlx::test]
+async fn test_load_table_snapshots_filter_payload_sizes(pool: PgPool) {
+    let (ctx, ns_params, table_ident, _) = setup_table_with_snapshots(pool).await;
+
+    let table_params = TableParameters {
+        prefix: ns_params.prefix.clone(),
+        table: table_ident.clone(),
+    };
+
+    let mut payloads = Vec::new();
+    for snapshots in [
+        SnapshotsQuery::All,
+        SnapshotsQuery::Refs,
+        SnapshotsQuery::None,
+    ] {
+        let result = CatalogServer::load_table(
+            table_params.clone(),
+            LoadTableRequest::builder()
+                .filters(LoadTableFilters { snapshots })
+                .build(),
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        let LoadTableResultOrNotModified::LoadTableResult(result) = result else {
+            panic!("Expected LoadTableResult");
+        };
+        let payload = serde_json::to_value(&result).unwrap();
+        let snapshot_count = payload["metadata"]
+            .get("snapshots")
+            .and_then(serde_json::Value::as_array)
+            .map_or(0, Vec::len);
+        payloads.push((snapshot_count, payload.to_string().len()));
+    }
+
+    let [
+        (all_count, all_size),
+        (refs_count, refs_size),
+        (none_count, none_size),
+    ] = payloads[..]
+    else {
+        panic!("Expected three payloads");
+    };
+    assert_eq!((all_count, refs_count, none_count), (3, 2, 0));
+    assert!(all_size > refs_size);
+    assert!(refs_size > none_size);
+}
+
 #[sqlx::test]
 async fn test_load_table_returns_not_modified_with_single_matching_etag(pool: PgPool) {
     let (api_context, namespace_parameters, table_identifier, table) =
@@ -761,3 +943,500 @@ async fn test_load_table_returns_not_modified_when_given_wildcard(pool: PgPool)
         LoadTableResultOrNotModified::NotModifiedResponse(etag)
     );
 }
+
+/// Copy the current state of all relations into a `replica` schema and return a
+/// pool that reads from it, simulating a read replica that stopped replicating.
+async fn lagging_replica_pool(pool: &PgPool) -> PgPool {
+    sqlx::query("CREATE SCHEMA replica")
+        .execute(pool)
+        .await
+        .unwrap();
+    let relations: Vec<String> = sqlx::query_scalar(
+        "SELECT c.relname::text FROM pg_class c
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        WHERE n.nspname = 'public' AND c.relkind IN ('r', 'p', 'v', 'm')",
+    )
+    .fetch_all(pool)
+    .await
+    .unwrap();
+    for relation in relations {
+        sqlx::query(&format!(
+            r#"CREATE TABLE replica."{relation}" AS SELECT * FROM public."{relation}""#
+        ))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    let options = (*pool.connect_options())
+        .clone()
+        .options([("search_path", "replica,public")]);
+    PgPoolOptions::new().connect_with(options).await.unwrap()
+}
+
+#[sqlx::test]
+async fn test_load_table_read_after_write_bypasses_lagging_replica(pool: PgPool) {
+    let (ctx, ns_params, table_ident, table) = setup_simple_table(pool.clone()).await;
+    let table_params = TableParameters {
+        prefix: ns_params.prefix.clone(),
+        table: table_ident.clone(),
+    };
+
+    let mut lagging_ctx = ctx.clone();
+    lagging_ctx.v1_state.catalog =
+        CatalogState::from_pools(lagging_replica_pool(&pool).await, pool.clone());
+
+    let committed = CatalogServer::commit_table(
+        table_params.clone(),
+        iceberg_ext::catalog::rest::CommitTableRequest {
+            identifier: Some(table_ident.clone()),
+            requirements: vec![],
+            updates: vec![TableUpdate::SetProperties {
+                updates: HashMap::from([("key".to_string(), "value".to_string())]),
+            }],
+        },
+        vec![],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let load = |read_after_write: bool| {
+        Box::pin(load_table(
+            table_params.clone(),
+            LoadTableRequest::builder()
+                .read_after_write(read_after_write)
+                .build(),
+            lagging_ctx.clone(),
+            random_request_metadata(),
+        ))
+    };
+
+    // The replica still serves the metadata from before the commit.
+    let LoadTableResultOrNotModified::LoadTableResult(stale) = load(false).await.unwrap() else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    assert_eq!(stale.metadata_location, table.metadata_location);
+
+    let LoadTableResultOrNotModified::LoadTableResult(fresh) = load(true).await.unwrap() else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    assert_eq!(
+        fresh.metadata_location.as_deref(),
+        Some(committed.metadata_location.as_str())
+    );
+    assert_eq!(
+        fresh.metadata.properties().get("key").map(String::as_str),
+        Some("value")
+    );
+}
+
+#[sqlx::test]
+async fn test_list_table_snapshots_paginated(pool: PgPool) {
+    let (ctx, ns_params, _, table) = setup_table_with_snapshots(pool).await;
+    let warehouse_id =
+        WarehouseId::from_str_or_internal(ns_params.prefix.unwrap().as_str()).unwrap();
+    let table_id = TableId::from(table.metadata.uuid());
+
+    let mut page_token = None;
+    let mut snapshot_ids = Vec::new();
+    loop {
+        let page =
+            ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::list_table_snapshots(
+                table_id,
+                warehouse_id,
+                ListTableSnapshotsQuery {
+                    page_token: page_token.clone(),
+                    page_size: Some(1),
+                },
+                ctx.clone(),
+                random_request_metadata(),
+            )
+            .await
+            .unwrap();
+        if page.snapshots.is_empty() {
+            break;
+        }
+        assert_eq!(page.snapshots.len(), 1);
+        let snapshot = &page.snapshots[0];
+        assert_eq!(snapshot.summary.operation, Operation::Append);
+        assert_eq!(snapshot.schema_id, 0);
+        assert_eq!(snapshot.sequence_number, snapshot.snapshot_id);
+        snapshot_ids.push(snapshot.snapshot_id);
+        page_token = page.next_page_token;
+        assert!(page_token.is_some());
+    }
+
+    // Newest snapshot first
+    assert_eq!(snapshot_ids, vec![3, 2, 1]);
+
+    // A single large page returns all snapshots at once
+    let page =
+        ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::list_table_snapshots(
+            table_id,
+            warehouse_id,
+            ListTableSnapshotsQuery::default(),
+            ctx,
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.snapshots.len(), 3);
+    assert!(
+        page.snapshots
+            .windows(2)
+            .all(|w| w[0].timestamp_ms > w[1].timestamp_ms)
+    );
+}
+
+#[sqlx::test]
+async fn test_get_table_metadata_location(pool: PgPool) {
+    let (ctx, ns_params, table_ident, table) = setup_table_with_snapshots(pool).await;
+    let warehouse_id =
+        WarehouseId::from_str_or_internal(ns_params.prefix.clone().unwrap().as_str()).unwrap();
+
+    let loaded = CatalogServer::load_table(
+        TableParameters {
+            prefix: ns_params.prefix.clone(),
+            table: table_ident,
+        },
+        LoadTableRequest::builder().build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let LoadTableResultOrNotModified::LoadTableResult(loaded) = loaded else {
+        panic!("Expected LoadTableResult");
+    };
+
+    let location =
+        ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::get_table_metadata_location(
+            TableId::from(table.metadata.uuid()),
+            warehouse_id,
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+    assert!(!location.staged);
+    assert!(location.metadata_location.is_some());
+    assert_eq!(location.metadata_location, loaded.metadata_location);
+
+    // Staged tables have no metadata location yet
+    let mut staged_request = create_table_request("staged_table");
+    staged_request.stage_create = Some(true);
+    let staged = CatalogServer::create_table(
+        ns_params,
+        staged_request,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let location =
+        ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::get_table_metadata_location(
+            TableId::from(staged.metadata.uuid()),
+            warehouse_id,
+            ctx,
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+    assert!(location.staged);
+    assert_eq!(location.metadata_location, None);
+}
+
+#[sqlx::test]
+async fn test_touch_table(pool: PgPool) {
+    let (ctx, ns_params, table_ident, table) = setup_table_with_snapshots(pool).await;
+    let warehouse_id =
+        WarehouseId::from_str_or_internal(ns_params.prefix.clone().unwrap().as_str()).unwrap();
+    let table_id = TableId::from(table.metadata.uuid());
+    let load = || async move {
+        let loaded = CatalogServer::load_table(
+            TableParameters {
+                prefix: ns_params.prefix.clone(),
+                table: table_ident.clone(),
+            },
+            LoadTableRequest::builder().build(),
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        let LoadTableResultOrNotModified::LoadTableResult(loaded) = loaded else {
+            panic!("Expected LoadTableResult");
+        };
+        loaded
+    };
+
+    let before = load().await;
+    let updated_at_before =
+        ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::get_table_protection(
+            table_id,
+            warehouse_id,
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap()
+        .updated_at;
+
+    let touched = ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::touch_table(
+        table_id,
+        warehouse_id,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(updated_at_before.is_none_or(|before| touched.updated_at > before));
+
+    let touched_again =
+        ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::touch_table(
+            table_id,
+            warehouse_id,
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+    assert!(touched_again.updated_at > touched.updated_at);
+
+    // The metadata is left untouched.
+    let after = load().await;
+    assert_eq!(after.metadata_location, before.metadata_location);
+    assert_eq!(after.metadata, before.metadata);
+}
+
+#[sqlx::test]
+async fn test_get_table_schema_diff(pool: PgPool) {
+    let (ctx, ns_params, table_ident, table) = setup_table_with_snapshots(pool).await;
+    let warehouse_id =
+        WarehouseId::from_str_or_internal(ns_params.prefix.clone().unwrap().as_str()).unwrap();
+    let table_id = TableId::from(table.metadata.uuid());
+
+    // Evolve the schema: widen `id`, rename `name` and add `email`.
+    let evolved_schema = Schema::builder()
+        .with_schema_id(1)
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)).into(),
+            NestedField::required(2, "full_name", Type::Primitive(PrimitiveType::String)).into(),
+            NestedField::optional(3, "email", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()
+        .unwrap();
+    let snapshot = Snapshot::builder()
+        .with_snapshot_id(4)
+        .with_timestamp_ms(chrono::Utc::now().timestamp_millis() + 10_000)
+        .with_sequence_number(4)
+        .with_manifest_list("/path/to/manifest4.avro")
+        .with_summary(Summary {
+            operation: Operation::Append,
+            additional_properties: HashMap::new(),
+        })
+        .with_schema_id(1)
+        .build();
+    CatalogServer::commit_table(
+        TableParameters {
+            prefix: ns_params.prefix.clone(),
+            table: table_ident.clone(),
+        },
+        iceberg_ext::catalog::rest::CommitTableRequest {
+            identifier: Some(table_ident),
+            requirements: vec![],
+            updates: vec![
+                TableUpdate::AddSchema {
+                    schema: evolved_schema,
+                },
+                TableUpdate::SetCurrentSchema { schema_id: -1 },
+                TableUpdate::AddSnapshot { snapshot },
+            ],
+        },
+        vec![],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let schema_diff = |from, to| {
+        ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::get_table_schema_diff(
+            table_id,
+            warehouse_id,
+            TableSchemaDiffQuery { from, to },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+
+    let diff = schema_diff(1, 4).await.unwrap();
+    assert_eq!(diff.from_schema_id, 0);
+    assert_eq!(diff.to_schema_id, 1);
+    assert_eq!(
+        diff.added_columns,
+        vec![SchemaDiffColumn {
+            field_id: 3,
+            name: "email".to_string(),
+            r#type: "string".to_string(),
+        }]
+    );
+    assert!(diff.removed_columns.is_empty());
+    assert_eq!(
+        diff.renamed_columns,
+        vec![RenamedColumn {
+            field_id: 2,
+            from_name: "name".to_string(),
+            to_name: "full_name".to_string(),
+        }]
+    );
+    assert_eq!(
+        diff.retyped_columns,
+        vec![RetypedColumn {
+            field_id: 1,
+            name: "id".to_string(),
+            from_type: "int".to_string(),
+            to_type: "long".to_string(),
+        }]
+    );
+
+    // Diffing in the other direction swaps added and removed columns
+    let reverse = schema_diff(4, 1).await.unwrap();
+    assert!(reverse.added_columns.is_empty());
+    assert_eq!(reverse.removed_columns, diff.added_columns);
+
+    // Snapshots sharing a schema have an empty diff
+    let diff = schema_diff(1, 3).await.unwrap();
+    assert_eq!(diff.from_schema_id, 0);
+    assert_eq!(diff.to_schema_id, 0);
+    assert!(diff.added_columns.is_empty());
+    assert!(diff.removed_columns.is_empty());
+    assert!(diff.renamed_columns.is_empty());
+    assert!(diff.retyped_columns.is_empty());
+
+    let err = schema_diff(1, 42).await.unwrap_err();
+    assert_eq!(err.error.code, 404);
+}
+
+#[sqlx::test]
+async fn test_load_tables_batch_matches_individual_loads(pool: PgPool) {
+    let (ctx, ns_params, first_table, _) = setup_simple_table(pool).await;
+    let warehouse_id =
+        WarehouseId::from_str_or_internal(ns_params.prefix.clone().unwrap().as_str()).unwrap();
+
+    let mut tables = vec![first_table];
+    for name in ["second_table", "third_table"] {
+        CatalogServer::create_table(
+            ns_params.clone(),
+            create_table_request(name),
+            DataAccess::not_specified(),
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        tables.push(TableIdent::new(
+            ns_params.namespace.clone(),
+            name.to_string(),
+        ));
+    }
+    // Results must follow the request order, not the creation order.
+    tables.reverse();
+
+    let batch = ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::load_tables_batch(
+        warehouse_id,
+        LoadTablesBatchRequest {
+            tables: tables.clone(),
+        },
+        DataAccessMode::default(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(batch.tables.len(), 3);
+
+    for (table, batch_result) in tables.iter().zip(&batch.tables) {
+        let LoadTableResultOrNotModified::LoadTableResult(single_result) =
+            CatalogServer::load_table(
+                TableParameters {
+                    prefix: ns_params.prefix.clone(),
+                    table: table.clone(),
+                },
+                LoadTableRequest::builder().build(),
+                ctx.clone(),
+                random_request_metadata(),
+            )
+            .await
+            .unwrap()
+        else {
+            panic!("Expected LoadTableResult");
+        };
+        assert_eq!(batch_result, &single_result);
+    }
+
+    // Listing a table twice is rejected
+    let err = ApiServer::<PostgresBackend, AllowAllAuthorizer, SecretsState>::load_tables_batch(
+        warehouse_id,
+        LoadTablesBatchRequest {
+            tables: vec![tables[0].clone(), tables[0].clone()],
+        },
+        DataAccessMode::default(),
+        ctx,
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "DuplicateTableIdent");
+}
+
+/// Records the names of a span and all of its ancestors for every new span.
+#[derive(Clone, Default)]
+struct SpanRecorder(Arc<Mutex<Vec<Vec<&'static str>>>>);
+
+impl<S> tracing_subscriber::Layer<S> for SpanRecorder
+where
+    S: tracing::Subscriber + for<'span> LookupSpan<'span>,
+{
+    fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("New span must be registered");
+        let mut scope = span.scope().map(|span| span.name()).collect::<Vec<_>>();
+        // Only DB spans declare `db.system`
+        if span.metadata().fields().field("db.system").is_some() {
+            scope[0] = "db";
+        }
+        self.0.lock().unwrap().push(scope);
+    }
+}
+
+#[sqlx::test]
+async fn test_load_table_span_has_db_child_spans(pool: PgPool) {
+    let (ctx, ns_params, table_ident, _) = setup_simple_table(pool).await;
+
+    let recorder = SpanRecorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+    CatalogServer::load_table(
+        TableParameters {
+            prefix: ns_params.prefix,
+            table: table_ident,
+        },
+        LoadTableRequest::builder().build(),
+        ctx,
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let spans = recorder.0.lock().unwrap();
+    assert!(spans.iter().any(|scope| scope == &["load_table"]));
+    assert!(
+        spans
+            .iter()
+            .any(|scope| scope[0] == "db" && scope.contains(&"load_table")),
+        "Expected a DB span below the load_table span, got {spans:?}"
+    );
+}
@@ -0,0 +1,240 @@
+use std::{collections::HashMap, sync::Arc};
+
+use iceberg::{
+    NamespaceIdent, TableIdent, TableUpdate,
+    io::Storage as _,
+    spec::{
+        DataContentType, DataFileBuilder, DataFileFormat, MAIN_BRANCH, ManifestListWriter,
+        ManifestWriterBuilder, NestedField, Operation, PrimitiveType, Schema, Snapshot,
+        SnapshotReference, SnapshotRetention, Struct, Summary, Type, UnboundPartitionSpec,
+    },
+};
+use iceberg_ext::catalog::rest::{
+    CommitTableRequest, CreateTableRequest, FetchScanTasksRequest, PlanTableScanRequest,
+    PlanTableScanResult,
+};
+use lakekeeper::{
+    api::{
+        iceberg::v1::{
+            NamespaceParameters, TableParameters,
+            namespace::NamespaceService as _,
+            scan_planning::Service as _,
+            tables::{DataAccess, DataAccessMode, TablesService as _},
+        },
+        management::v1::warehouse::TabularDeleteProfile,
+    },
+    server::CatalogServer,
+    service::authz::AllowAllAuthorizer,
+};
+use lakekeeper_integration_tests::{random_request_metadata, setup_simple};
+use lakekeeper_io::iceberg_bridge::IcebergStorageBridge;
+use sqlx::PgPool;
+
+const SNAPSHOT_ID: i64 = 1;
+
+fn create_test_schema() -> Schema {
+    Schema::builder()
+        .with_fields(vec![
+            NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)).into(),
+            NestedField::required(2, "name", Type::Primitive(PrimitiveType::String)).into(),
+        ])
+        .build()
+        .unwrap()
+}
+
+/// Write a data manifest referencing `data_files` and return its manifest list entry.
+async fn write_manifest(
+    storage: &IcebergStorageBridge,
+    path: &str,
+    schema: &Schema,
+    data_files: &[String],
+) -> iceberg::spec::ManifestFile {
+    let mut writer = ManifestWriterBuilder::new(
+        storage.new_output(path).unwrap(),
+        Some(SNAPSHOT_ID),
+        None,
+        Arc::new(schema.clone()),
+        UnboundPartitionSpec::builder()
+            .build()
+            .bind(Arc::new(schema.clone()))
+            .unwrap(),
+    )
+    .build_v2_data();
+    for data_file in data_files {
+        writer
+            .add_file(
+                DataFileBuilder::default()
+                    .content(DataContentType::Data)
+                    .file_path(data_file.clone())
+                    .file_format(DataFileFormat::Parquet)
+                    .partition(Struct::empty())
+                    .partition_spec_id(0)
+                    .record_count(10)
+                    .file_size_in_bytes(1024)
+                    .build()
+                    .unwrap(),
+                1,
+            )
+            .unwrap();
+    }
+    writer.write_manifest_file().await.unwrap()
+}
+
+#[sqlx::test]
+async fn test_plan_table_scan_returns_files_of_all_manifests(pool: PgPool) {
+    let storage_profile = lakekeeper_integration_tests::memory_io_profile();
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        storage_profile.clone(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    let ns_name = NamespaceIdent::new("test_namespace".to_string());
+    let ns_params = NamespaceParameters {
+        namespace: ns_name.clone(),
+        prefix: Some(warehouse.warehouse_id.to_string().into()),
+    };
+    CatalogServer::create_namespace(
+        ns_params.prefix.clone(),
+        lakekeeper::api::iceberg::v1::CreateNamespaceRequest {
+            namespace: ns_name.clone(),
+            properties: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let schema = create_test_schema();
+    let table = CatalogServer::create_table(
+        ns_params.clone(),
+        CreateTableRequest {
+            name: "scanned".to_string(),
+            location: None,
+            schema: schema.clone(),
+            partition_spec: Some(UnboundPartitionSpec::builder().build()),
+            write_order: None,
+            stage_create: Some(false),
+            properties: None,
+            table_uuid: None,
+        },
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let table_location = table.metadata.location().trim_end_matches('/').to_string();
+    let schema = table.metadata.current_schema().as_ref().clone();
+
+    // Write two manifests with two and one data file and a manifest list
+    // referencing both.
+    let storage = IcebergStorageBridge::new(Arc::new(storage_profile.file_io(None).await.unwrap()));
+    let data_files = [
+        vec![
+            format!("{table_location}/data/file-1.parquet"),
+            format!("{table_location}/data/file-2.parquet"),
+        ],
+        vec![format!("{table_location}/data/file-3.parquet")],
+    ];
+    let mut manifests = Vec::new();
+    for (i, files) in data_files.iter().enumerate() {
+        let path = format!("{table_location}/metadata/manifest-{i}.avro");
+        manifests.push(write_manifest(&storage, &path, &schema, files).await);
+    }
+    let manifest_list_path = format!("{table_location}/metadata/snap-{SNAPSHOT_ID}.avro");
+    let mut manifest_list_writer = ManifestListWriter::v2(
+        storage.new_output(&manifest_list_path).unwrap(),
+        SNAPSHOT_ID,
+        None,
+        1,
+    );
+    manifest_list_writer
+        .add_manifests(manifests.into_iter())
+        .unwrap();
+    manifest_list_writer.close().await.unwrap();
+
+    let table_ident = TableIdent::new(ns_name, "scanned".to_string());
+    let table_params = TableParameters {
+        prefix: Some(warehouse.warehouse_id.to_string().into()),
+        table: table_ident.clone(),
+    };
+    let snapshot = Snapshot::builder()
+        .with_snapshot_id(SNAPSHOT_ID)
+        .with_timestamp_ms(chrono::Utc::now().timestamp_millis())
+        .with_sequence_number(1)
+        .with_manifest_list(manifest_list_path)
+        .with_summary(Summary {
+            operation: Operation::Append,
+            additional_properties: HashMap::new(),
+        })
+        .with_schema_id(schema.schema_id())
+        .build();
+    CatalogServer::commit_table(
+        table_params.clone(),
+        CommitTableRequest {
+            identifier: Some(table_ident.clone()),
+            requirements: vec![],
+            updates: vec![
+                TableUpdate::AddSnapshot { snapshot },
+                TableUpdate::SetSnapshotRef {
+                    ref_name: MAIN_BRANCH.to_string(),
+                    reference: SnapshotReference {
+                        snapshot_id: SNAPSHOT_ID,
+                        retention: SnapshotRetention::Branch {
+                            min_snapshots_to_keep: None,
+                            max_snapshot_age_ms: None,
+                            max_ref_age_ms: None,
+                        },
+                    },
+                },
+            ],
+        },
+        vec![],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    // One plan task per manifest
+    let PlanTableScanResult::Completed(plan) = CatalogServer::plan_table_scan(
+        table_params.clone(),
+        PlanTableScanRequest::default(),
+        DataAccessMode::default(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(plan.scan_tasks.plan_tasks.len(), 2);
+    assert!(plan.scan_tasks.file_scan_tasks.is_empty());
+
+    // Fetching all plan tasks yields every data file of the snapshot
+    let mut scanned_files = Vec::new();
+    for plan_task in plan.scan_tasks.plan_tasks {
+        let tasks = CatalogServer::fetch_scan_tasks(
+            table_params.clone(),
+            FetchScanTasksRequest { plan_task },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        for task in tasks.scan_tasks.file_scan_tasks {
+            assert_eq!(task.data_file.file_format, "parquet");
+            assert_eq!(task.data_file.record_count, 10);
+            assert!(task.data_file.partition.is_empty());
+            scanned_files.push(task.data_file.file_path);
+        }
+    }
+    scanned_files.sort();
+    let mut expected_files = data_files.concat();
+    expected_files.sort();
+    assert_eq!(scanned_files, expected_files);
+}
@@ -5,16 +5,17 @@ use std::{collections::HashMap, sync::Arc};
 
 use http::StatusCode;
 use iceberg::{
-    NamespaceIdent, TableIdent, TableUpdate,
+    NamespaceIdent, TableIdent, TableRequirement, TableUpdate,
     spec::{
-        EncryptedKey, FormatVersion, MAIN_BRANCH, NestedField, Operation, PrimitiveType, Schema,
-        Snapshot, SnapshotReference, SnapshotRetention, Summary, TableMetadata, TableProperties,
-        Transform, Type, UnboundPartitionField, UnboundPartitionSpec,
+        BlobMetadata, EncryptedKey, FormatVersion, MAIN_BRANCH, NestedField, Operation,
+        PrimitiveType, Schema, Snapshot, SnapshotReference, SnapshotRetention, StatisticsFile,
+        Summary, TableMetadata, TableProperties, Transform, Type, UnboundPartitionField,
+        UnboundPartitionSpec,
     },
 };
 use iceberg_ext::catalog::rest::{
     CommitTableRequest, CommitTransactionRequest, CreateNamespaceResponse, CreateTableRequest,
-    LoadTableResult, RenameTableRequest,
+    ETag, LoadTableResult, RenameTableRequest,
 };
 use itertools::Itertools;
 use lakekeeper::{
@@ -26,6 +27,7 @@ use lakekeeper::{
             v1::{
                 DataAccess, DropParams, ListTablesQuery, LoadTableResultOrNotModified,
                 NamespaceParameters, TableParameters,
+                namespace::NamespaceService as _,
                 tables::{LoadTableRequest, TablesService as _},
             },
         },
@@ -107,6 +109,7 @@ fn create_table_request_with_format(
         write_order: None,
         stage_create: Some(false),
         properties,
+        table_uuid: None,
     }
 }
 
@@ -282,6 +285,115 @@ async fn test_set_properties_commit_table(pool: sqlx::PgPool) {
     assert_table_metadata_are_equal(&table_metadata.metadata, &tab.metadata);
 }
 
+/// A multi-table commit is atomic: if the requirements of one table are not met,
+/// e.g. because it was changed concurrently, none of the tables are changed.
+#[sqlx::test]
+async fn test_commit_transaction_with_conflict_changes_no_table(pool: PgPool) {
+    let (ctx, ns, ns_params, table_1) = commit_test_setup(pool).await;
+    let table_2 = CatalogServer::create_table(
+        ns_params.clone(),
+        create_request(Some("tab-2".to_string()), Some(false)),
+        DataAccess::not_specified(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    let table_ident = |name: &str| TableIdent {
+        namespace: ns.namespace.clone(),
+        name: name.to_string(),
+    };
+    let updates = vec![TableUpdate::SetProperties {
+        updates: HashMap::from([("p1".to_string(), "v1".to_string())]),
+    }];
+
+    let result = commit_tables_with_authz(
+        ns_params.prefix.clone(),
+        CommitTransactionRequest {
+            table_changes: vec![
+                CommitTableRequest {
+                    identifier: Some(table_ident("tab-1")),
+                    requirements: vec![TableRequirement::UuidMatch {
+                        uuid: table_1.metadata.uuid(),
+                    }],
+                    updates: updates.clone(),
+                },
+                CommitTableRequest {
+                    identifier: Some(table_ident("tab-2")),
+                    // The table has no snapshot yet, so this requirement is not met.
+                    requirements: vec![TableRequirement::RefSnapshotIdMatch {
+                        r#ref: MAIN_BRANCH.to_string(),
+                        snapshot_id: Some(1),
+                    }],
+                    updates,
+                },
+            ],
+        },
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+        None,
+    )
+    .await;
+    let err = result.unwrap_err();
+    assert_eq!(err.error.code, StatusCode::CONFLICT.as_u16());
+
+    for (name, before) in [("tab-1", &table_1), ("tab-2", &table_2)] {
+        let after = load_table(&ctx, &ns_params, name).await;
+        assert_eq!(after.metadata_location, before.metadata_location);
+        assert!(!after.metadata.properties().contains_key("p1"));
+    }
+}
+
+#[sqlx::test]
+async fn test_load_table_with_previous_etag_is_not_modified(pool: PgPool) {
+    let (ctx, ns, ns_params, _) = commit_test_setup(pool).await;
+    let table_ident = TableIdent {
+        namespace: ns.namespace.clone(),
+        name: "tab-1".to_string(),
+    };
+    let load = |etags: Vec<ETag>| {
+        CatalogServer::load_table(
+            TableParameters {
+                prefix: ns_params.prefix.clone(),
+                table: table_ident.clone(),
+            },
+            LoadTableRequest::builder().etags(etags).build(),
+            ctx.clone(),
+            RequestMetadata::new_unauthenticated(),
+        )
+    };
+
+    let LoadTableResultOrNotModified::LoadTableResult(tab) = load(vec![]).await.unwrap() else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    let etag = tab.etag().unwrap();
+    // Clients echo the ETag without quotes after parsing `If-None-Match`
+    let client_etag = ETag::from(etag.as_str().trim_matches('"'));
+
+    let LoadTableResultOrNotModified::NotModifiedResponse(not_modified_etag) =
+        load(vec![client_etag.clone()]).await.unwrap()
+    else {
+        panic!("Expected NotModified for a repeated load with the previous ETag");
+    };
+    assert_eq!(not_modified_etag, etag);
+
+    // After a commit the previous ETag no longer matches
+    commit_table_changes(
+        &ctx,
+        &ns_params,
+        &table_ident,
+        vec![TableUpdate::SetProperties {
+            updates: HashMap::from([("p1".to_string(), "v1".to_string())]),
+        }],
+    )
+    .await;
+    let LoadTableResultOrNotModified::LoadTableResult(tab) = load(vec![client_etag]).await.unwrap()
+    else {
+        panic!("Expected LoadTableResult after the table changed");
+    };
+    assert_ne!(tab.etag().unwrap(), etag);
+}
+
 fn schema() -> Schema {
     Schema::builder()
         .with_fields(vec![
@@ -962,6 +1074,7 @@ async fn test_v2_to_v3_migration(pg_pool: PgPool) {
         write_order: None,
         stage_create: Some(false),
         properties: None, // No format version specified, should default to V2
+        table_uuid: None,
     };
 
     let table = CatalogServer::create_table(
@@ -1427,6 +1540,85 @@ async fn test_remove_snapshot_commit(pg_pool: PgPool) {
     assert_table_metadata_are_equal(&builder.metadata, &tab.metadata);
 }
 
+#[sqlx::test]
+async fn test_set_statistics_commit(pg_pool: PgPool) {
+    let (ctx, ns, ns_params, table) = commit_test_setup(pg_pool).await;
+    let table_ident = TableIdent {
+        namespace: ns.namespace.clone(),
+        name: "tab-1".to_string(),
+    };
+
+    let snapshot = Snapshot::builder()
+        .with_snapshot_id(1)
+        .with_timestamp_ms(table.metadata.last_updated_ms() + 1)
+        .with_sequence_number(1)
+        .with_schema_id(0)
+        .with_manifest_list("/snap-1.avro")
+        .with_summary(Summary {
+            operation: Operation::Append,
+            additional_properties: HashMap::new(),
+        })
+        .build();
+    let statistics = |path: &str| StatisticsFile {
+        snapshot_id: 1,
+        statistics_path: format!("{}/metadata/{path}", table.metadata.location()),
+        file_size_in_bytes: 1024,
+        file_footer_size_in_bytes: 128,
+        key_metadata: None,
+        blob_metadata: vec![BlobMetadata {
+            r#type: "apache-datasketches-theta-v1".to_string(),
+            snapshot_id: 1,
+            sequence_number: 1,
+            fields: vec![1],
+            properties: HashMap::from([("ndv".to_string(), "42".to_string())]),
+        }],
+    };
+
+    commit_table_changes(
+        &ctx,
+        &ns_params,
+        &table_ident,
+        vec![
+            TableUpdate::AddSnapshot { snapshot },
+            TableUpdate::SetStatistics {
+                statistics: statistics("stats-1.puffin"),
+            },
+        ],
+    )
+    .await;
+    let loaded = load_table(&ctx, &ns_params, "tab-1").await;
+    assert_eq!(
+        loaded.metadata.statistics_for_snapshot(1),
+        Some(&statistics("stats-1.puffin"))
+    );
+
+    // Setting statistics for the same snapshot replaces the previous file.
+    commit_table_changes(
+        &ctx,
+        &ns_params,
+        &table_ident,
+        vec![TableUpdate::SetStatistics {
+            statistics: statistics("stats-2.puffin"),
+        }],
+    )
+    .await;
+    let loaded = load_table(&ctx, &ns_params, "tab-1").await;
+    assert_eq!(
+        loaded.metadata.statistics_iter().collect_vec(),
+        vec![&statistics("stats-2.puffin")]
+    );
+
+    commit_table_changes(
+        &ctx,
+        &ns_params,
+        &table_ident,
+        vec![TableUpdate::RemoveStatistics { snapshot_id: 1 }],
+    )
+    .await;
+    let loaded = load_table(&ctx, &ns_params, "tab-1").await;
+    assert_eq!(loaded.metadata.statistics_iter().count(), 0);
+}
+
 async fn commit_test_setup(
     pool: PgPool,
 ) -> (
@@ -1575,6 +1767,45 @@ async fn test_cannot_create_table_at_same_location(pool: PgPool) {
     assert_eq!(e.error.r#type.as_str(), "LocationAlreadyTaken");
 }
 
+#[sqlx::test]
+async fn test_location_conflict_reports_conflicting_table(pool: PgPool) {
+    let (ctx, _, ns_params, base_location) = table_test_setup(pool).await;
+    let tmp_id = Uuid::now_v7();
+    let mut create_request_1 = create_request(Some("tab-1".to_string()), Some(false));
+    create_request_1.location = Some(format!("{base_location}/{tmp_id}/bucket"));
+    let mut create_request_2 = create_request(Some("tab-2".to_string()), Some(false));
+    create_request_2.location = Some(format!("{base_location}/{tmp_id}/bucket/inner"));
+
+    let existing = CatalogServer::create_table(
+        ns_params.clone(),
+        create_request_1,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+
+    let e = CatalogServer::create_table(
+        ns_params.clone(),
+        create_request_2,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .expect_err("Table was created inside the location of another table");
+    assert_eq!(e.error.code, StatusCode::CONFLICT, "{e:?}");
+    assert_eq!(e.error.r#type.as_str(), "LocationAlreadyTaken");
+    assert!(
+        e.error
+            .message
+            .contains(&format!("Table '{}'", existing.metadata.uuid())),
+        "{}",
+        e.error.message
+    );
+}
+
 #[sqlx::test]
 async fn test_cannot_create_staged_tables_at_sublocations_1(pool: PgPool) {
     let (ctx, _, ns_params, base_location) = table_test_setup(pool).await;
@@ -2350,6 +2581,53 @@ async fn test_rename_table_without_target_namespace(pool: sqlx::PgPool) {
     assert_eq!(response.error.r#type, "NoSuchNamespaceException");
 }
 
+#[sqlx::test]
+async fn test_create_table_in_missing_namespace_does_not_create_namespace(pool: sqlx::PgPool) {
+    let prof = memory_io_profile();
+    let (ctx, warehouse) = setup_simple(
+        pool.clone(),
+        prof,
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        Some(UserId::new_unchecked("oidc", "test-user-id")),
+    )
+    .await;
+    let missing_ns = NamespaceIdent::new("mistyped_ns".to_string());
+    let ns_params = NamespaceParameters {
+        prefix: Some(Prefix(warehouse.warehouse_id.to_string())),
+        namespace: missing_ns.clone(),
+    };
+
+    // Namespaces are never created implicitly when creating a table.
+    let response = CatalogServer::create_table(
+        ns_params,
+        create_request(Some("my_table".to_string()), Some(false)),
+        DataAccess {
+            vended_credentials: true,
+            remote_signing: false,
+        },
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(response.error.code, StatusCode::NOT_FOUND);
+    assert_eq!(response.error.r#type, "NoSuchNamespaceException");
+
+    let response = CatalogServer::namespace_exists(
+        NamespaceParameters {
+            prefix: Some(Prefix(warehouse.warehouse_id.to_string())),
+            namespace: missing_ns,
+        },
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(response.error.code, StatusCode::NOT_FOUND);
+}
+
 #[sqlx::test]
 async fn test_rename_table_without_source_table(pool: sqlx::PgPool) {
     let prof = memory_io_profile();
@@ -2523,6 +2801,162 @@ async fn test_register_table_with_overwrite(pool: PgPool) {
     assert_ne!(loaded_table.metadata.uuid(), initial_table.metadata.uuid());
 }
 
+#[sqlx::test]
+async fn test_register_table_and_load(pool: PgPool) {
+    let (ctx, ns, ns_params, _) = table_test_setup(pool).await;
+
+    let original = CatalogServer::create_table(
+        ns_params.clone(),
+        create_request(Some("original".to_string()), Some(false)),
+        DataAccess::not_specified(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    let metadata_location = original.metadata_location.clone().unwrap();
+
+    // Drop the table but keep its files so the metadata can be registered again
+    CatalogServer::drop_table(
+        TableParameters {
+            prefix: ns_params.prefix.clone(),
+            table: TableIdent {
+                namespace: ns.namespace.clone(),
+                name: "original".to_string(),
+            },
+        },
+        DropParams {
+            purge_requested: false,
+            force: false,
+        },
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+
+    // A metadata file that does not exist cannot be registered
+    let missing_location = metadata_location.replace(".metadata.json", "-missing.metadata.json");
+    CatalogServer::register_table(
+        ns_params.clone(),
+        iceberg_ext::catalog::rest::RegisterTableRequest::builder()
+            .name("registered".to_string())
+            .metadata_location(missing_location)
+            .build(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .expect_err("Registering a missing metadata file should fail");
+
+    let registered = CatalogServer::register_table(
+        ns_params.clone(),
+        iceberg_ext::catalog::rest::RegisterTableRequest::builder()
+            .name("registered".to_string())
+            .metadata_location(metadata_location.clone())
+            .build(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        registered.metadata_location.as_deref(),
+        Some(metadata_location.as_str())
+    );
+
+    let loaded_table = CatalogServer::load_table(
+        TableParameters {
+            prefix: ns_params.prefix,
+            table: TableIdent {
+                namespace: ns.namespace.clone(),
+                name: "registered".to_string(),
+            },
+        },
+        LoadTableRequest::builder().build(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    let LoadTableResultOrNotModified::LoadTableResult(loaded_table) = loaded_table else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+
+    assert_eq!(loaded_table.metadata.uuid(), original.metadata.uuid());
+    assert_eq!(
+        loaded_table.metadata.location(),
+        original.metadata.location()
+    );
+    assert_eq!(
+        loaded_table.metadata_location.as_deref(),
+        Some(metadata_location.as_str())
+    );
+}
+
+#[sqlx::test]
+async fn test_cannot_register_table_at_taken_location(pool: PgPool) {
+    let (ctx, ns, ns_params, base_location) = table_test_setup(pool).await;
+    let location = format!("{base_location}/{}/bucket", Uuid::now_v7());
+
+    let mut create_original = create_request(Some("original".to_string()), Some(false));
+    create_original.location = Some(location.clone());
+    let original = CatalogServer::create_table(
+        ns_params.clone(),
+        create_original,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+
+    CatalogServer::drop_table(
+        TableParameters {
+            prefix: ns_params.prefix.clone(),
+            table: TableIdent {
+                namespace: ns.namespace.clone(),
+                name: "original".to_string(),
+            },
+        },
+        DropParams {
+            purge_requested: false,
+            force: false,
+        },
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+
+    // Another table now occupies the location of the dropped table
+    let mut create_occupant = create_request(Some("occupant".to_string()), Some(false));
+    create_occupant.location = Some(location);
+    CatalogServer::create_table(
+        ns_params.clone(),
+        create_occupant,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+
+    let e = CatalogServer::register_table(
+        ns_params.clone(),
+        iceberg_ext::catalog::rest::RegisterTableRequest::builder()
+            .name("registered".to_string())
+            .metadata_location(original.metadata_location.unwrap())
+            .build(),
+        ctx.clone(),
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .expect_err("Table was registered at a taken location which should not be possible");
+    assert_eq!(e.error.code, StatusCode::CONFLICT, "{e:?}");
+    assert_eq!(e.error.r#type.as_str(), "LocationAlreadyTaken");
+}
+
 // Reasons for using a mix of PostgresCatalog and CatalogServer:
 //
 // - PostgresCatalog: required for specifying id of table to be created
@@ -2712,3 +3146,166 @@ async fn test_reuse_table_ids_soft_delete(pool: PgPool) {
         .expect("table and metadata should still exist");
     }
 }
+
+#[sqlx::test]
+async fn test_commit_table_if_match_rejects_stale_writer(pool: PgPool) {
+    let (ctx, warehouse) = setup_simple(
+        pool,
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+    let prefix = warehouse.warehouse_id.to_string();
+    create_ns(ctx.clone(), prefix.clone(), "ns1".to_string()).await;
+    let created =
+        lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns1", "t1", false)
+            .await
+            .unwrap();
+    let initial_location = created.metadata_location.unwrap();
+
+    let table_params = TableParameters {
+        prefix: Some(Prefix(prefix.clone())),
+        table: TableIdent::new(NamespaceIdent::new("ns1".to_string()), "t1".to_string()),
+    };
+    let set_property = |value: &str| CommitTableRequest {
+        identifier: Some(table_params.table.clone()),
+        requirements: vec![],
+        updates: vec![TableUpdate::SetProperties {
+            updates: HashMap::from([("writer".to_string(), value.to_string())]),
+        }],
+    };
+
+    // Both writers read the initial metadata. The first one to commit wins.
+    let first = CatalogServer::commit_table(
+        table_params.clone(),
+        set_property("a"),
+        vec![initial_location.as_str().into()],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_ne!(first.metadata_location, initial_location);
+
+    let err = CatalogServer::commit_table(
+        table_params.clone(),
+        set_property("b"),
+        vec![initial_location.as_str().into()],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, StatusCode::PRECONDITION_FAILED.as_u16());
+    assert_eq!(err.error.r#type, "PreconditionFailed");
+
+    // The stale commit was not applied.
+    let LoadTableResultOrNotModified::LoadTableResult(loaded) = CatalogServer::load_table(
+        table_params.clone(),
+        LoadTableRequest::builder().build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap() else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    assert_eq!(
+        loaded.metadata_location.as_deref(),
+        Some(first.metadata_location.as_str())
+    );
+    assert_eq!(loaded.metadata.properties().get("writer").unwrap(), "a");
+
+    // After reloading, the second writer can commit against the current metadata.
+    CatalogServer::commit_table(
+        table_params,
+        set_property("b"),
+        vec![first.metadata_location.as_str().into()],
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+}
+
+#[sqlx::test]
+async fn test_create_table_with_supplied_uuid(pool: PgPool) {
+    let (ctx, _, ns_params, _) = table_test_setup(pool).await;
+    let warehouse_id =
+        WarehouseId::from_str_or_internal(ns_params.prefix.clone().unwrap().as_str()).unwrap();
+    let table_uuid = Uuid::new_v4();
+
+    let mut request = create_request(Some("tab-1".to_string()), Some(false));
+    request.table_uuid = Some(table_uuid);
+    let created = CatalogServer::create_table(
+        ns_params.clone(),
+        request,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(created.metadata.uuid(), table_uuid);
+
+    // Load the table back by the supplied id
+    let table_info = PostgresBackend::get_table_info(
+        warehouse_id,
+        TableId::from(table_uuid),
+        TabularListFlags::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(table_info.tabular_ident.name, "tab-1");
+
+    let loaded = CatalogServer::load_table(
+        TableParameters {
+            prefix: ns_params.prefix.clone(),
+            table: TableIdent::new(ns_params.namespace.clone(), "tab-1".to_string()),
+        },
+        LoadTableRequest::builder().build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let LoadTableResultOrNotModified::LoadTableResult(loaded) = loaded else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    assert_eq!(loaded.metadata.uuid(), table_uuid);
+
+    // The id cannot be used twice
+    let mut request = create_request(Some("tab-2".to_string()), Some(false));
+    request.table_uuid = Some(table_uuid);
+    let err = CatalogServer::create_table(
+        ns_params.clone(),
+        request,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, StatusCode::CONFLICT, "{err:?}");
+    assert_eq!(err.error.r#type, "TableUuidAlreadyExists");
+
+    // Only version 4 and version 7 UUIDs are accepted
+    let mut request = create_request(Some("tab-3".to_string()), Some(false));
+    request.table_uuid = Some(Uuid::nil());
+    let err = CatalogServer::create_table(
+        ns_params,
+        request,
+        DataAccess::not_specified(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, StatusCode::BAD_REQUEST, "{err:?}");
+    assert_eq!(err.error.r#type, "InvalidTableUuid");
+}
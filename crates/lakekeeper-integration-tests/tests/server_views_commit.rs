@@ -8,11 +8,14 @@ use lakekeeper::{
         RequestMetadata,
         iceberg::{
             types::{DropParams, Prefix},
-            v1::{DataAccess, ViewParameters},
+            v1::{DataAccess, DataAccessMode, ViewParameters, views::LoadViewRequest},
+        },
+        management::v1::{
+            ApiServer as ManagementApiServer,
+            view::{RollbackViewRequest, ViewManagementService},
         },
-        management::v1::{ApiServer as ManagementApiServer, view::ViewManagementService},
     },
-    server::views::{commit::commit_view, drop::drop_view},
+    server::views::{commit::commit_view, drop::drop_view, load::load_view},
 };
 use lakekeeper_integration_tests::{
     create_view_helper, create_view_request, random_request_metadata, views_test_setup,
@@ -185,6 +188,116 @@ async fn test_commit_view_fails_with_wrong_assertion(pool: PgPool) {
     assert_eq!(err.error.r#type, "ViewUuidMismatch");
 }
 
+#[sqlx::test]
+async fn test_rollback_view_restores_previous_version(pool: PgPool) {
+    let (api_context, namespace, whi, _) = views_test_setup(pool, None).await;
+    let prefix = whi.to_string();
+    let view_name = "myview";
+    let view_parameters = ViewParameters {
+        prefix: Some(Prefix(prefix.clone())),
+        view: TableIdent::from_strs(namespace.inner().into_iter().chain([view_name.into()]))
+            .unwrap(),
+    };
+    let created = create_view_helper(
+        api_context.clone(),
+        namespace.clone(),
+        create_view_request(Some(view_name), None),
+        Some(prefix.clone()),
+    )
+    .await
+    .unwrap();
+    let view_id = created.metadata.uuid().into();
+    let initial_version = created.metadata.current_version().clone();
+    let initial_schema = created.metadata.current_schema().clone();
+
+    let committed = Box::pin(commit_view(
+        view_parameters.clone(),
+        spark_commit_update_request(whi, Some(created.metadata.uuid())),
+        api_context.clone(),
+        DataAccess {
+            vended_credentials: true,
+            remote_signing: false,
+        },
+        RequestMetadata::new_unauthenticated(),
+    ))
+    .await
+    .unwrap();
+    assert_ne!(
+        committed.metadata.current_version().representations(),
+        initial_version.representations()
+    );
+
+    let versions = ManagementApiServer::get_view_versions(
+        view_id,
+        whi,
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(versions.current_version_id, 2);
+    assert_eq!(
+        versions
+            .versions
+            .iter()
+            .map(|v| v.version_id())
+            .collect::<Vec<_>>(),
+        vec![initial_version.version_id(), 2]
+    );
+
+    let err = ManagementApiServer::rollback_view(
+        view_id,
+        whi,
+        RollbackViewRequest { version_id: 42 },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .expect_err("Rollback to a missing version must fail");
+    assert_eq!(err.error.code, StatusCode::NOT_FOUND);
+    assert_eq!(err.error.r#type, "ViewVersionNotFound");
+
+    let rolled_back = Box::pin(ManagementApiServer::rollback_view(
+        view_id,
+        whi,
+        RollbackViewRequest {
+            version_id: initial_version.version_id(),
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    ))
+    .await
+    .unwrap();
+    assert_eq!(rolled_back.current_version_id, initial_version.version_id());
+    assert_eq!(
+        rolled_back.version_log.last().map(|l| l.version_id()),
+        Some(initial_version.version_id())
+    );
+
+    let loaded = load_view(
+        view_parameters,
+        LoadViewRequest {
+            data_access: DataAccessMode::ClientManaged,
+            referenced_by: None,
+        },
+        api_context,
+        RequestMetadata::new_unauthenticated(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        loaded.metadata.current_version().representations(),
+        initial_version.representations()
+    );
+    assert_eq!(
+        loaded.metadata.current_version().schema_id(),
+        initial_version.schema_id()
+    );
+    assert_eq!(loaded.metadata.current_schema(), &initial_schema);
+    // The newer version is retained and can be rolled forward again.
+    assert_eq!(loaded.metadata.versions().len(), 2);
+}
+
 fn spark_commit_update_request(
     warehouse_id: WarehouseId,
     asserted_uuid: Option<Uuid>,
@@ -6,13 +6,18 @@ use lakekeeper::{
             types::{PageToken, Prefix},
             v1::{DataAccess, ListTablesQuery, NamespaceParameters, views::ViewService},
         },
-        management::v1::warehouse::TabularDeleteProfile,
+        management::v1::{
+            ApiServer as ManagementApiServer,
+            view::{ListViewsQuery, ViewManagementService},
+            warehouse::TabularDeleteProfile,
+        },
     },
-    server::CatalogServer,
-    service::{State, UserId, authz::tests::HidingAuthorizer},
+    server::{CatalogServer, NAMESPACE_ID_PROPERTY},
+    service::{NamespaceId, State, UserId, authz::tests::HidingAuthorizer},
 };
 use lakekeeper_integration_tests::{
-    create_ns, create_view_request, impl_pagination_tests, memory_io_profile, setup_simple,
+    create_ns, create_table, create_view_request, impl_pagination_tests, memory_io_profile,
+    random_request_metadata, setup_simple,
 };
 use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
 use sqlx::PgPool;
@@ -453,3 +458,120 @@ async fn test_view_pagination_no_duplicates(pool: sqlx::PgPool) {
         all_names.iter().duplicates().collect::<Vec<_>>()
     );
 }
+
+#[sqlx::test]
+async fn test_management_list_views(pool: sqlx::PgPool) {
+    let authz = HidingAuthorizer::new();
+    // Prevent hidden views from becoming visible through `can_list_everything`.
+    authz.block_can_list_everything();
+
+    let (ctx, warehouse) = setup_simple(
+        pool.clone(),
+        memory_io_profile(),
+        None,
+        authz.clone(),
+        TabularDeleteProfile::Hard {},
+        Some(UserId::new_unchecked("oidc", "test-user-id")),
+    )
+    .await;
+    let warehouse_id = warehouse.warehouse_id;
+    let mut namespace_ids = Vec::new();
+    let mut expected = Vec::new();
+    for ns_name in ["ns1", "ns2"] {
+        let ns = create_ns(ctx.clone(), warehouse_id.to_string(), ns_name.to_string()).await;
+        namespace_ids.push(NamespaceId::from(
+            uuid::Uuid::parse_str(&ns.properties.unwrap()[NAMESPACE_ID_PROPERTY]).unwrap(),
+        ));
+        let ns_params = NamespaceParameters {
+            prefix: Some(Prefix(warehouse_id.to_string())),
+            namespace: ns.namespace.clone(),
+        };
+        for i in 0..3 {
+            let view = CatalogServer::create_view(
+                ns_params.clone(),
+                create_view_request(Some(&format!("view-{i}")), None),
+                ctx.clone(),
+                DataAccess::not_specified(),
+                RequestMetadata::new_unauthenticated(),
+            )
+            .await
+            .unwrap();
+            if i == 1 {
+                authz.hide(&format!("view:{warehouse_id}/{}", view.metadata.uuid()));
+            } else {
+                expected.push((
+                    ns_name.to_string(),
+                    format!("view-{i}"),
+                    Some(view.metadata_location),
+                ));
+            }
+        }
+        create_table(
+            ctx.clone(),
+            warehouse_id.to_string(),
+            ns_name,
+            "table",
+            false,
+        )
+        .await
+        .unwrap();
+    }
+
+    // Page through all views of the warehouse. Tables and hidden views are skipped.
+    let mut listed = Vec::new();
+    let mut page_token = None;
+    loop {
+        let page = ManagementApiServer::list_views(
+            warehouse_id,
+            ListViewsQuery {
+                namespace_id: None,
+                page_token,
+                page_size: Some(2),
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        assert!(page.views.len() <= 2);
+        listed.extend(page.views);
+        page_token = page.next_page_token;
+        if page_token.is_none() {
+            break;
+        }
+        assert!(listed.len() <= expected.len(), "Too many pages");
+    }
+    let listed = listed
+        .into_iter()
+        .map(|v| (v.namespace.join("."), v.name, v.metadata_location))
+        .sorted()
+        .collect_vec();
+    assert_eq!(listed, expected);
+
+    // Filtering by namespace only returns the views of that namespace.
+    let ns2_views = ManagementApiServer::list_views(
+        warehouse_id,
+        ListViewsQuery {
+            namespace_id: Some(namespace_ids[1]),
+            page_token: None,
+            page_size: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        ns2_views
+            .views
+            .iter()
+            .map(|v| (v.namespace.join("."), v.name.clone()))
+            .sorted()
+            .collect_vec(),
+        vec![
+            ("ns2".to_string(), "view-0".to_string()),
+            ("ns2".to_string(), "view-2".to_string()),
+        ]
+    );
+    assert!(ns2_views.next_page_token.is_none());
+}
@@ -0,0 +1,183 @@
+use iceberg::NamespaceIdent;
+use lakekeeper::{
+    SoftDeletedTableNameConflict, WarehouseId,
+    api::{
+        ApiContext,
+        iceberg::{
+            types::Prefix,
+            v1::{DataAccess, LoadTableResult, NamespaceParameters},
+        },
+        management::v1::{
+            ApiServer,
+            tasks::{ListTasksRequest, Service as _},
+            warehouse::{ListDeletedTabularsQuery, Service as _, TabularDeleteProfile},
+        },
+    },
+    service::{
+        State, TableId,
+        authz::AllowAllAuthorizer,
+        tasks::{WarehouseTaskEntityId, tabular_purge_queue::QUEUE_NAME as PURGE_QUEUE_NAME},
+    },
+};
+use lakekeeper_integration_tests::{create_table_request, random_request_metadata};
+use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+type Ctx = ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>;
+
+const TABLE_NAME: &str = "my_table";
+
+/// Sets up a soft-deleting warehouse with a namespace holding a dropped `my_table`.
+async fn setup_with_soft_deleted_table(pool: PgPool) -> (Ctx, WarehouseId, String, TableId) {
+    let (api_context, warehouse) = lakekeeper_integration_tests::setup(
+        pool,
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::Duration::seconds(300),
+        },
+        None,
+        1,
+        None,
+    )
+    .await;
+    let warehouse_id = warehouse.warehouse_id;
+    let prefix = warehouse_id.to_string();
+    let ns_name = format!("ns_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(api_context.clone(), prefix.clone(), ns_name.clone())
+        .await;
+
+    let dropped = lakekeeper_integration_tests::create_table(
+        api_context.clone(),
+        &prefix,
+        &ns_name,
+        TABLE_NAME,
+        false,
+    )
+    .await
+    .unwrap();
+    lakekeeper_integration_tests::drop_table(
+        api_context.clone(),
+        &prefix,
+        &ns_name,
+        TABLE_NAME,
+        None,
+        false,
+    )
+    .await
+    .unwrap();
+
+    (
+        api_context,
+        warehouse_id,
+        ns_name,
+        TableId::from(dropped.metadata.uuid()),
+    )
+}
+
+async fn create_table_with_policy(
+    api_context: Ctx,
+    warehouse_id: WarehouseId,
+    ns_name: &str,
+    policy: SoftDeletedTableNameConflict,
+) -> lakekeeper::api::Result<LoadTableResult> {
+    lakekeeper::server::tables::create_table::create_table_with_soft_deleted_name_conflict(
+        NamespaceParameters {
+            prefix: Some(Prefix(warehouse_id.to_string())),
+            namespace: NamespaceIdent::new(ns_name.to_string()),
+        },
+        create_table_request(Some(TABLE_NAME.to_string()), None),
+        DataAccess::not_specified(),
+        api_context,
+        random_request_metadata(),
+        policy,
+    )
+    .await
+}
+
+async fn soft_deleted_table_ids(api_context: &Ctx, warehouse_id: WarehouseId) -> Vec<Uuid> {
+    ApiServer::list_soft_deleted_tabulars(
+        warehouse_id,
+        ListDeletedTabularsQuery::default(),
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap()
+    .tabulars
+    .iter()
+    .map(|t| t.id)
+    .collect()
+}
+
+#[sqlx::test]
+async fn test_create_table_rejects_soft_deleted_name(pool: PgPool) {
+    let (api_context, warehouse_id, ns_name, dropped_id) =
+        setup_with_soft_deleted_table(pool).await;
+
+    let err = create_table_with_policy(
+        api_context.clone(),
+        warehouse_id,
+        &ns_name,
+        SoftDeletedTableNameConflict::Reject,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, http::StatusCode::CONFLICT.as_u16());
+    assert_eq!(err.error.r#type, "SoftDeletedTableExists");
+    assert!(
+        err.error.message.contains("Undrop"),
+        "{}",
+        err.error.message
+    );
+
+    // The soft-deleted table is untouched and can still be undropped.
+    assert_eq!(
+        soft_deleted_table_ids(&api_context, warehouse_id).await,
+        vec![*dropped_id]
+    );
+}
+
+#[sqlx::test]
+async fn test_create_table_purges_soft_deleted_name(pool: PgPool) {
+    let (api_context, warehouse_id, ns_name, dropped_id) =
+        setup_with_soft_deleted_table(pool).await;
+
+    let created = create_table_with_policy(
+        api_context.clone(),
+        warehouse_id,
+        &ns_name,
+        SoftDeletedTableNameConflict::Purge,
+    )
+    .await
+    .unwrap();
+    assert_ne!(TableId::from(created.metadata.uuid()), dropped_id);
+
+    assert!(
+        soft_deleted_table_ids(&api_context, warehouse_id)
+            .await
+            .is_empty()
+    );
+
+    let purge_tasks = ApiServer::list_tasks(
+        warehouse_id,
+        ListTasksRequest {
+            queue_name: Some(vec![PURGE_QUEUE_NAME.clone()]),
+            ..Default::default()
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap()
+    .tasks;
+    assert_eq!(purge_tasks.len(), 1);
+    assert_eq!(
+        purge_tasks[0].entity,
+        Some(WarehouseTaskEntityId::Table {
+            table_id: dropped_id
+        })
+    );
+}
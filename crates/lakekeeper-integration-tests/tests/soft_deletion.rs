@@ -16,19 +16,32 @@ use lakekeeper::{
         },
         management::v1::{
             ApiServer,
-            tasks::{ListTasksRequest, Service as _, TaskStatus},
+            namespace::{NamespaceManagementService as _, SetNamespaceDeleteProfileRequest},
+            tasks::{
+                ControlTaskAction, ControlTasksRequest, ListTasksRequest, Service as _, TaskStatus,
+                WarehouseTaskEntityFilter,
+            },
             warehouse::{
-                ListDeletedTabularsQuery, Service, TabularDeleteProfile, UndropTabularsRequest,
+                ListDeletedTabularsQuery, PurgeDeletedTabularQuery, Service, TabularDeleteProfile,
+                UndropTabularsRequest, UpdateWarehouseSettingsRequest, WarehouseSettings,
             },
         },
     },
     server::{CatalogServer, NAMESPACE_ID_PROPERTY},
     service::{
-        NamespaceId, TabularId, authz::AllowAllAuthorizer,
-        tasks::tabular_expiration_queue::QUEUE_NAME as EXPIRATION_QUEUE_NAME,
+        NamespaceId, TabularId,
+        authz::AllowAllAuthorizer,
+        tasks::{
+            WarehouseTaskEntityId,
+            tabular_expiration_queue::{
+                QUEUE_NAME as EXPIRATION_QUEUE_NAME, TabularExpirationTask, expire_picked_task,
+            },
+            tabular_purge_queue::QUEUE_NAME as PURGE_QUEUE_NAME,
+        },
     },
 };
 use lakekeeper_integration_tests::random_request_metadata;
+use lakekeeper_storage_postgres::PostgresBackend;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -211,6 +224,7 @@ async fn test_soft_deletion(pool: PgPool) {
         random_request_metadata(),
         UndropTabularsRequest {
             targets: vec![undrop_table_id],
+            namespace_id: None,
         },
         api_context.clone(),
     )
@@ -273,6 +287,245 @@ async fn test_soft_deletion(pool: PgPool) {
     assert_eq!(deleted_tabulars.len(), 9);
 }
 
+#[sqlx::test]
+async fn test_undrop_namespace(pool: PgPool) {
+    let (api_context, warehouse) = lakekeeper_integration_tests::setup(
+        pool.clone(),
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::Duration::seconds(300),
+        },
+        None,
+        1,
+        None,
+    )
+    .await;
+
+    let prefix = warehouse.warehouse_id.to_string();
+    let ns_ident = NamespaceIdent::new(format!("test_namespace_{}", Uuid::now_v7()));
+    let create_ns_response = lakekeeper_integration_tests::create_ns(
+        api_context.clone(),
+        prefix.clone(),
+        ns_ident.to_string(),
+    )
+    .await;
+    let ns_id = NamespaceId::from(
+        Uuid::parse_str(
+            create_ns_response
+                .properties
+                .unwrap()
+                .get(NAMESPACE_ID_PROPERTY)
+                .unwrap(),
+        )
+        .unwrap(),
+    );
+
+    for i in 0..3 {
+        let table_name = format!("table_{i}");
+        lakekeeper_integration_tests::create_table(
+            api_context.clone(),
+            &prefix,
+            &ns_ident.to_string(),
+            &table_name,
+            false,
+        )
+        .await
+        .unwrap();
+        CatalogServer::drop_table(
+            TableParameters {
+                prefix: Some(Prefix(prefix.clone())),
+                table: TableIdent::new(ns_ident.clone(), table_name),
+            },
+            DropParams {
+                purge_requested: true,
+                force: false,
+            },
+            api_context.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+    }
+
+    let list_deleted = || {
+        ApiServer::list_soft_deleted_tabulars(
+            warehouse.warehouse_id,
+            ListDeletedTabularsQuery {
+                namespace_id: Some(ns_id),
+                ..Default::default()
+            },
+            api_context.clone(),
+            random_request_metadata(),
+        )
+    };
+    assert_eq!(list_deleted().await.unwrap().tabulars.len(), 3);
+
+    ApiServer::undrop_tabulars(
+        warehouse.warehouse_id,
+        random_request_metadata(),
+        UndropTabularsRequest {
+            targets: vec![],
+            namespace_id: Some(ns_id),
+        },
+        api_context.clone(),
+    )
+    .await
+    .unwrap();
+
+    assert!(list_deleted().await.unwrap().tabulars.is_empty());
+    let tables = CatalogServer::list_tables(
+        NamespaceParameters {
+            prefix: Some(Prefix(prefix.clone())),
+            namespace: ns_ident.clone(),
+        },
+        ListTablesQuery::default(),
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(tables.identifiers.len(), 3);
+
+    // The expiration tasks of the undropped tables are cancelled.
+    let tasks = ApiServer::list_tasks(
+        warehouse.warehouse_id,
+        ListTasksRequest {
+            status: Some(vec![TaskStatus::Scheduled]),
+            queue_name: Some(vec![EXPIRATION_QUEUE_NAME.clone()]),
+            ..Default::default()
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap()
+    .tasks;
+    assert!(tasks.is_empty());
+}
+
+#[sqlx::test]
+async fn test_purge_soft_deleted_table(pool: PgPool) {
+    let (api_context, warehouse) = lakekeeper_integration_tests::setup(
+        pool.clone(),
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::Duration::seconds(300),
+        },
+        None,
+        1,
+        None,
+    )
+    .await;
+
+    let prefix = warehouse.warehouse_id.to_string();
+    let ns_ident = NamespaceIdent::new(format!("test_namespace_{}", Uuid::now_v7()));
+    lakekeeper_integration_tests::create_ns(
+        api_context.clone(),
+        prefix.clone(),
+        ns_ident.to_string(),
+    )
+    .await;
+    lakekeeper_integration_tests::create_table(
+        api_context.clone(),
+        &prefix,
+        &ns_ident.to_string(),
+        "purged",
+        false,
+    )
+    .await
+    .unwrap();
+
+    CatalogServer::drop_table(
+        TableParameters {
+            prefix: Some(Prefix(prefix.clone())),
+            table: TableIdent::new(ns_ident.clone(), "purged".to_string()),
+        },
+        DropParams {
+            purge_requested: false,
+            force: false,
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let list_deleted = || {
+        ApiServer::list_soft_deleted_tabulars(
+            warehouse.warehouse_id,
+            ListDeletedTabularsQuery::default(),
+            api_context.clone(),
+            random_request_metadata(),
+        )
+    };
+    let deleted = list_deleted().await.unwrap();
+    assert_eq!(deleted.tabulars.len(), 1);
+    let table_id = deleted.tabulars[0].id;
+
+    ApiServer::purge_deleted_tabular(
+        warehouse.warehouse_id,
+        table_id,
+        PurgeDeletedTabularQuery::default(),
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    // The table is gone from the catalog and its files are queued for deletion,
+    // without waiting for the expiration task.
+    assert!(list_deleted().await.unwrap().tabulars.is_empty());
+    let purge_tasks = ApiServer::list_tasks(
+        warehouse.warehouse_id,
+        ListTasksRequest {
+            queue_name: Some(vec![PURGE_QUEUE_NAME.clone()]),
+            ..Default::default()
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap()
+    .tasks;
+    assert_eq!(purge_tasks.len(), 1);
+    assert_eq!(
+        purge_tasks[0].entity,
+        Some(WarehouseTaskEntityId::Table {
+            table_id: table_id.into()
+        })
+    );
+    let tasks = ApiServer::list_tasks(
+        warehouse.warehouse_id,
+        ListTasksRequest {
+            status: Some(vec![TaskStatus::Scheduled]),
+            queue_name: Some(vec![EXPIRATION_QUEUE_NAME.clone()]),
+            ..Default::default()
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap()
+    .tasks;
+    assert!(tasks.is_empty());
+
+    // Purging again fails, the table is not known anymore.
+    let err = ApiServer::purge_deleted_tabular(
+        warehouse.warehouse_id,
+        table_id,
+        PurgeDeletedTabularQuery::default(),
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 404);
+}
+
 #[sqlx::test]
 async fn test_soft_delete_and_undrop_generic_table(pool: PgPool) {
     use lakekeeper::api::{
@@ -385,6 +638,7 @@ async fn test_soft_delete_and_undrop_generic_table(pool: PgPool) {
         random_request_metadata(),
         UndropTabularsRequest {
             targets: vec![TabularId::GenericTable(gt_id)],
+            namespace_id: None,
         },
         api_context.clone(),
     )
@@ -410,3 +664,415 @@ async fn test_soft_delete_and_undrop_generic_table(pool: PgPool) {
         "undropped generic table should reappear in list with the same id",
     );
 }
+
+#[sqlx::test]
+async fn test_list_expiration_task_by_table_entity(pool: PgPool) {
+    let (api_context, warehouse) = lakekeeper_integration_tests::setup(
+        pool,
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::Duration::seconds(300),
+        },
+        None,
+        1,
+        None,
+    )
+    .await;
+    let warehouse_id = warehouse.warehouse_id.to_string();
+    let ns_name = format!("test_namespace_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(
+        api_context.clone(),
+        warehouse_id.clone(),
+        ns_name.clone(),
+    )
+    .await;
+
+    let mut table_ids = vec![];
+    for table_name in ["table_a", "table_b"] {
+        let table = lakekeeper_integration_tests::create_table(
+            api_context.clone(),
+            &warehouse_id,
+            &ns_name,
+            table_name,
+            false,
+        )
+        .await
+        .unwrap();
+        table_ids.push(table.metadata.uuid());
+        lakekeeper_integration_tests::drop_table(
+            api_context.clone(),
+            &warehouse_id,
+            &ns_name,
+            table_name,
+            Some(true),
+            false,
+        )
+        .await
+        .unwrap();
+    }
+
+    // Both drops enqueued an expiration task; only the one of table_a is returned
+    let tasks = ApiServer::list_tasks(
+        warehouse.warehouse_id,
+        ListTasksRequest::builder()
+            .entities(Some(vec![WarehouseTaskEntityFilter::Table {
+                table_id: table_ids[0].into(),
+            }]))
+            .build(),
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap()
+    .tasks;
+
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(&tasks[0].queue_name, &*EXPIRATION_QUEUE_NAME);
+    assert_eq!(tasks[0].status, TaskStatus::Scheduled);
+    assert_eq!(
+        tasks[0].entity,
+        Some(WarehouseTaskEntityId::Table {
+            table_id: table_ids[0].into(),
+        })
+    );
+}
+
+#[sqlx::test]
+async fn test_undrop_cancels_running_expiration_task(pool: PgPool) {
+    let (api_context, warehouse) = lakekeeper_integration_tests::setup(
+        pool,
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::Duration::seconds(300),
+        },
+        None,
+        1,
+        None,
+    )
+    .await;
+    let prefix = warehouse.warehouse_id.to_string();
+    let ns_name = format!("test_namespace_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(api_context.clone(), prefix.clone(), ns_name.clone())
+        .await;
+    let table = lakekeeper_integration_tests::create_table(
+        api_context.clone(),
+        &prefix,
+        &ns_name,
+        "raced",
+        false,
+    )
+    .await
+    .unwrap();
+    let table_id = table.metadata.uuid();
+    lakekeeper_integration_tests::drop_table(
+        api_context.clone(),
+        &prefix,
+        &ns_name,
+        "raced",
+        None,
+        false,
+    )
+    .await
+    .unwrap();
+
+    let list_expiration_tasks = || {
+        ApiServer::list_tasks(
+            warehouse.warehouse_id,
+            ListTasksRequest::builder()
+                .entities(Some(vec![WarehouseTaskEntityFilter::Table {
+                    table_id: table_id.into(),
+                }]))
+                .status(Some(vec![TaskStatus::Scheduled, TaskStatus::Running]))
+                .build(),
+            api_context.clone(),
+            random_request_metadata(),
+        )
+    };
+    let tasks = list_expiration_tasks().await.unwrap().tasks;
+    assert_eq!(tasks.len(), 1);
+    let task_id = tasks[0].task_id;
+
+    // Simulate a worker picking up the expiration task right before the undrop.
+    ApiServer::control_tasks(
+        warehouse.warehouse_id,
+        ControlTasksRequest {
+            action: ControlTaskAction::RunNow,
+            task_ids: vec![task_id],
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let task = TabularExpirationTask::pick_new_task::<PostgresBackend>(
+        api_context.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .expect("expiration task should be due");
+    assert_eq!(task.task_id(), task_id);
+
+    ApiServer::undrop_tabulars(
+        warehouse.warehouse_id,
+        random_request_metadata(),
+        UndropTabularsRequest {
+            targets: vec![TabularId::Table(table_id.into())],
+            namespace_id: None,
+        },
+        api_context.clone(),
+    )
+    .await
+    .unwrap();
+
+    // The running task was cancelled together with the undrop
+    assert!(list_expiration_tasks().await.unwrap().tasks.is_empty());
+
+    // The worker resumes its attempt, which must not drop the restored table.
+    expire_picked_task::<PostgresBackend, _>(
+        api_context.v1_state.catalog.clone(),
+        api_context.v1_state.authz.clone(),
+        &task,
+    )
+    .await
+    .unwrap();
+
+    let table = CatalogServer::load_table(
+        TableParameters {
+            prefix: Some(Prefix(prefix)),
+            table: TableIdent::new(NamespaceIdent::new(ns_name), "raced".to_string()),
+        },
+        LoadTableRequest::builder().build(),
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let LoadTableResultOrNotModified::LoadTableResult(table) = table else {
+        panic!("Expected LoadTableResult, got NotModified");
+    };
+    assert_eq!(table.metadata.uuid(), table_id);
+}
+
+#[sqlx::test]
+async fn test_namespace_delete_profile_overrides_warehouse_expiration(pool: PgPool) {
+    let (api_context, warehouse) = lakekeeper_integration_tests::setup(
+        pool,
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::Duration::seconds(300),
+        },
+        None,
+        1,
+        None,
+    )
+    .await;
+    let warehouse_id = warehouse.warehouse_id.to_string();
+
+    // One namespace with a longer retention, one using the warehouse default
+    let override_ns = format!("override_namespace_{}", Uuid::now_v7());
+    let default_ns = format!("default_namespace_{}", Uuid::now_v7());
+    let create_ns_response = lakekeeper_integration_tests::create_ns(
+        api_context.clone(),
+        warehouse_id.clone(),
+        override_ns.clone(),
+    )
+    .await;
+    lakekeeper_integration_tests::create_ns(
+        api_context.clone(),
+        warehouse_id.clone(),
+        default_ns.clone(),
+    )
+    .await;
+    let override_ns_id = NamespaceId::from(
+        uuid::Uuid::parse_str(
+            create_ns_response
+                .properties
+                .unwrap()
+                .get(NAMESPACE_ID_PROPERTY)
+                .unwrap(),
+        )
+        .unwrap(),
+    );
+
+    let namespace_profile = TabularDeleteProfile::Soft {
+        expiration_seconds: chrono::Duration::seconds(3600),
+    };
+    let response = ApiServer::set_namespace_delete_profile(
+        override_ns_id,
+        warehouse.warehouse_id,
+        SetNamespaceDeleteProfileRequest {
+            delete_profile: Some(namespace_profile),
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.delete_profile, Some(namespace_profile));
+    let response = ApiServer::get_namespace_delete_profile(
+        override_ns_id,
+        warehouse.warehouse_id,
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.delete_profile, Some(namespace_profile));
+
+    let dropped_at = chrono::Utc::now();
+    let mut scheduled_for = vec![];
+    for ns_name in [&override_ns, &default_ns] {
+        let table = lakekeeper_integration_tests::create_table(
+            api_context.clone(),
+            &warehouse_id,
+            ns_name,
+            "dropped_table",
+            false,
+        )
+        .await
+        .unwrap();
+        lakekeeper_integration_tests::drop_table(
+            api_context.clone(),
+            &warehouse_id,
+            ns_name,
+            "dropped_table",
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+        let tasks = ApiServer::list_tasks(
+            warehouse.warehouse_id,
+            ListTasksRequest::builder()
+                .entities(Some(vec![WarehouseTaskEntityFilter::Table {
+                    table_id: table.metadata.uuid().into(),
+                }]))
+                .build(),
+            api_context.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap()
+        .tasks;
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(&tasks[0].queue_name, &*EXPIRATION_QUEUE_NAME);
+        scheduled_for.push(tasks[0].scheduled_for);
+    }
+
+    // The table in the override namespace expires after the namespace retention,
+    // the other one after the warehouse default.
+    let override_delay = (scheduled_for[0] - dropped_at).num_seconds();
+    let default_delay = (scheduled_for[1] - dropped_at).num_seconds();
+    assert!(
+        (3590..=3610).contains(&override_delay),
+        "unexpected delay {override_delay}"
+    );
+    assert!(
+        (290..=310).contains(&default_delay),
+        "unexpected delay {default_delay}"
+    );
+
+    // Removing the override falls back to the warehouse default
+    let response = ApiServer::set_namespace_delete_profile(
+        override_ns_id,
+        warehouse.warehouse_id,
+        SetNamespaceDeleteProfileRequest {
+            delete_profile: None,
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.delete_profile, None);
+}
+
+#[sqlx::test]
+async fn test_undrop_respects_table_quota(pool: PgPool) {
+    let (api_context, warehouse) = lakekeeper_integration_tests::setup(
+        pool.clone(),
+        lakekeeper_integration_tests::memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::Duration::seconds(300),
+        },
+        None,
+        1,
+        None,
+    )
+    .await;
+    let prefix = warehouse.warehouse_id.to_string();
+    lakekeeper_integration_tests::create_ns(api_context.clone(), prefix.clone(), "ns".to_string())
+        .await;
+
+    let dropped = lakekeeper_integration_tests::create_table(
+        api_context.clone(),
+        &prefix,
+        "ns",
+        "dropped",
+        false,
+    )
+    .await
+    .unwrap();
+    CatalogServer::drop_table(
+        TableParameters {
+            prefix: Some(Prefix(prefix.clone())),
+            table: TableIdent::new(NamespaceIdent::new("ns".to_string()), "dropped".to_string()),
+        },
+        DropParams {
+            purge_requested: false,
+            force: false,
+        },
+        api_context.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    lakekeeper_integration_tests::create_table(api_context.clone(), &prefix, "ns", "active", false)
+        .await
+        .unwrap();
+
+    let set_max_tables = |max_tables| {
+        ApiServer::update_warehouse_settings(
+            warehouse.warehouse_id,
+            UpdateWarehouseSettingsRequest {
+                settings: WarehouseSettings {
+                    max_tables: Some(max_tables),
+                    ..Default::default()
+                },
+            },
+            api_context.clone(),
+            random_request_metadata(),
+        )
+    };
+    let undrop = || {
+        ApiServer::undrop_tabulars(
+            warehouse.warehouse_id,
+            random_request_metadata(),
+            UndropTabularsRequest {
+                targets: vec![TabularId::Table(dropped.metadata.uuid().into())],
+                namespace_id: None,
+            },
+            api_context.clone(),
+        )
+    };
+
+    // Soft-deleted tables don't count towards the quota, restoring them does.
+    set_max_tables(1).await.unwrap();
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let err = undrop().await.unwrap_err();
+    assert_eq!(err.error.r#type, "QuotaExceeded");
+
+    set_max_tables(2).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    undrop().await.unwrap();
+}
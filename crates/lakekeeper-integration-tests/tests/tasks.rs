@@ -169,6 +169,215 @@ mod test {
             "Task was not processed as expected"
         );
     }
+
+    #[sqlx::test]
+    async fn test_pending_task_gauge_reflects_scheduled_tasks(pool: PgPool) {
+        use axum_prometheus::{metrics, metrics_exporter_prometheus::PrometheusBuilder};
+        use lakekeeper::service::tasks::queue_metrics::METRIC_TASK_QUEUE_PENDING_TASKS;
+
+        #[derive(Debug, Clone, Deserialize, Serialize)]
+        #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+        struct Config {}
+        #[derive(Debug, Clone, Deserialize, Serialize)]
+        struct TestTaskData {}
+        #[derive(Debug, Clone, Deserialize, Serialize)]
+        #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+        struct ExecutionDetails {}
+
+        impl TaskData for TestTaskData {}
+        impl TaskExecutionDetails for ExecutionDetails {}
+        static QUEUE_NAME: LazyLock<TaskQueueName> =
+            LazyLock::new(|| "pending_gauge_test_queue".into());
+        impl QueueConfigTrait for Config {
+            fn queue_name() -> &'static TaskQueueName {
+                &QUEUE_NAME
+            }
+
+            fn max_time_since_last_heartbeat() -> chrono::Duration {
+                chrono::Duration::seconds(120)
+            }
+        }
+        type TestTask = SpecializedTask<Config, TestTaskData, ExecutionDetails>;
+
+        let setup = super::setup_tasks_test(pool).await;
+        let catalog_state = setup.ctx.v1_state.catalog.clone();
+        let task_metadata = |scheduled_for| ScheduleTaskMetadata {
+            project_id: setup.warehouse.project_id.clone(),
+            parent_task_id: None,
+            entity: TaskEntity::EntityInWarehouse {
+                warehouse_id: setup.warehouse.warehouse_id,
+                entity_id: WarehouseTaskEntityId::Table {
+                    table_id: Uuid::now_v7().into(),
+                },
+                entity_name: vec!["mytable".to_string()],
+            },
+            scheduled_for,
+        };
+
+        // Three tasks are due now, one only in the future and is not pending yet.
+        let mut transaction =
+            <PostgresBackend as CatalogStore>::Transaction::begin_write(catalog_state.clone())
+                .await
+                .unwrap();
+        let future = Some(chrono::Utc::now() + chrono::Duration::hours(1));
+        TestTask::schedule_tasks::<PostgresBackend>(
+            [None, None, None, future]
+                .into_iter()
+                .map(|scheduled_for| (task_metadata(scheduled_for), TestTaskData {})),
+            transaction.transaction(),
+        )
+        .await
+        .unwrap();
+        transaction.commit().await.unwrap();
+
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let pending_gauge = || {
+            let prefix = format!(
+                "{METRIC_TASK_QUEUE_PENDING_TASKS}{{queue=\"{}\"}} ",
+                *QUEUE_NAME
+            );
+            handle
+                .render()
+                .lines()
+                .find_map(|line| line.strip_prefix(&prefix).map(str::to_string))
+                .expect("pending tasks gauge should be rendered")
+                .parse::<f64>()
+                .unwrap()
+        };
+        // `sqlx::test` runs on a current-thread runtime, so the thread-local
+        // recorder stays in place across awaits.
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        TestTask::report_queue_depth::<PostgresBackend>(catalog_state.clone())
+            .await
+            .unwrap();
+        assert!((pending_gauge() - 3.0).abs() < f64::EPSILON);
+
+        // A picked-up task is no longer pending.
+        TestTask::pick_new_task::<PostgresBackend>(catalog_state.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        TestTask::report_queue_depth::<PostgresBackend>(catalog_state)
+            .await
+            .unwrap();
+        assert!((pending_gauge() - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[sqlx::test]
+    async fn test_task_cancelled_mid_run_is_rescheduled_not_failed(pool: PgPool) {
+        use lakekeeper::{
+            api::management::v1::tasks::TaskStatus, service::tasks::TaskDetailsScope,
+        };
+
+        #[derive(Debug, Clone, Deserialize, Serialize)]
+        #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+        struct Config {}
+        #[derive(Debug, Clone, Deserialize, Serialize)]
+        struct TestTaskData {}
+        #[derive(Debug, Clone, Deserialize, Serialize)]
+        #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+        struct ExecutionDetails {}
+
+        impl TaskData for TestTaskData {}
+        impl TaskExecutionDetails for ExecutionDetails {}
+        static QUEUE_NAME: LazyLock<TaskQueueName> = LazyLock::new(|| "drain_test_queue".into());
+        impl QueueConfigTrait for Config {
+            fn queue_name() -> &'static TaskQueueName {
+                &QUEUE_NAME
+            }
+
+            fn max_time_since_last_heartbeat() -> chrono::Duration {
+                chrono::Duration::seconds(120)
+            }
+        }
+        type TestTask = SpecializedTask<Config, TestTaskData, ExecutionDetails>;
+
+        let setup = super::setup_tasks_test(pool).await;
+        let catalog_state = setup.ctx.v1_state.catalog.clone();
+        let mut transaction =
+            <PostgresBackend as CatalogStore>::Transaction::begin_write(catalog_state.clone())
+                .await
+                .unwrap();
+        let task_id = TestTask::schedule_task::<PostgresBackend>(
+            ScheduleTaskMetadata {
+                project_id: setup.warehouse.project_id.clone(),
+                parent_task_id: None,
+                entity: TaskEntity::EntityInWarehouse {
+                    warehouse_id: setup.warehouse.warehouse_id,
+                    entity_id: WarehouseTaskEntityId::Table {
+                        table_id: Uuid::now_v7().into(),
+                    },
+                    entity_name: vec!["mytable".to_string()],
+                },
+                scheduled_for: None,
+            },
+            TestTaskData {},
+            transaction.transaction(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        transaction.commit().await.unwrap();
+
+        let task = TestTask::pick_new_task::<PostgresBackend>(catalog_state.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.attempt(), 1);
+
+        // Shutdown is requested while the task is still being processed and
+        // the work does not finish within the drain period.
+        let cancellation_token = lakekeeper::CancellationToken::new();
+        cancellation_token.cancel();
+        let completed = task
+            .run_or_release::<PostgresBackend>(
+                catalog_state.clone(),
+                &cancellation_token,
+                std::time::Duration::from_millis(50),
+                std::future::pending(),
+            )
+            .await;
+        assert!(!completed);
+
+        let details = PostgresBackend::get_task_details(
+            task_id,
+            TaskDetailsScope::Warehouse {
+                project_id: setup.warehouse.project_id.clone(),
+                warehouse_id: setup.warehouse.warehouse_id,
+            },
+            10,
+            catalog_state.clone(),
+        )
+        .await
+        .unwrap()
+        .expect("Task should exist");
+        assert!(matches!(details.task.status, TaskStatus::Scheduled));
+        assert!(
+            details.attempts.is_empty(),
+            "Released attempt must not be recorded as failed"
+        );
+
+        // The task is picked up again without having consumed an attempt.
+        let task = TestTask::pick_new_task::<PostgresBackend>(catalog_state.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(task.task_id(), task_id);
+        assert_eq!(task.attempt(), 1);
+
+        // Work that finishes within the drain period completes normally.
+        let completed = task
+            .run_or_release::<PostgresBackend>(
+                catalog_state.clone(),
+                &cancellation_token,
+                std::time::Duration::from_secs(10),
+                async {},
+            )
+            .await;
+        assert!(completed);
+    }
 }
 
 struct TasksSetup {
@@ -1,25 +1,57 @@
+use std::collections::{HashMap, HashSet};
+
 use lakekeeper::{
     ProjectId, SecretId, WarehouseId,
     api::{
-        RequestMetadata,
+        ApiContext, RequestMetadata,
+        iceberg::{
+            types::{PageToken, Prefix},
+            v1::{
+                DataAccess, LoadTableResultOrNotModified, NamespaceParameters, TableParameters,
+                config::{GetConfigQueryParams, Service as _},
+                namespace::{NamespaceDropFlags, NamespaceService as _},
+                s3_signer::Service as _,
+                tables::{LoadTableRequest, TablesService as _},
+            },
+        },
         management::v1::{
-            ApiServer, DeleteWarehouseQuery,
+            ApiServer, DeleteWarehouseQuery, GetWarehouseQuery,
+            namespace::NamespaceManagementService as _,
+            table::TableManagementService as _,
+            task_queue::{QueueConfig, SetTaskQueueConfigRequest},
+            view::ViewManagementService as _,
             warehouse::{
-                CreateWarehouseRequest, RenameWarehouseRequest, Service,
-                SetWarehouseManagedByRequest, TabularDeleteProfile,
-                UpdateWarehouseCredentialRequest, UpdateWarehouseDeleteProfileRequest,
-                UpdateWarehouseFormatVersionPolicyRequest, UpdateWarehouseStorageRequest,
+                CloneWarehouseRequest, CreateWarehouseRequest, ListProtectedEntitiesQuery,
+                ListWarehousesRequest, MergeWarehouseStorageRequest, ProtectedEntityType,
+                RenameWarehouseRequest, Service, SetWarehouseManagedByRequest,
+                StorageValidationCheck, TabularDeleteProfile, UpdateWarehouseCredentialRequest,
+                UpdateWarehouseDeleteProfileRequest, UpdateWarehouseFormatVersionPolicyRequest,
+                UpdateWarehouseSettingsRequest, UpdateWarehouseStorageRequest,
+                ValidateStorageRequest, WarehouseSettings, WriteFileFormat,
             },
         },
     },
+    server::CatalogServer,
     service::{
-        CachePolicy, CatalogCreateWarehouseRequest, CatalogStore, CatalogWarehouseOps, ManagedBy,
-        Transaction, UserId, WarehouseStatus, authz::AllowAllAuthorizer,
+        CachePolicy, CatalogCreateWarehouseError, CatalogCreateWarehouseRequest,
+        CatalogNamespaceOps as _, CatalogStore, CatalogTaskOps, CatalogWarehouseOps,
+        FileFormatPolicy, ListNamespacesQuery, ManagedBy, NamespaceIdent, PartitionTransformKind,
+        PartitionTransformPolicy, ResolvedWarehouse, SchemaEvolutionPolicy, State, Transaction,
+        UserId, WarehouseCredentialMode, WarehouseStatus,
+        authz::AllowAllAuthorizer,
+        secrets::SecretStore as _,
+        storage::{GcsCredential, S3CredentialType, S3Flavor, S3Profile, StorageCredentialType},
+        task_configs::TaskQueueConfigFilter,
+        tasks::metadata_log_compaction_queue::{MetadataLogCompactionQueueConfig, QUEUE_NAME},
         warehouse_cache::WAREHOUSE_CACHE,
     },
 };
-use lakekeeper_integration_tests::{SetupTestCatalog, memory_io_profile, random_request_metadata};
-use lakekeeper_storage_postgres::PostgresBackend;
+use lakekeeper_integration_tests::{
+    SetupTestCatalog, create_table_request, memory_io_profile, random_request_metadata,
+    s3_compatible_profile, setup_simple,
+};
+use lakekeeper_io::LakekeeperStorage as _;
+use lakekeeper_storage_postgres::{PostgresBackend, SecretsState};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -152,6 +184,215 @@ async fn test_create_warehouse_duplicate_name(pool: PgPool) {
     ));
 }
 
+/// Test that validating a storage profile reports success without creating a warehouse
+#[sqlx::test]
+async fn test_validate_storage_valid_profile(pool: PgPool) {
+    let (ctx, _) = setup_simple(
+        pool,
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    let response = ApiServer::validate_storage(
+        ValidateStorageRequest {
+            storage_profile: memory_io_profile(),
+            storage_credential: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.valid);
+    assert!(response.failure.is_none());
+
+    // Validation must not persist anything
+    let warehouses = PostgresBackend::list_warehouses(
+        &ProjectId::from(Uuid::nil()),
+        None,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(warehouses.len(), 1);
+}
+
+/// Test that validating a storage profile with a bad credential reports the failed check
+#[sqlx::test]
+async fn test_validate_storage_bad_credential(pool: PgPool) {
+    let (ctx, _) = setup_simple(
+        pool,
+        memory_io_profile(),
+        None,
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    let storage_profile = S3Profile::builder()
+        .bucket("my-bucket".to_string())
+        .region("us-east-1".to_string())
+        .key_prefix("subfolder".to_string())
+        .sts_enabled(false)
+        .flavor(S3Flavor::Aws)
+        .build()
+        .into();
+    let response = ApiServer::validate_storage(
+        ValidateStorageRequest {
+            storage_profile,
+            storage_credential: Some(GcsCredential::GcpSystemIdentity {}.into()),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(!response.valid);
+    let failure = response.failure.unwrap();
+    assert_eq!(failure.check, StorageValidationCheck::Credentials);
+    assert_eq!(failure.error_type, "UnexpectedStorageProfileType");
+}
+
+async fn create_named_warehouse(
+    ctx: &ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    name: &str,
+) -> Result<ResolvedWarehouse, CatalogCreateWarehouseError> {
+    let mut transaction =
+        <PostgresBackend as CatalogStore>::Transaction::begin_write(ctx.v1_state.catalog.clone())
+            .await
+            .unwrap();
+    let warehouse = PostgresBackend::create_warehouse(
+        &ProjectId::from(Uuid::nil()),
+        CatalogCreateWarehouseRequest::builder()
+            .warehouse_name(name.to_string())
+            .storage_profile(memory_io_profile())
+            .delete_profile(TabularDeleteProfile::Hard {})
+            .build(),
+        transaction.transaction(),
+    )
+    .await?;
+    transaction.commit().await.unwrap();
+    Ok(warehouse)
+}
+
+#[sqlx::test]
+async fn test_create_warehouse_names_differing_in_case_collide_by_default(pool: PgPool) {
+    let (ctx, _) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let lower = format!("warehouse-{}", Uuid::now_v7());
+    let upper = lower.replacen('w', "W", 1);
+
+    create_named_warehouse(&ctx, &lower).await.unwrap();
+    let err = create_named_warehouse(&ctx, &upper).await.unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogCreateWarehouseError::WarehouseAlreadyExists(_)
+    ));
+
+    let warehouse = PostgresBackend::get_warehouse_by_name(
+        &lower.to_uppercase(),
+        &std::sync::Arc::new(ProjectId::from(Uuid::nil())),
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(warehouse.name, lower);
+}
+
+#[sqlx::test]
+async fn test_create_warehouse_names_differing_in_case_with_case_sensitive_project(pool: PgPool) {
+    let (ctx, _) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let project_id = std::sync::Arc::new(ProjectId::from(Uuid::nil()));
+    let lower_name = format!("warehouse-{}", Uuid::now_v7());
+    let upper_name = lower_name.replacen('w', "W", 1);
+
+    let mut transaction =
+        <PostgresBackend as CatalogStore>::Transaction::begin_write(ctx.v1_state.catalog.clone())
+            .await
+            .unwrap();
+    PostgresBackend::set_project_case_sensitive_warehouse_names(
+        &project_id,
+        true,
+        transaction.transaction(),
+    )
+    .await
+    .unwrap();
+    transaction.commit().await.unwrap();
+
+    let lower = create_named_warehouse(&ctx, &lower_name).await.unwrap();
+    let upper = create_named_warehouse(&ctx, &upper_name).await.unwrap();
+    assert_ne!(lower.warehouse_id, upper.warehouse_id);
+
+    // Exact duplicates are still rejected
+    let err = create_named_warehouse(&ctx, &upper_name).await.unwrap_err();
+    assert!(matches!(
+        err,
+        CatalogCreateWarehouseError::WarehouseAlreadyExists(_)
+    ));
+
+    // Lookups resolve the warehouse with the exact name, also when the other
+    // one is cached.
+    for (name, expected) in [
+        (&lower_name, lower.warehouse_id),
+        (&upper_name, upper.warehouse_id),
+        (&lower_name, lower.warehouse_id),
+    ] {
+        let warehouse = PostgresBackend::get_warehouse_by_name(
+            name,
+            &project_id,
+            WarehouseStatus::active(),
+            ctx.v1_state.catalog.clone(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(warehouse.warehouse_id, expected);
+    }
+    let missing = PostgresBackend::get_warehouse_by_name(
+        &lower_name.to_uppercase(),
+        &project_id,
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap();
+    assert!(missing.is_none());
+
+    // Names can't be made case-insensitive while they collide
+    let mut transaction =
+        <PostgresBackend as CatalogStore>::Transaction::begin_write(ctx.v1_state.catalog.clone())
+            .await
+            .unwrap();
+    let err = PostgresBackend::set_project_case_sensitive_warehouse_names(
+        &project_id,
+        false,
+        transaction.transaction(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, http::StatusCode::CONFLICT.as_u16());
+}
+
 #[sqlx::test]
 async fn test_get_warehouse_by_id(pool: PgPool) {
     let storage_profile = memory_io_profile();
@@ -388,6 +629,105 @@ async fn test_list_warehouses_include_inactive(pool: PgPool) {
     assert_eq!(all_warehouses.len(), 2);
 }
 
+async fn list_warehouses_page(
+    ctx: &ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    warehouse_status: Option<Vec<WarehouseStatus>>,
+    page_token: Option<String>,
+) -> (Vec<WarehouseId>, Option<String>) {
+    let response = ApiServer::list_warehouses(
+        ListWarehousesRequest {
+            warehouse_status,
+            project_id: Some(ProjectId::from(Uuid::nil())),
+            include_counts: false,
+            page_token,
+            page_size: Some(2),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.warehouses.len() <= 2);
+    (
+        response
+            .warehouses
+            .into_iter()
+            .map(|w| w.warehouse_id)
+            .collect(),
+        response.next_page_token,
+    )
+}
+
+#[sqlx::test]
+async fn test_list_warehouses_paginated(pool: PgPool) {
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(5)
+        .build()
+        .setup()
+        .await;
+
+    let mut transaction =
+        <PostgresBackend as CatalogStore>::Transaction::begin_write(ctx.v1_state.catalog.clone())
+            .await
+            .unwrap();
+    PostgresBackend::set_warehouse_status(
+        warehouse_resp.warehouse_id,
+        WarehouseStatus::Inactive,
+        transaction.transaction(),
+    )
+    .await
+    .unwrap();
+    transaction.commit().await.unwrap();
+
+    // Inactive warehouses are excluded by default. A warehouse created while
+    // paginating does not shift pages that were already returned and shows up last.
+    let (mut ids, mut page_token) = list_warehouses_page(&ctx, None, None).await;
+    assert_eq!(ids.len(), 2);
+
+    let mut transaction =
+        <PostgresBackend as CatalogStore>::Transaction::begin_write(ctx.v1_state.catalog.clone())
+            .await
+            .unwrap();
+    let created = PostgresBackend::create_warehouse(
+        &ProjectId::from(Uuid::nil()),
+        CatalogCreateWarehouseRequest::builder()
+            .warehouse_name(format!("test-warehouse-{}", Uuid::now_v7()))
+            .storage_profile(memory_io_profile())
+            .delete_profile(TabularDeleteProfile::Hard {})
+            .build(),
+        transaction.transaction(),
+    )
+    .await
+    .unwrap();
+    transaction.commit().await.unwrap();
+
+    while let Some(token) = page_token {
+        let (page, next_page_token) = list_warehouses_page(&ctx, None, Some(token)).await;
+        ids.extend(page);
+        page_token = next_page_token;
+    }
+    assert_eq!(ids.len(), 5);
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 5);
+    assert!(!ids.contains(&warehouse_resp.warehouse_id));
+    assert_eq!(ids.last(), Some(&created.warehouse_id));
+
+    // Explicitly requesting inactive warehouses includes them
+    let all_statuses = Some(vec![WarehouseStatus::Active, WarehouseStatus::Inactive]);
+    let (mut ids, mut page_token) = list_warehouses_page(&ctx, all_statuses.clone(), None).await;
+    while let Some(token) = page_token {
+        let (page, next_page_token) =
+            list_warehouses_page(&ctx, all_statuses.clone(), Some(token)).await;
+        ids.extend(page);
+        page_token = next_page_token;
+    }
+    assert_eq!(ids.len(), 6);
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 6);
+    assert!(ids.contains(&warehouse_resp.warehouse_id));
+}
+
 #[sqlx::test]
 async fn test_rename_warehouse_not_found(pool: PgPool) {
     let storage_profile = memory_io_profile();
@@ -1247,6 +1587,87 @@ async fn test_cache_invalidation_on_api_update_storage(pool: PgPool) {
     assert_eq!(warehouse_after.storage_profile, updated_storage_profile);
 }
 
+/// Merging only the region keeps the rest of the profile and re-uses the existing credential
+#[sqlx::test]
+async fn test_merge_storage_profile_keeps_credential(pool: PgPool) {
+    let (storage_profile, storage_credential) = s3_compatible_profile();
+    let (ctx, warehouse_resp) = setup_simple(
+        pool.clone(),
+        storage_profile.clone(),
+        Some(storage_credential),
+        AllowAllAuthorizer::default(),
+        TabularDeleteProfile::Hard {},
+        None,
+    )
+    .await;
+
+    let warehouse_before = PostgresBackend::get_warehouse_by_id(
+        warehouse_resp.warehouse_id,
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    let secret_id = warehouse_before.storage_secret_id;
+    assert!(secret_id.is_some());
+
+    // The merged profile is validated with the existing credential
+    let response = ApiServer::merge_storage(
+        warehouse_resp.warehouse_id,
+        MergeWarehouseStorageRequest {
+            storage_profile: serde_json::json!({"region": "other-region"})
+                .as_object()
+                .unwrap()
+                .clone(),
+            storage_credential: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        response.storage_credential_type,
+        Some(StorageCredentialType::S3(S3CredentialType::AccessKey))
+    );
+
+    let warehouse_after = PostgresBackend::get_warehouse_by_id(
+        warehouse_resp.warehouse_id,
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(warehouse_after.storage_secret_id, secret_id);
+
+    let mut expected = storage_profile.try_into_s3().unwrap();
+    expected.region = "other-region".to_string();
+    assert_eq!(
+        warehouse_after
+            .storage_profile
+            .clone()
+            .try_into_s3()
+            .unwrap(),
+        expected
+    );
+
+    // The credential still grants access to the storage
+    let credential = ctx
+        .v1_state
+        .secrets
+        .require_storage_secret_by_id(secret_id.unwrap())
+        .await
+        .unwrap()
+        .secret;
+    warehouse_after
+        .storage_profile
+        .validate_access(Some(credential.as_ref()), None, &random_request_metadata())
+        .await
+        .unwrap();
+}
+
 /// Test cache invalidation when updating delete profile via `ApiServer`
 #[sqlx::test]
 async fn test_cache_invalidation_on_api_update_delete_profile(pool: PgPool) {
@@ -1407,12 +1828,10 @@ async fn test_update_format_version_policy_invalid_default(pool: PgPool) {
     assert_eq!(err.error.r#type, "DefaultFormatVersionNotAllowed");
 }
 
-/// End-to-end of the managed-by lock through the management handlers (not just
-/// the storage layer): only an instance admin may set/clear the marker, a
-/// managed warehouse's spec is locked even when the resource authorizer allows
-/// the action (AllowAll here), and clearing the marker restores mutability.
+/// Test that the per-warehouse partition transform policy can be set and cleared
+/// via the API and is persisted.
 #[sqlx::test]
-async fn test_managed_by_locks_spec_via_handlers(pool: PgPool) {
+async fn test_update_partition_transform_policy(pool: PgPool) {
     let storage_profile = memory_io_profile();
     let (ctx, warehouse_resp) = SetupTestCatalog::builder()
         .pool(pool.clone())
@@ -1422,39 +1841,1370 @@ async fn test_managed_by_locks_spec_via_handlers(pool: PgPool) {
         .build()
         .setup()
         .await;
-    let warehouse_id = warehouse_resp.warehouse_id;
-
-    let non_admin = RequestMetadata::new_unauthenticated();
-    let admin = RequestMetadata::test_instance_admin(UserId::new_unchecked("oidc", "admin"));
 
-    // A non-instance-admin cannot set the marker (403, even with AllowAll authz).
-    let err = ApiServer::set_warehouse_managed_by(
-        warehouse_id,
-        SetWarehouseManagedByRequest {
-            managed_by: ManagedBy::InstanceAdmin,
-        },
-        ctx.clone(),
-        non_admin.clone(),
+    // New warehouses have no partition transform restrictions.
+    let before = PostgresBackend::get_warehouse_by_id(
+        warehouse_resp.warehouse_id,
+        WarehouseStatus::active(),
+        ctx.v1_state.catalog.clone(),
     )
     .await
-    .unwrap_err();
-    assert_eq!(err.error.code, 403);
-    assert_eq!(err.error.r#type, "InstanceAdminRequired");
+    .unwrap()
+    .unwrap();
+    assert!(before.settings.partition_transform_policy.is_none());
 
-    // An instance admin can mark it managed.
-    let resp = ApiServer::set_warehouse_managed_by(
-        warehouse_id,
-        SetWarehouseManagedByRequest {
-            managed_by: ManagedBy::InstanceAdmin,
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_resp.warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                partition_transform_policy: Some(
+                    PartitionTransformPolicy::try_new(
+                        vec![
+                            PartitionTransformKind::Day,
+                            PartitionTransformKind::Bucket,
+                            PartitionTransformKind::Identity,
+                        ],
+                        Some(16),
+                    )
+                    .unwrap(),
+                ),
+                ..Default::default()
+            },
         },
         ctx.clone(),
-        admin.clone(),
+        random_request_metadata(),
     )
     .await
     .unwrap();
-    assert_eq!(resp.managed_by, ManagedBy::InstanceAdmin);
-    // Let the managed-by-set event update the warehouse cache.
-    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    let policy = response.settings.partition_transform_policy.unwrap();
+    assert_eq!(
+        policy.allowed_transforms(),
+        &[
+            PartitionTransformKind::Identity,
+            PartitionTransformKind::Bucket,
+            PartitionTransformKind::Day,
+        ]
+    );
+    assert_eq!(policy.max_bucket_count(), Some(16));
+
+    let stored = PostgresBackend::get_warehouse_by_id_cache_aware(
+        warehouse_resp.warehouse_id,
+        WarehouseStatus::active(),
+        CachePolicy::Skip,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(stored.settings.partition_transform_policy, Some(policy));
+
+    // Omitting the allowed transforms clears the policy.
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_resp.warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                partition_transform_policy: None,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.settings.partition_transform_policy.is_none());
+}
+
+/// Test that an empty transform list is rejected.
+#[sqlx::test]
+async fn test_update_partition_transform_policy_invalid(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+
+    let request: UpdateWarehouseSettingsRequest = serde_json::from_value(serde_json::json!({
+        "settings": {"partition-transform-policy": {"allowed-transforms": []}}
+    }))
+    .unwrap();
+    let err = ApiServer::update_warehouse_settings(
+        warehouse_resp.warehouse_id,
+        request,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "EmptyAllowedPartitionTransforms");
+}
+
+/// Test that the per-warehouse file format policy is persisted and restricts the
+/// `write.format.default` property of new tables.
+#[sqlx::test]
+async fn test_file_format_policy_restricts_table_creation(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                file_format_policy: Some(
+                    FileFormatPolicy::try_new(vec![WriteFileFormat::Parquet]).unwrap(),
+                ),
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let policy = response.settings.file_format_policy.unwrap();
+    assert_eq!(policy.allowed_file_formats(), &[WriteFileFormat::Parquet]);
+
+    let stored = PostgresBackend::get_warehouse_by_id_cache_aware(
+        warehouse_id,
+        WarehouseStatus::active(),
+        CachePolicy::Skip,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(stored.settings.file_format_policy, Some(policy));
+
+    let prefix = warehouse_id.to_string();
+    let ns_name = format!("ns_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns_name.clone()).await;
+    let create_table = |name: &str, format: &str| {
+        let mut request = create_table_request(Some(name.to_string()), None);
+        request.properties = Some(HashMap::from([(
+            WriteFileFormat::PROPERTY.to_string(),
+            format.to_string(),
+        )]));
+        CatalogServer::create_table(
+            NamespaceParameters {
+                prefix: Some(Prefix(prefix.clone())),
+                namespace: NamespaceIdent::new(ns_name.clone()),
+            },
+            request,
+            DataAccess::not_specified(),
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+
+    let err = create_table("avro_table", "avro").await.unwrap_err();
+    assert_eq!(err.error.r#type, "FileFormatNotAllowed");
+    create_table("parquet_table", "PARQUET").await.unwrap();
+    // Tables without the property write parquet and are allowed.
+    lakekeeper_integration_tests::create_table(
+        ctx.clone(),
+        &prefix,
+        &ns_name,
+        "default_table",
+        false,
+    )
+    .await
+    .unwrap();
+
+    // Omitting the allowed formats clears the policy.
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                file_format_policy: None,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.settings.file_format_policy.is_none());
+    create_table("avro_table", "avro").await.unwrap();
+}
+
+/// Test that an empty file format list is rejected.
+#[sqlx::test]
+async fn test_update_file_format_policy_invalid(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+
+    let request: UpdateWarehouseSettingsRequest = serde_json::from_value(serde_json::json!({
+        "settings": {"file-format-policy": {"allowed-file-formats": []}}
+    }))
+    .unwrap();
+    let err = ApiServer::update_warehouse_settings(
+        warehouse_resp.warehouse_id,
+        request,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "EmptyAllowedFileFormats");
+}
+
+/// Test that the metadata location policy can be toggled via the API and that
+/// tables keep their metadata below the table location while it is enabled.
+#[sqlx::test]
+async fn test_update_metadata_location_policy(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                require_metadata_in_table_location: true,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.settings.require_metadata_in_table_location);
+
+    let stored = PostgresBackend::get_warehouse_by_id_cache_aware(
+        warehouse_id,
+        WarehouseStatus::active(),
+        CachePolicy::Skip,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(stored.settings.require_metadata_in_table_location);
+
+    let prefix = warehouse_id.to_string();
+    let ns_name = format!("ns_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns_name.clone()).await;
+    let table = lakekeeper_integration_tests::create_table(
+        ctx.clone(),
+        &prefix,
+        &ns_name,
+        "my_table",
+        false,
+    )
+    .await
+    .unwrap();
+    let table_location = format!("{}/", table.metadata.location().trim_end_matches('/'));
+    assert!(
+        table
+            .metadata_location
+            .as_deref()
+            .unwrap()
+            .starts_with(&table_location)
+    );
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                require_metadata_in_table_location: false,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(!response.settings.require_metadata_in_table_location);
+}
+
+/// Test that user-provided table locations outside of the warehouse storage root
+/// are rejected regardless of the table location policy.
+#[sqlx::test]
+async fn test_update_table_location_policy(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let stored = PostgresBackend::get_warehouse_by_id_cache_aware(
+        warehouse_id,
+        WarehouseStatus::active(),
+        CachePolicy::Skip,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(stored.settings.require_location_in_warehouse);
+
+    let prefix = warehouse_id.to_string();
+    let ns_name = format!("ns_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns_name.clone()).await;
+    let create_table = |name: &str, location: String| {
+        let mut request = create_table_request(Some(name.to_string()), None);
+        request.location = Some(location);
+        CatalogServer::create_table(
+            NamespaceParameters {
+                prefix: Some(Prefix(prefix.clone())),
+                namespace: NamespaceIdent::new(ns_name.clone()),
+            },
+            request,
+            DataAccess::not_specified(),
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+
+    let base_location = storage_profile.base_location().unwrap().to_string();
+    let in_root = format!(
+        "{}/{}/in_root",
+        base_location.trim_end_matches('/'),
+        Uuid::now_v7()
+    );
+    let out_of_root = format!("memory://other-{}/out_of_root", Uuid::now_v7());
+
+    create_table("in_root", in_root).await.unwrap();
+    let err = create_table("out_of_root", out_of_root.clone())
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.code, http::StatusCode::BAD_REQUEST.as_u16());
+    assert_eq!(err.error.r#type, "InvalidLocation");
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                require_location_in_warehouse: false,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(!response.settings.require_location_in_warehouse);
+
+    let err = create_table("out_of_root", out_of_root).await.unwrap_err();
+    assert_eq!(err.error.r#type, "InvalidLocation");
+}
+
+/// Test that the schema evolution policy can be toggled via the API and that
+/// `safe` rejects a commit removing a required column which `permissive` allows.
+#[sqlx::test]
+async fn test_update_schema_evolution_policy(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                schema_evolution_policy: SchemaEvolutionPolicy::Safe,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        response.settings.schema_evolution_policy,
+        SchemaEvolutionPolicy::Safe
+    );
+
+    let stored = PostgresBackend::get_warehouse_by_id_cache_aware(
+        warehouse_id,
+        WarehouseStatus::active(),
+        CachePolicy::Skip,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(
+        stored.settings.schema_evolution_policy,
+        SchemaEvolutionPolicy::Safe
+    );
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let prefix = warehouse_id.to_string();
+    let ns_name = format!("ns_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns_name.clone()).await;
+    // The table is created with the required columns `id` and `name`.
+    lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, &ns_name, "my_table", false)
+        .await
+        .unwrap();
+
+    let table_ident = iceberg::TableIdent::new(NamespaceIdent::new(ns_name), "my_table".into());
+    let drop_name_column = || {
+        let schema = iceberg::spec::Schema::builder()
+            .with_fields(vec![
+                iceberg::spec::NestedField::required(
+                    1,
+                    "id",
+                    iceberg::spec::Type::Primitive(iceberg::spec::PrimitiveType::Int),
+                )
+                .into(),
+            ])
+            .build()
+            .unwrap();
+        CatalogServer::commit_table(
+            TableParameters {
+                prefix: Some(Prefix(prefix.clone())),
+                table: table_ident.clone(),
+            },
+            iceberg_ext::catalog::rest::CommitTableRequest {
+                identifier: Some(table_ident.clone()),
+                requirements: vec![],
+                updates: vec![
+                    iceberg::TableUpdate::AddSchema { schema },
+                    iceberg::TableUpdate::SetCurrentSchema { schema_id: -1 },
+                ],
+            },
+            vec![],
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+
+    let err = drop_name_column().await.unwrap_err();
+    assert_eq!(err.error.code, 400);
+    assert_eq!(err.error.r#type, "IncompatibleSchemaChange");
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                schema_evolution_policy: SchemaEvolutionPolicy::Permissive,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        response.settings.schema_evolution_policy,
+        SchemaEvolutionPolicy::Permissive
+    );
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let committed = drop_name_column().await.unwrap();
+    assert!(
+        committed
+            .metadata
+            .current_schema()
+            .field_by_name("name")
+            .is_none()
+    );
+}
+
+/// Test that commits exceeding the warehouse snapshot limit expire the oldest
+/// unreferenced snapshots, and that nothing is expired while the oldest snapshot
+/// is still referenced by a tag.
+#[sqlx::test]
+async fn test_snapshot_limit_policy_trims_unreferenced_snapshots(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let err = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                max_snapshots_per_table: Some(0),
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "InvalidMaxSnapshotsPerTable");
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                max_snapshots_per_table: Some(2),
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.settings.max_snapshots_per_table, Some(2));
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let prefix = warehouse_id.to_string();
+    let ns_name = format!("ns_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns_name.clone()).await;
+    lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, &ns_name, "my_table", false)
+        .await
+        .unwrap();
+
+    let table_ident = iceberg::TableIdent::new(NamespaceIdent::new(ns_name), "my_table".into());
+    let commit = |updates: Vec<iceberg::TableUpdate>| {
+        CatalogServer::commit_table(
+            TableParameters {
+                prefix: Some(Prefix(prefix.clone())),
+                table: table_ident.clone(),
+            },
+            iceberg_ext::catalog::rest::CommitTableRequest {
+                identifier: Some(table_ident.clone()),
+                requirements: vec![],
+                updates,
+            },
+            vec![],
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+    let set_ref =
+        |ref_name: &str, snapshot_id: i64, retention| iceberg::TableUpdate::SetSnapshotRef {
+            ref_name: ref_name.to_string(),
+            reference: iceberg::spec::SnapshotReference {
+                snapshot_id,
+                retention,
+            },
+        };
+    let append_to_main = |snapshot_id: i64, parent: Option<i64>| {
+        let snapshot = iceberg::spec::Snapshot::builder()
+            .with_snapshot_id(snapshot_id)
+            .with_parent_snapshot_id(parent)
+            .with_timestamp_ms(chrono::Utc::now().timestamp_millis())
+            .with_sequence_number(snapshot_id)
+            .with_manifest_list(format!("/path/to/manifest{snapshot_id}.avro"))
+            .with_summary(iceberg::spec::Summary {
+                operation: iceberg::spec::Operation::Append,
+                additional_properties: HashMap::new(),
+            })
+            .with_schema_id(0)
+            .build();
+        vec![
+            iceberg::TableUpdate::AddSnapshot { snapshot },
+            set_ref(
+                iceberg::spec::MAIN_BRANCH,
+                snapshot_id,
+                iceberg::spec::SnapshotRetention::Branch {
+                    min_snapshots_to_keep: None,
+                    max_snapshot_age_ms: None,
+                    max_ref_age_ms: None,
+                },
+            ),
+        ]
+    };
+    let snapshot_ids = |response: &iceberg_ext::catalog::rest::CommitTableResponse| {
+        let mut ids = response
+            .metadata
+            .snapshots()
+            .map(|s| s.snapshot_id())
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids
+    };
+
+    commit(append_to_main(1, None)).await.unwrap();
+    let response = commit(append_to_main(2, Some(1))).await.unwrap();
+    assert_eq!(snapshot_ids(&response), vec![1, 2]);
+
+    // The third snapshot exceeds the limit, the unreferenced snapshot 1 is expired.
+    let response = commit(append_to_main(3, Some(2))).await.unwrap();
+    assert_eq!(snapshot_ids(&response), vec![2, 3]);
+
+    // Once the oldest snapshot is tagged it may no longer be expired, so the
+    // limit is exceeded rather than dropping the tag.
+    commit(vec![set_ref(
+        "v1",
+        2,
+        iceberg::spec::SnapshotRetention::Tag {
+            max_ref_age_ms: None,
+        },
+    )])
+    .await
+    .unwrap();
+    let response = commit(append_to_main(4, Some(3))).await.unwrap();
+    assert_eq!(snapshot_ids(&response), vec![2, 3, 4]);
+}
+
+/// Set up a warehouse with the given table properties limits and a namespace in it.
+async fn setup_table_properties_limit(
+    pool: PgPool,
+    settings: WarehouseSettings,
+) -> (
+    ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    WarehouseId,
+    String,
+    String,
+) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: settings.clone(),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.settings, settings);
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let prefix = warehouse_id.to_string();
+    let ns_name = format!("ns_{}", Uuid::now_v7());
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns_name.clone()).await;
+    (ctx, warehouse_id, prefix, ns_name)
+}
+
+async fn create_table_with_properties(
+    ctx: ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    prefix: &str,
+    ns_name: &str,
+    name: &str,
+    properties: &[(&str, &str)],
+) -> lakekeeper::api::Result<lakekeeper::api::iceberg::v1::LoadTableResult> {
+    let mut request = create_table_request(Some(name.to_string()), None);
+    request.properties = Some(
+        properties
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect(),
+    );
+    CatalogServer::create_table(
+        NamespaceParameters {
+            prefix: Some(Prefix(prefix.to_string())),
+            namespace: NamespaceIdent::new(ns_name.to_string()),
+        },
+        request,
+        DataAccess::not_specified(),
+        ctx,
+        random_request_metadata(),
+    )
+    .await
+}
+
+async fn set_table_properties(
+    ctx: ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    prefix: &str,
+    ns_name: &str,
+    name: &str,
+    properties: &[(&str, &str)],
+) -> lakekeeper::api::Result<iceberg_ext::catalog::rest::CommitTableResponse> {
+    let table_ident =
+        iceberg::TableIdent::new(NamespaceIdent::new(ns_name.to_string()), name.to_string());
+    CatalogServer::commit_table(
+        TableParameters {
+            prefix: Some(Prefix(prefix.to_string())),
+            table: table_ident.clone(),
+        },
+        iceberg_ext::catalog::rest::CommitTableRequest {
+            identifier: Some(table_ident),
+            requirements: vec![],
+            updates: vec![iceberg::TableUpdate::SetProperties {
+                updates: properties
+                    .iter()
+                    .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                    .collect(),
+            }],
+        },
+        vec![],
+        ctx,
+        random_request_metadata(),
+    )
+    .await
+}
+
+/// Test that creating tables and committing properties beyond the warehouse
+/// limit on the number of table properties is rejected, while reaching it is allowed.
+#[sqlx::test]
+async fn test_table_properties_count_limit(pool: PgPool) {
+    let (ctx, _, prefix, ns_name) = setup_table_properties_limit(
+        pool,
+        WarehouseSettings {
+            max_table_properties: Some(2),
+            max_table_properties_size_bytes: None,
+            ..Default::default()
+        },
+    )
+    .await;
+
+    let err = create_table_with_properties(
+        ctx.clone(),
+        &prefix,
+        &ns_name,
+        "too_many",
+        &[("a", "1"), ("b", "2"), ("c", "3")],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+
+    let table =
+        create_table_with_properties(ctx.clone(), &prefix, &ns_name, "my_table", &[("a", "1")])
+            .await
+            .unwrap();
+    assert_eq!(table.metadata.properties().len(), 1);
+
+    // Reaching the limit is allowed, exceeding it is not.
+    set_table_properties(ctx.clone(), &prefix, &ns_name, "my_table", &[("b", "2")])
+        .await
+        .unwrap();
+    let err = set_table_properties(ctx.clone(), &prefix, &ns_name, "my_table", &[("c", "3")])
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+    // Overwriting existing properties does not add to the count.
+    set_table_properties(ctx.clone(), &prefix, &ns_name, "my_table", &[("b", "22")])
+        .await
+        .unwrap();
+}
+
+/// Test that creating tables and committing properties beyond the warehouse
+/// limit on the total size of table properties is rejected, while reaching it is allowed.
+#[sqlx::test]
+async fn test_table_properties_size_limit(pool: PgPool) {
+    let (ctx, warehouse_id, prefix, ns_name) = setup_table_properties_limit(
+        pool,
+        WarehouseSettings {
+            max_table_properties: None,
+            max_table_properties_size_bytes: Some(16),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    // 8 + 9 = 17 bytes
+    let err = create_table_with_properties(
+        ctx.clone(),
+        &prefix,
+        &ns_name,
+        "too_large",
+        &[("property", "123456789")],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+
+    // 8 + 8 = 16 bytes
+    create_table_with_properties(
+        ctx.clone(),
+        &prefix,
+        &ns_name,
+        "my_table",
+        &[("property", "12345678")],
+    )
+    .await
+    .unwrap();
+
+    // Growing the value by a single byte exceeds the limit.
+    let err = set_table_properties(
+        ctx.clone(),
+        &prefix,
+        &ns_name,
+        "my_table",
+        &[("property", "123456789")],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+    set_table_properties(
+        ctx.clone(),
+        &prefix,
+        &ns_name,
+        "my_table",
+        &[("property", "1234567")],
+    )
+    .await
+    .unwrap();
+
+    let err = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                max_table_properties: None,
+                max_table_properties_size_bytes: Some(0),
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "InvalidTablePropertiesLimit");
+}
+
+/// Set up a warehouse with the given quota policy.
+async fn setup_quota_policy(
+    pool: PgPool,
+    settings: WarehouseSettings,
+) -> (
+    ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    WarehouseId,
+    String,
+) {
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: settings.clone(),
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.settings, settings);
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    (ctx, warehouse_id, warehouse_id.to_string())
+}
+
+async fn create_namespace(
+    ctx: ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    prefix: &str,
+    ns_name: &str,
+) -> lakekeeper::api::Result<iceberg_ext::catalog::rest::CreateNamespaceResponse> {
+    CatalogServer::create_namespace(
+        Some(Prefix(prefix.to_string())),
+        iceberg_ext::catalog::rest::CreateNamespaceRequest {
+            namespace: NamespaceIdent::new(ns_name.to_string()),
+            properties: None,
+        },
+        ctx,
+        random_request_metadata(),
+    )
+    .await
+}
+
+/// Test that creating namespaces is allowed up to the warehouse quota and
+/// rejected beyond it.
+#[sqlx::test]
+async fn test_namespace_quota(pool: PgPool) {
+    let (ctx, _, prefix) = setup_quota_policy(
+        pool,
+        WarehouseSettings {
+            max_tables: None,
+            max_namespaces: Some(2),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    create_namespace(ctx.clone(), &prefix, "ns1").await.unwrap();
+    create_namespace(ctx.clone(), &prefix, "ns2").await.unwrap();
+    let err = create_namespace(ctx.clone(), &prefix, "ns3")
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.r#type, "QuotaExceeded");
+    assert_eq!(err.error.code, 403);
+    assert!(
+        err.error.message.contains("contains 2 namespaces"),
+        "{}",
+        err.error.message
+    );
+}
+
+/// Test that creating tables is allowed up to the warehouse quota and rejected
+/// beyond it, and that clearing the quota lifts the restriction.
+#[sqlx::test]
+async fn test_table_quota(pool: PgPool) {
+    let (ctx, warehouse_id, prefix) = setup_quota_policy(
+        pool,
+        WarehouseSettings {
+            max_tables: Some(2),
+            max_namespaces: None,
+            ..Default::default()
+        },
+    )
+    .await;
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), "ns".to_string()).await;
+
+    for name in ["t1", "t2"] {
+        lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns", name, false)
+            .await
+            .unwrap();
+    }
+    let err = lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns", "t3", false)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.r#type, "QuotaExceeded");
+    assert!(
+        err.error.message.contains("contains 2 tables"),
+        "{}",
+        err.error.message
+    );
+
+    ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                max_tables: None,
+                max_namespaces: None,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns", "t3", false)
+        .await
+        .unwrap();
+
+    let err = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                max_tables: Some(0),
+                max_namespaces: None,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.r#type, "InvalidQuotaPolicy");
+}
+
+async fn load_table(
+    ctx: ApiContext<State<AllowAllAuthorizer, PostgresBackend, SecretsState>>,
+    prefix: &str,
+    ns_name: &str,
+    name: &str,
+) -> lakekeeper::api::Result<LoadTableResultOrNotModified> {
+    CatalogServer::load_table(
+        TableParameters {
+            prefix: Some(Prefix(prefix.to_string())),
+            table: iceberg::TableIdent::new(
+                NamespaceIdent::new(ns_name.to_string()),
+                name.to_string(),
+            ),
+        },
+        LoadTableRequest::builder().build(),
+        ctx,
+        random_request_metadata(),
+    )
+    .await
+}
+
+/// Test that namespaces and tables of warehouses with case-sensitive names are
+/// only found by their exact name once the setting is enabled, and that
+/// uniqueness stays case-insensitive.
+#[sqlx::test]
+async fn test_case_sensitive_namespace_and_table_lookups(pool: PgPool) {
+    let (ctx, warehouse) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let prefix = warehouse.warehouse_id.to_string();
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), "MyNs".to_string()).await;
+    lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "MyNs", "MyTable", false)
+        .await
+        .unwrap();
+
+    // Lookups ignore case by default.
+    load_table(ctx.clone(), &prefix, "myns", "mytable")
+        .await
+        .unwrap();
+
+    ApiServer::update_warehouse_settings(
+        warehouse.warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                case_sensitive_names: true,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    load_table(ctx.clone(), &prefix, "MyNs", "MyTable")
+        .await
+        .unwrap();
+    let err = load_table(ctx.clone(), &prefix, "MyNs", "mytable")
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.code, 404);
+    let err = load_table(ctx.clone(), &prefix, "myns", "MyTable")
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.code, 404);
+
+    // Names differing only in case still conflict.
+    let err =
+        lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "MyNs", "mytable", false)
+            .await
+            .unwrap_err();
+    assert_eq!(err.error.code, 409);
+    let err = create_namespace(ctx.clone(), &prefix, "myns")
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.code, 409);
+}
+
+/// Test that partition statistics files must exist in storage once the warehouse
+/// validates them, and are accepted without checks otherwise.
+#[sqlx::test]
+async fn test_partition_statistics_validation(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = warehouse_id.to_string();
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), "ns".to_string()).await;
+    let table =
+        lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns", "my_table", false)
+            .await
+            .unwrap();
+    let table_location = table.metadata.location().trim_end_matches('/').to_string();
+
+    let table_ident =
+        iceberg::TableIdent::new(NamespaceIdent::new("ns".to_string()), "my_table".into());
+    let commit = |updates: Vec<iceberg::TableUpdate>| {
+        CatalogServer::commit_table(
+            TableParameters {
+                prefix: Some(Prefix(prefix.clone())),
+                table: table_ident.clone(),
+            },
+            iceberg_ext::catalog::rest::CommitTableRequest {
+                identifier: Some(table_ident.clone()),
+                requirements: vec![],
+                updates,
+            },
+            vec![],
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+    let set_partition_statistics = |snapshot_id: i64, statistics_path: &str| {
+        vec![iceberg::TableUpdate::SetPartitionStatistics {
+            partition_statistics: iceberg::spec::PartitionStatisticsFile {
+                snapshot_id,
+                statistics_path: statistics_path.to_string(),
+                file_size_in_bytes: 4,
+            },
+        }]
+    };
+    let snapshot = iceberg::spec::Snapshot::builder()
+        .with_snapshot_id(1)
+        .with_timestamp_ms(chrono::Utc::now().timestamp_millis())
+        .with_sequence_number(1)
+        .with_manifest_list(format!("{table_location}/metadata/snap-1.avro"))
+        .with_summary(iceberg::spec::Summary {
+            operation: iceberg::spec::Operation::Append,
+            additional_properties: HashMap::new(),
+        })
+        .with_schema_id(0)
+        .build();
+    commit(vec![iceberg::TableUpdate::AddSnapshot { snapshot }])
+        .await
+        .unwrap();
+
+    // Without validation, statistics files are not checked.
+    let unchecked_path = format!("{table_location}/metadata/partition-stats-unchecked.parquet");
+    commit(set_partition_statistics(1, &unchecked_path))
+        .await
+        .unwrap();
+
+    let response = ApiServer::update_warehouse_settings(
+        warehouse_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                validate_partition_statistics_files: true,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.settings.validate_partition_statistics_files);
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let statistics_path = format!("{table_location}/metadata/partition-stats-1.parquet");
+    let err = commit(set_partition_statistics(1, &statistics_path))
+        .await
+        .unwrap_err();
+    assert_eq!(err.error.r#type, "PartitionStatisticsFileNotFound");
+    assert_eq!(err.error.code, 400);
+    let response = load_table(ctx.clone(), &prefix, "ns", "my_table")
+        .await
+        .unwrap();
+    let LoadTableResultOrNotModified::LoadTableResult(loaded) = response else {
+        panic!("Expected the table to be loaded");
+    };
+    assert_eq!(
+        loaded
+            .metadata
+            .partition_statistics_for_snapshot(1)
+            .map(|s| s.statistics_path.as_str()),
+        Some(unchecked_path.as_str())
+    );
+
+    let file_io = storage_profile.file_io(None).await.unwrap();
+    file_io
+        .write(&statistics_path, bytes::Bytes::from_static(b"PAR1"))
+        .await
+        .unwrap();
+    let response = commit(set_partition_statistics(1, &statistics_path))
+        .await
+        .unwrap();
+    assert_eq!(
+        response
+            .metadata
+            .partition_statistics_for_snapshot(1)
+            .map(|s| s.statistics_path.as_str()),
+        Some(statistics_path.as_str())
+    );
+}
+
+/// Test that a warehouse restricted to vended credentials advertises them in its
+/// catalog config and rejects remote signing requests.
+#[sqlx::test]
+async fn test_credential_mode_vended_rejects_remote_signing(pool: PgPool) {
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let set_credential_mode = |credential_mode| {
+        ApiServer::update_warehouse_settings(
+            warehouse_id,
+            UpdateWarehouseSettingsRequest {
+                settings: WarehouseSettings {
+                    credential_mode,
+                    ..Default::default()
+                },
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+    let access_delegation = || async {
+        CatalogServer::get_config(
+            GetConfigQueryParams {
+                warehouse: Some(format!(
+                    "{}/{}",
+                    warehouse_resp.project_id, warehouse_resp.warehouse_name
+                )),
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap()
+        .overrides
+        .get("header.X-Iceberg-Access-Delegation")
+        .cloned()
+    };
+    let sign = || {
+        CatalogServer::sign(
+            Some(Prefix(warehouse_id.to_string())),
+            None,
+            iceberg_ext::catalog::rest::S3SignRequest {
+                region: "local".to_string(),
+                uri: "https://bucket.s3.amazonaws.com/key".parse().unwrap(),
+                method: http::Method::GET,
+                headers: HashMap::new(),
+                body: None,
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+    };
+
+    let response = set_credential_mode(WarehouseCredentialMode::Vended)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.settings.credential_mode,
+        WarehouseCredentialMode::Vended
+    );
+    // Let the settings-updated event refresh the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    assert_eq!(
+        access_delegation().await.as_deref(),
+        Some("vended-credentials")
+    );
+    let err = sign().await.unwrap_err();
+    assert_eq!(err.error.code, 403);
+    assert_eq!(err.error.r#type, "RemoteSigningDisabled");
+
+    set_credential_mode(WarehouseCredentialMode::RemoteSigning)
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(access_delegation().await.as_deref(), Some("remote-signing"));
+
+    // With both modes allowed, clients choose and signing is only rejected
+    // because the memory storage does not support it.
+    let response = set_credential_mode(WarehouseCredentialMode::Both)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.settings.credential_mode,
+        WarehouseCredentialMode::Both
+    );
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(access_delegation().await, None);
+    let err = sign().await.unwrap_err();
+    assert_eq!(err.error.r#type, "UnsupportedStorageType");
+}
+
+/// End-to-end of the managed-by lock through the management handlers (not just
+/// the storage layer): only an instance admin may set/clear the marker, a
+/// managed warehouse's spec is locked even when the resource authorizer allows
+/// the action (AllowAll here), and clearing the marker restores mutability.
+#[sqlx::test]
+async fn test_managed_by_locks_spec_via_handlers(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+
+    let non_admin = RequestMetadata::new_unauthenticated();
+    let admin = RequestMetadata::test_instance_admin(UserId::new_unchecked("oidc", "admin"));
+
+    // A non-instance-admin cannot set the marker (403, even with AllowAll authz).
+    let err = ApiServer::set_warehouse_managed_by(
+        warehouse_id,
+        SetWarehouseManagedByRequest {
+            managed_by: ManagedBy::InstanceAdmin,
+        },
+        ctx.clone(),
+        non_admin.clone(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 403);
+    assert_eq!(err.error.r#type, "InstanceAdminRequired");
+
+    // An instance admin can mark it managed.
+    let resp = ApiServer::set_warehouse_managed_by(
+        warehouse_id,
+        SetWarehouseManagedByRequest {
+            managed_by: ManagedBy::InstanceAdmin,
+        },
+        ctx.clone(),
+        admin.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(resp.managed_by, ManagedBy::InstanceAdmin);
+    // Let the managed-by-set event update the warehouse cache.
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
 
     // Spec mutation by a non-admin is now locked, despite AllowAll passing authz.
     let err =
@@ -1649,6 +3399,20 @@ async fn test_all_spec_mutations_blocked_on_managed_warehouse(pool: PgPool) {
             non_admin.clone(),
         )
     );
+    assert_locked!(
+        "update_warehouse_settings",
+        ApiServer::update_warehouse_settings(
+            warehouse_id,
+            UpdateWarehouseSettingsRequest {
+                settings: WarehouseSettings {
+                    max_snapshots_per_table: Some(10),
+                    ..Default::default()
+                },
+            },
+            ctx.clone(),
+            non_admin.clone(),
+        )
+    );
     assert_locked!(
         "deactivate_warehouse",
         ApiServer::deactivate_warehouse(warehouse_id, ctx.clone(), non_admin.clone())
@@ -1681,3 +3445,452 @@ async fn test_all_spec_mutations_blocked_on_managed_warehouse(pool: PgPool) {
         )
     );
 }
+
+/// Cloning a warehouse copies its configuration, secondary storage profile and
+/// task queue configs, but none of its namespaces or tables.
+#[sqlx::test]
+async fn test_clone_warehouse(pool: PgPool) {
+    let storage_profile = memory_io_profile();
+    let delete_profile = TabularDeleteProfile::Soft {
+        expiration_seconds: chrono::TimeDelta::seconds(3600),
+    };
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(storage_profile.clone())
+        .authorizer(AllowAllAuthorizer::default())
+        .delete_profile(delete_profile)
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let source_id = warehouse_resp.warehouse_id;
+
+    ApiServer::update_warehouse_settings(
+        source_id,
+        UpdateWarehouseSettingsRequest {
+            settings: WarehouseSettings {
+                require_metadata_in_table_location: true,
+                ..Default::default()
+            },
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let secondary_storage_profile = memory_io_profile();
+    ApiServer::update_secondary_storage(
+        source_id,
+        UpdateWarehouseStorageRequest {
+            storage_profile: secondary_storage_profile.clone(),
+            storage_credential: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let queue_config = serde_json::to_value(MetadataLogCompactionQueueConfig {
+        max_metadata_log_entries: Some(10),
+        ..Default::default()
+    })
+    .unwrap();
+    let mut transaction =
+        <PostgresBackend as CatalogStore>::Transaction::begin_write(ctx.v1_state.catalog.clone())
+            .await
+            .unwrap();
+    PostgresBackend::set_task_queue_config(
+        warehouse_resp.project_id.clone(),
+        Some(source_id),
+        &QUEUE_NAME,
+        &SetTaskQueueConfigRequest {
+            queue_config: QueueConfig::from_json(queue_config.clone()),
+            max_seconds_since_last_heartbeat: Some(120),
+        },
+        transaction.transaction(),
+    )
+    .await
+    .unwrap();
+    transaction.commit().await.unwrap();
+
+    let prefix = source_id.to_string();
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), "ns1".to_string()).await;
+    lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns1", "tab1", false)
+        .await
+        .unwrap();
+
+    let clone_name = format!("clone-{}", Uuid::now_v7());
+    let cloned = ApiServer::clone_warehouse(
+        source_id,
+        CloneWarehouseRequest::builder()
+            .warehouse_name(clone_name.clone())
+            .build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let clone_id = cloned.warehouse_id();
+    assert_ne!(clone_id, source_id);
+    assert_eq!(cloned.project_id(), warehouse_resp.project_id);
+
+    let clone = PostgresBackend::get_warehouse_by_id_cache_aware(
+        clone_id,
+        WarehouseStatus::active(),
+        CachePolicy::Skip,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(clone.name, clone_name);
+    assert_eq!(clone.tabular_delete_profile, delete_profile);
+    assert_eq!(clone.storage_profile, storage_profile);
+    assert!(clone.settings.require_metadata_in_table_location);
+    assert_eq!(
+        clone.secondary_storage.map(|s| s.storage_profile),
+        Some(secondary_storage_profile)
+    );
+
+    let cloned_queue_config = PostgresBackend::get_task_queue_config(
+        &TaskQueueConfigFilter::WarehouseId {
+            warehouse_id: clone_id,
+        },
+        &QUEUE_NAME,
+        ctx.v1_state.catalog.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(cloned_queue_config.queue_config.config, queue_config);
+    assert_eq!(
+        cloned_queue_config.max_seconds_since_last_heartbeat,
+        Some(120)
+    );
+
+    let namespaces = CatalogServer::list_namespaces(
+        Some(Prefix(clone_id.to_string())),
+        ListNamespacesQuery {
+            page_token: PageToken::NotSpecified,
+            page_size: None,
+            parent: None,
+            return_uuids: false,
+            return_protection_status: false,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(namespaces.namespaces.is_empty());
+
+    // The name of the clone must still be unique within the project.
+    let err = ApiServer::clone_warehouse(
+        source_id,
+        CloneWarehouseRequest::builder()
+            .warehouse_name(clone_name)
+            .build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 409);
+}
+
+/// Entity counts are only computed if requested and exclude soft-deleted tables.
+#[sqlx::test]
+async fn test_get_warehouse_include_counts(pool: PgPool) {
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .delete_profile(TabularDeleteProfile::Soft {
+            expiration_seconds: chrono::TimeDelta::seconds(3600),
+        })
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = warehouse_id.to_string();
+
+    for ns in ["ns1", "ns2"] {
+        lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns.to_string()).await;
+    }
+    for (ns, table) in [("ns1", "tab1"), ("ns1", "tab2"), ("ns2", "tab3")] {
+        lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, ns, table, false)
+            .await
+            .unwrap();
+    }
+    lakekeeper_integration_tests::create_view(ctx.clone(), &prefix, "ns2", "view1", None)
+        .await
+        .unwrap();
+    lakekeeper_integration_tests::drop_table(ctx.clone(), &prefix, "ns2", "tab3", None, false)
+        .await
+        .unwrap();
+
+    let response = ApiServer::get_warehouse(
+        warehouse_id,
+        GetWarehouseQuery::default(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.num_namespaces, None);
+    assert_eq!(response.num_tables, None);
+    assert_eq!(response.num_views, None);
+
+    let response = ApiServer::get_warehouse(
+        warehouse_id,
+        GetWarehouseQuery::builder().include_counts().build(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.num_namespaces, Some(2));
+    assert_eq!(response.num_tables, Some(2));
+    assert_eq!(response.num_views, Some(1));
+
+    let response = ApiServer::list_warehouses(
+        ListWarehousesRequest {
+            warehouse_status: None,
+            project_id: Some((*warehouse_resp.project_id).clone()),
+            include_counts: true,
+            page_token: None,
+            page_size: None,
+        },
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    let listed = response
+        .warehouses
+        .iter()
+        .find(|w| w.warehouse_id == warehouse_id)
+        .unwrap();
+    assert_eq!(listed.num_namespaces, Some(2));
+    assert_eq!(listed.num_tables, Some(2));
+    assert_eq!(listed.num_views, Some(1));
+}
+
+/// The change version increments on changes within the warehouse.
+#[sqlx::test]
+async fn test_get_warehouse_version(pool: PgPool) {
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = warehouse_id.to_string();
+    let get_version = || async {
+        ApiServer::get_warehouse_version(warehouse_id, ctx.clone(), random_request_metadata())
+            .await
+            .unwrap()
+            .version
+    };
+
+    let initial = get_version().await;
+    lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), "ns1".to_string()).await;
+    let after_namespace = get_version().await;
+    assert!(after_namespace > initial);
+
+    // Creating a table bumps the version
+    lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns1", "tab1", false)
+        .await
+        .unwrap();
+    let after_table = get_version().await;
+    assert!(after_table > after_namespace);
+
+    // Reads do not change the version
+    assert_eq!(get_version().await, after_table);
+
+    // Deleting the namespace and its table bumps the version
+    lakekeeper_integration_tests::drop_namespace(
+        ctx.clone(),
+        NamespaceDropFlags {
+            force: false,
+            purge: false,
+            recursive: true,
+        },
+        NamespaceParameters {
+            prefix: Some(Prefix(prefix.clone())),
+            namespace: NamespaceIdent::new("ns1".to_string()),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(get_version().await > after_table);
+
+    let err = ApiServer::get_warehouse_version(
+        WarehouseId::new_random(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.error.code, 404);
+}
+
+#[sqlx::test]
+async fn test_list_protected_entities(pool: PgPool) {
+    let (ctx, warehouse_resp) = SetupTestCatalog::builder()
+        .pool(pool.clone())
+        .storage_profile(memory_io_profile())
+        .authorizer(AllowAllAuthorizer::default())
+        .number_of_warehouses(1)
+        .build()
+        .setup()
+        .await;
+    let warehouse_id = warehouse_resp.warehouse_id;
+    let prefix = warehouse_id.to_string();
+
+    let mut namespace_ids = Vec::new();
+    for ns in ["ns1", "ns2"] {
+        lakekeeper_integration_tests::create_ns(ctx.clone(), prefix.clone(), ns.to_string()).await;
+        let namespace = PostgresBackend::get_namespace(
+            warehouse_id,
+            NamespaceIdent::new(ns.to_string()),
+            ctx.v1_state.catalog.clone(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        namespace_ids.push(namespace.namespace_id());
+    }
+    let mut table_ids = Vec::new();
+    for table in ["tab1", "tab2"] {
+        let table =
+            lakekeeper_integration_tests::create_table(ctx.clone(), &prefix, "ns1", table, false)
+                .await
+                .unwrap();
+        table_ids.push(table.metadata.uuid());
+    }
+    let mut view_ids = Vec::new();
+    for view in ["view1", "view2"] {
+        let view =
+            lakekeeper_integration_tests::create_view(ctx.clone(), &prefix, "ns2", view, None)
+                .await
+                .unwrap();
+        view_ids.push(view.metadata.uuid());
+    }
+
+    // Nothing is protected yet
+    let response = ApiServer::list_protected_entities(
+        warehouse_id,
+        ListProtectedEntitiesQuery::default(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(!response.warehouse_protected);
+    assert!(response.entities.is_empty());
+    assert!(response.next_page_token.is_none());
+
+    // Protect one of each
+    ApiServer::set_warehouse_protection(warehouse_id, true, ctx.clone(), random_request_metadata())
+        .await
+        .unwrap();
+    ApiServer::set_namespace_protection(
+        namespace_ids[1],
+        warehouse_id,
+        true,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    ApiServer::set_table_protection(
+        table_ids[0].into(),
+        warehouse_id,
+        true,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    ApiServer::set_view_protection(
+        view_ids[1].into(),
+        warehouse_id,
+        true,
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+
+    let response = ApiServer::list_protected_entities(
+        warehouse_id,
+        ListProtectedEntitiesQuery::default(),
+        ctx.clone(),
+        random_request_metadata(),
+    )
+    .await
+    .unwrap();
+    assert!(response.warehouse_protected);
+    let mut entities = response
+        .entities
+        .iter()
+        .map(|e| (e.entity_type, e.id, e.name.join(".")))
+        .collect::<Vec<_>>();
+    entities.sort_by_key(|(_, _, name)| name.clone());
+    assert_eq!(
+        entities,
+        vec![
+            (
+                ProtectedEntityType::Table,
+                table_ids[0],
+                "ns1.tab1".to_string()
+            ),
+            (
+                ProtectedEntityType::Namespace,
+                *namespace_ids[1],
+                "ns2".to_string()
+            ),
+            (
+                ProtectedEntityType::View,
+                view_ids[1],
+                "ns2.view2".to_string()
+            ),
+        ]
+    );
+
+    // Paginate with a page size of one
+    let mut paged = Vec::new();
+    let mut page_token = None;
+    loop {
+        let response = ApiServer::list_protected_entities(
+            warehouse_id,
+            ListProtectedEntitiesQuery {
+                page_token: page_token.clone(),
+                page_size: Some(1),
+            },
+            ctx.clone(),
+            random_request_metadata(),
+        )
+        .await
+        .unwrap();
+        assert!(response.entities.len() <= 1);
+        paged.extend(response.entities.into_iter().map(|e| e.id));
+        page_token = response.next_page_token;
+        if page_token.is_none() {
+            break;
+        }
+    }
+    assert_eq!(
+        paged.iter().copied().collect::<HashSet<_>>(),
+        entities
+            .iter()
+            .map(|(_, id, _)| *id)
+            .collect::<HashSet<_>>()
+    );
+    assert_eq!(paged.len(), 3);
+}
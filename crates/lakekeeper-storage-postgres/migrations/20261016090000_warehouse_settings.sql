@@ -0,0 +1,6 @@
+-- Per-warehouse policies for tables, views and data access, serialized from
+-- `WarehouseSettings`. Omitted keys take their default.
+alter table warehouse
+    add column settings jsonb not null default '{}';
+
+alter type api_endpoints add value if not exists 'management-v1-update-warehouse-settings';
@@ -0,0 +1,4 @@
+-- Attempt at which a failed task was last requeued. Retries are counted from here,
+-- so a requeued task gets its full retry budget back.
+alter table task
+    add column requeued_at_attempt integer not null default 0;
@@ -0,0 +1,20 @@
+-- Audit trail of mutating management API requests. Only written if
+-- LAKEKEEPER__AUDIT__POSTGRES__ENABLED is set. Deliberately without foreign
+-- keys: records must outlive the projects and warehouses they refer to.
+create table audit_log
+(
+    audit_log_id uuid primary key,
+    request_id   uuid          not null,
+    project_id   text,
+    operation    api_endpoints not null,
+    -- Acting principal, e.g. {"actor_type": "principal", "principal": "oidc~..."}
+    actor        jsonb         not null,
+    -- Ids of the targeted entities, keyed by path parameter name
+    targets      jsonb         not null,
+    outcome      text          not null check (outcome in ('success', 'failure')),
+    http_status  integer       not null,
+    created_at   timestamptz   not null default now()
+);
+
+create index audit_log_created_at_idx on audit_log (created_at);
+create index audit_log_project_id_created_at_idx on audit_log (project_id, created_at);
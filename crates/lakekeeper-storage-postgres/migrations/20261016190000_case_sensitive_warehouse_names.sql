@@ -0,0 +1,19 @@
+-- Per-project opt-in for case-sensitive warehouse names. The setting is mirrored
+-- onto every warehouse of the project so that uniqueness can be enforced by
+-- partial unique indexes instead of a check that would race with concurrent creates.
+ALTER TABLE project
+ADD COLUMN case_sensitive_warehouse_names boolean NOT NULL DEFAULT false;
+
+ALTER TABLE warehouse
+ADD COLUMN case_sensitive_name boolean NOT NULL DEFAULT false;
+
+ALTER TABLE warehouse DROP CONSTRAINT unique_warehouse_name_in_project;
+
+-- `warehouse_name` uses the `case_insensitive` collation, so this keeps today's behavior.
+CREATE UNIQUE INDEX unique_warehouse_name_in_project ON warehouse (project_id, warehouse_name)
+WHERE
+    NOT case_sensitive_name;
+
+CREATE UNIQUE INDEX unique_warehouse_name_in_project_case_sensitive ON warehouse (project_id, (warehouse_name COLLATE "C"))
+WHERE
+    case_sensitive_name;
@@ -0,0 +1,2 @@
+alter type api_endpoints add value if not exists 'management-v1-get-view-versions';
+alter type api_endpoints add value if not exists 'management-v1-rollback-view';
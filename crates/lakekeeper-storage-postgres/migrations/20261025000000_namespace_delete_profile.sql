@@ -0,0 +1,12 @@
+-- Optional per-namespace override of the warehouse tabular delete profile.
+-- Both columns are NULL if the namespace uses the warehouse default.
+alter table namespace
+    add column tabular_delete_mode tabular_delete_mode,
+    add column tabular_expiration_seconds bigint;
+alter table namespace
+    add check ( (tabular_delete_mode IS NULL AND tabular_expiration_seconds IS NULL) OR
+                (tabular_expiration_seconds IS NOT NULL AND tabular_delete_mode = 'soft') OR
+                (tabular_expiration_seconds IS NULL AND tabular_delete_mode = 'hard') );
+
+alter type api_endpoints add value if not exists 'management-v1-get-namespace-delete-profile';
+alter type api_endpoints add value if not exists 'management-v1-set-namespace-delete-profile';
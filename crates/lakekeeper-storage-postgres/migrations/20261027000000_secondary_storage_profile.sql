@@ -0,0 +1,11 @@
+-- Optional secondary storage profile of a warehouse. Namespaces and tables select it
+-- on creation; the profile a tabular is stored on is recorded in `storage_profile_slot`.
+alter table warehouse
+    add column secondary_storage_profile jsonb,
+    add column secondary_storage_secret_id uuid;
+
+create type storage_profile_slot as enum ('primary', 'secondary');
+alter table tabular
+    add column storage_profile_slot storage_profile_slot not null default 'primary';
+
+alter type api_endpoints add value if not exists 'management-v1-update-secondary-storage-profile';
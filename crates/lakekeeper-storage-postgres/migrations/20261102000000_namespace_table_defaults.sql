@@ -0,0 +1,8 @@
+-- Optional partition spec and sort order inherited by new tables of a namespace.
+-- NULL if new tables are unpartitioned / unsorted unless the create request specifies them.
+alter table namespace
+    add column default_partition_spec jsonb,
+    add column default_sort_order jsonb;
+
+alter type api_endpoints add value if not exists 'management-v1-get-namespace-table-defaults';
+alter type api_endpoints add value if not exists 'management-v1-set-namespace-table-defaults';
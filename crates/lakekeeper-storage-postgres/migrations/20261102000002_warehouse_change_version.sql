@@ -0,0 +1,70 @@
+alter type api_endpoints add value if not exists 'management-v1-get-warehouse-version';
+
+-- Monotonic per-warehouse counter that increments on every change of the
+-- warehouse itself or of any namespace or tabular within it. Kept separate from
+-- `warehouse.version`, which only tracks the warehouse row and is used to
+-- validate cached warehouses.
+--
+-- The counter row is updated inside every mutating transaction. Concurrent
+-- writers of a warehouse wait for each other's row lock, so versions are
+-- assigned in commit order and a reader never misses a change that committed
+-- after it observed a higher version.
+create table warehouse_change_version
+(
+    warehouse_id uuid primary key not null REFERENCES warehouse (warehouse_id) ON DELETE CASCADE,
+    version      bigint           not null default 0
+);
+
+insert into warehouse_change_version (warehouse_id, version)
+select warehouse_id, version
+from warehouse;
+
+create or replace function create_warehouse_change_version() returns trigger as
+$$
+begin
+    INSERT INTO warehouse_change_version (warehouse_id, version)
+    VALUES (NEW.warehouse_id, NEW.version)
+    ON CONFLICT (warehouse_id) DO NOTHING;
+    RETURN NULL;
+end;
+$$ language plpgsql;
+
+create or replace function increment_warehouse_change_version() returns trigger as
+$$
+begin
+    -- COALESCE here is to handle the case when the row is being deleted and NEW is null
+    UPDATE warehouse_change_version
+    SET version = version + 1
+    WHERE warehouse_id = COALESCE(NEW.warehouse_id, OLD.warehouse_id);
+    RETURN NULL;
+end;
+$$ language plpgsql;
+
+CREATE TRIGGER create_warehouse_change_version
+    AFTER INSERT
+    ON warehouse
+    FOR EACH ROW
+EXECUTE PROCEDURE create_warehouse_change_version();
+
+-- Deferred to the end of the transaction like `update_counts`, so that the
+-- counter row is only locked while the transaction commits.
+CREATE CONSTRAINT TRIGGER increment_warehouse_change_version
+    AFTER UPDATE
+    ON warehouse
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW
+EXECUTE PROCEDURE increment_warehouse_change_version();
+
+CREATE CONSTRAINT TRIGGER increment_warehouse_change_version
+    AFTER INSERT OR UPDATE OR DELETE
+    ON namespace
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW
+EXECUTE PROCEDURE increment_warehouse_change_version();
+
+CREATE CONSTRAINT TRIGGER increment_warehouse_change_version
+    AFTER INSERT OR UPDATE OR DELETE
+    ON tabular
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW
+EXECUTE PROCEDURE increment_warehouse_change_version();
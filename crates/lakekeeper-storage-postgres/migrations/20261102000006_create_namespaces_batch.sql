@@ -0,0 +1 @@
+alter type api_endpoints add value if not exists 'management-v1-create-namespaces-batch';
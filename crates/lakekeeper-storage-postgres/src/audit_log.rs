@@ -0,0 +1,157 @@
+use std::str::FromStr as _;
+
+use iceberg_ext::catalog::rest::ErrorModel;
+use lakekeeper::{
+    CONFIG,
+    api::{
+        Result,
+        endpoints::EndpointFlat,
+        management::v1::audit::{AuditLogEntry, QueryAuditLogRequest, QueryAuditLogResponse},
+    },
+    service::{ArcProjectId, AuditLogRecord, events::OperationOutcome},
+};
+
+use super::{
+    PostgresBackend,
+    dbutils::DBErrorHandler as _,
+    pagination::{PaginateToken, V1PaginateToken},
+};
+
+impl PostgresBackend {
+    pub(crate) async fn insert_audit_log_record_impl(
+        record: &AuditLogRecord,
+        state: <Self as lakekeeper::service::CatalogStore>::State,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO audit_log (audit_log_id, request_id, project_id, operation, actor, targets, outcome, http_status)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            uuid::Uuid::now_v7(),
+            record.request_id,
+            record.project_id.as_ref().map(|p| p.as_str()),
+            record.operation as EndpointFlat,
+            record.actor,
+            record.targets,
+            record.outcome.as_str(),
+            i32::from(record.http_status.as_u16()),
+        )
+        .execute(&state.write_pool())
+        .await
+        .map_err(|e: sqlx::Error| e.into_error_model("Error inserting audit log record"))?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn query_audit_log_impl(
+        project_id: &ArcProjectId,
+        query: &QueryAuditLogRequest,
+        state: <Self as lakekeeper::service::CatalogStore>::State,
+    ) -> Result<QueryAuditLogResponse> {
+        let QueryAuditLogRequest {
+            actor,
+            warehouse_id,
+            operation,
+            created_after,
+            created_before,
+            page_token,
+            page_size,
+        } = query.clone();
+
+        let page_size = CONFIG.page_size_or_pagination_default(page_size);
+        let previous_page_token = page_token.clone();
+        let token = page_token.map(PaginateToken::try_from).transpose()?;
+        let (pagination_ts, pagination_id) = token
+            .as_ref()
+            .map(
+                |PaginateToken::V1(V1PaginateToken { created_at, id }): &PaginateToken<
+                    uuid::Uuid,
+                >| (created_at, id),
+            )
+            .map_or((None, None), |(ts, id)| (Some(ts), Some(id)));
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                audit_log_id,
+                request_id,
+                project_id,
+                operation::text as "operation!",
+                actor,
+                targets,
+                outcome,
+                http_status,
+                created_at
+            FROM audit_log
+            WHERE project_id = $1
+                AND ($2::text IS NULL OR actor->>'principal' = $2)
+                AND ($3::text IS NULL OR targets->>'warehouse_id' = $3)
+                AND ($4::text[] IS NULL OR operation::text = ANY($4))
+                AND (created_at >= $5 OR $5 IS NULL)
+                AND (created_at <= $6 OR $6 IS NULL)
+                AND ((created_at < $7 OR $7 IS NULL) OR (created_at = $7 AND audit_log_id < $8))
+            ORDER BY created_at DESC, audit_log_id DESC
+            LIMIT $9
+            "#,
+            project_id.as_str(),
+            actor.map(|a| a.to_string()),
+            warehouse_id.map(|w| w.to_string()),
+            operation.as_deref(),
+            created_after,
+            created_before,
+            pagination_ts,
+            pagination_id.copied(),
+            page_size,
+        )
+        .fetch_all(&state.read_pool())
+        .await
+        .map_err(|e: sqlx::Error| e.into_error_model("Error querying audit log"))?;
+
+        let entries = rows
+            .into_iter()
+            .map(|row| {
+                let outcome = OperationOutcome::from_str(&row.outcome).map_err(|e| {
+                    ErrorModel::internal(
+                        format!("Invalid outcome '{}' in audit log", row.outcome),
+                        "InvalidAuditLogOutcome",
+                        Some(Box::new(e)),
+                    )
+                })?;
+                let http_status = u16::try_from(row.http_status).map_err(|e| {
+                    ErrorModel::internal(
+                        format!("Invalid HTTP status '{}' in audit log", row.http_status),
+                        "InvalidAuditLogStatus",
+                        Some(Box::new(e)),
+                    )
+                })?;
+                Ok(AuditLogEntry {
+                    audit_log_id: row.audit_log_id,
+                    request_id: row.request_id,
+                    project_id: row.project_id,
+                    operation: row.operation,
+                    actor: row.actor,
+                    targets: row.targets,
+                    outcome,
+                    http_status,
+                    created_at: row.created_at,
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, ErrorModel>>()?;
+
+        let next_page_token = entries
+            .last()
+            .map(|last| {
+                PaginateToken::V1(V1PaginateToken {
+                    created_at: last.created_at,
+                    id: last.audit_log_id,
+                })
+                .to_string()
+            })
+            .or(previous_page_token);
+
+        Ok(QueryAuditLogResponse {
+            entries,
+            next_page_token,
+        })
+    }
+}
@@ -12,8 +12,11 @@ use lakekeeper::{
         },
         management::v1::{
             DeleteWarehouseQuery, TabularType,
+            audit::{QueryAuditLogRequest, QueryAuditLogResponse},
+            namespace::NamespaceTableDefaults,
             project::{EndpointStatisticsResponse, TimeWindowSelector, WarehouseFilter},
             role::UpdateRoleSourceSystemRequest,
+            table::{ListTableSnapshotsQuery, ListTableSnapshotsResponse},
             task_queue::{GetTaskQueueConfigResponse, SetTaskQueueConfigRequest},
             tasks::ListTasksRequest,
             user::{ListUsersResponse, SearchUserResponse, UserLastUpdatedWith, UserType},
@@ -22,13 +25,16 @@ use lakekeeper::{
     },
     service::{
         AddRoleMembersError, AddRoleMembersResult, AddUserRoleAssignmentsError,
-        AddUserRoleAssignmentsResult, ArcProjectId, CatalogBackendError,
+        AddUserRoleAssignmentsResult, ArcProjectId, AuditLogRecord, CatalogBackendError,
         CatalogCreateNamespaceError, CatalogCreateRoleRequest, CatalogCreateWarehouseError,
-        CatalogCreateWarehouseRequest, CatalogDeleteWarehouseError, CatalogGetNamespaceError,
-        CatalogGetWarehouseByIdError, CatalogGetWarehouseByNameError, CatalogListNamespaceError,
-        CatalogListNamespacesResponse, CatalogListRolesByIdFilter, CatalogListWarehousesError,
+        CatalogCreateWarehouseRequest, CatalogDeleteWarehouseError,
+        CatalogGetNamespaceDeleteProfileError, CatalogGetNamespaceError,
+        CatalogGetNamespaceTableDefaultsError, CatalogGetWarehouseByIdError,
+        CatalogGetWarehouseByNameError, CatalogListNamespaceError, CatalogListNamespacesResponse,
+        CatalogListRolesByIdFilter, CatalogListWarehousesError, CatalogMoveNamespaceError,
         CatalogNamespaceDropError, CatalogRenameWarehouseError, CatalogRoleForAssignment,
-        CatalogSearchTabularResponse, CatalogSetNamespaceProtectedError, CatalogStore,
+        CatalogSearchTabularResponse, CatalogSetNamespaceDeleteProfileError,
+        CatalogSetNamespaceProtectedError, CatalogSetNamespaceTableDefaultsError, CatalogStore,
         CatalogUpdateNamespacePropertiesError, CatalogUserRoleAssignmentUser, CatalogView,
         ClearTabularDeletedAtError, CommitTableTransactionError, CommitViewError,
         CreateGenericTableError, CreateNamespaceRequest, CreateOrUpdateUserResponse,
@@ -36,23 +42,27 @@ use lakekeeper::{
         DropTabularError, EnsureWarehouseSpecMutableError, GenericTableCreation, GenericTableId,
         GenericTableInfo, GenericTableListEntry, GetProjectResponse, GetTabularInfoByLocationError,
         GetTabularInfoError, GetTaskDetailsError, ListCatalogRoleMembersPage,
-        ListGenericTablesError, ListNamespacesQuery, ListRoleMembersResult, ListRolesError,
-        ListRolesPage, ListRolesResponse, ListTabularsError, ListUserRoleAssignmentsResult,
-        LoadGenericTableError, LoadTableError, LoadTableResponse, LoadViewError, ManagedBy,
-        MarkTabularAsDeletedError, NamespaceDropInfo, NamespaceId, NamespaceWithParent, ProjectId,
-        RemoveRoleMembersError, RemoveRoleMembersResult, RemoveUserRoleAssignmentsError,
-        RemoveUserRoleAssignmentsResult, RenameTabularError, ResolveTasksError, ResolvedTask,
-        ResolvedWarehouse, Result, Role, RoleId, RoleIdent, RoleMemberKind,
-        RoleMembershipDirection, RoleMembershipEntry, RoleProviderId, SearchRoleResponse,
-        SearchRolesError, SearchTabularError, ServerId, ServerInfo, SetTabularProtectionError,
+        ListGenericTablesError, ListNamespacesQuery, ListProtectedEntitiesError,
+        ListRoleMembersResult, ListRolesError, ListRolesPage, ListRolesResponse, ListTabularsError,
+        ListUserRoleAssignmentsResult, LoadGenericTableError, LoadTableError, LoadTableResponse,
+        LoadViewError, ManagedBy, MarkTabularAsDeletedError, NamespaceDropInfo, NamespaceId,
+        NamespaceMoveInfo, NamespaceWithParent, ProjectId, ProtectedEntity, RemoveRoleMembersError,
+        RemoveRoleMembersResult, RemoveUserRoleAssignmentsError, RemoveUserRoleAssignmentsResult,
+        RenameTabularError, ResolveTasksError, ResolvedTask, ResolvedWarehouse, Result, Role,
+        RoleId, RoleIdent, RoleMemberKind, RoleMembershipDirection, RoleMembershipEntry,
+        RoleProviderId, SearchRoleResponse, SearchRolesError, SearchTabularError, ServerId,
+        ServerInfo, SetNamespaceTabularsProtectionError, SetTabularProtectionError,
         SetWarehouseDeletionProfileError, SetWarehouseFormatVersionPolicyError,
-        SetWarehouseManagedByError, SetWarehouseProtectedError, SetWarehouseStatusError,
-        StagedTableId, SyncRoleMembersError, SyncRoleMembersResult, SyncUserRoleAssignmentsError,
-        SyncUserRoleAssignmentsResult, TableCommit, TableCreation, TableId, TableIdent, TableInfo,
-        TabularId, TabularIdentBorrowed, TabularListFlags, TaskDetails, TaskList, Transaction,
-        UniqueMembers, UniqueRoles, UpdateRoleError, UpdateWarehouseStorageProfileError,
-        UserMembershipEntry, UserUpsertMode, ViewCommit, ViewId, ViewInfo, ViewOrTableDeletionInfo,
-        ViewOrTableInfo, WarehouseFormatVersionPolicy, WarehouseId, WarehouseStatus,
+        SetWarehouseManagedByError, SetWarehouseProtectedError, SetWarehouseSettingsError,
+        SetWarehouseStatusError, StagedTableId, SyncRoleMembersError, SyncRoleMembersResult,
+        SyncUserRoleAssignmentsError, SyncUserRoleAssignmentsResult, TableCommit, TableCreation,
+        TableId, TableIdent, TableInfo, TabularId, TabularIdentBorrowed, TabularListFlags,
+        TabularPropertiesSize, TabularProtectionCounts, TaskDetails, TaskList, TaskQueueDepth,
+        TouchTabularError, Transaction, UniqueMembers, UniqueRoles, UpdateRoleError,
+        UpdateWarehouseStorageProfileError, UserMembershipEntry, UserUpsertMode, ViewCommit,
+        ViewId, ViewInfo, ViewOrTableDeletionInfo, ViewOrTableInfo, WarehouseEntityCounts,
+        WarehouseFormatVersionPolicy, WarehouseId, WarehouseQuotaEntity, WarehouseSettings,
+        WarehouseStatus,
         authn::UserId,
         idempotency::{IdempotencyCheck, IdempotencyInfo, IdempotencyKey},
         storage::StorageProfile,
@@ -68,45 +78,61 @@ use lakekeeper_io::Location;
 use super::{
     CatalogState, PostgresTransaction,
     bootstrap::{bootstrap, get_validation_data, reopen_for_bootstrap},
-    namespace::{create_namespace, drop_namespace, list_namespaces, update_namespace_properties},
+    namespace::{
+        create_namespace, drop_namespace, list_namespaces, list_namespaces_recursive,
+        update_namespace_properties,
+    },
     role::{create_roles, delete_roles, list_roles, list_roles_by_idents, update_role},
     tabular::table::load_tables,
     warehouse::{
         create_project, create_warehouse, delete_project, delete_warehouse, get_project,
-        get_warehouse_by_id, get_warehouse_by_name, list_projects, list_warehouses, rename_project,
-        rename_warehouse, set_warehouse_deletion_profile, set_warehouse_status,
-        update_storage_profile,
+        get_warehouse_by_id, get_warehouse_by_name, list_projects, list_warehouses,
+        list_warehouses_paginated, rename_project, rename_warehouse,
+        set_project_case_sensitive_warehouse_names, set_warehouse_deletion_profile,
+        set_warehouse_status, update_secondary_storage_profile, update_storage_profile,
     },
 };
 use crate::{
-    endpoint_statistics::list::list_statistics,
-    namespace::{get_namespaces_by_id, get_namespaces_by_name, set_namespace_protected},
+    endpoint_statistics::{delete::delete_statistics, list::list_statistics},
+    namespace::{
+        get_namespace_delete_profile, get_namespace_table_defaults, get_namespaces_by_id,
+        get_namespaces_by_name, move_namespace, namespace_last_activity,
+        set_namespace_delete_profile, set_namespace_protected, set_namespace_table_defaults,
+    },
     role::{search_role, update_role_source_system},
     tabular::{
-        clear_tabular_deleted_at, drop_tabular, get_tabular_infos_by_idents,
-        get_tabular_infos_by_ids, get_tabular_infos_by_s3_location, list_tabulars,
-        mark_tabular_as_deleted, rename_tabular, search_tabular, set_tabular_protected,
-        table::{commit_table_transaction, create_table},
+        clear_tabular_deleted_at, drop_tabular, get_soft_deleted_tabular,
+        get_tabular_infos_by_idents, get_tabular_infos_by_ids, get_tabular_infos_by_s3_location,
+        list_tabulars, list_tabulars_exceeding_properties_size, mark_tabular_as_deleted,
+        rename_tabular, search_tabular, set_namespace_tabulars_protected, set_tabular_protected,
+        table::{commit_table_transaction, create_table, list_table_snapshots},
+        touch_tabular,
         view::{commit_existing_view, create_view, load_view},
     },
     tasks::{
         cancel_scheduled_tasks, check_and_heartbeat_task, cleanup_task_logs_older_than,
-        get_task_details, get_task_queue_config, list_tasks, pick_task, queue_task_batch,
-        record_failure, record_success, request_tasks_stop, reschedule_tasks_for, resolve_tasks,
+        get_task_details, get_task_queue_config, get_task_queue_depth, list_tasks, pick_task,
+        queue_task_batch, record_failure, record_success, release_task, request_tasks_stop,
+        requeue_failed_tasks, reschedule_tasks_for, reschedule_unfinished_tasks, resolve_tasks,
         set_task_queue_config,
     },
     user::{create_or_update_user, delete_user, list_users, search_user},
     warehouse::{
-        ensure_warehouse_spec_mutable, get_warehouse_stats, set_warehouse_format_version_policy,
-        set_warehouse_managed_by, set_warehouse_protection,
+        count_warehouse_entities_up_to, ensure_warehouse_spec_mutable,
+        get_warehouse_change_version, get_warehouse_entity_counts, get_warehouse_stats,
+        list_protected_entities, set_warehouse_format_version_policy, set_warehouse_managed_by,
+        set_warehouse_protection, set_warehouse_settings,
     },
 };
 
+// Every method opens a span, so that DB round-trips show up as children of the
+// catalog operation in traces.
 #[async_trait::async_trait]
 impl CatalogStore for super::PostgresBackend {
     type Transaction = PostgresTransaction;
     type State = CatalogState;
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_server_info(
         catalog_state: Self::State,
     ) -> std::result::Result<ServerInfo, ErrorModel> {
@@ -114,6 +140,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     // ---------------- Bootstrap ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn bootstrap<'a>(
         terms_accepted: bool,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
@@ -121,10 +148,12 @@ impl CatalogStore for super::PostgresBackend {
         bootstrap(terms_accepted, &mut **transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn reopen_for_bootstrap(catalog_state: Self::State) -> Result<ServerId> {
         reopen_for_bootstrap(&catalog_state.write_pool()).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_warehouse_by_name_impl(
         warehouse_name: &str,
         project_id: &ProjectId,
@@ -133,6 +162,7 @@ impl CatalogStore for super::PostgresBackend {
         get_warehouse_by_name(warehouse_name, project_id, catalog_state).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_namespaces_impl<'a>(
         warehouse_id: WarehouseId,
         query: &ListNamespacesQuery,
@@ -141,6 +171,16 @@ impl CatalogStore for super::PostgresBackend {
         list_namespaces(warehouse_id, query, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn list_namespaces_recursive_impl<'a>(
+        warehouse_id: WarehouseId,
+        query: &ListNamespacesQuery,
+        transaction: <Self::Transaction as Transaction<CatalogState>>::Transaction<'a>,
+    ) -> std::result::Result<CatalogListNamespacesResponse, CatalogListNamespaceError> {
+        list_namespaces_recursive(warehouse_id, query, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_namespace_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -150,6 +190,7 @@ impl CatalogStore for super::PostgresBackend {
         create_namespace(warehouse_id, namespace_id, request, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_namespaces_by_id_impl<'a, 'b, SOT>(
         warehouse_id: WarehouseId,
         namespaces: &[NamespaceId],
@@ -175,6 +216,7 @@ impl CatalogStore for super::PostgresBackend {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_namespaces_by_ident_impl<'a, 'b, SOT>(
         warehouse_id: WarehouseId,
         namespaces: &[&NamespaceIdent],
@@ -200,6 +242,7 @@ impl CatalogStore for super::PostgresBackend {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn drop_namespace_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -209,6 +252,7 @@ impl CatalogStore for super::PostgresBackend {
         drop_namespace(warehouse_id, namespace_id, flags, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_namespace_properties_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -218,6 +262,7 @@ impl CatalogStore for super::PostgresBackend {
         update_namespace_properties(warehouse_id, namespace_id, properties, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_table_impl<'a>(
         table_creation: TableCreation<'_>,
         transaction: <Self::Transaction as Transaction<CatalogState>>::Transaction<'a>,
@@ -225,6 +270,7 @@ impl CatalogStore for super::PostgresBackend {
         create_table(table_creation, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn rename_tabular_impl(
         warehouse_id: WarehouseId,
         source_id: TabularId,
@@ -235,6 +281,7 @@ impl CatalogStore for super::PostgresBackend {
         rename_tabular(warehouse_id, source_id, source, destination, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn drop_tabular_impl<'a>(
         warehouse_id: WarehouseId,
         tabular_id: TabularId,
@@ -244,6 +291,7 @@ impl CatalogStore for super::PostgresBackend {
         drop_tabular(warehouse_id, tabular_id, force, None, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_tabular_infos_by_ident_impl(
         warehouse_id: WarehouseId,
         tabulars: &[TabularIdentBorrowed<'_>],
@@ -259,6 +307,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_tabular_infos_by_id_impl(
         warehouse_id: WarehouseId,
         tabulars: &[TabularId],
@@ -274,6 +323,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_tabular_infos_by_s3_location_impl(
         warehouse_id: WarehouseId,
         location: &Location,
@@ -284,6 +334,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     // Should also load staged tables but not tables of inactive warehouses
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn load_tables_impl<'a>(
         warehouse_id: WarehouseId,
         tables: impl IntoIterator<Item = TableId> + Send,
@@ -294,6 +345,7 @@ impl CatalogStore for super::PostgresBackend {
         load_tables(warehouse_id, tables, include_deleted, filters, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn clear_tabular_deleted_at_impl(
         tabular_ids: &[TabularId],
         warehouse_id: WarehouseId,
@@ -302,6 +354,16 @@ impl CatalogStore for super::PostgresBackend {
         clear_tabular_deleted_at(tabular_ids, warehouse_id, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_soft_deleted_tabular_impl(
+        warehouse_id: WarehouseId,
+        tabular_id: TabularId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<Option<ViewOrTableDeletionInfo>, GetTabularInfoError> {
+        get_soft_deleted_tabular(warehouse_id, tabular_id, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn mark_tabular_as_deleted_impl(
         warehouse_id: WarehouseId,
         tabular_id: TabularId,
@@ -311,6 +373,7 @@ impl CatalogStore for super::PostgresBackend {
         mark_tabular_as_deleted(warehouse_id, tabular_id, force, None, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn commit_table_transaction_impl<'a>(
         warehouse_id: WarehouseId,
         commits: impl IntoIterator<Item = TableCommit> + Send,
@@ -319,7 +382,18 @@ impl CatalogStore for super::PostgresBackend {
         commit_table_transaction(warehouse_id, commits, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn list_table_snapshots_impl(
+        warehouse_id: WarehouseId,
+        table_id: TableId,
+        query: &ListTableSnapshotsQuery,
+        state: Self::State,
+    ) -> Result<ListTableSnapshotsResponse> {
+        list_table_snapshots(warehouse_id, table_id, query, state).await
+    }
+
     // ---------------- Role Management API ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_roles_impl<'a>(
         project_id: &ProjectId,
         roles_to_create: Vec<CatalogCreateRoleRequest<'_>>,
@@ -329,6 +403,7 @@ impl CatalogStore for super::PostgresBackend {
         create_roles(project_id, roles_to_create, on_conflict, &mut **transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_role_impl<'a>(
         project_id: &ProjectId,
         role_id: RoleId,
@@ -346,6 +421,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_role_source_system_impl<'a>(
         project_id: &ProjectId,
         role_id: RoleId,
@@ -355,6 +431,7 @@ impl CatalogStore for super::PostgresBackend {
         update_role_source_system(project_id, role_id, request, &mut **transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_roles_impl(
         project_id: Option<&ProjectId>,
         filter: CatalogListRolesByIdFilter<'_>,
@@ -364,6 +441,7 @@ impl CatalogStore for super::PostgresBackend {
         list_roles(project_id, filter, pagination, &catalog_state.read_pool()).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn delete_roles_impl<'a>(
         project_id: Option<&ProjectId>,
         filter: lakekeeper::service::CatalogListRolesByIdFilter<'_>,
@@ -372,6 +450,7 @@ impl CatalogStore for super::PostgresBackend {
         delete_roles(project_id, filter, &mut **transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn search_role_impl(
         project_id: &ProjectId,
         search_term: &str,
@@ -380,6 +459,7 @@ impl CatalogStore for super::PostgresBackend {
         search_role(project_id, search_term, &catalog_state.read_pool()).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_roles_by_idents_impl(
         project_id: &ProjectId,
         idents: &[&RoleIdent],
@@ -389,6 +469,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     // ---------------- Role Assignment Management ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn sync_role_members_by_ident_impl<'a>(
         project_id: &ProjectId,
         role: &CatalogRoleForAssignment<'_>,
@@ -407,6 +488,7 @@ impl CatalogStore for super::PostgresBackend {
             .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn sync_user_role_assignments_by_provider_impl<'a>(
         user: &CatalogUserRoleAssignmentUser<'_>,
         project_id: &ProjectId,
@@ -434,6 +516,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_role_assignments_for_user_impl(
         user_id: &UserId,
         catalog_state: Self::State,
@@ -442,6 +525,7 @@ impl CatalogStore for super::PostgresBackend {
             .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_role_assignments_for_role_impl(
         role_id: RoleId,
         catalog_state: Self::State,
@@ -450,6 +534,7 @@ impl CatalogStore for super::PostgresBackend {
             .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_role_assignments_for_role_by_ident_impl(
         project_id: &ProjectId,
         role_ident: &RoleIdent,
@@ -463,6 +548,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn add_role_members_impl<'a>(
         project_id: &ArcProjectId,
         parent_role_id: RoleId,
@@ -479,6 +565,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn remove_role_members_impl<'a>(
         parent_role_id: RoleId,
         member_role_ids: &[RoleId],
@@ -488,6 +575,7 @@ impl CatalogStore for super::PostgresBackend {
             .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn add_user_role_assignments_impl<'a>(
         project_id: &ArcProjectId,
         role_id: RoleId,
@@ -503,6 +591,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn remove_user_role_assignments_impl<'a>(
         role_id: RoleId,
         user_ids: &[UserId],
@@ -511,6 +600,7 @@ impl CatalogStore for super::PostgresBackend {
         super::role_assignment::remove_user_role_assignments(role_id, user_ids, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_role_memberships_impl(
         role_id: RoleId,
         direction: RoleMembershipDirection,
@@ -524,6 +614,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn affected_users_for_membership_edges_impl<'a>(
         member_role_ids: &[RoleId],
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
@@ -536,6 +627,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_direct_role_members_page(
         project_id: &ProjectId,
         role_id: RoleId,
@@ -553,6 +645,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_direct_role_member_of_page(
         project_id: &ProjectId,
         role_id: RoleId,
@@ -568,6 +661,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_direct_user_roles_page(
         project_id: &ProjectId,
         user_id: &UserId,
@@ -583,6 +677,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_transitive_role_members_page(
         project_id: &ProjectId,
         role_id: RoleId,
@@ -600,6 +695,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_transitive_user_roles_page(
         project_id: &ProjectId,
         user_id: &UserId,
@@ -615,6 +711,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_transitive_role_member_of_page(
         project_id: &ProjectId,
         role_id: RoleId,
@@ -630,6 +727,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_user_membership_entries(
         user_ids: &[UserId],
         catalog_state: Self::State,
@@ -639,6 +737,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     // ---------------- User Management API ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_or_update_user<'a>(
         user_id: &UserId,
         name: &str,
@@ -660,6 +759,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn search_user(
         search_term: &str,
         catalog_state: Self::State,
@@ -668,6 +768,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     /// Return Ok(vec[]) if the user does not exist.
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_user(
         filter_user_id: Option<Vec<UserId>>,
         filter_name: Option<String>,
@@ -683,6 +784,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn delete_user<'a>(
         user_id: UserId,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
@@ -690,6 +792,7 @@ impl CatalogStore for super::PostgresBackend {
         delete_user(user_id, &mut **transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_warehouse_impl<'a>(
         project_id: &ProjectId,
         request: CatalogCreateWarehouseRequest,
@@ -699,6 +802,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     // ---------------- Management API ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_project<'a>(
         project_id: &ProjectId,
         project_name: String,
@@ -708,6 +812,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     /// Delete a project
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn delete_project<'a>(
         project_id: &ProjectId,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
@@ -716,6 +821,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     /// Get the project metadata
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_project<'a>(
         project_id: &ProjectId,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
@@ -723,6 +829,7 @@ impl CatalogStore for super::PostgresBackend {
         get_project(project_id, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_projects(
         project_ids: Option<HashSet<ProjectId>>,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
@@ -730,6 +837,7 @@ impl CatalogStore for super::PostgresBackend {
         list_projects(project_ids, &mut **transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_endpoint_statistics(
         project_id: ArcProjectId,
         warehouse_id: WarehouseFilter,
@@ -747,6 +855,17 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn delete_endpoint_statistics<'a>(
+        project_id: &ProjectId,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> Result<u64> {
+        delete_statistics(project_id, from, until, &mut **transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_warehouses_impl(
         project_id: &ProjectId,
         status_filter: Option<Vec<WarehouseStatus>>,
@@ -755,6 +874,26 @@ impl CatalogStore for super::PostgresBackend {
         list_warehouses(project_id, status_filter, &catalog_state.read_pool()).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn list_warehouses_paginated_impl<'a>(
+        project_id: &ProjectId,
+        status_filter: Option<Vec<WarehouseStatus>>,
+        pagination_query: PaginationQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> std::result::Result<
+        PaginatedMapping<WarehouseId, ResolvedWarehouse>,
+        CatalogListWarehousesError,
+    > {
+        list_warehouses_paginated(
+            project_id,
+            status_filter,
+            pagination_query,
+            &mut **transaction,
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_warehouse_by_id_impl<'a>(
         warehouse_id: WarehouseId,
         state: Self::State,
@@ -762,6 +901,7 @@ impl CatalogStore for super::PostgresBackend {
         get_warehouse_by_id(warehouse_id, &state.read_pool()).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_warehouse_stats(
         warehouse_id: WarehouseId,
         pagination_query: PaginationQuery,
@@ -770,6 +910,23 @@ impl CatalogStore for super::PostgresBackend {
         get_warehouse_stats(state.read_pool(), warehouse_id, pagination_query).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_warehouse_entity_counts(
+        warehouse_ids: &[WarehouseId],
+        state: Self::State,
+    ) -> Result<HashMap<WarehouseId, WarehouseEntityCounts>> {
+        get_warehouse_entity_counts(state.read_pool(), warehouse_ids).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_warehouse_change_version(
+        warehouse_id: WarehouseId,
+        state: Self::State,
+    ) -> Result<Option<i64>> {
+        get_warehouse_change_version(state.read_pool(), warehouse_id).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn delete_warehouse_impl<'a>(
         warehouse_id: WarehouseId,
         query: DeleteWarehouseQuery,
@@ -778,6 +935,7 @@ impl CatalogStore for super::PostgresBackend {
         delete_warehouse(warehouse_id, query, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn rename_warehouse_impl<'a>(
         warehouse_id: WarehouseId,
         new_name: &str,
@@ -786,6 +944,7 @@ impl CatalogStore for super::PostgresBackend {
         rename_warehouse(warehouse_id, new_name, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_warehouse_deletion_profile_impl<'a>(
         warehouse_id: WarehouseId,
         deletion_profile: &TabularDeleteProfile,
@@ -794,6 +953,7 @@ impl CatalogStore for super::PostgresBackend {
         set_warehouse_deletion_profile(warehouse_id, deletion_profile, &mut **transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn rename_project<'a>(
         project_id: &ProjectId,
         new_name: &str,
@@ -802,6 +962,16 @@ impl CatalogStore for super::PostgresBackend {
         rename_project(project_id, new_name, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn set_project_case_sensitive_warehouse_names<'a>(
+        project_id: &ProjectId,
+        case_sensitive: bool,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> Result<()> {
+        set_project_case_sensitive_warehouse_names(project_id, case_sensitive, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_warehouse_status_impl<'a>(
         warehouse_id: WarehouseId,
         status: WarehouseStatus,
@@ -810,6 +980,7 @@ impl CatalogStore for super::PostgresBackend {
         set_warehouse_status(warehouse_id, status, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn update_storage_profile_impl<'a>(
         warehouse_id: WarehouseId,
         storage_profile: StorageProfile,
@@ -825,6 +996,23 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn update_secondary_storage_profile_impl<'a>(
+        warehouse_id: WarehouseId,
+        storage_profile: StorageProfile,
+        storage_secret_id: Option<SecretId>,
+        transaction: <Self::Transaction as Transaction<CatalogState>>::Transaction<'a>,
+    ) -> std::result::Result<ResolvedWarehouse, UpdateWarehouseStorageProfileError> {
+        update_secondary_storage_profile(
+            warehouse_id,
+            storage_profile,
+            storage_secret_id,
+            transaction,
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_view_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -844,6 +1032,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn load_view_impl<'a>(
         warehouse_id: WarehouseId,
         view_id: ViewId,
@@ -853,6 +1042,7 @@ impl CatalogStore for super::PostgresBackend {
         load_view(warehouse_id, view_id, include_deleted, &mut *transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn commit_view_impl<'a>(
         ViewCommit {
             namespace_id,
@@ -873,6 +1063,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn search_tabular_impl(
         warehouse_id: WarehouseId,
         search_term: &str,
@@ -881,6 +1072,21 @@ impl CatalogStore for super::PostgresBackend {
         search_tabular(warehouse_id, search_term, &catalog_state.read_pool()).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn list_tabulars_exceeding_properties_size_impl(
+        warehouse_id: WarehouseId,
+        max_properties_bytes: u64,
+        catalog_state: Self::State,
+    ) -> std::result::Result<Vec<TabularPropertiesSize>, CatalogBackendError> {
+        list_tabulars_exceeding_properties_size(
+            warehouse_id,
+            max_properties_bytes,
+            &catalog_state.read_pool(),
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_tabulars_impl(
         warehouse_id: WarehouseId,
         namespace_id: Option<NamespaceId>,
@@ -900,6 +1106,7 @@ impl CatalogStore for super::PostgresBackend {
         )
         .await
     }
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_tabular_protected_impl(
         warehouse_id: WarehouseId,
         tabular_id: TabularId,
@@ -909,6 +1116,34 @@ impl CatalogStore for super::PostgresBackend {
         set_tabular_protected(warehouse_id, tabular_id, protect, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn touch_tabular_impl(
+        warehouse_id: WarehouseId,
+        tabular_id: TabularId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<chrono::DateTime<chrono::Utc>, TouchTabularError> {
+        touch_tabular(warehouse_id, tabular_id, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn set_namespace_tabulars_protected_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        protect: bool,
+        recursive: bool,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<TabularProtectionCounts, SetNamespaceTabularsProtectionError> {
+        set_namespace_tabulars_protected(
+            warehouse_id,
+            namespace_id,
+            protect,
+            recursive,
+            transaction,
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_namespace_protected_impl(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -918,6 +1153,67 @@ impl CatalogStore for super::PostgresBackend {
         set_namespace_protected(warehouse_id, namespace_id, protect, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_namespace_delete_profile_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<Option<TabularDeleteProfile>, CatalogGetNamespaceDeleteProfileError>
+    {
+        get_namespace_delete_profile(warehouse_id, namespace_id, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn set_namespace_delete_profile_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        delete_profile: Option<&TabularDeleteProfile>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<Option<TabularDeleteProfile>, CatalogSetNamespaceDeleteProfileError>
+    {
+        set_namespace_delete_profile(warehouse_id, namespace_id, delete_profile, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_namespace_table_defaults_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<NamespaceTableDefaults, CatalogGetNamespaceTableDefaultsError> {
+        get_namespace_table_defaults(warehouse_id, namespace_id, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn set_namespace_table_defaults_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        table_defaults: &NamespaceTableDefaults,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<NamespaceTableDefaults, CatalogSetNamespaceTableDefaultsError> {
+        set_namespace_table_defaults(warehouse_id, namespace_id, table_defaults, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn move_namespace_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        new_parent: Option<&NamespaceIdent>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<NamespaceMoveInfo, CatalogMoveNamespaceError> {
+        move_namespace(warehouse_id, namespace_id, new_parent, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn namespace_last_activity_impl(
+        warehouse_id: WarehouseId,
+        namespace_ids: &[NamespaceId],
+        catalog_state: Self::State,
+    ) -> std::result::Result<HashMap<NamespaceId, chrono::DateTime<chrono::Utc>>, CatalogBackendError>
+    {
+        namespace_last_activity(warehouse_id, namespace_ids, &catalog_state.read_pool()).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_warehouse_protected_impl(
         warehouse_id: WarehouseId,
         protect: bool,
@@ -926,6 +1222,7 @@ impl CatalogStore for super::PostgresBackend {
         set_warehouse_protection(warehouse_id, protect, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_warehouse_format_version_policy_impl(
         warehouse_id: WarehouseId,
         policy: &WarehouseFormatVersionPolicy,
@@ -934,6 +1231,38 @@ impl CatalogStore for super::PostgresBackend {
         set_warehouse_format_version_policy(warehouse_id, policy, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn set_warehouse_settings_impl(
+        warehouse_id: WarehouseId,
+        settings: &WarehouseSettings,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<ResolvedWarehouse, SetWarehouseSettingsError> {
+        set_warehouse_settings(warehouse_id, settings, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn count_warehouse_entities_up_to_impl(
+        warehouse_id: WarehouseId,
+        entity: WarehouseQuotaEntity,
+        limit: u32,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<u64, CatalogBackendError> {
+        count_warehouse_entities_up_to(warehouse_id, entity, limit, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn list_protected_entities_impl(
+        warehouse_id: WarehouseId,
+        pagination_query: PaginationQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<
+        PaginatedMapping<uuid::Uuid, ProtectedEntity>,
+        ListProtectedEntitiesError,
+    > {
+        list_protected_entities(warehouse_id, pagination_query, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_warehouse_managed_by_impl<'a>(
         warehouse_id: WarehouseId,
         managed_by: ManagedBy,
@@ -942,6 +1271,7 @@ impl CatalogStore for super::PostgresBackend {
         set_warehouse_managed_by(warehouse_id, managed_by, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn ensure_warehouse_spec_mutable_impl<'a>(
         warehouse_id: WarehouseId,
         bypass: bool,
@@ -950,6 +1280,7 @@ impl CatalogStore for super::PostgresBackend {
         ensure_warehouse_spec_mutable(warehouse_id, bypass, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn pick_new_task_impl(
         queue_name: &TaskQueueName,
         legacy_queue_names: &[&TaskQueueName],
@@ -965,6 +1296,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn resolve_tasks_impl(
         scope: TaskResolveScope,
         task_ids: &[TaskId],
@@ -973,6 +1305,7 @@ impl CatalogStore for super::PostgresBackend {
         resolve_tasks(scope, task_ids, &state.read_pool()).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_task_success_impl(
         id: TaskAttemptId,
         message: Option<&str>,
@@ -981,6 +1314,7 @@ impl CatalogStore for super::PostgresBackend {
         record_success(&&id, transaction, message).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn record_task_failure_impl(
         id: TaskAttemptId,
         error_details: &str,
@@ -990,6 +1324,15 @@ impl CatalogStore for super::PostgresBackend {
         record_failure(&id, max_retries, error_details, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn release_task_impl(
+        id: TaskAttemptId,
+        transaction: &mut <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()> {
+        release_task(&id, transaction).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_task_details_impl(
         task_id: TaskId,
         scope: TaskDetailsScope,
@@ -1000,6 +1343,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     /// List tasks
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_tasks_impl(
         filter: &TaskFilter,
         query: &ListTasksRequest,
@@ -1008,6 +1352,7 @@ impl CatalogStore for super::PostgresBackend {
         list_tasks(filter, query, &mut *transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn enqueue_tasks_impl(
         queue_name: &'static TaskQueueName,
         tasks: Vec<TaskInput>,
@@ -1023,6 +1368,7 @@ impl CatalogStore for super::PostgresBackend {
         Ok(queued.into_iter().map(|t| t.task_id).collect())
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cancel_scheduled_tasks_impl(
         queue_name: Option<&TaskQueueName>,
         legacy_queue_names: &[&TaskQueueName],
@@ -1040,6 +1386,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn check_and_heartbeat_task_impl(
         id: TaskAttemptId,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
@@ -1049,6 +1396,7 @@ impl CatalogStore for super::PostgresBackend {
         check_and_heartbeat_task(&mut *transaction, &id, progress, execution_details).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn stop_tasks_impl(
         task_ids: &[TaskId],
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
@@ -1056,6 +1404,7 @@ impl CatalogStore for super::PostgresBackend {
         request_tasks_stop(&mut *transaction, task_ids).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn run_tasks_at_impl(
         task_ids: &[TaskId],
         scheduled_for: Option<chrono::DateTime<chrono::Utc>>,
@@ -1064,6 +1413,24 @@ impl CatalogStore for super::PostgresBackend {
         reschedule_tasks_for(&mut *transaction, task_ids, scheduled_for).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn reschedule_tasks_impl(
+        task_ids: &[TaskId],
+        run_at: chrono::DateTime<chrono::Utc>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()> {
+        reschedule_unfinished_tasks(&mut *transaction, task_ids, run_at).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn requeue_tasks_impl(
+        task_ids: &[TaskId],
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()> {
+        requeue_failed_tasks(&mut *transaction, task_ids).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn set_task_queue_config_impl(
         project_id: ArcProjectId,
         warehouse_id: Option<WarehouseId>,
@@ -1074,6 +1441,7 @@ impl CatalogStore for super::PostgresBackend {
         set_task_queue_config(transaction, queue_name, project_id, warehouse_id, config).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn get_task_queue_config_impl(
         filter: &TaskQueueConfigFilter,
         queue_name: &TaskQueueName,
@@ -1082,6 +1450,16 @@ impl CatalogStore for super::PostgresBackend {
         get_task_queue_config(&state.read_pool(), filter, queue_name).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn get_task_queue_depth_impl(
+        queue_name: &TaskQueueName,
+        legacy_queue_names: &[&TaskQueueName],
+        state: Self::State,
+    ) -> Result<TaskQueueDepth> {
+        get_task_queue_depth(&state.read_pool(), queue_name, legacy_queue_names).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn cleanup_task_logs_older_than(
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
         retention_period: Duration,
@@ -1091,6 +1469,7 @@ impl CatalogStore for super::PostgresBackend {
     }
 
     // ---------------- Idempotency ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn check_idempotency_key_impl(
         warehouse_id: WarehouseId,
         key: &IdempotencyKey,
@@ -1099,6 +1478,7 @@ impl CatalogStore for super::PostgresBackend {
         Self::check_idempotency_key_impl(warehouse_id, key, state).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn try_insert_idempotency_key_impl<'a>(
         warehouse_id: WarehouseId,
         info: &IdempotencyInfo,
@@ -1107,7 +1487,26 @@ impl CatalogStore for super::PostgresBackend {
         Self::try_insert_idempotency_key_impl(warehouse_id, info, transaction).await
     }
 
+    // ---------------- Audit Log ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn insert_audit_log_record_impl(
+        record: &AuditLogRecord,
+        state: Self::State,
+    ) -> Result<()> {
+        Self::insert_audit_log_record_impl(record, state).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
+    async fn query_audit_log_impl(
+        project_id: &ArcProjectId,
+        query: &QueryAuditLogRequest,
+        state: Self::State,
+    ) -> Result<QueryAuditLogResponse> {
+        Self::query_audit_log_impl(project_id, query, state).await
+    }
+
     // ---------------- Generic Table Management ----------------
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn create_generic_table_impl<'a>(
         creation: GenericTableCreation,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
@@ -1115,6 +1514,7 @@ impl CatalogStore for super::PostgresBackend {
         super::tabular::generic_table::create_generic_table(creation, transaction).await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn load_generic_table_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -1130,6 +1530,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn load_generic_table_by_id_impl<'a>(
         warehouse_id: WarehouseId,
         generic_table_id: lakekeeper::service::GenericTableId,
@@ -1143,6 +1544,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn list_generic_tables_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -1163,6 +1565,7 @@ impl CatalogStore for super::PostgresBackend {
         .await
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = "postgresql"))]
     async fn drop_generic_table_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
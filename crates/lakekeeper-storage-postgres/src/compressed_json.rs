@@ -0,0 +1,130 @@
+//! Optional gzip compression of JSONB metadata blobs.
+//!
+//! Compressed values are stored as a JSON object with a single marker key
+//! holding the base64 encoded gzip payload, so they remain valid JSONB.
+//! Reads detect the marker and transparently fall back to plain JSON, which
+//! keeps rows written before compression was enabled (or with it disabled)
+//! readable.
+use std::io::Read;
+
+use base64::Engine as _;
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
+
+const COMPRESSION_MARKER: &str = "$lakekeeper-gzip";
+
+/// Serialize `value` for storage in a JSONB column, gzip-compressing it if
+/// `compress` is set.
+pub(crate) fn to_stored_json<T: Serialize + ?Sized>(
+    value: &T,
+    compress: bool,
+) -> serde_json::Result<serde_json::Value> {
+    if !compress {
+        return serde_json::to_value(value);
+    }
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    serde_json::to_writer(&mut encoder, value)?;
+    let compressed = encoder.finish().map_err(serde_json::Error::io)?;
+    Ok(serde_json::json!({
+        COMPRESSION_MARKER: base64::engine::general_purpose::STANDARD.encode(compressed)
+    }))
+}
+
+/// A value read from a JSONB column that may have been written by
+/// [`to_stored_json`] with compression enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct StoredJson<T>(pub(crate) T);
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for StoredJson<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let compressed = value
+            .as_object()
+            .filter(|object| object.len() == 1)
+            .and_then(|object| object.get(COMPRESSION_MARKER))
+            .and_then(serde_json::Value::as_str);
+
+        let Some(compressed) = compressed else {
+            return serde_json::from_value(value)
+                .map(StoredJson)
+                .map_err(serde::de::Error::custom);
+        };
+
+        let compressed = base64::engine::general_purpose::STANDARD
+            .decode(compressed)
+            .map_err(|e| {
+                serde::de::Error::custom(format!("Invalid base64 in compressed JSON: {e}"))
+            })?;
+        let mut decompressed = Vec::new();
+        GzDecoder::new(compressed.as_slice())
+            .read_to_end(&mut decompressed)
+            .map_err(|e| serde::de::Error::custom(format!("Failed to decompress JSON: {e}")))?;
+        serde_json::from_slice(&decompressed)
+            .map(StoredJson)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg::spec::{NestedField, PrimitiveType, Schema, Type};
+
+    use super::*;
+
+    fn large_schema() -> Schema {
+        let fields = (1..=500)
+            .map(|id| {
+                NestedField::optional(
+                    id,
+                    format!("column_with_a_fairly_long_name_{id}"),
+                    Type::Primitive(PrimitiveType::String),
+                )
+                .with_doc("A column of a table with a very wide schema")
+                .into()
+            })
+            .collect::<Vec<_>>();
+        Schema::builder()
+            .with_schema_id(1)
+            .with_fields(fields)
+            .build()
+            .unwrap()
+    }
+
+    fn round_trip(compress: bool) -> (serde_json::Value, Schema) {
+        let stored = to_stored_json(&large_schema(), compress).unwrap();
+        let StoredJson(schema) = serde_json::from_value::<StoredJson<Schema>>(stored.clone())
+            .expect("stored schema should deserialize");
+        (stored, schema)
+    }
+
+    #[test]
+    fn test_large_schema_round_trips_uncompressed() {
+        let (stored, schema) = round_trip(false);
+
+        assert_eq!(stored, serde_json::to_value(large_schema()).unwrap());
+        assert_eq!(schema, large_schema());
+    }
+
+    #[test]
+    fn test_large_schema_round_trips_compressed() {
+        let (stored, schema) = round_trip(true);
+
+        assert!(stored.get(COMPRESSION_MARKER).is_some());
+        assert!(
+            stored.to_string().len() < serde_json::to_string(&large_schema()).unwrap().len() / 2,
+            "Compressed representation should be considerably smaller"
+        );
+        assert_eq!(schema, large_schema());
+    }
+
+    #[test]
+    fn test_invalid_compressed_payload_is_rejected() {
+        let stored = serde_json::json!({ COMPRESSION_MARKER: "not base64!" });
+
+        assert!(serde_json::from_value::<StoredJson<Schema>>(stored).is_err());
+    }
+}
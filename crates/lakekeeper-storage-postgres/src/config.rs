@@ -30,6 +30,18 @@ pub struct DynAppConfig {
     pub pg_read_pool_connections: u32,
     pub pg_write_pool_connections: u32,
     pub pg_acquire_timeout: u64,
+    /// Postgres `statement_timeout` in seconds for connections of the read pool.
+    /// Unset disables the timeout.
+    pub pg_read_statement_timeout: Option<u64>,
+    /// Postgres `statement_timeout` in seconds for connections of the write pool.
+    /// Unset disables the timeout.
+    pub pg_write_statement_timeout: Option<u64>,
+    /// Log statements of both pools running longer than this many milliseconds
+    /// as warnings. Unset disables slow query logging.
+    pub pg_slow_query_log_ms: Option<u64>,
+    /// Gzip-compress table metadata JSON (partition specs and sort orders)
+    /// before storing it. Existing uncompressed rows remain readable.
+    pub pg_compress_table_metadata: bool,
 }
 
 impl Default for DynAppConfig {
@@ -52,10 +64,34 @@ impl Default for DynAppConfig {
             pg_read_pool_connections: 10,
             pg_write_pool_connections: 5,
             pg_acquire_timeout: 5,
+            pg_read_statement_timeout: None,
+            pg_write_statement_timeout: None,
+            pg_slow_query_log_ms: None,
+            pg_compress_table_metadata: false,
         }
     }
 }
 
+impl DynAppConfig {
+    #[must_use]
+    pub fn read_statement_timeout(&self) -> Option<std::time::Duration> {
+        self.pg_read_statement_timeout
+            .map(std::time::Duration::from_secs)
+    }
+
+    #[must_use]
+    pub fn write_statement_timeout(&self) -> Option<std::time::Duration> {
+        self.pg_write_statement_timeout
+            .map(std::time::Duration::from_secs)
+    }
+
+    #[must_use]
+    pub fn slow_query_log_threshold(&self) -> Option<std::time::Duration> {
+        self.pg_slow_query_log_ms
+            .map(std::time::Duration::from_millis)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 pub enum PgSslMode {
     Disable,
@@ -128,6 +164,44 @@ fn get_config() -> DynAppConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    #[allow(clippy::result_large_err)] // figment::Error is wide; not worth boxing in test setup.
+    fn test_statement_timeouts_are_configured_per_pool() {
+        figment::Jail::expect_with(|jail| {
+            let config = get_config();
+            assert_eq!(config.read_statement_timeout(), None);
+            assert_eq!(config.write_statement_timeout(), None);
+
+            jail.set_env("LAKEKEEPER_TEST__PG_READ_STATEMENT_TIMEOUT", "30");
+            jail.set_env("LAKEKEEPER_TEST__PG_WRITE_STATEMENT_TIMEOUT", "10");
+            let config = get_config();
+            assert_eq!(
+                config.read_statement_timeout(),
+                Some(std::time::Duration::from_secs(30))
+            );
+            assert_eq!(
+                config.write_statement_timeout(),
+                Some(std::time::Duration::from_secs(10))
+            );
+            Ok(())
+        });
+    }
+
+    #[test]
+    #[allow(clippy::result_large_err)] // figment::Error is wide; not worth boxing in test setup.
+    fn test_slow_query_log_threshold() {
+        figment::Jail::expect_with(|jail| {
+            assert_eq!(get_config().slow_query_log_threshold(), None);
+
+            jail.set_env("LAKEKEEPER_TEST__PG_SLOW_QUERY_LOG_MS", "250");
+            assert_eq!(
+                get_config().slow_query_log_threshold(),
+                Some(std::time::Duration::from_millis(250))
+            );
+            Ok(())
+        });
+    }
+
     #[test]
     #[allow(clippy::result_large_err)] // figment::Error is wide; not worth boxing in test setup.
     fn test_pg_ssl_mode_case_insensitive() {
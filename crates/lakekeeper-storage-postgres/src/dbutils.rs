@@ -3,6 +3,9 @@ use lakekeeper::{
     service::{CatalogBackendError, CatalogBackendErrorType},
 };
 
+/// `query_canceled`, raised when a statement exceeds `statement_timeout`.
+const QUERY_CANCELED: &str = "57014";
+
 pub(crate) trait DBErrorHandler
 where
     Self: ToString + Sized + Send + Sync + std::error::Error + 'static,
@@ -27,6 +30,12 @@ impl DBErrorHandler for sqlx::Error {
                 }
                 match db.code().as_deref() {
                     // https://www.postgresql.org/docs/current/errcodes-appendix.html
+                    Some(QUERY_CANCELED) => ErrorModel::service_unavailable(
+                        message,
+                        "QueryTimeout",
+                        Some(Box::new(self)),
+                    )
+                    .append_detail("The query exceeded the configured statement timeout."),
                     Some(
                         "2D000" | "25000" | "25001" | "25P01" | "25P02" | "25P03" | "40000"
                         | "40001" | "40002" | "40003" | "40004",
@@ -49,6 +58,10 @@ impl DBErrorHandler for sqlx::Error {
                 // an explicit error variant, so we treat it as unexpected here.
                 match db.code().as_deref() {
                     // https://www.postgresql.org/docs/current/errcodes-appendix.html
+                    Some(QUERY_CANCELED) => {
+                        CatalogBackendError::new(self, CatalogBackendErrorType::QueryTimeout)
+                            .append_detail("The query exceeded the configured statement timeout.")
+                    }
                     Some(
                         "2D000" | "25000" | "25001" | "25P01" | "25P02" | "25P03" | "40000"
                         | "40001" | "40002" | "40003" | "40004",
@@ -64,3 +77,105 @@ impl DBErrorHandler for sqlx::Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use lakekeeper::api::ErrorModel;
+    use sqlx::{PgPool, postgres::PgPoolOptions};
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::DBErrorHandler;
+    use crate::{with_slow_query_log, with_statement_timeout};
+
+    async fn pool_with_statement_timeout(pool: &PgPool) -> PgPool {
+        with_statement_timeout(
+            PgPoolOptions::new().max_connections(1),
+            Some(Duration::from_millis(100)),
+        )
+        .connect_with((*pool.connect_options()).clone())
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_slow_query_returns_query_timeout(pool: PgPool) {
+        let pool = pool_with_statement_timeout(&pool).await;
+
+        // Fast queries are unaffected
+        sqlx::query("SELECT 1").execute(&pool).await.unwrap();
+
+        let err = sqlx::query("SELECT pg_sleep(5)")
+            .execute(&pool)
+            .await
+            .unwrap_err()
+            .into_error_model("Error running slow query");
+        assert_eq!(err.r#type, "QueryTimeout");
+        assert_eq!(err.code, 503);
+
+        let err = sqlx::query("SELECT pg_sleep(5)")
+            .execute(&pool)
+            .await
+            .unwrap_err()
+            .into_catalog_backend_error();
+        let err = ErrorModel::from(err);
+        assert_eq!(err.r#type, "QueryTimeout");
+        assert_eq!(err.code, 503);
+    }
+
+    /// Records the fields of warnings emitted by sqlx for executed statements.
+    #[derive(Clone, Default)]
+    struct SlowQueryWarnings(Arc<Mutex<Vec<String>>>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SlowQueryWarnings {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let metadata = event.metadata();
+            if *metadata.level() != tracing::Level::WARN || metadata.target() != "sqlx::query" {
+                return;
+            }
+            let mut fields = String::new();
+            event.record(
+                &mut |field: &tracing::field::Field, value: &dyn std::fmt::Debug| {
+                    fields.push_str(&format!("{}={value:?} ", field.name()));
+                },
+            );
+            self.0.lock().unwrap().push(fields);
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_slow_queries_are_logged_as_warnings(pool: PgPool) {
+        let connect_opts = with_slow_query_log(
+            (*pool.connect_options()).clone(),
+            Some(Duration::from_millis(50)),
+        );
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .connect_with(connect_opts)
+            .await
+            .unwrap();
+
+        let warnings = SlowQueryWarnings::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(warnings.clone()));
+
+        sqlx::query("SELECT 1").execute(&pool).await.unwrap();
+        assert!(warnings.0.lock().unwrap().is_empty());
+
+        sqlx::query("SELECT pg_sleep(0.2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let recorded = warnings.0.lock().unwrap().clone();
+        assert_eq!(recorded.len(), 1, "{recorded:?}");
+        assert!(recorded[0].contains("pg_sleep"), "{recorded:?}");
+    }
+}
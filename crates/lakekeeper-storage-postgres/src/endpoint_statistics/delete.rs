@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use lakekeeper::ProjectId;
+use sqlx::PgConnection;
+
+use crate::dbutils::DBErrorHandler;
+
+pub(crate) async fn delete_statistics(
+    project: &ProjectId,
+    from: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    conn: &mut PgConnection,
+) -> lakekeeper::api::Result<u64> {
+    tracing::debug!("Deleting stats for project: '{project}', from: {from:?}, until: {until:?}");
+
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM endpoint_statistics
+        WHERE project_id = $1
+            AND ($2::timestamptz IS NULL OR timestamp >= $2)
+            AND ($3::timestamptz IS NULL OR timestamp < $3)
+        "#,
+        project.as_str(),
+        from,
+        until,
+    )
+    .execute(conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to delete stats: {e}");
+        e.into_error_model("failed to delete stats")
+    })?;
+
+    Ok(result.rows_affected())
+}
@@ -1,3 +1,4 @@
+pub(crate) mod delete;
 pub(crate) mod list;
 pub(crate) mod sink;
 
@@ -6,8 +6,10 @@
 //! coordination, and the migrations runner used at startup.
 
 mod advisory_lock;
+mod audit_log;
 mod bootstrap;
 mod catalog;
+mod compressed_json;
 pub mod config;
 pub(crate) mod dbutils;
 pub mod endpoint_statistics;
@@ -74,6 +76,47 @@ pub async fn get_writer_pool(pool_opts: PgPoolOptions) -> anyhow::Result<PgPool>
     Ok(pool)
 }
 
+/// Set the Postgres `statement_timeout` on every connection of the pool.
+///
+/// Statements exceeding the timeout are cancelled by Postgres and surface as a
+/// `QueryTimeout` error. `None` leaves the server default in place.
+#[must_use]
+pub fn with_statement_timeout(
+    pool_opts: PgPoolOptions,
+    statement_timeout: Option<std::time::Duration>,
+) -> PgPoolOptions {
+    let Some(statement_timeout) = statement_timeout else {
+        return pool_opts;
+    };
+    let statement_timeout = format!("{}ms", statement_timeout.as_millis());
+
+    pool_opts.after_connect(move |conn, _meta| {
+        let statement_timeout = statement_timeout.clone();
+        Box::pin(async move {
+            sqlx::query("SELECT set_config('statement_timeout', $1, false)")
+                .bind(statement_timeout)
+                .execute(conn)
+                .await?;
+            Ok(())
+        })
+    })
+}
+
+/// Log statements running longer than `threshold` as warnings.
+///
+/// The warning is emitted with the `sqlx::query` target and contains the
+/// statement and its execution time. `None` leaves slow query logging disabled.
+#[must_use]
+pub fn with_slow_query_log(
+    connect_opts: PgConnectOptions,
+    threshold: Option<std::time::Duration>,
+) -> PgConnectOptions {
+    let Some(threshold) = threshold else {
+        return connect_opts;
+    };
+    connect_opts.log_slow_statements(log::LevelFilter::Warn, threshold)
+}
+
 #[derive(Debug, Clone)]
 pub struct PostgresBackend {}
 
@@ -327,6 +370,8 @@ fn build_connect_ops(typ: ConnectionType) -> anyhow::Result<PgConnectOptions> {
     } else {
         opts.disable_statement_logging()
     };
+    // Applied after the statement logging config, which also resets the slow statement level.
+    let opts = with_slow_query_log(opts, CONFIG.slow_query_log_threshold());
 
     let conn_type = match typ {
         ConnectionType::Read => "read",
@@ -1,11 +1,15 @@
 use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
+    sync::Arc,
 };
 
 use anyhow::{Context, anyhow};
 use futures::future::BoxFuture;
-use lakekeeper::service::{ServerId, Transaction};
+use lakekeeper::service::{
+    ServerId, Transaction,
+    health::{Health, HealthExt, HealthStatus},
+};
 /// Re-exported for convenience so the `ExtensionMigrations::migrator` field
 /// type is reachable without naming `sqlx` in the caller's import list.
 ///
@@ -19,6 +23,7 @@ use sqlx::{
     Error, Postgres,
     migrate::{AppliedMigration, Migrate, MigrateError, Migration as SqlxMigration, Migrator},
 };
+use tokio::sync::RwLock;
 use typed_builder::TypedBuilder;
 
 use crate::{
@@ -489,6 +494,59 @@ pub enum MigrationState {
     Ahead,
 }
 
+/// Readiness check that passes once all migrations of this binary are
+/// applied, based on [`check_migration_status`] against the given pool.
+///
+/// Register it via `ServeConfiguration::readiness_checks` with the *write*
+/// pool — a lagging read replica may not carry the latest
+/// `_sqlx_migrations` rows yet. Once migrations are confirmed complete, the
+/// check is not repeated: a newer Lakekeeper migrating the database during a
+/// rolling deploy must not flip already running replicas to not-ready.
+#[derive(Debug, Clone)]
+pub struct MigrationHealth {
+    pool: sqlx::PgPool,
+    health: Arc<RwLock<Health>>,
+}
+
+impl MigrationHealth {
+    #[must_use]
+    pub fn new(pool: sqlx::PgPool) -> Self {
+        Self {
+            pool,
+            health: Arc::new(RwLock::new(Health::now(
+                "migrations",
+                HealthStatus::Unknown,
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthExt for MigrationHealth {
+    async fn health(&self) -> Vec<Health> {
+        vec![self.health.read().await.clone()]
+    }
+
+    async fn update_health(&self) {
+        if self.health.read().await.status() == HealthStatus::Healthy {
+            return;
+        }
+
+        let status = match check_migration_status(&self.pool).await {
+            Ok(MigrationState::Complete) => HealthStatus::Healthy,
+            Ok(state) => {
+                tracing::info!(?state, "Database is not up to date with binary.");
+                HealthStatus::Unhealthy
+            }
+            Err(e) => {
+                tracing::warn!(?e, "Failed to check migration status");
+                HealthStatus::Unhealthy
+            }
+        };
+        *self.health.write().await = Health::now("migrations", status);
+    }
+}
+
 pub trait MigrationHook: Send + Sync + 'static {
     fn apply<'c>(
         &self,
@@ -555,6 +613,7 @@ fn validate_applied_migrations(
 mod tests {
     use std::collections::HashSet;
 
+    use lakekeeper::service::health::{HealthExt, HealthStatus};
     use sqlx::{
         AssertSqlSafe, PgPool,
         postgres::{PgConnectOptions, PgPoolOptions},
@@ -562,7 +621,8 @@ mod tests {
     use uuid::Uuid;
 
     use super::{
-        ExtensionMigrations, MigrationState, check_migration_status, migrate, migrate_core_only,
+        ExtensionMigrations, MigrationHealth, MigrationState, check_migration_status, migrate,
+        migrate_core_only,
     };
 
     async fn table_exists(pool: &PgPool, name: &str) -> bool {
@@ -619,6 +679,24 @@ mod tests {
         );
     }
 
+    /// `/readyz` relies on this check: it must fail on an unmigrated database
+    /// and pass once migrations are applied.
+    #[sqlx::test(migrations = false)]
+    async fn test_migration_health_reports_ready_after_migration(pool: PgPool) {
+        let health = MigrationHealth::new(pool.clone());
+        assert_eq!(health.health().await[0].status(), HealthStatus::Unknown);
+
+        health.update_health().await;
+        assert_eq!(health.health().await[0].status(), HealthStatus::Unhealthy);
+
+        migrate_core_only(&pool)
+            .await
+            .expect("core-only migrate must succeed");
+
+        health.update_health().await;
+        assert_eq!(health.health().await[0].status(), HealthStatus::Healthy);
+    }
+
     /// An operator runs upstream OSS by itself for a while (their
     /// `_sqlx_migrations` is populated, they have user data in core tables),
     /// then later switches to a binary that registers an extension. Calling
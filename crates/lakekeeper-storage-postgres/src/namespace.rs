@@ -1,21 +1,31 @@
 use std::{collections::HashMap, sync::Arc};
 
-use iceberg::TableIdent;
+use iceberg::{
+    TableIdent,
+    spec::{SortOrder, UnboundPartitionSpec},
+};
 use itertools::izip;
 use lakekeeper::{
     CONFIG, WarehouseId,
-    api::iceberg::v1::{PaginatedMapping, namespace::NamespaceDropFlags},
+    api::{
+        iceberg::v1::{PaginatedMapping, namespace::NamespaceDropFlags},
+        management::v1::{namespace::NamespaceTableDefaults, warehouse::TabularDeleteProfile},
+    },
     server::namespace::MAX_NAMESPACE_DEPTH,
     service::{
-        CatalogCreateNamespaceError, CatalogGetNamespaceError, CatalogListNamespaceError,
-        CatalogListNamespacesResponse, CatalogNamespaceDropError,
-        CatalogSetNamespaceProtectedError, CatalogUpdateNamespacePropertiesError,
+        CatalogBackendError, CatalogCreateNamespaceError, CatalogGetNamespaceDeleteProfileError,
+        CatalogGetNamespaceError, CatalogGetNamespaceTableDefaultsError, CatalogListNamespaceError,
+        CatalogListNamespacesResponse, CatalogMoveNamespaceError, CatalogNamespaceDropError,
+        CatalogSetNamespaceDeleteProfileError, CatalogSetNamespaceProtectedError,
+        CatalogSetNamespaceTableDefaultsError, CatalogUpdateNamespacePropertiesError,
         ChildNamespaceProtected, ChildTabularProtected, CreateNamespaceRequest,
         InternalParseLocationError, InvalidNamespaceIdentifier, ListNamespacesQuery, Namespace,
         NamespaceAlreadyExists, NamespaceDropInfo, NamespaceHasRunningTabularExpirations,
-        NamespaceId, NamespaceIdent, NamespaceNotEmpty, NamespaceNotFound,
-        NamespacePropertiesSerializationError, NamespaceProtected, NamespaceWithParent, Result,
-        SerializationError, TabularId, WarehouseIdNotFound, storage::join_location, tasks::TaskId,
+        NamespaceId, NamespaceIdent, NamespaceMoveExceedsMaxDepth, NamespaceMoveInfo,
+        NamespaceMoveIntoOwnSubtree, NamespaceNotEmpty, NamespaceNotFound,
+        NamespacePropertiesSerializationError, NamespaceProtected, NamespaceSoftDeletionEnabled,
+        NamespaceWithParent, Result, SerializationError, TabularId, WarehouseIdNotFound,
+        storage::join_location, tasks::TaskId,
     },
 };
 use sqlx::types::Json;
@@ -25,6 +35,7 @@ use super::dbutils::DBErrorHandler;
 use crate::{
     pagination::{PaginateToken, V1PaginateToken},
     tabular::TabularType,
+    warehouse::{DbTabularDeleteProfile, db_to_api_tabular_delete_profile},
 };
 
 #[derive(Debug)]
@@ -226,6 +237,8 @@ pub(crate) async fn get_namespaces_by_name<
             select namespace_name
             from namespace
             where warehouse_id = $1 AND namespace_name = ANY(SELECT namespace_name FROM requested_namespaces)
+            AND (NOT (SELECT COALESCE((settings->>'case-sensitive-names')::boolean, false) FROM warehouse WHERE warehouse_id = $1)
+                OR namespace_name COLLATE "C" = ANY(SELECT namespace_name FROM requested_namespaces))
         ),
         parent_paths as (
             SELECT DISTINCT namespace_name[1:generate_series(1, array_length(namespace_name, 1))] as parent_name
@@ -539,6 +552,130 @@ pub(crate) async fn list_namespaces(
     Ok(namespace_map)
 }
 
+pub(crate) async fn list_namespaces_recursive(
+    warehouse_id: WarehouseId,
+    ListNamespacesQuery {
+        page_token,
+        page_size,
+        parent,
+        return_uuids: _,
+        return_protection_status: _,
+    }: &ListNamespacesQuery,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> std::result::Result<CatalogListNamespacesResponse, CatalogListNamespaceError> {
+    let page_size = CONFIG.page_size_or_pagination_max(*page_size);
+
+    // An empty root matches all top-level namespaces: `namespace_name[1:0]` is the empty array.
+    let root: &[String] = parent.as_ref().map_or(&[], |p| p.as_ref());
+    // If it doesn't fit in a i32 it is way too large. Validation would have failed
+    // already in the catalog.
+    let root_len: i32 = root.len().try_into().unwrap_or(MAX_NAMESPACE_DEPTH + 1);
+
+    let token = page_token
+        .as_option()
+        .map(PaginateToken::try_from)
+        .transpose()?;
+
+    let (token_ts, token_id) = token
+        .as_ref()
+        .map(
+            |PaginateToken::V1(V1PaginateToken { created_at, id }): &PaginateToken<Uuid>| {
+                (created_at, id)
+            },
+        )
+        .unzip();
+
+    // Walk the tree starting at the direct children of the root. Children
+    // are namespaces one level deeper whose name starts with the name of their parent.
+    let namespaces = sqlx::query_as!(
+        ListNamespaceRow,
+        r#"
+        WITH RECURSIVE tree AS (
+            SELECT
+                n.namespace_id,
+                n.namespace_name,
+                n.depth,
+                n.created_at
+            FROM namespace n
+            INNER JOIN warehouse w ON w.warehouse_id = $1
+            WHERE n.warehouse_id = $1
+            AND w.status = 'active'
+            AND n.depth = $2 + 1
+            AND "namespace_name"[1:$2] = $3
+            UNION ALL
+            SELECT
+                c.namespace_id,
+                c.namespace_name,
+                c.depth,
+                c.created_at
+            FROM namespace c
+            INNER JOIN tree t ON c.depth = t.depth + 1
+                AND c.namespace_name[1:t.depth] = t.namespace_name
+            WHERE c.warehouse_id = $1
+        ),
+        list_entries AS (
+            SELECT
+                t.namespace_id,
+                t.namespace_name
+            FROM tree t
+            --- PAGINATION
+            WHERE ((t.created_at > $4 OR $4 IS NULL) OR (t.created_at = $4 AND t.namespace_id > $5))
+            ORDER BY t.created_at, t.namespace_id ASC
+            LIMIT $6
+        ),
+        parent_paths AS (
+            SELECT DISTINCT
+                tn.namespace_name[1:generate_series(1, array_length(tn.namespace_name, 1))] as parent_name
+            FROM list_entries tn
+        ),
+        relevant_namespaces AS (
+            SELECT
+                n.namespace_id,
+                n.namespace_name,
+                n.warehouse_id,
+                n.protected,
+                n.namespace_properties,
+                n.created_at,
+                n.updated_at,
+                n.version,
+                n.namespace_id in (SELECT namespace_id FROM list_entries) AS "include_in_list"
+            FROM namespace n
+            WHERE n.warehouse_id = $1
+            AND n.namespace_name IN (SELECT parent_name FROM parent_paths)
+        )
+        SELECT
+            n.namespace_id,
+            n.namespace_name as "namespace_name: Vec<String>",
+            n.warehouse_id,
+            n.protected,
+            n.namespace_properties as "properties: Json<Option<HashMap<String, String>>>",
+            n.created_at,
+            n.updated_at,
+            n.version,
+            n.include_in_list AS "include_in_list!",
+            p.namespace_id as "parent_namespace_id?",
+            p.version as "parent_version?"
+        FROM relevant_namespaces n
+        LEFT JOIN relevant_namespaces p ON array_length(n.namespace_name, 1) = array_length(p.namespace_name, 1) + 1
+            AND n.namespace_name[1:array_length(p.namespace_name, 1)] = p.namespace_name
+        ORDER BY n.created_at, n.namespace_id ASC
+        "#,
+        *warehouse_id,
+        root_len,
+        root,
+        token_ts,
+        token_id,
+        page_size
+    )
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let namespace_map = list_rows_into_hierarchy(namespaces, warehouse_id)?;
+
+    Ok(namespace_map)
+}
+
 pub(crate) async fn create_namespace(
     warehouse_id: WarehouseId,
     namespace_id: NamespaceId,
@@ -583,6 +720,8 @@ pub(crate) async fn create_namespace(
             WHERE warehouse_id = $1
             AND $6
             AND namespace_name = $5
+            AND (NOT (SELECT COALESCE((settings->>'case-sensitive-names')::boolean, false) FROM warehouse WHERE warehouse_id = $1)
+                OR namespace_name COLLATE "C" = $5)
         )
         SELECT
             i.namespace_id as "namespace_id!",
@@ -660,12 +799,12 @@ pub(crate) async fn drop_namespace(
 ) -> std::result::Result<NamespaceDropInfo, CatalogNamespaceDropError> {
     let info = sqlx::query!(r#"
         WITH namespace_info AS (
-            SELECT namespace_name, namespace_id, protected
+            SELECT namespace_name, namespace_id, protected, tabular_delete_mode
             FROM namespace
             WHERE warehouse_id = $1 AND namespace_id = $2
         ),
         child_namespaces AS (
-            SELECT n.protected, n.namespace_id, n.namespace_name
+            SELECT n.protected, n.namespace_id, n.namespace_name, n.tabular_delete_mode
             FROM namespace n
             INNER JOIN namespace_info ni ON n.namespace_name[1:array_length(ni.namespace_name, 1)] = ni.namespace_name
             WHERE n.warehouse_id = $1 AND n.namespace_id != $2
@@ -684,8 +823,9 @@ pub(crate) async fn drop_namespace(
         SELECT
             ni.protected AS "is_protected!",
             ni.namespace_name AS "namespace_name: Vec<String>",
-            EXISTS (SELECT 1 FROM child_namespaces WHERE protected = true) AS "has_protected_namespaces!",
-            EXISTS (SELECT 1 FROM tabulars WHERE protected = true) AS "has_protected_tabulars!",
+            ARRAY(SELECT array_to_string(namespace_name, '.') FROM child_namespaces WHERE protected = true ORDER BY namespace_name) AS "protected_child_namespaces!",
+            ARRAY(SELECT array_to_string(namespace_name || table_name, '.') FROM tabulars WHERE protected = true ORDER BY namespace_name, table_name) AS "protected_child_tabulars!",
+            ARRAY(SELECT array_to_string(namespace_name, '.') FROM (SELECT namespace_name, tabular_delete_mode FROM namespace_info UNION ALL SELECT namespace_name, tabular_delete_mode FROM child_namespaces) ns WHERE tabular_delete_mode = 'soft' ORDER BY namespace_name) AS "soft_delete_namespaces!",
             EXISTS (SELECT 1 FROM tasks WHERE task_status = 'running' AND queue_name IN ('soft_deletion', 'tabular_expiration')) AS "has_running_expiration!",
             ARRAY(SELECT tabular_id FROM tabulars where deleted_at is NULL) AS "child_tabulars!",
             ARRAY(SELECT to_jsonb(namespace_name) FROM tabulars where deleted_at is NULL) AS "child_tabulars_namespace_names!: Vec<serde_json::Value>",
@@ -733,12 +873,39 @@ pub(crate) async fn drop_namespace(
         return Err(NamespaceProtected::new(warehouse_id, namespace_ident.clone()).into());
     }
 
-    if !force && info.has_protected_namespaces {
-        return Err(ChildNamespaceProtected::new(warehouse_id, namespace_ident.clone()).into());
+    if !force && !info.protected_child_namespaces.is_empty() {
+        return Err(
+            ChildNamespaceProtected::new(warehouse_id, namespace_ident.clone())
+                .append_detail(format!(
+                    "Protected child namespaces: {}",
+                    info.protected_child_namespaces.join(", ")
+                ))
+                .into(),
+        );
+    }
+
+    if !force && !info.protected_child_tabulars.is_empty() {
+        return Err(
+            ChildTabularProtected::new(warehouse_id, namespace_ident.clone())
+                .append_detail(format!(
+                    "Protected child tables, views or generic tables: {}",
+                    info.protected_child_tabulars.join(", ")
+                ))
+                .into(),
+        );
     }
 
-    if !force && info.has_protected_tabulars {
-        return Err(ChildTabularProtected::new(warehouse_id, namespace_ident.clone()).into());
+    // Recursive drops hard-delete all contained tabulars. Namespaces that override
+    // the delete profile with soft-deletion must be dropped with `force`.
+    if recursive && !force && !info.soft_delete_namespaces.is_empty() {
+        return Err(
+            NamespaceSoftDeletionEnabled::new(warehouse_id, namespace_ident.clone())
+                .append_detail(format!(
+                    "Namespaces with soft-deletion: {}",
+                    info.soft_delete_namespaces.join(", ")
+                ))
+                .into(),
+        );
     }
 
     if info.has_running_expiration {
@@ -938,6 +1105,358 @@ pub(crate) async fn set_namespace_protected(
         .map_err(Into::into)
 }
 
+pub(crate) async fn get_namespace_delete_profile(
+    warehouse_id: WarehouseId,
+    namespace_id: NamespaceId,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> std::result::Result<Option<TabularDeleteProfile>, CatalogGetNamespaceDeleteProfileError> {
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
+            tabular_expiration_seconds
+        FROM namespace
+        WHERE warehouse_id = $1 AND namespace_id = $2
+        "#,
+        *warehouse_id,
+        *namespace_id
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let Some(row) = row else {
+        return Err(NamespaceNotFound::new(warehouse_id, namespace_id).into());
+    };
+
+    row.tabular_delete_mode
+        .map(|mode| db_to_api_tabular_delete_profile(mode, row.tabular_expiration_seconds))
+        .transpose()
+        .map_err(Into::into)
+}
+
+pub(crate) async fn set_namespace_delete_profile(
+    warehouse_id: WarehouseId,
+    namespace_id: NamespaceId,
+    delete_profile: Option<&TabularDeleteProfile>,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> std::result::Result<Option<TabularDeleteProfile>, CatalogSetNamespaceDeleteProfileError> {
+    let mode = delete_profile.copied().map(DbTabularDeleteProfile::from);
+    let expiration_seconds = delete_profile
+        .and_then(TabularDeleteProfile::expiration_seconds)
+        .map(|dur| dur.num_seconds());
+
+    let row = sqlx::query!(
+        r#"
+        UPDATE namespace
+        SET tabular_delete_mode = $1, tabular_expiration_seconds = $2
+        WHERE warehouse_id = $3 AND namespace_id = $4
+        AND warehouse_id IN (
+            SELECT warehouse_id FROM warehouse WHERE status = 'active'
+        )
+        RETURNING
+            tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
+            tabular_expiration_seconds
+        "#,
+        mode as _,
+        expiration_seconds,
+        *warehouse_id,
+        *namespace_id
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let Some(row) = row else {
+        return Err(NamespaceNotFound::new(warehouse_id, namespace_id).into());
+    };
+
+    row.tabular_delete_mode
+        .map(|mode| db_to_api_tabular_delete_profile(mode, row.tabular_expiration_seconds))
+        .transpose()
+        .map_err(Into::into)
+}
+
+pub(crate) async fn get_namespace_table_defaults(
+    warehouse_id: WarehouseId,
+    namespace_id: NamespaceId,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> std::result::Result<NamespaceTableDefaults, CatalogGetNamespaceTableDefaultsError> {
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            default_partition_spec as "default_partition_spec: Json<UnboundPartitionSpec>",
+            default_sort_order as "default_sort_order: Json<SortOrder>"
+        FROM namespace
+        WHERE warehouse_id = $1 AND namespace_id = $2
+        "#,
+        *warehouse_id,
+        *namespace_id
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let Some(row) = row else {
+        return Err(NamespaceNotFound::new(warehouse_id, namespace_id).into());
+    };
+
+    Ok(NamespaceTableDefaults {
+        partition_spec: row.default_partition_spec.map(|spec| spec.0),
+        write_order: row.default_sort_order.map(|order| order.0),
+    })
+}
+
+pub(crate) async fn set_namespace_table_defaults(
+    warehouse_id: WarehouseId,
+    namespace_id: NamespaceId,
+    table_defaults: &NamespaceTableDefaults,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> std::result::Result<NamespaceTableDefaults, CatalogSetNamespaceTableDefaultsError> {
+    let row = sqlx::query!(
+        r#"
+        UPDATE namespace
+        SET default_partition_spec = $1, default_sort_order = $2
+        WHERE warehouse_id = $3 AND namespace_id = $4
+        AND warehouse_id IN (
+            SELECT warehouse_id FROM warehouse WHERE status = 'active'
+        )
+        RETURNING
+            default_partition_spec as "default_partition_spec: Json<UnboundPartitionSpec>",
+            default_sort_order as "default_sort_order: Json<SortOrder>"
+        "#,
+        table_defaults.partition_spec.as_ref().map(Json) as _,
+        table_defaults.write_order.as_ref().map(Json) as _,
+        *warehouse_id,
+        *namespace_id
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let Some(row) = row else {
+        return Err(NamespaceNotFound::new(warehouse_id, namespace_id).into());
+    };
+
+    Ok(NamespaceTableDefaults {
+        partition_spec: row.default_partition_spec.map(|spec| spec.0),
+        write_order: row.default_sort_order.map(|order| order.0),
+    })
+}
+
+pub(crate) async fn move_namespace(
+    warehouse_id: WarehouseId,
+    namespace_id: NamespaceId,
+    new_parent: Option<&NamespaceIdent>,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> std::result::Result<NamespaceMoveInfo, CatalogMoveNamespaceError> {
+    // Lock the namespace and all of its children. Tabulars of the subtree are updated
+    // by the `ON UPDATE CASCADE` foreign key on `tabular_namespace_name`.
+    let subtree = sqlx::query!(
+        r#"
+        WITH source_ns AS (
+            SELECT namespace_name
+            FROM namespace
+            WHERE warehouse_id = $1 AND namespace_id = $2
+            AND warehouse_id IN (
+                SELECT warehouse_id FROM warehouse WHERE status = 'active'
+            )
+        )
+        SELECT
+            n.namespace_id as "namespace_id!",
+            n.namespace_name as "namespace_name!"
+        FROM namespace n
+        INNER JOIN source_ns s ON n.namespace_name[1:array_length(s.namespace_name, 1)] = s.namespace_name
+        WHERE n.warehouse_id = $1
+        FOR UPDATE OF n
+        "#,
+        *warehouse_id,
+        *namespace_id,
+    )
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let Some(source) = subtree.iter().find(|ns| ns.namespace_id == *namespace_id) else {
+        return Err(NamespaceNotFound::new(warehouse_id, namespace_id).into());
+    };
+    let source_ident = parse_namespace_identifier_from_vec(
+        &source.namespace_name,
+        warehouse_id,
+        Some(namespace_id),
+    )?;
+    let subtree_ids = subtree
+        .iter()
+        .map(|ns| ns.namespace_id)
+        .collect::<Vec<Uuid>>();
+
+    // The destination parent is locked as well, so that it can't be dropped concurrently.
+    let new_prefix = if let Some(new_parent) = new_parent {
+        let parent = sqlx::query!(
+            r#"
+            SELECT namespace_id, namespace_name
+            FROM namespace
+            WHERE warehouse_id = $1 AND namespace_name = $2
+                AND (NOT (SELECT COALESCE((settings->>'case-sensitive-names')::boolean, false) FROM warehouse WHERE warehouse_id = $1)
+                    OR namespace_name COLLATE "C" = $2)
+            FOR UPDATE
+            "#,
+            *warehouse_id,
+            &**new_parent,
+        )
+        .fetch_optional(&mut **transaction)
+        .await
+        .map_err(DBErrorHandler::into_catalog_backend_error)?
+        .ok_or_else(|| NamespaceNotFound::new(warehouse_id, new_parent.clone()))?;
+
+        if subtree_ids.contains(&parent.namespace_id) {
+            return Err(NamespaceMoveIntoOwnSubtree::new(warehouse_id, source_ident).into());
+        }
+        parent.namespace_name
+    } else {
+        Vec::new()
+    };
+
+    let source_len = source.namespace_name.len();
+    let subtree_depth = subtree
+        .iter()
+        .map(|ns| ns.namespace_name.len() - source_len + 1)
+        .max()
+        .unwrap_or(1);
+    if new_prefix.len() + subtree_depth > MAX_NAMESPACE_DEPTH as usize {
+        return Err(NamespaceMoveExceedsMaxDepth::new(warehouse_id, source_ident)
+            .append_detail(format!(
+                "Maximum depth is {MAX_NAMESPACE_DEPTH}, the moved subtree is {subtree_depth} levels deep."
+            ))
+            .into());
+    }
+
+    let mut destination = new_prefix.clone();
+    destination.extend(source.namespace_name.last().cloned());
+    let destination =
+        parse_namespace_identifier_from_vec(&destination, warehouse_id, Some(namespace_id))?;
+    // Validated above, fits easily.
+    let source_len: i32 = source_len.try_into().unwrap_or(MAX_NAMESPACE_DEPTH + 1);
+
+    let row = sqlx::query_as!(
+        NamespaceWithParentVersionRow,
+        r#"
+        WITH updated_ns AS (
+            UPDATE namespace
+            SET namespace_name = $3::text[] || namespace_name[$4:]
+            WHERE warehouse_id = $1 AND namespace_id = ANY($5)
+            RETURNING
+                namespace_id,
+                namespace_name,
+                warehouse_id,
+                protected,
+                namespace_properties,
+                created_at,
+                updated_at,
+                version
+        ),
+        parent_ns AS (
+            SELECT
+                p.namespace_id,
+                p.version
+            FROM updated_ns u
+            INNER JOIN namespace p ON p.warehouse_id = u.warehouse_id
+                AND p.namespace_name = u.namespace_name[1:array_length(u.namespace_name, 1) - 1]
+            WHERE u.namespace_id = $2 AND array_length(u.namespace_name, 1) > 1
+        )
+        SELECT
+            u.namespace_id as "namespace_id!",
+            u.namespace_name as "namespace_name!",
+            -- No user-requested case when moving; return canonical.
+            u.namespace_name as "requested_name!",
+            u.warehouse_id as "warehouse_id!",
+            u.protected as "protected!",
+            u.namespace_properties as "properties!: Json<Option<HashMap<String, String>>>",
+            u.created_at as "created_at!",
+            u.updated_at,
+            u.version as "version!",
+            p.namespace_id as "parent_namespace_id?",
+            p.version as "parent_version?"
+        FROM updated_ns u
+        LEFT JOIN parent_ns p ON TRUE
+        WHERE u.namespace_id = $2
+        "#,
+        *warehouse_id,
+        *namespace_id,
+        &new_prefix,
+        source_len,
+        &subtree_ids,
+    )
+    .fetch_one(&mut **transaction)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(ref db_error) if db_error.is_unique_violation() => {
+            tracing::debug!("Namespace already exists at move destination: {db_error:?}");
+            CatalogMoveNamespaceError::from(NamespaceAlreadyExists::new(
+                warehouse_id,
+                destination.clone(),
+            ))
+        }
+        _ => e.into_catalog_backend_error().into(),
+    })?;
+
+    let namespace = row.into_namespace_with_parent_version(warehouse_id)?;
+    let source_depth = source.namespace_name.len();
+    let child_namespaces = subtree
+        .iter()
+        .filter(|ns| ns.namespace_id != *namespace_id)
+        .map(|ns| {
+            let mut name = new_prefix.clone();
+            name.extend_from_slice(&ns.namespace_name[source_depth - 1..]);
+            let child_id = NamespaceId::from(ns.namespace_id);
+            parse_namespace_identifier_from_vec(&name, warehouse_id, Some(child_id))
+                .map(|ident| (child_id, ident))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(NamespaceMoveInfo {
+        namespace,
+        child_namespaces,
+    })
+}
+
+pub(crate) async fn namespace_last_activity<
+    'c,
+    'e: 'c,
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+>(
+    warehouse_id: WarehouseId,
+    namespace_ids: &[NamespaceId],
+    connection: E,
+) -> std::result::Result<HashMap<NamespaceId, chrono::DateTime<chrono::Utc>>, CatalogBackendError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            namespace_id,
+            max(coalesce(updated_at, created_at)) as "last_activity!"
+        FROM tabular
+        WHERE warehouse_id = $1
+            AND namespace_id = ANY($2)
+            AND deleted_at IS NULL
+        GROUP BY namespace_id
+        "#,
+        *warehouse_id,
+        &namespace_ids
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect::<Vec<Uuid>>()
+    )
+    .fetch_all(connection)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| (NamespaceId::from(row.namespace_id), row.last_activity))
+        .collect())
+}
+
 pub(crate) async fn update_namespace_properties(
     warehouse_id: WarehouseId,
     namespace_id: NamespaceId,
@@ -2031,6 +2550,83 @@ pub mod tests {
         transaction.commit().await.unwrap();
     }
 
+    #[sqlx::test]
+    async fn test_cannot_drop_namespace_with_protected_nested_table(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+        let outer_namespace = NamespaceIdent::from_vec(vec!["test".to_string()]).unwrap();
+        let response =
+            initialize_namespace(state.clone(), warehouse_id, &outer_namespace, None).await;
+        let namespace_id = response.namespace_id();
+        let inner_namespace =
+            NamespaceIdent::from_vec(vec!["test".to_string(), "inner".to_string()]).unwrap();
+        initialize_namespace(state.clone(), warehouse_id, &inner_namespace, None).await;
+        let tab = initialize_table(
+            warehouse_id,
+            state.clone(),
+            false,
+            Some(inner_namespace),
+            None,
+            Some("protected_table".to_string()),
+        )
+        .await;
+
+        let mut transaction = PostgresTransaction::begin_write(state.clone())
+            .await
+            .unwrap();
+        set_tabular_protected(
+            warehouse_id,
+            TabularId::Table(tab.table_id),
+            true,
+            transaction.transaction(),
+        )
+        .await
+        .unwrap();
+
+        let err = drop_namespace(
+            warehouse_id,
+            namespace_id,
+            NamespaceDropFlags {
+                force: false,
+                purge: false,
+                recursive: true,
+            },
+            transaction.transaction(),
+        )
+        .await
+        .unwrap_err();
+
+        let CatalogNamespaceDropError::ChildTabularProtected(err) = err else {
+            panic!("Expected ChildTabularProtected, got {err:?}");
+        };
+        assert!(
+            err.stack
+                .iter()
+                .any(|detail| detail.contains("test.inner.protected_table")),
+            "blockers should be listed: {:?}",
+            err.stack
+        );
+
+        let drop_info = drop_namespace(
+            warehouse_id,
+            namespace_id,
+            NamespaceDropFlags {
+                force: true,
+                recursive: true,
+                purge: false,
+            },
+            transaction.transaction(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(drop_info.child_namespaces.len(), 1);
+        assert_eq!(drop_info.child_tables.len(), 1);
+
+        transaction.commit().await.unwrap();
+    }
+
     #[sqlx::test]
     async fn test_list_namespaces_with_hierarchy(pool: sqlx::PgPool) {
         let state = CatalogState::from_pools(pool.clone(), pool.clone());
@@ -2808,4 +3404,261 @@ pub mod tests {
             "State-path get_namespace must warm the shared NAMESPACE_CACHE"
         );
     }
+
+    #[sqlx::test]
+    async fn test_namespace_last_activity(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+
+        let mut namespaces = Vec::new();
+        for name in ["ns1", "ns2", "empty"] {
+            let ident = NamespaceIdent::from_vec(vec![name.to_string()]).unwrap();
+            let namespace = initialize_namespace(state.clone(), warehouse_id, &ident, None).await;
+            namespaces.push((ident, namespace.namespace_id()));
+        }
+        let [(ns1, ns1_id), (ns2, ns2_id), (_, empty_id)] = namespaces.try_into().unwrap();
+
+        let create = |namespace: &NamespaceIdent, name: &str| {
+            initialize_table(
+                warehouse_id,
+                state.clone(),
+                false,
+                Some(namespace.clone()),
+                None,
+                Some(name.to_string()),
+            )
+        };
+        let t1 = create(&ns1, "t1").await;
+        let t2 = create(&ns1, "t2").await;
+        let t3 = create(&ns2, "t3").await;
+
+        // Touch tables in separate transactions so their `updated_at` differ.
+        let touch = |table_id| {
+            let state = state.clone();
+            async move {
+                let mut transaction = PostgresTransaction::begin_write(state).await.unwrap();
+                let info = set_tabular_protected(
+                    warehouse_id,
+                    TabularId::Table(table_id),
+                    true,
+                    transaction.transaction(),
+                )
+                .await
+                .unwrap();
+                transaction.commit().await.unwrap();
+                info.updated_at().unwrap()
+            }
+        };
+        let t1_updated = touch(t1.table_id).await;
+        let t3_updated = touch(t3.table_id).await;
+        let t2_updated = touch(t2.table_id).await;
+        assert!(t2_updated > t1_updated);
+
+        let activity = PostgresBackend::namespace_last_activity(
+            warehouse_id,
+            &[ns1_id, ns2_id, empty_id],
+            state.clone(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            activity,
+            HashMap::from([(ns1_id, t2_updated), (ns2_id, t3_updated)])
+        );
+    }
+
+    async fn list_child_namespace_ids(
+        state: CatalogState,
+        warehouse_id: WarehouseId,
+        parent: Option<NamespaceIdent>,
+    ) -> Vec<NamespaceId> {
+        let mut transaction = PostgresTransaction::begin_read(state).await.unwrap();
+        let namespaces = list_namespaces(
+            warehouse_id,
+            &ListNamespacesQuery {
+                page_token: PageToken::NotSpecified,
+                page_size: Some(100),
+                parent,
+                return_uuids: true,
+                return_protection_status: false,
+            },
+            transaction.transaction(),
+        )
+        .await
+        .unwrap()
+        .namespaces;
+        transaction.commit().await.unwrap();
+        namespaces.into_hashmap().into_keys().collect()
+    }
+
+    #[sqlx::test]
+    async fn test_move_namespace_with_children_and_tables(pool: sqlx::PgPool) {
+        use lakekeeper::service::{CatalogTabularOps, TabularListFlags};
+
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+
+        let ident = |parts: &[&str]| {
+            NamespaceIdent::from_vec(parts.iter().map(ToString::to_string).collect()).unwrap()
+        };
+        let source =
+            initialize_namespace(state.clone(), warehouse_id, &ident(&["src"]), None).await;
+        let child =
+            initialize_namespace(state.clone(), warehouse_id, &ident(&["src", "child"]), None)
+                .await;
+        let destination =
+            initialize_namespace(state.clone(), warehouse_id, &ident(&["dest"]), None).await;
+        let source_table = initialize_table(
+            warehouse_id,
+            state.clone(),
+            false,
+            Some(ident(&["src"])),
+            None,
+            Some("t1".to_string()),
+        )
+        .await;
+        let child_table = initialize_table(
+            warehouse_id,
+            state.clone(),
+            false,
+            Some(ident(&["src", "child"])),
+            None,
+            Some("t2".to_string()),
+        )
+        .await;
+
+        let mut transaction = PostgresTransaction::begin_write(state.clone())
+            .await
+            .unwrap();
+        let move_info = move_namespace(
+            warehouse_id,
+            source.namespace_id(),
+            Some(&ident(&["dest"])),
+            transaction.transaction(),
+        )
+        .await
+        .unwrap();
+        transaction.commit().await.unwrap();
+
+        assert_eq!(
+            move_info.namespace.namespace_ident(),
+            &ident(&["dest", "src"])
+        );
+        assert_eq!(
+            move_info.namespace.parent_namespaces_id(),
+            Some(destination.namespace_id())
+        );
+        assert!(move_info.namespace.version() > source.version());
+        assert_eq!(
+            move_info.child_namespaces,
+            vec![(child.namespace_id(), ident(&["dest", "src", "child"]))]
+        );
+
+        assert_eq!(
+            list_child_namespace_ids(state.clone(), warehouse_id, Some(ident(&["dest"]))).await,
+            vec![source.namespace_id()]
+        );
+        assert_eq!(
+            list_child_namespace_ids(state.clone(), warehouse_id, Some(ident(&["dest", "src"])))
+                .await,
+            vec![child.namespace_id()]
+        );
+        assert_eq!(
+            list_child_namespace_ids(state.clone(), warehouse_id, None).await,
+            vec![destination.namespace_id()]
+        );
+
+        for (namespace, name, table_id) in [
+            (ident(&["dest", "src"]), "t1", source_table.table_id),
+            (ident(&["dest", "src", "child"]), "t2", child_table.table_id),
+        ] {
+            let table = PostgresBackend::get_table_info(
+                warehouse_id,
+                TableIdent::new(namespace, name.to_string()),
+                TabularListFlags::active(),
+                state.clone(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+            assert_eq!(table.tabular_id, table_id);
+        }
+        let old_table = PostgresBackend::get_table_info(
+            warehouse_id,
+            TableIdent::new(ident(&["src", "child"]), "t2".to_string()),
+            TabularListFlags::active(),
+            state.clone(),
+        )
+        .await
+        .unwrap();
+        assert!(old_table.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_move_namespace_rejects_cycles_and_conflicts(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+
+        let ident = |parts: &[&str]| {
+            NamespaceIdent::from_vec(parts.iter().map(ToString::to_string).collect()).unwrap()
+        };
+        let a = initialize_namespace(state.clone(), warehouse_id, &ident(&["a"]), None).await;
+        let a_b =
+            initialize_namespace(state.clone(), warehouse_id, &ident(&["a", "b"]), None).await;
+        initialize_namespace(state.clone(), warehouse_id, &ident(&["B"]), None).await;
+
+        let mut transaction = PostgresTransaction::begin_write(state.clone())
+            .await
+            .unwrap();
+        for new_parent in [ident(&["a"]), ident(&["a", "b"])] {
+            let err = move_namespace(
+                warehouse_id,
+                a.namespace_id(),
+                Some(&new_parent),
+                transaction.transaction(),
+            )
+            .await
+            .unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    CatalogMoveNamespaceError::NamespaceMoveIntoOwnSubtree(_)
+                ),
+                "{err:?}"
+            );
+        }
+
+        // Namespace names are case-insensitive, so `a.b` can't become a sibling of `B`.
+        let err = move_namespace(
+            warehouse_id,
+            a_b.namespace_id(),
+            None,
+            transaction.transaction(),
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(err, CatalogMoveNamespaceError::NamespaceAlreadyExists(_)),
+            "{err:?}"
+        );
+        transaction.rollback().await.unwrap();
+
+        let mut transaction = PostgresTransaction::begin_write(state.clone())
+            .await
+            .unwrap();
+        let err = move_namespace(
+            warehouse_id,
+            a_b.namespace_id(),
+            Some(&ident(&["missing"])),
+            transaction.transaction(),
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(err, CatalogMoveNamespaceError::NamespaceNotFound(_)),
+            "{err:?}"
+        );
+        transaction.rollback().await.unwrap();
+    }
 }
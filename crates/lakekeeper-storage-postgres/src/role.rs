@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use itertools::Itertools;
 use lakekeeper::{
-    CONFIG, ProjectId,
+    CONFIG, DynAppConfig, ProjectId,
     api::{iceberg::v1::PaginationQuery, management::v1::role::UpdateRoleSourceSystemRequest},
     service::{
         CatalogBackendError, CatalogCreateRoleRequest, CatalogListRolesByIdFilter, CreateRoleError,
@@ -297,6 +297,17 @@ pub(crate) async fn search_role<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sql
 }
 
 pub async fn list_roles<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sqlx::Postgres>>(
+    project_id: Option<&ProjectId>,
+    filter: CatalogListRolesByIdFilter<'_>,
+    pagination_query: PaginationQuery,
+    connection: E,
+) -> Result<ListRolesResponse, ListRolesError> {
+    list_roles_with_config(&CONFIG, project_id, filter, pagination_query, connection).await
+}
+
+/// Like [`list_roles`], but sizes pages according to the given `config`.
+async fn list_roles_with_config<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sqlx::Postgres>>(
+    config: &DynAppConfig,
     project_id: Option<&ProjectId>,
     filter: CatalogListRolesByIdFilter<'_>,
     PaginationQuery {
@@ -305,7 +316,7 @@ pub async fn list_roles<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sqlx::Postg
     }: PaginationQuery,
     connection: E,
 ) -> Result<ListRolesResponse, ListRolesError> {
-    let page_size = CONFIG.page_size_or_pagination_default(page_size);
+    let page_size = config.page_size_or_pagination_default(page_size);
 
     let CatalogListRolesByIdFilter {
         role_ids,
@@ -1215,6 +1226,105 @@ mod test {
         assert!(roles.next_page_token.is_none());
     }
 
+    #[sqlx::test]
+    async fn test_list_roles_clamps_page_size(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+        let project_id = ProjectId::new_random();
+
+        let mut t = PostgresTransaction::begin_write(state.clone())
+            .await
+            .unwrap();
+        PostgresBackend::create_project(
+            &project_id,
+            format!("Project {project_id}"),
+            t.transaction(),
+        )
+        .await
+        .unwrap();
+        t.commit().await.unwrap();
+
+        let provider_id = RoleProviderId::lakekeeper();
+        let roles = (0..2)
+            .map(|i| {
+                let role_id = RoleId::new_random();
+                (
+                    role_id,
+                    RoleSourceId::new_from_role_id(role_id),
+                    format!("Role-{i}"),
+                )
+            })
+            .collect::<Vec<_>>();
+        create_roles(
+            &project_id,
+            roles
+                .iter()
+                .map(|(role_id, source_id, name)| {
+                    CatalogCreateRoleRequest::builder()
+                        .role_id(*role_id)
+                        .role_name(name)
+                        .source_id(source_id)
+                        .provider_id(&provider_id)
+                        .build()
+                })
+                .collect(),
+            OnRoleConflict::Fail,
+            &state.write_pool(),
+        )
+        .await
+        .unwrap();
+
+        let provider_ids: [&RoleProviderId; 1] = [&provider_id];
+        let page = list_roles(
+            Some(&project_id),
+            CatalogListRolesByIdFilter::builder()
+                .provider_ids(Some(&provider_ids))
+                .build(),
+            PaginationQuery {
+                page_size: Some(0),
+                page_token: PageToken::Empty,
+            },
+            &state.read_pool(),
+        )
+        .await
+        .unwrap();
+        let min_page_size = usize::try_from(CONFIG.clamp_page_size(0)).unwrap();
+        assert_eq!(page.roles.len(), min_page_size);
+
+        let page = list_roles(
+            Some(&project_id),
+            CatalogListRolesByIdFilter::builder()
+                .provider_ids(Some(&provider_ids))
+                .build(),
+            PaginationQuery {
+                page_size: Some(i64::MAX),
+                page_token: page.next_page_token.into(),
+            },
+            &state.read_pool(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.roles.len(), 1);
+
+        // Requests above the configured maximum are capped to it.
+        let mut config = DynAppConfig::default();
+        config.pagination_size_max = 1;
+        let page = list_roles_with_config(
+            &config,
+            Some(&project_id),
+            CatalogListRolesByIdFilter::builder()
+                .provider_ids(Some(&provider_ids))
+                .build(),
+            PaginationQuery {
+                page_size: Some(2),
+                page_token: PageToken::Empty,
+            },
+            &state.read_pool(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.roles.len(), 1);
+    }
+
     #[sqlx::test]
     async fn test_delete_role(pool: sqlx::PgPool) {
         let state = CatalogState::from_pools(pool.clone(), pool.clone());
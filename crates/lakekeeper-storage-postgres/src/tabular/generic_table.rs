@@ -5,8 +5,8 @@ use lakekeeper::{
         CatalogBackendError, CreateGenericTableError, DropGenericTableError,
         GenericTableAlreadyExists, GenericTableCreation, GenericTableFormat, GenericTableId,
         GenericTableInfo, GenericTableListEntry, GenericTableNotFound, ListGenericTablesError,
-        LoadGenericTableError, NamespaceId, NamespaceVersion, TabularId, WarehouseVersion,
-        storage::join_location,
+        LoadGenericTableError, NamespaceId, NamespaceVersion, StorageProfileSlot, TabularId,
+        WarehouseVersion, storage::join_location,
     },
 };
 use uuid::Uuid;
@@ -105,6 +105,7 @@ pub(crate) async fn create_generic_table(
             typ: TabularType::GenericTable,
             metadata_location: None,
             location: &creation.location,
+            storage_profile_slot: StorageProfileSlot::Primary,
         },
         transaction,
     )
@@ -8,23 +8,24 @@ use std::{collections::HashMap, default::Default, fmt::Debug, str::FromStr as _}
 
 use chrono::Utc;
 use lakekeeper::{
-    CONFIG, WarehouseId,
+    CONFIG, DynAppConfig, WarehouseId,
     api::iceberg::v1::{PaginatedMapping, PaginationQuery},
     service::{
-        CatalogSearchTabularInfo, CatalogSearchTabularResponse, ClearTabularDeletedAtError,
-        ConcurrentUpdateError, CreateTabularError, DropTabularError, ExpirationTaskInfo,
-        GenericTableDeletionInfo, GenericTabularInfo, GetTabularInfoError,
+        CatalogBackendError, CatalogSearchTabularInfo, CatalogSearchTabularResponse,
+        ClearTabularDeletedAtError, ConcurrentUpdateError, CreateTabularError, DropTabularError,
+        ExpirationTaskInfo, GenericTableDeletionInfo, GenericTabularInfo, GetTabularInfoError,
         InternalParseLocationError, InvalidNamespaceIdentifier, ListTabularsError,
         LocationAlreadyTaken, MarkTabularAsDeletedError, NamespaceId,
         ProtectedTabularDeletionWithoutForce, RenameTabularError, SearchTabularError,
-        SerializationError, TableDeletionInfo, TableIdent, TableInfo, TabularAlreadyExists,
-        TabularId, TabularIdentBorrowed, TabularNotFound, ViewDeletionInfo, ViewInfo,
+        SerializationError, StorageProfileSlot, TableDeletionInfo, TableIdent, TableInfo,
+        TabularAlreadyExists, TabularId, TabularIdentBorrowed, TabularNotFound,
+        TabularPropertiesSize, TouchTabularError, ViewDeletionInfo, ViewInfo,
         ViewOrTableDeletionInfo, ViewOrTableInfo, storage::join_location,
     },
 };
 use lakekeeper_io::Location;
 pub(crate) use load_by_location::*;
-pub(crate) use protection::set_tabular_protected;
+pub(crate) use protection::{set_namespace_tabulars_protected, set_tabular_protected};
 use sqlx::FromRow;
 use uuid::Uuid;
 
@@ -438,6 +439,8 @@ where
             INNER JOIN warehouse w ON w.warehouse_id = $1
             WHERE in_t.name IS NOT NULL AND in_ns.name IS NOT NULL
                 AND w.status = 'active'
+                AND (NOT COALESCE((w.settings->>'case-sensitive-names')::boolean, false)
+                    OR (t.name COLLATE "C" = in_t.name AND n.namespace_name COLLATE "C" = in_ns.name))
                 AND (t.deleted_at is NULL OR $5)
                 AND (t.metadata_location is not NULL OR $6 OR t.typ = 'generic-table')
         ),
@@ -517,20 +520,34 @@ pub(crate) struct CreateTabular<'a> {
     pub(crate) typ: TabularType,
     pub(crate) metadata_location: Option<&'a Location>,
     pub(crate) location: &'a Location,
+    pub(crate) storage_profile_slot: StorageProfileSlot,
 }
 
+/// `fs_location`s of `location` and all of its parents. Each is returned with
+/// and without a trailing slash, so that tabulars are matched independent of
+/// the canonical form they were stored in.
 pub(crate) fn get_partial_fs_locations(
     location: &Location,
 ) -> Result<Vec<String>, InternalParseLocationError> {
-    location
-        .partial_locations()
-        .into_iter()
+    let mut fs_locations = Vec::new();
+    for partial_location in location.partial_locations() {
+        let partial_location = Location::from_str(partial_location)?;
         // Keep only the last part of the location
-        .map(|l| {
-            let location = Location::from_str(l)?;
-            Ok(location.authority_and_path().to_string())
-        })
-        .collect()
+        let fs_location = partial_location.authority_and_path().trim_end_matches('/');
+        fs_locations.push(format!("{fs_location}/"));
+        fs_locations.push(fs_location.to_string());
+    }
+    Ok(fs_locations)
+}
+
+/// `fs_location` of `location` in the canonical form configured by
+/// `tabular_location_trailing_slash`.
+pub(crate) fn canonical_fs_location(location: &Location) -> String {
+    let mut location = location.clone();
+    CONFIG
+        .tabular_location_trailing_slash
+        .canonicalize(&mut location);
+    location.authority_and_path().to_string()
 }
 
 impl From<FromTabularRowError> for CreateTabularError {
@@ -543,7 +560,8 @@ impl From<FromTabularRowError> for CreateTabularError {
 }
 
 /// Errors with `LocationAlreadyTaken` if any other tabular in `warehouse_id`
-/// occupies `location` (or a path that this location would shadow).
+/// occupies `location` (or a path that this location would shadow). The error
+/// names one of the conflicting tabulars.
 ///
 /// Shared between `create_tabular` (where it backstops the table-level unique
 /// constraint on `(warehouse_id, name, namespace_id)` for location uniqueness)
@@ -556,28 +574,32 @@ pub(crate) async fn ensure_location_available(
     transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> Result<(), CreateTabularError> {
     let partial_locations = get_partial_fs_locations(location)?;
-    let fs_location = location.authority_and_path();
-    let taken = sqlx::query_scalar!(
-        r#"SELECT EXISTS (
-               SELECT 1
-               FROM tabular ta
-               WHERE ta.warehouse_id = $1 AND (fs_location = ANY($2) OR
-                      (length($4) < length(fs_location) AND ((TRIM(TRAILING '/' FROM fs_location) || '/') LIKE $4 || '/%'))
-               ) AND tabular_id != $3
-           ) as "exists!""#,
+    let fs_location = location.authority_and_path().trim_end_matches('/');
+    let conflicting = sqlx::query!(
+        r#"SELECT tabular_id, typ as "typ: TabularType"
+           FROM tabular ta
+           WHERE ta.warehouse_id = $1 AND (fs_location = ANY($2) OR
+                  (length($4) < length(fs_location) AND ((TRIM(TRAILING '/' FROM fs_location) || '/') LIKE $4 || '/%'))
+           ) AND tabular_id != $3
+           LIMIT 1"#,
         warehouse_id,
         &partial_locations,
         self_tabular_id,
         fs_location,
     )
-    .fetch_one(&mut **transaction)
+    .fetch_optional(&mut **transaction)
     .await
     .map_err(|e| {
         e.into_catalog_backend_error()
             .append_detail("Error checking for conflicting locations")
     })?;
-    if taken {
-        return Err(LocationAlreadyTaken::new(location.clone()).into());
+    if let Some(conflicting) = conflicting {
+        let conflicting_tabular_id = match conflicting.typ {
+            TabularType::Table => TabularId::Table(conflicting.tabular_id.into()),
+            TabularType::View => TabularId::View(conflicting.tabular_id.into()),
+            TabularType::GenericTable => TabularId::GenericTable(conflicting.tabular_id.into()),
+        };
+        return Err(LocationAlreadyTaken::new(location.clone(), conflicting_tabular_id).into());
     }
     Ok(())
 }
@@ -591,11 +613,12 @@ pub(crate) async fn create_tabular(
         typ,
         metadata_location,
         location,
+        storage_profile_slot,
     }: CreateTabular<'_>,
     transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> Result<ViewOrTableInfo, CreateTabularError> {
     let fs_protocol = location.scheme();
-    let fs_location = location.authority_and_path();
+    let fs_location = canonical_fs_location(location);
 
     // Check location availability before the INSERT so a collision raises
     // `LocationAlreadyTaken` cleanly instead of inserting a row we'll have to
@@ -606,8 +629,8 @@ pub(crate) async fn create_tabular(
         TabularRowCore,
         r#"
         WITH inserted AS (
-            INSERT INTO tabular (tabular_id, name, namespace_id, tabular_namespace_name, warehouse_id, typ, metadata_location, fs_protocol, fs_location)
-            SELECT $1, $2, $3, n.namespace_name, $4, $5, $6, $7, $8
+            INSERT INTO tabular (tabular_id, name, namespace_id, tabular_namespace_name, warehouse_id, typ, metadata_location, fs_protocol, fs_location, storage_profile_slot)
+            SELECT $1, $2, $3, n.namespace_name, $4, $5, $6, $7, $8, $9
             FROM namespace n
             WHERE n.namespace_id = $3 AND n.warehouse_id = $4
             RETURNING
@@ -645,7 +668,8 @@ pub(crate) async fn create_tabular(
         typ as _,
         metadata_location.map(Location::as_str),
         fs_protocol,
-        fs_location
+        fs_location,
+        storage_profile_slot as _
     )
     .fetch_one(&mut **transaction)
     .await
@@ -769,7 +793,6 @@ impl From<FromTabularRowError> for ListTabularsError {
     }
 }
 
-#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 pub(crate) async fn list_tabulars<'e, 'c, E>(
     warehouse_id: WarehouseId,
     namespace_id: Option<NamespaceId>,
@@ -781,7 +804,33 @@ pub(crate) async fn list_tabulars<'e, 'c, E>(
 where
     E: 'e + sqlx::Executor<'c, Database = sqlx::Postgres>,
 {
-    let page_size = CONFIG.page_size_or_pagination_max(pagination_query.page_size);
+    list_tabulars_with_config(
+        &CONFIG,
+        warehouse_id,
+        namespace_id,
+        list_flags,
+        catalog_state,
+        typ,
+        pagination_query,
+    )
+    .await
+}
+
+/// Like [`list_tabulars`], but sizes pages according to the given `config`.
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
+pub(crate) async fn list_tabulars_with_config<'e, 'c, E>(
+    config: &DynAppConfig,
+    warehouse_id: WarehouseId,
+    namespace_id: Option<NamespaceId>,
+    list_flags: lakekeeper::service::TabularListFlags,
+    catalog_state: E,
+    typ: Option<TabularType>,
+    pagination_query: PaginationQuery,
+) -> Result<PaginatedMapping<TabularId, ViewOrTableDeletionInfo>, ListTabularsError>
+where
+    E: 'e + sqlx::Executor<'c, Database = sqlx::Postgres>,
+{
+    let page_size = config.page_size_or_pagination_max(pagination_query.page_size);
 
     let token = pagination_query
         .page_token
@@ -927,6 +976,97 @@ where
     Ok(tabulars)
 }
 
+pub(crate) async fn get_soft_deleted_tabular(
+    warehouse_id: WarehouseId,
+    tabular_id: TabularId,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<Option<ViewOrTableDeletionInfo>, GetTabularInfoError> {
+    let tabular = sqlx::query_as!(
+        TabularRowWithDeletion,
+        r#"
+        WITH selected_tabular AS (
+            SELECT
+                t.tabular_id,
+                t.name as tabular_name,
+                t.tabular_namespace_name as namespace_name,
+                t.namespace_id,
+                t.metadata_location,
+                t.typ,
+                t.updated_at,
+                t.created_at,
+                t.deleted_at,
+                tt.scheduled_for as deletion_scheduled_for,
+                tt.task_id as deletion_task_id,
+                t.protected,
+                t.fs_location,
+                t.fs_protocol,
+                w.version as warehouse_version,
+                n.version as namespace_version
+            FROM tabular t
+            INNER JOIN warehouse w ON w.warehouse_id = $1
+            INNER JOIN namespace n ON n.namespace_id = t.namespace_id AND n.warehouse_id = $1
+            LEFT JOIN task tt ON (t.tabular_id = tt.entity_id AND tt.entity_type in ('table', 'view', 'generic-table') AND tt.queue_name IN ('soft_deletion', 'tabular_expiration') AND tt.warehouse_id = $1 AND tt.project_id = w.project_id)
+            WHERE t.warehouse_id = $1
+                AND t.tabular_id = $2
+                AND t.deleted_at IS NOT NULL
+                AND w.status = 'active'
+            FOR UPDATE OF t
+        )
+        SELECT st.tabular_id,
+               st.tabular_name,
+               st.namespace_name,
+               st.namespace_id,
+               st.metadata_location,
+               st.typ as "typ: TabularType",
+               st.updated_at,
+               st.created_at,
+               st.deleted_at,
+               st.deletion_scheduled_for as "deletion_scheduled_for?",
+               st.deletion_task_id as "deletion_task_id?",
+               st.protected,
+               st.fs_location,
+               st.fs_protocol,
+               st.namespace_version,
+               st.warehouse_version,
+               vp.view_properties_keys,
+               vp.view_properties_values,
+               tp.keys as table_properties_keys,
+               tp.values as table_properties_values,
+               gtp.keys as generic_table_properties_keys,
+               gtp.values as generic_table_properties_values
+        FROM selected_tabular st
+        LEFT JOIN (SELECT view_id,
+                    ARRAY_AGG(key)   AS view_properties_keys,
+                    ARRAY_AGG(value) AS view_properties_values
+            FROM view_properties
+            WHERE warehouse_id = $1 AND view_id = $2
+            GROUP BY view_id) vp ON st.tabular_id = vp.view_id
+        LEFT JOIN (SELECT table_id,
+                    ARRAY_AGG(key) as keys,
+                    ARRAY_AGG(value) as values
+                FROM table_properties
+                WHERE warehouse_id = $1 AND table_id = $2
+                GROUP BY table_id) tp ON st.tabular_id = tp.table_id
+        LEFT JOIN (SELECT generic_table_id,
+                    ARRAY_AGG(key) as keys,
+                    ARRAY_AGG(value) as values
+                FROM generic_table_properties
+                WHERE warehouse_id = $1 AND generic_table_id = $2
+                GROUP BY generic_table_id) gtp ON st.tabular_id = gtp.generic_table_id
+        "#,
+        *warehouse_id,
+        *tabular_id,
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(super::dbutils::DBErrorHandler::into_catalog_backend_error)?;
+
+    tabular
+        .map(|tabular| tabular.try_into_table_or_view(warehouse_id))
+        .transpose()
+        .map_err(Into::into)
+}
+
 struct PostgresSearchTabularInfo {
     tabular_id: Uuid,
     namespace_id: Uuid,
@@ -1033,6 +1173,8 @@ impl PostgresSearchTabularInfo {
 /// If the search term corresponds to an uuid, it instead searches for a table or namespace
 /// with that uuid. If a namespace with that uuid exists, the response contains tabulars inside the
 /// namespace.
+///
+/// Results with equal distance are ordered by `tabular_id`, so the order is stable across calls.
 #[allow(clippy::too_many_lines)]
 pub(crate) async fn search_tabular<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sqlx::Postgres>>(
     warehouse_id: WarehouseId,
@@ -1066,7 +1208,7 @@ pub(crate) async fn search_tabular<'e, 'c: 'e, E: sqlx::Executor<'c, Database =
                     AND t.deleted_at IS NULL
                     AND (t.metadata_location IS NOT NULL OR t.typ = 'generic-table')
                     AND (t.tabular_id = $2 OR t.namespace_id = $2)
-                ORDER BY (t.tabular_id = $2) DESC
+                ORDER BY (t.tabular_id = $2) DESC, t.tabular_id ASC
                 LIMIT 10
             ),
             selected_views AS (
@@ -1116,6 +1258,7 @@ pub(crate) async fn search_tabular<'e, 'c: 'e, E: sqlx::Executor<'c, Database =
                     FROM generic_table_properties
                     WHERE warehouse_id = $1 AND generic_table_id in (SELECT tabular_id FROM selected_generic_tables)
                     GROUP BY generic_table_id) gtp ON st.tabular_id = gtp.generic_table_id
+            ORDER BY (st.tabular_id = $2) DESC, st.tabular_id ASC
             "#,
             *warehouse_id,
             id,
@@ -1152,7 +1295,7 @@ pub(crate) async fn search_tabular<'e, 'c: 'e, E: sqlx::Executor<'c, Database =
                     AND w.status = 'active'
                     AND t.deleted_at IS NULL
                     AND (t.metadata_location IS NOT NULL OR t.typ = 'generic-table')
-                ORDER BY distance ASC
+                ORDER BY distance ASC, tabular_id ASC
                 LIMIT 10
             ),
             filtered_tabulars AS (
@@ -1206,7 +1349,7 @@ pub(crate) async fn search_tabular<'e, 'c: 'e, E: sqlx::Executor<'c, Database =
                     FROM generic_table_properties
                     WHERE warehouse_id = $1 AND generic_table_id in (SELECT tabular_id FROM selected_generic_tables)
                     GROUP BY generic_table_id) gtp ON st.tabular_id = gtp.generic_table_id
-            ORDER BY distance ASC
+            ORDER BY distance ASC, st.tabular_id ASC
             "#,
             *warehouse_id,
             search_term,
@@ -1224,6 +1367,67 @@ pub(crate) async fn search_tabular<'e, 'c: 'e, E: sqlx::Executor<'c, Database =
     })
 }
 
+pub(crate) async fn list_tabulars_exceeding_properties_size<
+    'e,
+    'c: 'e,
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+>(
+    warehouse_id: WarehouseId,
+    max_properties_bytes: u64,
+    connection: E,
+) -> Result<Vec<TabularPropertiesSize>, CatalogBackendError> {
+    let rows = sqlx::query!(
+        r#"
+        WITH properties_size AS (
+            SELECT table_id AS tabular_id, sum(octet_length(key) + octet_length(value)) AS bytes
+            FROM table_properties
+            WHERE warehouse_id = $1
+            GROUP BY table_id
+            UNION ALL
+            SELECT view_id AS tabular_id, sum(octet_length(key) + octet_length(value)) AS bytes
+            FROM view_properties
+            WHERE warehouse_id = $1
+            GROUP BY view_id
+            UNION ALL
+            SELECT generic_table_id AS tabular_id, sum(octet_length(key) + octet_length(value)) AS bytes
+            FROM generic_table_properties
+            WHERE warehouse_id = $1
+            GROUP BY generic_table_id
+        )
+        SELECT
+            t.tabular_id,
+            t.namespace_id,
+            t.name,
+            t.typ as "typ: TabularType",
+            ps.bytes as "properties_bytes!"
+        FROM properties_size ps
+        INNER JOIN tabular t ON t.warehouse_id = $1 AND t.tabular_id = ps.tabular_id
+        WHERE ps.bytes > $2
+            AND t.deleted_at IS NULL
+        ORDER BY ps.bytes DESC, t.tabular_id
+        "#,
+        *warehouse_id,
+        i64::try_from(max_properties_bytes).unwrap_or(i64::MAX),
+    )
+    .fetch_all(connection)
+    .await
+    .map_err(super::dbutils::DBErrorHandler::into_catalog_backend_error)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| TabularPropertiesSize {
+            tabular_id: match row.typ {
+                TabularType::Table => TabularId::Table(row.tabular_id.into()),
+                TabularType::View => TabularId::View(row.tabular_id.into()),
+                TabularType::GenericTable => TabularId::GenericTable(row.tabular_id.into()),
+            },
+            namespace_id: row.namespace_id.into(),
+            tabular_name: row.name,
+            properties_bytes: u64::try_from(row.properties_bytes).unwrap_or_default(),
+        })
+        .collect())
+}
+
 impl From<FromTabularRowError> for RenameTabularError {
     fn from(err: FromTabularRowError) -> Self {
         match err {
@@ -1386,6 +1590,8 @@ pub(crate) async fn rename_tabular(
                 SELECT namespace_id
                 FROM namespace
                 WHERE warehouse_id = $2 AND namespace_name = $3
+                    AND (NOT (SELECT COALESCE((settings->>'case-sensitive-names')::boolean, false) FROM warehouse WHERE warehouse_id = $2)
+                        OR namespace_name COLLATE "C" = $3)
                 FOR UPDATE
             ),
             locked_source_namespace AS ( -- source namespace of the tabular
@@ -1524,6 +1730,38 @@ impl From<TabularType> for lakekeeper::api::management::v1::TabularType {
     }
 }
 
+pub(crate) async fn touch_tabular(
+    warehouse_id: WarehouseId,
+    tabular_id: TabularId,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<chrono::DateTime<Utc>, TouchTabularError> {
+    // The `set_updated_at` trigger only fires if the row changes, so the timestamp
+    // is written explicitly. The warehouse change version is bumped by its trigger.
+    let updated_at = sqlx::query_scalar!(
+        r#"
+        UPDATE tabular t
+        SET updated_at = now()
+        FROM warehouse w
+        WHERE t.warehouse_id = $1
+            AND t.tabular_id = $2
+            AND t.typ = $3
+            AND t.deleted_at IS NULL
+            AND w.warehouse_id = $1
+            AND w.status = 'active'
+        RETURNING t.updated_at as "updated_at!"
+        "#,
+        *warehouse_id,
+        *tabular_id,
+        TabularType::from(tabular_id) as _,
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(super::dbutils::DBErrorHandler::into_catalog_backend_error)?
+    .ok_or_else(|| TabularNotFound::new(warehouse_id, tabular_id))?;
+
+    Ok(updated_at)
+}
+
 impl From<FromTabularRowError> for ClearTabularDeletedAtError {
     fn from(err: FromTabularRowError) -> Self {
         match err {
@@ -1929,7 +2167,8 @@ mod tests {
 
     use super::*;
     use crate::{
-        CatalogState, namespace::tests::initialize_namespace, warehouse::test::initialize_warehouse,
+        CatalogState, namespace::tests::initialize_namespace,
+        tabular::table::tests::initialize_table, warehouse::test::initialize_warehouse,
     };
 
     pub(super) async fn setup_test_tabular(pool: &sqlx::PgPool, protected: bool) -> TableInfo {
@@ -1957,6 +2196,7 @@ mod tests {
                 typ: TabularType::Table,
                 metadata_location: Some(&metadata_location),
                 location: &location,
+                storage_profile_slot: StorageProfileSlot::Primary,
             },
             &mut transaction,
         )
@@ -2217,6 +2457,7 @@ mod tests {
                         typ: TabularType::Table,
                         metadata_location: Some(&metadata_location),
                         location: &location,
+                        storage_profile_slot: StorageProfileSlot::Primary,
                     },
                     &mut transaction,
                 )
@@ -2278,6 +2519,7 @@ mod tests {
                     typ: TabularType::Table,
                     metadata_location: Some(&metadata_location),
                     location: &location,
+                    storage_profile_slot: StorageProfileSlot::Primary,
                 },
                 &mut transaction,
             )
@@ -2309,4 +2551,112 @@ mod tests {
         );
         assert_eq!(res.tabular.tabular_ident().name, "test_region_42");
     }
+
+    #[sqlx::test]
+    async fn test_search_tabular_orders_ties_by_tabular_id(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+
+        // Same table name in two namespaces whose names differ in a single character, so both
+        // are equally distant from the search term. The tabular with the larger id is created
+        // first to rule out insertion order as tiebreaker.
+        let mut ids = [Uuid::now_v7(), Uuid::now_v7()];
+        ids.sort();
+        let [smaller_id, larger_id] = ids;
+        for (ns, table_id) in [("sales_a", larger_id), ("sales_b", smaller_id)] {
+            let namespace = iceberg_ext::NamespaceIdent::from_vec(vec![ns.to_string()]).unwrap();
+            let namespace_id = initialize_namespace(state.clone(), warehouse_id, &namespace, None)
+                .await
+                .namespace_id();
+            let location =
+                Location::from_str(&format!("s3://test-bucket/{namespace_id}/orders/")).unwrap();
+            let metadata_location = Location::from_str(&format!(
+                "s3://test-bucket/{namespace_id}/orders/metadata/v1.json"
+            ))
+            .unwrap();
+            let mut transaction = pool.begin().await.unwrap();
+            create_tabular(
+                CreateTabular {
+                    id: table_id,
+                    name: "orders",
+                    namespace_id: *namespace_id,
+                    warehouse_id: *warehouse_id,
+                    typ: TabularType::Table,
+                    metadata_location: Some(&metadata_location),
+                    location: &location,
+                    storage_profile_slot: StorageProfileSlot::Primary,
+                },
+                &mut transaction,
+            )
+            .await
+            .unwrap();
+            transaction.commit().await.unwrap();
+        }
+
+        for _ in 0..5 {
+            let results = search_tabular(warehouse_id, "orders", &state.read_write.read_pool)
+                .await
+                .unwrap()
+                .search_results;
+            assert_eq!(results.len(), 2);
+            assert_eq!(results[0].distance, results[1].distance);
+            assert_eq!(
+                results
+                    .iter()
+                    .map(|r| *r.tabular.tabular_id())
+                    .collect::<Vec<_>>(),
+                vec![smaller_id, larger_id]
+            );
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_list_tabulars_exceeding_properties_size(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+
+        let mut table_ids = Vec::new();
+        for value_size in [12_000, 30_000, 0] {
+            let table =
+                initialize_table(warehouse_id, state.clone(), false, None, None, None).await;
+            if value_size > 0 {
+                sqlx::query(
+                    "INSERT INTO table_properties (warehouse_id, table_id, key, value) VALUES ($1, $2, $3, $4)",
+                )
+                .bind(*warehouse_id)
+                .bind(*table.table_id)
+                .bind("large.property")
+                .bind("x".repeat(value_size))
+                .execute(&pool)
+                .await
+                .unwrap();
+            }
+            table_ids.push(table.table_id);
+        }
+        let [medium, large, small] = table_ids.try_into().unwrap();
+
+        let oversized =
+            list_tabulars_exceeding_properties_size(warehouse_id, 10_000, &state.read_pool())
+                .await
+                .unwrap();
+
+        assert_eq!(
+            oversized.iter().map(|t| t.tabular_id).collect::<Vec<_>>(),
+            vec![TabularId::Table(large), TabularId::Table(medium)],
+            "Only tables above the budget are flagged, largest first"
+        );
+        assert!(oversized[0].properties_bytes > 30_000);
+        assert!(
+            !oversized
+                .iter()
+                .any(|t| t.tabular_id == TabularId::Table(small))
+        );
+
+        // Raising the budget above the largest table yields nothing.
+        let oversized =
+            list_tabulars_exceeding_properties_size(warehouse_id, 1_000_000, &state.read_pool())
+                .await
+                .unwrap();
+        assert!(oversized.is_empty());
+    }
 }
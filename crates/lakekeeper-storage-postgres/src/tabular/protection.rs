@@ -1,6 +1,10 @@
 use lakekeeper::{
     WarehouseId,
-    service::{SetTabularProtectionError, TabularId, TabularNotFound, ViewOrTableInfo},
+    service::{
+        NamespaceId, NamespaceNotFound, SetNamespaceTabularsProtectionError,
+        SetTabularProtectionError, TabularId, TabularNotFound, TabularProtectionCounts,
+        ViewOrTableInfo,
+    },
 };
 
 use super::TabularType;
@@ -123,3 +127,61 @@ pub(crate) async fn set_tabular_protected(
 
     row.try_into_table_or_view(warehouse_id).map_err(Into::into)
 }
+
+pub(crate) async fn set_namespace_tabulars_protected(
+    warehouse_id: WarehouseId,
+    namespace_id: NamespaceId,
+    protected: bool,
+    recursive: bool,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<TabularProtectionCounts, SetNamespaceTabularsProtectionError> {
+    tracing::debug!(
+        "Setting protection of all tabulars in namespace {namespace_id} (recursive: {recursive}) to {protected}"
+    );
+
+    // Tabulars of descendants are found by the prefix of their namespace name.
+    let row = sqlx::query!(
+        r#"
+        WITH ns AS (
+            SELECT n.namespace_id, n.namespace_name
+            FROM namespace n
+            INNER JOIN warehouse w ON w.warehouse_id = n.warehouse_id
+            WHERE n.warehouse_id = $1 AND n.namespace_id = $2 AND w.status = 'active'
+        ),
+        updated_tabular AS (
+            UPDATE tabular t
+            SET protected = $3
+            FROM ns
+            WHERE t.warehouse_id = $1
+                AND t.deleted_at IS NULL
+                AND (t.namespace_id = ns.namespace_id
+                    OR ($4 AND t.tabular_namespace_name[1:array_length(ns.namespace_name, 1)] = ns.namespace_name))
+            RETURNING t.typ
+        )
+        SELECT
+            EXISTS (SELECT 1 FROM ns) as "namespace_exists!",
+            count(*) FILTER (WHERE typ = 'table') as "tables!",
+            count(*) FILTER (WHERE typ = 'view') as "views!",
+            count(*) FILTER (WHERE typ = 'generic-table') as "generic_tables!"
+        FROM updated_tabular
+        "#,
+        *warehouse_id,
+        *namespace_id,
+        protected,
+        recursive
+    )
+    .fetch_one(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    if !row.namespace_exists {
+        return Err(NamespaceNotFound::new(warehouse_id, namespace_id).into());
+    }
+
+    let count = |n: i64| u64::try_from(n).unwrap_or_default();
+    Ok(TabularProtectionCounts {
+        tables: count(row.tables),
+        views: count(row.views),
+        generic_tables: count(row.generic_tables),
+    })
+}
@@ -17,7 +17,7 @@ use sqlx::{FromRow, Postgres, Row, Transaction};
 use crate::{
     dbutils::DBErrorHandler,
     tabular::{
-        FromTabularRowError, TabularRowCore,
+        FromTabularRowError, TabularRowCore, canonical_fs_location,
         table::{
             DbTableFormatVersion, MAX_PARAMETERS, TableUpdateFlags,
             common::{self, expire_metadata_log_entries, remove_snapshot_log_entries},
@@ -233,7 +233,7 @@ fn build_table_and_tabular_update_queries(
     ) in location_metadata_pairs.into_iter().enumerate()
     {
         let fs_protocol = new_location.scheme();
-        let fs_location = new_location.authority_and_path();
+        let fs_location = canonical_fs_location(&new_location);
 
         let next_row_id = i64::try_from(new_metadata.next_row_id()).map_err(|e| {
             ConversionError::new_external(
@@ -270,7 +270,7 @@ fn build_table_and_tabular_update_queries(
         query_builder_tabular.push(", ");
         query_builder_tabular.push_bind(new_metadata_location.to_string());
         query_builder_tabular.push(", ");
-        query_builder_tabular.push_bind(fs_location.to_string());
+        query_builder_tabular.push_bind(fs_location);
         query_builder_tabular.push(", ");
         query_builder_tabular.push_bind(fs_protocol.to_string());
         query_builder_tabular.push(", ");
@@ -522,6 +522,23 @@ async fn apply_metadata_changes(
         .await?;
     }
 
+    // Must run before insert_table_statistics, as replaced statistics are both
+    // removed and added, and before remove_snapshots
+    if !diffs.removed_stats.is_empty() {
+        common::remove_table_statistics(warehouse_id, table_id, diffs.removed_stats, transaction)
+            .await?;
+    }
+    // Must run before insert_partition_statistics and remove_snapshots
+    if !diffs.removed_partition_stats.is_empty() {
+        common::remove_partition_statistics(
+            warehouse_id,
+            table_id,
+            diffs.removed_partition_stats,
+            transaction,
+        )
+        .await?;
+    }
+
     // Must run after insert_snapshots
     if !diffs.added_partition_stats.is_empty() {
         common::insert_partition_statistics(
@@ -552,21 +569,6 @@ async fn apply_metadata_changes(
         )
         .await?;
     }
-    // Must run before remove_snapshots
-    if !diffs.removed_stats.is_empty() {
-        common::remove_table_statistics(warehouse_id, table_id, diffs.removed_stats, transaction)
-            .await?;
-    }
-    // Must run before remove_snapshots
-    if !diffs.removed_partition_stats.is_empty() {
-        common::remove_partition_statistics(
-            warehouse_id,
-            table_id,
-            diffs.removed_partition_stats,
-            transaction,
-        )
-        .await?;
-    }
 
     // Must run after insert_snapshots
     if !diffs.removed_snapshots.is_empty() {
@@ -640,7 +642,7 @@ mod tests {
     use lakekeeper::{
         api::iceberg::v1::tables::LoadTableFilters,
         server::tables::calculate_diffs,
-        service::{CatalogTableOps, TableCreation, TableInfo},
+        service::{CatalogTableOps, StorageProfileSlot, TableCreation, TableInfo},
     };
     use lakekeeper_io::Location;
 
@@ -726,6 +728,7 @@ mod tests {
             },
             metadata_location: Some(&metadata_location),
             table_metadata: &metadata,
+            storage_profile_slot: StorageProfileSlot::Primary,
         };
 
         let mut t = pool.begin().await.unwrap();
@@ -13,6 +13,8 @@ use lakekeeper::{
 use sqlx::{PgConnection, Postgres, Transaction};
 
 use crate::{
+    compressed_json::to_stored_json,
+    config::CONFIG,
     dbutils::DBErrorHandler,
     tabular::table::{assigned_rows_as_i64, first_row_id_as_i64, normalized_schema},
 };
@@ -177,7 +179,7 @@ pub(crate) async fn insert_partition_specs(
     for part_spec in partition_specs {
         spec_ids.push(part_spec.spec_id());
         specs.push(
-            serde_json::to_value(part_spec)
+            to_stored_json(part_spec, CONFIG.pg_compress_table_metadata)
                 .map_err(|e| SerializationError::new("partition spec", e))?,
         );
     }
@@ -266,7 +268,7 @@ pub(crate) async fn insert_sort_orders(
     for sort_order in sort_orders_iter {
         sort_order_ids.push(sort_order.order_id);
         sort_orders.push(
-            serde_json::to_value(sort_order)
+            to_stored_json(sort_order, CONFIG.pg_compress_table_metadata)
                 .map_err(|e| SerializationError::new("sort order", e))?,
         );
     }
@@ -33,6 +33,7 @@ pub(crate) async fn create_table(
         table_ident,
         table_metadata,
         metadata_location,
+        storage_profile_slot,
     }: TableCreation<'_>,
     transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
 ) -> Result<(TableInfo, Option<StagedTableId>), CreateTableError> {
@@ -52,6 +53,7 @@ pub(crate) async fn create_table(
             typ: TabularType::Table,
             metadata_location,
             location: &location,
+            storage_profile_slot,
         },
         transaction,
     )
@@ -2,6 +2,7 @@ mod commit;
 mod common;
 mod create;
 pub(crate) mod normalized_schema;
+mod snapshots;
 
 use std::{collections::HashMap, default::Default, ops::Deref, str::FromStr, sync::Arc};
 
@@ -21,13 +22,16 @@ use lakekeeper::{
     api::iceberg::v1::tables::{LoadTableFilters, SnapshotsQuery},
     service::{
         ConversionError, InternalParseLocationError, InternalTableMetadataBuildFailed,
-        LoadTableError, LoadTableResponse, RequiredTableComponentMissing, TableId,
-        storage::join_location,
+        LoadTableError, LoadTableResponse, RequiredTableComponentMissing, StorageProfileSlot,
+        TableId, storage::join_location,
     },
 };
+pub(crate) use snapshots::list_table_snapshots;
 use sqlx::types::Json;
 use uuid::Uuid;
 
+use crate::compressed_json::StoredJson;
+
 const MAX_PARAMETERS: usize = 30000;
 
 #[inline]
@@ -129,7 +133,7 @@ struct TableQueryStruct {
     table_ref_retention: Option<Vec<Json<SnapshotRetention>>>,
     default_sort_order_id: Option<i64>,
     sort_order_ids: Option<Vec<i64>>,
-    sort_orders: Option<Vec<Json<SortOrder>>>,
+    sort_orders: Option<Vec<Json<StoredJson<SortOrder>>>>,
     metadata_log_timestamps: Option<Vec<i64>>,
     metadata_log_files: Option<Vec<String>>,
     snapshot_log_timestamps: Option<Vec<i64>>,
@@ -148,11 +152,12 @@ struct TableQueryStruct {
     table_fs_location: String,
     table_fs_protocol: String,
     warehouse_version: i64,
+    storage_profile_slot: StorageProfileSlot,
     table_properties_keys: Option<Vec<String>>,
     table_properties_values: Option<Vec<String>>,
     default_partition_spec_id: Option<i32>,
     partition_spec_ids: Option<Vec<i32>>,
-    partition_specs: Option<Vec<Json<PartitionSpec>>>,
+    partition_specs: Option<Vec<Json<StoredJson<PartitionSpec>>>>,
     current_schema: Option<i32>,
     table_format_version: DbTableFormatVersion,
     next_row_id: i64,
@@ -181,6 +186,7 @@ impl TableQueryStruct {
         self,
         schema_rows: Vec<normalized_schema::SchemaFieldRow>,
         expected_schema_ids: &[i32],
+        snapshots_filter: &SnapshotsQuery,
     ) -> Result<TableMetadata, LoadTableError> {
         fn expect<T>(
             field: Option<T>,
@@ -213,7 +219,7 @@ impl TableQueryStruct {
             .zip(
                 expect(self.partition_specs, "Partition Specs", &info)?
                     .into_iter()
-                    .map(|s| Arc::new(s.0)),
+                    .map(|s| Arc::new(s.0.0)),
             )
             .collect::<HashMap<_, _>>();
 
@@ -299,7 +305,7 @@ impl TableQueryStruct {
         )
         .collect::<Result<HashMap<_, _>, LoadTableError>>()?;
 
-        let snapshot_log = itertools::multizip((
+        let mut snapshot_log = itertools::multizip((
             self.snapshot_log_ids.unwrap_or_default(),
             self.snapshot_log_timestamps.unwrap_or_default(),
         ))
@@ -323,10 +329,10 @@ impl TableQueryStruct {
             expect(self.sort_order_ids, "Sort Order IDs", &info)?,
             expect(self.sort_orders, "Sort Orders", &info)?,
         ))
-        .map(|(sort_order_id, sort_order)| (sort_order_id, Arc::new(sort_order.0)))
+        .map(|(sort_order_id, sort_order)| (sort_order_id, Arc::new(sort_order.0.0)))
         .collect::<HashMap<_, _>>();
 
-        let refs = itertools::multizip((
+        let mut refs = itertools::multizip((
             self.table_ref_names.unwrap_or_default(),
             self.table_ref_snapshot_ids.unwrap_or_default(),
             self.table_ref_retention.unwrap_or_default(),
@@ -342,6 +348,23 @@ impl TableQueryStruct {
         })
         .collect::<HashMap<_, _>>();
 
+        match snapshots_filter {
+            // No snapshots are loaded in `none` mode, so refs and the snapshot log
+            // would dangle. The table is presented as if it had no snapshots.
+            SnapshotsQuery::None => {
+                refs.clear();
+                snapshot_log.clear();
+            }
+            // Only the head of `main` is loaded in `current` mode. Keep the refs and
+            // snapshot log entries pointing to it, drop everything else.
+            SnapshotsQuery::Current => {
+                let current_snapshot_id = refs.get(MAIN_BRANCH).map(|r| r.snapshot_id);
+                refs.retain(|_, r| Some(r.snapshot_id) == current_snapshot_id);
+                snapshot_log.retain(|log| Some(log.snapshot_id) == current_snapshot_id);
+            }
+            SnapshotsQuery::All | SnapshotsQuery::Refs => {}
+        }
+
         let current_snapshot_id = refs.get(MAIN_BRANCH).map(|s| s.snapshot_id);
 
         let partition_statistics = itertools::multizip((
@@ -522,7 +545,7 @@ pub(crate) async fn load_tables(
             WHERE warehouse_id = $1 AND table_id = ANY($2)
         ),
         snapshots_to_load AS (
-            -- refs mode: drive from filtered_table_refs (one index lookup per ref)
+            -- refs and current mode: drive from filtered_table_refs (one index lookup per ref)
             SELECT ts.table_id, ts.snapshot_id, ts.parent_snapshot_id, ts.sequence_number,
                    ts.manifest_list, ts.summary, ts.schema_id, ts.timestamp_ms,
                    ts.first_row_id, ts.assigned_rows, ts.key_id
@@ -532,6 +555,8 @@ pub(crate) async fn load_tables(
                AND ftr.table_id    = ts.table_id
                AND ftr.snapshot_id = ts.snapshot_id
             WHERE $4 = 'refs'
+            -- current mode: only the snapshot of the main branch
+               OR ($4 = 'current' AND ftr.table_ref_name = 'main')
             UNION ALL
             -- all mode: full scan, unchanged behaviour
             SELECT table_id, snapshot_id, parent_snapshot_id, sequence_number,
@@ -556,6 +581,7 @@ pub(crate) async fn load_tables(
             ti.tabular_namespace_name as "namespace_name",
             ti.namespace_id,
             ti."metadata_location",
+            ti.storage_profile_slot as "storage_profile_slot: StorageProfileSlot",
             w.version as "warehouse_version",
             tcs.schema_id as "current_schema",
             tdps.partition_spec_id as "default_partition_spec_id",
@@ -571,7 +597,7 @@ pub(crate) async fn load_tables(
             tsnap.key_id as "snapshot_key_ids: Vec<Option<String>>",
             tdsort.sort_order_id as "default_sort_order_id?",
             tps.partition_spec_id as "partition_spec_ids",
-            tps.partition_spec as "partition_specs: Vec<Json<PartitionSpec>>",
+            tps.partition_spec as "partition_specs: Vec<Json<StoredJson<PartitionSpec>>>",
             tp.keys as "table_properties_keys",
             tp.values as "table_properties_values",
             tsl.snapshot_ids as "snapshot_log_ids",
@@ -579,7 +605,7 @@ pub(crate) async fn load_tables(
             tml.metadata_files as "metadata_log_files",
             tml.timestamps as "metadata_log_timestamps",
             tso.sort_order_ids as "sort_order_ids",
-            tso.sort_orders as "sort_orders: Vec<Json<SortOrder>>",
+            tso.sort_orders as "sort_orders: Vec<Json<StoredJson<SortOrder>>>",
             tr.table_ref_names as "table_ref_names",
             tr.snapshot_ids as "table_ref_snapshot_ids",
             tr.retentions as "table_ref_retention: Vec<Json<SnapshotRetention>>",
@@ -685,6 +711,8 @@ pub(crate) async fn load_tables(
         match snapshots_filter {
             SnapshotsQuery::All => "all",
             SnapshotsQuery::Refs => "refs",
+            SnapshotsQuery::None => "none",
+            SnapshotsQuery::Current => "current",
         }
     )
     .fetch_all(&mut **transaction)
@@ -752,6 +780,7 @@ pub(crate) async fn load_tables(
         .into_iter()
         .map(|table| {
             let warehouse_version = table.warehouse_version;
+            let storage_profile_slot = table.storage_profile_slot;
             let table_id = table.table_id.into();
             let metadata_location = table
                 .metadata_location
@@ -766,7 +795,8 @@ pub(crate) async fn load_tables(
             let expected_schema_ids = schema_ids_by_table
                 .remove(&table.table_id)
                 .unwrap_or_default();
-            let table_metadata = table.into_table_metadata(schema_rows, &expected_schema_ids)?;
+            let table_metadata =
+                table.into_table_metadata(schema_rows, &expected_schema_ids, snapshots_filter)?;
 
             Ok(LoadTableResponse {
                 table_id,
@@ -774,6 +804,7 @@ pub(crate) async fn load_tables(
                 table_metadata,
                 metadata_location,
                 warehouse_version: warehouse_version.into(),
+                storage_profile_slot,
             })
         })
         .collect()
@@ -850,8 +881,8 @@ pub mod tests {
         namespace::tests::initialize_namespace,
         tabular::{
             drop_tabular, get_tabular_infos_by_idents, get_tabular_infos_by_ids,
-            get_tabular_infos_by_s3_location, list_tabulars, mark_tabular_as_deleted,
-            rename_tabular, table::create::create_table,
+            get_tabular_infos_by_s3_location, list_tabulars, list_tabulars_with_config,
+            mark_tabular_as_deleted, rename_tabular, table::create::create_table,
         },
         warehouse::{set_warehouse_status, test::initialize_warehouse},
     };
@@ -905,6 +936,7 @@ pub mod tests {
                 write_order: None,
                 stage_create,
                 properties: None,
+                table_uuid: None,
             },
             metadata_location,
         )
@@ -1020,6 +1052,7 @@ pub mod tests {
             namespace_id,
             table_ident: &table_ident,
             table_metadata: &table_metadata,
+            storage_profile_slot: StorageProfileSlot::Primary,
             metadata_location: metadata_location.as_ref(),
         };
         let mut transaction = state.write_pool().begin().await.unwrap();
@@ -1064,6 +1097,7 @@ pub mod tests {
             write_order: None,
             stage_create: Some(false),
             properties: None,
+            table_uuid: None,
         };
         let table_metadata = create_table_request_into_table_metadata(
             table_id,
@@ -1079,6 +1113,7 @@ pub mod tests {
             namespace_id,
             table_ident: &table_ident,
             table_metadata: &table_metadata,
+            storage_profile_slot: StorageProfileSlot::Primary,
             metadata_location: Some(&metadata_location),
         };
         let mut transaction = state.write_pool().begin().await.unwrap();
@@ -1120,6 +1155,7 @@ pub mod tests {
             namespace_id,
             table_ident: &table_ident,
             table_metadata: &table_metadata,
+            storage_profile_slot: StorageProfileSlot::Primary,
             metadata_location: metadata_location.as_ref(),
         };
 
@@ -1209,6 +1245,7 @@ pub mod tests {
             namespace_id,
             table_ident: &table_ident,
             table_metadata: &table_metadata,
+            storage_profile_slot: StorageProfileSlot::Primary,
             metadata_location: metadata_location.as_ref(),
         };
 
@@ -1262,6 +1299,7 @@ pub mod tests {
             namespace_id,
             table_ident: &table_ident,
             table_metadata: &table_metadata,
+            storage_profile_slot: StorageProfileSlot::Primary,
             metadata_location: metadata_location.as_ref(),
         };
         let mut transaction = pool.begin().await.unwrap();
@@ -1854,6 +1892,79 @@ pub mod tests {
         assert!(tables.next_token().is_none());
     }
 
+    #[sqlx::test]
+    async fn test_list_tables_clamps_page_size(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+        let namespace = NamespaceIdent::from_vec(vec!["my_namespace".to_string()]).unwrap();
+        let namespace_id = initialize_namespace(state.clone(), warehouse_id, &namespace, None)
+            .await
+            .namespace_id();
+
+        for i in 0..2 {
+            initialize_table(
+                warehouse_id,
+                state.clone(),
+                false,
+                Some(namespace.clone()),
+                None,
+                Some(format!("t{i}")),
+            )
+            .await;
+        }
+
+        let tables = list_tabulars(
+            warehouse_id,
+            Some(namespace_id),
+            TabularListFlags::active(),
+            &state.read_pool(),
+            None,
+            PaginationQuery {
+                page_token: PageToken::NotSpecified,
+                page_size: Some(0),
+            },
+        )
+        .await
+        .unwrap();
+        let min_page_size = usize::try_from(lakekeeper::CONFIG.clamp_page_size(0)).unwrap();
+        assert_eq!(tables.len(), min_page_size);
+
+        let tables = list_tabulars(
+            warehouse_id,
+            Some(namespace_id),
+            TabularListFlags::active(),
+            &state.read_pool(),
+            None,
+            PaginationQuery {
+                page_token: PageToken::Present(tables.next_token().unwrap().to_string()),
+                page_size: Some(i64::MAX),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(tables.len(), 1);
+
+        // Requests above the configured maximum are capped to it.
+        let mut config = lakekeeper::DynAppConfig::default();
+        config.pagination_size_max = 1;
+        let tables = list_tabulars_with_config(
+            &config,
+            warehouse_id,
+            Some(namespace_id),
+            TabularListFlags::active(),
+            &state.read_pool(),
+            None,
+            PaginationQuery {
+                page_token: PageToken::NotSpecified,
+                page_size: Some(2),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(tables.len(), 1);
+    }
+
     #[sqlx::test]
     #[cfg_attr(test, tracing_test::traced_test)]
     async fn test_get_id_by_location(pool: sqlx::PgPool) {
@@ -1957,6 +2068,56 @@ pub mod tests {
         assert_eq!(table_info_by_location, None);
     }
 
+    #[sqlx::test]
+    async fn test_get_by_location_ignores_trailing_slash(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+
+        let (_, warehouse_id) = initialize_warehouse(state.clone(), None, None, None, true).await;
+        let table = initialize_table(warehouse_id, state.clone(), false, None, None, None).await;
+        let table_info = get_tabular_infos_by_ids(
+            warehouse_id,
+            &[table.table_id.into()],
+            TabularListFlags::active(),
+            &state.read_pool(),
+        )
+        .await
+        .unwrap()
+        .remove(0);
+
+        let mut without_slash = table_info.location().clone();
+        without_slash.without_trailing_slash();
+        let mut with_slash = table_info.location().clone();
+        with_slash.with_trailing_slash();
+        let mut file_location = without_slash.clone();
+        file_location.push("data/foo.parquet");
+
+        // Tabulars stored in the other canonical form must be found as well
+        for stored in [
+            without_slash.authority_and_path(),
+            with_slash.authority_and_path(),
+        ] {
+            sqlx::query("UPDATE tabular SET fs_location = $1 WHERE tabular_id = $2")
+                .bind(stored)
+                .bind(*table.table_id)
+                .execute(&pool)
+                .await
+                .unwrap();
+
+            for location in [&without_slash, &with_slash, &file_location] {
+                let table_info_by_location = get_tabular_infos_by_s3_location(
+                    warehouse_id,
+                    location,
+                    TabularListFlags::active(),
+                    state.clone(),
+                )
+                .await
+                .unwrap()
+                .unwrap_or_else(|| panic!("No tabular found at {location} (stored: {stored})"));
+                assert_eq!(table_info_by_location.tabular_id(), table.table_id.into());
+            }
+        }
+    }
+
     #[sqlx::test]
     async fn test_cannot_get_table_of_inactive_warehouse(pool: sqlx::PgPool) {
         let state = CatalogState::from_pools(pool.clone(), pool.clone());
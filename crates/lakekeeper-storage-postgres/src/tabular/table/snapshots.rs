@@ -0,0 +1,107 @@
+use iceberg::spec::Summary;
+use iceberg_ext::catalog::rest::ErrorModel;
+use lakekeeper::{
+    CONFIG, WarehouseId,
+    api::{
+        Result,
+        management::v1::table::{
+            ListTableSnapshotsQuery, ListTableSnapshotsResponse, TableSnapshot,
+        },
+    },
+    service::TableId,
+};
+use sqlx::types::Json;
+
+use crate::{
+    CatalogState,
+    dbutils::DBErrorHandler as _,
+    pagination::{PaginateToken, V1PaginateToken},
+};
+
+pub(crate) async fn list_table_snapshots(
+    warehouse_id: WarehouseId,
+    table_id: TableId,
+    query: &ListTableSnapshotsQuery,
+    state: CatalogState,
+) -> Result<ListTableSnapshotsResponse> {
+    let page_size = CONFIG.page_size_or_pagination_default(query.page_size);
+    let previous_page_token = query.page_token.clone();
+    let token = query
+        .page_token
+        .as_deref()
+        .map(PaginateToken::<i64>::try_from)
+        .transpose()?;
+    // Snapshot timestamps are stored in milliseconds, the token carries them as a timestamp.
+    let (pagination_ts, pagination_id) = token
+        .map(|PaginateToken::V1(V1PaginateToken { created_at, id })| {
+            (created_at.timestamp_millis(), id)
+        })
+        .map_or((None, None), |(ts, id)| (Some(ts), Some(id)));
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            snapshot_id,
+            parent_snapshot_id,
+            sequence_number,
+            timestamp_ms,
+            schema_id,
+            summary as "summary: Json<Summary>"
+        FROM table_snapshot
+        WHERE warehouse_id = $1 AND table_id = $2
+            AND ((timestamp_ms < $3 OR $3 IS NULL) OR (timestamp_ms = $3 AND snapshot_id < $4))
+        ORDER BY timestamp_ms DESC, snapshot_id DESC
+        LIMIT $5
+        "#,
+        *warehouse_id,
+        *table_id,
+        pagination_ts,
+        pagination_id,
+        page_size,
+    )
+    .fetch_all(&state.read_pool())
+    .await
+    .map_err(|e| e.into_error_model("Error listing table snapshots"))?;
+
+    let next_page_token = rows
+        .last()
+        .map(|last| {
+            let created_at = chrono::DateTime::from_timestamp_millis(last.timestamp_ms)
+                .ok_or_else(|| {
+                    ErrorModel::internal(
+                        format!(
+                            "Timestamp of snapshot {} is out of range: {}",
+                            last.snapshot_id, last.timestamp_ms
+                        ),
+                        "InvalidSnapshotTimestamp",
+                        None,
+                    )
+                })?;
+            Ok::<_, ErrorModel>(
+                PaginateToken::V1(V1PaginateToken {
+                    created_at,
+                    id: last.snapshot_id,
+                })
+                .to_string(),
+            )
+        })
+        .transpose()?
+        .or(previous_page_token);
+
+    let snapshots = rows
+        .into_iter()
+        .map(|row| TableSnapshot {
+            snapshot_id: row.snapshot_id,
+            parent_snapshot_id: row.parent_snapshot_id,
+            sequence_number: row.sequence_number,
+            timestamp_ms: row.timestamp_ms,
+            schema_id: row.schema_id,
+            summary: row.summary.0,
+        })
+        .collect();
+
+    Ok(ListTableSnapshotsResponse {
+        snapshots,
+        next_page_token,
+    })
+}
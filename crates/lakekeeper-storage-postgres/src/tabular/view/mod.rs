@@ -13,7 +13,8 @@ use lakekeeper::{
     service::{
         CatalogBackendError, CommitViewError, ConcurrentUpdateError, ConversionError,
         CreateViewError, CreateViewVersionError, InternalParseLocationError, NamespaceId,
-        SerializationError, TabularNotFound, UnexpectedTabularInResponse, ViewId, ViewInfo,
+        SerializationError, StorageProfileSlot, TabularNotFound, UnexpectedTabularInResponse,
+        ViewId, ViewInfo,
     },
 };
 use lakekeeper_io::Location;
@@ -50,6 +51,7 @@ pub(crate) async fn create_view(
             typ: TabularType::View,
             metadata_location: Some(metadata_location),
             location: &location,
+            storage_profile_slot: StorageProfileSlot::Primary,
         },
         &mut *transaction,
     )
@@ -95,7 +97,7 @@ pub(crate) async fn commit_existing_view(
     let location =
         Location::from_str(metadata.location()).map_err(InternalParseLocationError::from)?;
     let view_id = ViewId::from(metadata.uuid());
-    let fs_location = location.authority_and_path();
+    let fs_location = super::canonical_fs_location(&location);
     let fs_protocol = location.scheme();
 
     // Compile-time guard: the `tabular` UPDATE below does not touch
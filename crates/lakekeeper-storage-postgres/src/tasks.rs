@@ -9,7 +9,7 @@ use lakekeeper::{
         tasks::TaskStatus,
     },
     service::{
-        ArcProjectId, DatabaseIntegrityError, TableId, ViewId,
+        ArcProjectId, DatabaseIntegrityError, TableId, TaskQueueDepth, ViewId,
         task_configs::TaskQueueConfigFilter,
         tasks::{
             CancelTasksFilter, ScheduleTaskMetadata, Task, TaskAttemptId, TaskCheckState,
@@ -594,7 +594,20 @@ pub(crate) async fn record_failure(
     conn: &mut PgConnection,
 ) -> Result<(), IcebergErrorResponse> {
     let TaskAttemptId { task_id, attempt } = *id.as_ref();
-    let max_retries_exceeded = attempt >= max_retries;
+    // Retries are counted from the last requeue of the task.
+    let requeued_at_attempt = sqlx::query_scalar!(
+        r#"SELECT requeued_at_attempt FROM task WHERE task_id = $1 AND attempt = $2"#,
+        *task_id,
+        attempt
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        e.into_error_model("Error recording task attempt failure.")
+            .append_detail(format!("Task ID: {task_id}, Attempt: {attempt}"))
+    })?
+    .unwrap_or_default();
+    let max_retries_exceeded = attempt - requeued_at_attempt >= max_retries;
     let (task_log_exists, log_inserted, conflicting_message, conflicting_created_at) = if max_retries_exceeded {
         sqlx::query!(
             r#"
@@ -782,6 +795,51 @@ pub(crate) async fn record_failure(
     Ok(())
 }
 
+/// Put a running attempt back into the queue without recording it as failed.
+///
+/// Used when a worker has to abandon a task, e.g. during a graceful shutdown.
+/// The attempt counter is reset, so the next pickup reuses the attempt number
+/// and the retry budget of the task is not consumed.
+pub(crate) async fn release_task(
+    id: impl AsRef<TaskAttemptId>,
+    conn: &mut PgConnection,
+) -> Result<(), IcebergErrorResponse> {
+    let TaskAttemptId { task_id, attempt } = *id.as_ref();
+    let released = sqlx::query!(
+        r#"
+        UPDATE task
+        SET status = 'scheduled',
+            progress = 0.0,
+            execution_details = NULL,
+            picked_up_at = NULL,
+            last_heartbeat_at = NULL,
+            attempt = attempt - 1
+        WHERE task_id = $1 AND attempt = $2 AND status IN ('running', 'should-stop')
+        "#,
+        *task_id,
+        attempt
+    )
+    .execute(conn)
+    .await
+    .map_err(|e| {
+        e.into_error_model("Error releasing task attempt.")
+            .append_detail(format!("Task ID: {task_id}, Attempt: {attempt}"))
+    })?
+    .rows_affected();
+
+    if released == 0 {
+        return Err(ErrorModel::not_found(
+            format!("Task {task_id} with attempt {attempt} not found in running tasks."),
+            "TaskNotFound",
+            None,
+        )
+        .append_detail("Error releasing task attempt.")
+        .into());
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 struct TaskConfigRow {
     config: serde_json::Value,
@@ -893,6 +951,39 @@ pub(crate) async fn set_task_queue_config(
     Ok(())
 }
 
+pub(crate) async fn get_task_queue_depth(
+    pool: &PgPool,
+    queue_name: &TaskQueueName,
+    legacy_queue_names: &[&TaskQueueName],
+) -> lakekeeper::api::Result<TaskQueueDepth> {
+    let queue_names: Vec<String> = std::iter::once(queue_name.as_str().to_string())
+        .chain(legacy_queue_names.iter().map(|n| n.as_str().to_string()))
+        .collect();
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            COUNT(*) AS "pending!",
+            EXTRACT(EPOCH FROM now() - MIN(scheduled_for))::float8 AS "oldest_pending_age_seconds"
+        FROM task
+        WHERE queue_name = ANY($1)
+            AND status = 'scheduled'
+            AND scheduled_for <= now()
+        "#,
+        &queue_names,
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(?e, "Failed to get task queue depth");
+        e.into_error_model(format!("Failed to get task queue depth for {queue_name}"))
+    })?;
+
+    Ok(TaskQueueDepth {
+        pending: row.pending,
+        oldest_pending_age_seconds: row.oldest_pending_age_seconds,
+    })
+}
+
 pub(crate) async fn request_tasks_stop(
     transaction: &mut PgConnection,
     task_ids: &[TaskId],
@@ -1008,6 +1099,134 @@ pub(crate) async fn reschedule_tasks_for(
     Ok(())
 }
 
+/// Defer tasks to `run_at`. Tasks that already completed successfully cannot be
+/// rescheduled; if any of `task_ids` did, no task is modified and a conflict is returned.
+/// All other tasks are handled like [`reschedule_tasks_for`].
+pub(crate) async fn reschedule_unfinished_tasks(
+    transaction: &mut PgConnection,
+    task_ids: &[TaskId],
+    run_at: chrono::DateTime<chrono::Utc>,
+) -> lakekeeper::api::Result<()> {
+    let succeeded_task_ids = sqlx::query_scalar!(
+        r#"
+        SELECT DISTINCT tl.task_id as "task_id!"
+        FROM task_log tl
+        WHERE tl.task_id = ANY($1)
+            AND tl.status = 'success'
+            AND NOT EXISTS (SELECT 1 FROM task t WHERE t.task_id = tl.task_id)
+        "#,
+        &task_ids.iter().map(|s| **s).collect_vec(),
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .map_err(|e| {
+        tracing::error!(
+            ?e,
+            "Failed to check for completed tasks before rescheduling"
+        );
+        e.into_error_model("Failed to check for completed tasks before rescheduling.")
+    })?;
+
+    if !succeeded_task_ids.is_empty() {
+        return Err(ErrorModel::conflict(
+            format!(
+                "Cannot reschedule tasks that already completed successfully: {}",
+                succeeded_task_ids.iter().sorted().join(", ")
+            ),
+            "TaskAlreadySucceeded",
+            None,
+        )
+        .into());
+    }
+
+    reschedule_tasks_for(transaction, task_ids, Some(run_at)).await
+}
+
+/// Requeue tasks that failed permanently. The task keeps its id and history;
+/// it is scheduled to run now and gets its full retry budget back.
+///
+/// Fails with a conflict if any of the tasks is not in the final `failed`
+/// state or another task is active for the same entity and queue. Tasks
+/// requeued before the error are only discarded if the transaction is not committed.
+pub(crate) async fn requeue_failed_tasks(
+    transaction: &mut PgConnection,
+    task_ids: &[TaskId],
+) -> lakekeeper::api::Result<()> {
+    let requeued_task_ids = sqlx::query_scalar!(
+        r#"
+        WITH last_attempt AS (
+            SELECT DISTINCT ON (task_id) *
+            FROM task_log
+            WHERE task_id = ANY($1)
+            ORDER BY task_id, attempt DESC
+        ),
+        failed AS (
+            SELECT l.* FROM last_attempt l
+            WHERE l.status = 'failed'
+                AND NOT EXISTS (SELECT 1 FROM task t WHERE t.task_id = l.task_id)
+        )
+        INSERT INTO task(
+            task_id,
+            queue_name,
+            status,
+            parent_task_id,
+            warehouse_id,
+            scheduled_for,
+            task_data,
+            entity_id,
+            entity_type,
+            entity_name,
+            project_id,
+            attempt,
+            requeued_at_attempt,
+            created_at
+        )
+        SELECT
+            task_id,
+            queue_name,
+            'scheduled',
+            parent_task_id,
+            warehouse_id,
+            now(),
+            task_data,
+            entity_id,
+            entity_type,
+            entity_name,
+            project_id,
+            attempt,
+            attempt,
+            task_created_at
+        FROM failed
+        ON CONFLICT (project_id, warehouse_id, entity_type, entity_id, queue_name) DO NOTHING
+        RETURNING task_id
+        "#,
+        &task_ids.iter().map(|s| **s).collect_vec(),
+    )
+    .fetch_all(&mut *transaction)
+    .await
+    .map_err(|e| {
+        tracing::error!(?e, "Failed to requeue tasks");
+        e.into_error_model("Failed to requeue tasks.")
+    })?;
+
+    if requeued_task_ids.len() != task_ids.len() {
+        let not_requeued = task_ids
+            .iter()
+            .filter(|id| !requeued_task_ids.contains(&***id))
+            .join(", ");
+        return Err(ErrorModel::conflict(
+            format!(
+                "Cannot requeue tasks {not_requeued}. Only tasks that failed permanently can be requeued, and only if no other task is active for the same entity and queue."
+            ),
+            "TaskNotRequeueable",
+            None,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
 pub(crate) async fn check_and_heartbeat_task(
     transaction: &mut PgConnection,
     id: impl AsRef<TaskAttemptId>,
@@ -3378,6 +3597,304 @@ mod test {
             .unwrap();
     }
 
+    #[sqlx::test]
+    async fn test_reschedule_failed_task_is_picked_up_at_new_time(pool: PgPool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let (warehouse_id, project_id) = setup_warehouse(pool.clone()).await;
+        let tq_name = generate_tq_name();
+        let entity_id = WarehouseTaskEntityId::Table {
+            table_id: Uuid::now_v7().into(),
+        };
+        let task_id = queue_task(
+            &mut conn,
+            &tq_name,
+            None,
+            project_id.clone(),
+            None,
+            None,
+            TaskEntity::EntityInWarehouse {
+                warehouse_id,
+                entity_id,
+                entity_name: vec![format!("entity-{}", entity_id.as_uuid())],
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        // Fail the first attempt, the task is immediately available for a retry
+        let task = pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+            .await
+            .unwrap()
+            .unwrap();
+        record_failure(&task, 5, "failed", &mut pool.acquire().await.unwrap())
+            .await
+            .unwrap();
+
+        let run_at = Utc::now() + chrono::Duration::seconds(2);
+        reschedule_unfinished_tasks(&mut conn, &[task_id], run_at)
+            .await
+            .unwrap();
+
+        let details = get_task_details(
+            task_id,
+            TaskDetailsScope::Warehouse {
+                project_id,
+                warehouse_id,
+            },
+            10,
+            &pool,
+        )
+        .await
+        .unwrap()
+        .expect("Task should exist");
+        assert!(matches!(details.task.status, TaskStatus::Scheduled));
+        // Postgres stores timestamps with microsecond precision
+        assert!(
+            (details.task.scheduled_for() - run_at)
+                .num_milliseconds()
+                .abs()
+                <= 1
+        );
+        assert!(
+            pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+                .await
+                .unwrap()
+                .is_none(),
+            "Task should not be pickable before the new time"
+        );
+
+        tokio::time::sleep((run_at - Utc::now()).to_std().unwrap_or_default()).await;
+        let task = pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+            .await
+            .unwrap()
+            .expect("Task should be pickable at the new time");
+        assert_eq!(task.task_id(), task_id);
+        assert_eq!(task.attempt(), 2);
+    }
+
+    async fn queue_table_task(
+        conn: &mut PgConnection,
+        tq_name: &TaskQueueName,
+        warehouse_id: WarehouseId,
+        project_id: ArcProjectId,
+    ) -> TaskId {
+        let entity_id = WarehouseTaskEntityId::Table {
+            table_id: Uuid::now_v7().into(),
+        };
+        queue_task(
+            conn,
+            tq_name,
+            None,
+            project_id,
+            None,
+            None,
+            TaskEntity::EntityInWarehouse {
+                warehouse_id,
+                entity_id,
+                entity_name: vec![format!("entity-{}", entity_id.as_uuid())],
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap()
+    }
+
+    async fn pick_and_fail(pool: &PgPool, tq_name: &TaskQueueName, max_retries: i32) -> Task {
+        let task = pick_task(pool, tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+            .await
+            .unwrap()
+            .expect("Task should be pickable");
+        record_failure(
+            &task,
+            max_retries,
+            &format!("attempt {} failed", task.attempt()),
+            &mut pool.acquire().await.unwrap(),
+        )
+        .await
+        .unwrap();
+        task
+    }
+
+    #[sqlx::test]
+    async fn test_task_failing_past_max_retries_can_be_requeued(pool: PgPool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let (warehouse_id, project_id) = setup_warehouse(pool.clone()).await;
+        let tq_name = generate_tq_name();
+        let task_id = queue_table_task(&mut conn, &tq_name, warehouse_id, project_id.clone()).await;
+        let scope = TaskDetailsScope::Warehouse {
+            project_id,
+            warehouse_id,
+        };
+
+        pick_and_fail(&pool, &tq_name, 2).await;
+        pick_and_fail(&pool, &tq_name, 2).await;
+
+        // Retries are exhausted, the task is dead
+        assert!(
+            pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let details = get_task_details(task_id, scope.clone(), 10, &pool)
+            .await
+            .unwrap()
+            .expect("Task should exist");
+        assert!(matches!(details.task.status, TaskStatus::Failed));
+        assert_eq!(details.message.as_deref(), Some("attempt 2 failed"));
+
+        requeue_failed_tasks(&mut conn, &[task_id]).await.unwrap();
+
+        let details = get_task_details(task_id, scope.clone(), 10, &pool)
+            .await
+            .unwrap()
+            .expect("Task should exist");
+        assert!(matches!(details.task.status, TaskStatus::Scheduled));
+        assert_eq!(details.attempts.len(), 2);
+
+        // The requeued task gets its full retry budget back
+        let task = pick_and_fail(&pool, &tq_name, 2).await;
+        assert_eq!(task.task_id(), task_id);
+        assert_eq!(task.attempt(), 3);
+        let task = pick_and_fail(&pool, &tq_name, 2).await;
+        assert_eq!(task.attempt(), 4);
+        assert!(
+            pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let details = get_task_details(task_id, scope, 10, &pool)
+            .await
+            .unwrap()
+            .expect("Task should exist");
+        assert!(matches!(details.task.status, TaskStatus::Failed));
+        assert_eq!(details.message.as_deref(), Some("attempt 4 failed"));
+        assert_eq!(details.attempts.len(), 3);
+    }
+
+    #[sqlx::test]
+    async fn test_permanent_failure_skips_retries(pool: PgPool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let (warehouse_id, project_id) = setup_warehouse(pool.clone()).await;
+        let tq_name = generate_tq_name();
+        let task_id = queue_table_task(&mut conn, &tq_name, warehouse_id, project_id.clone()).await;
+
+        // A permanent failure is recorded with no retries left
+        pick_and_fail(&pool, &tq_name, 0).await;
+
+        assert!(
+            pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let details = get_task_details(
+            task_id,
+            TaskDetailsScope::Warehouse {
+                project_id,
+                warehouse_id,
+            },
+            10,
+            &pool,
+        )
+        .await
+        .unwrap()
+        .expect("Task should exist");
+        assert!(matches!(details.task.status, TaskStatus::Failed));
+        assert_eq!(details.task.attempt(), 1);
+    }
+
+    #[sqlx::test]
+    async fn test_requeue_rejects_tasks_that_did_not_fail(pool: PgPool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let (warehouse_id, project_id) = setup_warehouse(pool.clone()).await;
+        let tq_name = generate_tq_name();
+        let succeeded_task_id =
+            queue_table_task(&mut conn, &tq_name, warehouse_id, project_id.clone()).await;
+        let task = pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+            .await
+            .unwrap()
+            .unwrap();
+        record_success(&task, &mut pool.acquire().await.unwrap(), Some(""))
+            .await
+            .unwrap();
+        let scheduled_task_id =
+            queue_table_task(&mut conn, &tq_name, warehouse_id, project_id).await;
+
+        for task_id in [succeeded_task_id, scheduled_task_id] {
+            let mut transaction = pool.begin().await.unwrap();
+            let err = requeue_failed_tasks(&mut transaction, &[task_id])
+                .await
+                .unwrap_err();
+            assert_eq!(err.error.code, 409);
+            assert_eq!(err.error.r#type, "TaskNotRequeueable");
+            assert!(err.error.message.contains(&task_id.to_string()));
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_reschedule_rejects_succeeded_tasks(pool: PgPool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let (warehouse_id, project_id) = setup_warehouse(pool.clone()).await;
+        let tq_name = generate_tq_name();
+        let mut task_ids = vec![];
+        for _ in 0..2 {
+            let entity_id = WarehouseTaskEntityId::Table {
+                table_id: Uuid::now_v7().into(),
+            };
+            let task_id = queue_task(
+                &mut conn,
+                &tq_name,
+                None,
+                project_id.clone(),
+                None,
+                None,
+                TaskEntity::EntityInWarehouse {
+                    warehouse_id,
+                    entity_id,
+                    entity_name: vec![format!("entity-{}", entity_id.as_uuid())],
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+            task_ids.push(task_id);
+        }
+
+        let task = pick_task(&pool, &tq_name, &[], DEFAULT_MAX_TIME_SINCE_LAST_HEARTBEAT)
+            .await
+            .unwrap()
+            .unwrap();
+        record_success(&task, &mut pool.acquire().await.unwrap(), Some(""))
+            .await
+            .unwrap();
+        let pending_task_id = *task_ids.iter().find(|id| **id != task.task_id()).unwrap();
+
+        let run_at = Utc::now() + chrono::Duration::hours(1);
+        let err = reschedule_unfinished_tasks(&mut conn, &task_ids, run_at)
+            .await
+            .unwrap_err();
+        assert_eq!(err.error.code, 409);
+        assert_eq!(err.error.r#type, "TaskAlreadySucceeded");
+
+        // The pending task was not modified
+        let details = get_task_details(
+            pending_task_id,
+            TaskDetailsScope::Warehouse {
+                project_id,
+                warehouse_id,
+            },
+            10,
+            &pool,
+        )
+        .await
+        .unwrap()
+        .expect("Task should exist");
+        assert!(details.task.scheduled_for() < run_at);
+    }
+
     #[sqlx::test]
     async fn test_reschedule_running_task_creates_failed_attempt(pool: PgPool) {
         let mut conn = pool.acquire().await.unwrap();
@@ -4,7 +4,7 @@ use chrono::DateTime;
 use iceberg_ext::catalog::rest::{ErrorModel, IcebergErrorResponse};
 use itertools::Itertools;
 use lakekeeper::{
-    CONFIG, ProjectId,
+    CONFIG, DynAppConfig, ProjectId,
     api::management::v1::tasks::{ListTasksRequest, TaskStatus, WarehouseTaskEntityFilter},
     service::{
         TaskList,
@@ -95,11 +95,21 @@ fn categorize_task_statuses(
     (task_status_filter, task_log_status_filter)
 }
 
-#[allow(clippy::too_many_lines)]
 pub(crate) async fn list_tasks(
     filter: &TaskFilter,
     query: &ListTasksRequest,
     transaction: &mut PgConnection,
+) -> Result<TaskList, IcebergErrorResponse> {
+    list_tasks_with_config(&CONFIG, filter, query, transaction).await
+}
+
+/// Like [`list_tasks`], but sizes pages according to the given `config`.
+#[allow(clippy::too_many_lines)]
+async fn list_tasks_with_config(
+    config: &DynAppConfig,
+    filter: &TaskFilter,
+    query: &ListTasksRequest,
+    transaction: &mut PgConnection,
 ) -> Result<TaskList, IcebergErrorResponse> {
     let ListTasksRequest {
         status,
@@ -128,7 +138,7 @@ pub(crate) async fn list_tasks(
         TaskFilter::All => (None, None, true),
     };
 
-    let page_size = CONFIG.page_size_or_pagination_default(page_size);
+    let page_size = config.page_size_or_pagination_default(page_size);
     let previous_page_token = page_token.clone();
     let token = page_token.map(PaginateToken::try_from).transpose()?;
 
@@ -1095,6 +1105,61 @@ mod tests {
         }
     }
 
+    #[sqlx::test]
+    async fn test_list_tasks_clamps_page_size(pool: PgPool) {
+        let mut conn = pool.acquire().await.unwrap();
+        let (warehouse_id, project_id) = setup_warehouse(pool.clone()).await;
+        let tq_name = generate_tq_name();
+
+        for _ in 0..2 {
+            let entity_id = WarehouseTaskEntityId::Table {
+                table_id: Uuid::now_v7().into(),
+            };
+            queue_task_helper(
+                &mut conn,
+                &tq_name,
+                entity_id,
+                project_id.clone(),
+                warehouse_id,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        let filter = TaskFilter::WarehouseId {
+            warehouse_id,
+            project_id,
+        };
+        let request = ListTasksRequest {
+            page_size: Some(0),
+            ..Default::default()
+        };
+        let result = list_tasks(&filter, &request, &mut conn).await.unwrap();
+        let min_page_size = usize::try_from(CONFIG.clamp_page_size(0)).unwrap();
+        assert_eq!(result.tasks.len(), min_page_size);
+
+        let request = ListTasksRequest {
+            page_size: Some(i64::MAX),
+            page_token: result.next_page_token,
+            ..Default::default()
+        };
+        let result = list_tasks(&filter, &request, &mut conn).await.unwrap();
+        assert_eq!(result.tasks.len(), 1);
+
+        // Requests above the configured maximum are capped to it.
+        let mut config = DynAppConfig::default();
+        config.pagination_size_max = 1;
+        let request = ListTasksRequest {
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let result = list_tasks_with_config(&config, &filter, &request, &mut conn)
+            .await
+            .unwrap();
+        assert_eq!(result.tasks.len(), 1);
+    }
+
     #[sqlx::test]
     async fn test_list_tasks_pagination_mixed_active_completed(pool: PgPool) {
         let mut conn = pool.acquire().await.unwrap();
@@ -2092,6 +2157,7 @@ mod tests {
             CreateProjectRequest {
                 project_id: Some(ProjectId::new_random()),
                 project_name: "Other Project".to_string(),
+                case_sensitive_warehouse_names: false,
             },
             context,
             RequestMetadata::new_unauthenticated(),
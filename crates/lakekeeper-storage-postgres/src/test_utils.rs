@@ -71,6 +71,11 @@ pub async fn spawn_build_in_queues<T: Authorizer>(
             ctx.v1_state.authz.clone(),
             poll_interval.unwrap_or(CONFIG.task_poll_interval),
         )
+        .await
+        .register_metadata_log_compaction_queue(
+            ctx.clone(),
+            poll_interval.unwrap_or(CONFIG.task_poll_interval),
+        )
         .await;
     let task_runner = task_queues.task_queues_runner(cancellation_token).await;
 
@@ -198,6 +203,7 @@ pub async fn setup_with_registry<T: Authorizer>(
             delete_profile,
             allowed_format_versions: None,
             default_format_version: None,
+            settings: Default::default(),
             managed_by: Default::default(),
         },
         api_context.clone(),
@@ -217,6 +223,7 @@ pub async fn setup_with_registry<T: Authorizer>(
                 delete_profile,
                 allowed_format_versions: None,
                 default_format_version: None,
+                settings: Default::default(),
                 managed_by: Default::default(),
             },
             api_context.clone(),
@@ -285,6 +292,9 @@ pub async fn get_api_context_with_registry<T: Authorizer>(
             build_info: &DEFAULT_BUILD_INFO,
         },
     };
+    task_queues
+        .register_metadata_log_compaction_queue(ctx.clone(), CONFIG.task_poll_interval)
+        .await;
     (ctx, task_queues)
 }
 
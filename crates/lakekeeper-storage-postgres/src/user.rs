@@ -1,5 +1,5 @@
 use lakekeeper::{
-    CONFIG,
+    CONFIG, DynAppConfig,
     api::{
         iceberg::v1::PaginationQuery,
         management::v1::user::{
@@ -111,6 +111,24 @@ impl TryFrom<UserRow> for User {
 }
 
 pub(crate) async fn list_users<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sqlx::Postgres>>(
+    filter_user_id: Option<Vec<UserId>>,
+    filter_name: Option<String>,
+    pagination_query: PaginationQuery,
+    connection: E,
+) -> Result<ListUsersResponse> {
+    list_users_with_config(
+        &CONFIG,
+        filter_user_id,
+        filter_name,
+        pagination_query,
+        connection,
+    )
+    .await
+}
+
+/// Like [`list_users`], but sizes pages according to the given `config`.
+async fn list_users_with_config<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sqlx::Postgres>>(
+    config: &DynAppConfig,
     filter_user_id: Option<Vec<UserId>>,
     filter_name: Option<String>,
     PaginationQuery {
@@ -119,7 +137,7 @@ pub(crate) async fn list_users<'e, 'c: 'e, E: sqlx::Executor<'c, Database = sqlx
     }: PaginationQuery,
     connection: E,
 ) -> Result<ListUsersResponse> {
-    let page_size = CONFIG.page_size_or_pagination_default(page_size);
+    let page_size = config.page_size_or_pagination_default(page_size);
     let filter_name = filter_name.unwrap_or_default();
 
     let token = page_token
@@ -676,4 +694,66 @@ mod test {
         assert_eq!(users.users.len(), 0);
         assert!(users.next_page_token.is_none());
     }
+
+    #[sqlx::test]
+    async fn test_list_users_clamps_page_size(pool: sqlx::PgPool) {
+        let state = CatalogState::from_pools(pool.clone(), pool.clone());
+        for i in 0..2 {
+            create_or_update_user(
+                &UserId::new_unchecked("oidc", &format!("test_user_{i}")),
+                &format!("test user {i}"),
+                None,
+                UserLastUpdatedWith::ConfigCallCreation,
+                UserType::Application,
+                UserUpsertMode::Overwrite,
+                &state.read_write.write_pool,
+            )
+            .await
+            .unwrap();
+        }
+
+        let users = list_users(
+            None,
+            None,
+            PaginationQuery {
+                page_token: PageToken::NotSpecified,
+                page_size: Some(0),
+            },
+            &state.read_write.read_pool,
+        )
+        .await
+        .unwrap();
+        let min_page_size = usize::try_from(CONFIG.clamp_page_size(0)).unwrap();
+        assert_eq!(users.users.len(), min_page_size);
+
+        let users = list_users(
+            None,
+            None,
+            PaginationQuery {
+                page_token: users.next_page_token.into(),
+                page_size: Some(i64::MAX),
+            },
+            &state.read_write.read_pool,
+        )
+        .await
+        .unwrap();
+        assert_eq!(users.users.len(), 1);
+
+        // Requests above the configured maximum are capped to it.
+        let mut config = DynAppConfig::default();
+        config.pagination_size_max = 1;
+        let users = list_users_with_config(
+            &config,
+            None,
+            None,
+            PaginationQuery {
+                page_token: PageToken::NotSpecified,
+                page_size: Some(2),
+            },
+            &state.read_write.read_pool,
+        )
+        .await
+        .unwrap();
+        assert_eq!(users.users.len(), 1);
+    }
 }
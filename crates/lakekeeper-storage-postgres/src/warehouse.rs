@@ -1,27 +1,33 @@
-use std::{collections::HashSet, ops::Deref, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    sync::Arc,
+};
 
 use iceberg::spec::FormatVersion;
 use lakekeeper::{
     CONFIG, ProjectId, SecretId, WarehouseId,
     api::{
         ErrorModel,
-        iceberg::v1::PaginationQuery,
+        iceberg::v1::{PaginatedMapping, PaginationQuery},
         management::v1::{
             DeleteWarehouseQuery,
             warehouse::{TabularDeleteProfile, WarehouseStatistics, WarehouseStatisticsResponse},
         },
     },
     service::{
-        AllowedFormatVersions, CatalogCreateWarehouseError, CatalogCreateWarehouseRequest,
-        CatalogDeleteWarehouseError, CatalogGetWarehouseByIdError, CatalogGetWarehouseByNameError,
-        CatalogListWarehousesError, CatalogRenameWarehouseError, CatalogRoleOps,
-        DatabaseIntegrityError, EnsureWarehouseSpecMutableError, GetProjectResponse, ManagedBy,
-        ProjectIdNotFoundError, ResolvedWarehouse, SetWarehouseDeletionProfileError,
-        SetWarehouseFormatVersionPolicyError, SetWarehouseManagedByError,
-        SetWarehouseProtectedError, SetWarehouseStatusError, StorageProfileSerializationError,
-        SystemRoleSeederCap, UpdateWarehouseStorageProfileError, WarehouseAlreadyExists,
-        WarehouseFormatVersionPolicy, WarehouseHasUnfinishedTasks, WarehouseIdNotFound,
-        WarehouseNotEmpty, WarehouseProtected, WarehouseSpecLocked, WarehouseStatus,
+        AllowedFormatVersions, CatalogBackendError, CatalogCreateWarehouseError,
+        CatalogCreateWarehouseRequest, CatalogDeleteWarehouseError, CatalogGetWarehouseByIdError,
+        CatalogGetWarehouseByNameError, CatalogListWarehousesError, CatalogRenameWarehouseError,
+        CatalogRoleOps, DatabaseIntegrityError, EnsureWarehouseSpecMutableError,
+        GetProjectResponse, ListProtectedEntitiesError, ManagedBy, ProjectIdNotFoundError,
+        ProtectedEntity, ProtectedEntityType, ResolvedWarehouse, SecondaryStorage,
+        SetWarehouseDeletionProfileError, SetWarehouseFormatVersionPolicyError,
+        SetWarehouseManagedByError, SetWarehouseProtectedError, SetWarehouseSettingsError,
+        SetWarehouseStatusError, StorageProfileSerializationError, SystemRoleSeederCap,
+        UpdateWarehouseStorageProfileError, WarehouseAlreadyExists, WarehouseFormatVersionPolicy,
+        WarehouseHasUnfinishedTasks, WarehouseIdNotFound, WarehouseNotEmpty, WarehouseProtected,
+        WarehouseQuotaEntity, WarehouseSettings, WarehouseSpecLocked, WarehouseStatus,
         WarehouseVersion, registered_system_roles, storage::StorageProfile,
     },
 };
@@ -32,6 +38,7 @@ use crate::{
     PostgresBackend,
     dbutils::DBErrorHandler,
     pagination::{PaginateToken, V1PaginateToken},
+    tabular::TabularType,
 };
 
 pub(super) async fn set_warehouse_deletion_profile<
@@ -61,12 +68,16 @@ pub(super) async fn set_warehouse_deletion_profile<
                 warehouse_name,
                 storage_profile as "storage_profile: Json<StorageProfile>",
                 storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
                 status AS "status: WarehouseStatus",
                 tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                 tabular_expiration_seconds,
                 protected,
+                case_sensitive_name,
                 allowed_format_versions,
                 default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
                 managed_by as "managed_by: ManagedBy",
                 updated_at,
                 version
@@ -97,6 +108,7 @@ pub(crate) async fn create_warehouse(
         storage_secret_id,
         delete_profile: tabular_delete_profile,
         format_version_policy,
+        settings,
         managed_by,
     } = request;
     let storage_profile_ser =
@@ -126,20 +138,27 @@ pub(crate) async fn create_warehouse(
                                    tabular_delete_mode,
                                    allowed_format_versions,
                                    default_format_version,
-                                   managed_by)
-                                VALUES ($1, $2, $3, $4, 'active', $5, $6, $7, $8, $9)
+                                   settings,
+                                   managed_by,
+                                   case_sensitive_name)
+                                VALUES ($1, $2, $3, $4, 'active', $5, $6, $7, $8, $9, $10,
+                                        COALESCE((SELECT case_sensitive_warehouse_names FROM project WHERE project_id = $2), false))
                                 RETURNING
                                     project_id,
                                     warehouse_id,
                                     warehouse_name,
                                     storage_profile as "storage_profile: Json<StorageProfile>",
                                     storage_secret_id,
+                                    secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                                    secondary_storage_secret_id,
                                     status AS "status: WarehouseStatus",
                                     tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                                     tabular_expiration_seconds,
                                     protected,
+                                    case_sensitive_name,
                                     allowed_format_versions,
                                     default_format_version,
+                                    settings as "settings: Json<WarehouseSettings>",
                                     managed_by as "managed_by: ManagedBy",
                                     updated_at,
                                     version),
@@ -158,6 +177,7 @@ pub(crate) async fn create_warehouse(
         prof as _,
         &allowed_format_versions_db,
         default_format_version_db,
+        Json(settings) as _,
         managed_by as ManagedBy
     )
     .fetch_one(&mut **transaction)
@@ -165,9 +185,13 @@ pub(crate) async fn create_warehouse(
     .map_err(|e| match &e {
         sqlx::Error::Database(db_err) => match db_err.constraint() {
             // ToDo: Get constraint name from const
-            Some("unique_warehouse_name_in_project") => CatalogCreateWarehouseError::from(
-                WarehouseAlreadyExists::new(warehouse_name, project_id.clone()),
-            ),
+            Some(
+                "unique_warehouse_name_in_project"
+                | "unique_warehouse_name_in_project_case_sensitive",
+            ) => CatalogCreateWarehouseError::from(WarehouseAlreadyExists::new(
+                warehouse_name,
+                project_id.clone(),
+            )),
             Some("warehouse_project_id_fk") => {
                 ProjectIdNotFoundError::new(project_id.clone()).into()
             }
@@ -203,6 +227,52 @@ pub(crate) async fn rename_project(
     Ok(())
 }
 
+pub(crate) async fn set_project_case_sensitive_warehouse_names(
+    project_id: &ProjectId,
+    case_sensitive: bool,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> lakekeeper::service::Result<()> {
+    let row_count = sqlx::query!(
+        "UPDATE project
+            SET case_sensitive_warehouse_names = $1
+            WHERE project_id = $2",
+        case_sensitive,
+        project_id
+    )
+    .execute(&mut **transaction)
+    .await
+    .map_err(|e| e.into_error_model("Error updating project"))?
+    .rows_affected();
+
+    if row_count == 0 {
+        return Err(ErrorModel::not_found("Project not found", "ProjectNotFound", None).into());
+    }
+
+    sqlx::query!(
+        "UPDATE warehouse
+            SET case_sensitive_name = $1
+            WHERE project_id = $2",
+        case_sensitive,
+        project_id
+    )
+    .execute(&mut **transaction)
+    .await
+    .map_err(|e| match &e {
+        sqlx::Error::Database(db_err)
+            if db_err.constraint() == Some("unique_warehouse_name_in_project") =>
+        {
+            ErrorModel::conflict(
+                "Cannot make warehouse names case-insensitive: the project contains warehouses whose names differ only in case",
+                "WarehouseNameCaseConflict",
+                Some(Box::new(e)),
+            )
+        }
+        _ => e.into_error_model("Error updating warehouses of project"),
+    })?;
+
+    Ok(())
+}
+
 // `'static` on the inner Transaction lifetime is required so the call to
 // `PostgresBackend::upsert_system_roles` below matches the trait's
 // `Transaction<'_>` GAT, which for `PostgresBackend` resolves to
@@ -266,7 +336,8 @@ pub(crate) async fn get_project(
         r#"
         SELECT
             project_name,
-            project_id
+            project_id,
+            case_sensitive_warehouse_names
         FROM project
         WHERE project_id = $1
         "#,
@@ -286,6 +357,7 @@ pub(crate) async fn get_project(
         Ok(Some(GetProjectResponse {
             project_id: Arc::new(ProjectId::from_db_unchecked(project.project_id)),
             name: project.project_name,
+            case_sensitive_warehouse_names: project.case_sensitive_warehouse_names,
         }))
     } else {
         Ok(None)
@@ -329,13 +401,17 @@ struct WarehouseRecord {
     warehouse_name: String,
     storage_profile: Json<StorageProfile>,
     storage_secret_id: Option<uuid::Uuid>,
+    secondary_storage_profile: Option<Json<StorageProfile>>,
+    secondary_storage_secret_id: Option<uuid::Uuid>,
     status: WarehouseStatus,
     tabular_delete_mode: DbTabularDeleteProfile,
     tabular_expiration_seconds: Option<i64>,
     protected: bool,
+    case_sensitive_name: bool,
     managed_by: ManagedBy,
     allowed_format_versions: Vec<i16>,
     default_format_version: Option<i16>,
+    settings: Json<WarehouseSettings>,
     updated_at: Option<chrono::DateTime<chrono::Utc>>,
     version: i64,
 }
@@ -361,12 +437,20 @@ impl TryFrom<WarehouseRecord> for ResolvedWarehouse {
             project_id: Arc::new(ProjectId::from_db_unchecked(value.project_id)),
             storage_profile: value.storage_profile.deref().clone(),
             storage_secret_id: value.storage_secret_id.map(Into::into),
+            secondary_storage: value.secondary_storage_profile.map(|storage_profile| {
+                SecondaryStorage {
+                    storage_profile: storage_profile.0,
+                    storage_secret_id: value.secondary_storage_secret_id.map(Into::into),
+                }
+            }),
             status: value.status,
             tabular_delete_profile,
             protected: value.protected,
+            case_sensitive_name: value.case_sensitive_name,
             managed_by: value.managed_by,
             allowed_format_versions,
             default_format_version,
+            settings: value.settings.0,
             updated_at: value.updated_at,
             version: WarehouseVersion::from(value.version),
         })
@@ -392,12 +476,16 @@ pub(crate) async fn list_warehouses<
                 warehouse_name,
                 storage_profile as "storage_profile: Json<StorageProfile>",
                 storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
                 status AS "status: WarehouseStatus",
                 tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                 tabular_expiration_seconds,
                 protected,
+                case_sensitive_name,
                 allowed_format_versions,
                 default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
                 managed_by as "managed_by: ManagedBy",
                 updated_at,
                 version
@@ -418,6 +506,110 @@ pub(crate) async fn list_warehouses<
         .collect()
 }
 
+pub(crate) async fn list_warehouses_paginated<
+    'e,
+    'c: 'e,
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+>(
+    project_id: &ProjectId,
+    include_status: Option<Vec<WarehouseStatus>>,
+    pagination_query: PaginationQuery,
+    catalog_state: E,
+) -> Result<PaginatedMapping<WarehouseId, ResolvedWarehouse>, CatalogListWarehousesError> {
+    let include_status = include_status.unwrap_or_else(|| vec![WarehouseStatus::Active]);
+    let page_size = CONFIG.page_size_or_pagination_max(pagination_query.page_size);
+
+    let token = pagination_query
+        .page_token
+        .as_option()
+        .map(PaginateToken::try_from)
+        .transpose()?;
+
+    let (token_ts, token_id) = token
+        .as_ref()
+        .map(
+            |PaginateToken::V1(V1PaginateToken { created_at, id }): &PaginateToken<uuid::Uuid>| {
+                (created_at, id)
+            },
+        )
+        .unzip();
+
+    let rows = sqlx::query!(
+        r#"
+            SELECT
+                project_id,
+                warehouse_id,
+                warehouse_name,
+                storage_profile as "storage_profile: Json<StorageProfile>",
+                storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
+                status AS "status: WarehouseStatus",
+                tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
+                tabular_expiration_seconds,
+                protected,
+                case_sensitive_name,
+                allowed_format_versions,
+                default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
+                managed_by as "managed_by: ManagedBy",
+                created_at,
+                updated_at,
+                version
+            FROM warehouse
+            WHERE project_id = $1
+            AND status = ANY($2)
+            AND ((created_at > $3 OR $3 IS NULL) OR (created_at = $3 AND warehouse_id > $4))
+            ORDER BY created_at, warehouse_id ASC
+            LIMIT $5
+            "#,
+        project_id,
+        include_status as Vec<WarehouseStatus>,
+        token_ts,
+        token_id,
+        page_size
+    )
+    .fetch_all(catalog_state)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let mut warehouses = PaginatedMapping::with_capacity(rows.len());
+    for row in rows {
+        let created_at = row.created_at;
+        let warehouse = ResolvedWarehouse::try_from(WarehouseRecord {
+            project_id: row.project_id,
+            warehouse_id: row.warehouse_id,
+            warehouse_name: row.warehouse_name,
+            storage_profile: row.storage_profile,
+            storage_secret_id: row.storage_secret_id,
+            secondary_storage_profile: row.secondary_storage_profile,
+            secondary_storage_secret_id: row.secondary_storage_secret_id,
+            status: row.status,
+            tabular_delete_mode: row.tabular_delete_mode,
+            tabular_expiration_seconds: row.tabular_expiration_seconds,
+            protected: row.protected,
+            case_sensitive_name: row.case_sensitive_name,
+            managed_by: row.managed_by,
+            allowed_format_versions: row.allowed_format_versions,
+            default_format_version: row.default_format_version,
+            settings: row.settings,
+            updated_at: row.updated_at,
+            version: row.version,
+        })?;
+        warehouses.insert(
+            warehouse.warehouse_id,
+            warehouse,
+            PaginateToken::V1(V1PaginateToken {
+                created_at,
+                id: row.warehouse_id,
+            })
+            .to_string(),
+        );
+    }
+
+    Ok(warehouses)
+}
+
 pub(super) async fn get_warehouse_by_name(
     warehouse_name: &str,
     project_id: &ProjectId,
@@ -432,17 +624,22 @@ pub(super) async fn get_warehouse_by_name(
             warehouse_name,
             storage_profile as "storage_profile: Json<StorageProfile>",
             storage_secret_id,
+            secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+            secondary_storage_secret_id,
             status AS "status: WarehouseStatus",
             tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
             tabular_expiration_seconds,
             protected,
+            case_sensitive_name,
             allowed_format_versions,
             default_format_version,
+            settings as "settings: Json<WarehouseSettings>",
             managed_by as "managed_by: ManagedBy",
             updated_at,
             version
         FROM warehouse
         WHERE warehouse_name = $1 AND project_id = $2
+            AND (NOT case_sensitive_name OR warehouse_name COLLATE "C" = $1)
         "#,
         warehouse_name.to_string(),
         project_id
@@ -475,12 +672,16 @@ pub(crate) async fn get_warehouse_by_id<
             warehouse_name,
             storage_profile as "storage_profile: Json<StorageProfile>",
             storage_secret_id,
+            secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+            secondary_storage_secret_id,
             status AS "status: WarehouseStatus",
             tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
             tabular_expiration_seconds,
             protected,
+            case_sensitive_name,
             allowed_format_versions,
             default_format_version,
+            settings as "settings: Json<WarehouseSettings>",
             managed_by as "managed_by: ManagedBy",
             updated_at,
             version
@@ -507,7 +708,7 @@ pub(crate) async fn list_projects<'e, 'c: 'e, E: sqlx::Executor<'c, Database = s
     let return_all = project_ids.is_none();
     let projects = sqlx::query!(
         r#"
-        SELECT project_id, project_name FROM project WHERE project_id = ANY($1) or $2
+        SELECT project_id, project_name, case_sensitive_warehouse_names FROM project WHERE project_id = ANY($1) or $2
         "#,
         project_ids
             .map(|ids| ids.into_iter().map(|i| i.to_string()).collect::<Vec<_>>())
@@ -523,6 +724,7 @@ pub(crate) async fn list_projects<'e, 'c: 'e, E: sqlx::Executor<'c, Database = s
         .map(|project| GetProjectResponse {
             project_id: Arc::new(ProjectId::from_db_unchecked(project.project_id)),
             name: project.project_name,
+            case_sensitive_warehouse_names: project.case_sensitive_warehouse_names,
         })
         .collect())
 }
@@ -599,12 +801,16 @@ pub(crate) async fn rename_warehouse(
             warehouse_name,
             storage_profile as "storage_profile: Json<StorageProfile>",
             storage_secret_id,
+            secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+            secondary_storage_secret_id,
             status AS "status: WarehouseStatus",
             tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
             tabular_expiration_seconds,
             protected,
+            case_sensitive_name,
             allowed_format_versions,
             default_format_version,
+            settings as "settings: Json<WarehouseSettings>",
             managed_by as "managed_by: ManagedBy",
             updated_at,
             version
@@ -639,12 +845,16 @@ pub(crate) async fn set_warehouse_status(
                 warehouse_name,
                 storage_profile as "storage_profile: Json<StorageProfile>",
                 storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
                 status AS "status: WarehouseStatus",
                 tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                 tabular_expiration_seconds,
                 protected,
+                case_sensitive_name,
                 allowed_format_versions,
                 default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
                 managed_by as "managed_by: ManagedBy",
                 updated_at,
                 version
@@ -679,12 +889,16 @@ pub(crate) async fn set_warehouse_protection(
                 warehouse_name,
                 storage_profile as "storage_profile: Json<StorageProfile>",
                 storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
                 status AS "status: WarehouseStatus",
                 tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                 tabular_expiration_seconds,
                 protected,
+                case_sensitive_name,
                 allowed_format_versions,
                 default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
                 managed_by as "managed_by: ManagedBy",
                 updated_at,
                 version
@@ -719,12 +933,16 @@ pub(crate) async fn set_warehouse_managed_by(
                 warehouse_name,
                 storage_profile as "storage_profile: Json<StorageProfile>",
                 storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
                 status AS "status: WarehouseStatus",
                 tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                 tabular_expiration_seconds,
                 protected,
+                case_sensitive_name,
                 allowed_format_versions,
                 default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
                 managed_by as "managed_by: ManagedBy",
                 updated_at,
                 version
@@ -797,12 +1015,16 @@ pub(crate) async fn set_warehouse_format_version_policy(
                 warehouse_name,
                 storage_profile as "storage_profile: Json<StorageProfile>",
                 storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
                 status AS "status: WarehouseStatus",
                 tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                 tabular_expiration_seconds,
                 protected,
+                case_sensitive_name,
                 allowed_format_versions,
                 default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
                 managed_by as "managed_by: ManagedBy",
                 updated_at,
                 version
@@ -822,6 +1044,196 @@ pub(crate) async fn set_warehouse_format_version_policy(
     Ok(warehouse.try_into()?)
 }
 
+pub(crate) async fn set_warehouse_settings(
+    warehouse_id: WarehouseId,
+    settings: &WarehouseSettings,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<ResolvedWarehouse, SetWarehouseSettingsError> {
+    let warehouse = sqlx::query_as!(
+        WarehouseRecord,
+        r#"UPDATE warehouse
+            SET settings = $1
+            WHERE warehouse_id = $2
+            RETURNING
+                project_id,
+                warehouse_id,
+                warehouse_name,
+                storage_profile as "storage_profile: Json<StorageProfile>",
+                storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
+                status AS "status: WarehouseStatus",
+                tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
+                tabular_expiration_seconds,
+                protected,
+                case_sensitive_name,
+                allowed_format_versions,
+                default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
+                managed_by as "managed_by: ManagedBy",
+                updated_at,
+                version
+            "#,
+        Json(settings) as _,
+        *warehouse_id
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let Some(warehouse) = warehouse else {
+        return Err(WarehouseIdNotFound::new(warehouse_id).into());
+    };
+
+    Ok(warehouse.try_into()?)
+}
+
+pub(crate) async fn count_warehouse_entities_up_to(
+    warehouse_id: WarehouseId,
+    entity: WarehouseQuotaEntity,
+    limit: u32,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<u64, CatalogBackendError> {
+    // Lock the warehouse, so that concurrent creations in the warehouse wait for
+    // each other and can't exceed the quota together.
+    sqlx::query!(
+        r#"SELECT warehouse_id FROM warehouse WHERE warehouse_id = $1 FOR UPDATE"#,
+        *warehouse_id
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    // Stop counting at `limit` so that checking a quota does not scan
+    // all entities of large warehouses.
+    let limit = i64::from(limit);
+    let count = match entity {
+        WarehouseQuotaEntity::Table => {
+            sqlx::query_scalar!(
+                r#"SELECT count(*) AS "count!"
+            FROM (
+                SELECT 1 FROM tabular
+                WHERE warehouse_id = $1 AND typ = 'table' AND deleted_at IS NULL
+                LIMIT $2
+            ) t"#,
+                *warehouse_id,
+                limit
+            )
+            .fetch_one(&mut **transaction)
+            .await
+        }
+        WarehouseQuotaEntity::Namespace => {
+            sqlx::query_scalar!(
+                r#"SELECT count(*) AS "count!"
+            FROM (
+                SELECT 1 FROM namespace
+                WHERE warehouse_id = $1
+                LIMIT $2
+            ) n"#,
+                *warehouse_id,
+                limit
+            )
+            .fetch_one(&mut **transaction)
+            .await
+        }
+    }
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    Ok(count.unsigned_abs())
+}
+
+pub(crate) async fn list_protected_entities(
+    warehouse_id: WarehouseId,
+    pagination_query: PaginationQuery,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<PaginatedMapping<uuid::Uuid, ProtectedEntity>, ListProtectedEntitiesError> {
+    let page_size = CONFIG.page_size_or_pagination_max(pagination_query.page_size);
+
+    let token = pagination_query
+        .page_token
+        .as_option()
+        .map(PaginateToken::try_from)
+        .transpose()?;
+
+    let (token_ts, token_id) = token
+        .as_ref()
+        .map(
+            |PaginateToken::V1(V1PaginateToken { created_at, id }): &PaginateToken<uuid::Uuid>| {
+                (created_at, id)
+            },
+        )
+        .unzip();
+
+    // Namespaces have no tabular type. Staged tables and soft-deleted tabulars
+    // are not listed.
+    let rows = sqlx::query!(
+        r#"
+        WITH protected_entities AS (
+            SELECT n.namespace_id AS id,
+                   NULL::tabular_type AS typ,
+                   n.namespace_name AS name,
+                   n.created_at,
+                   n.updated_at
+            FROM namespace n
+            WHERE n.warehouse_id = $1 AND n.protected
+            UNION ALL
+            SELECT t.tabular_id AS id,
+                   t.typ,
+                   t.tabular_namespace_name || t.name AS name,
+                   t.created_at,
+                   t.updated_at
+            FROM tabular t
+            WHERE t.warehouse_id = $1 AND t.protected
+                AND t.deleted_at IS NULL
+                AND (t.metadata_location IS NOT NULL OR t.typ = 'generic-table')
+        )
+        SELECT id as "id!",
+               typ as "typ?: TabularType",
+               name as "name!",
+               created_at as "created_at!",
+               updated_at
+        FROM protected_entities
+        WHERE ((created_at > $2 OR $2 IS NULL) OR (created_at = $2 AND id > $3))
+        ORDER BY created_at, id ASC
+        LIMIT $4
+        "#,
+        *warehouse_id,
+        token_ts,
+        token_id,
+        page_size
+    )
+    .fetch_all(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let mut entities = PaginatedMapping::with_capacity(rows.len());
+    for row in rows {
+        let entity_type = match row.typ {
+            None => ProtectedEntityType::Namespace,
+            Some(TabularType::Table) => ProtectedEntityType::Table,
+            Some(TabularType::View) => ProtectedEntityType::View,
+            Some(TabularType::GenericTable) => ProtectedEntityType::GenericTable,
+        };
+        entities.insert(
+            row.id,
+            ProtectedEntity {
+                entity_type,
+                id: row.id,
+                name: row.name,
+                created_at: row.created_at,
+                updated_at: row.updated_at,
+            },
+            PaginateToken::V1(V1PaginateToken {
+                created_at: row.created_at,
+                id: row.id,
+            })
+            .to_string(),
+        );
+    }
+
+    Ok(entities)
+}
+
 pub(crate) async fn update_storage_profile(
     warehouse_id: WarehouseId,
     storage_profile: StorageProfile,
@@ -844,12 +1256,67 @@ pub(crate) async fn update_storage_profile(
                 warehouse_name,
                 storage_profile as "storage_profile: Json<StorageProfile>",
                 storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
+                status AS "status: WarehouseStatus",
+                tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
+                tabular_expiration_seconds,
+                protected,
+                case_sensitive_name,
+                allowed_format_versions,
+                default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
+                managed_by as "managed_by: ManagedBy",
+                updated_at,
+                version
+        "#,
+        storage_profile_ser,
+        storage_secret_id.map(|id| id.into_uuid()),
+        *warehouse_id
+    )
+    .fetch_optional(&mut **transaction)
+    .await
+    .map_err(DBErrorHandler::into_catalog_backend_error)?;
+
+    let Some(warehouse) = warehouse else {
+        return Err(WarehouseIdNotFound::new(warehouse_id).into());
+    };
+
+    Ok(warehouse.try_into()?)
+}
+
+pub(crate) async fn update_secondary_storage_profile(
+    warehouse_id: WarehouseId,
+    storage_profile: StorageProfile,
+    storage_secret_id: Option<SecretId>,
+    transaction: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+) -> Result<ResolvedWarehouse, UpdateWarehouseStorageProfileError> {
+    let storage_profile_ser =
+        serde_json::to_value(storage_profile).map_err(StorageProfileSerializationError::from)?;
+
+    let warehouse = sqlx::query_as!(
+        WarehouseRecord,
+        r#"
+            UPDATE warehouse
+            SET secondary_storage_profile = $1, secondary_storage_secret_id = $2
+            WHERE warehouse_id = $3
+            AND status = 'active'
+            RETURNING
+                project_id,
+                warehouse_id,
+                warehouse_name,
+                storage_profile as "storage_profile: Json<StorageProfile>",
+                storage_secret_id,
+                secondary_storage_profile as "secondary_storage_profile: Json<StorageProfile>",
+                secondary_storage_secret_id,
                 status AS "status: WarehouseStatus",
                 tabular_delete_mode as "tabular_delete_mode: DbTabularDeleteProfile",
                 tabular_expiration_seconds,
                 protected,
+                case_sensitive_name,
                 allowed_format_versions,
                 default_format_version,
+                settings as "settings: Json<WarehouseSettings>",
                 managed_by as "managed_by: ManagedBy",
                 updated_at,
                 version
@@ -871,7 +1338,7 @@ pub(crate) async fn update_storage_profile(
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
 #[sqlx(type_name = "tabular_delete_mode", rename_all = "kebab-case")]
-enum DbTabularDeleteProfile {
+pub(crate) enum DbTabularDeleteProfile {
     Soft,
     Hard,
 }
@@ -886,14 +1353,14 @@ impl From<TabularDeleteProfile> for DbTabularDeleteProfile {
 }
 
 /// Convert a database tabular delete profile to the API tabular delete profile
-fn db_to_api_tabular_delete_profile(
+pub(crate) fn db_to_api_tabular_delete_profile(
     mode: DbTabularDeleteProfile,
     expiration_seconds: Option<i64>,
 ) -> Result<TabularDeleteProfile, DatabaseIntegrityError> {
     match mode {
         DbTabularDeleteProfile::Soft => {
             let seconds = expiration_seconds.ok_or(DatabaseIntegrityError::new(
-                "Did not find `expiration_seconds` for warehouse with soft deletion enabled.",
+                "Did not find `expiration_seconds` for delete profile with soft deletion enabled.",
             ))?;
             Ok(TabularDeleteProfile::Soft {
                 expiration_seconds: chrono::Duration::seconds(seconds),
@@ -1021,6 +1488,74 @@ pub(crate) async fn get_warehouse_stats(
     })
 }
 
+pub(crate) async fn get_warehouse_entity_counts(
+    conn: PgPool,
+    warehouse_ids: &[WarehouseId],
+) -> lakekeeper::api::Result<HashMap<WarehouseId, WarehouseEntityCounts>> {
+    let warehouse_ids = warehouse_ids.iter().map(|id| **id).collect::<Vec<_>>();
+    let counts = sqlx::query!(
+        r#"
+        SELECT
+            w.warehouse_id,
+            (SELECT count(*) FROM namespace n
+             WHERE n.warehouse_id = w.warehouse_id) as "num_namespaces!",
+            (SELECT count(*) FROM tabular t
+             WHERE t.warehouse_id = w.warehouse_id
+                AND t.typ = 'table'
+                AND t.deleted_at IS NULL
+                AND t.metadata_location IS NOT NULL) as "num_tables!",
+            (SELECT count(*) FROM tabular t
+             WHERE t.warehouse_id = w.warehouse_id
+                AND t.typ = 'view'
+                AND t.deleted_at IS NULL) as "num_views!"
+        FROM warehouse w
+        WHERE w.warehouse_id = ANY($1)
+        "#,
+        &warehouse_ids
+    )
+    .fetch_all(&conn)
+    .await
+    .map_err(|e| {
+        tracing::error!(error=?e, "Error counting warehouse entities");
+        e.into_error_model("failed to count warehouse entities")
+    })?;
+
+    Ok(counts
+        .into_iter()
+        .map(|c| {
+            (
+                c.warehouse_id.into(),
+                WarehouseEntityCounts {
+                    num_namespaces: c.num_namespaces,
+                    num_tables: c.num_tables,
+                    num_views: c.num_views,
+                },
+            )
+        })
+        .collect())
+}
+
+pub(crate) async fn get_warehouse_change_version(
+    conn: PgPool,
+    warehouse_id: WarehouseId,
+) -> lakekeeper::api::Result<Option<i64>> {
+    let version = sqlx::query_scalar!(
+        r#"
+        SELECT version FROM warehouse_change_version
+        WHERE warehouse_id = $1
+        "#,
+        *warehouse_id
+    )
+    .fetch_optional(&conn)
+    .await
+    .map_err(|e| {
+        tracing::error!(error=?e, "Error fetching warehouse change version");
+        e.into_error_model("failed to fetch warehouse change version")
+    })?;
+
+    Ok(version)
+}
+
 #[cfg(any(test, feature = "test-utils"))]
 #[allow(unused_imports, dead_code)]
 pub mod test {
@@ -0,0 +1,76 @@
+//! Middleware that records an audit event for every mutating management API
+//! request (create/delete warehouse, set protection, bootstrap, role and user
+//! changes, ...).
+//!
+//! The event carries the actor from [`RequestMetadata`], the operation, the
+//! ids of the targeted entities (path parameters plus the project) and the
+//! outcome derived from the response status. It is dispatched to every
+//! [`EventListener`](crate::service::events::EventListener) via
+//! [`EventListener::management_operation_completed`](crate::service::events::EventListener::management_operation_completed)
+//! before the response is returned, so a record is written for failed requests
+//! as well. Mutating is determined by method, like the maintenance gate.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use axum::{
+    extract::{MatchedPath, Path, Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{
+    api::{RequestMetadata, endpoints::Endpoint, maintenance::is_mutating},
+    service::events::{EventDispatcher, ManagementOperationEvent},
+};
+
+/// Key under which the project of the request is added to the audit targets
+/// if the route itself has no `project_id` parameter.
+const PROJECT_ID_TARGET: &str = "project_id";
+
+/// Axum middleware. Apply with [`axum::middleware::from_fn_with_state`] to the
+/// management router only, below the layers that insert and authenticate
+/// [`RequestMetadata`].
+pub async fn management_audit_middleware_fn(
+    State(events): State<EventDispatcher>,
+    Path(path_params): Path<BTreeMap<String, String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !is_mutating(request.method()) {
+        return next.run(request).await;
+    }
+
+    let endpoint = request
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|path| Endpoint::from_method_and_matched_path(request.method(), path.as_str()));
+    let request_metadata = request.extensions().get::<RequestMetadata>().cloned();
+
+    let response = next.run(request).await;
+
+    let (Some(endpoint), Some(request_metadata)) = (endpoint, request_metadata) else {
+        tracing::error!(
+            ?path_params,
+            "Could not audit management operation: no endpoint or request metadata found."
+        );
+        return response;
+    };
+
+    let mut targets = path_params;
+    if let Some(project_id) = request_metadata.preferred_project_id() {
+        targets
+            .entry(PROJECT_ID_TARGET.to_string())
+            .or_insert_with(|| project_id.to_string());
+    }
+
+    events
+        .management_operation_completed(ManagementOperationEvent {
+            endpoint,
+            targets: Arc::new(targets),
+            http_status: response.status(),
+            request_metadata: Arc::new(request_metadata),
+        })
+        .await;
+
+    response
+}
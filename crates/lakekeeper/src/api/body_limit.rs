@@ -0,0 +1,204 @@
+//! Middleware that rejects request bodies larger than
+//! `LAKEKEEPER__MAX_REQUEST_BODY_SIZE` with a structured Iceberg error.
+//!
+//! The limit itself is enforced by axum's [`axum::extract::DefaultBodyLimit`],
+//! whose rejection is a plain-text `413` that clients surface as an opaque
+//! error. This middleware rejects requests with a too large `Content-Length`
+//! before they reach a handler, and replaces axum's rejection for streamed
+//! bodies without `Content-Length`, so that clients always receive an
+//! [`IcebergErrorResponse`] naming the limit.
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{
+        StatusCode,
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use iceberg_ext::catalog::rest::{ErrorModel, IcebergErrorResponse};
+
+/// Error code returned in [`ErrorModel::r#type`] when a request body exceeds
+/// the configured maximum size.
+pub const REQUEST_TOO_LARGE_ERROR_TYPE: &str = "RequestTooLarge";
+
+/// Build the standardized 413 response.
+fn request_too_large_response(limit: usize) -> Response {
+    let err: IcebergErrorResponse = ErrorModel::builder()
+        .code(StatusCode::PAYLOAD_TOO_LARGE.as_u16())
+        .r#type(REQUEST_TOO_LARGE_ERROR_TYPE.to_string())
+        .message(format!(
+            "Request body exceeds the maximum size of {limit} bytes. The limit can be raised with `LAKEKEEPER__MAX_REQUEST_BODY_SIZE`."
+        ))
+        .build()
+        .into();
+
+    (StatusCode::PAYLOAD_TOO_LARGE, axum::Json(err)).into_response()
+}
+
+/// True if the request announces a body larger than `limit`.
+fn content_length_exceeds(request: &Request<Body>, limit: usize) -> bool {
+    request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|hv| hv.to_str().ok())
+        .and_then(|hv| hv.parse::<u64>().ok())
+        .is_some_and(|length| u64::try_from(limit).is_ok_and(|limit| length > limit))
+}
+
+/// True for the plain-text rejection axum returns once a streamed body
+/// exceeds the [`axum::extract::DefaultBodyLimit`]. Handlers return JSON errors.
+fn is_length_limit_rejection(response: &Response) -> bool {
+    response.status() == StatusCode::PAYLOAD_TOO_LARGE
+        && !response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|hv| hv.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("application/json"))
+}
+
+async fn limit_request_body(limit: usize, request: Request<Body>, next: Next) -> Response {
+    if content_length_exceeds(&request, limit) {
+        tracing::debug!(
+            method = %request.method(),
+            path = request.uri().path(),
+            limit,
+            "Rejecting request: body exceeds the maximum request body size",
+        );
+        return request_too_large_response(limit);
+    }
+
+    let response = next.run(request).await;
+    if is_length_limit_rejection(&response) {
+        return request_too_large_response(limit);
+    }
+    response
+}
+
+/// Axum middleware. Apply with [`axum::middleware::from_fn`] to the same router
+/// as the [`axum::extract::DefaultBodyLimit`] layer.
+pub(crate) async fn body_limit_middleware_fn(request: Request<Body>, next: Next) -> Response {
+    limit_request_body(crate::CONFIG.max_request_body_size, request, next).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Json, Router, extract::DefaultBodyLimit, middleware, routing::post};
+    use futures::stream;
+    use http::{Method, Request, StatusCode};
+    use tower::ServiceExt as _;
+
+    use super::*;
+
+    const LIMIT: usize = 1024;
+    const COMMIT_PATH: &str = "/catalog/v1/wh/namespaces/ns/tables/tab";
+
+    /// Router mirroring the production setup with a small limit, so that the
+    /// global `CONFIG` does not need to be changed.
+    fn router() -> Router {
+        Router::new()
+            .route(
+                COMMIT_PATH,
+                post(|Json(body): Json<serde_json::Value>| async move { Json(body) }),
+            )
+            .layer(middleware::from_fn(|req, next| {
+                limit_request_body(LIMIT, req, next)
+            }))
+            .layer(DefaultBodyLimit::max(LIMIT))
+    }
+
+    /// A commit whose schema has many fields.
+    fn commit_body(fields: usize) -> String {
+        let fields = (0..fields)
+            .map(|id| {
+                serde_json::json!({
+                    "id": id, "name": format!("field_{id}"), "required": false, "type": "string"
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::json!({
+            "requirements": [],
+            "updates": [{
+                "action": "add-schema",
+                "schema": {"type": "struct", "schema-id": 1, "fields": fields}
+            }]
+        })
+        .to_string()
+    }
+
+    async fn assert_request_too_large(response: Response) {
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let parsed: IcebergErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed.error.code, StatusCode::PAYLOAD_TOO_LARGE.as_u16());
+        assert_eq!(parsed.error.r#type, REQUEST_TOO_LARGE_ERROR_TYPE);
+        assert!(
+            parsed.error.message.contains(&format!("{LIMIT} bytes")),
+            "Limit not surfaced in message: {}",
+            parsed.error.message
+        );
+    }
+
+    #[tokio::test]
+    async fn oversized_commit_with_content_length_returns_413() {
+        let body = commit_body(100);
+        assert!(body.len() > LIMIT);
+        let response = router()
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri(COMMIT_PATH)
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(CONTENT_LENGTH, body.len())
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_request_too_large(response).await;
+    }
+
+    #[tokio::test]
+    async fn oversized_streamed_commit_returns_413() {
+        let body = commit_body(100);
+        let chunks = body
+            .into_bytes()
+            .chunks(256)
+            .map(|chunk| Ok::<_, std::io::Error>(chunk.to_vec()))
+            .collect::<Vec<_>>();
+        let response = router()
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri(COMMIT_PATH)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from_stream(stream::iter(chunks)))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_request_too_large(response).await;
+    }
+
+    #[tokio::test]
+    async fn commit_within_limit_passes() {
+        let body = commit_body(1);
+        assert!(body.len() <= LIMIT);
+        let response = router()
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri(COMMIT_PATH)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
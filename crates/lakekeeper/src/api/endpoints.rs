@@ -125,13 +125,13 @@ macro_rules! generate_endpoints {
 impl CatalogV1Endpoint {
     #[must_use]
     pub fn unimplemented(self) -> bool {
-        matches!(
-            self,
-            CatalogV1Endpoint::PlanTableScan
-                | CatalogV1Endpoint::FetchPlanningResult
-                | CatalogV1Endpoint::CancelPlanning
-                | CatalogV1Endpoint::FetchScanTasks
-        )
+        match self {
+            CatalogV1Endpoint::FetchPlanningResult | CatalogV1Endpoint::CancelPlanning => true,
+            CatalogV1Endpoint::PlanTableScan | CatalogV1Endpoint::FetchScanTasks => {
+                cfg!(not(feature = "scan-planning"))
+            }
+            _ => false,
+        }
     }
 }
 
@@ -213,6 +213,7 @@ generate_endpoints! {
         ListUserTransitiveRoles(GET, "/management/v1/user/{user_id}/roles/transitive"),
         ListRoleTransitiveMemberOf(GET, "/management/v1/role/{role_id}/member-of/transitive"),
         CreateWarehouse(POST, "/management/v1/warehouse"),
+        ValidateStorage(POST, "/management/v1/storage/validate"),
         ListProjects(GET, "/management/v1/project-list"),
         CreateProject(POST, "/management/v1/project"),
         GetProject(GET, "/management/v1/project"),
@@ -224,29 +225,56 @@ generate_endpoints! {
         GetWarehouseActions(GET, "/management/v1/warehouse/{warehouse_id}/actions"),
         DeleteWarehouse(DELETE, "/management/v1/warehouse/{warehouse_id}"),
         RenameWarehouse(POST, "/management/v1/warehouse/{warehouse_id}/rename"),
+        CloneWarehouse(POST, "/management/v1/warehouse/{warehouse_id}/clone"),
         UpdateWarehouseDeleteProfile(POST, "/management/v1/warehouse/{warehouse_id}/delete-profile"),
         UpdateWarehouseFormatVersionPolicy(POST, "/management/v1/warehouse/{warehouse_id}/format-version-policy"),
+        UpdateWarehouseSettings(POST, "/management/v1/warehouse/{warehouse_id}/settings"),
         DeactivateWarehouse(POST, "/management/v1/warehouse/{warehouse_id}/deactivate"),
         ActivateWarehouse(POST, "/management/v1/warehouse/{warehouse_id}/activate"),
         UpdateStorageProfile(POST, "/management/v1/warehouse/{warehouse_id}/storage"),
+        MergeStorageProfile(POST, "/management/v1/warehouse/{warehouse_id}/storage/merge"),
+        UpdateSecondaryStorageProfile(POST, "/management/v1/warehouse/{warehouse_id}/secondary-storage"),
+        MergeSecondaryStorageProfile(POST, "/management/v1/warehouse/{warehouse_id}/secondary-storage/merge"),
         UpdateStorageCredential(POST, "/management/v1/warehouse/{warehouse_id}/storage-credential"),
         GetWarehouseStatistics(GET, "/management/v1/warehouse/{warehouse_id}/statistics"),
+        GetWarehouseCatalogConfig(GET, "/management/v1/warehouse/{warehouse_id}/catalog-config"),
+        GetWarehouseVersion(GET, "/management/v1/warehouse/{warehouse_id}/version"),
+        ListProtectedEntities(GET, "/management/v1/warehouse/{warehouse_id}/protected"),
         LoadEndpointStatistics(POST, "/management/v1/endpoint-statistics"),
+        DeleteEndpointStatistics(DELETE, "/management/v1/endpoint-statistics"),
         SearchTabular(POST, "/management/v1/warehouse/{warehouse_id}/search-tabular"),
         ListDeletedTabulars(GET, "/management/v1/warehouse/{warehouse_id}/deleted-tabulars"),
         UndropTabulars(POST, "/management/v1/warehouse/{warehouse_id}/deleted-tabulars/undrop"),
+        PurgeDeletedTabular(POST, "/management/v1/warehouse/{warehouse_id}/deleted-tabulars/{tabular_id}/purge"),
         GetTableProtection(GET, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/protection"),
         SetTableProtection(POST, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/protection"),
         GetTableActions(GET, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/actions"),
+        GetTableMetadataLocation(GET, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/metadata-location"),
+        ListTableSnapshots(GET, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/snapshots"),
+        GetTableSchemaDiff(GET, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/schema-diff"),
+        GetTableStorageUsage(GET, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/storage-usage"),
+        TouchTable(POST, "/management/v1/warehouse/{warehouse_id}/table/{table_id}/touch"),
+        LoadTablesBatch(POST, "/management/v1/warehouse/{warehouse_id}/tables/load-batch"),
+        ListViews(GET, "/management/v1/warehouse/{warehouse_id}/views"),
         GetViewProtection(GET, "/management/v1/warehouse/{warehouse_id}/view/{view_id}/protection"),
         SetViewProtection(POST, "/management/v1/warehouse/{warehouse_id}/view/{view_id}/protection"),
         GetViewActions(GET, "/management/v1/warehouse/{warehouse_id}/view/{view_id}/actions"),
+        GetViewVersions(GET, "/management/v1/warehouse/{warehouse_id}/view/{view_id}/versions"),
+        RollbackView(POST, "/management/v1/warehouse/{warehouse_id}/view/{view_id}/rollback"),
         GetGenericTableActions(GET, "/management/v1/warehouse/{warehouse_id}/generic-table/{generic_table_id}/actions"),
         GetGenericTableProtection(GET, "/management/v1/warehouse/{warehouse_id}/generic-table/{generic_table_id}/protection"),
         SetGenericTableProtection(POST, "/management/v1/warehouse/{warehouse_id}/generic-table/{generic_table_id}/protection"),
         SetNamespaceProtection(POST, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/protection"),
         GetNamespaceProtection(GET, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/protection"),
+        ProtectAllNamespaceTabulars(POST, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/protect-all"),
+        SetNamespaceDeleteProfile(POST, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/delete-profile"),
+        GetNamespaceDeleteProfile(GET, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/delete-profile"),
+        SetNamespaceTableDefaults(POST, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/table-defaults"),
+        GetNamespaceTableDefaults(GET, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/table-defaults"),
+        MoveNamespace(POST, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/move"),
         GetNamespaceActions(GET, "/management/v1/warehouse/{warehouse_id}/namespace/{namespace_id}/actions"),
+        ListNamespaceTree(GET, "/management/v1/warehouse/{warehouse_id}/namespaces/tree"),
+        CreateNamespacesBatch(POST, "/management/v1/warehouse/{warehouse_id}/namespaces/batch"),
         SetWarehouseProtection(POST, "/management/v1/warehouse/{warehouse_id}/protection"),
         SetWarehouseManagedBy(POST, "/management/v1/warehouse/{warehouse_id}/managed-by"),
         SetTaskQueueConfig(POST, "/management/v1/warehouse/{warehouse_id}/task-queue/{queue_name}/config"),
@@ -261,6 +289,7 @@ generate_endpoints! {
         GetProjectTaskDetails(GET, "/management/v1/project/task/by-id/{task_id}"),
         ControlProjectTasks(POST, "/management/v1/project/task/control"),
         BatchCheckActions(POST, "/management/v1/action/batch-check"),
+        QueryAuditLog(POST, "/management/v1/audit/query"),
         // --------- Deprecated endpoints ---------
         GetDefaultProjectDeprecated(GET, "/management/v1/default-project"),
         DeleteDefaultProjectDeprecated(DELETE, "/management/v1/default-project"),
@@ -419,6 +448,8 @@ mod test {
 
         use crate::api::endpoints::Endpoint;
         let exempt_config_paths = [
+            "management/v1/warehouse/{warehouse_id}/task-queue/metadata_log_compaction/config",
+            "management/v1/warehouse/{warehouse_id}/task-queue/metadata_log_compaction/schedule",
             "management/v1/warehouse/{warehouse_id}/task-queue/soft_deletion/config",
             "management/v1/warehouse/{warehouse_id}/task-queue/tabular_purge/config",
             "management/v1/project/task-queue/task_log_cleanup/config",
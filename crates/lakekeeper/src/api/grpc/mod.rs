@@ -0,0 +1,348 @@
+//! gRPC interface for the hot paths of the catalog: loading and committing tables.
+//!
+//! The service is mounted on the same router as the REST API, so authentication,
+//! request metadata, rate limiting and tracing apply unchanged. Requests are
+//! handled by the same [`TablesService`] implementation as their REST counterparts,
+//! so the two surfaces behave identically. The protocol is defined in
+//! `proto/lakekeeper/catalog/v1/catalog.proto`.
+pub mod proto;
+
+use std::convert::Infallible;
+
+use axum::Router;
+use http::StatusCode;
+use iceberg::{NamespaceIdent, TableIdent};
+use iceberg_ext::catalog::rest::{CommitTableRequest, IcebergErrorResponse};
+use tonic::codegen::{Body, BoxFuture, Context, Poll, Service, StdError};
+
+use crate::{
+    CONFIG,
+    api::{
+        ApiContext, RequestMetadata,
+        iceberg::{
+            types::Prefix,
+            v1::{
+                LoadTableResultOrNotModified, TableParameters,
+                avro::{from_avro, to_avro},
+                tables::{
+                    DataAccess, DataAccessMode, LoadTableFilters, LoadTableRequest, SnapshotsQuery,
+                    TablesService, normalize_tabular_name,
+                },
+            },
+        },
+    },
+    server::CatalogServer,
+    service::{CatalogStore, SecretStore, State, authz::Authorizer},
+};
+
+/// Router serving [`CatalogGrpcService`] on the gRPC method paths.
+pub(crate) fn router<
+    C: CatalogStore,
+    A: Authorizer + Clone,
+    S: SecretStore,
+    T: Clone + Send + Sync + 'static,
+>(
+    state: ApiContext<State<A, C, S>>,
+) -> Router<T> {
+    let service = CatalogGrpcService::new(state);
+    Router::new()
+        .route_service(proto::LOAD_TABLE_PATH, service.clone())
+        .route_service(proto::COMMIT_TABLE_PATH, service)
+}
+
+/// Tower service implementing `lakekeeper.catalog.v1.CatalogService`.
+///
+/// Expects [`RequestMetadata`] in the request extensions, as inserted by the
+/// request metadata and authentication middlewares of the router.
+pub struct CatalogGrpcService<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> {
+    state: ApiContext<State<A, C, S>>,
+}
+
+impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> Clone for CatalogGrpcService<C, A, S> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> std::fmt::Debug
+    for CatalogGrpcService<C, A, S>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CatalogGrpcService").finish_non_exhaustive()
+    }
+}
+
+impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> CatalogGrpcService<C, A, S> {
+    #[must_use]
+    pub fn new(state: ApiContext<State<A, C, S>>) -> Self {
+        Self { state }
+    }
+
+    /// Load a table, equivalent to the REST `loadTable` endpoint.
+    ///
+    /// # Errors
+    /// Fails with the gRPC equivalent of the status code the REST endpoint would return.
+    pub async fn load_table(
+        &self,
+        request: tonic::Request<proto::LoadTableRequest>,
+    ) -> Result<tonic::Response<proto::LoadTableResponse>, tonic::Status> {
+        let request_metadata = request_metadata(&request)?;
+        let request = request.into_inner();
+        let snapshots = match proto::SnapshotsFilter::try_from(request.snapshots) {
+            Ok(proto::SnapshotsFilter::Unspecified | proto::SnapshotsFilter::All) => {
+                SnapshotsQuery::All
+            }
+            Ok(proto::SnapshotsFilter::Refs) => SnapshotsQuery::Refs,
+            Ok(proto::SnapshotsFilter::None) => SnapshotsQuery::None,
+            Ok(proto::SnapshotsFilter::Current) => SnapshotsQuery::Current,
+            Err(_) => {
+                return Err(tonic::Status::invalid_argument(format!(
+                    "Unknown snapshots filter {}",
+                    request.snapshots
+                )));
+            }
+        };
+        let parameters = table_parameters(request.prefix, request.table)?;
+        let load_request = LoadTableRequest::builder()
+            .data_access(DataAccessMode::ServerDelegated(DataAccess {
+                vended_credentials: request.vended_credentials,
+                remote_signing: request.remote_signing,
+            }))
+            .filters(LoadTableFilters { snapshots })
+            .build();
+
+        let result = CatalogServer::<C, A, S>::load_table(
+            parameters,
+            load_request,
+            self.state.clone(),
+            request_metadata,
+        )
+        .await
+        .map_err(error_to_status)?;
+        // No ETags are sent, so the table is always returned.
+        let LoadTableResultOrNotModified::LoadTableResult(result) = result else {
+            return Err(tonic::Status::internal(
+                "Unexpected not-modified response for unconditional load",
+            ));
+        };
+
+        Ok(tonic::Response::new(proto::LoadTableResponse {
+            metadata_location: result.metadata_location,
+            metadata: to_avro(&*result.metadata).map_err(encoding_error)?,
+            config: result.config.unwrap_or_default(),
+            storage_credentials: result
+                .storage_credentials
+                .unwrap_or_default()
+                .into_iter()
+                .map(|credential| proto::StorageCredential {
+                    prefix: credential.prefix,
+                    config: credential.config,
+                })
+                .collect(),
+        }))
+    }
+
+    /// Commit updates to a table, equivalent to the REST `updateTable` endpoint.
+    ///
+    /// # Errors
+    /// Fails with the gRPC equivalent of the status code the REST endpoint would return.
+    pub async fn commit_table(
+        &self,
+        request: tonic::Request<proto::CommitTableRequest>,
+    ) -> Result<tonic::Response<proto::CommitTableResponse>, tonic::Status> {
+        // The maintenance middleware only inspects HTTP methods, and every gRPC call is a POST.
+        if CONFIG.maintenance_mode.is_read_only() {
+            return Err(tonic::Status::unavailable(
+                "Lakekeeper is in read-only maintenance mode. Commits are temporarily rejected.",
+            ));
+        }
+        let request_metadata = request_metadata(&request)?;
+        let request = request.into_inner();
+        let commit = from_avro::<CommitTableRequest>(&request.commit).map_err(|e| {
+            tonic::Status::invalid_argument(format!("Failed to decode commit: {e}"))
+        })?;
+        let parameters = table_parameters(request.prefix, request.table)?;
+
+        let result = CatalogServer::<C, A, S>::commit_table(
+            parameters,
+            commit,
+            vec![],
+            self.state.clone(),
+            request_metadata,
+        )
+        .await
+        .map_err(error_to_status)?;
+
+        Ok(tonic::Response::new(proto::CommitTableResponse {
+            metadata_location: result.metadata_location,
+            metadata: to_avro(&*result.metadata).map_err(encoding_error)?,
+        }))
+    }
+}
+
+impl<C, A, S, B> Service<http::Request<B>> for CatalogGrpcService<C, A, S>
+where
+    C: CatalogStore,
+    A: Authorizer + Clone,
+    S: SecretStore,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let service = self.clone();
+        match req.uri().path() {
+            proto::LOAD_TABLE_PATH => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::default());
+                Ok(grpc.unary(LoadTableSvc(service), req).await)
+            }),
+            proto::COMMIT_TABLE_PATH => Box::pin(async move {
+                let mut grpc = tonic::server::Grpc::new(tonic_prost::ProstCodec::default());
+                Ok(grpc.unary(CommitTableSvc(service), req).await)
+            }),
+            path => {
+                let status = tonic::Status::unimplemented(format!("Unknown method {path}"));
+                Box::pin(async move { Ok(status.into_http()) })
+            }
+        }
+    }
+}
+
+struct LoadTableSvc<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+    CatalogGrpcService<C, A, S>,
+);
+
+impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
+    tonic::server::UnaryService<proto::LoadTableRequest> for LoadTableSvc<C, A, S>
+{
+    type Response = proto::LoadTableResponse;
+    type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+    fn call(&mut self, request: tonic::Request<proto::LoadTableRequest>) -> Self::Future {
+        let service = self.0.clone();
+        Box::pin(async move { service.load_table(request).await })
+    }
+}
+
+struct CommitTableSvc<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+    CatalogGrpcService<C, A, S>,
+);
+
+impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
+    tonic::server::UnaryService<proto::CommitTableRequest> for CommitTableSvc<C, A, S>
+{
+    type Response = proto::CommitTableResponse;
+    type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+    fn call(&mut self, request: tonic::Request<proto::CommitTableRequest>) -> Self::Future {
+        let service = self.0.clone();
+        Box::pin(async move { service.commit_table(request).await })
+    }
+}
+
+fn request_metadata<T>(request: &tonic::Request<T>) -> Result<RequestMetadata, tonic::Status> {
+    request
+        .extensions()
+        .get::<RequestMetadata>()
+        .cloned()
+        .ok_or_else(|| tonic::Status::internal("Request metadata missing for gRPC request"))
+}
+
+fn table_parameters(
+    prefix: String,
+    table: Option<proto::TableIdentifier>,
+) -> Result<TableParameters, tonic::Status> {
+    let table = table.ok_or_else(|| tonic::Status::invalid_argument("Table is required"))?;
+    let namespace = NamespaceIdent::from_vec(table.namespace)
+        .map_err(|e| tonic::Status::invalid_argument(format!("Invalid namespace: {e}")))?;
+    Ok(TableParameters {
+        prefix: Some(Prefix(prefix)),
+        table: TableIdent::new(namespace, normalize_tabular_name(&table.name)),
+    })
+}
+
+fn encoding_error(e: impl std::fmt::Display) -> tonic::Status {
+    tonic::Status::internal(format!("Failed to encode table metadata: {e}"))
+}
+
+/// Map the error of a REST handler to the gRPC status code with the closest meaning.
+/// The Iceberg error type is preserved in the `x-iceberg-error-type` metadata.
+fn error_to_status(error: IcebergErrorResponse) -> tonic::Status {
+    let error = error.error;
+    let code = match StatusCode::from_u16(error.code) {
+        Ok(StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY) => {
+            tonic::Code::InvalidArgument
+        }
+        Ok(StatusCode::UNAUTHORIZED) => tonic::Code::Unauthenticated,
+        Ok(StatusCode::FORBIDDEN) => tonic::Code::PermissionDenied,
+        Ok(StatusCode::NOT_FOUND) => tonic::Code::NotFound,
+        Ok(StatusCode::CONFLICT) => tonic::Code::FailedPrecondition,
+        Ok(StatusCode::TOO_MANY_REQUESTS) => tonic::Code::ResourceExhausted,
+        Ok(StatusCode::NOT_IMPLEMENTED) => tonic::Code::Unimplemented,
+        Ok(StatusCode::SERVICE_UNAVAILABLE) => tonic::Code::Unavailable,
+        Ok(StatusCode::GATEWAY_TIMEOUT | StatusCode::REQUEST_TIMEOUT) => {
+            tonic::Code::DeadlineExceeded
+        }
+        _ => tonic::Code::Internal,
+    };
+    let mut status = tonic::Status::new(code, error.message);
+    if let Ok(error_type) = error.r#type.parse() {
+        status
+            .metadata_mut()
+            .insert(ICEBERG_ERROR_TYPE_METADATA, error_type);
+    }
+    status
+}
+
+const ICEBERG_ERROR_TYPE_METADATA: &str = "x-iceberg-error-type";
+
+#[cfg(test)]
+mod tests {
+    use iceberg_ext::catalog::rest::ErrorModel;
+
+    use super::*;
+
+    #[test]
+    fn test_error_to_status_maps_code_and_type() {
+        let error: IcebergErrorResponse = ErrorModel::builder()
+            .code(StatusCode::NOT_FOUND.as_u16())
+            .r#type("NoSuchTableException")
+            .message("Table does not exist")
+            .build()
+            .into();
+
+        let status = error_to_status(error);
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), "Table does not exist");
+        assert_eq!(
+            status.metadata().get(ICEBERG_ERROR_TYPE_METADATA).unwrap(),
+            "NoSuchTableException"
+        );
+    }
+
+    #[test]
+    fn test_table_parameters_require_table() {
+        let err = table_parameters("warehouse".to_string(), None).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        let parameters = table_parameters(
+            "warehouse".to_string(),
+            Some(proto::TableIdentifier {
+                namespace: vec!["ns".to_string()],
+                name: "tbl".to_string(),
+            }),
+        )
+        .unwrap();
+        assert_eq!(parameters.prefix, Some(Prefix("warehouse".to_string())));
+        assert_eq!(parameters.table.name, "tbl");
+    }
+}
@@ -0,0 +1,83 @@
+//! Messages of the `lakekeeper.catalog.v1` package.
+//!
+//! Kept in sync by hand with `proto/lakekeeper/catalog/v1/catalog.proto`, so that
+//! building Lakekeeper does not require `protoc`.
+use std::collections::HashMap;
+
+pub const LOAD_TABLE_PATH: &str = "/lakekeeper.catalog.v1.CatalogService/LoadTable";
+pub const COMMIT_TABLE_PATH: &str = "/lakekeeper.catalog.v1.CatalogService/CommitTable";
+
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct TableIdentifier {
+    #[prost(string, repeated, tag = "1")]
+    pub namespace: Vec<String>,
+    #[prost(string, tag = "2")]
+    pub name: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum SnapshotsFilter {
+    Unspecified = 0,
+    All = 1,
+    Refs = 2,
+    None = 3,
+    Current = 4,
+}
+
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct StorageCredential {
+    #[prost(string, tag = "1")]
+    pub prefix: String,
+    #[prost(map = "string, string", tag = "2")]
+    pub config: HashMap<String, String>,
+}
+
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct LoadTableRequest {
+    /// Catalog prefix, i.e. the ID of the warehouse.
+    #[prost(string, tag = "1")]
+    pub prefix: String,
+    #[prost(message, optional, tag = "2")]
+    pub table: Option<TableIdentifier>,
+    #[prost(enumeration = "SnapshotsFilter", tag = "3")]
+    pub snapshots: i32,
+    #[prost(bool, tag = "4")]
+    pub vended_credentials: bool,
+    #[prost(bool, tag = "5")]
+    pub remote_signing: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct LoadTableResponse {
+    #[prost(string, optional, tag = "1")]
+    pub metadata_location: Option<String>,
+    /// Avro encoded Iceberg `TableMetadata`.
+    #[prost(bytes = "vec", tag = "2")]
+    pub metadata: Vec<u8>,
+    #[prost(map = "string, string", tag = "3")]
+    pub config: HashMap<String, String>,
+    #[prost(message, repeated, tag = "4")]
+    pub storage_credentials: Vec<StorageCredential>,
+}
+
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct CommitTableRequest {
+    /// Catalog prefix, i.e. the ID of the warehouse.
+    #[prost(string, tag = "1")]
+    pub prefix: String,
+    #[prost(message, optional, tag = "2")]
+    pub table: Option<TableIdentifier>,
+    /// Avro encoded Iceberg REST `CommitTableRequest`.
+    #[prost(bytes = "vec", tag = "3")]
+    pub commit: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Eq, prost::Message)]
+pub struct CommitTableResponse {
+    #[prost(string, tag = "1")]
+    pub metadata_location: String,
+    /// Avro encoded Iceberg `TableMetadata`.
+    #[prost(bytes = "vec", tag = "2")]
+    pub metadata: Vec<u8>,
+}
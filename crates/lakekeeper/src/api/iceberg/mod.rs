@@ -12,11 +12,13 @@ pub mod v1 {
 
     use crate::api::ThreadSafe;
 
+    pub mod avro;
     pub mod config;
     pub mod metrics;
     pub mod namespace;
     pub mod oauth;
     pub mod s3_signer;
+    pub mod scan_planning;
     pub mod tables;
     pub mod views;
 
@@ -49,11 +51,13 @@ pub mod v1 {
             + tables::TablesService<S>
             + metrics::Service<S>
             + s3_signer::Service<S>
+            + scan_planning::Service<S>
             + views::ViewService<S>,
         #[cfg(not(feature = "s3-signer"))] T: config::Service<S>
             + namespace::NamespaceService<S>
             + tables::TablesService<S>
             + metrics::Service<S>
+            + scan_planning::Service<S>
             + views::ViewService<S>,
         S: ThreadSafe,
     >() -> Router<ApiContext<S>> {
@@ -67,6 +71,9 @@ pub mod v1 {
         #[cfg(feature = "s3-signer")]
         let router = router.merge(s3_signer::router::<T, S>());
 
+        #[cfg(feature = "scan-planning")]
+        let router = router.merge(scan_planning::router::<T, S>());
+
         router
     }
 
@@ -315,8 +322,10 @@ mod test {
         let paths = s["paths"].as_object().unwrap();
         let unsupported = &[
             "/v1/oauth/tokens",
+            #[cfg(not(feature = "scan-planning"))]
             "/v1/{prefix}/namespaces/{namespace}/tables/{table}/plan",
             "/v1/{prefix}/namespaces/{namespace}/tables/{table}/plan/{plan-id}",
+            #[cfg(not(feature = "scan-planning"))]
             "/v1/{prefix}/namespaces/{namespace}/tables/{table}/tasks",
         ];
         // Check that openapi endpoints are in the supported endpoints
@@ -0,0 +1,173 @@
+//! Avro encoding of catalog responses for clients that prefer a compact,
+//! binary representation over JSON.
+//!
+//! Responses are encoded as Avro object container files with a single datum.
+//! The writer schema is a generic, recursive JSON value schema, so every type
+//! with a JSON representation (such as `TableMetadata`) can be encoded without
+//! maintaining a dedicated Avro schema that has to follow the Iceberg spec.
+//! The container header embeds the schema, so any Avro reader can decode the
+//! payload.
+use std::sync::LazyLock;
+
+use apache_avro::{Reader, Schema, Writer, types::Value as AvroValue};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value as JsonValue;
+
+pub const AVRO_CONTENT_TYPE: &str = "application/avro";
+
+const JSON_VALUE_FIELD: &str = "value";
+
+static JSON_VALUE_SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
+    Schema::parse_str(
+        r#"{
+            "type": "record",
+            "name": "JsonValue",
+            "namespace": "io.lakekeeper",
+            "fields": [{
+                "name": "value",
+                "type": [
+                    "null",
+                    "boolean",
+                    "long",
+                    "double",
+                    "string",
+                    {"type": "array", "items": "JsonValue"},
+                    {"type": "map", "values": "JsonValue"}
+                ]
+            }]
+        }"#,
+    )
+    .expect("JSON value Avro schema must be valid")
+});
+
+#[derive(Debug, thiserror::Error)]
+pub enum AvroEncodingError {
+    #[error("Avro encoding failed: {0}")]
+    Avro(#[from] apache_avro::Error),
+    #[error("JSON conversion failed: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Avro container must contain exactly one datum")]
+    UnexpectedDatumCount,
+    #[error("Unexpected Avro value: {0}")]
+    UnexpectedValue(String),
+}
+
+/// Encode `value` as an Avro object container holding its JSON representation.
+///
+/// # Errors
+/// Fails if `value` cannot be serialized to JSON or written as Avro.
+pub fn to_avro<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, AvroEncodingError> {
+    let json = serde_json::to_value(value)?;
+    let mut writer = Writer::new(&JSON_VALUE_SCHEMA, Vec::new());
+    writer.append(json_to_avro(json))?;
+    Ok(writer.into_inner()?)
+}
+
+/// Decode an Avro object container produced by [`to_avro`].
+///
+/// # Errors
+/// Fails if `bytes` is not a valid container with a single JSON value datum,
+/// or if the value does not deserialize into `T`.
+pub fn from_avro<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, AvroEncodingError> {
+    let mut reader = Reader::with_schema(&JSON_VALUE_SCHEMA, bytes)?;
+    let datum = reader
+        .next()
+        .ok_or(AvroEncodingError::UnexpectedDatumCount)??;
+    if reader.next().is_some() {
+        return Err(AvroEncodingError::UnexpectedDatumCount);
+    }
+    Ok(serde_json::from_value(avro_to_json(datum)?)?)
+}
+
+fn json_to_avro(value: JsonValue) -> AvroValue {
+    let (index, value) = match value {
+        JsonValue::Null => (0, AvroValue::Null),
+        JsonValue::Bool(b) => (1, AvroValue::Boolean(b)),
+        JsonValue::Number(n) => match n.as_i64() {
+            Some(i) => (2, AvroValue::Long(i)),
+            // Only floats and integers above `i64::MAX` end up here.
+            None => (3, AvroValue::Double(n.as_f64().unwrap_or_default())),
+        },
+        JsonValue::String(s) => (4, AvroValue::String(s)),
+        JsonValue::Array(items) => (
+            5,
+            AvroValue::Array(items.into_iter().map(json_to_avro).collect()),
+        ),
+        JsonValue::Object(fields) => (
+            6,
+            AvroValue::Map(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key, json_to_avro(value)))
+                    .collect(),
+            ),
+        ),
+    };
+    AvroValue::Record(vec![(
+        JSON_VALUE_FIELD.to_string(),
+        AvroValue::Union(index, Box::new(value)),
+    )])
+}
+
+fn avro_to_json(value: AvroValue) -> Result<JsonValue, AvroEncodingError> {
+    let AvroValue::Record(mut fields) = value else {
+        return Err(AvroEncodingError::UnexpectedValue(format!("{value:?}")));
+    };
+    let value = match fields.pop() {
+        Some((name, AvroValue::Union(_, value)))
+            if name == JSON_VALUE_FIELD && fields.is_empty() =>
+        {
+            *value
+        }
+        other => return Err(AvroEncodingError::UnexpectedValue(format!("{other:?}"))),
+    };
+
+    Ok(match value {
+        AvroValue::Null => JsonValue::Null,
+        AvroValue::Boolean(b) => JsonValue::Bool(b),
+        AvroValue::Long(i) => JsonValue::from(i),
+        AvroValue::Double(d) => serde_json::Number::from_f64(d)
+            .map(JsonValue::Number)
+            .ok_or_else(|| AvroEncodingError::UnexpectedValue(d.to_string()))?,
+        AvroValue::String(s) => JsonValue::String(s),
+        AvroValue::Array(items) => JsonValue::Array(
+            items
+                .into_iter()
+                .map(avro_to_json)
+                .collect::<Result<_, _>>()?,
+        ),
+        AvroValue::Map(fields) => JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| Ok((key, avro_to_json(value)?)))
+                .collect::<Result<_, AvroEncodingError>>()?,
+        ),
+        other => return Err(AvroEncodingError::UnexpectedValue(format!("{other:?}"))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_value_round_trip() {
+        let value = serde_json::json!({
+            "null": null,
+            "bool": true,
+            "long": -42,
+            "double": 1.5,
+            "string": "value",
+            "array": [1, "two", [3.0]],
+            "object": {"nested": {"empty": {}}}
+        });
+
+        let decoded: JsonValue = from_avro(&to_avro(&value).unwrap()).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_invalid_avro_is_rejected() {
+        assert!(from_avro::<JsonValue>(b"not avro").is_err());
+    }
+}
@@ -152,7 +152,7 @@ impl Serialize for NamespaceIdentUrl {
 }
 
 #[allow(clippy::ref_option)]
-fn serialize_namespace_ident_as_url<S>(
+pub(crate) fn serialize_namespace_ident_as_url<S>(
     value: &Option<NamespaceIdent>,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
@@ -165,7 +165,7 @@ where
     }
 }
 
-fn deserialize_namespace_ident_from_url<'de, D>(
+pub(crate) fn deserialize_namespace_ident_from_url<'de, D>(
     deserializer: D,
 ) -> Result<Option<NamespaceIdent>, D::Error>
 where
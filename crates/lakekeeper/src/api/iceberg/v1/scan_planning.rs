@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    routing::post,
+};
+use http::HeaderMap;
+use iceberg::TableIdent;
+use iceberg_ext::catalog::rest::{
+    FetchScanTasksRequest, FetchScanTasksResult, PlanTableScanRequest, PlanTableScanResult,
+};
+
+use super::{
+    namespace::NamespaceIdentUrl,
+    tables::{DataAccessMode, TableParameters, normalize_tabular_name, parse_data_access},
+};
+use crate::{
+    api::{ApiContext, Result, iceberg::types::Prefix},
+    request_metadata::RequestMetadata,
+};
+
+#[async_trait]
+pub trait Service<S: crate::api::ThreadSafe>
+where
+    Self: Send + Sync + 'static,
+{
+    /// Plan a scan of a table. Planning is synchronous: the result is always
+    /// `completed` and contains plan tasks that are resolved to file scan
+    /// tasks via [`Self::fetch_scan_tasks`].
+    async fn plan_table_scan(
+        parameters: TableParameters,
+        request: PlanTableScanRequest,
+        data_access: DataAccessMode,
+        state: ApiContext<S>,
+        request_metadata: RequestMetadata,
+    ) -> Result<PlanTableScanResult>;
+
+    /// Fetch the file scan tasks of a plan task returned by [`Self::plan_table_scan`].
+    async fn fetch_scan_tasks(
+        parameters: TableParameters,
+        request: FetchScanTasksRequest,
+        state: ApiContext<S>,
+        request_metadata: RequestMetadata,
+    ) -> Result<FetchScanTasksResult>;
+}
+
+pub fn router<I: Service<S>, S: crate::api::ThreadSafe>() -> Router<ApiContext<S>> {
+    Router::new()
+        // /{prefix}/namespaces/{namespace}/tables/{table}/plan
+        .route(
+            "/{prefix}/namespaces/{namespace}/tables/{table}/plan",
+            post(
+                |Path((prefix, namespace, table)): Path<(Prefix, NamespaceIdentUrl, String)>,
+                 State(api_context): State<ApiContext<S>>,
+                 headers: HeaderMap,
+                 Extension(metadata): Extension<RequestMetadata>,
+                 Json(request): Json<PlanTableScanRequest>| {
+                    I::plan_table_scan(
+                        TableParameters {
+                            prefix: Some(prefix),
+                            table: TableIdent {
+                                namespace: namespace.into(),
+                                name: normalize_tabular_name(&table),
+                            },
+                        },
+                        request,
+                        parse_data_access(&headers),
+                        api_context,
+                        metadata,
+                    )
+                },
+            ),
+        )
+        // /{prefix}/namespaces/{namespace}/tables/{table}/tasks
+        .route(
+            "/{prefix}/namespaces/{namespace}/tables/{table}/tasks",
+            post(
+                |Path((prefix, namespace, table)): Path<(Prefix, NamespaceIdentUrl, String)>,
+                 State(api_context): State<ApiContext<S>>,
+                 Extension(metadata): Extension<RequestMetadata>,
+                 Json(request): Json<FetchScanTasksRequest>| {
+                    I::fetch_scan_tasks(
+                        TableParameters {
+                            prefix: Some(prefix),
+                            table: TableIdent {
+                                namespace: namespace.into(),
+                                name: normalize_tabular_name(&table),
+                            },
+                        },
+                        request,
+                        api_context,
+                        metadata,
+                    )
+                },
+            ),
+        )
+}
@@ -11,12 +11,15 @@ use iceberg::TableIdent;
 use iceberg_ext::catalog::rest::{ETag, LoadCredentialsResponse};
 use serde::Deserialize;
 
-use super::{PageToken, PaginationQuery};
+use super::{
+    PageToken, PaginationQuery,
+    avro::{AVRO_CONTENT_TYPE, to_avro},
+};
 use crate::{
     api::{
         ApiContext, CommitTableRequest, CommitTableResponse, CommitTransactionRequest,
-        CreateTableRequest, ListTablesResponse, LoadTableResult, RegisterTableRequest,
-        RenameTableRequest, Result,
+        CreateTableRequest, ErrorModel, IcebergErrorResponse, ListTablesResponse, LoadTableResult,
+        RegisterTableRequest, RenameTableRequest, Result,
         iceberg::{
             types::{DropParams, Prefix, ReferencedByQuery},
             v1::{
@@ -30,7 +33,7 @@ use crate::{
 
 /// Normalize table name by replacing `+` with space.
 /// This is needed because `+` in URLs is decoded to space by some clients.
-pub(super) fn normalize_tabular_name(table: &str) -> String {
+pub(crate) fn normalize_tabular_name(table: &str) -> String {
     table.replace('+', " ")
 }
 
@@ -76,6 +79,13 @@ pub enum SnapshotsQuery {
     All,
     /// load all snapshots referenced by branches or tags
     Refs,
+    /// Load only the current snapshot, i.e. the head of the `main` branch.
+    /// Other refs and snapshot log entries are omitted, as they would reference
+    /// snapshots missing from the metadata.
+    Current,
+    /// Load no snapshots. Refs and the snapshot log are omitted as well, as
+    /// they would reference snapshots missing from the metadata.
+    None,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, Default)]
@@ -117,6 +127,8 @@ impl<'de> serde::Deserialize<'de> for LoadTableQuery {
                         snapshots = match decoded.as_ref() {
                             "all" => Some(SnapshotsQuery::All),
                             "refs" => Some(SnapshotsQuery::Refs),
+                            "current" => Some(SnapshotsQuery::Current),
+                            "none" => Some(SnapshotsQuery::None),
                             _ => {
                                 return Err(E::custom(format!(
                                     "Invalid snapshots value: {decoded}"
@@ -154,6 +166,10 @@ pub struct LoadTableRequest {
     pub etags: Vec<ETag>,
     #[builder(default)]
     pub referenced_by: Option<Vec<ReferencingView>>,
+    /// Read the table metadata from the primary instead of a read replica so that
+    /// a load directly following a commit never observes replication lag.
+    #[builder(default)]
+    pub read_after_write: bool,
 }
 
 impl From<ListTablesQuery> for PaginationQuery {
@@ -198,6 +214,56 @@ impl IntoResponse for LoadTableResultOrNotModified {
     }
 }
 
+/// Encoding of a loaded table, negotiated via the `Accept` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoadTableResponseFormat {
+    #[default]
+    Json,
+    /// Avro object container, see [`super::avro`].
+    Avro,
+}
+
+/// A [`LoadTableResultOrNotModified`] together with the format it is sent in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegotiatedLoadTableResult {
+    pub result: LoadTableResultOrNotModified,
+    pub format: LoadTableResponseFormat,
+}
+
+impl IntoResponse for NegotiatedLoadTableResult {
+    fn into_response(self) -> axum::response::Response {
+        let load_table_result = match (self.format, self.result) {
+            (LoadTableResponseFormat::Avro, LoadTableResultOrNotModified::LoadTableResult(r)) => r,
+            (_, result) => return result.into_response(),
+        };
+
+        let body = match to_avro(&load_table_result) {
+            Ok(body) => body,
+            Err(e) => {
+                return IcebergErrorResponse::from(ErrorModel::internal(
+                    "Failed to encode table metadata as Avro",
+                    "AvroEncodingError",
+                    Some(Box::new(e)),
+                ))
+                .into_response();
+            }
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(AVRO_CONTENT_TYPE),
+        );
+        if let Some(header_value) = load_table_result
+            .etag()
+            .and_then(|etag| etag.as_str().parse::<HeaderValue>().ok())
+        {
+            headers.insert(header::ETAG, header_value);
+        }
+        (headers, body).into_response()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct LoadTableCredentialsQuery {
@@ -288,9 +354,13 @@ where
     ) -> Result<LoadCredentialsResponse>;
 
     /// Commit updates to a table
+    ///
+    /// If `if_match` is not empty, the commit is only applied if the current
+    /// metadata of the table matches one of the given values.
     async fn commit_table(
         parameters: TableParameters,
         request: CommitTableRequest,
+        if_match: Vec<ETag>,
         state: ApiContext<S>,
         request_metadata: RequestMetadata,
     ) -> Result<CommitTableResponse>;
@@ -419,7 +489,8 @@ pub fn router<I: TablesService<S>, S: crate::api::ThreadSafe>() -> Router<ApiCon
                                 .ok()
                         })
                         .unwrap_or_default();
-                    I::load_table(
+                    let format = parse_load_table_response_format(&headers);
+                    let result = I::load_table(
                         TableParameters {
                             prefix: Some(prefix),
                             table: TableIdent {
@@ -436,16 +507,23 @@ pub fn router<I: TablesService<S>, S: crate::api::ThreadSafe>() -> Router<ApiCon
                             referenced_by: load_table_query
                                 .referenced_by
                                 .map(ReferencedByQuery::into_inner),
+                            read_after_write: parse_read_after_write(&headers),
                         },
                         api_context,
                         metadata,
-                    )
+                    );
+                    async move {
+                        result
+                            .await
+                            .map(|result| NegotiatedLoadTableResult { result, format })
+                    }
                 },
             )
             // Commit updates to a table
             .post(
                 |Path((prefix, namespace, table)): Path<(Prefix, NamespaceIdentUrl, String)>,
                  State(api_context): State<ApiContext<S>>,
+                 headers: HeaderMap,
                  Extension(metadata): Extension<RequestMetadata>,
                  Json(request): Json<CommitTableRequest>| {
                     I::commit_table(
@@ -457,6 +535,7 @@ pub fn router<I: TablesService<S>, S: crate::api::ThreadSafe>() -> Router<ApiCon
                             },
                         },
                         request,
+                        parse_if_match(&headers),
                         api_context,
                         metadata,
                     )
@@ -603,10 +682,15 @@ pub struct TableParameters {
 pub const DATA_ACCESS_HEADER: &str = "x-iceberg-access-delegation";
 pub const IF_NONE_MATCH_HEADER: &str = "if-none-match";
 pub const ETAG_HEADER: &str = "etag";
+/// Lakekeeper specific header to request a load from the primary database,
+/// see [`LoadTableRequest::read_after_write`].
+pub const READ_AFTER_WRITE_HEADER: &str = "x-lakekeeper-read-after-write";
 
 pub const DATA_ACCESS_HEADER_NAME: HeaderName = HeaderName::from_static(DATA_ACCESS_HEADER);
 pub const ETAG_HEADER_NAME: HeaderName = HeaderName::from_static(ETAG_HEADER);
 pub const IF_NONE_MATCH_HEADER_NAME: HeaderName = HeaderName::from_static(IF_NONE_MATCH_HEADER);
+pub const READ_AFTER_WRITE_HEADER_NAME: HeaderName =
+    HeaderName::from_static(READ_AFTER_WRITE_HEADER);
 
 #[derive(Debug, Hash, Clone, PartialEq, Eq, Copy)]
 // Modeled as a string to enable multiple values to be specified.
@@ -678,6 +762,15 @@ pub fn parse_if_none_match(headers: &HeaderMap) -> Vec<ETag> {
         .collect()
 }
 
+pub fn parse_if_match(headers: &HeaderMap) -> Vec<ETag> {
+    headers
+        .get_all(header::IF_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(parse_etags)
+        .collect()
+}
+
 pub(crate) fn parse_data_access(headers: &HeaderMap) -> DataAccessMode {
     let header = headers
         .get_all(DATA_ACCESS_HEADER)
@@ -697,6 +790,30 @@ pub(crate) fn parse_data_access(headers: &HeaderMap) -> DataAccessMode {
     .into()
 }
 
+pub(crate) fn parse_read_after_write(headers: &HeaderMap) -> bool {
+    headers
+        .get(READ_AFTER_WRITE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"))
+}
+
+/// Avro is only sent to clients that explicitly list `application/avro` in
+/// their `Accept` header. Everyone else receives JSON.
+pub(crate) fn parse_load_table_response_format(headers: &HeaderMap) -> LoadTableResponseFormat {
+    let accepts_avro = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|media_range| media_range.split(';').next())
+        .any(|media_type| media_type.trim().eq_ignore_ascii_case(AVRO_CONTENT_TYPE));
+    if accepts_avro {
+        LoadTableResponseFormat::Avro
+    } else {
+        LoadTableResponseFormat::Json
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::{collections::HashMap, error::Error, str::FromStr, sync::Arc};
@@ -720,6 +837,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_parse_read_after_write() {
+        let mut headers = http::header::HeaderMap::new();
+        assert!(!super::parse_read_after_write(&headers));
+        headers.insert(
+            super::READ_AFTER_WRITE_HEADER_NAME,
+            http::header::HeaderValue::from_static("True"),
+        );
+        assert!(super::parse_read_after_write(&headers));
+        headers.insert(
+            super::READ_AFTER_WRITE_HEADER_NAME,
+            http::header::HeaderValue::from_static("false"),
+        );
+        assert!(!super::parse_read_after_write(&headers));
+    }
+
     #[test]
     fn test_parse_data_access_capitalization() {
         let mut headers = http::header::HeaderMap::new();
@@ -849,6 +982,8 @@ mod test {
                 let snapshots_str = match request.filters.snapshots {
                     super::SnapshotsQuery::All => "all",
                     super::SnapshotsQuery::Refs => "refs",
+                    super::SnapshotsQuery::Current => "current",
+                    super::SnapshotsQuery::None => "none",
                 };
 
                 Err(ErrorModel::builder()
@@ -873,6 +1008,7 @@ mod test {
             async fn commit_table(
                 _parameters: super::TableParameters,
                 _request: crate::api::CommitTableRequest,
+                _if_match: Vec<ETag>,
                 _state: ApiContext<ThisState>,
                 _request_metadata: RequestMetadata,
             ) -> crate::api::Result<crate::api::CommitTableResponse> {
@@ -966,7 +1102,7 @@ mod test {
         req.extensions_mut()
             .insert(RequestMetadata::new_unauthenticated());
 
-        let r = router.oneshot(req).await.unwrap();
+        let r = router.clone().oneshot(req).await.unwrap();
         assert_eq!(r.status().as_u16(), 406);
         let bytes = http_body_util::BodyExt::collect(r)
             .await
@@ -975,6 +1111,42 @@ mod test {
         let response_str = String::from_utf8(bytes.to_vec()).unwrap();
         let error = serde_json::from_str::<IcebergErrorResponse>(&response_str).unwrap();
         assert_eq!(error.error.message, "snapshots=refs");
+
+        // Test 4: snapshots=none
+        let mut req = http::Request::builder()
+            .uri("/test/namespaces/test-namespace/tables/test-table?snapshots=none")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        req.extensions_mut()
+            .insert(RequestMetadata::new_unauthenticated());
+
+        let r = router.clone().oneshot(req).await.unwrap();
+        assert_eq!(r.status().as_u16(), 406);
+        let bytes = http_body_util::BodyExt::collect(r)
+            .await
+            .unwrap()
+            .to_bytes();
+        let response_str = String::from_utf8(bytes.to_vec()).unwrap();
+        let error = serde_json::from_str::<IcebergErrorResponse>(&response_str).unwrap();
+        assert_eq!(error.error.message, "snapshots=none");
+
+        // Test 5: snapshots=current
+        let mut req = http::Request::builder()
+            .uri("/test/namespaces/test-namespace/tables/test-table?snapshots=current")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        req.extensions_mut()
+            .insert(RequestMetadata::new_unauthenticated());
+
+        let r = router.oneshot(req).await.unwrap();
+        assert_eq!(r.status().as_u16(), 406);
+        let bytes = http_body_util::BodyExt::collect(r)
+            .await
+            .unwrap()
+            .to_bytes();
+        let response_str = String::from_utf8(bytes.to_vec()).unwrap();
+        let error = serde_json::from_str::<IcebergErrorResponse>(&response_str).unwrap();
+        assert_eq!(error.error.message, "snapshots=current");
     }
 
     #[tokio::test]
@@ -1057,6 +1229,7 @@ mod test {
             async fn commit_table(
                 _parameters: super::TableParameters,
                 _request: crate::api::CommitTableRequest,
+                _if_match: Vec<ETag>,
                 _state: ApiContext<ThisState>,
                 _request_metadata: RequestMetadata,
             ) -> crate::api::Result<crate::api::CommitTableResponse> {
@@ -1221,6 +1394,63 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_parse_load_table_response_format() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            parse_load_table_response_format(&headers),
+            LoadTableResponseFormat::Json
+        );
+
+        headers.insert(header::ACCEPT, "application/json".parse().unwrap());
+        assert_eq!(
+            parse_load_table_response_format(&headers),
+            LoadTableResponseFormat::Json
+        );
+
+        headers.insert(
+            header::ACCEPT,
+            "application/json;q=0.5, Application/Avro;q=1"
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            parse_load_table_response_format(&headers),
+            LoadTableResponseFormat::Avro
+        );
+    }
+
+    #[tokio::test]
+    async fn test_avro_load_table_response_decodes_to_table_metadata() {
+        let load_table_result = LoadTableResult {
+            metadata_location: Some("s3://bucket/table/metadata.json".to_string()),
+            metadata: create_table_metadata_mock(),
+            config: Some(HashMap::from([("key".to_string(), "value".to_string())])),
+            storage_credentials: None,
+            credentials_revalidate_after_ms: None,
+        };
+
+        let response = NegotiatedLoadTableResult {
+            result: LoadTableResultOrNotModified::LoadTableResult(load_table_result.clone()),
+            format: LoadTableResponseFormat::Avro,
+        }
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            AVRO_CONTENT_TYPE
+        );
+        assert_eq!(
+            response.headers().get(header::ETAG).unwrap(),
+            load_table_result.etag().unwrap().as_str()
+        );
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let decoded: LoadTableResult = crate::api::iceberg::v1::avro::from_avro(&body).unwrap();
+        assert_eq!(decoded.metadata, load_table_result.metadata);
+        assert_eq!(decoded, load_table_result);
+    }
+
     async fn extract_body_from_response(response: Response) -> Result<String, Box<dyn Error>> {
         let bytes = response.into_body().collect().await?.to_bytes();
         Ok(String::from_utf8(bytes.to_vec())?)
@@ -1428,6 +1658,24 @@ mod test {
         assert!(etags.is_empty());
     }
 
+    #[test]
+    fn test_parse_if_match_only_reads_if_match_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_NONE_MATCH, "\"abc\"".parse().unwrap());
+        assert!(parse_if_match(&headers).is_empty());
+
+        headers.insert(
+            header::IF_MATCH,
+            "\"s3://bucket/table/metadata/00001-a.metadata.json\""
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            parse_if_match(&headers),
+            vec!["s3://bucket/table/metadata/00001-a.metadata.json".into()]
+        );
+    }
+
     #[test]
     fn test_parse_if_none_match_is_quoted_twice() {
         let etag = "\"\"abcdefghi123456789\"\"".to_string();
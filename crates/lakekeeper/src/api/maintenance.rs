@@ -37,7 +37,7 @@ pub const MAINTENANCE_ERROR_TYPE: &str = "MaintenanceModeError";
 /// maintenance, which is acceptable for a planned upgrade window. The one
 /// `GET`-with-write side-effect we know of (`GET /v1/config` user auto-register)
 /// is suppressed in the handler itself, not here.
-fn is_mutating(method: &Method) -> bool {
+pub(crate) fn is_mutating(method: &Method) -> bool {
     !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
 }
 
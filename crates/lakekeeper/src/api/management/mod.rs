@@ -1,6 +1,7 @@
 #![allow(deprecated)]
 
 pub mod v1 {
+    pub mod audit;
     pub mod check;
     pub mod generic_table;
     pub mod lakekeeper_actions;
@@ -22,6 +23,7 @@ pub mod v1 {
 
     use std::{marker::PhantomData, sync::Arc};
 
+    use audit::{QueryAuditLogRequest, QueryAuditLogResponse, Service as _};
     use axum::{
         Extension, Json, Router,
         extract::{Path, Query, State as AxumState},
@@ -43,37 +45,54 @@ pub mod v1 {
         get_allowed_server_actions, get_allowed_table_actions, get_allowed_user_actions,
         get_allowed_view_actions, get_allowed_warehouse_actions,
     };
-    use namespace::NamespaceManagementService as _;
+    use namespace::{
+        CreateNamespacesBatchRequest, CreateNamespacesBatchResponse, ListNamespaceTreeQuery,
+        ListNamespaceTreeResponse, MoveNamespaceRequest, NamespaceDeleteProfileResponse,
+        NamespaceManagementService as _, NamespaceTableDefaults, ProtectAllTabularsRequest,
+        ProtectAllTabularsResponse, SetNamespaceDeleteProfileRequest,
+    };
     #[cfg(feature = "open-api")]
     pub use openapi::api_doc;
     use project::{
-        CreateProjectRequest, CreateProjectResponse, GetProjectResponse, ListProjectsResponse,
-        RenameProjectRequest, Service as _,
+        CreateProjectRequest, CreateProjectResponse, DeleteEndpointStatisticsQuery,
+        GetProjectResponse, ListProjectsResponse, RenameProjectRequest, Service as _,
     };
     use role::{
         CreateRoleRequest, ListRolesQuery, Role, SearchRoleRequest, Service as _, UpdateRoleRequest,
     };
     use role_membership::{
         AddRoleMembersRequest, AddRoleMembersResponse, ListMembersQuery, ListRoleMembersResponse,
-        ListRoleMembershipsResponse, ListRolesPageQuery, RoleMemberType, Service as _,
+        ListRoleMembershipsResponse, ListRolesPageQuery, ListUserRolesResponse, RoleMemberType,
+        Service as _,
     };
     use serde::{Deserialize, Serialize};
     use server::{BootstrapRequest, ServerInfo, Service as _};
-    use table::TableManagementService as _;
+    use table::{
+        ListTableSnapshotsQuery, ListTableSnapshotsResponse, LoadTablesBatchRequest,
+        LoadTablesBatchResponse, TableManagementService as _, TableMetadataLocationResponse,
+        TableSchemaDiffQuery, TableSchemaDiffResponse, TableStorageUsageResponse,
+        TouchTableResponse,
+    };
     use tabular::TabularManagementService as _;
     use typed_builder::TypedBuilder;
     use user::{
         CreateUserRequest, SearchUserRequest, SearchUserResponse, Service as _, UpdateUserRequest,
         User, WhoamiResponse,
     };
-    use view::ViewManagementService as _;
+    use view::{
+        GetViewVersionsResponse, ListViewsQuery, ListViewsResponse, RollbackViewRequest,
+        ViewManagementService as _,
+    };
     use warehouse::{
-        CreateWarehouseRequest, CreateWarehouseResponse, GetWarehouseResponse,
-        ListDeletedTabularsQuery, ListWarehousesRequest, ListWarehousesResponse,
-        RenameWarehouseRequest, Service as _, SetWarehouseManagedByRequest,
-        UpdateWarehouseCredentialRequest, UpdateWarehouseDeleteProfileRequest,
-        UpdateWarehouseFormatVersionPolicyRequest, UpdateWarehouseStorageRequest,
-        WarehouseStatisticsResponse,
+        CloneWarehouseRequest, CreateWarehouseRequest, CreateWarehouseResponse,
+        GetWarehouseCatalogConfigResponse, GetWarehouseResponse, GetWarehouseResponseOrNotModified,
+        GetWarehouseVersionResponse, ListDeletedTabularsQuery, ListProtectedEntitiesQuery,
+        ListProtectedEntitiesResponse, ListWarehousesRequest, ListWarehousesResponse,
+        MergeWarehouseStorageRequest, PurgeDeletedTabularQuery, RenameWarehouseRequest,
+        Service as _, SetWarehouseManagedByRequest, UpdateWarehouseCredentialRequest,
+        UpdateWarehouseDeleteProfileRequest, UpdateWarehouseFormatVersionPolicyRequest,
+        UpdateWarehouseSettingsRequest, UpdateWarehouseStorageRequest, ValidateStorageRequest,
+        ValidateStorageResponse, WarehouseStatisticsResponse,
     };
 
     /// Macro to create an Arc wrapper for a response type that implements `IntoResponse`.
@@ -118,7 +137,13 @@ pub mod v1 {
         api::{
             ApiContext, Result,
             endpoints::ManagementV1Endpoint,
-            iceberg::{types::PageToken, v1::PaginationQuery},
+            iceberg::{
+                types::PageToken,
+                v1::{
+                    PaginationQuery,
+                    tables::{parse_data_access, parse_if_none_match},
+                },
+            },
             management::v1::{
                 check::{CatalogActionsBatchCheckRequest, CatalogActionsBatchCheckResponse},
                 lakekeeper_actions::GetAccessQuery,
@@ -784,6 +809,9 @@ pub mod v1 {
     /// List User Roles
     ///
     /// Lists the roles a user is directly assigned to, keyset-paginated.
+    /// If users list their own roles, `group-roles` additionally contains the
+    /// roles mapped from the groups in their token (`LAKEKEEPER__OPENID_GROUP_ROLE_MAPPING_FILE`).
+    /// Can be called by the user themselves or by admins.
     #[cfg_attr(feature = "open-api", utoipa::path(
         get,
         tag = "user",
@@ -794,7 +822,7 @@ pub mod v1 {
             ("x-project-id" = Option<String>, Header, description = PROJECT_ID_HEADER_DESCRIPTION)
         ),
         responses(
-            (status = 200, description = "Roles the user is assigned to", body = ListRoleMembershipsResponse),
+            (status = 200, description = "Roles the user is assigned to", body = ListUserRolesResponse),
             (status = "4XX", body = IcebergErrorResponse),
         )
     ))]
@@ -803,7 +831,7 @@ pub mod v1 {
         AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
         Extension(metadata): Extension<RequestMetadata>,
         Query(query): Query<ListRolesPageQuery>,
-    ) -> Result<ListRoleMembershipsResponse> {
+    ) -> Result<ListUserRolesResponse> {
         ApiServer::<C, A, S>::list_user_roles(api_context, metadata, user_id, query).await
     }
 
@@ -924,6 +952,33 @@ pub mod v1 {
         ApiServer::<C, A, S>::create_warehouse(request, api_context, metadata).await
     }
 
+    /// Validate Storage Profile
+    ///
+    /// Runs the storage validation of warehouse creation against the provided storage
+    /// profile and credential without creating a warehouse. This checks the profile,
+    /// the credential, read / write access to the storage and that the storage does not
+    /// overlap with a warehouse of the project. Nothing is persisted.
+    ///
+    /// Returns `200` both if the validation passed and if a check failed. The first
+    /// failed check is reported in `failure`.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::ValidateStorage.path(),
+        request_body = ValidateStorageRequest,
+        responses(
+            (status = 200, description = "Result of the storage validation", body = ValidateStorageResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn validate_storage<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<ValidateStorageRequest>,
+    ) -> Result<ValidateStorageResponse> {
+        ApiServer::<C, A, S>::validate_storage(request, api_context, metadata).await
+    }
+
     /// List Projects
     ///
     /// Lists all projects that the requesting user has access to.
@@ -1133,6 +1188,8 @@ pub mod v1 {
     /// Returns all warehouses in the project that the current user has access to.
     /// By default, deactivated warehouses are not included in the results.
     /// Set the `include_deactivated` query parameter to `true` to include them.
+    /// Set `includeCounts` to `true` to also return the number of namespaces,
+    /// tables and views of each warehouse.
     #[cfg_attr(feature = "open-api", utoipa::path(
         get,
         tag = "warehouse",
@@ -1151,25 +1208,54 @@ pub mod v1 {
         ApiServer::<C, A, S>::list_warehouses(request, api_context, metadata).await
     }
 
+    #[derive(Debug, Default, Deserialize, TypedBuilder)]
+    #[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+    pub struct GetWarehouseQuery {
+        /// Include the number of namespaces, tables and views of the warehouse
+        /// in the response. Defaults to `false`.
+        #[serde(
+            deserialize_with = "crate::api::iceberg::types::deserialize_bool",
+            default
+        )]
+        #[builder(setter(strip_bool))]
+        pub include_counts: bool,
+    }
+
     /// Get Warehouse
     ///
     /// Retrieves detailed information about a specific warehouse.
+    /// Set `include_counts` to `true` to also return the number of namespaces,
+    /// tables and views in the warehouse.
+    ///
+    /// The response carries an `ETag` header. If it matches a value of the
+    /// `If-None-Match` request header, `304 Not Modified` is returned instead.
     #[cfg_attr(feature = "open-api", utoipa::path(
         get,
         tag = "warehouse",
         path = ManagementV1Endpoint::GetWarehouse.path(),
-        params(("warehouse_id" = Uuid,)),
+        params(
+            ("warehouse_id" = Uuid,),
+            GetWarehouseQuery,
+            ("If-None-Match" = Option<String>, Header, description = "ETag of a previously retrieved representation"),
+        ),
         responses(
             (status = 200, description = "Warehouse details", body = GetWarehouseResponse),
+            (status = 304, description = "Warehouse has not changed since the representation identified by `If-None-Match`"),
             (status = "4XX", body = IcebergErrorResponse),
         )
     ))]
     async fn get_warehouse<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
         Path(warehouse_id): Path<uuid::Uuid>,
+        Query(query): Query<GetWarehouseQuery>,
+        headers: http::HeaderMap,
         AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
         Extension(metadata): Extension<RequestMetadata>,
-    ) -> Result<GetWarehouseResponse> {
-        ApiServer::<C, A, S>::get_warehouse(warehouse_id.into(), api_context, metadata).await
+    ) -> Result<GetWarehouseResponseOrNotModified> {
+        ApiServer::<C, A, S>::get_warehouse(warehouse_id.into(), query, api_context, metadata)
+            .await
+            .map(|response| {
+                GetWarehouseResponseOrNotModified::new(response, &parse_if_none_match(&headers))
+            })
     }
 
     #[derive(Debug, Deserialize, TypedBuilder)]
@@ -1300,6 +1386,41 @@ pub mod v1 {
         .await
     }
 
+    /// Update Warehouse Settings
+    ///
+    /// Replaces the per-warehouse policies for tables, views and data access:
+    /// partition transforms, file formats, metadata and table locations, schema
+    /// evolution, snapshot and table property limits, quotas, partition statistics
+    /// validation and credential mode. Omitted settings are reset to their default,
+    /// which imposes no restriction except for `require-location-in-warehouse`.
+    /// Existing tables are not rewritten; tightened policies apply to subsequent
+    /// creates and commits.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::UpdateWarehouseSettings.path(),
+        params(("warehouse_id" = Uuid,)),
+        request_body = UpdateWarehouseSettingsRequest,
+        responses(
+            (status = 200, body = GetWarehouseResponse, description = "Warehouse settings updated successfully"),
+        (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn update_warehouse_settings<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<UpdateWarehouseSettingsRequest>,
+    ) -> Result<GetWarehouseResponse> {
+        ApiServer::<C, A, S>::update_warehouse_settings(
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
     /// Deactivate Warehouse
     ///
     /// Temporarily disables access to a warehouse without deleting its data.
@@ -1371,6 +1492,36 @@ pub mod v1 {
         ))
     }
 
+    /// Clone Warehouse
+    ///
+    /// Creates a new warehouse with the configuration of an existing one.
+    /// The storage profile, secondary storage profile, delete profile, format
+    /// version policy, settings and task queue configs are copied.
+    /// Namespaces, tables and views are not copied.
+    ///
+    /// The storage credentials of the source warehouse are never copied and must
+    /// be provided in the request if the storage requires them.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::CloneWarehouse.path(),
+        params(("warehouse_id" = Uuid,)),
+        request_body = CloneWarehouseRequest,
+        responses(
+            (status = 201, description = "Warehouse cloned successfully", body = CreateWarehouseResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn clone_warehouse<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<CloneWarehouseRequest>,
+    ) -> Result<CreateWarehouseResponse> {
+        ApiServer::<C, A, S>::clone_warehouse(warehouse_id.into(), request, api_context, metadata)
+            .await
+    }
+
     /// Update Storage Profile
     ///
     /// Updates both the storage profile and credentials of a warehouse.
@@ -1395,6 +1546,104 @@ pub mod v1 {
             .await
     }
 
+    /// Update Secondary Storage Profile
+    ///
+    /// Sets the secondary storage profile and credential of a warehouse. Namespaces and
+    /// tables are stored on the secondary storage if their `storage_profile` property is
+    /// set to `secondary` on creation. Once set, the location of the secondary storage
+    /// profile cannot be changed.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::UpdateSecondaryStorageProfile.path(),
+        params(("warehouse_id" = Uuid,)),
+        request_body = UpdateWarehouseStorageRequest,
+        responses(
+            (status = 200, body=GetWarehouseResponse, description = "Secondary storage profile updated successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn update_secondary_storage_profile<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<UpdateWarehouseStorageRequest>,
+    ) -> Result<GetWarehouseResponse> {
+        ApiServer::<C, A, S>::update_secondary_storage(
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
+    /// Merge Storage Profile
+    ///
+    /// Updates only the specified fields of the storage profile of a warehouse.
+    /// Fields that are not specified keep their current value. If no credential is provided,
+    /// the existing credential is re-used. The merged profile is validated like a new profile.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::MergeStorageProfile.path(),
+        params(("warehouse_id" = Uuid,)),
+        request_body = MergeWarehouseStorageRequest,
+        responses(
+            (status = 200, body=GetWarehouseResponse, description = "Storage profile updated successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn merge_storage_profile<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<MergeWarehouseStorageRequest>,
+    ) -> Result<GetWarehouseResponse> {
+        ApiServer::<C, A, S>::merge_storage(warehouse_id.into(), request, api_context, metadata)
+            .await
+    }
+
+    /// Merge Secondary Storage Profile
+    ///
+    /// Updates only the specified fields of the secondary storage profile of a warehouse.
+    /// Fields that are not specified keep their current value. If no credential is provided,
+    /// the existing secondary credential is re-used. The warehouse must already have a
+    /// secondary storage profile.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::MergeSecondaryStorageProfile.path(),
+        params(("warehouse_id" = Uuid,)),
+        request_body = MergeWarehouseStorageRequest,
+        responses(
+            (status = 200, body=GetWarehouseResponse, description = "Secondary storage profile updated successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn merge_secondary_storage_profile<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<MergeWarehouseStorageRequest>,
+    ) -> Result<GetWarehouseResponse> {
+        ApiServer::<C, A, S>::merge_secondary_storage(
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
     /// Update Storage Credential
     ///
     /// Updates only the storage credential of a warehouse without modifying the storage profile.
@@ -1499,6 +1748,93 @@ pub mod v1 {
         .map(Json)
     }
 
+    /// Get Warehouse Catalog Config
+    ///
+    /// Returns the config clients of the warehouse receive from the Iceberg REST catalog's `GET /config` endpoint,
+    /// together with the prefix and credential mode of the warehouse. Useful to debug client connection issues.
+    /// Requires the same permission as `GET /config`.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::GetWarehouseCatalogConfig.path(),
+        params(("warehouse_id" = Uuid,)),
+        responses(
+            (status = 200, description = "Catalog config of the warehouse", body = GetWarehouseCatalogConfigResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn get_warehouse_catalog_config<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+    ) -> Result<GetWarehouseCatalogConfigResponse> {
+        ApiServer::<C, A, S>::get_warehouse_catalog_config(
+            warehouse_id.into(),
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
+    /// Get Warehouse Version
+    ///
+    /// Returns the change version of a warehouse. The version increments whenever the warehouse
+    /// or any namespace, table, view or generic table within it is created, updated or dropped,
+    /// so clients can poll this single number to detect changes before listing the warehouse again.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::GetWarehouseVersion.path(),
+        params(("warehouse_id" = Uuid,)),
+        responses(
+            (status = 200, description = "Change version of the warehouse", body = GetWarehouseVersionResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn get_warehouse_version<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+    ) -> Result<GetWarehouseVersionResponse> {
+        ApiServer::<C, A, S>::get_warehouse_version(warehouse_id.into(), api_context, metadata)
+            .await
+    }
+
+    /// List Protected Entities
+    ///
+    /// Lists the namespaces, tables, views and generic tables of a warehouse that are protected from deletion,
+    /// together with the protection status of the warehouse itself.
+    /// Use this before deleting a warehouse with `force` to review what would be removed despite its protection.
+    /// Soft-deleted tabulars are not included.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::ListProtectedEntities.path(),
+        params(("warehouse_id" = Uuid,), ListProtectedEntitiesQuery),
+        responses(
+            (status = 200, description = "Protected entities of the warehouse", body = ListProtectedEntitiesResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn list_protected_entities<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        Query(query): Query<ListProtectedEntitiesQuery>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+    ) -> Result<ListProtectedEntitiesResponse> {
+        ApiServer::<C, A, S>::list_protected_entities(
+            warehouse_id.into(),
+            query,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
     /// Get API Statistics
     ///
     /// Retrieves detailed endpoint call statistics for your project, allowing you to monitor API usage patterns,
@@ -1557,6 +1893,61 @@ pub mod v1 {
             .map(Json)
     }
 
+    /// Delete Endpoint Statistics
+    ///
+    /// Deletes the collected endpoint statistics of the project, either entirely
+    /// or only the time buckets within `[from, until)`. Requires project admin
+    /// privileges.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        delete,
+        tag = "project",
+        path = ManagementV1Endpoint::DeleteEndpointStatistics.path(),
+        params(
+            DeleteEndpointStatisticsQuery,
+            ("x-project-id" = Option<String>, Header, description = PROJECT_ID_HEADER_DESCRIPTION),
+        ),
+        responses(
+            (status = 204, description = "Endpoint statistics deleted"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn delete_endpoint_statistics<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Query(query): Query<DeleteEndpointStatisticsQuery>,
+    ) -> Result<StatusCode> {
+        ApiServer::<C, A, S>::delete_endpoint_statistics(api_context, query, metadata)
+            .await
+            .map(|()| StatusCode::NO_CONTENT)
+    }
+
+    /// Query the audit log
+    ///
+    /// Returns the audit trail of mutating management operations in the project,
+    /// newest entries first. Entries are only recorded if
+    /// `LAKEKEEPER__AUDIT__POSTGRES__ENABLED` is set.
+    /// Filters are combined with AND.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "project",
+        path = ManagementV1Endpoint::QueryAuditLog.path(),
+        request_body = QueryAuditLogRequest,
+        params(("x-project-id" = Option<String>, Header, description = PROJECT_ID_HEADER_DESCRIPTION)),
+        responses(
+            (status = 200, description = "Audit log entries", body = QueryAuditLogResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn query_audit_log<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<QueryAuditLogRequest>,
+    ) -> Result<Json<QueryAuditLogResponse>> {
+        ApiServer::<C, A, S>::query_audit_log(request, api_context, metadata)
+            .await
+            .map(Json)
+    }
+
     /// Search Tabulars
     ///
     /// Performs a fuzzy search for tabulars based on the provided criteria. If the search string
@@ -1643,6 +2034,38 @@ pub mod v1 {
         Ok(StatusCode::NO_CONTENT)
     }
 
+    /// Purge Soft-Deleted Tabular
+    ///
+    /// Permanently removes a soft-deleted table, view or generic table and queues the deletion
+    /// of its data immediately instead of waiting for the expiration of the soft-deletion period.
+    /// Protected tabulars can only be purged with `force`.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::PurgeDeletedTabular.path(),
+        params(("warehouse_id" = Uuid,), ("tabular_id" = Uuid,), PurgeDeletedTabularQuery),
+        responses(
+            (status = 204, description = "Tabular purged successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn purge_deleted_tabular<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, tabular_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Query(query): Query<PurgeDeletedTabularQuery>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+    ) -> Result<StatusCode> {
+        ApiServer::<C, A, S>::purge_deleted_tabular(
+            WarehouseId::from(warehouse_id),
+            tabular_id,
+            query,
+            api_context,
+            metadata,
+        )
+        .await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
     #[derive(Serialize, Deserialize, Debug)]
     #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
     pub struct ProtectionResponse {
@@ -1685,20 +2108,79 @@ pub mod v1 {
         .await
     }
 
-    /// Set Table Protection
+    /// Get Table Metadata Location
     ///
-    /// Configures whether a table should be protected from deletion.
+    /// Returns the location of the current metadata file of a table without loading the metadata.
+    /// Staged tables have no metadata location yet.
     #[cfg_attr(feature = "open-api", utoipa::path(
-        post,
+        get,
         tag = "warehouse",
-        path = ManagementV1Endpoint::SetTableProtection.path(),
+        path = ManagementV1Endpoint::GetTableMetadataLocation.path(),
         params(("warehouse_id" = Uuid,),("table_id" = Uuid,)),
         responses(
-            (status = 200, body =  ProtectionResponse, description = "Table protection set successfully"),
+            (status = 200, body = TableMetadataLocationResponse),
             (status = "4XX", body = IcebergErrorResponse),
         )
     ))]
-    async fn set_table_protection<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+    async fn get_table_metadata_location<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, table_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<Json<TableMetadataLocationResponse>> {
+        ApiServer::<C, A, S>::get_table_metadata_location(
+            TableId::from(table_id),
+            warehouse_id.into(),
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
+    /// Touch Table
+    ///
+    /// Refreshes the `updated_at` timestamp of a table without changing its metadata,
+    /// for example to invalidate external caches keyed on it.
+    /// The change version of the warehouse is incremented as for any other change of the table.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::TouchTable.path(),
+        params(("warehouse_id" = Uuid,),("table_id" = Uuid,)),
+        responses(
+            (status = 200, body = TouchTableResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn touch_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, table_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<Json<TouchTableResponse>> {
+        ApiServer::<C, A, S>::touch_table(
+            TableId::from(table_id),
+            warehouse_id.into(),
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
+    /// Set Table Protection
+    ///
+    /// Configures whether a table should be protected from deletion.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::SetTableProtection.path(),
+        params(("warehouse_id" = Uuid,),("table_id" = Uuid,)),
+        responses(
+            (status = 200, body =  ProtectionResponse, description = "Table protection set successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn set_table_protection<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
         Path((warehouse_id, table_id)): Path<(uuid::Uuid, uuid::Uuid)>,
         Extension(metadata): Extension<RequestMetadata>,
         AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
@@ -1714,6 +2196,138 @@ pub mod v1 {
         .await
     }
 
+    /// List Table Snapshots
+    ///
+    /// Lists the snapshots of a table together with their summaries, newest first.
+    /// Snapshots are read from the catalog directly, without loading the table metadata.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::ListTableSnapshots.path(),
+        params(("warehouse_id" = Uuid,),("table_id" = Uuid,), ListTableSnapshotsQuery),
+        responses(
+            (status = 200, body = ListTableSnapshotsResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn list_table_snapshots<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, table_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Query(query): Query<ListTableSnapshotsQuery>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<Json<ListTableSnapshotsResponse>> {
+        ApiServer::<C, A, S>::list_table_snapshots(
+            TableId::from(table_id),
+            warehouse_id.into(),
+            query,
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
+    /// Get Table Schema Diff
+    ///
+    /// Compares the schemas of two snapshots of a table and returns the columns that were
+    /// added, removed, renamed or retyped between them. Columns are matched by field ID.
+    /// If both snapshots reference the same schema, the diff is empty.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::GetTableSchemaDiff.path(),
+        params(("warehouse_id" = Uuid,),("table_id" = Uuid,), TableSchemaDiffQuery),
+        responses(
+            (status = 200, body = TableSchemaDiffResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn get_table_schema_diff<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, table_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Query(query): Query<TableSchemaDiffQuery>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<Json<TableSchemaDiffResponse>> {
+        ApiServer::<C, A, S>::get_table_schema_diff(
+            TableId::from(table_id),
+            warehouse_id.into(),
+            query,
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
+    /// Get Table Storage Usage
+    ///
+    /// Returns the total size and number of the data and delete files referenced by the
+    /// current snapshot of a table. Totals are taken from the snapshot summary if available,
+    /// otherwise the manifests of the snapshot are read. Results are cached for a short time.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::GetTableStorageUsage.path(),
+        params(("warehouse_id" = Uuid,),("table_id" = Uuid,)),
+        responses(
+            (status = 200, body = TableStorageUsageResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn get_table_storage_usage<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, table_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<Json<TableStorageUsageResponse>> {
+        ApiServer::<C, A, S>::get_table_storage_usage(
+            TableId::from(table_id),
+            warehouse_id.into(),
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
+    /// Load Tables Batch
+    ///
+    /// Loads up to 100 tables of a warehouse in a single request.
+    /// Each table is authorized and returned exactly as by the Iceberg REST `loadTable` endpoint,
+    /// but the metadata of all tables is read from the catalog at once.
+    /// Credentials are vended according to the `X-Iceberg-Access-Delegation` header.
+    /// The request fails if any of the tables cannot be loaded.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::LoadTablesBatch.path(),
+        params(
+            ("warehouse_id" = Uuid,),
+            ("X-Iceberg-Access-Delegation" = Option<String>, Header, description = "Comma separated list of access delegation mechanisms: `vended-credentials`, `remote-signing`"),
+        ),
+        request_body = LoadTablesBatchRequest,
+        responses(
+            (status = 200, body = LoadTablesBatchResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn load_tables_batch<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        headers: http::HeaderMap,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Json(request): Json<LoadTablesBatchRequest>,
+    ) -> Result<Json<LoadTablesBatchResponse>> {
+        ApiServer::<C, A, S>::load_tables_batch(
+            warehouse_id.into(),
+            request,
+            parse_data_access(&headers),
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
     /// Get allowed actions for a table
     #[cfg_attr(feature = "open-api", utoipa::path(
     get,
@@ -1804,6 +2418,94 @@ pub mod v1 {
         .await
     }
 
+    /// Get View Versions
+    ///
+    /// Lists all versions of a view that are retained in its metadata, together with the
+    /// log of previously current versions.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::GetViewVersions.path(),
+        params(("warehouse_id" = Uuid,),("view_id" = Uuid,)),
+        responses(
+            (status = 200, body = GetViewVersionsResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn get_view_versions<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, view_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<Json<GetViewVersionsResponse>> {
+        ApiServer::<C, A, S>::get_view_versions(
+            ViewId::from(view_id),
+            warehouse_id.into(),
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
+    /// Rollback View
+    ///
+    /// Makes a previous version of a view its current version again.
+    /// The rollback is committed like any other view update: a new metadata file is written
+    /// and the change is recorded in the version log.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::RollbackView.path(),
+        params(("warehouse_id" = Uuid,),("view_id" = Uuid,)),
+        request_body = RollbackViewRequest,
+        responses(
+            (status = 200, body = GetViewVersionsResponse, description = "View rolled back successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn rollback_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, view_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Json(request): Json<RollbackViewRequest>,
+    ) -> Result<Json<GetViewVersionsResponse>> {
+        ApiServer::<C, A, S>::rollback_view(
+            ViewId::from(view_id),
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+        .map(Json)
+    }
+
+    /// List Views
+    ///
+    /// Lists the active views of a warehouse together with the metadata location of their
+    /// current version. Tables are not included. Only views visible to the current user
+    /// are returned.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::ListViews.path(),
+        params(("warehouse_id" = Uuid,), ListViewsQuery),
+        responses(
+            (status = 200, body = ListViewsResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn list_views<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        Query(query): Query<ListViewsQuery>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+    ) -> Result<Json<ListViewsResponse>> {
+        ApiServer::<C, A, S>::list_views(warehouse_id.into(), query, api_context, metadata)
+            .await
+            .map(Json)
+    }
+
     /// Get allowed actions for a view
     #[cfg_attr(feature = "open-api", utoipa::path(
     get,
@@ -1992,6 +2694,271 @@ pub mod v1 {
         .await
     }
 
+    /// Protect All Tabulars of a Namespace
+    ///
+    /// Sets the protection status of all tables, views and generic tables in a namespace with a single update.
+    /// If `recursive` is set, tabulars in all descendant namespaces are updated as well.
+    /// The protection of the namespaces themselves is not changed. Soft-deleted tabulars are not affected.
+    /// Requires permission to set the protection of the namespace.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::ProtectAllNamespaceTabulars.path(),
+        params(("warehouse_id" = Uuid,),("namespace_id" = Uuid,)),
+        request_body = ProtectAllTabularsRequest,
+        responses(
+            (status = 200, body = ProtectAllTabularsResponse, description = "Protection of the tabulars set successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn protect_all_namespace_tabulars<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path((warehouse_id, namespace_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Json(request): Json<ProtectAllTabularsRequest>,
+    ) -> Result<ProtectAllTabularsResponse> {
+        ApiServer::<C, A, S>::protect_all_tabulars(
+            NamespaceId::from(namespace_id),
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
+    /// Get Namespace Delete Profile
+    ///
+    /// Retrieves the delete profile override of a namespace.
+    /// If no override is set, tables, views and generic tables in the namespace use the delete profile of the warehouse.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::GetNamespaceDeleteProfile.path(),
+        params(("warehouse_id" = Uuid,),("namespace_id" = Uuid,)),
+        responses(
+            (status = 200, body = NamespaceDeleteProfileResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn get_namespace_delete_profile<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path((warehouse_id, namespace_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<NamespaceDeleteProfileResponse> {
+        ApiServer::<C, A, S>::get_namespace_delete_profile(
+            NamespaceId::from(namespace_id),
+            warehouse_id.into(),
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
+    /// Set Namespace Delete Profile
+    ///
+    /// Overrides the delete profile of the warehouse for tables, views and generic tables directly contained in the namespace.
+    /// Child namespaces are not affected. Setting `delete-profile` to null removes the override.
+    /// Requires permission to modify the soft-deletion settings of the warehouse.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::SetNamespaceDeleteProfile.path(),
+        params(("warehouse_id" = Uuid,),("namespace_id" = Uuid,)),
+        request_body = SetNamespaceDeleteProfileRequest,
+        responses(
+            (status = 200, body = NamespaceDeleteProfileResponse, description = "Namespace delete profile set successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn set_namespace_delete_profile<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path((warehouse_id, namespace_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Json(request): Json<SetNamespaceDeleteProfileRequest>,
+    ) -> Result<NamespaceDeleteProfileResponse> {
+        ApiServer::<C, A, S>::set_namespace_delete_profile(
+            NamespaceId::from(namespace_id),
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
+    /// Get Namespace Table Defaults
+    ///
+    /// Retrieves the partition spec and sort order inherited by new tables of a namespace.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::GetNamespaceTableDefaults.path(),
+        params(("warehouse_id" = Uuid,),("namespace_id" = Uuid,)),
+        responses(
+            (status = 200, body = NamespaceTableDefaults),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn get_namespace_table_defaults<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path((warehouse_id, namespace_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+    ) -> Result<NamespaceTableDefaults> {
+        ApiServer::<C, A, S>::get_namespace_table_defaults(
+            NamespaceId::from(namespace_id),
+            warehouse_id.into(),
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
+    /// Set Namespace Table Defaults
+    ///
+    /// Sets the partition spec and sort order inherited by new tables of the namespace.
+    /// Defaults are applied if a create table request omits the partition spec or sort order
+    /// or specifies an empty one. Source ids refer to the field ids of the schema in the create request.
+    /// Child namespaces and existing tables are not affected. Unset fields are removed.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::SetNamespaceTableDefaults.path(),
+        params(("warehouse_id" = Uuid,),("namespace_id" = Uuid,)),
+        request_body = NamespaceTableDefaults,
+        responses(
+            (status = 200, body = NamespaceTableDefaults, description = "Namespace table defaults set successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn set_namespace_table_defaults<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        Path((warehouse_id, namespace_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Json(request): Json<NamespaceTableDefaults>,
+    ) -> Result<NamespaceTableDefaults> {
+        ApiServer::<C, A, S>::set_namespace_table_defaults(
+            NamespaceId::from(namespace_id),
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
+    /// Move Namespace
+    ///
+    /// Moves a namespace, including all of its child namespaces, tables and views, below a
+    /// different parent namespace or to the root of the warehouse. The namespace keeps its name.
+    /// Moves that would result in a name conflict at the destination or that would move the
+    /// namespace into its own subtree are rejected.
+    /// Storage locations of existing tables and views are not changed.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::MoveNamespace.path(),
+        params(("warehouse_id" = Uuid,),("namespace_id" = Uuid,)),
+        request_body = MoveNamespaceRequest,
+        responses(
+            (status = 204, description = "Namespace moved successfully"),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn move_namespace<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path((warehouse_id, namespace_id)): Path<(uuid::Uuid, uuid::Uuid)>,
+        Extension(metadata): Extension<RequestMetadata>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Json(request): Json<MoveNamespaceRequest>,
+    ) -> Result<(StatusCode, ())> {
+        ApiServer::<C, A, S>::move_namespace(
+            NamespaceId::from(namespace_id),
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+        .map(|()| (StatusCode::NO_CONTENT, ()))
+    }
+
+    /// List Namespace Tree
+    ///
+    /// Lists all namespaces below `root` recursively, including their full path, in a single paginated call.
+    /// If `root` is not set, all namespaces of the warehouse are listed.
+    /// Namespaces are ordered by creation time. Namespaces that are not visible to the current user are omitted.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        get,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::ListNamespaceTree.path(),
+        params(("warehouse_id" = Uuid,), ListNamespaceTreeQuery),
+        responses(
+            (status = 200, description = "Namespaces below the root", body = ListNamespaceTreeResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn list_namespace_tree<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        Query(query): Query<ListNamespaceTreeQuery>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+    ) -> Result<ListNamespaceTreeResponse> {
+        ApiServer::<C, A, S>::list_namespace_tree(warehouse_id.into(), query, api_context, metadata)
+            .await
+    }
+
+    /// Create Namespaces Batch
+    ///
+    /// Creates up to 100 namespaces of a warehouse in a single transaction.
+    /// The parent of each namespace must either exist or be listed before it in the request.
+    /// Namespaces that already exist are left unchanged and reported as `already-exists`.
+    /// If any namespace cannot be created, no namespace is created.
+    #[cfg_attr(feature = "open-api", utoipa::path(
+        post,
+        tag = "warehouse",
+        path = ManagementV1Endpoint::CreateNamespacesBatch.path(),
+        params(("warehouse_id" = Uuid,)),
+        request_body = CreateNamespacesBatchRequest,
+        responses(
+            (status = 200, description = "Result for each requested namespace", body = CreateNamespacesBatchResponse),
+            (status = "4XX", body = IcebergErrorResponse),
+        )
+    ))]
+    async fn create_namespaces_batch<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+        Path(warehouse_id): Path<uuid::Uuid>,
+        AxumState(api_context): AxumState<ApiContext<State<A, C, S>>>,
+        Extension(metadata): Extension<RequestMetadata>,
+        Json(request): Json<CreateNamespacesBatchRequest>,
+    ) -> Result<CreateNamespacesBatchResponse> {
+        ApiServer::<C, A, S>::create_namespaces_batch(
+            warehouse_id.into(),
+            request,
+            api_context,
+            metadata,
+        )
+        .await
+    }
+
     /// Get allowed actions for a namespace
     #[cfg_attr(feature = "open-api", utoipa::path(
     get,
@@ -2494,7 +3461,14 @@ pub mod v1 {
                     ManagementV1Endpoint::GetServerActions.path_in_management_v1(),
                     get(get_server_actions),
                 )
-                .route("/endpoint-statistics", post(get_endpoint_statistics))
+                .route(
+                    "/endpoint-statistics",
+                    post(get_endpoint_statistics).delete(delete_endpoint_statistics),
+                )
+                .route(
+                    ManagementV1Endpoint::QueryAuditLog.path_in_management_v1(),
+                    post(query_audit_log),
+                )
                 // Role management
                 .route("/role", get(list_roles).post(create_role))
                 .route(
@@ -2576,6 +3550,11 @@ pub mod v1 {
                 )
                 // Create a new warehouse
                 .route("/warehouse", post(create_warehouse).get(list_warehouses))
+                // Validate a storage profile without creating a warehouse
+                .route(
+                    ManagementV1Endpoint::ValidateStorage.path_in_management_v1(),
+                    post(validate_storage),
+                )
                 // List all projects
                 .route("/project-list", get(list_projects))
                 .route(
@@ -2584,6 +3563,8 @@ pub mod v1 {
                 )
                 // Rename warehouse
                 .route("/warehouse/{warehouse_id}/rename", post(rename_warehouse))
+                // Clone warehouse configuration
+                .route("/warehouse/{warehouse_id}/clone", post(clone_warehouse))
                 // Deactivate warehouse
                 .route(
                     "/warehouse/{warehouse_id}/deactivate",
@@ -2600,6 +3581,21 @@ pub mod v1 {
                     "/warehouse/{warehouse_id}/storage",
                     post(update_storage_profile),
                 )
+                // Update only the given fields of the storage profile.
+                // The existing credential is re-used unless a new one is provided.
+                .route(
+                    ManagementV1Endpoint::MergeStorageProfile.path_in_management_v1(),
+                    post(merge_storage_profile),
+                )
+                // Set the secondary storage profile and credential.
+                .route(
+                    ManagementV1Endpoint::UpdateSecondaryStorageProfile.path_in_management_v1(),
+                    post(update_secondary_storage_profile),
+                )
+                .route(
+                    ManagementV1Endpoint::MergeSecondaryStorageProfile.path_in_management_v1(),
+                    post(merge_secondary_storage_profile),
+                )
                 // Update only the storage credential - keep the storage profile as is
                 .route(
                     "/warehouse/{warehouse_id}/storage-credential",
@@ -2610,6 +3606,18 @@ pub mod v1 {
                     "/warehouse/{warehouse_id}/statistics",
                     get(get_warehouse_statistics),
                 )
+                .route(
+                    ManagementV1Endpoint::GetWarehouseCatalogConfig.path_in_management_v1(),
+                    get(get_warehouse_catalog_config),
+                )
+                .route(
+                    ManagementV1Endpoint::GetWarehouseVersion.path_in_management_v1(),
+                    get(get_warehouse_version),
+                )
+                .route(
+                    ManagementV1Endpoint::ListProtectedEntities.path_in_management_v1(),
+                    get(list_protected_entities),
+                )
                 .route(
                     ManagementV1Endpoint::SearchTabular.path_in_management_v1(),
                     post(search_tabular),
@@ -2622,6 +3630,10 @@ pub mod v1 {
                     "/warehouse/{warehouse_id}/deleted-tabulars/undrop",
                     post(undrop_tabulars),
                 )
+                .route(
+                    "/warehouse/{warehouse_id}/deleted-tabulars/{tabular_id}/purge",
+                    post(purge_deleted_tabular),
+                )
                 .route(
                     "/warehouse/{warehouse_id}/delete-profile",
                     post(update_warehouse_delete_profile),
@@ -2630,6 +3642,10 @@ pub mod v1 {
                     "/warehouse/{warehouse_id}/format-version-policy",
                     post(update_warehouse_format_version_policy),
                 )
+                .route(
+                    "/warehouse/{warehouse_id}/settings",
+                    post(update_warehouse_settings),
+                )
                 .route(
                     ManagementV1Endpoint::GetWarehouseActions.path_in_management_v1(),
                     get(get_warehouse_actions),
@@ -2642,6 +3658,34 @@ pub mod v1 {
                     ManagementV1Endpoint::GetTableActions.path_in_management_v1(),
                     get(get_table_actions),
                 )
+                .route(
+                    ManagementV1Endpoint::GetTableMetadataLocation.path_in_management_v1(),
+                    get(get_table_metadata_location),
+                )
+                .route(
+                    ManagementV1Endpoint::ListTableSnapshots.path_in_management_v1(),
+                    get(list_table_snapshots),
+                )
+                .route(
+                    ManagementV1Endpoint::GetTableSchemaDiff.path_in_management_v1(),
+                    get(get_table_schema_diff),
+                )
+                .route(
+                    ManagementV1Endpoint::GetTableStorageUsage.path_in_management_v1(),
+                    get(get_table_storage_usage),
+                )
+                .route(
+                    ManagementV1Endpoint::TouchTable.path_in_management_v1(),
+                    post(touch_table),
+                )
+                .route(
+                    ManagementV1Endpoint::LoadTablesBatch.path_in_management_v1(),
+                    post(load_tables_batch),
+                )
+                .route(
+                    ManagementV1Endpoint::ListViews.path_in_management_v1(),
+                    get(list_views),
+                )
                 .route(
                     ManagementV1Endpoint::GetViewProtection.path_in_management_v1(),
                     get(get_view_protection).post(set_view_protection),
@@ -2650,6 +3694,14 @@ pub mod v1 {
                     ManagementV1Endpoint::GetViewActions.path_in_management_v1(),
                     get(get_view_actions),
                 )
+                .route(
+                    ManagementV1Endpoint::GetViewVersions.path_in_management_v1(),
+                    get(get_view_versions),
+                )
+                .route(
+                    ManagementV1Endpoint::RollbackView.path_in_management_v1(),
+                    post(rollback_view),
+                )
                 .route(
                     ManagementV1Endpoint::GetGenericTableActions.path_in_management_v1(),
                     get(get_generic_table_actions),
@@ -2662,10 +3714,34 @@ pub mod v1 {
                     ManagementV1Endpoint::GetNamespaceProtection.path_in_management_v1(),
                     get(get_namespace_protection).post(set_namespace_protection),
                 )
+                .route(
+                    ManagementV1Endpoint::ProtectAllNamespaceTabulars.path_in_management_v1(),
+                    post(protect_all_namespace_tabulars),
+                )
+                .route(
+                    ManagementV1Endpoint::GetNamespaceDeleteProfile.path_in_management_v1(),
+                    get(get_namespace_delete_profile).post(set_namespace_delete_profile),
+                )
+                .route(
+                    ManagementV1Endpoint::GetNamespaceTableDefaults.path_in_management_v1(),
+                    get(get_namespace_table_defaults).post(set_namespace_table_defaults),
+                )
+                .route(
+                    ManagementV1Endpoint::MoveNamespace.path_in_management_v1(),
+                    post(move_namespace),
+                )
                 .route(
                     ManagementV1Endpoint::GetNamespaceActions.path_in_management_v1(),
                     get(get_namespace_actions),
                 )
+                .route(
+                    ManagementV1Endpoint::ListNamespaceTree.path_in_management_v1(),
+                    get(list_namespace_tree),
+                )
+                .route(
+                    ManagementV1Endpoint::CreateNamespacesBatch.path_in_management_v1(),
+                    post(create_namespaces_batch),
+                )
                 .route(
                     ManagementV1Endpoint::SetWarehouseProtection.path_in_management_v1(),
                     post(set_warehouse_protection),
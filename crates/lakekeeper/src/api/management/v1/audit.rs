@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    api::{ApiContext, Result, management::v1::ApiServer},
+    request_metadata::RequestMetadata,
+    service::{
+        CatalogAuditLogOps, CatalogStore, State, UserId,
+        authz::{AuthZProjectOps, Authorizer, CatalogProjectAction},
+        events::{APIEventContext, OperationOutcome},
+        secrets::SecretStore,
+    },
+};
+
+#[derive(Clone, Debug, Deserialize, Default, typed_builder::TypedBuilder)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct QueryAuditLogRequest {
+    /// Only return operations performed by this user, either directly or
+    /// while assuming a role.
+    #[serde(default)]
+    #[builder(default)]
+    #[cfg_attr(feature = "open-api", schema(value_type = Option<String>))]
+    pub actor: Option<UserId>,
+    /// Only return operations that targeted this warehouse.
+    #[serde(default)]
+    #[builder(default)]
+    pub warehouse_id: Option<Uuid>,
+    /// Only return the given operations, e.g. `management-v1-delete-warehouse`.
+    #[serde(default)]
+    #[builder(default)]
+    pub operation: Option<Vec<String>>,
+    /// Only return entries recorded at or after this timestamp
+    #[serde(default)]
+    #[builder(default)]
+    #[cfg_attr(feature = "open-api", schema(example = "2025-12-31T23:59:59Z"))]
+    pub created_after: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only return entries recorded at or before this timestamp
+    #[serde(default)]
+    #[builder(default)]
+    #[cfg_attr(feature = "open-api", schema(example = "2025-12-31T23:59:59Z"))]
+    pub created_before: Option<chrono::DateTime<chrono::Utc>>,
+    /// Next page token, re-use the same request as for the original request,
+    /// but set this to the `next_page_token` from the previous response.
+    /// Stop iterating when no more items are returned in a page.
+    #[serde(default)]
+    #[builder(default)]
+    pub page_token: Option<String>,
+    /// Number of results per page
+    #[serde(default)]
+    #[builder(default)]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct AuditLogEntry {
+    /// Unique identifier of the entry
+    pub audit_log_id: Uuid,
+    /// ID of the request that performed the operation
+    pub request_id: Uuid,
+    /// Project the operation was scoped to
+    pub project_id: Option<String>,
+    /// Operation that was performed, e.g. `management-v1-delete-warehouse`
+    pub operation: String,
+    /// Acting principal, e.g. `{"actor_type": "principal", "principal": "oidc~..."}`
+    pub actor: serde_json::Value,
+    /// IDs of the targeted entities, keyed by path parameter name
+    pub targets: serde_json::Value,
+    /// Whether the operation succeeded
+    pub outcome: OperationOutcome,
+    /// HTTP status code of the response
+    pub http_status: u16,
+    /// When the operation completed
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct QueryAuditLogResponse {
+    /// Matching entries, newest first
+    pub entries: Vec<AuditLogEntry>,
+    /// Token for the next page of results
+    pub next_page_token: Option<String>,
+}
+
+impl<C: CatalogStore, A: Authorizer, S: SecretStore> Service<C, A, S> for ApiServer<C, A, S> {}
+
+#[async_trait::async_trait]
+pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
+    async fn query_audit_log(
+        request: QueryAuditLogRequest,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<QueryAuditLogResponse> {
+        let project_id = request_metadata.require_project_id(None)?;
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_project_arc(
+            Arc::new(request_metadata),
+            context.v1_state.events,
+            project_id.clone(),
+            Arc::new(CatalogProjectAction::ReadAuditLog),
+        );
+        let authz_result = authorizer
+            .require_project_action(
+                event_ctx.request_metadata(),
+                &project_id,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (_event_ctx, ()) = event_ctx.emit_authz(authz_result)?;
+
+        C::query_audit_log(&project_id, &request, context.v1_state.catalog).await
+    }
+}
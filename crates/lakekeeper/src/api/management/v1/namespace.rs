@@ -1,17 +1,308 @@
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
+
+use futures::FutureExt;
+use iceberg::{
+    NamespaceIdent,
+    spec::{SortOrder, UnboundPartitionSpec},
+};
+use iceberg_ext::catalog::rest::CreateTableRequest;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
-use super::{ApiServer, ProtectionResponse};
+use super::{
+    ApiServer, ProtectionResponse,
+    warehouse::{TabularDeleteProfile, spec_lock_to_error},
+};
 use crate::{
     WarehouseId,
-    api::{ApiContext, RequestMetadata, Result},
+    api::{
+        ApiContext, RequestMetadata, Result,
+        iceberg::{
+            types::PageToken,
+            v1::{
+                CreateNamespaceRequest, ErrorModel,
+                namespace::{ListNamespacesQuery, deserialize_namespace_ident_from_url},
+            },
+        },
+    },
+    server::{
+        UnfilteredPage, fetch_until_full_page,
+        namespace::{
+            create::authorize_namespace_create, create_namespace_or_warehouse_event_context,
+            list::authorize_namespace_list, new_namespace_properties, validate_namespace_ident,
+            validate_new_namespace,
+        },
+    },
     service::{
-        CachePolicy, CatalogNamespaceOps, CatalogStore, NamespaceId, SecretStore, State,
-        Transaction,
-        authz::{Authorizer, AuthzNamespaceOps, CatalogNamespaceAction},
-        events::{APIEventContext, context::ResolvedNamespace},
+        CachePolicy, CatalogNamespaceOps, CatalogStore, CatalogTabularOps, CatalogWarehouseOps,
+        NamespaceHierarchy, NamespaceId, ResolvedWarehouse, SecretStore, State, Transaction,
+        WarehouseQuotaEntity,
+        authz::{
+            AuthZError, Authorizer, AuthzNamespaceOps, AuthzWarehouseOps, CatalogNamespaceAction,
+            CatalogWarehouseAction, NamespaceParent,
+        },
+        events::{
+            APIEventContext, NamespaceOrWarehouseAPIContext, ResolvedNamespaceOrWarehouseContext,
+            context::{ResolvedNamespace, UserProvidedNamespace, authz_to_error_no_audit},
+        },
     },
 };
 
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct MoveNamespaceRequest {
+    /// Namespace to move the namespace into.
+    /// If not set, the namespace is moved to the root of the warehouse.
+    #[serde(default)]
+    #[cfg_attr(feature = "open-api", schema(value_type = Option<Vec<String>>))]
+    pub new_parent: Option<NamespaceIdent>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct SetNamespaceDeleteProfileRequest {
+    /// Delete profile for tables, views and generic tables directly contained in the namespace.
+    /// If not set, the override is removed and the delete profile of the warehouse is used.
+    #[serde(default)]
+    pub delete_profile: Option<TabularDeleteProfile>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct NamespaceDeleteProfileResponse {
+    /// Delete profile override of the namespace.
+    /// Not set if the namespace uses the delete profile of the warehouse.
+    pub delete_profile: Option<TabularDeleteProfile>,
+}
+
+impl axum::response::IntoResponse for NamespaceDeleteProfileResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        (http::StatusCode::OK, axum::Json(self)).into_response()
+    }
+}
+
+/// Partition spec and sort order inherited by new tables of a namespace.
+///
+/// Defaults are applied if a create table request does not specify a partition
+/// spec or sort order, or specifies an empty one. Source ids of the fields refer
+/// to the field ids of the schema in the create table request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct NamespaceTableDefaults {
+    /// Partition spec of new tables. If not set, new tables are unpartitioned
+    /// unless the create request specifies a partition spec.
+    #[serde(default)]
+    #[cfg_attr(feature = "open-api", schema(value_type = Option<Object>))]
+    pub partition_spec: Option<UnboundPartitionSpec>,
+    /// Sort order of new tables. If not set, new tables are unsorted
+    /// unless the create request specifies a sort order.
+    #[serde(default)]
+    #[cfg_attr(feature = "open-api", schema(value_type = Option<Object>))]
+    pub write_order: Option<SortOrder>,
+}
+
+impl NamespaceTableDefaults {
+    /// Whether the partition spec or sort order of a create table request
+    /// is missing or empty, so that defaults would apply.
+    pub(crate) fn applies_to(request: &CreateTableRequest) -> bool {
+        lacks_partition_spec(request) || lacks_write_order(request)
+    }
+
+    /// Fill the partition spec and sort order of a create table request
+    /// with the defaults if they are missing or empty.
+    pub(crate) fn apply_to(&self, request: &mut CreateTableRequest) {
+        if let Some(partition_spec) = &self.partition_spec
+            && lacks_partition_spec(request)
+        {
+            request.partition_spec = Some(partition_spec.clone());
+        }
+        if let Some(write_order) = &self.write_order
+            && lacks_write_order(request)
+        {
+            request.write_order = Some(write_order.clone());
+        }
+    }
+}
+
+fn lacks_partition_spec(request: &CreateTableRequest) -> bool {
+    request
+        .partition_spec
+        .as_ref()
+        .is_none_or(|spec| spec.fields().is_empty())
+}
+
+fn lacks_write_order(request: &CreateTableRequest) -> bool {
+    request
+        .write_order
+        .as_ref()
+        .is_none_or(SortOrder::is_unsorted)
+}
+
+impl axum::response::IntoResponse for NamespaceTableDefaults {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        (http::StatusCode::OK, axum::Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ProtectAllTabularsRequest {
+    /// Protection status to set on all tables, views and generic tables of the namespace.
+    pub protected: bool,
+    /// Also set the protection of tabulars in all descendant namespaces.
+    /// Default: false
+    #[serde(default)]
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ProtectAllTabularsResponse {
+    /// Protection status set on the tabulars.
+    pub protected: bool,
+    /// Number of tables whose protection was set.
+    pub tables: u64,
+    /// Number of views whose protection was set.
+    pub views: u64,
+    /// Number of generic tables whose protection was set.
+    pub generic_tables: u64,
+}
+
+impl axum::response::IntoResponse for ProtectAllTabularsResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        (http::StatusCode::OK, axum::Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct ListNamespaceTreeQuery {
+    /// Namespace below which to list namespaces recursively.
+    /// If not set, all namespaces of the warehouse are listed.
+    /// If root is a multipart namespace, the parts must be separated by the unit separator (`0x1F`) byte.
+    #[serde(default, deserialize_with = "deserialize_namespace_ident_from_url")]
+    #[cfg_attr(feature = "open-api", param(value_type = Option<String>))]
+    pub root: Option<NamespaceIdent>,
+    /// Next page token
+    #[serde(default)]
+    pub page_token: Option<String>,
+    /// Signals an upper bound of the number of results that a client will receive.
+    /// Default: 100
+    #[serde(default)]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ListNamespaceTreeResponse {
+    /// Namespaces below the root, ordered by creation time.
+    pub namespaces: Vec<NamespaceTreeEntry>,
+    /// Token to fetch the next page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct NamespaceTreeEntry {
+    /// ID of the namespace
+    pub namespace_id: uuid::Uuid,
+    /// Full path of the namespace, starting at the root of the warehouse
+    pub namespace: Vec<String>,
+    /// ID of the parent namespace. Not set for top-level namespaces.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_namespace_id: Option<uuid::Uuid>,
+    /// Whether the namespace is protected
+    pub protected: bool,
+}
+
+impl axum::response::IntoResponse for ListNamespaceTreeResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        (http::StatusCode::OK, axum::Json(self)).into_response()
+    }
+}
+
+/// Maximum number of namespaces that can be created with a single batch request.
+pub(crate) const MAX_NAMESPACES_PER_CREATE_BATCH: usize = 100;
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct CreateNamespacesBatchRequest {
+    /// Namespaces to create, at most 100. Each namespace may only be listed once.
+    /// The parent of a namespace must either exist or be listed before it.
+    pub namespaces: Vec<CreateNamespacesBatchEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct CreateNamespacesBatchEntry {
+    /// Full path of the namespace, starting at the root of the warehouse
+    #[cfg_attr(feature = "open-api", schema(value_type = Vec<String>))]
+    pub namespace: NamespaceIdent,
+    /// Properties of the namespace. Ignored if the namespace already exists.
+    #[serde(default)]
+    pub properties: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct CreateNamespacesBatchResponse {
+    /// Result for each requested namespace, in the order of the request.
+    pub results: Vec<CreateNamespacesBatchResult>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct CreateNamespacesBatchResult {
+    /// Full path of the namespace as requested
+    pub namespace: Vec<String>,
+    /// ID of the created or already existing namespace
+    pub namespace_id: uuid::Uuid,
+    pub status: CreateNamespacesBatchStatus,
+}
+
+/// Outcome of creating a namespace of a batch request.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum CreateNamespacesBatchStatus {
+    /// The namespace was created by this request.
+    Created,
+    /// The namespace already existed and was left unchanged.
+    AlreadyExists,
+}
+
+impl axum::response::IntoResponse for CreateNamespacesBatchResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        (http::StatusCode::OK, axum::Json(self)).into_response()
+    }
+}
+
+/// Parent of a namespace of a batch create request that does not exist yet.
+enum BatchNamespaceParent {
+    /// The warehouse or an existing namespace.
+    Existing(Option<NamespaceHierarchy>),
+    /// A namespace created earlier in the same batch, by position among the
+    /// namespaces to create.
+    Batch(usize),
+}
+
 impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> NamespaceManagementService<C, A, S>
     for ApiServer<C, A, S>
 {
@@ -83,6 +374,674 @@ where
         Ok(protection_response)
     }
 
+    /// Set the protection of all tabulars in a namespace with a single update.
+    ///
+    /// Protection of a namespace's tabulars may be changed by anyone allowed to
+    /// change the protection of the namespace: its permission is inherited by
+    /// the contained tabulars and descendant namespaces.
+    async fn protect_all_tabulars(
+        namespace_id: NamespaceId,
+        warehouse_id: WarehouseId,
+        request: ProtectAllTabularsRequest,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<ProtectAllTabularsResponse> {
+        //  ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+        let state_catalog = state.v1_state.catalog.clone();
+
+        let event_ctx = APIEventContext::for_namespace(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            namespace_id,
+            CatalogNamespaceAction::SetProtection,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_namespace_action::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity().clone(),
+                event_ctx.action().clone(),
+                CachePolicy::Skip,
+                state_catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_write(state_catalog).await?;
+        let counts = C::set_namespace_tabulars_protected(
+            warehouse_id,
+            namespace_id,
+            request.protected,
+            request.recursive,
+            t.transaction(),
+        )
+        .await?;
+        t.commit().await?;
+
+        Ok(ProtectAllTabularsResponse {
+            protected: request.protected,
+            tables: counts.tables,
+            views: counts.views,
+            generic_tables: counts.generic_tables,
+        })
+    }
+
+    /// Move a namespace, including its child namespaces, tables and views, below a
+    /// different parent. The name of the namespace itself is kept.
+    async fn move_namespace(
+        namespace_id: NamespaceId,
+        warehouse_id: WarehouseId,
+        request: MoveNamespaceRequest,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<()> {
+        // ------------------- VALIDATIONS -------------------
+        if let Some(new_parent) = &request.new_parent {
+            validate_namespace_ident(new_parent)?;
+        }
+
+        //  ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+        let state_catalog = state.v1_state.catalog.clone();
+
+        // Moving removes the namespace from its current parent, which requires the same
+        // permission as dropping the subtree.
+        let event_ctx = APIEventContext::for_namespace(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            namespace_id,
+            CatalogNamespaceAction::Delete {
+                force: false,
+                purge: false,
+                recursive: true,
+            },
+        );
+
+        let authz_result = authorize_move_namespace::<C, A>(
+            event_ctx.request_metadata(),
+            event_ctx.user_provided_entity().clone(),
+            event_ctx.action().clone(),
+            request.new_parent.as_ref(),
+            &authorizer,
+            state_catalog.clone(),
+        )
+        .await;
+        let (event_ctx, (warehouse, namespace, new_parent)) = event_ctx.emit_authz(authz_result)?;
+        let previous_parent_id = namespace.namespace.parent_namespaces_id();
+        let new_parent_id = new_parent.as_ref().map(NamespaceHierarchy::namespace_id);
+        let event_ctx = event_ctx.resolve(ResolvedNamespace {
+            warehouse,
+            namespace: namespace.namespace,
+        });
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_write(state_catalog).await?;
+        let move_info = C::move_namespace(
+            warehouse_id,
+            namespace_id,
+            request.new_parent.as_ref(),
+            t.transaction(),
+        )
+        .await?;
+        // Moved namespaces must satisfy the same rules as newly created ones, including
+        // the configured maximum depth and reserved names at the root of the warehouse.
+        validate_new_namespace(move_info.namespace.namespace_ident(), None)?;
+        for (_, child_namespace) in &move_info.child_namespaces {
+            validate_new_namespace(child_namespace, None)?;
+        }
+
+        if previous_parent_id != new_parent_id {
+            let as_parent = |parent_id: Option<NamespaceId>| {
+                parent_id.map_or(
+                    NamespaceParent::Warehouse(warehouse_id),
+                    NamespaceParent::Namespace,
+                )
+            };
+            authorizer
+                .move_namespace(
+                    event_ctx.request_metadata(),
+                    namespace_id,
+                    as_parent(previous_parent_id),
+                    as_parent(new_parent_id),
+                )
+                .await?;
+        }
+        t.commit().await?;
+
+        event_ctx.emit_namespace_moved_async(move_info);
+
+        Ok(())
+    }
+
+    /// Set or remove the delete profile override of a namespace. The override
+    /// applies to tables, views and generic tables directly contained in the namespace.
+    async fn set_namespace_delete_profile(
+        namespace_id: NamespaceId,
+        warehouse_id: WarehouseId,
+        request: SetNamespaceDeleteProfileRequest,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<NamespaceDeleteProfileResponse> {
+        //  ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        // Retention is a property of the warehouse, the override requires the same
+        // permission as changing the delete profile of the warehouse.
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::ModifySoftDeletion,
+        );
+
+        let warehouse =
+            C::get_active_warehouse_by_id(warehouse_id, state.v1_state.catalog.clone()).await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let event_ctx = event_ctx.resolve(warehouse);
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_write(state.v1_state.catalog).await?;
+        C::ensure_warehouse_spec_mutable(
+            warehouse_id,
+            event_ctx.action(),
+            event_ctx
+                .request_metadata()
+                .bypasses_control_plane_authz(None),
+            t.transaction(),
+        )
+        .await
+        .map_err(|e| spec_lock_to_error(&event_ctx, e))?;
+        let delete_profile = C::set_namespace_delete_profile(
+            warehouse_id,
+            namespace_id,
+            request.delete_profile.as_ref(),
+            t.transaction(),
+        )
+        .await?;
+        t.commit().await?;
+
+        event_ctx.emit_namespace_delete_profile_set(namespace_id, Arc::new(request));
+
+        Ok(NamespaceDeleteProfileResponse { delete_profile })
+    }
+
+    async fn get_namespace_delete_profile(
+        namespace_id: NamespaceId,
+        warehouse_id: WarehouseId,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<NamespaceDeleteProfileResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_namespace(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            namespace_id,
+            CatalogNamespaceAction::GetMetadata,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_namespace_action::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity().clone(),
+                event_ctx.action().clone(),
+                CachePolicy::Skip,
+                state.v1_state.catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_read(state.v1_state.catalog).await?;
+        let delete_profile =
+            C::get_namespace_delete_profile(warehouse_id, namespace_id, t.transaction()).await?;
+        t.commit().await?;
+
+        Ok(NamespaceDeleteProfileResponse { delete_profile })
+    }
+
+    /// List all namespaces below `query.root` recursively with their full path.
+    /// Namespaces the user is not allowed to see are omitted.
+    #[allow(clippy::too_many_lines)]
+    /// Replace the partition spec and sort order inherited by new tables of a namespace.
+    /// Existing tables are not changed.
+    async fn set_namespace_table_defaults(
+        namespace_id: NamespaceId,
+        warehouse_id: WarehouseId,
+        request: NamespaceTableDefaults,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<NamespaceTableDefaults> {
+        //  ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        // Table defaults are settings of the namespace, changing them requires
+        // the same permission as changing the properties of the namespace.
+        let event_ctx = APIEventContext::for_namespace(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            namespace_id,
+            CatalogNamespaceAction::UpdateProperties {
+                removed_properties: Arc::default(),
+                updated_properties: Arc::default(),
+            },
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_namespace_action::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity().clone(),
+                event_ctx.action().clone(),
+                CachePolicy::Skip,
+                state.v1_state.catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_write(state.v1_state.catalog).await?;
+        let table_defaults =
+            C::set_namespace_table_defaults(warehouse_id, namespace_id, &request, t.transaction())
+                .await?;
+        t.commit().await?;
+
+        Ok(table_defaults)
+    }
+
+    async fn get_namespace_table_defaults(
+        namespace_id: NamespaceId,
+        warehouse_id: WarehouseId,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<NamespaceTableDefaults> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_namespace(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            namespace_id,
+            CatalogNamespaceAction::GetMetadata,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_namespace_action::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity().clone(),
+                event_ctx.action().clone(),
+                CachePolicy::Skip,
+                state.v1_state.catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_read(state.v1_state.catalog).await?;
+        let table_defaults =
+            C::get_namespace_table_defaults(warehouse_id, namespace_id, t.transaction()).await?;
+        t.commit().await?;
+
+        Ok(table_defaults)
+    }
+
+    async fn list_namespace_tree(
+        warehouse_id: WarehouseId,
+        query: ListNamespaceTreeQuery,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<ListNamespaceTreeResponse> {
+        // ------------------- VALIDATIONS -------------------
+        let ListNamespaceTreeQuery {
+            root,
+            page_token,
+            page_size,
+        } = query;
+        let root = root.filter(|r| !r.as_ref().is_empty());
+        root.as_ref().map(validate_namespace_ident).transpose()?;
+
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        let event_ctx = create_namespace_or_warehouse_event_context(
+            root.clone(),
+            request_metadata,
+            state.v1_state.events,
+            warehouse_id,
+            CatalogNamespaceAction::ListNamespaces,
+            CatalogWarehouseAction::ListNamespaces,
+        );
+
+        let authz_result = authorize_namespace_list::<C, A>(
+            authorizer.clone(),
+            event_ctx.request_metadata(),
+            warehouse_id,
+            root.as_ref(),
+            state.v1_state.catalog.clone(),
+        )
+        .await;
+
+        let (event_ctx, (can_list_everything, warehouse, _root_namespace)) =
+            event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_read(state.v1_state.catalog).await?;
+        let (namespaces, _ids, next_page_token) = fetch_until_full_page::<_, _, _, C>(
+            page_size,
+            page_token.map_or(PageToken::Empty, PageToken::Present),
+            |ps, page_token, trx| {
+                let root = root.clone();
+                let authorizer = authorizer.clone();
+                let warehouse = warehouse.clone();
+                let request_metadata = event_ctx.request_metadata().clone();
+                async move {
+                    let query = ListNamespacesQuery {
+                        page_size: Some(ps),
+                        page_token: page_token.into(),
+                        parent: root,
+                        return_uuids: true,
+                        return_protection_status: true,
+                    };
+
+                    let list_namespaces =
+                        C::list_namespaces_recursive(warehouse_id, &query, trx.transaction())
+                            .await?;
+                    let parent_namespaces = list_namespaces.parent_namespaces;
+                    let (ids, responses, tokens): (Vec<_>, Vec<_>, Vec<_>) = list_namespaces
+                        .namespaces
+                        .into_iter_with_page_tokens()
+                        .multiunzip();
+
+                    let masks = if can_list_everything {
+                        vec![true; ids.len()]
+                    } else {
+                        authorizer
+                            .are_allowed_namespace_actions_vec(
+                                &request_metadata,
+                                None,
+                                &warehouse,
+                                &parent_namespaces,
+                                &responses
+                                    .iter()
+                                    .map(|ns| (ns, CatalogNamespaceAction::IncludeInList))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .await
+                            .map_err(authz_to_error_no_audit)?
+                            .into_allowed()
+                    };
+
+                    Ok(UnfilteredPage::new(
+                        responses,
+                        ids,
+                        tokens,
+                        masks,
+                        ps.clamp(0, i64::MAX).try_into().expect("We clamped it"),
+                    ))
+                }
+                .boxed()
+            },
+            &mut t,
+        )
+        .await?;
+        t.commit().await?;
+
+        let namespaces = namespaces
+            .into_iter()
+            .map(|ns| NamespaceTreeEntry {
+                namespace_id: *ns.namespace_id(),
+                namespace: ns.namespace_ident().clone().inner(),
+                parent_namespace_id: ns.parent_namespaces_id().map(|id| *id),
+                protected: ns.is_protected(),
+            })
+            .collect();
+
+        Ok(ListNamespaceTreeResponse {
+            namespaces,
+            next_page_token,
+        })
+    }
+
+    /// Create multiple namespaces of a warehouse in a single transaction.
+    ///
+    /// Namespaces that already exist are reported and left unchanged. Each namespace
+    /// whose parent exists is authorized like a single create. A namespace whose parent
+    /// is created by the same request is covered by the authorization of that parent.
+    #[allow(clippy::too_many_lines)]
+    async fn create_namespaces_batch(
+        warehouse_id: WarehouseId,
+        request: CreateNamespacesBatchRequest,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<CreateNamespacesBatchResponse> {
+        // ------------------- VALIDATIONS -------------------
+        let CreateNamespacesBatchRequest { namespaces } = request;
+        if namespaces.len() > MAX_NAMESPACES_PER_CREATE_BATCH {
+            return Err(ErrorModel::bad_request(
+                format!(
+                    "Too many namespaces requested: {}. Maximum allowed is {MAX_NAMESPACES_PER_CREATE_BATCH}",
+                    namespaces.len()
+                ),
+                "TooManyNamespaces",
+                None,
+            )
+            .into());
+        }
+        {
+            let mut seen = HashSet::with_capacity(namespaces.len());
+            for entry in &namespaces {
+                validate_new_namespace(&entry.namespace, entry.properties.as_ref())?;
+                if !seen.insert(&entry.namespace) {
+                    return Err(ErrorModel::bad_request(
+                        format!(
+                            "Namespace {:?} is requested more than once",
+                            entry.namespace
+                        ),
+                        "DuplicateNamespaceIdent",
+                        None,
+                    )
+                    .into());
+                }
+            }
+        }
+
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz.clone();
+        let catalog_state = state.v1_state.catalog.clone();
+
+        let mut results = Vec::with_capacity(namespaces.len());
+        // Namespaces to create with their index in the request.
+        let mut to_create: Vec<(
+            usize,
+            NamespaceId,
+            BatchNamespaceParent,
+            ResolvedNamespaceOrWarehouseContext,
+        )> = Vec::new();
+        let mut batch_indices = HashMap::new();
+        let mut warehouse = None;
+        for (index, entry) in namespaces.iter().enumerate() {
+            let namespace = &entry.namespace;
+            if let Some(existing) =
+                C::get_namespace(warehouse_id, namespace.clone(), catalog_state.clone()).await?
+            {
+                results.push(CreateNamespacesBatchResult {
+                    namespace: namespace.clone().inner(),
+                    namespace_id: *existing.namespace_id(),
+                    status: CreateNamespacesBatchStatus::AlreadyExists,
+                });
+                continue;
+            }
+
+            let batch_parent = namespace
+                .parent()
+                .and_then(|parent| batch_indices.get(&parent).copied());
+            let (parent, event_ctx) = if let Some(parent_index) = batch_parent {
+                (
+                    BatchNamespaceParent::Batch(parent_index),
+                    to_create[parent_index].3.clone(),
+                )
+            } else {
+                let properties: Arc<BTreeMap<String, String>> = Arc::new(
+                    entry
+                        .properties
+                        .clone()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .collect(),
+                );
+                let name = namespace.as_ref().last().cloned();
+                let event_ctx = create_namespace_or_warehouse_event_context(
+                    namespace.parent(),
+                    request_metadata.clone(),
+                    state.v1_state.events.clone(),
+                    warehouse_id,
+                    CatalogNamespaceAction::CreateNamespace {
+                        name: name.clone(),
+                        properties: properties.clone(),
+                    },
+                    CatalogWarehouseAction::CreateNamespace {
+                        name,
+                        properties: properties.clone(),
+                    },
+                );
+                let authz_result = authorize_namespace_create::<C, _>(
+                    &authorizer,
+                    event_ctx.request_metadata(),
+                    warehouse_id,
+                    namespace,
+                    catalog_state.clone(),
+                    properties,
+                )
+                .await;
+                let (event_ctx, (resolved_warehouse, parent_namespace)) =
+                    event_ctx.emit_authz(authz_result)?;
+                let event_ctx = match (&parent_namespace, event_ctx) {
+                    (Some(parent), NamespaceOrWarehouseAPIContext::Namespace(ctx)) => {
+                        NamespaceOrWarehouseAPIContext::Namespace(ctx.resolve(ResolvedNamespace {
+                            warehouse: resolved_warehouse.clone(),
+                            namespace: parent.namespace.clone(),
+                        }))
+                    }
+                    (None, NamespaceOrWarehouseAPIContext::Warehouse(ctx)) => {
+                        NamespaceOrWarehouseAPIContext::Warehouse(
+                            ctx.resolve(resolved_warehouse.clone()),
+                        )
+                    }
+                    _ => {
+                        return Err(ErrorModel::internal(
+                            "Inconsistent authorization context after namespace creation authorization. Please report this to the developers.",
+                            "InconsistentAuthZContext",
+                            None,
+                        )
+                        .into());
+                    }
+                };
+                warehouse = Some(resolved_warehouse);
+                (BatchNamespaceParent::Existing(parent_namespace), event_ctx)
+            };
+
+            let namespace_id = NamespaceId::new_random();
+            batch_indices.insert(namespace.clone(), to_create.len());
+            results.push(CreateNamespacesBatchResult {
+                namespace: namespace.clone().inner(),
+                namespace_id: *namespace_id,
+                status: CreateNamespacesBatchStatus::Created,
+            });
+            to_create.push((index, namespace_id, parent, event_ctx));
+        }
+
+        let Some(warehouse) = warehouse else {
+            // Nothing to create
+            return Ok(CreateNamespacesBatchResponse { results });
+        };
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_write(state.v1_state.catalog).await?;
+        let mut created: Vec<(NamespaceHierarchy, ResolvedNamespaceOrWarehouseContext)> =
+            Vec::with_capacity(to_create.len());
+        for (index, namespace_id, parent, event_ctx) in to_create {
+            let CreateNamespacesBatchEntry {
+                namespace,
+                properties,
+            } = &namespaces[index];
+            let parent = match parent {
+                BatchNamespaceParent::Existing(parent) => parent,
+                BatchNamespaceParent::Batch(parent_index) => Some(created[parent_index].0.clone()),
+            };
+
+            let properties = new_namespace_properties(
+                &warehouse,
+                namespace,
+                namespace_id,
+                properties.clone(),
+                parent.as_ref(),
+            )?;
+            C::ensure_warehouse_quota(
+                &warehouse,
+                WarehouseQuotaEntity::Namespace,
+                1,
+                t.transaction(),
+            )
+            .await?;
+            let namespace_with_parent = C::create_namespace(
+                warehouse_id,
+                namespace_id,
+                CreateNamespaceRequest {
+                    namespace: namespace.clone(),
+                    properties: Some(properties),
+                },
+                t.transaction(),
+            )
+            .await?;
+
+            let authz_parent = parent
+                .as_ref()
+                .map_or(NamespaceParent::Warehouse(warehouse_id), |parent| {
+                    NamespaceParent::Namespace(parent.namespace_id())
+                });
+            authorizer
+                .create_namespace(event_ctx.request_metadata(), namespace_id, authz_parent)
+                .await?;
+
+            let parents = parent
+                .map(|parent| {
+                    std::iter::once(parent.namespace)
+                        .chain(parent.parents)
+                        .collect()
+                })
+                .unwrap_or_default();
+            created.push((
+                NamespaceHierarchy {
+                    namespace: namespace_with_parent,
+                    parents,
+                },
+                event_ctx,
+            ));
+        }
+        t.commit().await?;
+
+        for (hierarchy, event_ctx) in created {
+            event_ctx.emit_namespace_created_async(hierarchy.namespace);
+        }
+
+        Ok(CreateNamespacesBatchResponse { results })
+    }
+
     async fn get_namespace_protection(
         namespace_id: NamespaceId,
         warehouse_id: WarehouseId,
@@ -117,3 +1076,68 @@ where
         })
     }
 }
+
+/// Requires permission to drop the namespace at its current location and to create
+/// a namespace with the same name below the new parent.
+async fn authorize_move_namespace<C: CatalogStore, A: Authorizer>(
+    request_metadata: &RequestMetadata,
+    namespace: UserProvidedNamespace,
+    action: CatalogNamespaceAction,
+    new_parent: Option<&NamespaceIdent>,
+    authorizer: &A,
+    catalog_state: C::State,
+) -> std::result::Result<
+    (
+        Arc<ResolvedWarehouse>,
+        NamespaceHierarchy,
+        Option<NamespaceHierarchy>,
+    ),
+    AuthZError,
+> {
+    let warehouse_id = namespace.warehouse_id;
+    let (warehouse, namespace) = authorizer
+        .load_and_authorize_namespace_action::<C>(
+            request_metadata,
+            namespace,
+            action,
+            CachePolicy::Skip,
+            catalog_state.clone(),
+        )
+        .await?;
+
+    let name = namespace.namespace_ident().as_ref().last().cloned();
+    let properties = Arc::new(
+        namespace
+            .properties()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+    );
+
+    let new_parent = if let Some(new_parent) = new_parent {
+        let parent_namespace = C::get_namespace(warehouse_id, new_parent, catalog_state).await;
+        let parent_namespace = authorizer
+            .require_namespace_action(
+                request_metadata,
+                &warehouse,
+                new_parent,
+                parent_namespace,
+                CatalogNamespaceAction::CreateNamespace { name, properties },
+            )
+            .await?;
+        Some(parent_namespace)
+    } else {
+        authorizer
+            .require_warehouse_action(
+                request_metadata,
+                warehouse_id,
+                Ok(Some(warehouse.clone())),
+                CatalogWarehouseAction::CreateNamespace { name, properties },
+            )
+            .await?;
+        None
+    };
+
+    Ok((warehouse, namespace, new_parent))
+}
@@ -52,13 +52,16 @@ use crate::{
         super::activate_warehouse,
         super::batch_check_actions,
         super::bootstrap,
+        super::clone_warehouse,
         super::control_tasks,
         super::control_project_tasks,
+        super::create_namespaces_batch,
         super::create_project,
         super::create_role,
         super::create_user,
         super::create_warehouse,
         super::deactivate_warehouse,
+        super::delete_endpoint_statistics,
         super::delete_project_by_id_deprecated,
         super::delete_project,
         super::delete_role,
@@ -66,7 +69,9 @@ use crate::{
         super::delete_warehouse,
         super::get_endpoint_statistics,
         super::get_namespace_actions,
+        super::get_namespace_delete_profile,
         super::get_namespace_protection,
+        super::get_namespace_table_defaults,
         super::get_project_actions,
         super::get_project_by_id_deprecated,
         super::get_project,
@@ -78,7 +83,10 @@ use crate::{
         super::get_server_actions,
         super::get_server_info,
         super::get_table_actions,
+        super::get_table_metadata_location,
         super::get_table_protection,
+        super::get_table_schema_diff,
+        super::get_table_storage_usage,
         super::get_task_details,
         super::get_task_queue_config,
         super::get_user_actions,
@@ -87,11 +95,16 @@ use crate::{
         super::get_generic_table_actions,
         super::get_generic_table_protection,
         super::get_view_protection,
+        super::get_view_versions,
         super::get_warehouse_actions,
+        super::get_warehouse_catalog_config,
         super::get_warehouse_statistics,
+        super::get_warehouse_version,
         super::get_warehouse,
         super::list_deleted_tabulars,
+        super::list_namespace_tree,
         super::list_projects,
+        super::list_protected_entities,
         super::list_project_tasks,
         super::list_roles,
         super::list_role_members,
@@ -102,16 +115,28 @@ use crate::{
         super::list_role_transitive_members,
         super::list_user_transitive_roles,
         super::list_role_transitive_member_of,
+        super::list_table_snapshots,
+        super::load_tables_batch,
         super::list_tasks,
         super::list_user,
+        super::list_views,
         super::list_warehouses,
+        super::merge_secondary_storage_profile,
+        super::merge_storage_profile,
+        super::move_namespace,
+        super::purge_deleted_tabular,
+        super::protect_all_namespace_tabulars,
+        super::query_audit_log,
         super::rename_project_by_id_deprecated,
         super::rename_project,
         super::rename_warehouse,
+        super::rollback_view,
         super::search_role,
         super::search_tabular,
         super::search_user,
+        super::set_namespace_delete_profile,
         super::set_namespace_protection,
+        super::set_namespace_table_defaults,
         super::set_project_task_queue_config,
         super::set_generic_table_protection,
         super::set_table_protection,
@@ -120,14 +145,18 @@ use crate::{
         super::set_view_protection,
         super::set_warehouse_protection,
         super::set_warehouse_managed_by,
+        super::touch_table,
         super::undrop_tabulars,
         super::update_role_source_system,
         super::update_role,
+        super::update_secondary_storage_profile,
         super::update_storage_credential,
         super::update_storage_profile,
         super::update_user,
         super::update_warehouse_delete_profile,
         super::update_warehouse_format_version_policy,
+        super::update_warehouse_settings,
+        super::validate_storage,
         super::whoami,
     ),
     components(schemas(
@@ -55,6 +55,9 @@ pub struct GetProjectResponse {
     pub project_id: ArcProjectId,
     /// Name of the project
     pub project_name: String,
+    /// Whether warehouse names in this project are case-sensitive.
+    /// If `false`, `warehouse1` and `Warehouse1` refer to the same warehouse.
+    pub case_sensitive_warehouse_names: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -83,6 +86,10 @@ pub struct CreateProjectRequest {
     /// If not provided, a new project ID will be generated (recommended).
     #[cfg_attr(feature = "open-api", schema(value_type = Option::<String>))]
     pub project_id: Option<ProjectId>,
+    /// Treat warehouse names that differ only in case as distinct
+    /// warehouses. Defaults to `false`.
+    #[serde(default)]
+    pub case_sensitive_warehouse_names: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +125,7 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         let CreateProjectRequest {
             project_name,
             project_id,
+            case_sensitive_warehouse_names,
         } = request;
 
         // Create event context for tracking authorization and operation events
@@ -143,6 +151,10 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         let mut t = C::Transaction::begin_write(context.v1_state.catalog).await?;
         let project_id = Arc::new(project_id.unwrap_or(ProjectId::from(uuid::Uuid::now_v7())));
         C::create_project(&project_id, project_name.clone(), t.transaction()).await?;
+        if case_sensitive_warehouse_names {
+            C::set_project_case_sensitive_warehouse_names(&project_id, true, t.transaction())
+                .await?;
+        }
         authorizer
             .create_project(event_ctx.request_metadata(), &project_id)
             .await?;
@@ -246,6 +258,7 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         Ok(GetProjectResponse {
             project_id,
             project_name: project.name,
+            case_sensitive_warehouse_names: project.case_sensitive_warehouse_names,
         })
     }
 
@@ -347,6 +360,7 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
                 .map(|project| GetProjectResponse {
                     project_id: project.project_id,
                     project_name: project.name,
+                    case_sensitive_warehouse_names: project.case_sensitive_warehouse_names,
                 })
                 .collect(),
         })
@@ -419,6 +433,59 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         .await
     }
 
+    async fn delete_endpoint_statistics(
+        context: ApiContext<State<A, C, S>>,
+        query: DeleteEndpointStatisticsQuery,
+        request_metadata: RequestMetadata,
+    ) -> Result<()> {
+        let project_id = request_metadata.require_project_id(None)?;
+        if let (Some(from), Some(until)) = (query.from, query.until)
+            && from >= until
+        {
+            return Err(ErrorModel::bad_request(
+                "`from` must be before `until`",
+                "InvalidTimeRange",
+                None,
+            )
+            .into());
+        }
+
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+        let event_ctx = APIEventContext::for_project_arc(
+            Arc::new(request_metadata),
+            context.v1_state.events,
+            project_id.clone(),
+            Arc::new(CatalogProjectAction::DeleteEndpointStatistics),
+        );
+        let authz_result = authorizer
+            .require_project_action(
+                event_ctx.request_metadata(),
+                &project_id,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (_event_ctx, ()) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- Business Logic -------------------
+        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
+        let deleted = C::delete_endpoint_statistics(
+            &project_id,
+            query.from,
+            query.until,
+            transaction.transaction(),
+        )
+        .await?;
+        transaction.commit().await?;
+        tracing::info!(
+            "Deleted {deleted} endpoint statistic rows of project '{project_id}' within [{:?}, {:?})",
+            query.from,
+            query.until
+        );
+
+        Ok(())
+    }
+
     async fn set_project_task_queue_config(
         queue_name: &TaskQueueName,
         request: SetTaskQueueConfigRequest,
@@ -600,6 +667,20 @@ pub struct GetEndpointStatisticsRequest {
     pub range_specifier: Option<TimeWindowSelector>,
 }
 
+#[derive(Deserialize, Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+#[serde(rename_all = "kebab-case")]
+pub struct DeleteEndpointStatisticsQuery {
+    /// Only delete statistics of time buckets at or after this timestamp.
+    /// If omitted, all statistics before `until` are deleted.
+    #[serde(default)]
+    pub from: Option<chrono::DateTime<Utc>>,
+    /// Only delete statistics of time buckets before this timestamp.
+    /// If omitted, all statistics from `from` on are deleted.
+    #[serde(default)]
+    pub until: Option<chrono::DateTime<Utc>>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
 #[serde(rename_all = "kebab-case", tag = "type")]
@@ -287,6 +287,25 @@ pub struct ListRoleMembershipsResponse {
     pub next_page_token: Option<String>,
 }
 
+/// One page of the roles a user is directly assigned to, plus the roles mapped
+/// from the groups of their identity provider token.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ListUserRolesResponse {
+    /// Roles the user is directly assigned to.
+    pub roles: Vec<RoleMembership>,
+    /// Roles of the project mapped from the groups in the user's token, in
+    /// mapping order. Not paginated. Group memberships are only known from the
+    /// token of the current request, so this is `null` unless users list their
+    /// own roles and a groups claim is configured.
+    pub group_roles: Option<Vec<RoleMembership>>,
+    /// Token for the next page of `roles`; `null`/absent once the listing is
+    /// exhausted.
+    #[serde(alias = "next_page_token")]
+    pub next_page_token: Option<String>,
+}
+
 /// Query parameters for `GET /role/{id}/members`.
 #[derive(Debug, Clone, Deserialize)]
 #[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
@@ -356,6 +375,12 @@ impl IntoResponse for ListRoleMembershipsResponse {
     }
 }
 
+impl IntoResponse for ListUserRolesResponse {
+    fn into_response(self) -> axum::response::Response {
+        (StatusCode::OK, Json(self)).into_response()
+    }
+}
+
 // ─── helpers ──────────────────────────────────────────────────────────────────
 
 /// Parse a member id into its typed identifier.
@@ -961,15 +986,23 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         })
     }
 
-    /// `GET /user/{id}/roles` — the roles a user is directly assigned to.
+    /// `GET /user/{id}/roles` — the roles a user is directly assigned to, and
+    /// the roles mapped from the groups in their token if they list their own.
     async fn list_user_roles(
         context: ApiContext<State<A, C, S>>,
         request_metadata: RequestMetadata,
         user_id: UserId,
         query: ListRolesPageQuery,
-    ) -> Result<ListRoleMembershipsResponse> {
+    ) -> Result<ListUserRolesResponse> {
         let project_id = request_metadata.require_project_id(None)?;
         let authorizer = context.v1_state.authz;
+        let catalog = context.v1_state.catalog;
+        // Group memberships are only known from the token of the current request.
+        let group_role_ids = if request_metadata.user_id() == Some(&user_id) {
+            request_metadata.group_roles().map(<[RoleId]>::to_vec)
+        } else {
+            None
+        };
 
         let event_ctx = APIEventContext::for_user(
             request_metadata.into(),
@@ -991,40 +1024,62 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         // Unlike the catalog reader, OpenFGA cannot prove a user does not exist, so
         // this arm is tolerant: an unknown user yields an empty page (200), never a
         // 404 — a user is just a subject with no assignment tuples here.
-        if let Some(assignments) = authorizer.role_assignments() {
-            let (roles, next_page_token) = read_assignee_roles_hydrated::<C>(
+        let (roles, next_page_token) = if let Some(assignments) = authorizer.role_assignments() {
+            read_assignee_roles_hydrated::<C>(
                 assignments,
                 event_ctx.request_metadata(),
                 project_id.clone(),
                 UserOrRoleId::User(user_id.clone()),
                 query.pagination_query(),
-                context.v1_state.catalog,
+                catalog.clone(),
             )
-            .await?;
-            return Ok(ListRoleMembershipsResponse {
-                roles,
-                next_page_token,
-            });
-        }
-        // The catalog reader returns `None` for a user with no catalog row → 404;
-        // `Some(page)` is a user that exists (page may be empty → 200).
-        let page = C::list_direct_user_roles_page(
-            &project_id,
-            &user_id,
-            query.pagination_query(),
-            context.v1_state.catalog,
-        )
-        .await?
-        .ok_or_else(|| {
-            ErrorModel::not_found(
-                format!("User with id {user_id} not found or not provisioned."),
-                "UserNotFound",
-                None,
+            .await?
+        } else {
+            // The catalog reader returns `None` for a user with no catalog row → 404;
+            // `Some(page)` is a user that exists (page may be empty → 200).
+            let page = C::list_direct_user_roles_page(
+                &project_id,
+                &user_id,
+                query.pagination_query(),
+                catalog.clone(),
             )
-        })?;
-        Ok(ListRoleMembershipsResponse {
-            roles: page.entries.into_iter().map(RoleMembership::from).collect(),
-            next_page_token: page.next_page_token,
+            .await?
+            .ok_or_else(|| {
+                ErrorModel::not_found(
+                    format!("User with id {user_id} not found or not provisioned."),
+                    "UserNotFound",
+                    None,
+                )
+            })?;
+            (
+                page.entries.into_iter().map(RoleMembership::from).collect(),
+                page.next_page_token,
+            )
+        };
+
+        let group_roles = match group_role_ids {
+            Some(role_ids) => {
+                let mut roles = fetch_roles_by_ids::<C>(&role_ids, catalog).await?;
+                Some(
+                    role_ids
+                        .iter()
+                        .filter_map(|role_id| roles.remove(role_id))
+                        .filter(|role| role.project_id() == &*project_id)
+                        .map(|role| RoleMembership {
+                            id: role.id,
+                            ident: role.ident_arc(),
+                            name: role.name.clone(),
+                        })
+                        .collect(),
+                )
+            }
+            None => None,
+        };
+
+        Ok(ListUserRolesResponse {
+            roles,
+            group_roles,
+            next_page_token,
         })
     }
 
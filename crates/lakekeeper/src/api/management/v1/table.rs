@@ -1,17 +1,187 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
+
+use iceberg::{
+    TableIdent,
+    spec::{NestedFieldRef, Schema, Summary, TableMetadata, Type},
+};
+use iceberg_ext::catalog::rest::{ErrorModel, LoadTableResult};
+use serde::{Deserialize, Serialize};
 
 use super::{ApiServer, ProtectionResponse};
 use crate::{
     WarehouseId,
-    api::{ApiContext, RequestMetadata, Result},
+    api::{
+        ApiContext, RequestMetadata, Result,
+        iceberg::v1::tables::{DataAccessMode, LoadTableFilters},
+    },
+    server::tables::{load_table, storage_usage},
     service::{
-        CatalogStore, CatalogTabularOps, SecretStore, State, TableId, TabularId, TabularListFlags,
-        Transaction,
+        CatalogStore, CatalogTableOps, CatalogTabularOps, SecretStore, State, TableId, TabularId,
+        TabularListFlags, TabularNotFound, Transaction,
         authz::{AuthZTableOps, Authorizer, CatalogTableAction},
         events::APIEventContext,
     },
 };
 
+#[derive(Debug, Clone, Deserialize, Default)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct ListTableSnapshotsQuery {
+    /// Next page token
+    #[serde(default)]
+    pub page_token: Option<String>,
+    /// Signals an upper bound of the number of results that a client will receive.
+    /// Default: 100
+    #[serde(default)]
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct TableSnapshot {
+    /// ID of the snapshot
+    pub snapshot_id: i64,
+    /// ID of the snapshot this snapshot was derived from
+    pub parent_snapshot_id: Option<i64>,
+    /// Sequence number of the snapshot
+    pub sequence_number: i64,
+    /// Time the snapshot was created, in milliseconds since the unix epoch
+    pub timestamp_ms: i64,
+    /// ID of the table's current schema when the snapshot was created
+    pub schema_id: i32,
+    /// Operation that produced the snapshot along with its summary properties
+    #[cfg_attr(feature = "open-api", schema(value_type = Object))]
+    pub summary: Summary,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ListTableSnapshotsResponse {
+    /// Snapshots of the table, newest first
+    pub snapshots: Vec<TableSnapshot>,
+    /// Token for the next page of results
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadTablesBatchRequest {
+    /// Tables to load, at most 100. Each table may only be listed once.
+    #[cfg_attr(feature = "open-api", schema(value_type = Vec<Object>))]
+    pub tables: Vec<TableIdent>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadTablesBatchResponse {
+    /// Loaded tables in the order of the request. Each entry has the same
+    /// format as the response of the Iceberg REST `loadTable` endpoint.
+    #[cfg_attr(feature = "open-api", schema(value_type = Vec<Object>))]
+    pub tables: Vec<LoadTableResult>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct TableSchemaDiffQuery {
+    /// ID of the snapshot whose schema is the base of the diff
+    pub from: i64,
+    /// ID of the snapshot whose schema is compared against the base
+    pub to: i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct SchemaDiffColumn {
+    /// Field ID of the column
+    pub field_id: i32,
+    /// Full name of the column, nested fields are separated by `.`
+    pub name: String,
+    /// Type of the column. Nested types are reported as `struct`, `list` or `map`.
+    pub r#type: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct RenamedColumn {
+    /// Field ID of the column
+    pub field_id: i32,
+    /// Full name of the column in the `from` schema
+    pub from_name: String,
+    /// Full name of the column in the `to` schema
+    pub to_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct RetypedColumn {
+    /// Field ID of the column
+    pub field_id: i32,
+    /// Full name of the column in the `to` schema
+    pub name: String,
+    /// Type of the column in the `from` schema
+    pub from_type: String,
+    /// Type of the column in the `to` schema
+    pub to_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct TableSchemaDiffResponse {
+    /// ID of the schema of the `from` snapshot
+    pub from_schema_id: i32,
+    /// ID of the schema of the `to` snapshot
+    pub to_schema_id: i32,
+    /// Columns that only exist in the `to` schema, ordered by field ID
+    pub added_columns: Vec<SchemaDiffColumn>,
+    /// Columns that only exist in the `from` schema, ordered by field ID
+    pub removed_columns: Vec<SchemaDiffColumn>,
+    /// Columns whose name changed, ordered by field ID
+    pub renamed_columns: Vec<RenamedColumn>,
+    /// Columns whose type changed, ordered by field ID
+    pub retyped_columns: Vec<RetypedColumn>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct TableStorageUsageResponse {
+    /// ID of the snapshot the usage was computed for.
+    /// Not set if the table has no current snapshot.
+    pub snapshot_id: Option<i64>,
+    /// Total size in bytes of all data and delete files of the snapshot
+    pub total_bytes: u64,
+    /// Number of data and delete files of the snapshot
+    pub file_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadataLocationResponse {
+    /// Location of the current metadata file of the table.
+    /// Not set if the table is staged.
+    pub metadata_location: Option<String>,
+    /// Whether the table is staged, i.e. created but not yet committed
+    pub staged: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct TouchTableResponse {
+    /// New `updated_at` of the table
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
 impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> TableManagementService<C, A, S>
     for ApiServer<C, A, S>
 {
@@ -100,4 +270,482 @@ where
             updated_at: table.updated_at,
         })
     }
+
+    async fn get_table_metadata_location(
+        table_id: TableId,
+        warehouse_id: WarehouseId,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<TableMetadataLocationResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_table(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            table_id,
+            CatalogTableAction::GetMetadata,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_table_operation::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity(),
+                TabularListFlags::active_and_staged(),
+                event_ctx.action().clone(),
+                state.v1_state.catalog,
+            )
+            .await;
+        let (_event_ctx, (_, _, table)) = event_ctx.emit_authz(authz_result)?;
+
+        // The table info loaded for authorization already carries the stored
+        // metadata location, the table metadata itself is never read.
+        Ok(TableMetadataLocationResponse {
+            staged: table.metadata_location.is_none(),
+            metadata_location: table.metadata_location.map(|l| l.to_string()),
+        })
+    }
+
+    async fn touch_table(
+        table_id: TableId,
+        warehouse_id: WarehouseId,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<TouchTableResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+        let state_catalog = state.v1_state.catalog;
+
+        let event_ctx = APIEventContext::for_table(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            table_id,
+            CatalogTableAction::Commit {
+                updated_properties: Arc::default(),
+                removed_properties: Arc::default(),
+            },
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_table_operation::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity(),
+                TabularListFlags::active(),
+                event_ctx.action().clone(),
+                state_catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _table) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_write(state_catalog).await?;
+        let updated_at =
+            C::touch_tabular(warehouse_id, TabularId::Table(table_id), t.transaction()).await?;
+        t.commit().await?;
+
+        Ok(TouchTableResponse { updated_at })
+    }
+
+    async fn list_table_snapshots(
+        table_id: TableId,
+        warehouse_id: WarehouseId,
+        query: ListTableSnapshotsQuery,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<ListTableSnapshotsResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_table(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            table_id,
+            CatalogTableAction::GetMetadata,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_table_operation::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity(),
+                TabularListFlags::active(),
+                event_ctx.action().clone(),
+                state.v1_state.catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _table) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        C::list_table_snapshots(warehouse_id, table_id, &query, state.v1_state.catalog).await
+    }
+
+    async fn load_tables_batch(
+        warehouse_id: WarehouseId,
+        request: LoadTablesBatchRequest,
+        data_access: DataAccessMode,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<LoadTablesBatchResponse> {
+        let LoadTablesBatchRequest { tables } = request;
+        let tables = load_table::load_tables_batch(
+            warehouse_id,
+            tables,
+            data_access,
+            state,
+            request_metadata,
+        )
+        .await?;
+        Ok(LoadTablesBatchResponse { tables })
+    }
+
+    async fn get_table_schema_diff(
+        table_id: TableId,
+        warehouse_id: WarehouseId,
+        query: TableSchemaDiffQuery,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<TableSchemaDiffResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_table(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            table_id,
+            CatalogTableAction::GetMetadata,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_table_operation::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity(),
+                TabularListFlags::active(),
+                event_ctx.action().clone(),
+                state.v1_state.catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _table) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_read(state.v1_state.catalog).await?;
+        let loaded = C::load_tables(
+            warehouse_id,
+            [table_id],
+            false,
+            &LoadTableFilters::default(),
+            t.transaction(),
+        )
+        .await?;
+        t.commit().await?;
+        let metadata = loaded
+            .into_iter()
+            .next()
+            .ok_or_else(|| TabularNotFound::new(warehouse_id, table_id))?
+            .table_metadata;
+
+        let from = snapshot_schema(&metadata, query.from)?;
+        let to = snapshot_schema(&metadata, query.to)?;
+        Ok(diff_schemas(from, to))
+    }
+
+    async fn get_table_storage_usage(
+        table_id: TableId,
+        warehouse_id: WarehouseId,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<TableStorageUsageResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_table(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            table_id,
+            CatalogTableAction::GetMetadata,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_table_operation::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity(),
+                TabularListFlags::active(),
+                event_ctx.action().clone(),
+                state.v1_state.catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, (warehouse, _, _)) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_read(state.v1_state.catalog).await?;
+        let loaded = C::load_tables(
+            warehouse_id,
+            [table_id],
+            false,
+            &LoadTableFilters::default(),
+            t.transaction(),
+        )
+        .await?;
+        t.commit().await?;
+        let metadata = loaded
+            .into_iter()
+            .next()
+            .ok_or_else(|| TabularNotFound::new(warehouse_id, table_id))?
+            .table_metadata;
+
+        storage_usage::table_storage_usage(&warehouse, table_id, &metadata, &state.v1_state.secrets)
+            .await
+    }
+}
+
+fn snapshot_schema(metadata: &TableMetadata, snapshot_id: i64) -> Result<&Schema> {
+    let snapshot = metadata.snapshot_by_id(snapshot_id).ok_or_else(|| {
+        ErrorModel::not_found(
+            format!("Snapshot {snapshot_id} does not exist"),
+            "NoSuchSnapshotException",
+            None,
+        )
+    })?;
+    let schema_id = snapshot.schema_id().ok_or_else(|| {
+        ErrorModel::bad_request(
+            format!("Snapshot {snapshot_id} does not reference a schema"),
+            "SnapshotSchemaUnknown",
+            None,
+        )
+    })?;
+    let schema = metadata.schema_by_id(schema_id).ok_or_else(|| {
+        ErrorModel::internal(
+            format!("Schema {schema_id} of snapshot {snapshot_id} does not exist"),
+            "SnapshotSchemaNotFound",
+            None,
+        )
+    })?;
+    Ok(schema.as_ref())
+}
+
+/// A column of a schema, flattened by field ID.
+struct FlatColumn {
+    path: String,
+    name: String,
+    r#type: String,
+}
+
+fn diff_schemas(from: &Schema, to: &Schema) -> TableSchemaDiffResponse {
+    let mut diff = TableSchemaDiffResponse {
+        from_schema_id: from.schema_id(),
+        to_schema_id: to.schema_id(),
+        added_columns: vec![],
+        removed_columns: vec![],
+        renamed_columns: vec![],
+        retyped_columns: vec![],
+    };
+    // Schemas are immutable, snapshots sharing a schema have nothing to diff.
+    if diff.from_schema_id == diff.to_schema_id {
+        return diff;
+    }
+
+    let from_columns = flatten_schema(from);
+    let to_columns = flatten_schema(to);
+    for (&field_id, column) in &from_columns {
+        let Some(new_column) = to_columns.get(&field_id) else {
+            diff.removed_columns.push(SchemaDiffColumn {
+                field_id,
+                name: column.path.clone(),
+                r#type: column.r#type.clone(),
+            });
+            continue;
+        };
+        // Only report the renamed field itself, not all fields nested below it.
+        if column.name != new_column.name {
+            diff.renamed_columns.push(RenamedColumn {
+                field_id,
+                from_name: column.path.clone(),
+                to_name: new_column.path.clone(),
+            });
+        }
+        if column.r#type != new_column.r#type {
+            diff.retyped_columns.push(RetypedColumn {
+                field_id,
+                name: new_column.path.clone(),
+                from_type: column.r#type.clone(),
+                to_type: new_column.r#type.clone(),
+            });
+        }
+    }
+    diff.added_columns = to_columns
+        .into_iter()
+        .filter(|(field_id, _)| !from_columns.contains_key(field_id))
+        .map(|(field_id, column)| SchemaDiffColumn {
+            field_id,
+            name: column.path,
+            r#type: column.r#type,
+        })
+        .collect();
+    diff
+}
+
+fn flatten_schema(schema: &Schema) -> BTreeMap<i32, FlatColumn> {
+    let mut columns = BTreeMap::new();
+    for field in schema.as_struct().fields() {
+        flatten_field(field, "", &mut columns);
+    }
+    columns
+}
+
+fn flatten_field(field: &NestedFieldRef, prefix: &str, columns: &mut BTreeMap<i32, FlatColumn>) {
+    let path = format!("{prefix}{}", field.name);
+    let r#type = match field.field_type.as_ref() {
+        Type::Primitive(p) => p.to_string(),
+        Type::Struct(s) => {
+            for nested in s.fields() {
+                flatten_field(nested, &format!("{path}."), columns);
+            }
+            "struct".to_string()
+        }
+        Type::List(l) => {
+            flatten_field(&l.element_field, &format!("{path}."), columns);
+            "list".to_string()
+        }
+        Type::Map(m) => {
+            flatten_field(&m.key_field, &format!("{path}."), columns);
+            flatten_field(&m.value_field, &format!("{path}."), columns);
+            "map".to_string()
+        }
+    };
+    columns.insert(
+        field.id,
+        FlatColumn {
+            path,
+            name: field.name.clone(),
+            r#type,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg::spec::{ListType, NestedField, PrimitiveType, StructType};
+
+    use super::*;
+
+    fn schema(schema_id: i32, fields: Vec<NestedField>) -> Schema {
+        Schema::builder()
+            .with_schema_id(schema_id)
+            .with_fields(fields.into_iter().map(Arc::new))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_diff_of_same_schema_is_empty() {
+        let from = schema(
+            0,
+            vec![NestedField::required(
+                1,
+                "id",
+                Type::Primitive(PrimitiveType::Int),
+            )],
+        );
+
+        let diff = diff_schemas(&from, &from);
+        assert_eq!(diff.from_schema_id, 0);
+        assert_eq!(diff.to_schema_id, 0);
+        assert!(diff.added_columns.is_empty());
+        assert!(diff.removed_columns.is_empty());
+        assert!(diff.renamed_columns.is_empty());
+        assert!(diff.retyped_columns.is_empty());
+    }
+
+    #[test]
+    fn test_diff_schemas() {
+        let address = |city_name: &str| {
+            Type::Struct(StructType::new(vec![
+                NestedField::optional(4, city_name, Type::Primitive(PrimitiveType::String)).into(),
+            ]))
+        };
+        let from = schema(
+            0,
+            vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Int)),
+                NestedField::optional(2, "name", Type::Primitive(PrimitiveType::String)),
+                NestedField::optional(3, "address", address("city")),
+                NestedField::optional(5, "legacy", Type::Primitive(PrimitiveType::Boolean)),
+            ],
+        );
+        let to = schema(
+            1,
+            vec![
+                NestedField::required(1, "id", Type::Primitive(PrimitiveType::Long)),
+                NestedField::optional(2, "full_name", Type::Primitive(PrimitiveType::String)),
+                NestedField::optional(3, "location", address("town")),
+                NestedField::optional(
+                    6,
+                    "tags",
+                    Type::List(ListType::new(
+                        NestedField::list_element(7, Type::Primitive(PrimitiveType::String), true)
+                            .into(),
+                    )),
+                ),
+            ],
+        );
+
+        let diff = diff_schemas(&from, &to);
+        assert_eq!(diff.from_schema_id, 0);
+        assert_eq!(diff.to_schema_id, 1);
+        assert_eq!(
+            diff.added_columns,
+            vec![
+                SchemaDiffColumn {
+                    field_id: 6,
+                    name: "tags".to_string(),
+                    r#type: "list".to_string(),
+                },
+                SchemaDiffColumn {
+                    field_id: 7,
+                    name: "tags.element".to_string(),
+                    r#type: "string".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.removed_columns,
+            vec![SchemaDiffColumn {
+                field_id: 5,
+                name: "legacy".to_string(),
+                r#type: "boolean".to_string(),
+            }]
+        );
+        assert_eq!(
+            diff.renamed_columns,
+            vec![
+                RenamedColumn {
+                    field_id: 2,
+                    from_name: "name".to_string(),
+                    to_name: "full_name".to_string(),
+                },
+                RenamedColumn {
+                    field_id: 3,
+                    from_name: "address".to_string(),
+                    to_name: "location".to_string(),
+                },
+                RenamedColumn {
+                    field_id: 4,
+                    from_name: "address.city".to_string(),
+                    to_name: "location.town".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            diff.retyped_columns,
+            vec![RetypedColumn {
+                field_id: 1,
+                name: "id".to_string(),
+                from_type: "int".to_string(),
+                to_type: "long".to_string(),
+            }]
+        );
+    }
 }
@@ -385,7 +385,8 @@ pub enum TaskStatus {
     Cancelled,
     /// Task completed successfully. This is a final state.
     Success,
-    /// Task failed. This is a final state.
+    /// Task failed, either because it exhausted its retries or because the failure is permanent.
+    /// This is a final state. The task can be requeued with the `requeue` control action.
     Failed,
 }
 
@@ -528,7 +529,8 @@ pub struct ListTasksRequest {
     #[cfg_attr(feature = "open-api", schema(value_type = Option<Vec<String>>))]
     #[builder(default)]
     pub queue_name: Option<Vec<TaskQueueName>>,
-    /// Filter by specific entity
+    /// Filter by one or more entities, e.g. to list all tasks of a table.
+    /// Tasks matching any of the given entities are returned.
     #[serde(default)]
     #[builder(default)]
     pub entities: Option<Vec<WarehouseTaskEntityFilter>>,
@@ -646,6 +648,20 @@ pub enum ControlTaskAction {
         #[serde(alias = "scheduled_for")]
         scheduled_for: chrono::DateTime<chrono::Utc>,
     },
+    /// Defer the task to the specified time, moving the `scheduled_for` time to the provided timestamp.
+    /// Affects only tasks in `Scheduled` or `Stopping` state, including tasks waiting for a retry after a failed attempt.
+    /// Unlike `run-at`, the request fails with `409 TaskAlreadySucceeded` if any of the tasks already completed successfully.
+    /// Timestamps must be in RFC 3339 format.
+    #[serde(rename_all = "kebab-case")]
+    Reschedule {
+        /// The time to run the task at
+        #[cfg_attr(feature = "open-api", schema(example = "2025-12-31T23:59:59Z"))]
+        run_at: chrono::DateTime<chrono::Utc>,
+    },
+    /// Requeue a task that failed permanently, i.e. exhausted its retries or hit a permanent failure.
+    /// The task keeps its id and attempt history, is scheduled to run immediately and gets its full retry budget back.
+    /// Fails with `409 TaskNotRequeueable` if any of the tasks is not in `Failed` state or another task is active for the same entity and queue.
+    Requeue,
 }
 
 // -------------------- SERVICE TRAIT --------------------
@@ -846,6 +862,10 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
             ControlTaskAction::RunAt { scheduled_for } => {
                 C::run_tasks_at(task_ids, Some(scheduled_for), t.transaction()).await?;
             }
+            ControlTaskAction::Reschedule { run_at } => {
+                C::reschedule_tasks(task_ids, run_at, t.transaction()).await?;
+            }
+            ControlTaskAction::Requeue => C::requeue_tasks(task_ids, t.transaction()).await?,
         }
         t.commit().await?;
 
@@ -1092,6 +1112,10 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
             ControlTaskAction::RunAt { scheduled_for } => {
                 C::run_tasks_at(&task_ids, Some(scheduled_for), t.transaction()).await?;
             }
+            ControlTaskAction::Reschedule { run_at } => {
+                C::reschedule_tasks(&task_ids, run_at, t.transaction()).await?;
+            }
+            ControlTaskAction::Requeue => C::requeue_tasks(&task_ids, t.transaction()).await?,
         }
         t.commit().await?;
 
@@ -1750,6 +1774,36 @@ mod test {
         assert_eq!(deserialized, request);
     }
 
+    #[test]
+    fn test_control_task_request_reschedule_serde() {
+        let request = ControlTasksRequest {
+            action: ControlTaskAction::Reschedule {
+                run_at: "2025-12-31T23:59:59Z"
+                    .parse()
+                    .expect("Failed to parse datetime"),
+            },
+            task_ids: vec![TaskId::from(
+                uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+            )],
+        };
+        let request_json = serde_json::json!({
+            "action": {
+                "action-type": "reschedule",
+                "run-at": "2025-12-31T23:59:59Z"
+            },
+            "task-ids": ["550e8400-e29b-41d4-a716-446655440000"]
+        });
+
+        assert_eq!(
+            serde_json::to_value(&request).expect("Failed to serialize"),
+            request_json
+        );
+
+        let deserialized: ControlTasksRequest =
+            serde_json::from_value(request_json).expect("Failed to deserialize");
+        assert_eq!(deserialized, request);
+    }
+
     mod schedule_static_validation {
         use super::super::{MAX_SCHEDULE_HORIZON_DAYS, validate_schedule_request_static_checks};
         use crate::{
@@ -1,17 +1,130 @@
 use std::sync::Arc;
 
+use futures::FutureExt as _;
+use iceberg::spec::{ViewMetadata, ViewVersion, ViewVersionLog};
+use iceberg_ext::catalog::{AssertViewUuid, ViewRequirement, rest::ViewUpdate};
+use itertools::Itertools as _;
+use serde::{Deserialize, Serialize};
+
 use super::{ApiServer, ProtectionResponse};
 use crate::{
     WarehouseId,
-    api::{ApiContext, RequestMetadata, Result},
+    api::{
+        ApiContext, RequestMetadata, Result,
+        iceberg::v1::{
+            CommitViewRequest, DataAccessMode, ErrorModel, PageToken, PaginationQuery, Prefix,
+            ViewParameters,
+        },
+    },
+    server::{UnfilteredPage, fetch_until_full_page, views::commit::commit_view},
     service::{
-        CatalogStore, CatalogTabularOps, SecretStore, State, TabularId, TabularListFlags,
+        CatalogNamespaceOps, CatalogStore, CatalogTabularOps, CatalogViewOps, CatalogWarehouseOps,
+        NamespaceId, ResolvedWarehouse, SecretStore, State, TabularId, TabularListFlags,
         Transaction, ViewId,
-        authz::{AuthZViewOps, Authorizer, CatalogViewAction},
-        events::APIEventContext,
+        authz::{
+            ActionOnView, AuthZCannotUseWarehouseId, AuthZViewOps, Authorizer, AuthzWarehouseOps,
+            CatalogViewAction, CatalogWarehouseAction, RequireWarehouseActionError,
+        },
+        events::{
+            APIEventContext,
+            context::{WarehouseActionListViews, authz_to_error_no_audit},
+        },
+        require_namespace_for_tabular,
     },
 };
 
+#[derive(Debug, Clone, Deserialize, Default)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct ListViewsQuery {
+    /// Only list views of this namespace. Views of child namespaces are not included.
+    #[serde(default)]
+    #[cfg_attr(feature = "open-api", param(value_type = uuid::Uuid))]
+    pub namespace_id: Option<NamespaceId>,
+    /// Next page token
+    #[serde(default)]
+    pub page_token: Option<String>,
+    /// Signals an upper bound of the number of results that a client will receive.
+    /// Default: 100
+    #[serde(default)]
+    pub page_size: Option<i64>,
+}
+
+impl ListViewsQuery {
+    #[must_use]
+    pub fn pagination_query(&self) -> PaginationQuery {
+        PaginationQuery {
+            page_token: self
+                .page_token
+                .clone()
+                .map_or(PageToken::Empty, PageToken::Present),
+            page_size: self.page_size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ListViewsResponse {
+    /// Views visible to the current user
+    pub views: Vec<ViewSummary>,
+    /// Token for the next page of results
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ViewSummary {
+    /// ID of the view
+    pub view_id: uuid::Uuid,
+    /// List of namespace parts the view belongs to
+    pub namespace: Vec<String>,
+    /// Name of the view
+    pub name: String,
+    /// Location of the metadata file of the current view version
+    pub metadata_location: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct RollbackViewRequest {
+    /// Version of the view to make the current version again.
+    /// Must be one of the versions returned by the view versions endpoint.
+    pub version_id: i32,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct GetViewVersionsResponse {
+    /// ID of the current version of the view
+    pub current_version_id: i32,
+    /// All versions of the view that are retained in its metadata
+    #[cfg_attr(feature = "open-api", schema(value_type = Vec<Object>))]
+    pub versions: Vec<ViewVersion>,
+    /// History of the current version, oldest first
+    #[cfg_attr(feature = "open-api", schema(value_type = Vec<Object>))]
+    pub version_log: Vec<ViewVersionLog>,
+}
+
+impl From<&ViewMetadata> for GetViewVersionsResponse {
+    fn from(metadata: &ViewMetadata) -> Self {
+        let mut versions = metadata
+            .versions()
+            .map(|v| v.as_ref().clone())
+            .collect::<Vec<_>>();
+        versions.sort_by_key(ViewVersion::version_id);
+        Self {
+            current_version_id: metadata.current_version_id(),
+            versions,
+            version_log: metadata.history().to_vec(),
+        }
+    }
+}
+
 impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> ViewManagementService<C, A, S>
     for ApiServer<C, A, S>
 {
@@ -102,4 +215,290 @@ where
             updated_at: view.updated_at,
         })
     }
+
+    async fn get_view_versions(
+        view_id: ViewId,
+        warehouse_id: WarehouseId,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<GetViewVersionsResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz;
+        let state_catalog = state.v1_state.catalog;
+
+        let event_ctx = APIEventContext::for_view(
+            Arc::new(request_metadata),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            view_id,
+            CatalogViewAction::GetMetadata,
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_view_operation::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity(),
+                TabularListFlags::active(),
+                event_ctx.action().clone(),
+                state_catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, _view) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_read(state_catalog).await?;
+        let view = C::load_view(warehouse_id, view_id, false, t.transaction()).await?;
+        t.commit().await?;
+
+        Ok(GetViewVersionsResponse::from(view.metadata.as_ref()))
+    }
+
+    async fn rollback_view(
+        view_id: ViewId,
+        warehouse_id: WarehouseId,
+        request: RollbackViewRequest,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<GetViewVersionsResponse> {
+        let RollbackViewRequest { version_id } = request;
+
+        // ------------------- AUTHZ -------------------
+        let authorizer = state.v1_state.authz.clone();
+        let state_catalog = state.v1_state.catalog.clone();
+
+        let event_ctx = APIEventContext::for_view(
+            Arc::new(request_metadata.clone()),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            view_id,
+            CatalogViewAction::Commit {
+                updated_properties: Arc::default(),
+                removed_properties: Arc::default(),
+            },
+        );
+
+        let authz_result = authorizer
+            .load_and_authorize_view_operation::<C>(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity(),
+                TabularListFlags::active(),
+                event_ctx.action().clone(),
+                state_catalog.clone(),
+            )
+            .await;
+        let (_event_ctx, (_warehouse, _namespace, view_info)) =
+            event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let mut t = C::Transaction::begin_read(state_catalog).await?;
+        let view = C::load_view(warehouse_id, view_id, false, t.transaction()).await?;
+        t.commit().await?;
+
+        if view.metadata.version_by_id(version_id).is_none() {
+            return Err(ErrorModel::not_found(
+                format!("Version {version_id} does not exist for view {view_id}"),
+                "ViewVersionNotFound",
+                None,
+            )
+            .into());
+        }
+        if view.metadata.current_version_id() == version_id {
+            return Ok(GetViewVersionsResponse::from(view.metadata.as_ref()));
+        }
+
+        // Rollbacks go through the regular commit path so that a new metadata file is
+        // written and the view is updated transactionally.
+        let result = commit_view(
+            ViewParameters {
+                prefix: Some(Prefix(warehouse_id.to_string())),
+                view: view_info.tabular_ident.clone(),
+            },
+            CommitViewRequest {
+                identifier: None,
+                requirements: Some(vec![ViewRequirement::AssertViewUuid(AssertViewUuid {
+                    uuid: *view_id,
+                })]),
+                updates: vec![ViewUpdate::SetCurrentViewVersion {
+                    view_version_id: version_id,
+                }],
+            },
+            state,
+            DataAccessMode::ClientManaged,
+            request_metadata,
+        )
+        .await?;
+
+        Ok(GetViewVersionsResponse::from(result.metadata.as_ref()))
+    }
+
+    async fn list_views(
+        warehouse_id: WarehouseId,
+        query: ListViewsQuery,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<ListViewsResponse> {
+        // ------------------- AUTHZ -------------------
+        let authorizer = context.v1_state.authz;
+        let catalog = context.v1_state.catalog;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            WarehouseActionListViews {},
+        );
+
+        let authz_result = authorize_list_views::<C, A>(
+            event_ctx.request_metadata(),
+            warehouse_id,
+            &authorizer,
+            catalog.clone(),
+        )
+        .await;
+        let (
+            event_ctx,
+            AuthorizeListViewsResult {
+                warehouse,
+                authz_list_all,
+            },
+        ) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let pagination_query = query.pagination_query();
+        let namespace_id = query.namespace_id;
+        let request_metadata = event_ctx.request_metadata().clone();
+        let mut t = C::Transaction::begin_read(catalog).await?;
+        let (views, view_ids, next_page_token) = fetch_until_full_page::<_, _, _, C>(
+            pagination_query.page_size,
+            pagination_query.page_token,
+            |page_size, page_token, t| {
+                let authorizer = authorizer.clone();
+                let request_metadata = request_metadata.clone();
+                let warehouse = warehouse.clone();
+                async move {
+                    let query = PaginationQuery {
+                        page_size: Some(page_size),
+                        page_token: page_token.into(),
+                    };
+                    let page = C::list_views(
+                        warehouse_id,
+                        namespace_id,
+                        TabularListFlags::active(),
+                        t.transaction(),
+                        query,
+                    )
+                    .await?;
+                    let (ids, views, tokens): (Vec<_>, Vec<_>, Vec<_>) =
+                        page.into_iter_with_page_tokens().multiunzip();
+
+                    let authz_decisions = if authz_list_all {
+                        vec![true; ids.len()]
+                    } else {
+                        let namespaces = C::get_namespaces_by_id(
+                            warehouse_id,
+                            &views.iter().map(|v| v.tabular.namespace_id).collect_vec(),
+                            t.transaction(),
+                        )
+                        .await?;
+                        let actions = views
+                            .iter()
+                            .map(|v| {
+                                Ok::<_, ErrorModel>((
+                                    require_namespace_for_tabular(&namespaces, &v.tabular)
+                                        .map_err(authz_to_error_no_audit)?,
+                                    ActionOnView {
+                                        info: v,
+                                        action: CatalogViewAction::IncludeInList,
+                                        user: None,
+                                        is_delegated_execution: false,
+                                    },
+                                ))
+                            })
+                            .collect::<Result<Vec<_>, _>>()?;
+
+                        authorizer
+                            .are_allowed_view_actions_vec(
+                                &request_metadata,
+                                &warehouse,
+                                &namespaces,
+                                &actions,
+                            )
+                            .await
+                            .map_err(authz_to_error_no_audit)?
+                            .into_allowed()
+                    };
+
+                    Ok(UnfilteredPage::new(
+                        views,
+                        ids,
+                        tokens,
+                        authz_decisions,
+                        page_size
+                            .clamp(0, i64::MAX)
+                            .try_into()
+                            .expect("We clamped."),
+                    ))
+                }
+                .boxed()
+            },
+            &mut t,
+        )
+        .await?;
+        t.commit().await?;
+
+        let views = view_ids
+            .into_iter()
+            .zip(views)
+            .map(|(view_id, view)| {
+                let view = view.tabular;
+                ViewSummary {
+                    view_id: *view_id,
+                    namespace: view.tabular_ident.namespace.inner(),
+                    name: view.tabular_ident.name,
+                    metadata_location: view.metadata_location.as_ref().map(ToString::to_string),
+                }
+            })
+            .collect();
+
+        Ok(ListViewsResponse {
+            views,
+            next_page_token,
+        })
+    }
+}
+
+struct AuthorizeListViewsResult {
+    warehouse: Arc<ResolvedWarehouse>,
+    authz_list_all: bool,
+}
+
+async fn authorize_list_views<C: CatalogStore, A: Authorizer>(
+    request_metadata: &RequestMetadata,
+    warehouse_id: WarehouseId,
+    authorizer: &A,
+    state: C::State,
+) -> Result<AuthorizeListViewsResult, RequireWarehouseActionError> {
+    let warehouse = C::get_active_warehouse_by_id(warehouse_id, state).await;
+    let warehouse = authorizer.require_warehouse_presence(warehouse_id, warehouse)?;
+
+    let [authz_can_use, authz_list_all] = authorizer
+        .are_allowed_warehouse_actions_arr(
+            request_metadata,
+            None,
+            &[
+                (&warehouse, CatalogWarehouseAction::Use),
+                (&warehouse, CatalogWarehouseAction::ListEverything),
+            ],
+        )
+        .await?
+        .into_inner();
+
+    if !authz_can_use {
+        return Err(AuthZCannotUseWarehouseId::new_access_denied(warehouse_id).into());
+    }
+
+    Ok(AuthorizeListViewsResult {
+        warehouse,
+        authz_list_all,
+    })
 }
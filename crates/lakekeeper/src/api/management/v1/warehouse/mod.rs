@@ -4,59 +4,67 @@ use std::sync::Arc;
 
 use futures::{FutureExt, StreamExt as _};
 use iceberg::spec::FormatVersion;
-use iceberg_ext::catalog::rest::ErrorModel;
+use iceberg_ext::catalog::rest::{ETag, ErrorModel};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
 use super::{DeleteWarehouseQuery, ProtectionResponse};
 pub use crate::service::{
-    CatalogCreateWarehouseRequest, ManagedBy, WarehouseStatus,
+    CatalogCreateWarehouseRequest, FileFormatPolicy, ManagedBy, PartitionTransformKind,
+    PartitionTransformPolicy, ProtectedEntity, ProtectedEntityType, SchemaEvolutionPolicy,
+    WarehouseCredentialMode, WarehouseSettings, WarehouseStatus, WriteFileFormat,
     storage::{
         AzCredential, GcsCredential, GcsProfile, GcsServiceKey, GenericAdlsProfile, OneLakeProfile,
         S3Credential, S3Profile, StorageCredential, StorageCredentialType, StorageProfile,
     },
 };
 use crate::{
-    ProjectId, WarehouseId,
+    CONFIG, ProjectId, WarehouseId,
     api::{
         ApiContext, Result,
         iceberg::v1::{PageToken, PaginationQuery},
         management::v1::{
-            ApiServer, DeletedTabularResponse, GetWarehouseStatisticsQuery,
+            ApiServer, DeletedTabularResponse, GetWarehouseQuery, GetWarehouseStatisticsQuery,
             ListDeletedTabularsResponse,
             task_queue::{
-                GetTaskQueueConfigResponse, SetTaskQueueConfigRequest,
+                GetTaskQueueConfigResponse, QueueConfig, SetTaskQueueConfigRequest,
                 get_task_queue_config as get_task_queue_config_authorized,
                 set_task_queue_config as set_task_queue_config_authorized,
             },
         },
     },
     request_metadata::RequestMetadata,
-    server::UnfilteredPage,
+    server::{UnfilteredPage, catalog_config},
     service::{
         AllowedFormatVersions, ArcProjectId, CachePolicy, CatalogNamespaceOps, CatalogStore,
-        CatalogTabularOps, CatalogWarehouseOps, EnsureWarehouseSpecMutableError, NamespaceId,
-        State, TabularId, TabularListFlags, Transaction, ViewOrTableDeletionInfo,
-        WarehouseFormatVersionPolicy, WarehouseSpecLocked,
+        CatalogTabularOps, CatalogTaskOps, CatalogWarehouseOps, EnsureWarehouseSpecMutableError,
+        NamedEntity, NamespaceId, ResolvedWarehouse, State, StorageProfileSlot, TabularId,
+        TabularListFlags, Transaction, ViewOrTableDeletionInfo, ViewOrTableInfo,
+        WarehouseEntityCounts, WarehouseFormatVersionPolicy, WarehouseQuotaEntity,
+        WarehouseSpecLocked,
         authz::{
-            AuthZProjectOps, AuthZTableOps, Authorizer, AuthzNamespaceOps, AuthzWarehouseOps,
-            CatalogGenericTableAction, CatalogNamespaceAction, CatalogProjectAction,
-            CatalogTableAction, CatalogViewAction, CatalogWarehouseAction, InstanceAdminAction,
-            InstanceAdminAuthorizer,
+            AuthZProjectOps, AuthZTableOps, AuthZWarehouseActionForbidden, Authorizer,
+            AuthzNamespaceOps, AuthzWarehouseOps, CatalogGenericTableAction,
+            CatalogNamespaceAction, CatalogProjectAction, CatalogTableAction, CatalogViewAction,
+            CatalogWarehouseAction, InstanceAdminAction, InstanceAdminAuthorizer,
         },
         events::{
             APIEventContext,
             context::{
-                APIEventActions, AuthzChecked, ResolutionState, TabularAction, UserProvidedEntity,
-                authz_to_error_no_audit,
+                APIEventActions, AuthzChecked, ResolutionState, Resolved, TabularAction,
+                UserProvidedEntity, authz_to_error_no_audit,
             },
         },
         require_namespace_for_tabular,
         secrets::SecretStore,
+        storage::ValidationError,
         task_configs::TaskQueueConfigFilter,
         tasks::{
-            CancelTasksFilter, TaskQueueName, tabular_expiration_queue::TabularExpirationTask,
+            CancelTasksFilter, ScheduleTaskMetadata, TaskEntity, TaskQueueName,
+            WarehouseTaskEntityId,
+            tabular_expiration_queue::TabularExpirationTask,
+            tabular_purge_queue::{TabularPurgePayload, TabularPurgeTask},
         },
     },
 };
@@ -91,6 +99,44 @@ impl ListDeletedTabularsQuery {
     }
 }
 
+#[derive(Debug, Deserialize, Default)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+#[serde(rename_all = "camelCase")]
+pub struct ListProtectedEntitiesQuery {
+    /// Next page token
+    #[serde(default)]
+    pub page_token: Option<String>,
+    /// Signals an upper bound of the number of results that a client will receive.
+    /// Default: 100
+    #[serde(default)]
+    pub page_size: Option<i64>,
+}
+
+impl ListProtectedEntitiesQuery {
+    #[must_use]
+    pub fn pagination_query(&self) -> PaginationQuery {
+        PaginationQuery {
+            page_token: self
+                .page_token
+                .clone()
+                .map_or(PageToken::Empty, PageToken::Present),
+            page_size: self.page_size,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Default, TypedBuilder)]
+#[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
+pub struct PurgeDeletedTabularQuery {
+    /// Purge the tabular even if it is protected.
+    #[serde(
+        deserialize_with = "crate::api::iceberg::types::deserialize_bool",
+        default
+    )]
+    #[builder(setter(strip_bool))]
+    pub force: bool,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TypedBuilder)]
 #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
 #[serde(rename_all = "kebab-case")]
@@ -126,6 +172,12 @@ pub struct CreateWarehouseRequest {
     #[builder(default, setter(strip_option))]
     #[cfg_attr(feature = "open-api", schema(value_type=Option::<i32>))]
     pub default_format_version: Option<FormatVersion>,
+    /// Policies for the tables, views and data access of the warehouse.
+    /// Omitted settings impose no restriction, except for
+    /// `require-location-in-warehouse`, which defaults to `true`.
+    #[serde(default)]
+    #[builder(default)]
+    pub settings: WarehouseSettings,
     /// Which control plane, if any, exclusively manages this warehouse's spec.
     /// Defaults to `self-managed`. Creating a managed warehouse (e.g. `instance-admin`)
     /// requires instance-admin privilege.
@@ -134,6 +186,38 @@ pub struct CreateWarehouseRequest {
     pub managed_by: ManagedBy,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TypedBuilder)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct CloneWarehouseRequest {
+    /// Name of the new warehouse. Must be unique
+    /// within its project and may not contain "/"
+    pub warehouse_name: String,
+    /// Project ID in which to create the new warehouse.
+    /// Defaults to the project of the source warehouse.
+    #[cfg_attr(feature = "open-api", schema(value_type=Option::<String>))]
+    #[serde(default)]
+    #[builder(default, setter(strip_option))]
+    pub project_id: Option<ProjectId>,
+    /// Storage profile of the new warehouse.
+    /// Defaults to the storage profile of the source warehouse. Within the
+    /// same project the location of the new warehouse may not overlap with
+    /// the source, so a different bucket or key prefix is usually required.
+    #[serde(default)]
+    #[builder(default, setter(strip_option))]
+    pub storage_profile: Option<StorageProfile>,
+    /// Storage credential of the new warehouse.
+    /// The credential of the source warehouse is never copied.
+    #[serde(default)]
+    #[builder(default, setter(strip_option))]
+    pub storage_credential: Option<StorageCredential>,
+    /// Storage credential for the secondary storage profile of the new warehouse.
+    /// Ignored if the source warehouse has no secondary storage profile.
+    #[serde(default)]
+    #[builder(default, setter(strip_option))]
+    pub secondary_storage_credential: Option<StorageCredential>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
 #[serde(rename_all = "kebab-case", tag = "type")]
@@ -219,6 +303,86 @@ pub struct UpdateWarehouseStorageRequest {
     pub storage_credential: Option<StorageCredential>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct MergeWarehouseStorageRequest {
+    /// Fields of the storage profile to change, applied as a JSON merge patch
+    /// (RFC 7396) to the current profile. Fields that are not specified
+    /// keep their current value, fields set to `null` are removed.
+    /// The same restrictions as for replacing the storage profile apply.
+    #[serde(default)]
+    #[cfg_attr(feature = "open-api", schema(value_type = Object))]
+    pub storage_profile: serde_json::Map<String, serde_json::Value>,
+    /// Optional storage credential to use for the warehouse.
+    /// If no credential is provided, the existing credential is re-used.
+    #[serde(default)]
+    pub storage_credential: Option<StorageCredential>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ValidateStorageRequest {
+    /// Storage profile to validate.
+    pub storage_profile: StorageProfile,
+    /// Optional storage credential to validate the storage profile with.
+    #[serde(default)]
+    pub storage_credential: Option<StorageCredential>,
+}
+
+/// Check run when validating a storage profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum StorageValidationCheck {
+    /// The storage profile is well-formed and matches the credential.
+    Profile,
+    /// The credential is accepted by the storage.
+    Credentials,
+    /// Files can be written, read and deleted at a test location, directly
+    /// and, if enabled, with vended credentials.
+    Access,
+    /// The storage does not overlap with a warehouse of the project.
+    StorageOverlap,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct StorageValidationFailure {
+    /// Check that failed.
+    pub check: StorageValidationCheck,
+    /// Type of the error that caused the check to fail.
+    pub error_type: String,
+    /// Human-readable reason why the check failed.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ValidateStorageResponse {
+    /// Whether all checks passed.
+    pub valid: bool,
+    /// First check that failed. Not set if the storage profile is valid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<StorageValidationFailure>,
+}
+
+impl ValidateStorageResponse {
+    fn failed(check: StorageValidationCheck, error: ErrorModel) -> Self {
+        Self {
+            valid: false,
+            failure: Some(StorageValidationFailure {
+                check,
+                error_type: error.r#type,
+                message: error.message,
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "open-api", derive(utoipa::IntoParams))]
 #[serde(rename_all = "camelCase")]
@@ -234,6 +398,34 @@ pub struct ListWarehousesRequest {
     #[serde(default)]
     #[cfg_attr(feature = "open-api", param(value_type=Option::<String>))]
     pub project_id: Option<ProjectId>,
+    /// Include the number of namespaces, tables and views of each warehouse
+    /// in the response. Defaults to `false`.
+    #[serde(
+        deserialize_with = "crate::api::iceberg::types::deserialize_bool",
+        default,
+        alias = "include_counts"
+    )]
+    pub include_counts: bool,
+    /// Next page token
+    #[serde(default)]
+    pub page_token: Option<String>,
+    /// Signals an upper bound of the number of results that a client will receive.
+    /// If not provided, all warehouses up to the maximum page size are returned.
+    #[serde(default)]
+    pub page_size: Option<i64>,
+}
+
+impl ListWarehousesRequest {
+    #[must_use]
+    pub fn pagination_query(&self) -> PaginationQuery {
+        PaginationQuery {
+            page_token: self
+                .page_token
+                .clone()
+                .map_or(PageToken::NotSpecified, PageToken::Present),
+            page_size: self.page_size,
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -267,6 +459,15 @@ pub struct UpdateWarehouseFormatVersionPolicyRequest {
     pub default_format_version: Option<FormatVersion>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct UpdateWarehouseSettingsRequest {
+    /// New settings of the warehouse. Replaces the current settings; omitted
+    /// settings are reset to their default.
+    pub settings: WarehouseSettings,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
 #[serde(rename_all = "kebab-case")]
@@ -299,6 +500,10 @@ pub struct GetWarehouseResponse {
     /// failed. Does not contain secret values.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage_credential_type: Option<StorageCredentialType>,
+    /// Secondary storage profile of the warehouse. Namespaces and tables select
+    /// it at creation time by setting the `storage_profile` property to `secondary`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_storage_profile: Option<StorageProfile>,
     /// Delete profile used for the warehouse.
     pub delete_profile: TabularDeleteProfile,
     /// Whether the warehouse is active.
@@ -319,6 +524,21 @@ pub struct GetWarehouseResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "open-api", schema(value_type=Option::<i32>))]
     pub default_format_version: Option<FormatVersion>,
+    /// Policies for the tables, views and data access of the warehouse.
+    #[serde(default)]
+    pub settings: WarehouseSettings,
+    /// Number of namespaces in the warehouse.
+    /// Only present if requested via `include_counts`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_namespaces: Option<i64>,
+    /// Number of tables in the warehouse, excluding soft-deleted and staged tables.
+    /// Only present if requested via `include_counts`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_tables: Option<i64>,
+    /// Number of views in the warehouse, excluding soft-deleted views.
+    /// Only present if requested via `include_counts`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_views: Option<i64>,
     /// Last updated timestamp.
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -329,6 +549,9 @@ pub struct GetWarehouseResponse {
 pub struct ListWarehousesResponse {
     /// List of warehouses in the project.
     pub warehouses: Vec<GetWarehouseResponse>,
+    /// Token to fetch the next page of warehouses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -355,6 +578,36 @@ impl axum::response::IntoResponse for CreateWarehouseResponse {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct GetWarehouseCatalogConfigResponse {
+    /// ID of the warehouse.
+    pub warehouse_id: uuid::Uuid,
+    /// Prefix clients of this warehouse use in Iceberg REST catalog requests.
+    pub prefix: String,
+    /// Data access mechanisms offered to clients of this warehouse.
+    pub credential_mode: WarehouseCredentialMode,
+    /// Properties clients use as default configuration.
+    pub defaults: std::collections::HashMap<String, String>,
+    /// Properties that override the client configuration.
+    pub overrides: std::collections::HashMap<String, String>,
+    /// Endpoints supported by the catalog for this warehouse.
+    pub endpoints: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct ListProtectedEntitiesResponse {
+    /// Whether the warehouse itself is protected.
+    pub warehouse_protected: bool,
+    /// Protected namespaces, tables, views and generic tables of the warehouse.
+    pub entities: Vec<ProtectedEntity>,
+    /// Token to fetch the next page
+    pub next_page_token: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
 #[serde(rename_all = "kebab-case")]
@@ -373,6 +626,17 @@ pub struct WarehouseStatistics {
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct GetWarehouseVersionResponse {
+    /// ID of the warehouse.
+    pub warehouse_id: uuid::Uuid,
+    /// Change version of the warehouse. Increases whenever the warehouse or
+    /// any namespace, table, view or generic table within it changes.
+    pub version: i64,
+}
+
 #[derive(Debug, Serialize)]
 #[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
 #[serde(rename_all = "kebab-case")]
@@ -390,7 +654,13 @@ pub struct WarehouseStatisticsResponse {
 #[serde(rename_all = "kebab-case")]
 pub struct UndropTabularsRequest {
     /// Tabulars to undrop
+    #[serde(default)]
     pub targets: Vec<TabularId>,
+    /// Additionally undrop all soft-deleted tables, views and generic tables of
+    /// this namespace. Nested namespaces are not included.
+    #[serde(default)]
+    #[cfg_attr(feature = "open-api", schema(value_type = Option<uuid::Uuid>))]
+    pub namespace_id: Option<NamespaceId>,
 }
 
 impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore> Service<C, A, S>
@@ -408,19 +678,21 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         let CreateWarehouseRequest {
             warehouse_name,
             project_id,
-            mut storage_profile,
+            storage_profile,
             storage_credential,
             delete_profile,
             allowed_format_versions,
             default_format_version,
+            settings,
             managed_by,
         } = request;
         let project_id = request_metadata.require_project_id(project_id)?;
         let format_version_policy =
             validate_format_version_policy(allowed_format_versions, default_format_version)?;
+        let settings = validate_warehouse_settings(settings)?;
 
         // ------------------- AuthZ -------------------
-        let authorizer = context.v1_state.authz;
+        let authorizer = &context.v1_state.authz;
 
         let event_ctx = APIEventContext::for_project_arc(
             Arc::new(request_metadata),
@@ -457,63 +729,23 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         }
 
         // ------------------- Business Logic -------------------
-        validate_warehouse_name(&warehouse_name)?;
-        storage_profile.normalize(storage_credential.as_ref())?;
-
-        // Run credential validation and storage-overlap check in parallel
-        let validation_future =
-            storage_profile.validate_access(storage_credential.as_ref(), None, request_metadata);
-        let overlap_check_future = ensure_no_storage_overlap::<C>(
-            project_id,
-            &storage_profile,
-            context.v1_state.catalog.clone(),
-        );
-
-        let (validation_result, overlap_result) =
-            tokio::join!(validation_future, overlap_check_future);
-
-        // Check results from both operations
-        validation_result?;
-        overlap_result?;
-
-        let credential_type = storage_credential
-            .as_ref()
-            .map(StorageCredential::credential_type);
-        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
-        let secret_id = if let Some(storage_credential) = storage_credential {
-            Some(
-                context
-                    .v1_state
-                    .secrets
-                    .create_storage_secret(storage_credential)
-                    .await?,
-            )
-        } else {
-            None
-        };
-
-        let resolved_warehouse = C::create_warehouse(
+        let (resolved_warehouse, credential_type) = create_authorized_warehouse(
             project_id,
             CatalogCreateWarehouseRequest::builder()
                 .warehouse_name(warehouse_name)
                 .storage_profile(storage_profile)
-                .storage_secret_id(secret_id)
                 .delete_profile(delete_profile)
                 .format_version_policy(format_version_policy)
+                .settings(settings)
                 .managed_by(managed_by)
                 .build(),
-            transaction.transaction(),
+            storage_credential,
+            None,
+            Vec::new(),
+            &context,
+            request_metadata,
         )
         .await?;
-        authorizer
-            .create_warehouse(
-                request_metadata,
-                resolved_warehouse.warehouse_id,
-                project_id,
-            )
-            .await?;
-
-        transaction.commit().await?;
 
         event_ctx.emit_warehouse_created(resolved_warehouse.clone());
 
@@ -522,23 +754,25 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         Ok(CreateWarehouseResponse(response))
     }
 
-    async fn list_warehouses(
-        request: ListWarehousesRequest,
+    async fn validate_storage(
+        request: ValidateStorageRequest,
         context: ApiContext<State<A, C, S>>,
         request_metadata: RequestMetadata,
-    ) -> Result<ListWarehousesResponse> {
-        const MAX_CONCURRENT_SECRET_LOOKUPS: usize = 32;
+    ) -> Result<ValidateStorageResponse> {
+        let project_id = request_metadata.require_project_id(None)?;
+
         // ------------------- AuthZ -------------------
-        let project_id = request_metadata.require_project_id(request.project_id)?;
+        // Validation reaches out to the storage like creating a warehouse does,
+        // so it requires the same permission.
+        let authorizer = &context.v1_state.authz;
 
         let event_ctx = APIEventContext::for_project_arc(
             Arc::new(request_metadata),
             context.v1_state.events.clone(),
             project_id,
-            Arc::new(CatalogProjectAction::ListWarehouses),
+            Arc::new(CatalogProjectAction::CreateWarehouse { name: None }),
         );
 
-        let authorizer = context.v1_state.authz;
         let authz_result = authorizer
             .require_project_action(
                 event_ctx.request_metadata(),
@@ -546,95 +780,529 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
                 event_ctx.action().clone(),
             )
             .await;
+
         let (event_ctx, ()) = event_ctx.emit_authz(authz_result)?;
 
         // ------------------- Business Logic -------------------
-        let warehouses = C::list_warehouses(
+        // Runs the checks of `create_authorized_warehouse` without persisting anything.
+        let ValidateStorageRequest {
+            mut storage_profile,
+            storage_credential,
+        } = request;
+
+        if let Err(e) = storage_profile.normalize(storage_credential.as_ref()) {
+            return validation_error_to_response(e);
+        }
+        if let Err(e) = Box::pin(storage_profile.validate_access(
+            storage_credential.as_ref(),
+            None,
+            event_ctx.request_metadata(),
+        ))
+        .await
+        {
+            return validation_error_to_response(e);
+        }
+        if let Some(warehouse_name) = find_overlapping_warehouse::<C>(
             event_ctx.user_provided_entity(),
-            request.warehouse_status,
+            &storage_profile,
             context.v1_state.catalog,
         )
-        .await?;
-
-        let allowed_warehouses: Vec<_> = authorizer
-            .are_allowed_warehouse_actions_vec(
-                event_ctx.request_metadata(),
-                None,
-                &warehouses
-                    .iter()
-                    .map(|w| (&**w, CatalogWarehouseAction::IncludeInList))
-                    .collect::<Vec<_>>(),
-            )
-            .await
-            .map_err(authz_to_error_no_audit)?
-            .into_allowed()
-            .into_iter()
-            .zip(warehouses)
-            .filter_map(|(allowed, warehouse)| if allowed { Some(warehouse) } else { None })
-            .collect();
-
-        // Collect futures first to avoid for<'a> lifetime issues with stream combinators.
-        let futs: Vec<_> = allowed_warehouses
-            .iter()
-            .map(|w| resolve_credential_type(w, &context.v1_state.secrets))
-            .collect();
-        let credential_types: Vec<_> = futures::stream::iter(futs)
-            .buffered(MAX_CONCURRENT_SECRET_LOOKUPS)
-            .collect()
-            .await;
-        let warehouses: Vec<GetWarehouseResponse> = allowed_warehouses
-            .into_iter()
-            .zip(credential_types)
-            .map(|(warehouse, credential_type)| {
-                GetWarehouseResponse::from_resolved((*warehouse).clone(), credential_type)
-            })
-            .collect();
+        .await?
+        {
+            return Ok(ValidateStorageResponse::failed(
+                StorageValidationCheck::StorageOverlap,
+                storage_overlap_error(&warehouse_name),
+            ));
+        }
 
-        Ok(ListWarehousesResponse { warehouses })
+        Ok(ValidateStorageResponse {
+            valid: true,
+            failure: None,
+        })
     }
 
-    async fn get_warehouse(
+    async fn clone_warehouse(
         warehouse_id: WarehouseId,
+        request: CloneWarehouseRequest,
         context: ApiContext<State<A, C, S>>,
         request_metadata: RequestMetadata,
-    ) -> Result<GetWarehouseResponse> {
-        let authorizer = context.v1_state.authz;
+    ) -> Result<CreateWarehouseResponse> {
+        let CloneWarehouseRequest {
+            warehouse_name,
+            project_id,
+            storage_profile,
+            storage_credential,
+            secondary_storage_credential,
+        } = request;
+        let request_metadata = Arc::new(request_metadata);
 
-        let event_ctx = APIEventContext::for_warehouse(
-            Arc::new(request_metadata),
+        // ------------------- AuthZ -------------------
+        let authorizer = &context.v1_state.authz;
+
+        let source_event_ctx = APIEventContext::for_warehouse(
+            request_metadata.clone(),
             context.v1_state.events.clone(),
             warehouse_id,
             CatalogWarehouseAction::GetMetadata,
         );
-
-        let warehouse = C::get_warehouse_by_id_cache_aware(
+        let source = C::get_warehouse_by_id_cache_aware(
             warehouse_id,
             WarehouseStatus::active_and_inactive(),
             CachePolicy::Skip,
-            context.v1_state.catalog,
+            context.v1_state.catalog.clone(),
         )
         .await;
         let authz_result = authorizer
             .require_warehouse_action(
-                event_ctx.request_metadata(),
+                source_event_ctx.request_metadata(),
                 warehouse_id,
-                warehouse,
-                event_ctx.action().clone(),
+                source,
+                source_event_ctx.action().clone(),
             )
             .await;
-        let (_event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
-        let credential_type = resolve_credential_type(&warehouse, &context.v1_state.secrets).await;
-        Ok(GetWarehouseResponse::from_resolved(
-            (*warehouse).clone(),
-            credential_type,
-        ))
-    }
+        let (source_event_ctx, source) = source_event_ctx.emit_authz(authz_result)?;
+        // Task queue configs are copied as well and are guarded by their own action.
+        let can_get_task_queue_config = authorizer
+            .is_allowed_warehouse_action(
+                source_event_ctx.request_metadata(),
+                None,
+                &source,
+                CatalogWarehouseAction::GetTaskQueueConfig,
+            )
+            .await
+            .map_err(|e| source_event_ctx.emit_late_authz_failure(e))?
+            .into_inner();
+        if !can_get_task_queue_config {
+            return Err(source_event_ctx
+                .emit_late_authz_failure(AuthZWarehouseActionForbidden::new(
+                    warehouse_id,
+                    &CatalogWarehouseAction::GetTaskQueueConfig,
+                ))
+                .into());
+        }
 
-    async fn get_warehouse_statistics(
-        warehouse_id: WarehouseId,
-        query: GetWarehouseStatisticsQuery,
-        context: ApiContext<State<A, C, S>>,
-        request_metadata: RequestMetadata,
+        let project_id = project_id.map_or_else(|| source.project_id.clone(), Arc::new);
+        let event_ctx = APIEventContext::for_project_arc(
+            request_metadata,
+            context.v1_state.events.clone(),
+            project_id,
+            Arc::new(CatalogProjectAction::CreateWarehouse {
+                name: Some(warehouse_name.clone()),
+            }),
+        );
+        let authz_result = authorizer
+            .require_project_action(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity_arc_ref(),
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, ()) = event_ctx.emit_authz(authz_result)?;
+        let request_metadata = event_ctx.request_metadata();
+        let project_id = event_ctx.user_provided_entity();
+
+        // ------------------- Business Logic -------------------
+        let mut task_queue_configs = Vec::new();
+        let filter = TaskQueueConfigFilter::WarehouseId { warehouse_id };
+        for queue_name in context.v1_state.registered_task_queues.queue_names().await {
+            if let Some(config) =
+                C::get_task_queue_config(&filter, queue_name, context.v1_state.catalog.clone())
+                    .await?
+            {
+                task_queue_configs.push((
+                    queue_name,
+                    SetTaskQueueConfigRequest {
+                        queue_config: QueueConfig::from_json(config.queue_config.config),
+                        max_seconds_since_last_heartbeat: config.max_seconds_since_last_heartbeat,
+                    },
+                ));
+            }
+        }
+
+        let secondary_storage = source.secondary_storage.as_ref().map(|secondary| {
+            (
+                secondary.storage_profile.clone(),
+                secondary_storage_credential,
+            )
+        });
+        let (resolved_warehouse, credential_type) = create_authorized_warehouse(
+            project_id,
+            CatalogCreateWarehouseRequest::builder()
+                .warehouse_name(warehouse_name)
+                .storage_profile(storage_profile.unwrap_or_else(|| source.storage_profile.clone()))
+                .delete_profile(source.tabular_delete_profile)
+                .format_version_policy(WarehouseFormatVersionPolicy {
+                    allowed_format_versions: source.allowed_format_versions.clone(),
+                    default_format_version: source.default_format_version,
+                })
+                .settings(source.settings.clone())
+                .build(),
+            storage_credential,
+            secondary_storage,
+            task_queue_configs,
+            &context,
+            request_metadata,
+        )
+        .await?;
+
+        event_ctx.emit_warehouse_created(resolved_warehouse.clone());
+
+        let response =
+            GetWarehouseResponse::from_resolved((*resolved_warehouse).clone(), credential_type);
+        Ok(CreateWarehouseResponse(response))
+    }
+
+    async fn list_warehouses(
+        request: ListWarehousesRequest,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<ListWarehousesResponse> {
+        const MAX_CONCURRENT_SECRET_LOOKUPS: usize = 32;
+        // ------------------- AuthZ -------------------
+        let project_id = request_metadata.require_project_id(request.project_id)?;
+
+        let event_ctx = APIEventContext::for_project_arc(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            project_id,
+            Arc::new(CatalogProjectAction::ListWarehouses),
+        );
+
+        let authorizer = context.v1_state.authz;
+        let authz_result = authorizer
+            .require_project_action(
+                event_ctx.request_metadata(),
+                event_ctx.user_provided_entity_arc_ref(),
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, ()) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- Business Logic -------------------
+        let pagination_query = request.pagination_query();
+        let warehouse_status = request.warehouse_status;
+        let request_metadata = event_ctx.request_metadata().clone();
+        let project_id = event_ctx.user_provided_entity().clone();
+        let mut t = C::Transaction::begin_read(context.v1_state.catalog.clone()).await?;
+        let (allowed_warehouses, _ids, next_page_token) =
+            crate::server::fetch_until_full_page::<_, _, _, C>(
+                pagination_query.page_size,
+                pagination_query.page_token,
+                |page_size, page_token, t| {
+                    let authorizer = authorizer.clone();
+                    let request_metadata = request_metadata.clone();
+                    let project_id = project_id.clone();
+                    let warehouse_status = warehouse_status.clone();
+                    async move {
+                        let query = PaginationQuery {
+                            page_size: Some(page_size),
+                            page_token: page_token.into(),
+                        };
+
+                        let page = C::list_warehouses_paginated(
+                            &project_id,
+                            warehouse_status,
+                            query,
+                            t.transaction(),
+                        )
+                        .await?;
+                        let (ids, warehouses, tokens): (Vec<_>, Vec<_>, Vec<_>) =
+                            page.into_iter_with_page_tokens().multiunzip();
+
+                        let mask = authorizer
+                            .are_allowed_warehouse_actions_vec(
+                                &request_metadata,
+                                None,
+                                &warehouses
+                                    .iter()
+                                    .map(|w| (&**w, CatalogWarehouseAction::IncludeInList))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .await
+                            .map_err(authz_to_error_no_audit)?
+                            .into_allowed();
+
+                        Ok(UnfilteredPage::new(
+                            warehouses,
+                            ids,
+                            tokens,
+                            mask,
+                            page_size
+                                .clamp(0, i64::MAX)
+                                .try_into()
+                                .expect("We clamped."),
+                        ))
+                    }
+                    .boxed()
+                },
+                &mut t,
+            )
+            .await?;
+        t.commit().await?;
+
+        // Collect futures first to avoid for<'a> lifetime issues with stream combinators.
+        let futs: Vec<_> = allowed_warehouses
+            .iter()
+            .map(|w| resolve_credential_type(w, &context.v1_state.secrets))
+            .collect();
+        let credential_types: Vec<_> = futures::stream::iter(futs)
+            .buffered(MAX_CONCURRENT_SECRET_LOOKUPS)
+            .collect()
+            .await;
+        let entity_counts = if request.include_counts {
+            let warehouse_ids = allowed_warehouses
+                .iter()
+                .map(|w| w.warehouse_id)
+                .collect::<Vec<_>>();
+            Some(C::get_warehouse_entity_counts(&warehouse_ids, context.v1_state.catalog).await?)
+        } else {
+            None
+        };
+        let warehouses: Vec<GetWarehouseResponse> = allowed_warehouses
+            .into_iter()
+            .zip(credential_types)
+            .map(|(warehouse, credential_type)| {
+                let response =
+                    GetWarehouseResponse::from_resolved((*warehouse).clone(), credential_type);
+                match &entity_counts {
+                    Some(counts) => response.with_entity_counts(
+                        counts
+                            .get(&warehouse.warehouse_id)
+                            .copied()
+                            .unwrap_or_default(),
+                    ),
+                    None => response,
+                }
+            })
+            .collect();
+
+        Ok(ListWarehousesResponse {
+            warehouses,
+            next_page_token,
+        })
+    }
+
+    async fn get_warehouse(
+        warehouse_id: WarehouseId,
+        query: GetWarehouseQuery,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<GetWarehouseResponse> {
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::GetMetadata,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active_and_inactive(),
+            CachePolicy::Skip,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (_event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let credential_type = resolve_credential_type(&warehouse, &context.v1_state.secrets).await;
+        let response = GetWarehouseResponse::from_resolved((*warehouse).clone(), credential_type);
+        if !query.include_counts {
+            return Ok(response);
+        }
+
+        let counts = C::get_warehouse_entity_counts(&[warehouse_id], context.v1_state.catalog)
+            .await?
+            .remove(&warehouse_id)
+            .unwrap_or_default();
+        Ok(response.with_entity_counts(counts))
+    }
+
+    async fn get_warehouse_catalog_config(
+        warehouse_id: WarehouseId,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<GetWarehouseCatalogConfigResponse> {
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::GetConfig,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active(),
+            CachePolicy::Use,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- Business Logic -------------------
+        let config = catalog_config(&warehouse, event_ctx.request_metadata());
+        Ok(GetWarehouseCatalogConfigResponse {
+            warehouse_id: *warehouse_id,
+            prefix: CONFIG.warehouse_prefix(warehouse_id),
+            credential_mode: warehouse.settings.credential_mode,
+            defaults: config.defaults,
+            overrides: config.overrides,
+            endpoints: config.endpoints,
+        })
+    }
+
+    async fn get_warehouse_version(
+        warehouse_id: WarehouseId,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<GetWarehouseVersionResponse> {
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::GetMetadata,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active_and_inactive(),
+            CachePolicy::Use,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (_event_ctx, _warehouse) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- Business Logic -------------------
+        let version = C::get_warehouse_change_version(warehouse_id, context.v1_state.catalog)
+            .await?
+            .ok_or_else(|| {
+                ErrorModel::not_found(
+                    format!("Warehouse '{warehouse_id}' not found"),
+                    "WarehouseNotFound",
+                    None,
+                )
+            })?;
+
+        Ok(GetWarehouseVersionResponse {
+            warehouse_id: *warehouse_id,
+            version,
+        })
+    }
+
+    /// List the protected namespaces and tabulars of a warehouse. Requires
+    /// permission to list everything in the warehouse, as entities are not
+    /// filtered individually.
+    async fn list_protected_entities(
+        warehouse_id: WarehouseId,
+        query: ListProtectedEntitiesQuery,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<ListProtectedEntitiesResponse> {
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::ListEverything,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active(),
+            CachePolicy::Skip,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (_event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- Business Logic -------------------
+        let pagination_query = query.pagination_query();
+        let mut t = C::Transaction::begin_read(context.v1_state.catalog).await?;
+        let (entities, _ids, next_page_token) = crate::server::fetch_until_full_page::<_, _, _, C>(
+            pagination_query.page_size,
+            pagination_query.page_token,
+            |page_size, page_token, t| {
+                async move {
+                    let query = PaginationQuery {
+                        page_size: Some(page_size),
+                        page_token: page_token.into(),
+                    };
+                    let page =
+                        C::list_protected_entities(warehouse_id, query, t.transaction()).await?;
+                    let (ids, entities, tokens): (Vec<_>, Vec<_>, Vec<_>) =
+                        page.into_iter_with_page_tokens().multiunzip();
+                    let mask = vec![true; ids.len()];
+
+                    Ok(UnfilteredPage::new(
+                        entities,
+                        ids,
+                        tokens,
+                        mask,
+                        page_size
+                            .clamp(0, i64::MAX)
+                            .try_into()
+                            .expect("We clamped."),
+                    ))
+                }
+                .boxed()
+            },
+            &mut t,
+        )
+        .await?;
+        t.commit().await?;
+
+        Ok(ListProtectedEntitiesResponse {
+            warehouse_protected: warehouse.protected,
+            entities,
+            next_page_token,
+        })
+    }
+
+    async fn get_warehouse_statistics(
+        warehouse_id: WarehouseId,
+        query: GetWarehouseStatisticsQuery,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
     ) -> Result<WarehouseStatisticsResponse> {
         // ------------------- AuthZ -------------------
         let authorizer = context.v1_state.authz;
@@ -1016,26 +1684,269 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
             transaction.transaction(),
         )
         .await?;
-        transaction.commit().await?;
+        transaction.commit().await?;
+
+        event_ctx.emit_warehouse_format_version_policy_updated(
+            Arc::new(request),
+            updated_warehouse.clone(),
+        );
+
+        let credential_type =
+            resolve_credential_type(&updated_warehouse, &context.v1_state.secrets).await;
+        Ok(GetWarehouseResponse::from_resolved(
+            (*updated_warehouse).clone(),
+            credential_type,
+        ))
+    }
+
+    async fn update_warehouse_settings(
+        warehouse_id: WarehouseId,
+        request: UpdateWarehouseSettingsRequest,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<GetWarehouseResponse> {
+        let settings = validate_warehouse_settings(request.settings.clone())?;
+
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::ModifySettings,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active_and_inactive(),
+            CachePolicy::Skip,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let event_ctx = event_ctx.resolve(warehouse);
+
+        // ------------------- Business Logic -------------------
+        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
+        C::ensure_warehouse_spec_mutable(
+            warehouse_id,
+            event_ctx.action(),
+            event_ctx
+                .request_metadata()
+                .bypasses_control_plane_authz(None),
+            transaction.transaction(),
+        )
+        .await
+        .map_err(|e| spec_lock_to_error(&event_ctx, e))?;
+        let updated_warehouse =
+            C::set_warehouse_settings(warehouse_id, &settings, transaction.transaction()).await?;
+        transaction.commit().await?;
+
+        event_ctx.emit_warehouse_settings_updated(Arc::new(request), updated_warehouse.clone());
+
+        let credential_type =
+            resolve_credential_type(&updated_warehouse, &context.v1_state.secrets).await;
+        Ok(GetWarehouseResponse::from_resolved(
+            (*updated_warehouse).clone(),
+            credential_type,
+        ))
+    }
+
+    async fn deactivate_warehouse(
+        warehouse_id: WarehouseId,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<()> {
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::Deactivate,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active_and_inactive(),
+            CachePolicy::Skip,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- Business Logic -------------------
+        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
+
+        C::ensure_warehouse_spec_mutable(
+            warehouse_id,
+            event_ctx.action(),
+            event_ctx
+                .request_metadata()
+                .bypasses_control_plane_authz(None),
+            transaction.transaction(),
+        )
+        .await
+        .map_err(|e| spec_lock_to_error(&event_ctx, e))?;
+
+        C::set_warehouse_status(
+            warehouse_id,
+            WarehouseStatus::Inactive,
+            transaction.transaction(),
+        )
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn activate_warehouse(
+        warehouse_id: WarehouseId,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<()> {
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::Activate,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active_and_inactive(),
+            CachePolicy::Skip,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+
+        // ------------------- Business Logic -------------------
+        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
+
+        C::ensure_warehouse_spec_mutable(
+            warehouse_id,
+            event_ctx.action(),
+            event_ctx
+                .request_metadata()
+                .bypasses_control_plane_authz(None),
+            transaction.transaction(),
+        )
+        .await
+        .map_err(|e| spec_lock_to_error(&event_ctx, e))?;
+
+        C::set_warehouse_status(
+            warehouse_id,
+            WarehouseStatus::Active,
+            transaction.transaction(),
+        )
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn update_storage(
+        warehouse_id: WarehouseId,
+        request: UpdateWarehouseStorageRequest,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<GetWarehouseResponse> {
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_warehouse(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            CatalogWarehouseAction::UpdateStorage,
+        );
+
+        let warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active(),
+            CachePolicy::Skip,
+            context.v1_state.catalog.clone(),
+        )
+        .await;
+        let authz_result = authorizer
+            .require_warehouse_action(
+                event_ctx.request_metadata(),
+                warehouse_id,
+                warehouse,
+                event_ctx.action().clone(),
+            )
+            .await;
+        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let event_ctx = event_ctx.resolve(warehouse.clone());
+
+        // ------------------- Business Logic -------------------
+        let request_for_event = Arc::new(request.clone());
+        let UpdateWarehouseStorageRequest {
+            storage_profile,
+            storage_credential,
+        } = request;
+        let credential_type = storage_credential
+            .as_ref()
+            .map(StorageCredential::credential_type);
+
+        let updated_warehouse = update_authorized_storage::<C, S>(
+            &warehouse,
+            StorageProfileSlot::Primary,
+            storage_profile,
+            storage_credential,
+            false,
+            &event_ctx,
+            context.v1_state.catalog,
+            &context.v1_state.secrets,
+        )
+        .await?;
 
-        event_ctx.emit_warehouse_format_version_policy_updated(
-            Arc::new(request),
-            updated_warehouse.clone(),
-        );
+        event_ctx.emit_warehouse_storage_updated(request_for_event, updated_warehouse.clone());
 
-        let credential_type =
-            resolve_credential_type(&updated_warehouse, &context.v1_state.secrets).await;
         Ok(GetWarehouseResponse::from_resolved(
             (*updated_warehouse).clone(),
             credential_type,
         ))
     }
 
-    async fn deactivate_warehouse(
+    async fn update_secondary_storage(
         warehouse_id: WarehouseId,
+        request: UpdateWarehouseStorageRequest,
         context: ApiContext<State<A, C, S>>,
         request_metadata: RequestMetadata,
-    ) -> Result<()> {
+    ) -> Result<GetWarehouseResponse> {
         // ------------------- AuthZ -------------------
         let authorizer = context.v1_state.authz;
 
@@ -1043,12 +1954,12 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
             Arc::new(request_metadata),
             context.v1_state.events.clone(),
             warehouse_id,
-            CatalogWarehouseAction::Deactivate,
+            CatalogWarehouseAction::UpdateStorage,
         );
 
         let warehouse = C::get_warehouse_by_id_cache_aware(
             warehouse_id,
-            WarehouseStatus::active_and_inactive(),
+            WarehouseStatus::active(),
             CachePolicy::Skip,
             context.v1_state.catalog.clone(),
         )
@@ -1061,39 +1972,45 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
                 event_ctx.action().clone(),
             )
             .await;
-        let (event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let event_ctx = event_ctx.resolve(warehouse.clone());
 
         // ------------------- Business Logic -------------------
-        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
-
-        C::ensure_warehouse_spec_mutable(
-            warehouse_id,
-            event_ctx.action(),
-            event_ctx
-                .request_metadata()
-                .bypasses_control_plane_authz(None),
-            transaction.transaction(),
-        )
-        .await
-        .map_err(|e| spec_lock_to_error(&event_ctx, e))?;
+        let request_for_event = Arc::new(request.clone());
+        let UpdateWarehouseStorageRequest {
+            storage_profile,
+            storage_credential,
+        } = request;
 
-        C::set_warehouse_status(
-            warehouse_id,
-            WarehouseStatus::Inactive,
-            transaction.transaction(),
+        let updated_warehouse = update_authorized_storage::<C, S>(
+            &warehouse,
+            StorageProfileSlot::Secondary,
+            storage_profile,
+            storage_credential,
+            false,
+            &event_ctx,
+            context.v1_state.catalog,
+            &context.v1_state.secrets,
         )
         .await?;
 
-        transaction.commit().await?;
+        event_ctx
+            .emit_warehouse_secondary_storage_updated(request_for_event, updated_warehouse.clone());
 
-        Ok(())
+        let credential_type =
+            resolve_credential_type(&updated_warehouse, &context.v1_state.secrets).await;
+        Ok(GetWarehouseResponse::from_resolved(
+            (*updated_warehouse).clone(),
+            credential_type,
+        ))
     }
 
-    async fn activate_warehouse(
+    async fn merge_storage(
         warehouse_id: WarehouseId,
+        request: MergeWarehouseStorageRequest,
         context: ApiContext<State<A, C, S>>,
         request_metadata: RequestMetadata,
-    ) -> Result<()> {
+    ) -> Result<GetWarehouseResponse> {
         // ------------------- AuthZ -------------------
         let authorizer = context.v1_state.authz;
 
@@ -1101,12 +2018,12 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
             Arc::new(request_metadata),
             context.v1_state.events.clone(),
             warehouse_id,
-            CatalogWarehouseAction::Activate,
+            CatalogWarehouseAction::UpdateStorage,
         );
 
         let warehouse = C::get_warehouse_by_id_cache_aware(
             warehouse_id,
-            WarehouseStatus::active_and_inactive(),
+            WarehouseStatus::active(),
             CachePolicy::Skip,
             context.v1_state.catalog.clone(),
         )
@@ -1119,37 +2036,48 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
                 event_ctx.action().clone(),
             )
             .await;
-        let (event_ctx, _) = event_ctx.emit_authz(authz_result)?;
+        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let event_ctx = event_ctx.resolve(warehouse.clone());
 
         // ------------------- Business Logic -------------------
-        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
-
-        C::ensure_warehouse_spec_mutable(
-            warehouse_id,
-            event_ctx.action(),
-            event_ctx
-                .request_metadata()
-                .bypasses_control_plane_authz(None),
-            transaction.transaction(),
-        )
-        .await
-        .map_err(|e| spec_lock_to_error(&event_ctx, e))?;
+        let MergeWarehouseStorageRequest {
+            storage_profile: patch,
+            storage_credential,
+        } = request;
+        let storage_profile = merge_storage_profile(&warehouse.storage_profile, patch)?;
 
-        C::set_warehouse_status(
-            warehouse_id,
-            WarehouseStatus::Active,
-            transaction.transaction(),
+        // Re-use the existing credential unless a new one is provided
+        let updated_warehouse = update_authorized_storage::<C, S>(
+            &warehouse,
+            StorageProfileSlot::Primary,
+            storage_profile,
+            storage_credential.clone(),
+            true,
+            &event_ctx,
+            context.v1_state.catalog,
+            &context.v1_state.secrets,
         )
         .await?;
 
-        transaction.commit().await?;
+        event_ctx.emit_warehouse_storage_updated(
+            Arc::new(UpdateWarehouseStorageRequest {
+                storage_profile: updated_warehouse.storage_profile.clone(),
+                storage_credential,
+            }),
+            updated_warehouse.clone(),
+        );
 
-        Ok(())
+        let credential_type =
+            resolve_credential_type(&updated_warehouse, &context.v1_state.secrets).await;
+        Ok(GetWarehouseResponse::from_resolved(
+            (*updated_warehouse).clone(),
+            credential_type,
+        ))
     }
 
-    async fn update_storage(
+    async fn merge_secondary_storage(
         warehouse_id: WarehouseId,
-        request: UpdateWarehouseStorageRequest,
+        request: MergeWarehouseStorageRequest,
         context: ApiContext<State<A, C, S>>,
         request_metadata: RequestMetadata,
     ) -> Result<GetWarehouseResponse> {
@@ -1182,77 +2110,37 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         let event_ctx = event_ctx.resolve(warehouse.clone());
 
         // ------------------- Business Logic -------------------
-        let request_for_event = Arc::new(request.clone());
-        let UpdateWarehouseStorageRequest {
-            mut storage_profile,
+        let MergeWarehouseStorageRequest {
+            storage_profile: patch,
             storage_credential,
         } = request;
+        let (current_profile, _) = warehouse.storage(StorageProfileSlot::Secondary)?;
+        let storage_profile = merge_storage_profile(current_profile, patch)?;
 
-        storage_profile.normalize(storage_credential.as_ref())?;
-        Box::pin(storage_profile.validate_access(
-            storage_credential.as_ref(),
-            None,
-            event_ctx.request_metadata(),
-        ))
-        .await?;
-
-        let credential_type = storage_credential
-            .as_ref()
-            .map(StorageCredential::credential_type);
-        let mut transaction = C::Transaction::begin_write(context.v1_state.catalog).await?;
-        C::ensure_warehouse_spec_mutable(
-            warehouse_id,
-            event_ctx.action(),
-            event_ctx
-                .request_metadata()
-                .bypasses_control_plane_authz(None),
-            transaction.transaction(),
-        )
-        .await
-        .map_err(|e| spec_lock_to_error(&event_ctx, e))?;
-        let storage_profile = warehouse
-            .storage_profile
-            .clone()
-            .update_with(storage_profile)?;
-        let old_secret_id = warehouse.storage_secret_id;
-
-        let secret_id = if let Some(storage_credential) = storage_credential {
-            Some(
-                context
-                    .v1_state
-                    .secrets
-                    .create_storage_secret(storage_credential)
-                    .await?,
-            )
-        } else {
-            None
-        };
-
-        let updated_warehouse = C::update_storage_profile(
-            warehouse_id,
+        // Re-use the existing credential unless a new one is provided
+        let updated_warehouse = update_authorized_storage::<C, S>(
+            &warehouse,
+            StorageProfileSlot::Secondary,
             storage_profile,
-            secret_id,
-            transaction.transaction(),
+            storage_credential.clone(),
+            true,
+            &event_ctx,
+            context.v1_state.catalog,
+            &context.v1_state.secrets,
         )
         .await?;
 
-        transaction.commit().await?;
-
-        event_ctx.emit_warehouse_storage_updated(request_for_event, updated_warehouse.clone());
-
-        // Delete the old secret if it exists - never fail the request if the deletion fails
-        if let Some(old_secret_id) = old_secret_id {
-            context
-                .v1_state
-                .secrets
-                .delete_secret(&old_secret_id)
-                .await
-                .map_err(|e| {
-                    tracing::warn!(error=?e.error, "Failed to delete old storage secret");
-                })
-                .ok();
-        }
+        let (storage_profile, _) = updated_warehouse.storage(StorageProfileSlot::Secondary)?;
+        event_ctx.emit_warehouse_secondary_storage_updated(
+            Arc::new(UpdateWarehouseStorageRequest {
+                storage_profile: storage_profile.clone(),
+                storage_credential,
+            }),
+            updated_warehouse.clone(),
+        );
 
+        let credential_type =
+            resolve_credential_type(&updated_warehouse, &context.v1_state.secrets).await;
         Ok(GetWarehouseResponse::from_resolved(
             (*updated_warehouse).clone(),
             credential_type,
@@ -1374,7 +2262,18 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
         request: UndropTabularsRequest,
         context: ApiContext<State<A, C, S>>,
     ) -> Result<()> {
-        if request.targets.is_empty() {
+        let mut targets = request.targets.clone();
+        if let Some(namespace_id) = request.namespace_id {
+            let soft_deleted = undrop::list_soft_deleted_tabular_ids::<C>(
+                warehouse_id,
+                namespace_id,
+                context.v1_state.catalog.clone(),
+            )
+            .await?;
+            targets.extend(soft_deleted);
+            targets = targets.into_iter().unique().collect();
+        }
+        if targets.is_empty() {
             return Ok(());
         }
         // ------------------- AuthZ -------------------
@@ -1386,7 +2285,7 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
             request_metadata.clone(),
             context.v1_state.events.clone(),
             warehouse_id,
-            request.targets.clone(),
+            targets,
             TabularAction {
                 table_action: CatalogTableAction::Undrop,
                 view_action: CatalogViewAction::Undrop,
@@ -1394,54 +2293,191 @@ pub trait Service<C: CatalogStore, A: Authorizer, S: SecretStore> {
             },
         );
 
-        let authz_result = undrop::require_undrop_permissions::<A, C>(
+        let authz_result = undrop::require_undrop_permissions::<A, C>(
+            warehouse_id,
+            &event_ctx.user_provided_entity().tabulars,
+            &authorizer,
+            context.v1_state.catalog.clone(),
+            event_ctx.request_metadata(),
+        )
+        .await;
+        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let event_ctx = event_ctx.resolve(warehouse.clone());
+
+        // ------------------- Business Logic -------------------
+        let catalog = context.v1_state.catalog;
+        let mut transaction = C::Transaction::begin_write(catalog.clone()).await?;
+        let tabular_ids = &event_ctx.user_provided_entity().tabulars;
+        // Restored tables count towards the table quota of the warehouse again.
+        let undropped_tables = tabular_ids.iter().filter(|id| id.is_table()).count();
+        C::ensure_warehouse_quota(
+            &warehouse,
+            WarehouseQuotaEntity::Table,
+            u32::try_from(undropped_tables).unwrap_or(u32::MAX),
+            transaction.transaction(),
+        )
+        .await?;
+        let undrop_tabular_responses =
+            C::clear_tabular_deleted_at(tabular_ids, warehouse_id, transaction.transaction())
+                .await?;
+        // Cancel expiration tasks that a worker already picked up, too. The worker
+        // re-checks its task after dropping the tabular and rolls back if it is gone.
+        TabularExpirationTask::cancel_scheduled_tasks::<C>(
+            CancelTasksFilter::TaskIds(
+                undrop_tabular_responses
+                    .iter()
+                    .filter_map(|r| {
+                        if r.expiration_task().is_none() {
+                            tracing::warn!(
+                                "No expiration task found for tabular '{}' with soft deletion marker set.",
+                                r.tabular_ident()
+                            );
+                        }
+                        r.expiration_task().map(|t| t.task_id)})
+                    .collect(),
+            ),
+            transaction.transaction(),
+            true,
+        )
+        .await?;
+        transaction.commit().await?;
+
+        event_ctx.emit_tabular_undropped(
+            warehouse,
+            Arc::new(request),
+            Arc::new(
+                undrop_tabular_responses
+                    .into_iter()
+                    .map(ViewOrTableDeletionInfo::into_table_or_view_info)
+                    .collect(),
+            ),
+        );
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
+    async fn purge_deleted_tabular(
+        warehouse_id: WarehouseId,
+        tabular_id: uuid::Uuid,
+        query: PurgeDeletedTabularQuery,
+        context: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<()> {
+        let PurgeDeletedTabularQuery { force } = query;
+        let catalog = context.v1_state.catalog;
+
+        // The path only carries the id, the type of the tabular is looked up.
+        // Unknown ids are reported as missing tables during authorization.
+        let candidates = [
+            TabularId::Table(tabular_id.into()),
+            TabularId::View(tabular_id.into()),
+            TabularId::GenericTable(tabular_id.into()),
+        ];
+        let tabular_id = C::get_tabular_infos_by_id(
+            warehouse_id,
+            &candidates,
+            TabularListFlags::all(),
+            catalog.clone(),
+        )
+        .await?
+        .first()
+        .map_or(candidates[0], ViewOrTableInfo::tabular_id);
+
+        // ------------------- AuthZ -------------------
+        let authorizer = context.v1_state.authz;
+
+        let event_ctx = APIEventContext::for_tabulars(
+            Arc::new(request_metadata),
+            context.v1_state.events.clone(),
+            warehouse_id,
+            vec![tabular_id],
+            TabularAction {
+                table_action: CatalogTableAction::Drop { force, purge: true },
+                view_action: CatalogViewAction::Drop { force, purge: true },
+                generic_table_action: CatalogGenericTableAction::Drop,
+            },
+        );
+
+        let authz_result = undrop::require_tabular_permissions::<A, C>(
             warehouse_id,
             &event_ctx.user_provided_entity().tabulars,
+            event_ctx.action(),
             &authorizer,
-            context.v1_state.catalog.clone(),
+            catalog.clone(),
             event_ctx.request_metadata(),
         )
         .await;
-        let (event_ctx, warehouse) = event_ctx.emit_authz(authz_result)?;
+        let (event_ctx, (warehouse, tabulars)) = event_ctx.emit_authz(authz_result)?;
         let event_ctx = event_ctx.resolve(warehouse.clone());
+        let Some(tabular) = tabulars.into_iter().next() else {
+            return Err(ErrorModel::internal(
+                "Authorized tabular not returned by catalog",
+                "TabularNotReturned",
+                None,
+            )
+            .into());
+        };
 
         // ------------------- Business Logic -------------------
-        let catalog = context.v1_state.catalog;
-        let mut transaction = C::Transaction::begin_write(catalog.clone()).await?;
-        let tabular_ids = &event_ctx.user_provided_entity().tabulars;
-        let undrop_tabular_responses =
-            C::clear_tabular_deleted_at(tabular_ids, warehouse_id, transaction.transaction())
-                .await?;
-        TabularExpirationTask::cancel_scheduled_tasks::<C>(
-            CancelTasksFilter::TaskIds(
-                undrop_tabular_responses
-                    .iter()
-                    .filter_map(|r| {
-                        if r.expiration_task().is_none() {
-                            tracing::warn!(
-                                "No expiration task found for tabular '{}' with soft deletion marker set.",
-                                r.tabular_ident()
-                            );
-                        }
-                        r.expiration_task().map(|t| t.task_id)})
-                    .collect(),
-            ),
+        let mut transaction = C::Transaction::begin_write(catalog).await?;
+        let Some(deletion_info) =
+            C::get_soft_deleted_tabular(warehouse_id, tabular_id, transaction.transaction())
+                .await?
+        else {
+            return Err(ErrorModel::not_found(
+                format!(
+                    "Tabular '{tabular_id}' in warehouse '{warehouse_id}' is not soft-deleted."
+                ),
+                "TabularNotSoftDeleted",
+                None,
+            )
+            .into());
+        };
+        let location =
+            C::drop_tabular(warehouse_id, tabular_id, force, transaction.transaction()).await?;
+        if let Some(expiration_task) = deletion_info.expiration_task() {
+            TabularExpirationTask::cancel_scheduled_tasks::<C>(
+                CancelTasksFilter::TaskIds(vec![expiration_task.task_id]),
+                transaction.transaction(),
+                false,
+            )
+            .await?;
+        }
+        TabularPurgeTask::schedule_task::<C>(
+            ScheduleTaskMetadata {
+                project_id: warehouse.project_id.clone(),
+                parent_task_id: None,
+                scheduled_for: None,
+                entity: TaskEntity::EntityInWarehouse {
+                    entity_name: tabular.tabular_ident().clone().into_name_parts(),
+                    warehouse_id,
+                    entity_id: WarehouseTaskEntityId::from(tabular_id),
+                },
+            },
+            TabularPurgePayload::new(location.to_string()),
             transaction.transaction(),
-            false,
         )
         .await?;
         transaction.commit().await?;
+        tracing::debug!("Queued purge task for purged tabular '{tabular_id}'.");
 
-        event_ctx.emit_tabular_undropped(
-            warehouse,
-            Arc::new(request),
-            Arc::new(
-                undrop_tabular_responses
-                    .into_iter()
-                    .map(ViewOrTableDeletionInfo::into_table_or_view_info)
-                    .collect(),
-            ),
-        );
+        // Best-effort authz cleanup, the tabular is already gone from the catalog.
+        let authz_cleanup = match tabular_id {
+            TabularId::Table(id) => authorizer.delete_table(warehouse_id, id).await,
+            TabularId::View(id) => authorizer.delete_view(warehouse_id, id).await,
+            TabularId::GenericTable(id) => authorizer.delete_generic_table(warehouse_id, id).await,
+        };
+        authz_cleanup
+            .inspect_err(|e| {
+                tracing::error!(
+                    "Failed to delete purged tabular '{tabular_id}' from authorizer: {}",
+                    e.error
+                );
+            })
+            .ok();
+
+        event_ctx.emit_deleted_tabular_purged(Arc::new(tabular), force);
 
         Ok(())
     }
@@ -1719,13 +2755,106 @@ impl axum::response::IntoResponse for ListWarehousesResponse {
     }
 }
 
-impl axum::response::IntoResponse for GetWarehouseResponse {
+impl axum::response::IntoResponse for GetWarehouseCatalogConfigResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        axum::Json(self).into_response()
+    }
+}
+
+impl axum::response::IntoResponse for GetWarehouseVersionResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        axum::Json(self).into_response()
+    }
+}
+
+impl axum::response::IntoResponse for ListProtectedEntitiesResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        axum::Json(self).into_response()
+    }
+}
+
+impl axum::response::IntoResponse for ValidateStorageResponse {
     fn into_response(self) -> axum::http::Response<axum::body::Body> {
         axum::Json(self).into_response()
     }
 }
 
+impl axum::response::IntoResponse for GetWarehouseResponse {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        let etag = self.etag();
+        let mut response = axum::Json(self).into_response();
+        match etag.as_str().parse::<http::HeaderValue>() {
+            Ok(header_value) => {
+                response
+                    .headers_mut()
+                    .insert(http::header::ETAG, header_value);
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to create valid ETAG header from String {}, error: {e}",
+                    etag.as_str()
+                );
+            }
+        }
+        response
+    }
+}
+
+/// A [`GetWarehouseResponse`], or `304 Not Modified` if the representation
+/// cached by the client is still current.
+#[derive(Debug, Clone)]
+pub enum GetWarehouseResponseOrNotModified {
+    Warehouse(GetWarehouseResponse),
+    NotModified(ETag),
+}
+
+impl GetWarehouseResponseOrNotModified {
+    /// Answer with `NotModified` if any of the `If-None-Match` values matches
+    /// the [`ETag`] of `response`.
+    #[must_use]
+    pub fn new(response: GetWarehouseResponse, if_none_match: &[ETag]) -> Self {
+        let etag = response.etag();
+        // Client-supplied ETags have their quotes stripped.
+        let current = etag.as_str().trim_matches('"');
+        if if_none_match
+            .iter()
+            .any(|client| client.as_str() == "*" || client.as_str() == current)
+        {
+            Self::NotModified(etag)
+        } else {
+            Self::Warehouse(response)
+        }
+    }
+}
+
+impl axum::response::IntoResponse for GetWarehouseResponseOrNotModified {
+    fn into_response(self) -> axum::http::Response<axum::body::Body> {
+        match self {
+            Self::Warehouse(response) => response.into_response(),
+            Self::NotModified(etag) => {
+                let mut headers = http::HeaderMap::new();
+                if let Ok(header_value) = etag.as_str().parse::<http::HeaderValue>() {
+                    headers.insert(http::header::ETAG, header_value);
+                }
+                (http::StatusCode::NOT_MODIFIED, headers).into_response()
+            }
+        }
+    }
+}
+
 impl GetWarehouseResponse {
+    /// Strong [`ETag`] of this representation.
+    ///
+    /// Derived from the serialized response, which includes `updated-at`, so it
+    /// changes whenever the warehouse is updated or, if requested, its entity
+    /// counts change.
+    #[must_use]
+    pub fn etag(&self) -> ETag {
+        let body = serde_json::to_vec(self).unwrap_or_default();
+        let hash = xxhash_rust::xxh3::xxh3_64(&body);
+        ETag::from(format!("\"wh1.{hash:x}\""))
+    }
+
     fn from_resolved(
         warehouse: crate::service::ResolvedWarehouse,
         storage_credential_type: Option<StorageCredentialType>,
@@ -1737,15 +2866,27 @@ impl GetWarehouseResponse {
             project_id: warehouse.project_id,
             storage_profile: warehouse.storage_profile,
             storage_credential_type,
+            secondary_storage_profile: warehouse.secondary_storage.map(|s| s.storage_profile),
             status: warehouse.status,
             delete_profile: warehouse.tabular_delete_profile,
             protected: warehouse.protected,
             managed_by: warehouse.managed_by,
             allowed_format_versions: warehouse.allowed_format_versions.to_vec(),
             default_format_version: warehouse.default_format_version,
+            settings: warehouse.settings,
+            num_namespaces: None,
+            num_tables: None,
+            num_views: None,
             updated_at: warehouse.updated_at,
         }
     }
+
+    fn with_entity_counts(mut self, counts: WarehouseEntityCounts) -> Self {
+        self.num_namespaces = Some(counts.num_namespaces);
+        self.num_tables = Some(counts.num_tables);
+        self.num_views = Some(counts.num_views);
+        self
+    }
 }
 
 /// Resolves the credential type for a warehouse by looking up the secret.
@@ -1778,7 +2919,7 @@ async fn resolve_credential_type<S: SecretStore>(
 /// allowed the action, so the lock is the decision that denied it — and is recorded
 /// as a late authorization-failure audit event. Backend/integrity errors propagate
 /// without an authz event.
-fn spec_lock_to_error<P, R, A>(
+pub(crate) fn spec_lock_to_error<P, R, A>(
     event_ctx: &APIEventContext<P, R, A, AuthzChecked>,
     err: EnsureWarehouseSpecMutableError,
 ) -> ErrorModel
@@ -1795,6 +2936,252 @@ where
     }
 }
 
+/// Validate a new storage profile for `slot` of a warehouse and store it together
+/// with its storage secret. Callers must have authorized
+/// [`CatalogWarehouseAction::UpdateStorage`] on the warehouse.
+///
+/// `storage_credential` replaces the current secret of the slot. Without a new
+/// credential, the current secret is kept if `keep_secret` is set and removed otherwise.
+/// Replaced secrets are deleted after the update is committed.
+#[allow(clippy::too_many_arguments)]
+async fn update_authorized_storage<C: CatalogStore, S: SecretStore>(
+    warehouse: &ResolvedWarehouse,
+    slot: StorageProfileSlot,
+    mut storage_profile: StorageProfile,
+    storage_credential: Option<StorageCredential>,
+    keep_secret: bool,
+    event_ctx: &APIEventContext<
+        WarehouseId,
+        Resolved<Arc<ResolvedWarehouse>>,
+        CatalogWarehouseAction,
+        AuthzChecked,
+    >,
+    catalog_state: C::State,
+    secrets: &S,
+) -> Result<Arc<ResolvedWarehouse>> {
+    let (current_profile, old_secret_id) = match slot {
+        StorageProfileSlot::Primary => (
+            Some(&warehouse.storage_profile),
+            warehouse.storage_secret_id,
+        ),
+        StorageProfileSlot::Secondary => {
+            warehouse
+                .secondary_storage
+                .as_ref()
+                .map_or((None, None), |secondary| {
+                    (
+                        Some(&secondary.storage_profile),
+                        secondary.storage_secret_id,
+                    )
+                })
+        }
+    };
+    let kept_secret_id = old_secret_id.filter(|_| keep_secret && storage_credential.is_none());
+    let validation_credential = match (&storage_credential, kept_secret_id) {
+        (Some(storage_credential), _) => Some(storage_credential.clone()),
+        (None, Some(secret_id)) => Some(
+            secrets
+                .require_storage_secret_by_id(secret_id)
+                .await
+                .map(|secret| Arc::unwrap_or_clone(secret.secret))?,
+        ),
+        (None, None) => None,
+    };
+
+    storage_profile.normalize(validation_credential.as_ref())?;
+    if slot == StorageProfileSlot::Secondary
+        && warehouse
+            .storage_profile
+            .is_overlapping_location(&storage_profile)
+    {
+        return Err(ErrorModel::bad_request(
+            "Secondary storage profile overlaps with the storage profile of the warehouse",
+            "SecondaryStorageProfileOverlap",
+            None,
+        )
+        .into());
+    }
+    Box::pin(storage_profile.validate_access(
+        validation_credential.as_ref(),
+        None,
+        event_ctx.request_metadata(),
+    ))
+    .await?;
+
+    let mut transaction = C::Transaction::begin_write(catalog_state).await?;
+    C::ensure_warehouse_spec_mutable(
+        warehouse.warehouse_id,
+        event_ctx.action(),
+        event_ctx
+            .request_metadata()
+            .bypasses_control_plane_authz(None),
+        transaction.transaction(),
+    )
+    .await
+    .map_err(|e| spec_lock_to_error(event_ctx, e))?;
+    // Tables may already be stored on the storage, so its location must not change once set.
+    let storage_profile = match current_profile {
+        Some(current_profile) => current_profile.clone().update_with(storage_profile)?,
+        None => storage_profile,
+    };
+
+    let secret_id = if let Some(storage_credential) = storage_credential {
+        Some(secrets.create_storage_secret(storage_credential).await?)
+    } else {
+        kept_secret_id
+    };
+
+    let updated_warehouse = match slot {
+        StorageProfileSlot::Primary => {
+            C::update_storage_profile(
+                warehouse.warehouse_id,
+                storage_profile,
+                secret_id,
+                transaction.transaction(),
+            )
+            .await?
+        }
+        StorageProfileSlot::Secondary => {
+            C::update_secondary_storage_profile(
+                warehouse.warehouse_id,
+                storage_profile,
+                secret_id,
+                transaction.transaction(),
+            )
+            .await?
+        }
+    };
+
+    transaction.commit().await?;
+
+    // Delete the old secret if it was replaced - never fail the request if the deletion fails
+    if let Some(old_secret_id) = old_secret_id.filter(|id| Some(*id) != secret_id) {
+        secrets
+            .delete_secret(&old_secret_id)
+            .await
+            .map_err(|e| {
+                tracing::warn!(error=?e.error, "Failed to delete old storage secret");
+            })
+            .ok();
+    }
+
+    Ok(updated_warehouse)
+}
+
+/// Validate the storage of a new warehouse and create it together with its
+/// storage secret and `task_queue_configs` in a single transaction.
+///
+/// Callers must have authorized the creation in `project_id`.
+async fn create_authorized_warehouse<C: CatalogStore, A: Authorizer, S: SecretStore>(
+    project_id: &ProjectId,
+    mut warehouse: CatalogCreateWarehouseRequest,
+    storage_credential: Option<StorageCredential>,
+    mut secondary_storage: Option<(StorageProfile, Option<StorageCredential>)>,
+    task_queue_configs: Vec<(&'static TaskQueueName, SetTaskQueueConfigRequest)>,
+    context: &ApiContext<State<A, C, S>>,
+    request_metadata: &RequestMetadata,
+) -> Result<(Arc<ResolvedWarehouse>, Option<StorageCredentialType>)> {
+    validate_warehouse_name(&warehouse.warehouse_name)?;
+    warehouse
+        .storage_profile
+        .normalize(storage_credential.as_ref())?;
+    if let Some((secondary_profile, secondary_credential)) = &mut secondary_storage {
+        secondary_profile.normalize(secondary_credential.as_ref())?;
+        if warehouse
+            .storage_profile
+            .is_overlapping_location(secondary_profile)
+        {
+            return Err(ErrorModel::bad_request(
+                "Secondary storage profile overlaps with the storage profile of the warehouse",
+                "SecondaryStorageProfileOverlap",
+                None,
+            )
+            .into());
+        }
+        Box::pin(secondary_profile.validate_access(
+            secondary_credential.as_ref(),
+            None,
+            request_metadata,
+        ))
+        .await?;
+    }
+
+    // Run credential validation and storage-overlap check in parallel
+    let validation_future = warehouse.storage_profile.validate_access(
+        storage_credential.as_ref(),
+        None,
+        request_metadata,
+    );
+    let overlap_check_future = ensure_no_storage_overlap::<C>(
+        project_id,
+        &warehouse.storage_profile,
+        context.v1_state.catalog.clone(),
+    );
+
+    let (validation_result, overlap_result) = tokio::join!(validation_future, overlap_check_future);
+
+    // Check results from both operations
+    validation_result?;
+    overlap_result?;
+
+    let credential_type = storage_credential
+        .as_ref()
+        .map(StorageCredential::credential_type);
+    let mut transaction = C::Transaction::begin_write(context.v1_state.catalog.clone()).await?;
+    if let Some(storage_credential) = storage_credential {
+        warehouse.storage_secret_id = Some(
+            context
+                .v1_state
+                .secrets
+                .create_storage_secret(storage_credential)
+                .await?,
+        );
+    }
+
+    let mut resolved_warehouse =
+        C::create_warehouse(project_id, warehouse, transaction.transaction()).await?;
+    let warehouse_id = resolved_warehouse.warehouse_id;
+    if let Some((secondary_profile, secondary_credential)) = secondary_storage {
+        let secondary_secret_id = if let Some(secondary_credential) = secondary_credential {
+            Some(
+                context
+                    .v1_state
+                    .secrets
+                    .create_storage_secret(secondary_credential)
+                    .await?,
+            )
+        } else {
+            None
+        };
+        resolved_warehouse = C::update_secondary_storage_profile(
+            warehouse_id,
+            secondary_profile,
+            secondary_secret_id,
+            transaction.transaction(),
+        )
+        .await?;
+    }
+    for (queue_name, config) in &task_queue_configs {
+        C::set_task_queue_config(
+            resolved_warehouse.project_id.clone(),
+            Some(warehouse_id),
+            queue_name,
+            config,
+            transaction.transaction(),
+        )
+        .await?;
+    }
+    context
+        .v1_state
+        .authz
+        .create_warehouse(request_metadata, warehouse_id, project_id)
+        .await?;
+
+    transaction.commit().await?;
+
+    Ok((resolved_warehouse, credential_type))
+}
+
 /// Reject creation when the new storage profile overlaps the location of an
 /// existing warehouse in the same project.
 async fn ensure_no_storage_overlap<C: CatalogStore>(
@@ -1804,26 +3191,105 @@ async fn ensure_no_storage_overlap<C: CatalogStore>(
 ) -> Result<()> {
     // Include inactive warehouses: a deactivated warehouse still occupies its
     // storage location, so a new warehouse overlapping it is still a conflict.
+    match find_overlapping_warehouse::<C>(project_id, storage_profile, catalog_state).await? {
+        Some(warehouse_name) => Err(storage_overlap_error(&warehouse_name).into()),
+        None => Ok(()),
+    }
+}
+
+/// Name of a warehouse of the project whose storage overlaps with `storage_profile`.
+async fn find_overlapping_warehouse<C: CatalogStore>(
+    project_id: &ProjectId,
+    storage_profile: &StorageProfile,
+    catalog_state: C::State,
+) -> Result<Option<String>> {
     let warehouses = C::list_warehouses(
         project_id,
         Some(WarehouseStatus::active_and_inactive().to_vec()),
         catalog_state,
     )
     .await?;
-    for w in &warehouses {
-        if storage_profile.is_overlapping_location(&w.storage_profile) {
-            return Err(ErrorModel::bad_request(
-                format!(
-                    "Storage profile overlaps with existing warehouse {}",
-                    w.name
-                ),
-                "CreateWarehouseStorageProfileOverlap",
-                None,
-            )
-            .into());
+    Ok(warehouses
+        .iter()
+        .find(|w| storage_profile.is_overlapping_location(&w.storage_profile))
+        .map(|w| w.name.clone()))
+}
+
+fn storage_overlap_error(warehouse_name: &str) -> ErrorModel {
+    ErrorModel::bad_request(
+        format!("Storage profile overlaps with existing warehouse {warehouse_name}"),
+        "CreateWarehouseStorageProfileOverlap",
+        None,
+    )
+}
+
+/// Report a failed validation of a storage profile. Internal errors are not a
+/// property of the storage profile and are returned as errors instead.
+fn validation_error_to_response(error: ValidationError) -> Result<ValidateStorageResponse> {
+    let check = match &error {
+        ValidationError::InvalidProfile(_)
+        | ValidationError::InvalidLocation(_)
+        | ValidationError::UnsupportedCompressionCodec(_) => StorageValidationCheck::Profile,
+        ValidationError::Credentials(_) => StorageValidationCheck::Credentials,
+        ValidationError::IoOperationFailed(_)
+        | ValidationError::Deserialization(_)
+        | ValidationError::FileDecompression(_) => StorageValidationCheck::Access,
+        ValidationError::Internal(_) => return Err(error.into()),
+    };
+    Ok(ValidateStorageResponse::failed(check, error.into()))
+}
+
+/// Apply `patch` as a JSON merge patch (RFC 7396) to the serialized `current`
+/// profile. The storage type can not be changed by a merge.
+fn merge_storage_profile(
+    current: &StorageProfile,
+    patch: serde_json::Map<String, serde_json::Value>,
+) -> Result<StorageProfile> {
+    fn merge(target: &mut serde_json::Value, patch: serde_json::Value) {
+        let serde_json::Value::Object(patch) = patch else {
+            *target = patch;
+            return;
+        };
+        if !target.is_object() {
+            *target = serde_json::Value::Object(serde_json::Map::new());
+        }
+        if let serde_json::Value::Object(target) = target {
+            for (key, value) in patch {
+                if value.is_null() {
+                    target.remove(&key);
+                } else {
+                    merge(target.entry(key).or_insert(serde_json::Value::Null), value);
+                }
+            }
         }
     }
-    Ok(())
+
+    let mut merged = serde_json::to_value(current).map_err(|e| {
+        ErrorModel::internal(
+            "Failed to serialize storage profile",
+            "StorageProfileSerializationError",
+            Some(Box::new(e)),
+        )
+    })?;
+    let current_type = merged.get("type").cloned();
+    merge(&mut merged, serde_json::Value::Object(patch));
+    if merged.get("type") != current_type.as_ref() {
+        return Err(ErrorModel::bad_request(
+            "The storage type of a warehouse can not be changed by a merge",
+            "StorageTypeChangeNotAllowed",
+            None,
+        )
+        .into());
+    }
+
+    serde_json::from_value(merged).map_err(|e| {
+        ErrorModel::bad_request(
+            format!("Merged storage profile is invalid: {e}"),
+            "InvalidStorageProfile",
+            Some(Box::new(e)),
+        )
+        .into()
+    })
 }
 
 fn validate_warehouse_name(warehouse_name: &str) -> Result<()> {
@@ -1878,6 +3344,90 @@ fn validate_format_version_policy(
     })
 }
 
+/// Validate warehouse settings received via the API.
+///
+/// Policies are rebuilt through their constructors, which reject empty allow
+/// lists and normalize their order.
+fn validate_warehouse_settings(settings: WarehouseSettings) -> Result<WarehouseSettings> {
+    let WarehouseSettings {
+        partition_transform_policy,
+        file_format_policy,
+        require_metadata_in_table_location,
+        require_location_in_warehouse,
+        schema_evolution_policy,
+        max_snapshots_per_table,
+        max_table_properties,
+        max_table_properties_size_bytes,
+        max_tables,
+        max_namespaces,
+        validate_partition_statistics_files,
+        credential_mode,
+        case_sensitive_names,
+    } = settings;
+
+    let partition_transform_policy = partition_transform_policy
+        .map(|policy| {
+            PartitionTransformPolicy::try_new(
+                policy.allowed_transforms().iter().copied(),
+                policy.max_bucket_count(),
+            )
+        })
+        .transpose()
+        .map_err(ErrorModel::from)?;
+    let file_format_policy = file_format_policy
+        .map(|policy| FileFormatPolicy::try_new(policy.allowed_file_formats().iter().copied()))
+        .transpose()
+        .map_err(ErrorModel::from)?;
+
+    Ok(WarehouseSettings {
+        partition_transform_policy,
+        file_format_policy,
+        require_metadata_in_table_location,
+        require_location_in_warehouse,
+        schema_evolution_policy,
+        max_snapshots_per_table: validate_limit(
+            "max-snapshots-per-table",
+            "InvalidMaxSnapshotsPerTable",
+            max_snapshots_per_table,
+        )?,
+        max_table_properties: validate_limit(
+            "max-table-properties",
+            "InvalidTablePropertiesLimit",
+            max_table_properties,
+        )?,
+        max_table_properties_size_bytes: validate_limit(
+            "max-table-properties-size-bytes",
+            "InvalidTablePropertiesLimit",
+            max_table_properties_size_bytes,
+        )?,
+        max_tables: validate_limit("max-tables", "InvalidQuotaPolicy", max_tables)?,
+        max_namespaces: validate_limit("max-namespaces", "InvalidQuotaPolicy", max_namespaces)?,
+        validate_partition_statistics_files,
+        credential_mode,
+        case_sensitive_names,
+    })
+}
+
+/// Validate an optional limit of the warehouse settings. `None` means unbounded.
+/// Limits must be positive and fit into an `i32`, as advertised by the API schema.
+fn validate_limit(field: &str, error_type: &str, limit: Option<u32>) -> Result<Option<u32>> {
+    match limit {
+        Some(0) => {
+            Err(
+                ErrorModel::bad_request(format!("{field} must be at least 1"), error_type, None)
+                    .into(),
+            )
+        }
+        Some(max) if i32::try_from(max).is_err() => Err(ErrorModel::bad_request(
+            format!("{field} must not exceed {}", i32::MAX),
+            error_type,
+            None,
+        )
+        .into()),
+        max => Ok(max),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
@@ -1897,12 +3447,15 @@ mod test {
             project_id: Arc::new(uuid::Uuid::new_v4().into()),
             storage_profile: crate::service::storage::MemoryProfile::default().into(),
             storage_secret_id,
+            secondary_storage: None,
             status: WarehouseStatus::Active,
             tabular_delete_profile: super::TabularDeleteProfile::Hard {},
             protected: false,
+            case_sensitive_name: false,
             managed_by: crate::service::ManagedBy::SelfManaged,
             allowed_format_versions: crate::service::AllowedFormatVersions::default(),
             default_format_version: None,
+            settings: crate::service::WarehouseSettings::default(),
             updated_at: None,
             version: crate::service::WarehouseVersion::from(0),
         }
@@ -2083,4 +3636,88 @@ mod test {
         assert_eq!(s3_profile.region, "dummy");
         assert_eq!(s3_profile.path_style_access, Some(true));
     }
+
+    #[test]
+    fn test_merge_storage_profile_keeps_unspecified_fields() {
+        let profile: super::StorageProfile = serde_json::from_value(serde_json::json!({
+            "type": "s3",
+            "bucket": "test",
+            "key-prefix": "prefix",
+            "region": "us-east-1",
+            "endpoint": "http://localhost:9000",
+            "path-style-access": true,
+            "sts-enabled": true,
+        }))
+        .unwrap();
+        let patch = serde_json::json!({"region": "eu-central-1", "path-style-access": null});
+
+        let merged =
+            super::merge_storage_profile(&profile, patch.as_object().unwrap().clone()).unwrap();
+        let s3_profile = merged.try_into_s3().unwrap();
+        assert_eq!(s3_profile.region, "eu-central-1");
+        assert_eq!(s3_profile.bucket, "test");
+        assert_eq!(s3_profile.key_prefix.as_deref(), Some("prefix"));
+        assert_eq!(s3_profile.path_style_access, None);
+        assert!(s3_profile.sts_enabled);
+    }
+
+    #[test]
+    fn test_merge_storage_profile_rejects_type_change() {
+        let profile: super::StorageProfile =
+            crate::service::storage::MemoryProfile::default().into();
+        let patch = serde_json::json!({"type": "s3", "bucket": "test", "region": "us-east-1"});
+
+        let err =
+            super::merge_storage_profile(&profile, patch.as_object().unwrap().clone()).unwrap_err();
+        assert_eq!(err.error.r#type, "StorageTypeChangeNotAllowed");
+    }
+
+    #[test]
+    fn test_get_warehouse_etag_and_not_modified() {
+        use axum::response::IntoResponse as _;
+        use iceberg_ext::catalog::rest::ETag;
+
+        use super::GetWarehouseResponseOrNotModified;
+
+        let mut warehouse = test_warehouse(None);
+        let response = GetWarehouseResponse::from_resolved(warehouse.clone(), None);
+        let etag = response.etag();
+        assert_eq!(
+            GetWarehouseResponse::from_resolved(warehouse.clone(), None).etag(),
+            etag
+        );
+
+        let http_response = response.clone().into_response();
+        assert_eq!(http_response.status(), http::StatusCode::OK);
+        assert_eq!(
+            http_response.headers()[http::header::ETAG]
+                .to_str()
+                .unwrap(),
+            etag.as_str()
+        );
+
+        // A repeated request with the previous ETag is answered with 304
+        let client_etag = ETag::from(etag.as_str().trim_matches('"'));
+        let not_modified =
+            GetWarehouseResponseOrNotModified::new(response.clone(), &[client_etag.clone()])
+                .into_response();
+        assert_eq!(not_modified.status(), http::StatusCode::NOT_MODIFIED);
+        assert_eq!(
+            not_modified.headers()[http::header::ETAG].to_str().unwrap(),
+            etag.as_str()
+        );
+        assert!(matches!(
+            GetWarehouseResponseOrNotModified::new(response, &[ETag::from("*")]),
+            GetWarehouseResponseOrNotModified::NotModified(_)
+        ));
+
+        // Updating the warehouse changes the ETag
+        warehouse.updated_at = Some(chrono::Utc::now());
+        let updated = GetWarehouseResponse::from_resolved(warehouse, None);
+        assert_ne!(updated.etag(), etag);
+        assert!(matches!(
+            GetWarehouseResponseOrNotModified::new(updated, &[client_etag]),
+            GetWarehouseResponseOrNotModified::Warehouse(_)
+        ));
+    }
 }
@@ -2,10 +2,15 @@ use std::sync::Arc;
 
 use crate::{
     WarehouseId,
+    api::{
+        Result as ApiResult,
+        iceberg::v1::{PageToken, PaginationQuery},
+    },
     request_metadata::RequestMetadata,
     service::{
         CachePolicy, CatalogNamespaceOps, CatalogStore, CatalogTabularOps, CatalogWarehouseOps,
-        ResolvedWarehouse, TabularId, TabularListFlags, ViewOrTableInfo, WarehouseStatus,
+        NamespaceId, ResolvedWarehouse, TabularId, TabularListFlags, Transaction, ViewOrTableInfo,
+        WarehouseStatus,
         authz::{
             AuthZCannotSeeNamespace, AuthZCannotSeeTable, AuthZCannotSeeView,
             AuthZCannotUseWarehouseId, AuthZError, AuthZTableOps, AuthZWarehouseActionForbidden,
@@ -13,6 +18,7 @@ use crate::{
             CatalogViewAction, CatalogWarehouseAction, RequireTableActionError,
             RequireWarehouseActionError,
         },
+        events::context::TabularAction,
         require_namespace_for_tabular,
     },
 };
@@ -24,6 +30,33 @@ pub(crate) async fn require_undrop_permissions<A: Authorizer, C: CatalogStore>(
     catalog_state: C::State,
     request_metadata: &RequestMetadata,
 ) -> Result<Arc<ResolvedWarehouse>, AuthZError> {
+    let action = TabularAction {
+        table_action: CatalogTableAction::Undrop,
+        view_action: CatalogViewAction::Undrop,
+        generic_table_action: CatalogGenericTableAction::Undrop,
+    };
+    require_tabular_permissions::<A, C>(
+        warehouse_id,
+        request,
+        &action,
+        authorizer,
+        catalog_state,
+        request_metadata,
+    )
+    .await
+    .map(|(warehouse, _)| warehouse)
+}
+
+/// Require `action` on all requested tabulars, including soft-deleted ones.
+/// Returns the resolved warehouse and the infos of the requested tabulars.
+pub(super) async fn require_tabular_permissions<A: Authorizer, C: CatalogStore>(
+    warehouse_id: WarehouseId,
+    request: &[TabularId],
+    action: &TabularAction,
+    authorizer: &A,
+    catalog_state: C::State,
+    request_metadata: &RequestMetadata,
+) -> Result<(Arc<ResolvedWarehouse>, Vec<ViewOrTableInfo>), AuthZError> {
     let warehouse = C::get_warehouse_by_id_cache_aware(
         warehouse_id,
         WarehouseStatus::active(),
@@ -100,9 +133,9 @@ pub(crate) async fn require_undrop_permissions<A: Authorizer, C: CatalogStore>(
             Ok::<_, AuthZCannotSeeNamespace>((
                 require_namespace_for_tabular(&namespaces, t)?,
                 t.as_action_request(
-                    CatalogViewAction::Undrop,
-                    CatalogTableAction::Undrop,
-                    CatalogGenericTableAction::Undrop,
+                    action.view_action.clone(),
+                    action.table_action.clone(),
+                    action.generic_table_action.clone(),
                     None,
                 ),
             ))
@@ -111,7 +144,41 @@ pub(crate) async fn require_undrop_permissions<A: Authorizer, C: CatalogStore>(
     authorizer
         .require_tabular_actions(request_metadata, &warehouse, &namespaces, &actions)
         .await?;
-    Ok(warehouse)
+    Ok((warehouse, tabulars))
+}
+
+/// Ids of all soft-deleted tables, views and generic tables directly contained in
+/// the namespace, across all pages.
+pub(super) async fn list_soft_deleted_tabular_ids<C: CatalogStore>(
+    warehouse_id: WarehouseId,
+    namespace_id: NamespaceId,
+    catalog_state: C::State,
+) -> ApiResult<Vec<TabularId>> {
+    let mut transaction = C::Transaction::begin_read(catalog_state).await?;
+    let mut tabular_ids = Vec::new();
+    let mut page_token = PageToken::NotSpecified;
+    loop {
+        let page = C::list_tabulars(
+            warehouse_id,
+            Some(namespace_id),
+            TabularListFlags::only_deleted(),
+            transaction.transaction(),
+            None,
+            PaginationQuery::new(page_token, None),
+        )
+        .await?;
+        let mut next_page_token = None;
+        for (tabular_id, _, token) in page.into_iter_with_page_tokens() {
+            tabular_ids.push(tabular_id);
+            next_page_token = Some(token);
+        }
+        let Some(next_page_token) = next_page_token else {
+            break;
+        };
+        page_token = PageToken::Present(next_page_token);
+    }
+    transaction.commit().await?;
+    Ok(tabular_ids)
 }
 
 #[derive(Debug)]
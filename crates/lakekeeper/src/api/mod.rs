@@ -2,9 +2,19 @@ pub mod data;
 pub mod iceberg;
 pub mod management;
 
+#[cfg(feature = "router")]
+pub mod audit;
+#[cfg(feature = "router")]
+pub mod body_limit;
 pub mod endpoints;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 #[cfg(feature = "router")]
 pub mod maintenance;
+#[cfg(all(feature = "router", feature = "open-api"))]
+pub mod openapi_validation;
+#[cfg(feature = "router")]
+pub mod rate_limit;
 #[cfg(feature = "router")]
 pub mod router;
 pub use iceberg_ext::catalog::rest::*;
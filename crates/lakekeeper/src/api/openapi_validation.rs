@@ -0,0 +1,247 @@
+//! Detect drift between the served routes and the `OpenAPI` documentation.
+//!
+//! Axum routers cannot be enumerated, so the routes to check are passed in
+//! explicitly, usually the [`registered_routes`] of the [`Endpoint`] registry. Every route that the router serves must be documented, and every
+//! documented operation must be served by the router. Routing is probed by
+//! sending requests through the router with a route layer that short-circuits
+//! before any handler is called, so no state is ever touched.
+
+use std::fmt;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::{Method, StatusCode};
+use strum::IntoEnumIterator as _;
+use tower::ServiceExt as _;
+use utoipa::openapi::OpenApi;
+
+use crate::api::endpoints::{Endpoint, EndpointFlat};
+
+/// Value inserted for path parameters when probing the router.
+const PATH_PARAMETER_PLACEHOLDER: &str = "x";
+
+/// Result of [`validate_openapi`]. Routes are formatted as `METHOD /path`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpenApiValidationReport {
+    /// Routes served by the router without a documented operation.
+    pub undocumented_routes: Vec<String>,
+    /// Documented operations that the router does not serve.
+    pub unrouted_operations: Vec<String>,
+}
+
+impl OpenApiValidationReport {
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.undocumented_routes.is_empty() && self.unrouted_operations.is_empty()
+    }
+}
+
+impl fmt::Display for OpenApiValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_ok() {
+            return write!(
+                f,
+                "All routes are documented and all documented operations are routed."
+            );
+        }
+        for route in &self.undocumented_routes {
+            writeln!(f, "Route is not documented: {route}")?;
+        }
+        for operation in &self.unrouted_operations {
+            writeln!(f, "Documented operation is not routed: {operation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Routes of all non-deprecated endpoints of the [`Endpoint`] registry.
+/// Deprecated endpoints are still served but intentionally not documented.
+#[must_use]
+pub fn registered_routes() -> Vec<(Method, &'static str)> {
+    Endpoint::iter()
+        .filter(|endpoint| {
+            !EndpointFlat::from(*endpoint)
+                .to_string()
+                .ends_with("-deprecated")
+        })
+        .map(|endpoint| (endpoint.method(), endpoint.path()))
+        .collect()
+}
+
+/// Check `routes` and the operations of `doc` against `router`.
+///
+/// Paths use the axum / `OpenAPI` template syntax, e.g. `/warehouse/{warehouse_id}`.
+/// Routes that `router` does not serve are ignored, so `routes` may be a superset
+/// of the routes of `router`.
+pub async fn validate_openapi<'a>(
+    router: Router,
+    doc: &OpenApi,
+    routes: impl IntoIterator<Item = (Method, &'a str)>,
+) -> OpenApiValidationReport {
+    let router = router.route_layer(axum::middleware::from_fn(short_circuit));
+    let documented = documented_operations(doc);
+
+    let mut routes = routes.into_iter().collect::<Vec<_>>();
+    routes.sort_by(|(m1, p1), (m2, p2)| (p1, m1.as_str()).cmp(&(p2, m2.as_str())));
+    routes.dedup();
+
+    let mut report = OpenApiValidationReport::default();
+    for (method, path) in routes {
+        let is_routed = matched_path(&router, &method, path).await.as_deref() == Some(path);
+        let is_documented = documented
+            .iter()
+            .any(|(m, p)| *m == method && template_matches(path, p));
+        if is_routed && !is_documented {
+            report.undocumented_routes.push(format!("{method} {path}"));
+        }
+    }
+    for (method, path) in &documented {
+        let is_routed = matched_path(&router, method, path)
+            .await
+            .is_some_and(|matched| template_matches(&matched, path));
+        if !is_routed {
+            report.unrouted_operations.push(format!("{method} {path}"));
+        }
+    }
+    report
+}
+
+fn documented_operations(doc: &OpenApi) -> Vec<(Method, String)> {
+    let mut operations = Vec::new();
+    for (path, item) in &doc.paths.paths {
+        let methods = [
+            (Method::GET, &item.get),
+            (Method::PUT, &item.put),
+            (Method::POST, &item.post),
+            (Method::DELETE, &item.delete),
+            (Method::OPTIONS, &item.options),
+            (Method::HEAD, &item.head),
+            (Method::PATCH, &item.patch),
+            (Method::TRACE, &item.trace),
+        ];
+        for (method, operation) in methods {
+            if operation.is_some() {
+                operations.push((method, path.clone()));
+            }
+        }
+    }
+    operations
+}
+
+/// Route template that serves `method` on `path`, if any.
+async fn matched_path(router: &Router, method: &Method, path: &str) -> Option<String> {
+    let request = Request::builder()
+        .method(method)
+        .uri(fill_path_parameters(path))
+        .body(Body::empty())
+        .ok()?;
+    let response = router.clone().oneshot(request).await.ok()?;
+    response
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+}
+
+async fn short_circuit(matched_path: MatchedPath, _request: Request, _next: Next) -> Response {
+    let mut response = StatusCode::NO_CONTENT.into_response();
+    response.extensions_mut().insert(matched_path);
+    response
+}
+
+fn fill_path_parameters(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if is_path_parameter(segment) {
+                PATH_PARAMETER_PLACEHOLDER
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Whether `path` is an instance of `template`. Documented paths may fix a
+/// parameter of the route, e.g. the queue name of per-queue schedule paths.
+fn template_matches(template: &str, path: &str) -> bool {
+    let template = template.split('/').collect::<Vec<_>>();
+    let path = path.split('/').collect::<Vec<_>>();
+    template.len() == path.len()
+        && template
+            .iter()
+            .zip(&path)
+            .all(|(t, p)| t == p || is_path_parameter(t))
+}
+
+fn is_path_parameter(segment: &str) -> bool {
+    segment.starts_with('{') && segment.ends_with('}')
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::routing::{get, post};
+    use utoipa::openapi::{
+        OpenApiBuilder, PathItem, Paths,
+        path::{HttpMethod, OperationBuilder},
+    };
+
+    use super::*;
+
+    fn doc(path: &str, item: PathItem) -> OpenApi {
+        OpenApiBuilder::new()
+            .paths(Paths::builder().path(path, item).build())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_undocumented_route_is_detected() {
+        let router = Router::new()
+            .route("/warehouse/{warehouse_id}", get(|| async { "handler" }))
+            .route("/undocumented", post(|| async { "handler" }));
+        let mut item = PathItem::new(HttpMethod::Get, OperationBuilder::new().build());
+        item.delete = Some(OperationBuilder::new().build());
+        let doc = doc("/warehouse/{warehouse_id}", item);
+
+        let report = validate_openapi(
+            router,
+            &doc,
+            [
+                (Method::GET, "/warehouse/{warehouse_id}"),
+                (Method::POST, "/undocumented"),
+                (Method::GET, "/not-served-by-this-router"),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            report,
+            OpenApiValidationReport {
+                undocumented_routes: vec!["POST /undocumented".to_string()],
+                unrouted_operations: vec!["DELETE /warehouse/{warehouse_id}".to_string()],
+            }
+        );
+        assert!(!report.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_documented_instance_of_route_is_accepted() {
+        let router = Router::new().route("/task-queue/{queue_name}/schedule", post(|| async {}));
+        let doc = doc(
+            "/task-queue/expiration/schedule",
+            PathItem::new(HttpMethod::Post, OperationBuilder::new().build()),
+        );
+
+        let report = validate_openapi(
+            router,
+            &doc,
+            [(Method::POST, "/task-queue/{queue_name}/schedule")],
+        )
+        .await;
+        assert!(report.is_ok(), "{report}");
+    }
+}
@@ -0,0 +1,277 @@
+//! Middleware that enforces a per-project request rate limit using a
+//! [`RateLimiter`]. Enabled via `LAKEKEEPER__RATE_LIMIT__ENABLED` or by
+//! passing a custom limiter to [`crate::serve::ServeConfiguration`].
+//!
+//! Rejected requests receive `429 Too Many Requests` with a `Retry-After`
+//! header. Requests that are not scoped to a project pass unchecked. Requests
+//! naming a project that does not exist are limited per principal, see
+//! [`RateLimitKey`].
+
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    extract::{Request, State},
+    http::{StatusCode, header::RETRY_AFTER},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use iceberg_ext::catalog::rest::{ErrorModel, IcebergErrorResponse};
+use moka::future::Cache;
+
+use crate::{
+    ProjectId,
+    request_metadata::RequestMetadata,
+    service::{
+        CatalogStore, Transaction,
+        rate_limit::{RateLimitDecision, RateLimitKey, RateLimiter},
+    },
+};
+
+/// Error code returned in [`ErrorModel::r#type`] when a request is rejected
+/// because its project exceeded the rate limit.
+pub const RATE_LIMIT_ERROR_TYPE: &str = "RateLimitExceeded";
+
+/// Maximum number of project ids whose existence is remembered.
+const MAX_KNOWN_PROJECTS: u64 = 100_000;
+
+/// How long the existence of a project is remembered. Projects created in the
+/// meantime are limited per principal until then.
+const KNOWN_PROJECTS_TTL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimitMiddlewareState<C: CatalogStore> {
+    limiter: Arc<dyn RateLimiter>,
+    catalog_state: C::State,
+    /// Whether a project id names an existing project.
+    known_projects: Cache<ProjectId, bool>,
+}
+
+impl<C: CatalogStore> RateLimitMiddlewareState<C> {
+    pub(crate) fn new(limiter: Arc<dyn RateLimiter>, catalog_state: C::State) -> Self {
+        Self {
+            limiter,
+            catalog_state,
+            known_projects: Cache::builder()
+                .max_capacity(MAX_KNOWN_PROJECTS)
+                .time_to_live(KNOWN_PROJECTS_TTL)
+                .build(),
+        }
+    }
+
+    /// Key the request is counted against, `None` if it is not scoped to a
+    /// project.
+    async fn key_for(&self, request_metadata: &RequestMetadata) -> Option<RateLimitKey> {
+        let project_id = request_metadata.preferred_project_id()?;
+        let project_exists = self.project_exists(&project_id).await;
+        Some(rate_limit_key(
+            request_metadata,
+            &project_id,
+            project_exists,
+        ))
+    }
+
+    /// Lookup failures are not remembered and count as unknown projects, so
+    /// that the request is still limited per principal.
+    async fn project_exists(&self, project_id: &ProjectId) -> bool {
+        self.known_projects
+            .try_get_with_by_ref(project_id, async {
+                let mut t = C::Transaction::begin_read(self.catalog_state.clone()).await?;
+                let project = C::get_project(project_id, t.transaction()).await?;
+                t.commit().await?;
+                Ok::<_, IcebergErrorResponse>(project.is_some())
+            })
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(
+                    %project_id,
+                    "Failed to look up project for rate limiting: {}",
+                    e.error.message
+                );
+                false
+            })
+    }
+}
+
+/// Requests are counted against the project they are scoped to only if it
+/// exists. Otherwise the client-supplied project id could be varied to obtain
+/// fresh budgets, so they are counted against their principal instead.
+fn rate_limit_key(
+    request_metadata: &RequestMetadata,
+    project_id: &ProjectId,
+    project_exists: bool,
+) -> RateLimitKey {
+    if project_exists {
+        RateLimitKey::Project(project_id.clone())
+    } else if let Some(user_id) = request_metadata.user_id() {
+        RateLimitKey::Principal(user_id.clone())
+    } else {
+        RateLimitKey::Anonymous
+    }
+}
+
+/// Build the standardized 429 response. `Retry-After` is rounded up to whole
+/// seconds and is at least one second.
+fn rate_limited_response(retry_after: Duration) -> Response {
+    let err: IcebergErrorResponse = ErrorModel::builder()
+        .code(StatusCode::TOO_MANY_REQUESTS.as_u16())
+        .r#type(RATE_LIMIT_ERROR_TYPE.to_string())
+        .message(
+            "Too many requests for this project. Retry after the time given in the `Retry-After` header."
+                .to_string(),
+        )
+        .build()
+        .into();
+
+    let retry_after_secs =
+        (retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)).max(1);
+    let mut response = (StatusCode::TOO_MANY_REQUESTS, axum::Json(err)).into_response();
+    response.headers_mut().insert(
+        RETRY_AFTER,
+        retry_after_secs
+            .to_string()
+            .parse()
+            .expect("Seconds format as ASCII digits, always a valid header value"),
+    );
+    response
+}
+
+/// Axum middleware. Apply with [`axum::middleware::from_fn_with_state`] below
+/// the authentication layer and the layer that inserts [`RequestMetadata`]
+/// into the request extensions.
+pub(crate) async fn rate_limit_middleware_fn<C: CatalogStore>(
+    State(state): State<RateLimitMiddlewareState<C>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let key = match request.extensions().get::<RequestMetadata>() {
+        Some(request_metadata) => state.key_for(request_metadata).await,
+        None => None,
+    };
+    enforce_rate_limit(state.limiter.as_ref(), key, request, next).await
+}
+
+async fn enforce_rate_limit(
+    limiter: &dyn RateLimiter,
+    key: Option<RateLimitKey>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(key) = key
+        && let RateLimitDecision::Limited { retry_after } = limiter.check(&key).await
+    {
+        tracing::debug!(
+            %key,
+            path = request.uri().path(),
+            "Rejecting request: rate limit exceeded",
+        );
+        return rate_limited_response(retry_after);
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use axum::{Router, body::Body, middleware, routing::get};
+    use http::Request;
+    use tower::ServiceExt as _;
+
+    use super::*;
+    use crate::service::{UserId, rate_limit::InMemoryRateLimiter};
+
+    fn router(requests_per_second: u32, burst: u32, key: RateLimitKey) -> Router {
+        let limiter: Arc<dyn RateLimiter> = Arc::new(InMemoryRateLimiter::new(
+            NonZeroU32::new(requests_per_second).unwrap(),
+            NonZeroU32::new(burst).unwrap(),
+        ));
+        Router::new()
+            .route("/r", get(|| async { "ok" }))
+            .layer(middleware::from_fn(
+                move |request: axum::extract::Request, next: Next| {
+                    let limiter = limiter.clone();
+                    let key = key.clone();
+                    async move { enforce_rate_limit(limiter.as_ref(), Some(key), request, next).await }
+                },
+            ))
+    }
+
+    async fn send(app: &Router) -> Response {
+        app.clone()
+            .oneshot(Request::builder().uri("/r").body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn burst_within_limit_passes() {
+        let app = router(1, 5, RateLimitKey::Project(ProjectId::new_random()));
+        for _ in 0..5 {
+            assert_eq!(send(&app).await.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn burst_over_limit_returns_429_with_retry_after() {
+        let app = router(1, 3, RateLimitKey::Project(ProjectId::new_random()));
+        for _ in 0..3 {
+            assert_eq!(send(&app).await.status(), StatusCode::OK);
+        }
+
+        let resp = send(&app).await;
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            resp.headers().get(RETRY_AFTER).unwrap().to_str().unwrap(),
+            "1"
+        );
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let parsed: IcebergErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(parsed.error.r#type, RATE_LIMIT_ERROR_TYPE);
+        assert_eq!(parsed.error.code, StatusCode::TOO_MANY_REQUESTS.as_u16());
+    }
+
+    #[test]
+    fn known_projects_are_limited_per_project() {
+        let project_id = ProjectId::new_random();
+        let request_metadata = RequestMetadata::test_user(UserId::new_unchecked("oidc", "u1"));
+        assert_eq!(
+            rate_limit_key(&request_metadata, &project_id, true),
+            RateLimitKey::Project(project_id)
+        );
+    }
+
+    #[test]
+    fn unknown_projects_are_limited_per_principal() {
+        let project_id = ProjectId::new_random();
+        let user_id = UserId::new_unchecked("oidc", "u1");
+        let request_metadata = RequestMetadata::test_user(user_id.clone());
+        assert_eq!(
+            rate_limit_key(&request_metadata, &project_id, false),
+            RateLimitKey::Principal(user_id)
+        );
+        assert_eq!(
+            rate_limit_key(&RequestMetadata::new_unauthenticated(), &project_id, false),
+            RateLimitKey::Anonymous
+        );
+    }
+
+    #[test]
+    fn retry_after_is_rounded_up_to_whole_seconds() {
+        let retry_after = |d: Duration| {
+            rate_limited_response(d)
+                .headers()
+                .get(RETRY_AFTER)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(retry_after(Duration::ZERO), "1");
+        assert_eq!(retry_after(Duration::from_millis(200)), "1");
+        assert_eq!(retry_after(Duration::from_secs(2)), "2");
+        assert_eq!(retry_after(Duration::from_millis(2001)), "3");
+    }
+}
@@ -38,7 +38,9 @@ use crate::{
         admission::AdmissionGates,
         authn::{AuthMiddlewareState, auth_middleware_fn},
         authz::{Authorizer, InstanceAdminMembership},
+        group_roles::GroupRoleMapping,
         health::{HealthState, HealthStatus, ServiceHealthProvider},
+        rate_limit::RateLimiter,
         tasks::QueueApiConfig,
     },
 };
@@ -72,6 +74,12 @@ pub struct RouterArgs<C: CatalogStore, A: Authorizer + Clone, S: SecretStore, N:
     /// request); host binaries may register gates that reject already
     /// authenticated principals before they reach any handler.
     pub admission_gates: AdmissionGates,
+    /// Mapping of token groups to roles. Use
+    /// [`GroupRoleMapping::from_config`] for the mapping configured via
+    /// `LAKEKEEPER__OPENID_GROUP_ROLE_MAPPING_FILE`.
+    pub group_role_mapping: Arc<GroupRoleMapping>,
+    /// Per-project rate limiter. `None` disables rate limiting.
+    pub rate_limiter: Option<Arc<dyn RateLimiter>>,
 }
 
 impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore, N: Authenticator + Debug> Debug
@@ -94,6 +102,8 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore, N: Authenticator +
             )
             .field("instance_admin_membership", &self.instance_admin_membership)
             .field("admission_gates", &self.admission_gates)
+            .field("group_role_mapping", &self.group_role_mapping)
+            .field("rate_limiter", &self.rate_limiter)
             .finish()
     }
 }
@@ -117,6 +127,8 @@ pub async fn new_full_router<
         endpoint_statistics_tracker_tx,
         instance_admin_membership,
         admission_gates,
+        group_role_mapping,
+        rate_limiter,
         // registered_task_queues,
     }: RouterArgs<C, A, S, N>,
 ) -> anyhow::Result<Router> {
@@ -128,7 +140,12 @@ pub async fn new_full_router<
     >();
 
     let authorizer = state.v1_state.authz.clone();
-    let management_routes = Router::new().merge(ApiServer::new_v1_router(&authorizer));
+    let management_routes = Router::new()
+        .merge(ApiServer::new_v1_router(&authorizer))
+        .layer(axum::middleware::from_fn_with_state(
+            state.v1_state.events.clone(),
+            crate::api::audit::management_audit_middleware_fn,
+        ));
     let maybe_cors_layer = get_cors_layer(cors_origins);
 
     let maybe_auth_layer = if let Some(authenticator) = authenticator {
@@ -140,6 +157,7 @@ pub async fn new_full_router<
                 catalog_state: state.v1_state.catalog.clone(),
                 instance_admin_membership,
                 admission_gates,
+                group_role_mapping,
             },
             auth_middleware_fn::<C, _, _>,
         )))
@@ -147,6 +165,16 @@ pub async fn new_full_router<
         option_layer(None)
     };
 
+    let maybe_rate_limit_layer = option_layer(rate_limiter.map(|rate_limiter| {
+        axum::middleware::from_fn_with_state(
+            crate::api::rate_limit::RateLimitMiddlewareState::<C>::new(
+                rate_limiter,
+                state.v1_state.catalog.clone(),
+            ),
+            crate::api::rate_limit::rate_limit_middleware_fn::<C>,
+        )
+    }));
+
     let mut router = Router::new()
         .nest("/catalog/v1", v1_routes)
         .nest("/management/v1", management_routes)
@@ -158,8 +186,19 @@ pub async fn new_full_router<
         .layer(axum::middleware::from_fn(
             crate::api::maintenance::maintenance_middleware_fn,
         ))
+        // Turns oversized request bodies into a structured 413 naming the limit.
+        .layer(axum::middleware::from_fn(
+            crate::api::body_limit::body_limit_middleware_fn,
+        ))
         .layer(DefaultBodyLimit::max(CONFIG.max_request_body_size));
 
+    // gRPC calls are always POSTs, so the service is merged after the maintenance
+    // gate and rejects commits in read-only mode itself.
+    #[cfg(feature = "grpc")]
+    {
+        router = router.merge(crate::api::grpc::router(state.clone()));
+    }
+
     // Apply request body logging middleware FIRST, before any other middleware that might consume the body
     if CONFIG.debug.log_request_bodies {
         router = router.layer(axum::middleware::from_fn(print_request_body));
@@ -170,15 +209,30 @@ pub async fn new_full_router<
             endpoint_statistics_tracker_tx,
             crate::service::endpoint_statistics::endpoint_statistics_middleware_fn,
         ))
+        // Rate limiting runs after authentication, so unauthenticated requests
+        // cannot exhaust the budget of a project named in their headers.
+        .layer(maybe_rate_limit_layer)
         .layer(maybe_auth_layer)
-        // Add health later so that it is not authenticated
+        // Add health and ping later so that they are not authenticated
         .route(
             "/health",
+            get({
+                let service_health_provider = service_health_provider.clone();
+                || async move {
+                    let health = service_health_provider.collect_health().await;
+                    health_response(health)
+                }
+            }),
+        )
+        .route(LIVEZ_PATH, get(ping))
+        .route(
+            READYZ_PATH,
             get(|| async move {
-                let health = service_health_provider.collect_health().await;
-                health_response(health)
+                let readiness = service_health_provider.collect_readiness().await;
+                health_response(readiness)
             }),
-        );
+        )
+        .route(PING_PATH, get(ping));
 
     let registered_api_configs = state.v1_state.registered_task_queues.api_config().await;
     let (warehouse_task_api_configs, project_task_api_configs) = registered_api_configs
@@ -230,6 +284,22 @@ pub async fn new_full_router<
     })
 }
 
+/// Liveness endpoint for load balancers. Unlike `/health`, it does not check
+/// the database, the authorizer, or any other dependency.
+const PING_PATH: &str = "/management/v1/ping";
+
+/// Liveness probe: returns 200 as long as the process serves requests.
+const LIVEZ_PATH: &str = "/livez";
+
+/// Readiness probe: returns 503 until all services are healthy (pools warm,
+/// authorizer reachable) and all readiness checks passed — for the Postgres
+/// backend this includes that all migrations of this binary are applied.
+const READYZ_PATH: &str = "/readyz";
+
+async fn ping() -> StatusCode {
+    StatusCode::OK
+}
+
 fn health_response(health: HealthState) -> axum::response::Response {
     let status = match health.health {
         HealthStatus::Healthy => StatusCode::OK,
@@ -6,7 +6,9 @@ use std::{
     collections::{HashMap, HashSet},
     convert::Infallible,
     net::{IpAddr, Ipv4Addr},
+    num::{NonZeroU32, NonZeroUsize},
     ops::{Deref, DerefMut},
+    path::PathBuf,
     str::FromStr,
     sync::{Arc, LazyLock},
     time::Duration,
@@ -21,6 +23,7 @@ use url::Url;
 
 use crate::{
     WarehouseId,
+    api::endpoints::{Endpoint, EndpointFlat},
     service::{
         ArcProjectId, UserId,
         authn::{K8S_IDP_ID, OIDC_IDP_ID, OidcProviderConfig},
@@ -225,6 +228,82 @@ impl TrinoEngineConfig {
     }
 }
 
+/// Endpoints of selected warehouses that can be called without a token.
+///
+/// Requests without `Authorization` header to a listed endpoint are performed as
+/// [`AnonymousAccess::principal`] instead of being rejected. Access is limited
+/// to the warehouses listed for the endpoint, and the principal never receives
+/// write access to table data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnonymousAccess {
+    /// Principal anonymous requests are performed as. Grant it read permissions
+    /// on the listed warehouses in the authorizer. (default: `lakekeeper~anonymous`)
+    pub principal: UserId,
+    /// Endpoint and warehouse combinations reachable without a token.
+    pub allow: Vec<AnonymousAccessRule>,
+}
+
+impl Default for AnonymousAccess {
+    fn default() -> Self {
+        Self {
+            principal: UserId::new_unchecked("lakekeeper", "anonymous"),
+            allow: Vec::new(),
+        }
+    }
+}
+
+impl AnonymousAccess {
+    /// Warehouses that `endpoint` may be called for anonymously. Empty if the
+    /// endpoint is not listed.
+    #[must_use]
+    pub fn warehouses_for(&self, endpoint: EndpointFlat) -> Vec<WarehouseId> {
+        self.allow
+            .iter()
+            .filter(|rule| rule.endpoint == endpoint)
+            .map(|rule| rule.warehouse_id)
+            .unique()
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnonymousAccessRule {
+    /// Endpoint in kebab-case as reported by endpoint statistics, e.g.
+    /// `catalog-v1-load-table`. Only `GET` and `HEAD` endpoints are accepted.
+    #[serde(
+        deserialize_with = "deserialize_read_endpoint",
+        serialize_with = "serialize_endpoint"
+    )]
+    pub endpoint: EndpointFlat,
+    pub warehouse_id: WarehouseId,
+}
+
+fn deserialize_read_endpoint<'de, D>(deserializer: D) -> Result<EndpointFlat, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use strum::IntoEnumIterator as _;
+
+    let name = String::deserialize(deserializer)?;
+    let endpoint = EndpointFlat::iter()
+        .find(|endpoint| endpoint.to_string() == name)
+        .ok_or_else(|| serde::de::Error::custom(format!("Unknown endpoint `{name}`")))?;
+    let method = Endpoint::from(endpoint).method();
+    if method != http::Method::GET && method != http::Method::HEAD {
+        return Err(serde::de::Error::custom(format!(
+            "Endpoint `{name}` is not a read endpoint and cannot be accessed anonymously"
+        )));
+    }
+    Ok(endpoint)
+}
+
+fn serialize_endpoint<S>(endpoint: &EndpointFlat, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    endpoint.to_string().serialize(serializer)
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum TrustedEngine {
@@ -345,6 +424,11 @@ pub struct DynAppConfig {
     /// If x-forwarded-x headers should be respected.
     /// Defaults to true
     pub use_x_forwarded_headers: bool,
+    /// Number of reverse proxies in front of Lakekeeper whose x-forwarded-x headers
+    /// are trusted. Each proxy appends to comma-separated x-forwarded-x headers, so
+    /// only the last `n` values are considered and the leftmost of those is used.
+    /// Defaults to `None`: all proxies are trusted and the leftmost value is used.
+    pub x_forwarded_trusted_hops: Option<NonZeroUsize>,
     /// If true (default), the NIL uuid is used as default project id.
     pub enable_default_project: bool,
     /// If true, the swagger UI is served at /swagger-ui
@@ -372,6 +456,20 @@ pub struct DynAppConfig {
         serialize_with = "serialize_reserved_namespaces"
     )]
     pub reserved_namespaces: ReservedNamespaces,
+    /// Maximum number of parts a namespace may have, including all of its
+    /// parents. Creating a namespace that would be nested deeper is rejected
+    /// with `NamespaceTooDeep`. Defaults to `None` (only the built-in limit
+    /// applies).
+    pub max_namespace_depth: Option<usize>,
+    /// Namespace property keys that clients may not set or remove when
+    /// updating the properties of a namespace. Such updates are rejected
+    /// with `NamespacePropertyReserved`. Specify multiple keys as a
+    /// comma-separated list. Defaults to `None` (no reserved keys).
+    #[serde(
+        deserialize_with = "deserialize_comma_separated",
+        serialize_with = "serialize_comma_separated"
+    )]
+    pub reserved_namespace_properties: Option<Vec<String>>,
     // ------------- STORAGE OPTIONS -------------
     /// If true, can create Warehouses with using System Identities.
     pub(crate) enable_aws_system_credentials: bool,
@@ -388,6 +486,10 @@ pub struct DynAppConfig {
     /// Enable GCP System Identities
     pub(crate) enable_gcp_system_credentials: bool,
 
+    /// Retries of failed object store requests.
+    #[serde(default)]
+    pub storage_retry: StorageRetryConfig,
+
     // ------------- TRACING CLOUDEVENTS ----------
     pub log_cloudevents: Option<bool>,
 
@@ -440,6 +542,19 @@ pub struct DynAppConfig {
     /// The field should contain a single string claim path.
     /// Supports nested claims using dot notation, e.g., `resource_access.account.roles`
     pub openid_roles_claim: Option<String>,
+    /// Claim to use in provided JWT tokens to extract group memberships.
+    /// Supports nested claims using dot notation, e.g., `realm_access.groups`.
+    /// Groups are mapped to roles via `openid_group_role_mapping_file`.
+    pub openid_groups_claim: Option<String>,
+    /// Path to a JSON file mapping values of `openid_groups_claim` to role ids.
+    /// See [`GroupRoleMapping`](crate::service::group_roles::GroupRoleMapping).
+    pub openid_group_role_mapping_file: Option<PathBuf>,
+    /// Interval at which the group role mapping file is checked for changes.
+    #[serde(
+        deserialize_with = "crate::config::seconds_to_std_duration",
+        serialize_with = "crate::config::serialize_std_duration_as_ms"
+    )]
+    pub openid_group_role_mapping_reload_interval: std::time::Duration,
     /// Multiple OIDC providers keyed by identity provider ID.
     /// When set, each provider gets its own JWKS authenticator and is added
     /// in addition to the single-provider configuration (`openid_provider_uri`).
@@ -470,6 +585,15 @@ pub struct DynAppConfig {
     /// must be wrapped in brackets: `["oidc~alice"]`.
     #[serde(default)]
     pub instance_admins: HashSet<UserId>,
+    /// Endpoints of selected warehouses that can be called without a token,
+    /// e.g. `/config` and table reads of public warehouses.
+    ///
+    /// ```text
+    /// LAKEKEEPER__ANONYMOUS_ACCESS__PRINCIPAL=lakekeeper~anonymous
+    /// LAKEKEEPER__ANONYMOUS_ACCESS__ALLOW=[{endpoint="catalog-v1-load-table",warehouse_id="<warehouse-id>"}]
+    /// ```
+    #[serde(default)]
+    pub anonymous_access: AnonymousAccess,
     // ------------- TRUSTED ENGINES -------------
     #[serde(default)]
     pub trusted_engines: HashMap<String, TrustedEngine>,
@@ -488,6 +612,15 @@ pub struct DynAppConfig {
     // ------------- Tasks -------------
     /// Duration to wait after no new task was found before polling for new tasks again.
     pub task_poll_interval: std::time::Duration,
+    /// Time a task worker may keep processing its current task after shutdown
+    /// was requested. Workers stop picking up new tasks immediately; a task that
+    /// is still running when the drain period ends is aborted and put back
+    /// into the queue without counting as a failed attempt. (default: 15s)
+    #[serde(
+        deserialize_with = "crate::config::seconds_to_std_duration",
+        serialize_with = "crate::config::serialize_std_duration_as_ms"
+    )]
+    pub task_drain_timeout: std::time::Duration,
     /// Number of workers to spawn for finalizing soft-deleted tabulars once
     /// their expiration elapses. (default: 2)
     ///
@@ -499,6 +632,8 @@ pub struct DynAppConfig {
     pub task_tabular_purge_workers: usize,
     /// Number of workers to spawn for cleaning task logs. (default: 2)
     pub task_log_cleanup_workers: usize,
+    /// Number of workers to spawn for compacting table metadata logs. (default: 2)
+    pub task_metadata_log_compaction_workers: usize,
     // ------------- Tabular -------------
     /// Delay in seconds after which a tabular will be deleted
     #[serde(
@@ -506,6 +641,21 @@ pub struct DynAppConfig {
         serialize_with = "duration_to_seconds"
     )]
     pub default_tabular_expiration_delay_seconds: chrono::Duration,
+    /// Behavior when a table is created with the name of a soft-deleted table
+    /// in the same namespace.
+    ///
+    /// `allow` (default) creates the new table next to the soft-deleted one;
+    /// the soft-deleted table can then not be undropped while the name is
+    /// taken. `purge` drops the soft-deleted table immediately and queues the
+    /// removal of its files. `reject` fails the creation with
+    /// `409 Conflict`.
+    #[serde(default)]
+    pub soft_deleted_table_name_conflict: SoftDeletedTableNameConflict,
+    /// Canonical form of table and view locations with respect to a trailing
+    /// slash. Locations are normalized to this form before they are stored.
+    /// Lookups by location match tabulars stored in either form.
+    #[serde(default)]
+    pub tabular_location_trailing_slash: LocationTrailingSlash,
 
     // ------------- Page size for paginated queries -------------
     pub pagination_size_default: u32,
@@ -525,6 +675,12 @@ pub struct DynAppConfig {
         serialize_with = "serialize_std_duration_as_ms"
     )]
     pub endpoint_stat_flush_interval: Duration,
+    /// Fraction of requests counted in the endpoint statistics, within `(0, 1]`.
+    /// Each sampled request is counted with the inverse of the rate, so counts are
+    /// approximate for rates below `1.0`.
+    pub endpoint_stat_sample_rate: f64,
+    /// Per-project overrides of `endpoint_stat_sample_rate`, keyed by project ID.
+    pub endpoint_stat_project_sample_rates: HashMap<String, f64>,
 
     // ------------- Caching -------------
     #[serde(default)]
@@ -540,6 +696,10 @@ pub struct DynAppConfig {
     #[serde(default)]
     pub idempotency: IdempotencyConfig,
 
+    // ------------- Rate Limiting -------------
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+
     // ------------- Debug -------------
     #[serde(default)]
     pub debug: DebugConfig,
@@ -589,6 +749,41 @@ pub enum MaintenanceMode {
     ReadOnly,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SoftDeletedTableNameConflict {
+    /// Create the new table, keeping the soft-deleted table until it expires.
+    #[default]
+    Allow,
+    /// Drop the soft-deleted table and queue a purge of its files.
+    Purge,
+    /// Reject the creation until the soft-deleted table is undropped or expired.
+    Reject,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LocationTrailingSlash {
+    /// Store locations without a trailing slash, e.g. `s3://bucket/table`.
+    #[default]
+    Without,
+    /// Store locations with a trailing slash, e.g. `s3://bucket/table/`.
+    With,
+}
+
+impl LocationTrailingSlash {
+    /// Bring `location` into the canonical form.
+    pub fn canonicalize(
+        self,
+        location: &mut lakekeeper_io::Location,
+    ) -> &mut lakekeeper_io::Location {
+        match self {
+            Self::Without => location.without_trailing_slash(),
+            Self::With => location.with_trailing_slash(),
+        }
+    }
+}
+
 impl MaintenanceMode {
     #[must_use]
     pub fn is_read_only(self) -> bool {
@@ -794,6 +989,74 @@ pub struct IdempotencyConfig {
     pub cleanup_timeout: Duration,
 }
 
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct StorageRetryConfig {
+    /// Maximum number of retries of an object store request that failed with a
+    /// transient error (e.g. throttling or a timeout). Retries are performed by
+    /// the storage clients; `0` disables them.
+    /// Default: 3
+    pub max_retries: u32,
+    /// Backoff before the first retry (ISO-8601 duration). The backoff doubles
+    /// with every further retry. Not used by ADLS, which retries with a fixed delay.
+    /// Default: PT0.1S (100 milliseconds)
+    #[serde(with = "crate::utils::time_conversion::iso8601_std_duration_serde")]
+    pub initial_backoff: Duration,
+    /// Upper bound of the backoff between two retries (ISO-8601 duration).
+    /// Default: PT10S (10 seconds)
+    #[serde(with = "crate::utils::time_conversion::iso8601_std_duration_serde")]
+    pub max_backoff: Duration,
+    /// Randomize each backoff so that concurrent requests do not retry in lockstep.
+    /// Default: true
+    pub jitter: bool,
+}
+
+impl Default for StorageRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+}
+
+impl StorageRetryConfig {
+    #[must_use]
+    pub fn policy(&self) -> lakekeeper_io::StorageRetryPolicy {
+        lakekeeper_io::StorageRetryPolicy::new(
+            self.max_retries,
+            self.initial_backoff,
+            self.max_backoff,
+        )
+        .with_jitter(self.jitter)
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct RateLimitConfig {
+    /// Whether requests are rate-limited per project.
+    /// Requests exceeding the limit are rejected with `429 Too Many Requests`.
+    /// Default: false
+    pub enabled: bool,
+    /// Sustained number of requests per second allowed for a single project.
+    /// Default: 100
+    pub requests_per_second: NonZeroU32,
+    /// Maximum number of requests a single project can issue in a burst.
+    /// Default: 200
+    pub burst: NonZeroU32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            requests_per_second: NonZeroU32::new(100).expect("100 is non-zero"),
+            burst: NonZeroU32::new(200).expect("200 is non-zero"),
+        }
+    }
+}
+
 impl Default for IdempotencyConfig {
     fn default() -> Self {
         Self {
@@ -851,6 +1114,8 @@ pub struct DebugConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub(crate) struct AuditConfig {
     pub tracing: AuditTracingConfig,
+    #[serde(default)]
+    pub postgres: AuditPostgresConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -858,6 +1123,13 @@ pub(crate) struct AuditTracingConfig {
     pub enabled: bool,
 }
 
+/// Additionally persist the audit trail of mutating management operations in
+/// the `audit_log` table of the catalog database.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub(crate) struct AuditPostgresConfig {
+    pub enabled: bool,
+}
+
 /// Cache for `UserId → ListUserRoleAssignmentsResult` lookups.
 ///
 /// Hot path: checked on every authorisation request.
@@ -919,6 +1191,33 @@ pub(crate) struct Cache {
     pub(crate) user_assignments: UserAssignmentsCache,
     /// Role-members cache: `RoleId → members`.
     pub(crate) role_members: RoleMembersCache,
+    /// Authentication cache: `sha256(token) → authentication`.
+    pub(crate) authentication: AuthenticationCache,
+}
+
+/// Cache of successful token authentications.
+///
+/// Hot path: checked on every authenticated request. Bounds the time a token
+/// revoked at the identity provider is still accepted, so keep the TTL short.
+/// Disabled by default, as it delays revocations.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub(crate) struct AuthenticationCache {
+    pub(crate) enabled: bool,
+    pub(crate) capacity: u64,
+    /// Time-to-live for cache entries in seconds. Entries expire earlier if
+    /// the token expires. Defaults to 30 seconds.
+    pub(crate) time_to_live_secs: u64,
+}
+
+impl Default for AuthenticationCache {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            capacity: 10_000,
+            time_to_live_secs: 30,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1063,12 +1362,15 @@ impl Default for DynAppConfig {
             base_uri: None,
             enable_default_project: true,
             use_x_forwarded_headers: true,
+            x_forwarded_trusted_hops: None,
             prefix_template: "{warehouse_id}".to_string(),
             allow_origin: None,
             reserved_namespaces: ReservedNamespaces(HashSet::from([
                 "system".to_string(),
                 "examples".to_string(),
             ])),
+            max_namespace_depth: None,
+            reserved_namespace_properties: None,
             enable_azure_system_credentials: false,
             enable_aws_system_credentials: false,
             s3_enable_direct_system_credentials: false,
@@ -1077,6 +1379,7 @@ impl Default for DynAppConfig {
             log_cloudevents: None,
             authz_backend: AuthZBackend::default(),
             instance_admins: HashSet::new(),
+            anonymous_access: AnonymousAccess::default(),
             trusted_engines: HashMap::new(),
             protected_properties: HashSet::new(),
             openid_provider_uri: None,
@@ -1089,23 +1392,34 @@ impl Default for DynAppConfig {
             kubernetes_authentication_subject_source: KubernetesSubjectSource::default(),
             openid_subject_claim: None,
             openid_roles_claim: None,
+            openid_groups_claim: None,
+            openid_group_role_mapping_file: None,
+            openid_group_role_mapping_reload_interval: Duration::from_secs(30),
             openid_providers: HashMap::new(),
             listen_port: 8181,
             bind_ip: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
             health_check_frequency_seconds: 10,
             secret_backend: SecretBackend::Postgres,
             task_poll_interval: Duration::from_secs(10),
+            task_drain_timeout: Duration::from_secs(15),
             task_soft_deletion_workers: 2,
             task_tabular_purge_workers: 2,
             task_log_cleanup_workers: 2,
+            task_metadata_log_compaction_workers: 2,
             default_tabular_expiration_delay_seconds: chrono::Duration::days(7),
+            soft_deleted_table_name_conflict: SoftDeletedTableNameConflict::Allow,
+            tabular_location_trailing_slash: LocationTrailingSlash::Without,
             pagination_size_default: 100,
             pagination_size_max: 1000,
             metrics: Metrics::default(),
             endpoint_stat_flush_interval: Duration::from_secs(30),
+            endpoint_stat_sample_rate: 1.0,
+            endpoint_stat_project_sample_rates: HashMap::new(),
             serve_swagger_ui: true,
             skip_storage_validation: false,
+            storage_retry: StorageRetryConfig::default(),
             idempotency: IdempotencyConfig::default(),
+            rate_limit: RateLimitConfig::default(),
             debug: DebugConfig::default(),
             role: RoleConfig::default(),
             cache: Cache::default(),
@@ -1113,6 +1427,7 @@ impl Default for DynAppConfig {
             max_request_time: Duration::from_secs(30),
             audit: AuditConfig {
                 tracing: AuditTracingConfig { enabled: true },
+                postgres: AuditPostgresConfig::default(),
             },
             maintenance_mode: MaintenanceMode::Off,
         }
@@ -1141,17 +1456,24 @@ impl DynAppConfig {
         self.openid_provider_uri.is_some()
     }
 
+    /// Clamp a client-provided page size to `1..=pagination_size_max`.
+    ///
+    /// Every paginated list query must size its pages through this helper so
+    /// that oversized requests are silently capped instead of honored.
+    #[must_use]
+    pub fn clamp_page_size(&self, page_size: i64) -> i64 {
+        page_size.clamp(1, self.pagination_size_max.into())
+    }
+
     /// Helper for common conversion of optional page size to `i64`.
+    #[must_use]
     pub fn page_size_or_pagination_max(&self, page_size: Option<i64>) -> i64 {
-        page_size.map_or(self.pagination_size_max.into(), |i| {
-            i.clamp(1, self.pagination_size_max.into())
-        })
+        self.clamp_page_size(page_size.unwrap_or(self.pagination_size_max.into()))
     }
 
+    #[must_use]
     pub fn page_size_or_pagination_default(&self, page_size: Option<i64>) -> i64 {
-        page_size
-            .unwrap_or(self.pagination_size_default.into())
-            .clamp(1, self.pagination_size_max.into())
+        self.clamp_page_size(page_size.unwrap_or(self.pagination_size_default.into()))
     }
 }
 
@@ -1388,6 +1710,75 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_soft_deleted_table_name_conflict_via_env() {
+        let config = get_config();
+        assert_eq!(
+            config.soft_deleted_table_name_conflict,
+            SoftDeletedTableNameConflict::Allow
+        );
+        figment::Jail::expect_with(|jail| {
+            jail.set_env(
+                "LAKEKEEPER_TEST__SOFT_DELETED_TABLE_NAME_CONFLICT",
+                "reject",
+            );
+            let config = get_config();
+            assert_eq!(
+                config.soft_deleted_table_name_conflict,
+                SoftDeletedTableNameConflict::Reject
+            );
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_tabular_location_trailing_slash_via_env() {
+        let config = get_config();
+        assert_eq!(
+            config.tabular_location_trailing_slash,
+            LocationTrailingSlash::Without
+        );
+        figment::Jail::expect_with(|jail| {
+            jail.set_env("LAKEKEEPER_TEST__TABULAR_LOCATION_TRAILING_SLASH", "with");
+            let config = get_config();
+            assert_eq!(
+                config.tabular_location_trailing_slash,
+                LocationTrailingSlash::With
+            );
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_location_trailing_slash_canonicalize() {
+        for input in ["s3://bucket/table", "s3://bucket/table/"] {
+            let mut location = lakekeeper_io::Location::from_str(input).unwrap();
+            LocationTrailingSlash::Without.canonicalize(&mut location);
+            assert_eq!(location.as_str(), "s3://bucket/table");
+            LocationTrailingSlash::With.canonicalize(&mut location);
+            assert_eq!(location.as_str(), "s3://bucket/table/");
+        }
+    }
+
+    #[test]
+    fn test_page_size_is_clamped_to_pagination_max() {
+        figment::Jail::expect_with(|jail| {
+            jail.set_env("LAKEKEEPER_TEST__PAGINATION_SIZE_DEFAULT", "10");
+            jail.set_env("LAKEKEEPER_TEST__PAGINATION_SIZE_MAX", "50");
+            let config = get_config();
+            assert_eq!(config.clamp_page_size(20), 20);
+            assert_eq!(config.clamp_page_size(51), 50);
+            assert_eq!(config.clamp_page_size(i64::MAX), 50);
+            assert_eq!(config.clamp_page_size(0), 1);
+            assert_eq!(config.clamp_page_size(-5), 1);
+            assert_eq!(config.page_size_or_pagination_max(None), 50);
+            assert_eq!(config.page_size_or_pagination_max(Some(1000)), 50);
+            assert_eq!(config.page_size_or_pagination_default(None), 10);
+            assert_eq!(config.page_size_or_pagination_default(Some(1000)), 50);
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_instance_admins_rejects_missing_idp_prefix() {
         figment::Jail::expect_with(|jail| {
@@ -1405,6 +1796,54 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_anonymous_access() {
+        let warehouse_id = uuid::Uuid::now_v7();
+        figment::Jail::expect_with(|jail| {
+            jail.set_env(
+                "LAKEKEEPER_TEST__ANONYMOUS_ACCESS__ALLOW",
+                format!(
+                    r#"[{{endpoint="catalog-v1-get-config",warehouse_id="{warehouse_id}"}},{{endpoint="catalog-v1-load-table",warehouse_id="{warehouse_id}"}}]"#
+                ),
+            );
+            let config = get_config();
+            assert_eq!(
+                config.anonymous_access.principal,
+                UserId::new_unchecked("lakekeeper", "anonymous")
+            );
+            assert_eq!(
+                config
+                    .anonymous_access
+                    .warehouses_for(EndpointFlat::CatalogV1LoadTable),
+                vec![WarehouseId::from(warehouse_id)]
+            );
+            assert!(
+                config
+                    .anonymous_access
+                    .warehouses_for(EndpointFlat::CatalogV1ListNamespaces)
+                    .is_empty()
+            );
+            Ok(())
+        });
+
+        // Write endpoints cannot be allowed anonymously
+        figment::Jail::expect_with(|jail| {
+            jail.set_env(
+                "LAKEKEEPER_TEST__ANONYMOUS_ACCESS__ALLOW",
+                format!(
+                    r#"[{{endpoint="catalog-v1-update-table",warehouse_id="{warehouse_id}"}}]"#
+                ),
+            );
+            let defaults = figment::providers::Serialized::defaults(DynAppConfig::default());
+            let env = figment::providers::Env::prefixed("LAKEKEEPER_TEST__").split("__");
+            let result = figment::Figment::from(defaults)
+                .merge(env)
+                .extract::<DynAppConfig>();
+            assert!(result.is_err());
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_base_uri_trailing_slash_stripped() {
         figment::Jail::expect_with(|jail| {
@@ -1506,6 +1945,34 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_task_drain_timeout() {
+        figment::Jail::expect_with(|jail| {
+            let config = get_config();
+            assert_eq!(config.task_drain_timeout, Duration::from_secs(15));
+            jail.set_env("LAKEKEEPER_TEST__TASK_DRAIN_TIMEOUT", "500ms");
+            let config = get_config();
+            assert_eq!(config.task_drain_timeout, Duration::from_millis(500));
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_reserved_namespace_properties() {
+        figment::Jail::expect_with(|jail| {
+            jail.set_env(
+                "LAKEKEEPER_TEST__RESERVED_NAMESPACE_PROPERTIES",
+                "owner,team",
+            );
+            let config = get_config();
+            assert_eq!(
+                config.reserved_namespace_properties,
+                Some(vec!["owner".to_string(), "team".to_string()])
+            );
+            Ok(())
+        });
+    }
+
     #[test]
     fn reserved_namespaces_should_contains_default_values() {
         assert!(CONFIG.reserved_namespaces.contains("system"));
@@ -1998,6 +2465,29 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_authentication_cache() {
+        figment::Jail::expect_with(|_jail| {
+            let config = get_config();
+            assert!(!config.cache.authentication.enabled);
+            assert_eq!(config.cache.authentication.capacity, 10_000);
+            assert_eq!(config.cache.authentication.time_to_live_secs, 30);
+            Ok(())
+        });
+
+        figment::Jail::expect_with(|jail| {
+            jail.set_env("LAKEKEEPER_TEST__CACHE__AUTHENTICATION__ENABLED", "true");
+            jail.set_env(
+                "LAKEKEEPER_TEST__CACHE__AUTHENTICATION__TIME_TO_LIVE_SECS",
+                "5",
+            );
+            let config = get_config();
+            assert!(config.cache.authentication.enabled);
+            assert_eq!(config.cache.authentication.time_to_live_secs, 5);
+            Ok(())
+        });
+    }
+
     #[test]
     #[should_panic(expected = "user_assignments.time_to_live_secs")]
     fn test_user_assignments_ttl_exceeds_role_ttl_is_rejected() {
@@ -2070,6 +2560,25 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_audit_postgres_enabled() {
+        // Test default value is false
+        figment::Jail::expect_with(|_jail| {
+            let config = get_config();
+            assert!(!config.audit.postgres.enabled);
+            Ok(())
+        });
+
+        // Test can be enabled
+        figment::Jail::expect_with(|jail| {
+            jail.set_env("LAKEKEEPER_TEST__AUDIT__POSTGRES__ENABLED", "true");
+            let config = get_config();
+            assert!(config.audit.postgres.enabled);
+            assert!(config.audit.tracing.enabled);
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_trusted_engine_configuration() {
         figment::Jail::expect_with(|_jail| {
@@ -2153,6 +2662,65 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_rate_limit_env_vars() {
+        figment::Jail::expect_with(|jail| {
+            let config = get_config();
+            assert!(!config.rate_limit.enabled);
+
+            jail.set_env("LAKEKEEPER_TEST__RATE_LIMIT__ENABLED", "true");
+            jail.set_env("LAKEKEEPER_TEST__RATE_LIMIT__REQUESTS_PER_SECOND", "5");
+            let config = get_config();
+            assert!(config.rate_limit.enabled);
+            assert_eq!(config.rate_limit.requests_per_second.get(), 5);
+            // burst keeps default
+            assert_eq!(config.rate_limit.burst.get(), 200);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_storage_retry_env_vars() {
+        figment::Jail::expect_with(|jail| {
+            let config = get_config();
+            assert_eq!(config.storage_retry, StorageRetryConfig::default());
+
+            jail.set_env("LAKEKEEPER_TEST__STORAGE_RETRY__MAX_RETRIES", "0");
+            jail.set_env("LAKEKEEPER_TEST__STORAGE_RETRY__INITIAL_BACKOFF", "PT0.5S");
+            let config = get_config();
+            assert_eq!(config.storage_retry.max_retries, 0);
+            assert_eq!(
+                config.storage_retry.initial_backoff,
+                Duration::from_millis(500)
+            );
+            // max-backoff keeps default
+            assert_eq!(config.storage_retry.max_backoff, Duration::from_secs(10));
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_endpoint_stat_sample_rates() {
+        figment::Jail::expect_with(|jail| {
+            let config = get_config();
+            assert!((config.endpoint_stat_sample_rate - 1.0).abs() < f64::EPSILON);
+            assert!(config.endpoint_stat_project_sample_rates.is_empty());
+
+            jail.set_env("LAKEKEEPER_TEST__ENDPOINT_STAT_SAMPLE_RATE", "0.1");
+            jail.set_env(
+                "LAKEKEEPER_TEST__ENDPOINT_STAT_PROJECT_SAMPLE_RATES__MY-PROJECT",
+                "0.5",
+            );
+            let config = get_config();
+            assert!((config.endpoint_stat_sample_rate - 0.1).abs() < f64::EPSILON);
+            assert_eq!(
+                config.endpoint_stat_project_sample_rates,
+                HashMap::from([("my-project".to_string(), 0.5)])
+            );
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_idempotency_defaults() {
         figment::Jail::expect_with(|_jail| {
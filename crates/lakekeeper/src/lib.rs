@@ -14,8 +14,9 @@ mod config;
 pub mod server;
 pub mod service;
 pub use config::{
-    AuthZBackend, CONFIG, DEFAULT_PROJECT_ID, KubernetesSubjectSource, MatchedEngines,
-    SecretBackend, TrinoEngineConfig, TrustedEngine,
+    AuthZBackend, CONFIG, DEFAULT_PROJECT_ID, DynAppConfig, KubernetesSubjectSource,
+    MatchedEngines, RateLimitConfig, SecretBackend, SoftDeletedTableNameConflict,
+    StorageRetryConfig, TrinoEngineConfig, TrustedEngine,
 };
 pub use service::{ProjectId, SecretId, WarehouseId};
 
@@ -1,6 +1,6 @@
 #[cfg(feature = "router")]
 use std::str::FromStr;
-use std::sync::Arc;
+use std::{num::NonZeroUsize, sync::Arc};
 
 #[cfg(feature = "router")]
 use axum::{
@@ -19,7 +19,7 @@ use crate::{
     api::iceberg::v1::namespace::NamespaceIdentUrl,
     config::MatchedEngines,
     service::{
-        ArcProjectId, RoleIdent, TabularId,
+        ArcProjectId, RoleId, RoleIdent, TabularId,
         authn::{Actor, InternalActor},
         authz::{InstanceAdminAuthorizer, UserOrRole},
         events::{AuthorizationFailureReason, AuthorizationFailureSource},
@@ -99,6 +99,13 @@ pub struct RequestMetadata {
     /// an external entitlement service. Kept separate from `token_roles` so the
     /// provenance (token claim vs externally resolved) stays explicit.
     admission_roles: Option<TokenRoles>,
+    /// Roles mapped from the groups in the caller's token (see
+    /// [`GroupRoleMapping`](crate::service::group_roles::GroupRoleMapping)).
+    /// `None` if no groups claim is configured.
+    group_roles: Option<Arc<[RoleId]>>,
+    /// Warehouses a request without token may access. `Some` only for requests
+    /// admitted via `LAKEKEEPER__ANONYMOUS_ACCESS__ALLOW`.
+    anonymous_access: Option<Arc<[WarehouseId]>>,
     base_url: String,
     actor: InternalActor,
     matched_path: Option<Arc<str>>,
@@ -240,6 +247,42 @@ impl RequestMetadata {
         self.admission_roles.as_ref()
     }
 
+    /// Set the roles mapped from the groups in the caller's token. Written by
+    /// the auth middleware if a groups claim is configured.
+    #[cfg_attr(not(feature = "router"), allow(dead_code))]
+    pub(crate) fn set_group_roles(&mut self, group_roles: Vec<RoleId>) -> &mut Self {
+        self.group_roles = Some(group_roles.into());
+        self
+    }
+
+    /// Roles mapped from the groups in the caller's token, in mapping order.
+    /// `None` if no groups claim is configured.
+    #[must_use]
+    pub fn group_roles(&self) -> Option<&[RoleId]> {
+        self.group_roles.as_deref()
+    }
+
+    /// Admit a request without token as `principal`, limited to `warehouses`.
+    /// Written by the auth middleware for endpoints listed in
+    /// `LAKEKEEPER__ANONYMOUS_ACCESS__ALLOW`.
+    #[cfg_attr(not(feature = "router"), allow(dead_code))]
+    pub(crate) fn set_anonymous_access(
+        &mut self,
+        principal: crate::service::UserId,
+        warehouses: Vec<WarehouseId>,
+    ) -> &mut Self {
+        self.actor = Actor::Principal(principal).into();
+        self.anonymous_access = Some(warehouses.into());
+        self
+    }
+
+    /// Warehouses a request admitted without token may access. `None` for
+    /// all other requests.
+    #[must_use]
+    pub fn anonymous_access(&self) -> Option<&[WarehouseId]> {
+        self.anonymous_access.as_deref()
+    }
+
     #[must_use]
     pub fn user_agent(&self) -> Option<&UserAgent> {
         self.user_agent.as_ref()
@@ -285,6 +328,9 @@ impl RequestMetadata {
             engines: MatchedEngines::default(),
             token_roles: None,
             admission_roles: None,
+            group_roles: None,
+            anonymous_access: None,
+            anonymous_access: None,
             idempotency_key: None,
             is_instance_admin: false,
         }
@@ -350,6 +396,9 @@ impl RequestMetadata {
             engines: MatchedEngines::default(),
             token_roles: None,
             admission_roles: None,
+            group_roles: None,
+            anonymous_access: None,
+            anonymous_access: None,
             idempotency_key: None,
             is_instance_admin: false,
         }
@@ -581,6 +630,12 @@ pub struct RequestMetadataTestBuilder {
     /// construct a request that carries them.
     #[builder(default, setter(strip_option))]
     pub admission_roles: Option<TokenRoles>,
+    /// Roles mapped from the groups in the caller's token.
+    #[builder(default, setter(strip_option))]
+    pub group_roles: Option<Vec<RoleId>>,
+    /// Warehouses the request was admitted to anonymously.
+    #[builder(default, setter(strip_option))]
+    pub anonymous_access: Option<Vec<WarehouseId>>,
 }
 
 #[cfg(any(test, feature = "test-utils"))]
@@ -598,6 +653,8 @@ impl From<RequestMetadataTestBuilder> for RequestMetadata {
             engines: MatchedEngines::default(),
             token_roles: b.token_roles,
             admission_roles: b.admission_roles,
+            group_roles: b.group_roles.map(Into::into),
+            anonymous_access: b.anonymous_access.map(Into::into),
             idempotency_key: None,
             is_instance_admin: b.is_instance_admin,
         }
@@ -676,6 +733,8 @@ pub(crate) async fn create_request_metadata_with_trace_and_project_fn(
         authentication: None,
         token_roles: None,
         admission_roles: None,
+        group_roles: None,
+        anonymous_access: None,
         base_url: base_uri,
         actor: Actor::Anonymous.into(),
         project_id: project_id.map(Arc::new),
@@ -695,9 +754,7 @@ pub(crate) async fn create_request_metadata_with_trace_and_project_fn(
 /// Skips leading and trailing slashes from the prefix.
 pub fn determine_forwarded_prefix(headers: &HeaderMap) -> Option<&str> {
     if CONFIG.use_x_forwarded_headers {
-        headers
-            .get(X_FORWARDED_PREFIX_HEADER)
-            .and_then(|hv| hv.to_str().ok())
+        forwarded_header(headers, X_FORWARDED_PREFIX_HEADER)
             .map(|s| s.trim_matches('/'))
             .filter(|s| !s.is_empty())
     } else {
@@ -710,32 +767,65 @@ pub fn determine_base_uri(headers: &HeaderMap) -> Option<String> {
         return Some(uri.to_string());
     }
 
+    determine_base_uri_from_headers(
+        headers,
+        CONFIG.use_x_forwarded_headers,
+        CONFIG.x_forwarded_trusted_hops,
+    )
+}
+
+/// Value of an `x-forwarded-*` header, respecting the configured trusted hops.
+fn forwarded_header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    forwarded_header_value(headers, name, CONFIG.x_forwarded_trusted_hops)
+}
+
+/// Every proxy appends the value it received to an `x-forwarded-*` header, either
+/// comma-separated or as an additional header line. The leftmost value is the
+/// original one. If `trusted_hops` is set, only the values appended by the last
+/// `trusted_hops` proxies are considered, as earlier ones could be spoofed by the client.
+fn forwarded_header_value<'a>(
+    headers: &'a HeaderMap,
+    name: &str,
+    trusted_hops: Option<NonZeroUsize>,
+) -> Option<&'a str> {
+    let values = headers
+        .get_all(name)
+        .iter()
+        .filter_map(|hv| hv.to_str().ok())
+        .flat_map(|hv| hv.split(','))
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .collect::<Vec<_>>();
+    let untrusted = trusted_hops.map_or(0, |hops| values.len().saturating_sub(hops.get()));
+    values.get(untrusted).copied()
+}
+
+fn determine_base_uri_from_headers(
+    headers: &HeaderMap,
+    use_x_forwarded_headers: bool,
+    trusted_hops: Option<NonZeroUsize>,
+) -> Option<String> {
     let host_header = headers
         .get(http::header::HOST)
         .and_then(|hv| hv.to_str().ok());
 
-    if CONFIG.use_x_forwarded_headers {
+    if use_x_forwarded_headers {
         let any_x_forwarded_header_present = headers
             .get(X_FORWARDED_HOST_HEADER)
             .or(headers.get(X_FORWARDED_PROTO_HEADER))
             .or(headers.get(X_FORWARDED_PORT_HEADER))
             .is_some();
 
-        let host = headers
-            .get(X_FORWARDED_HOST_HEADER)
-            .and_then(|hv| hv.to_str().ok())
+        let host = forwarded_header_value(headers, X_FORWARDED_HOST_HEADER, trusted_hops)
             .or(host_header)?;
 
-        let x_forwarded_proto = headers
-            .get(X_FORWARDED_PROTO_HEADER)
-            .and_then(|hv| hv.to_str().ok());
-        let x_forwarded_port = headers
-            .get(X_FORWARDED_PORT_HEADER)
-            .and_then(|hv| hv.to_str().ok());
-        let x_forwarded_prefix = headers
-            .get(X_FORWARDED_PREFIX_HEADER)
-            .and_then(|hv| hv.to_str().ok())
-            .map(|s| s.trim_matches('/'));
+        let x_forwarded_proto =
+            forwarded_header_value(headers, X_FORWARDED_PROTO_HEADER, trusted_hops);
+        let x_forwarded_port =
+            forwarded_header_value(headers, X_FORWARDED_PORT_HEADER, trusted_hops);
+        let x_forwarded_prefix =
+            forwarded_header_value(headers, X_FORWARDED_PREFIX_HEADER, trusted_hops)
+                .map(|s| s.trim_matches('/'));
 
         let mut base_uri = String::new();
         let proto = x_forwarded_proto.unwrap_or({
@@ -967,6 +1057,66 @@ mod test {
         assert_eq!(result, Some("https://example.com:8443".to_string()));
     }
 
+    fn two_proxy_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            X_FORWARDED_HOST_HEADER,
+            HeaderValue::from_static("example.com, proxy-1.internal"),
+        );
+        headers.insert(
+            X_FORWARDED_PROTO_HEADER,
+            HeaderValue::from_static("https,http"),
+        );
+        headers.insert(X_FORWARDED_PORT_HEADER, HeaderValue::from_static("443"));
+        headers.append(X_FORWARDED_PORT_HEADER, HeaderValue::from_static("8080"));
+        headers.insert(
+            X_FORWARDED_PREFIX_HEADER,
+            HeaderValue::from_static("/lakekeeper/, /"),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_determine_host_with_x_forwarded_chain_uses_original_values() {
+        let result = determine_base_uri_from_headers(&two_proxy_headers(), true, None);
+        assert_eq!(result, Some("https://example.com/lakekeeper".to_string()));
+    }
+
+    #[test]
+    fn test_determine_host_with_x_forwarded_chain_and_trusted_hops() {
+        let headers = two_proxy_headers();
+        let hops = |n| NonZeroUsize::new(n);
+
+        // Both proxies are trusted: the values of the first proxy are used.
+        for n in [2, 3] {
+            let result = determine_base_uri_from_headers(&headers, true, hops(n));
+            assert_eq!(result, Some("https://example.com/lakekeeper".to_string()));
+        }
+
+        // Only the proxy in front of Lakekeeper is trusted.
+        let result = determine_base_uri_from_headers(&headers, true, hops(1));
+        assert_eq!(result, Some("http://proxy-1.internal:8080/".to_string()));
+    }
+
+    #[test]
+    fn test_forwarded_header_value_skips_untrusted_values() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            X_FORWARDED_HOST_HEADER,
+            HeaderValue::from_static("spoofed.com, example.com ,proxy-1.internal"),
+        );
+        let value = |hops| {
+            forwarded_header_value(&headers, X_FORWARDED_HOST_HEADER, NonZeroUsize::new(hops))
+        };
+        assert_eq!(value(0), Some("spoofed.com"));
+        assert_eq!(value(2), Some("example.com"));
+        assert_eq!(value(1), Some("proxy-1.internal"));
+        assert_eq!(
+            forwarded_header_value(&headers, X_FORWARDED_PORT_HEADER, None),
+            None
+        );
+    }
+
     #[test]
     fn test_determine_host_with_x_forwarded_no_proto() {
         let mut headers = HeaderMap::new();
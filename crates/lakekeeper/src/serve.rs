@@ -21,14 +21,17 @@ use crate::{
         authz::{AllowAllAuthorizer, Authorizer, ConfiguredInstanceAdmins},
         contract_verification::ContractVerifiers,
         endpoint_statistics::{
-            EndpointStatisticsMessage, EndpointStatisticsSink, EndpointStatisticsTracker, FlushMode,
+            EndpointStatisticsMessage, EndpointStatisticsSink, EndpointStatisticsTracker,
+            FlushMode, SampleRates,
         },
         events::{
             CloudEventBackend, CloudEventsMessage, CloudEventsPublisher,
             CloudEventsPublisherBackgroundTask, EventDispatcher,
-            backends::audit::AuditEventListener,
+            backends::{audit::AuditEventListener, audit_store::CatalogAuditLogListener},
         },
-        health::ServiceHealthProvider,
+        group_roles::GroupRoleMapping,
+        health::{HealthExt, ServiceHealthProvider},
+        rate_limit::{InMemoryRateLimiter, RateLimiter},
         tasks::TaskQueueRegistry,
     },
 };
@@ -141,6 +144,16 @@ pub struct ServeConfiguration<
     /// external control-plane permission check.
     pub admission_gates: AdmissionGates,
     #[builder(default)]
+    /// Per-project rate limiter. If not set and `LAKEKEEPER__RATE_LIMIT__ENABLED`
+    /// is true, an in-memory token bucket limiter is used.
+    pub rate_limiter: Option<Arc<dyn RateLimiter>>,
+    #[builder(default)]
+    /// Additional checks that must pass before `/readyz` reports the server as
+    /// ready, e.g. that all database migrations are applied. Services that are
+    /// always checked (catalog, secrets, authorizer) don't need to be listed.
+    #[debug("Vec with {} readiness checks", readiness_checks.len())]
+    pub readiness_checks: Vec<(&'static str, Arc<dyn HealthExt + Sync + Send>)>,
+    #[builder(default)]
     /// A function to modify the router before serving
     pub modify_router_fn: Option<fn(axum::Router) -> axum::Router>,
     /// Cloud events sinks / publishers
@@ -200,6 +213,11 @@ pub async fn serve<C: CatalogStore, S: SecretStore, A: Authorizer, N: Authentica
         let _ = rejected;
     }
 
+    // Storage clients read the retry policy when they are first created.
+    if CONFIG.storage_retry.policy().install().is_err() {
+        tracing::debug!("Storage retry policy was already installed");
+    }
+
     let cancellation_token = CancellationToken::new();
 
     // Validate Authenticators and propagate their IDP IDs to the authorizer
@@ -263,9 +281,10 @@ pub async fn serve<C: CatalogStore, S: SecretStore, A: Authorizer, N: Authentica
         .await?;
     cloud_events_tx.send(CloudEventsMessage::Shutdown).await?;
 
-    // Wait for remaining tasks to finish, wait at most 20 seconds, then print which tasks are still running
-    // but exit anyway. Report progress every 5 seconds.
-    let shutdown_timeout_secs = 20;
+    // Wait for remaining tasks to finish, wait at most 20 seconds (or longer if task workers
+    // need more time to drain), then print which tasks are still running but exit anyway.
+    // Report progress every 5 seconds.
+    let shutdown_timeout_secs = 20.max(CONFIG.task_drain_timeout.as_secs() + 5);
     let report_interval_secs = 5;
     let start_time = std::time::Instant::now();
 
@@ -356,6 +375,8 @@ async fn serve_inner<
         stats,
         contract_verification,
         admission_gates,
+        rate_limiter,
+        readiness_checks,
         modify_router_fn,
         cloud_event_sinks,
         enable_built_in_task_queues: enable_built_in_queues,
@@ -386,7 +407,8 @@ async fn serve_inner<
             ("auth", Arc::new(authorizer.clone())),
         ],
         CONFIG.health_check_frequency_seconds,
-    );
+    )
+    .with_readiness_providers(readiness_checks);
 
     // Cloud events publisher setup
     let cloud_events_background_task = CloudEventsPublisherBackgroundTask {
@@ -412,7 +434,11 @@ async fn serve_inner<
         stats,
         CONFIG.endpoint_stat_flush_interval,
         FlushMode::Automatic,
-    );
+    )
+    .with_sample_rates(SampleRates::new(
+        CONFIG.endpoint_stat_sample_rate,
+        CONFIG.endpoint_stat_project_sample_rates.clone(),
+    ));
 
     // Event system setup
     let dispatcher = additional_event_dispatcher.unwrap_or(EventDispatcher::new(vec![]));
@@ -455,6 +481,14 @@ async fn serve_inner<
     } else {
         tracing::info!("Audit tracing is disabled");
     }
+    if CONFIG.audit.postgres.enabled {
+        tracing::info!("Audit log persistence is enabled, registering audit log listener");
+        dispatcher
+            .append(Arc::new(CatalogAuditLogListener::<C>::new(
+                catalog_state.clone(),
+            )))
+            .await;
+    }
 
     // Task queues
     let task_queue_registry = TaskQueueRegistry::new();
@@ -495,10 +529,18 @@ async fn serve_inner<
         },
     };
 
+    if enable_built_in_queues && !skip_built_in_queues_for_maintenance {
+        task_queue_registry
+            .register_metadata_log_compaction_queue(state.clone(), CONFIG.task_poll_interval)
+            .await;
+    }
+
     for register_fn in register_additional_task_queues_fn {
         register_fn(task_queue_registry.clone(), state.clone()).await?;
     }
 
+    let group_role_mapping = Arc::new(GroupRoleMapping::from_config()?);
+
     // Router
     let mut router = new_full_router::<C, _, _, _>(RouterArgs {
         authenticator: authenticator.clone(),
@@ -509,6 +551,13 @@ async fn serve_inner<
         endpoint_statistics_tracker_tx: endpoint_statistics_tracker_tx.clone(),
         instance_admin_membership: Arc::new(ConfiguredInstanceAdmins::from_config()),
         admission_gates,
+        group_role_mapping: group_role_mapping.clone(),
+        rate_limiter: rate_limiter.or_else(|| {
+            CONFIG.rate_limit.enabled.then(|| {
+                Arc::new(InMemoryRateLimiter::from_config(&CONFIG.rate_limit))
+                    as Arc<dyn RateLimiter>
+            })
+        }),
     })
     .await?;
 
@@ -541,6 +590,25 @@ async fn serve_inner<
     });
     service_ids.insert(ce_abort_handle.id(), "Event Publisher".to_string());
 
+    // Group role mapping reloader:
+    if let Some(path) = CONFIG.openid_group_role_mapping_file.clone() {
+        let cancellation_token_clone = cancellation_token.clone();
+        let reload_abort_handle = service_futures.spawn(async move {
+            group_role_mapping
+                .reload_periodically(
+                    path,
+                    CONFIG.openid_group_role_mapping_reload_interval,
+                    cancellation_token_clone,
+                )
+                .await;
+            Ok(())
+        });
+        service_ids.insert(
+            reload_abort_handle.id(),
+            "Group Role Mapping Reloader".to_string(),
+        );
+    }
+
     // Endpoint statistics tracker:
     let tracker_abort_handle = service_futures.spawn(async move {
         tracker.run().await;
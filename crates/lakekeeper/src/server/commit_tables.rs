@@ -1,15 +1,30 @@
-use std::{collections::HashMap, str::FromStr as _};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr as _,
+};
 
 use iceberg::{
     TableRequirement, TableUpdate,
-    spec::{SchemaRef, TableMetadata},
+    spec::{
+        NestedFieldRef, PrimitiveType, SchemaRef, StructType, TableMetadata, TableProperties, Type,
+    },
 };
 use iceberg_ext::spec::{TableMetadataBuildResult, TableMetadataBuilder};
-use lakekeeper_io::Location;
+use lakekeeper_io::{LakekeeperStorage, Location};
 
 use crate::{
-    server::tables::create_table::ensure_format_version_allowed,
-    service::{AllowedFormatVersions, ErrorModel, IcebergErrorResponse, Result},
+    server::{
+        io::file_exists,
+        tables::create_table::{
+            ensure_file_format_allowed, ensure_format_version_allowed,
+            ensure_partition_transforms_allowed, ensure_table_properties_within_limits,
+            table_properties_size,
+        },
+    },
+    service::{
+        AllowedFormatVersions, ErrorModel, FileFormatPolicy, IcebergErrorResponse,
+        PartitionTransformPolicy, Result, SchemaEvolutionPolicy, WriteFileFormat,
+    },
 };
 
 /// Table properties that must not be modified or removed once set.
@@ -33,6 +48,115 @@ pub(crate) fn ensure_format_version_upgrades_allowed(
     Ok(())
 }
 
+/// Reject any `AddSpec` update that uses a partition transform not permitted by
+/// the warehouse policy. Existing specs are not re-checked, so tightening a
+/// policy does not retroactively block writes to existing tables.
+pub(crate) fn ensure_partition_specs_allowed(
+    updates: &[TableUpdate],
+    policy: Option<&PartitionTransformPolicy>,
+) -> Result<()> {
+    let Some(policy) = policy else {
+        return Ok(());
+    };
+    for update in updates {
+        if let TableUpdate::AddSpec { spec } = update {
+            ensure_partition_transforms_allowed(spec, policy)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reject any `SetProperties` or `RemoveProperties` update that changes
+/// `write.format.default` to a file format not permitted by the warehouse policy.
+/// Removing the property falls back to the Iceberg default format. Tables whose
+/// property is left untouched are not re-checked, so tightening a policy does not
+/// retroactively block writes to existing tables.
+pub(crate) fn ensure_file_format_updates_allowed(
+    updates: &[TableUpdate],
+    policy: Option<&FileFormatPolicy>,
+) -> Result<()> {
+    let Some(policy) = policy else {
+        return Ok(());
+    };
+    for update in updates {
+        match update {
+            TableUpdate::SetProperties { updates } => {
+                if let Some(value) = updates.get(WriteFileFormat::PROPERTY) {
+                    ensure_file_format_allowed(Some(value), policy)?;
+                }
+            }
+            TableUpdate::RemoveProperties { removals } => {
+                if removals.iter().any(|key| key == WriteFileFormat::PROPERTY) {
+                    ensure_file_format_allowed(None, policy)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Reject a metadata location that is not below the table location. Enforced for
+/// warehouses with `require_metadata_in_table_location` set so that removing the
+/// table location also removes all of the table's metadata files.
+pub(crate) fn ensure_metadata_location_in_table_location(
+    table_location: &Location,
+    metadata_location: &Location,
+) -> Result<()> {
+    let mut table_location = table_location.clone();
+    table_location.without_trailing_slash();
+    if metadata_location != &table_location && metadata_location.is_sublocation_of(&table_location)
+    {
+        return Ok(());
+    }
+
+    Err(ErrorModel::bad_request(
+        format!(
+            "Metadata location '{metadata_location}' is not located below the table location '{table_location}'. This warehouse requires metadata files to reside within the table location."
+        ),
+        "MetadataLocationOutsideTableLocation",
+        None,
+    )
+    .into())
+}
+
+/// Reject any `SetPartitionStatistics` update whose statistics file does not exist
+/// in storage. Enforced for warehouses with `validate_partition_statistics_files`
+/// set, so that `partition_statistics` never references files that were not written.
+pub(crate) async fn ensure_partition_statistics_files_exist(
+    io: &impl LakekeeperStorage,
+    updates: &[TableUpdate],
+) -> Result<()> {
+    for update in updates {
+        let TableUpdate::SetPartitionStatistics {
+            partition_statistics,
+        } = update
+        else {
+            continue;
+        };
+        let statistics_path = &partition_statistics.statistics_path;
+        let location = Location::from_str(statistics_path).map_err(|e| {
+            ErrorModel::bad_request(
+                format!("Invalid partition statistics path '{statistics_path}': {e}"),
+                "InvalidPartitionStatisticsPath",
+                None,
+            )
+        })?;
+        if !file_exists(io, &location).await? {
+            return Err(ErrorModel::bad_request(
+                format!(
+                    "Partition statistics file '{statistics_path}' of snapshot {} does not exist. This warehouse requires partition statistics files to be written before they are committed.",
+                    partition_statistics.snapshot_id
+                ),
+                "PartitionStatisticsFileNotFound",
+                None,
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
 /// Reject a commit that would rebind an existing schema id to different content.
 ///
 /// Iceberg treats a schema id as an immutable handle to a fixed set of columns, and the
@@ -74,6 +198,235 @@ pub(crate) fn ensure_schema_content_stable<'a>(
     Ok(())
 }
 
+/// Reject schema changes that are not backwards compatible when the warehouse uses
+/// [`SchemaEvolutionPolicy::Safe`]. Every schema added by the commit, as well as the new
+/// current schema, is compared against the previous current schema. Removing a required
+/// field, making an optional field required, and changing a type other than through an
+/// Iceberg type promotion (`int` to `long`, `float` to `double`, widening a decimal's
+/// precision) are rejected. Existing schemas are not re-checked, so tightening the policy
+/// does not retroactively block writes to existing tables.
+pub(crate) fn ensure_schema_evolution_allowed(
+    previous: &TableMetadata,
+    new: &TableMetadata,
+    policy: SchemaEvolutionPolicy,
+) -> Result<()> {
+    if policy == SchemaEvolutionPolicy::Permissive {
+        return Ok(());
+    }
+    let base = previous.current_schema();
+    let current_changed = previous.current_schema_id() != new.current_schema_id();
+    for schema in new.schemas_iter() {
+        let added = previous.schema_by_id(schema.schema_id()).is_none();
+        let made_current = current_changed && schema.schema_id() == new.current_schema_id();
+        if !added && !made_current {
+            continue;
+        }
+        let mut violations = Vec::new();
+        check_struct_evolution(base.as_struct(), schema.as_struct(), "", &mut violations);
+        if !violations.is_empty() {
+            return Err(ErrorModel::bad_request(
+                format!(
+                    "Schema {} is not a compatible evolution of the current schema {}: {}. This warehouse only permits safe schema evolution.",
+                    schema.schema_id(),
+                    base.schema_id(),
+                    violations.join("; ")
+                ),
+                "IncompatibleSchemaChange",
+                None,
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+fn check_struct_evolution(
+    previous: &StructType,
+    new: &StructType,
+    prefix: &str,
+    violations: &mut Vec<String>,
+) {
+    for field in previous.fields() {
+        let path = format!("{prefix}{}", field.name);
+        match new.field_by_id(field.id) {
+            None if field.required => {
+                violations.push(format!("required field `{path}` was removed"));
+            }
+            None => {}
+            Some(new_field) => check_field_evolution(field, new_field, &path, violations),
+        }
+    }
+}
+
+fn check_field_evolution(
+    previous: &NestedFieldRef,
+    new: &NestedFieldRef,
+    path: &str,
+    violations: &mut Vec<String>,
+) {
+    if !previous.required && new.required {
+        violations.push(format!("optional field `{path}` was made required"));
+    }
+    match (previous.field_type.as_ref(), new.field_type.as_ref()) {
+        (Type::Primitive(p), Type::Primitive(n)) => {
+            if !is_allowed_type_promotion(p, n) {
+                violations.push(format!("field `{path}` changed type from {p} to {n}"));
+            }
+        }
+        (Type::Struct(p), Type::Struct(n)) => {
+            check_struct_evolution(p, n, &format!("{path}."), violations);
+        }
+        (Type::List(p), Type::List(n)) => check_field_evolution(
+            &p.element_field,
+            &n.element_field,
+            &format!("{path}.element"),
+            violations,
+        ),
+        (Type::Map(p), Type::Map(n)) => {
+            check_field_evolution(
+                &p.key_field,
+                &n.key_field,
+                &format!("{path}.key"),
+                violations,
+            );
+            check_field_evolution(
+                &p.value_field,
+                &n.value_field,
+                &format!("{path}.value"),
+                violations,
+            );
+        }
+        (p, n) => violations.push(format!("field `{path}` changed type from {p} to {n}")),
+    }
+}
+
+fn is_allowed_type_promotion(previous: &PrimitiveType, new: &PrimitiveType) -> bool {
+    match (previous, new) {
+        (p, n) if p == n => true,
+        (PrimitiveType::Int, PrimitiveType::Long)
+        | (PrimitiveType::Float, PrimitiveType::Double) => true,
+        (
+            PrimitiveType::Decimal {
+                precision: p_precision,
+                scale: p_scale,
+            },
+            PrimitiveType::Decimal {
+                precision: n_precision,
+                scale: n_scale,
+            },
+        ) => p_scale == n_scale && n_precision >= p_precision,
+        _ => false,
+    }
+}
+
+/// Reject commits whose `SetProperties` updates grow the table properties beyond
+/// the warehouse limits. Commits that do not set properties are not checked, and a
+/// limit is only enforced if the commit increases the respective measure, so
+/// tightening the limits does not block writes to existing tables.
+pub(crate) fn ensure_property_updates_within_limits(
+    updates: &[TableUpdate],
+    previous_metadata: &TableMetadata,
+    new_metadata: &TableMetadata,
+    max_properties: Option<u32>,
+    max_properties_size_bytes: Option<u32>,
+) -> Result<()> {
+    if !updates
+        .iter()
+        .any(|update| matches!(update, TableUpdate::SetProperties { .. }))
+    {
+        return Ok(());
+    }
+    let previous = previous_metadata.properties();
+    let new = new_metadata.properties();
+    ensure_table_properties_within_limits(
+        new,
+        max_properties.filter(|_| new.len() > previous.len()),
+        max_properties_size_bytes
+            .filter(|_| table_properties_size(new) > table_properties_size(previous)),
+    )
+}
+
+/// Select the snapshots to expire so that `metadata` retains at most `max_snapshots`
+/// snapshots, oldest first. If any of the selected snapshots is still referenced by a
+/// branch or tag, nothing is expired and an empty list is returned, as removing the
+/// snapshot would silently drop the reference.
+pub(crate) fn snapshots_exceeding_limit(metadata: &TableMetadata, max_snapshots: u32) -> Vec<i64> {
+    let mut snapshots: Vec<_> = metadata.snapshots().collect();
+    let max_snapshots = usize::try_from(max_snapshots).unwrap_or(usize::MAX);
+    let excess = snapshots.len().saturating_sub(max_snapshots);
+    if excess == 0 {
+        return vec![];
+    }
+    snapshots.sort_by_key(|s| (s.timestamp_ms(), s.sequence_number(), s.snapshot_id()));
+    let expired: Vec<i64> = snapshots[..excess]
+        .iter()
+        .map(|s| s.snapshot_id())
+        .collect();
+    let mut referenced: HashSet<i64> = metadata.refs().values().map(|r| r.snapshot_id).collect();
+    referenced.extend(metadata.current_snapshot_id());
+    if let Some(snapshot_id) = expired.iter().find(|id| referenced.contains(id)) {
+        tracing::debug!(
+            "Not expiring snapshots above the limit of {max_snapshots}: snapshot {snapshot_id} is still referenced"
+        );
+        return vec![];
+    }
+    expired
+}
+
+/// Drop the oldest entries of the metadata log of `build_result` so that at most
+/// `max_entries` remain. The dropped entries are added to its expired metadata logs.
+///
+/// The metadata builder only trims the log to `write.metadata.previous-versions-max`,
+/// so the metadata is built once more with that property lowered to `max_entries`
+/// and once more with the property restored.
+pub(crate) fn prune_metadata_log(
+    build_result: TableMetadataBuildResult,
+    max_entries: usize,
+) -> Result<TableMetadataBuildResult> {
+    let TableMetadataBuildResult {
+        metadata,
+        changes,
+        mut expired_metadata_logs,
+    } = build_result;
+    if metadata.metadata_log().len() <= max_entries {
+        return Ok(TableMetadataBuildResult {
+            metadata,
+            changes,
+            expired_metadata_logs,
+        });
+    }
+
+    let build_error = |e: iceberg::Error| {
+        ErrorModel::internal(
+            "Failed to prune the metadata log of the table metadata.",
+            "MetadataLogPruneError",
+            Some(Box::new(e)),
+        )
+    };
+    let key = TableProperties::PROPERTY_METADATA_PREVIOUS_VERSIONS_MAX.to_string();
+    let previous_value = metadata.properties().get(&key).cloned();
+    let lowered = metadata
+        .into_builder(None)
+        .set_properties(HashMap::from([(key.clone(), max_entries.to_string())]))
+        .and_then(TableMetadataBuilder::build)
+        .map_err(build_error)?;
+    expired_metadata_logs.extend(lowered.expired_metadata_logs);
+
+    let builder = lowered.metadata.into_builder(None);
+    let restored = match previous_value {
+        Some(value) => builder.set_properties(HashMap::from([(key, value)])),
+        None => builder.remove_properties(&[key]),
+    }
+    .and_then(TableMetadataBuilder::build)
+    .map_err(build_error)?;
+
+    Ok(TableMetadataBuildResult {
+        metadata: restored.metadata,
+        changes,
+        expired_metadata_logs,
+    })
+}
+
 /// Apply the commits to table metadata.
 pub(super) fn apply_commit(
     metadata: TableMetadata,
@@ -249,20 +602,28 @@ fn check_immutable_properties_not_removed(
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{collections::HashMap, str::FromStr as _};
 
     use iceberg::{
         TableUpdate,
         spec::{
-            FormatVersion, NestedField, PrimitiveType, Schema, SortOrder, UnboundPartitionSpec,
+            FormatVersion, MAIN_BRANCH, NestedField, Operation, PrimitiveType, Schema, Snapshot,
+            SnapshotReference, SnapshotRetention, SortOrder, Summary, Transform,
+            UnboundPartitionSpec,
         },
     };
     use iceberg_ext::spec::TableMetadataBuilder;
+    use lakekeeper_io::Location;
 
     use super::{
-        AllowedFormatVersions, apply_commit, ensure_format_version_upgrades_allowed,
-        ensure_schema_content_stable,
+        AllowedFormatVersions, FileFormatPolicy, PartitionTransformPolicy, apply_commit,
+        ensure_file_format_updates_allowed, ensure_format_version_upgrades_allowed,
+        ensure_metadata_location_in_table_location, ensure_partition_specs_allowed,
+        ensure_property_updates_within_limits, ensure_schema_content_stable,
+        ensure_schema_evolution_allowed, ensure_table_properties_within_limits, prune_metadata_log,
+        snapshots_exceeding_limit,
     };
+    use crate::service::{PartitionTransformKind, SchemaEvolutionPolicy, WriteFileFormat};
 
     fn test_metadata_with_properties(
         props: HashMap<String, String>,
@@ -428,6 +789,140 @@ mod tests {
         assert_eq!(err.error.r#type, "FormatVersionNotAllowed");
     }
 
+    fn add_spec_update(transform: Transform) -> TableUpdate {
+        TableUpdate::AddSpec {
+            spec: UnboundPartitionSpec::builder()
+                .add_partition_field(1, "id_part", transform)
+                .unwrap()
+                .build(),
+        }
+    }
+
+    #[test]
+    fn test_add_spec_with_allowed_transforms_succeeds() {
+        let policy = PartitionTransformPolicy::try_new(
+            [
+                PartitionTransformKind::Identity,
+                PartitionTransformKind::Bucket,
+            ],
+            Some(16),
+        )
+        .expect("non-empty");
+
+        ensure_partition_specs_allowed(
+            &[
+                add_spec_update(Transform::Identity),
+                add_spec_update(Transform::Bucket(16)),
+            ],
+            Some(&policy),
+        )
+        .expect("identity and bucket[16] are allowed");
+    }
+
+    #[test]
+    fn test_add_spec_with_disallowed_transform_is_rejected() {
+        let policy = PartitionTransformPolicy::try_new(
+            [
+                PartitionTransformKind::Identity,
+                PartitionTransformKind::Bucket,
+            ],
+            Some(16),
+        )
+        .expect("non-empty");
+
+        for transform in [Transform::Day, Transform::Bucket(32)] {
+            let err = ensure_partition_specs_allowed(&[add_spec_update(transform)], Some(&policy))
+                .unwrap_err();
+            assert_eq!(err.error.r#type, "PartitionTransformNotAllowed");
+        }
+    }
+
+    #[test]
+    fn test_add_spec_without_policy_allows_any_transform() {
+        ensure_partition_specs_allowed(&[add_spec_update(Transform::Void)], None)
+            .expect("no policy configured");
+    }
+
+    #[test]
+    fn test_file_format_updates_allowed_by_policy() {
+        let policy = FileFormatPolicy::try_new([WriteFileFormat::Parquet]).unwrap();
+        let updates = vec![
+            TableUpdate::SetProperties {
+                updates: HashMap::from([(
+                    "write.format.default".to_string(),
+                    "PARQUET".to_string(),
+                )]),
+            },
+            TableUpdate::RemoveProperties {
+                removals: vec!["write.format.default".to_string()],
+            },
+        ];
+
+        ensure_file_format_updates_allowed(&updates, Some(&policy)).expect("parquet is allowed");
+    }
+
+    #[test]
+    fn test_file_format_updates_rejected_by_policy() {
+        let policy = FileFormatPolicy::try_new([WriteFileFormat::Orc]).unwrap();
+        for update in [
+            TableUpdate::SetProperties {
+                updates: HashMap::from([("write.format.default".to_string(), "avro".to_string())]),
+            },
+            // Removing the property falls back to parquet.
+            TableUpdate::RemoveProperties {
+                removals: vec!["write.format.default".to_string()],
+            },
+        ] {
+            let err = ensure_file_format_updates_allowed(&[update], Some(&policy)).unwrap_err();
+            assert_eq!(err.error.r#type, "FileFormatNotAllowed");
+        }
+
+        // Other properties and missing policies are not checked.
+        ensure_file_format_updates_allowed(
+            &[TableUpdate::SetProperties {
+                updates: HashMap::from([("owner".to_string(), "me".to_string())]),
+            }],
+            Some(&policy),
+        )
+        .expect("unrelated property");
+        ensure_file_format_updates_allowed(
+            &[TableUpdate::SetProperties {
+                updates: HashMap::from([("write.format.default".to_string(), "avro".to_string())]),
+            }],
+            None,
+        )
+        .expect("no policy configured");
+    }
+
+    #[test]
+    fn test_metadata_location_below_table_location_is_accepted() {
+        let table_location = Location::from_str("s3://bucket/wh/ns/tbl/").unwrap();
+        let metadata_location =
+            Location::from_str("s3://bucket/wh/ns/tbl/metadata/00001-abc.metadata.json").unwrap();
+
+        ensure_metadata_location_in_table_location(&table_location, &metadata_location)
+            .expect("metadata is below the table location");
+    }
+
+    #[test]
+    fn test_metadata_location_outside_table_location_is_rejected() {
+        let table_location = Location::from_str("s3://bucket/wh/ns/tbl").unwrap();
+        for metadata_location in [
+            "s3://bucket/wh/ns/other/metadata/00001-abc.metadata.json",
+            // Shares the table location as a string prefix but is a sibling directory.
+            "s3://bucket/wh/ns/tbl-metadata/00001-abc.metadata.json",
+            "s3://other-bucket/wh/ns/tbl/metadata/00001-abc.metadata.json",
+            "s3://bucket/wh/ns/tbl",
+        ] {
+            let err = ensure_metadata_location_in_table_location(
+                &table_location,
+                &Location::from_str(metadata_location).unwrap(),
+            )
+            .unwrap_err();
+            assert_eq!(err.error.r#type, "MetadataLocationOutsideTableLocation");
+        }
+    }
+
     #[test]
     fn adding_a_new_schema_preserves_existing_content_ok() {
         // Base metadata has schema id 0 (current) = [1: id int]. Adding a structurally different
@@ -558,4 +1053,308 @@ mod tests {
                 .expect("identical schemas with the same identifier set must not be flagged");
         }
     }
+
+    fn set_current_schema_commit(
+        metadata: &iceberg::spec::TableMetadata,
+        fields: Vec<NestedField>,
+    ) -> iceberg::spec::TableMetadata {
+        let schema = Schema::builder()
+            .with_fields(fields.into_iter().map(Into::into).collect::<Vec<_>>())
+            .build()
+            .unwrap();
+        apply_commit(
+            metadata.clone(),
+            None,
+            &[],
+            vec![
+                TableUpdate::AddSchema { schema },
+                TableUpdate::SetCurrentSchema { schema_id: -1 },
+            ],
+        )
+        .unwrap()
+        .metadata
+    }
+
+    #[test]
+    fn test_safe_schema_evolution_rejects_removing_required_field() {
+        // Base: schema 0 = [1: id int (required)].
+        let metadata = test_metadata_with_properties(HashMap::new());
+        let new_metadata = set_current_schema_commit(
+            &metadata,
+            vec![NestedField::optional(
+                2,
+                "name",
+                iceberg::spec::Type::Primitive(PrimitiveType::String),
+            )],
+        );
+
+        let err =
+            ensure_schema_evolution_allowed(&metadata, &new_metadata, SchemaEvolutionPolicy::Safe)
+                .unwrap_err();
+        assert_eq!(err.error.r#type, "IncompatibleSchemaChange");
+        assert!(err.error.message.contains("`id`"), "{}", err.error.message);
+
+        ensure_schema_evolution_allowed(
+            &metadata,
+            &new_metadata,
+            SchemaEvolutionPolicy::Permissive,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_safe_schema_evolution_rejects_type_narrowing() {
+        let metadata = test_metadata_with_properties(HashMap::new());
+        let widened = set_current_schema_commit(
+            &metadata,
+            vec![NestedField::required(
+                1,
+                "id",
+                iceberg::spec::Type::Primitive(PrimitiveType::Long),
+            )],
+        );
+        ensure_schema_evolution_allowed(&metadata, &widened, SchemaEvolutionPolicy::Safe).unwrap();
+
+        let narrowed = set_current_schema_commit(
+            &widened,
+            vec![NestedField::required(
+                1,
+                "id",
+                iceberg::spec::Type::Primitive(PrimitiveType::Int),
+            )],
+        );
+        let err = ensure_schema_evolution_allowed(&widened, &narrowed, SchemaEvolutionPolicy::Safe)
+            .unwrap_err();
+        assert_eq!(err.error.r#type, "IncompatibleSchemaChange");
+    }
+
+    #[test]
+    fn test_safe_schema_evolution_allows_adding_optional_field() {
+        let metadata = test_metadata_with_properties(HashMap::new());
+        let new_metadata = set_current_schema_commit(
+            &metadata,
+            vec![
+                NestedField::required(1, "id", iceberg::spec::Type::Primitive(PrimitiveType::Int)),
+                NestedField::optional(
+                    2,
+                    "name",
+                    iceberg::spec::Type::Primitive(PrimitiveType::String),
+                ),
+            ],
+        );
+        ensure_schema_evolution_allowed(&metadata, &new_metadata, SchemaEvolutionPolicy::Safe)
+            .unwrap();
+    }
+
+    fn commit_snapshot(
+        metadata: iceberg::spec::TableMetadata,
+        snapshot_id: i64,
+        reference: &str,
+    ) -> iceberg::spec::TableMetadata {
+        let snapshot = Snapshot::builder()
+            .with_snapshot_id(snapshot_id)
+            .with_parent_snapshot_id(metadata.current_snapshot_id())
+            .with_timestamp_ms(metadata.last_updated_ms() + 1)
+            .with_sequence_number(metadata.last_sequence_number() + 1)
+            .with_manifest_list(format!(
+                "s3://bucket/table/metadata/snap-{snapshot_id}.avro"
+            ))
+            .with_summary(Summary {
+                operation: Operation::Append,
+                additional_properties: HashMap::new(),
+            })
+            .with_schema_id(0)
+            .build();
+        let retention = if reference == MAIN_BRANCH {
+            SnapshotRetention::Branch {
+                min_snapshots_to_keep: None,
+                max_snapshot_age_ms: None,
+                max_ref_age_ms: None,
+            }
+        } else {
+            SnapshotRetention::Tag {
+                max_ref_age_ms: None,
+            }
+        };
+        apply_commit(
+            metadata,
+            None,
+            &[],
+            vec![
+                TableUpdate::AddSnapshot { snapshot },
+                TableUpdate::SetSnapshotRef {
+                    ref_name: reference.to_string(),
+                    reference: SnapshotReference {
+                        snapshot_id,
+                        retention,
+                    },
+                },
+            ],
+        )
+        .unwrap()
+        .metadata
+    }
+
+    #[test]
+    fn test_snapshots_exceeding_limit_selects_oldest() {
+        let mut metadata = test_metadata_with_properties(HashMap::new());
+        for snapshot_id in 1..=3 {
+            metadata = commit_snapshot(metadata, snapshot_id, MAIN_BRANCH);
+        }
+
+        assert_eq!(snapshots_exceeding_limit(&metadata, 1), vec![1, 2]);
+        assert_eq!(snapshots_exceeding_limit(&metadata, 2), vec![1]);
+        assert!(snapshots_exceeding_limit(&metadata, 3).is_empty());
+    }
+
+    #[test]
+    fn test_snapshots_exceeding_limit_skips_referenced_snapshots() {
+        let mut metadata = test_metadata_with_properties(HashMap::new());
+        metadata = commit_snapshot(metadata, 1, "v1");
+        metadata = commit_snapshot(metadata, 2, MAIN_BRANCH);
+        metadata = commit_snapshot(metadata, 3, MAIN_BRANCH);
+
+        // Snapshot 1 is the oldest but still tagged, so nothing is expired.
+        assert!(snapshots_exceeding_limit(&metadata, 2).is_empty());
+    }
+
+    #[test]
+    fn test_prune_metadata_log_drops_oldest_entries() {
+        let mut metadata = test_metadata_with_properties(HashMap::new());
+        for i in 0..50 {
+            let location = Location::from_str(&format!(
+                "s3://bucket/table/metadata/{i:05}-{}.metadata.json",
+                uuid::Uuid::now_v7()
+            ))
+            .unwrap();
+            metadata = apply_commit(
+                metadata,
+                Some(&location),
+                &[],
+                set_properties(&[("change", i.to_string().as_str())]),
+            )
+            .unwrap()
+            .metadata;
+        }
+        assert_eq!(metadata.metadata_log().len(), 50);
+        let expected_remaining = metadata.metadata_log()[40..].to_vec();
+        let expected_pruned = metadata.metadata_log()[..40].to_vec();
+
+        let build_result = apply_commit(metadata.clone(), None, &[], vec![]).unwrap();
+        let pruned = prune_metadata_log(build_result, 10).unwrap();
+        assert_eq!(
+            pruned.metadata.metadata_log(),
+            expected_remaining.as_slice()
+        );
+        assert_eq!(pruned.expired_metadata_logs, expected_pruned);
+        assert_eq!(pruned.metadata.properties(), metadata.properties());
+
+        let unchanged = prune_metadata_log(
+            apply_commit(pruned.metadata.clone(), None, &[], vec![]).unwrap(),
+            10,
+        )
+        .unwrap();
+        assert_eq!(
+            unchanged.metadata.metadata_log(),
+            pruned.metadata.metadata_log()
+        );
+        assert!(unchanged.expired_metadata_logs.is_empty());
+    }
+
+    fn set_properties(properties: &[(&str, &str)]) -> Vec<TableUpdate> {
+        vec![TableUpdate::SetProperties {
+            updates: properties
+                .iter()
+                .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                .collect(),
+        }]
+    }
+
+    #[test]
+    fn test_table_properties_count_limit_boundary() {
+        let properties = HashMap::from([
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "2".to_string()),
+        ]);
+
+        ensure_table_properties_within_limits(&properties, Some(2), None).unwrap();
+        let err = ensure_table_properties_within_limits(&properties, Some(1), None).unwrap_err();
+        assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+    }
+
+    #[test]
+    fn test_table_properties_size_limit_boundary() {
+        // Keys and values add up to 2 + 4 + 1 + 1 = 8 bytes.
+        let properties = HashMap::from([
+            ("ab".to_string(), "cdef".to_string()),
+            ("g".to_string(), "h".to_string()),
+        ]);
+
+        ensure_table_properties_within_limits(&properties, None, Some(8)).unwrap();
+        let err = ensure_table_properties_within_limits(&properties, None, Some(7)).unwrap_err();
+        assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+    }
+
+    #[test]
+    fn test_property_updates_within_limits_count_boundary() {
+        let metadata =
+            test_metadata_with_properties(HashMap::from([("a".to_string(), "1".to_string())]));
+        let updates = set_properties(&[("b", "2")]);
+        let new_metadata = apply_commit(metadata.clone(), None, &[], updates.clone())
+            .unwrap()
+            .metadata;
+
+        ensure_property_updates_within_limits(&updates, &metadata, &new_metadata, Some(2), None)
+            .unwrap();
+        let err = ensure_property_updates_within_limits(
+            &updates,
+            &metadata,
+            &new_metadata,
+            Some(1),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+    }
+
+    #[test]
+    fn test_property_updates_within_limits_size_boundary() {
+        let metadata = test_metadata_with_properties(HashMap::new());
+        // 3 + 5 = 8 bytes
+        let updates = set_properties(&[("key", "value")]);
+        let new_metadata = apply_commit(metadata.clone(), None, &[], updates.clone())
+            .unwrap()
+            .metadata;
+
+        ensure_property_updates_within_limits(&updates, &metadata, &new_metadata, None, Some(8))
+            .unwrap();
+        let err = ensure_property_updates_within_limits(
+            &updates,
+            &metadata,
+            &new_metadata,
+            None,
+            Some(7),
+        )
+        .unwrap_err();
+        assert_eq!(err.error.r#type, "TablePropertiesLimitExceeded");
+    }
+
+    #[test]
+    fn test_property_updates_within_limits_allows_shrinking_tables_above_limit() {
+        let metadata = test_metadata_with_properties(HashMap::from([
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "2".to_string()),
+            ("c".to_string(), "3".to_string()),
+        ]));
+        // Overwriting a value with a shorter one neither adds properties nor grows them.
+        let updates = set_properties(&[("a", "")]);
+        let new_metadata = apply_commit(metadata.clone(), None, &[], updates.clone())
+            .unwrap()
+            .metadata;
+
+        ensure_property_updates_within_limits(&updates, &metadata, &new_metadata, Some(1), Some(1))
+            .unwrap();
+        // Commits without `SetProperties` are not checked at all.
+        ensure_property_updates_within_limits(&[], &metadata, &metadata, Some(1), Some(1)).unwrap();
+    }
 }
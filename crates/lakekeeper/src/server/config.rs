@@ -13,8 +13,8 @@ use crate::{
     config::MaintenanceMode,
     request_metadata::RequestMetadata,
     service::{
-        CatalogStore, CatalogWarehouseOps, ProjectId, SecretStore, State, Transaction,
-        UserUpsertMode, WarehouseNameNotFound, WarehouseStatus,
+        CatalogStore, CatalogWarehouseOps, ProjectId, ResolvedWarehouse, SecretStore, State,
+        Transaction, UserUpsertMode, WarehouseNameNotFound, WarehouseStatus,
         authz::{
             Authorizer, AuthzWarehouseOps, CatalogWarehouseAction, RequireWarehouseActionError,
         },
@@ -107,34 +107,54 @@ impl<A: Authorizer + Clone, C: CatalogStore, S: SecretStore>
             }
         };
 
-        let mut config = warehouse.storage_profile.generate_catalog_config(
-            warehouse.warehouse_id,
-            &request_metadata_arc,
-            warehouse.tabular_delete_profile,
-        );
+        Ok(catalog_config(&warehouse, &request_metadata_arc))
+    }
+}
 
-        config.defaults.insert(
-            "prefix".to_string(),
-            CONFIG.warehouse_prefix(warehouse.warehouse_id),
-        );
-        config.defaults.insert(
-            "rest-page-size".to_string(),
-            CONFIG.pagination_size_default.to_string(),
-        );
+/// The catalog config returned to clients of `warehouse` by `GET /config`.
+///
+/// Also served by the management API, so that operators see exactly the
+/// config a client of the warehouse receives.
+pub(crate) fn catalog_config(
+    warehouse: &ResolvedWarehouse,
+    request_metadata: &RequestMetadata,
+) -> CatalogConfig {
+    let mut config = warehouse.storage_profile.generate_catalog_config(
+        warehouse.warehouse_id,
+        request_metadata,
+        warehouse.tabular_delete_profile,
+    );
 
-        config
-            .overrides
-            .insert("uri".to_string(), request_metadata_arc.base_uri_catalog());
+    config.defaults.insert(
+        "prefix".to_string(),
+        CONFIG.warehouse_prefix(warehouse.warehouse_id),
+    );
+    config.defaults.insert(
+        "rest-page-size".to_string(),
+        CONFIG.pagination_size_default.to_string(),
+    );
 
-        if CONFIG.idempotency.enabled {
-            config.overrides.insert(
-                "idempotency-key-lifetime".to_string(),
-                CONFIG.idempotency.lifetime_iso8601(),
-            );
-        }
+    config
+        .overrides
+        .insert("uri".to_string(), request_metadata.base_uri_catalog());
 
-        Ok(config)
+    // Clients send this header with every table request, so a warehouse
+    // restricted to a single credential mode only receives requests for it.
+    if let Some(access_delegation) = warehouse.settings.credential_mode.access_delegation() {
+        config.overrides.insert(
+            "header.X-Iceberg-Access-Delegation".to_string(),
+            access_delegation.to_string(),
+        );
+    }
+
+    if CONFIG.idempotency.enabled {
+        config.overrides.insert(
+            "idempotency-key-lifetime".to_string(),
+            CONFIG.idempotency.lifetime_iso8601(),
+        );
     }
+
+    config
 }
 
 fn parse_warehouse_arg(arg: &str) -> (Option<ProjectId>, String) {
@@ -183,6 +203,10 @@ async fn maybe_register_user<D: CatalogStore>(
     let Some(user_id) = request_metadata.user_id() else {
         return Ok(());
     };
+    // Requests admitted anonymously act as a configured principal without token.
+    if request_metadata.anonymous_access().is_some() {
+        return Ok(());
+    }
 
     // `parse_create_user_request` can fail - we can't run it for already registered users
     let user = D::list_user(
@@ -15,11 +15,14 @@ use crate::{
         iceberg::v1::namespace::NamespaceParameters,
     },
     request_metadata::RequestMetadata,
-    server::{require_warehouse_id, tabular::determine_tabular_location},
+    server::{
+        require_warehouse_id,
+        tabular::{determine_tabular_location, namespace_storage_profile_slot},
+    },
     service::{
         CachePolicy, CatalogGenericTableOps, CatalogIdempotencyOps, CatalogStore,
-        GenericTableCreation, GenericTableId, Result, SecretStore, State, TabularId, Transaction,
-        WarehouseId,
+        GenericTableCreation, GenericTableId, Result, SecretStore, State, StorageProfileSlot,
+        TabularId, Transaction, WarehouseId,
         authz::{Authorizer, AuthzNamespaceOps, CatalogNamespaceAction},
         events::{
             APIEventContext,
@@ -254,6 +257,9 @@ async fn create_generic_table_inner<C: CatalogStore, A: Authorizer + Clone, S: S
         tabular_id,
         &table_ident,
         &warehouse.storage_profile,
+        // Views and generic tables are always stored on the primary storage profile.
+        namespace_storage_profile_slot(&ns_hierarchy.namespace) == StorageProfileSlot::Primary,
+        warehouse.settings.require_location_in_warehouse,
     )?;
 
     let creation = GenericTableCreation {
@@ -107,6 +107,7 @@ pub(super) async fn load_generic_table_credentials<
         .storage_profile
         .generate_table_config(
             data_access.into(),
+            warehouse.settings.credential_mode,
             storage_secret_ref,
             &gt_info.location,
             storage_permissions,
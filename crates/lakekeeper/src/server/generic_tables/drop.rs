@@ -13,8 +13,8 @@ use crate::{
     request_metadata::RequestMetadata,
     server::require_warehouse_id,
     service::{
-        CatalogIdempotencyOps, CatalogStore, CatalogTabularOps, NamedEntity, Result, SecretStore,
-        State, TabularId, Transaction,
+        CatalogIdempotencyOps, CatalogNamespaceOps, CatalogStore, CatalogTabularOps, NamedEntity,
+        Result, SecretStore, State, TabularId, Transaction,
         authz::{Authorizer, CatalogGenericTableAction},
         events::{APIEventContext, context::ResolvedGenericTable},
         idempotency::IdempotencyInfo,
@@ -77,6 +77,7 @@ pub(super) async fn drop_generic_table<C: CatalogStore, A: Authorizer + Clone, S
         .await,
     )?;
     let generic_table_id = info.generic_table_id;
+    let namespace_id = info.namespace_id;
 
     let event_ctx = event_ctx.resolve(ResolvedGenericTable {
         warehouse: warehouse.clone(),
@@ -90,7 +91,9 @@ pub(super) async fn drop_generic_table<C: CatalogStore, A: Authorizer + Clone, S
     let delete_profile = if force {
         TabularDeleteProfile::Hard {}
     } else {
-        warehouse.tabular_delete_profile
+        C::get_namespace_delete_profile(warehouse_id, namespace_id, t.transaction())
+            .await?
+            .unwrap_or(warehouse.tabular_delete_profile)
     };
     let project_id = &warehouse.project_id;
 
@@ -79,6 +79,7 @@ pub(super) async fn load_generic_table<C: CatalogStore, A: Authorizer + Clone, S
             .storage_profile
             .generate_table_config(
                 data_access,
+                warehouse.settings.credential_mode,
                 storage_secret_ref,
                 &info.location,
                 storage_permissions,
@@ -31,6 +31,13 @@ pub(crate) async fn delete_file(
     io.delete(location.as_str()).await.map_err(Into::into)
 }
 
+pub(crate) async fn file_exists(
+    io: &impl LakekeeperStorage,
+    location: &Location,
+) -> Result<bool, IOErrorExt> {
+    io.exists(location.as_str()).await.map_err(Into::into)
+}
+
 pub(crate) async fn read_file(
     io: &impl LakekeeperStorage,
     file: &Location,
@@ -13,6 +13,7 @@ pub mod views;
 
 use std::{collections::HashMap, fmt::Debug, marker::PhantomData, sync::Arc};
 
+pub(crate) use config::catalog_config;
 use futures::future::BoxFuture;
 use iceberg::spec::{TableMetadata, ViewMetadata};
 use iceberg_ext::catalog::rest::IcebergErrorResponse;
@@ -231,13 +232,11 @@ where
     // a word of advice: don't, we need to take the nth page-token of the next page when
     // we're filling a auth-filtered page. Without a vec, that won't fly.
 {
-    let page_size = page_size
-        .unwrap_or(if matches!(page_token, PageToken::NotSpecified) {
-            CONFIG.pagination_size_max.into()
-        } else {
-            CONFIG.pagination_size_default.into()
-        })
-        .clamp(1, CONFIG.pagination_size_max.into());
+    let page_size = if matches!(page_token, PageToken::NotSpecified) {
+        CONFIG.page_size_or_pagination_max(page_size)
+    } else {
+        CONFIG.page_size_or_pagination_default(page_size)
+    };
     let page_as_usize: usize = page_size
         .try_into()
         .expect("should be running on at least 32 bit architecture");
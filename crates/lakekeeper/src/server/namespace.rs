@@ -7,10 +7,13 @@ use iceberg_ext::configs::{ConfigProperty as _, namespace::NamespaceProperties};
 use itertools::Itertools;
 use lakekeeper_io::Location;
 
-mod create;
-mod list;
+pub(crate) mod create;
+pub(crate) mod list;
 
-use super::{CatalogServer, UnfilteredPage, require_warehouse_id};
+use super::{
+    CatalogServer, UnfilteredPage, require_warehouse_id,
+    tabular::{namespace_storage_profile_slot, parse_storage_profile_slot},
+};
 use crate::{
     CONFIG,
     api::{
@@ -27,8 +30,8 @@ use crate::{
     server,
     service::{
         CachePolicy, CatalogIdempotencyOps, CatalogNamespaceOps, CatalogStore, CatalogTaskOps,
-        NamedEntity, NamespaceHierarchy, NamespaceId, ResolvedWarehouse, State, TabularId,
-        Transaction,
+        CatalogWarehouseOps, NamedEntity, NamespaceHierarchy, NamespaceId, ResolvedWarehouse,
+        State, StorageProfileSlot, TabularId, Transaction, WarehouseQuotaEntity,
         authz::{
             Authorizer, AuthzNamespaceOps, CatalogNamespaceAction, CatalogWarehouseAction,
             NamespaceParent,
@@ -41,7 +44,10 @@ use crate::{
         },
         idempotency::IdempotencyInfo,
         secrets::SecretStore,
-        storage::storage_layout::{NamespaceNameContext, NamespacePath},
+        storage::{
+            StorageProfile,
+            storage_layout::{NamespaceNameContext, NamespacePath},
+        },
         tasks::{
             CancelTasksFilter, ScheduleTaskMetadata, TaskEntity, WarehouseTaskEntityId,
             tabular_purge_queue::{TabularPurgePayload, TabularPurgeTask},
@@ -212,24 +218,7 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
             namespace,
             properties,
         } = &request;
-        validate_namespace_ident_creation(namespace)?;
-        properties
-            .as_ref()
-            .map(|p| validate_namespace_properties_keys(p.keys()))
-            .transpose()?;
-
-        if CONFIG
-            .reserved_namespaces
-            .contains(&namespace.as_ref()[0].to_lowercase())
-        {
-            tracing::debug!("Denying reserved namespace: '{}'", &namespace.as_ref()[0]);
-            return Err(ErrorModel::bad_request(
-                "Namespace is reserved for internal use.",
-                "ReservedNamespace",
-                None,
-            )
-            .into());
-        }
+        validate_new_namespace(namespace, properties.as_ref())?;
 
         // ------------------- IDEMPOTENCY CHECK -------------------
         let idempotency_key = request_metadata.idempotency_key().copied();
@@ -303,22 +292,25 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
 
         // ------------------- BUSINESS LOGIC -------------------
         let namespace_id = NamespaceId::new_random();
-
-        let mut namespace_props = NamespaceProperties::try_from_maybe_props(properties.clone())
-            .map_err(|e| ErrorModel::bad_request(e.to_string(), e.err_type(), None))?;
-        set_namespace_location_property(
-            &mut namespace_props,
+        let properties = new_namespace_properties(
             &warehouse,
             namespace,
             namespace_id,
+            properties.clone(),
             parent_namespace.as_ref(),
         )?;
-        remove_managed_namespace_properties(&mut namespace_props);
 
         let mut request = request;
-        request.properties = Some(namespace_props.into());
+        request.properties = Some(properties);
 
         let mut t = C::Transaction::begin_write(state.v1_state.catalog).await?;
+        C::ensure_warehouse_quota(
+            &warehouse,
+            WarehouseQuotaEntity::Namespace,
+            1,
+            t.transaction(),
+        )
+        .await?;
         let r = C::create_namespace(warehouse_id, namespace_id, request, t.transaction()).await?;
         // Insert idempotency key in the same transaction — atomic with the mutation.
         if let Some(ref key) = idempotency_key
@@ -606,6 +598,15 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
             .transpose()?;
 
         namespace_location_may_not_change(updates.as_ref(), removals.as_ref())?;
+        namespace_storage_profile_may_not_change(updates.as_ref(), removals.as_ref())?;
+        namespace_properties_may_not_be_reserved(
+            updates.as_ref(),
+            removals.as_ref(),
+            CONFIG
+                .reserved_namespace_properties
+                .as_deref()
+                .unwrap_or_default(),
+        )?;
         let mut updates = NamespaceProperties::try_from_maybe_props(updates.clone())
             .map_err(|e| ErrorModel::bad_request(e.to_string(), e.err_type(), None))?;
         remove_managed_namespace_properties(&mut updates);
@@ -933,6 +934,102 @@ pub(crate) fn validate_namespace_ident_creation(namespace: &NamespaceIdent) -> R
     Ok(())
 }
 
+/// Validate the full depth of a namespace (including its parents) against the
+/// configured `max_namespace_depth`. `None` disables the check.
+pub(crate) fn validate_namespace_depth(
+    namespace: &NamespaceIdent,
+    max_depth: Option<usize>,
+) -> Result<()> {
+    if let Some(max_depth) = max_depth
+        && namespace.len() > max_depth
+    {
+        return Err(ErrorModel::bad_request(
+            format!(
+                "Namespace depth of {} exceeds the configured maximum of {max_depth}",
+                namespace.len()
+            ),
+            "NamespaceTooDeep",
+            None,
+        )
+        .append_detail(format!("Namespace: {namespace:?}"))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Validate the identifier and properties of a namespace to be created.
+pub(crate) fn validate_new_namespace(
+    namespace: &NamespaceIdent,
+    properties: Option<&HashMap<String, String>>,
+) -> Result<()> {
+    if namespace.as_ref().is_empty() {
+        return Err(
+            ErrorModel::bad_request("Namespace must not be empty", "NamespaceEmpty", None).into(),
+        );
+    }
+    validate_namespace_ident_creation(namespace)?;
+    validate_namespace_depth(namespace, CONFIG.max_namespace_depth)?;
+    properties
+        .map(|p| validate_namespace_properties_keys(p.keys()))
+        .transpose()?;
+
+    if CONFIG
+        .reserved_namespaces
+        .contains(&namespace.as_ref()[0].to_lowercase())
+    {
+        tracing::debug!("Denying reserved namespace: '{}'", &namespace.as_ref()[0]);
+        return Err(ErrorModel::bad_request(
+            "Namespace is reserved for internal use.",
+            "ReservedNamespace",
+            None,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Properties to store for a new namespace: the requested properties with the
+/// storage profile slot and location resolved and managed properties removed.
+pub(crate) fn new_namespace_properties(
+    warehouse: &ResolvedWarehouse,
+    namespace: &NamespaceIdent,
+    namespace_id: NamespaceId,
+    mut properties: Option<HashMap<String, String>>,
+    parent_namespace: Option<&NamespaceHierarchy>,
+) -> Result<HashMap<String, String>> {
+    // Namespaces inherit the storage profile slot of their parent. Non-default slots
+    // are recorded on the namespace, so that tables created in it inherit them as well.
+    let storage_profile_slot = if let Some(slot) = parse_storage_profile_slot(properties.as_ref())?
+    {
+        slot
+    } else {
+        parent_namespace
+            .map(|parent| namespace_storage_profile_slot(&parent.namespace))
+            .unwrap_or_default()
+    };
+    let (storage_profile, _) = warehouse.storage(storage_profile_slot)?;
+    if storage_profile_slot != StorageProfileSlot::default() {
+        properties.get_or_insert_default().insert(
+            StorageProfileSlot::PROPERTY.to_string(),
+            storage_profile_slot.to_string(),
+        );
+    }
+
+    let mut namespace_props = NamespaceProperties::try_from_maybe_props(properties)
+        .map_err(|e| ErrorModel::bad_request(e.to_string(), e.err_type(), None))?;
+    set_namespace_location_property(
+        &mut namespace_props,
+        storage_profile,
+        namespace,
+        namespace_id,
+        parent_namespace,
+    )?;
+    remove_managed_namespace_properties(&mut namespace_props);
+    Ok(namespace_props.into())
+}
+
 fn remove_managed_namespace_properties(namespace_props: &mut NamespaceProperties) {
     namespace_props.remove_untyped(NAMESPACE_ID_PROPERTY);
     namespace_props.remove_untyped(MANAGED_ACCESS_PROPERTY);
@@ -940,7 +1037,7 @@ fn remove_managed_namespace_properties(namespace_props: &mut NamespaceProperties
 
 fn set_namespace_location_property(
     namespace_props: &mut NamespaceProperties,
-    warehouse: &ResolvedWarehouse,
+    storage_profile: &StorageProfile,
     namespace_ident: &NamespaceIdent,
     namespace_id: NamespaceId,
     namespace_parents: Option<&NamespaceHierarchy>,
@@ -953,9 +1050,7 @@ fn set_namespace_location_property(
     // For customer specified location, we need to check if we can write to the location.
     // If no location is specified, we use our default location.
     let location = if let Some(location) = location {
-        warehouse
-            .storage_profile
-            .require_allowed_location(&location)?;
+        storage_profile.require_allowed_location(&location)?;
         location
     } else {
         let namespace_name = namespace_ident.last().ok_or_else(|| {
@@ -977,9 +1072,7 @@ fn set_namespace_location_property(
         };
         namespace_name_contexts.push(namespace_name_context);
         let namespace_path = NamespacePath::new(namespace_name_contexts);
-        warehouse
-            .storage_profile
-            .default_namespace_location(&namespace_path)?
+        storage_profile.default_namespace_location(&namespace_path)?
     };
 
     namespace_props.insert(&location);
@@ -1060,8 +1153,50 @@ fn namespace_location_may_not_change(
     Ok(())
 }
 
+fn namespace_storage_profile_may_not_change(
+    updates: Option<&HashMap<String, String>>,
+    removals: Option<&Vec<String>>,
+) -> Result<()> {
+    let key = StorageProfileSlot::PROPERTY;
+    if removals.is_some_and(|r| r.iter().any(|k| k == key))
+        || updates.is_some_and(|u| u.contains_key(key))
+    {
+        return Err(ErrorModel::bad_request(
+            format!("Namespace property `{key}` cannot be changed after creation."),
+            "StorageProfileCannotBeChanged",
+            None,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn namespace_properties_may_not_be_reserved(
+    updates: Option<&HashMap<String, String>>,
+    removals: Option<&Vec<String>>,
+    reserved: &[String],
+) -> Result<()> {
+    let reserved_key = updates
+        .into_iter()
+        .flat_map(HashMap::keys)
+        .chain(removals.into_iter().flatten())
+        .find(|key| reserved.iter().any(|r| r.eq_ignore_ascii_case(key)));
+
+    if let Some(key) = reserved_key {
+        return Err(ErrorModel::bad_request(
+            format!("Namespace property `{key}` is reserved and cannot be changed."),
+            "NamespacePropertyReserved",
+            None,
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
 /// Helper function to create event context for either namespace or warehouse actions
-fn create_namespace_or_warehouse_event_context(
+pub(crate) fn create_namespace_or_warehouse_event_context(
     namespace: Option<NamespaceIdent>,
     request_metadata: RequestMetadata,
     events: EventDispatcher,
@@ -1125,6 +1260,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_namespace_depth_at_limit_is_allowed() {
+        let namespace = NamespaceIdent::from_vec(vec!["a".to_string(), "b".to_string()]).unwrap();
+        validate_namespace_depth(&namespace, Some(2)).unwrap();
+    }
+
+    #[test]
+    fn test_namespace_depth_above_limit_is_rejected() {
+        let namespace =
+            NamespaceIdent::from_vec(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+                .unwrap();
+        let err = validate_namespace_depth(&namespace, Some(2)).unwrap_err();
+        assert_eq!(err.error.code, StatusCode::BAD_REQUEST.as_u16());
+        assert_eq!(err.error.r#type, "NamespaceTooDeep");
+    }
+
+    #[test]
+    fn test_namespace_depth_unlimited() {
+        let namespace =
+            NamespaceIdent::from_vec(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+                .unwrap();
+        validate_namespace_depth(&namespace, None).unwrap();
+    }
+
+    #[test]
+    fn test_reserved_namespace_property_is_rejected() {
+        let reserved = vec!["owner".to_string()];
+
+        let updates = HashMap::from_iter(vec![("comment".to_string(), "value".to_string())]);
+        namespace_properties_may_not_be_reserved(Some(&updates), None, &reserved).unwrap();
+
+        let updates = HashMap::from_iter(vec![("owner".to_string(), "alice".to_string())]);
+        let err =
+            namespace_properties_may_not_be_reserved(Some(&updates), None, &reserved).unwrap_err();
+        assert_eq!(err.error.code, StatusCode::BAD_REQUEST.as_u16());
+        assert_eq!(err.error.r#type, "NamespacePropertyReserved");
+
+        let removals = vec!["owner".to_string()];
+        let err =
+            namespace_properties_may_not_be_reserved(None, Some(&removals), &reserved).unwrap_err();
+        assert_eq!(err.error.r#type, "NamespacePropertyReserved");
+
+        // Without reserved keys, every property may be changed
+        namespace_properties_may_not_be_reserved(Some(&updates), Some(&removals), &[]).unwrap();
+    }
+
     #[test]
     fn test_update_ns_properties_empty_removal() {
         let previous_properties = HashMap::from_iter(vec![]);
@@ -15,7 +15,7 @@ use crate::{
     },
 };
 
-pub(super) async fn authorize_namespace_create<C: CatalogStore, A: Authorizer>(
+pub(crate) async fn authorize_namespace_create<C: CatalogStore, A: Authorizer>(
     authorizer: &A,
     request_metadata: &RequestMetadata,
     warehouse_id: WarehouseId,
@@ -16,7 +16,7 @@ use crate::{
     },
 };
 
-pub(super) async fn authorize_namespace_list<C: CatalogStore, A: Authorizer>(
+pub(crate) async fn authorize_namespace_list<C: CatalogStore, A: Authorizer>(
     authorizer: A,
     request_metadata: &RequestMetadata,
     warehouse_id: WarehouseId,
@@ -18,8 +18,8 @@ use crate::{
     server::require_warehouse_id,
     service::{
         AuthZTableInfo, CatalogNamespaceOps, CatalogStore, CatalogTabularOps, CatalogWarehouseOps,
-        GetTabularInfoByLocationError, ResolvedWarehouse, State, TableId, TableInfo,
-        TabularListFlags,
+        GetTabularInfoByLocationError, ResolvedWarehouse, State, StorageProfileSlot, TableId,
+        TableInfo, TabularListFlags,
         authz::{
             AuthZCannotSeeTableLocation, AuthZError, AuthZTableOps, Authorizer, AuthzNamespaceOps,
             AuthzWarehouseOps, CatalogTableAction, CatalogWarehouseAction, RequireTableActionError,
@@ -86,19 +86,13 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
             // Too noisy otherwise
             .map_err(authz_to_error_no_audit)?;
 
-        // Check if remote signing is enabled for this storage profile
-        if let StorageProfile::S3(s3_profile) = &warehouse.storage_profile {
-            if !s3_profile.remote_signing_enabled {
-                return Err(IcebergErrorResponse::from(ErrorModel::forbidden(
-                    "Remote signing is disabled for this storage profile",
-                    "RemoteSigningDisabled",
-                    None,
-                )));
-            }
-        } else {
-            return Err(IcebergErrorResponse::from(ErrorModel::bad_request(
-                "Remote signing is only supported for S3 storage",
-                "UnsupportedStorageType",
+        if !warehouse.settings.credential_mode.allows_remote_signing() {
+            return Err(IcebergErrorResponse::from(ErrorModel::forbidden(
+                format!(
+                    "Remote signing is disabled for this warehouse. Its credential mode is '{}'.",
+                    warehouse.settings.credential_mode
+                ),
+                "RemoteSigningDisabled",
                 None,
             )));
         }
@@ -112,13 +106,30 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         } = request.clone();
 
         let decoded_url = urldecode_uri_path_segments(&request_url)?;
-        let (parsed_url, operation) = s3_utils::parse_s3_url(
+        let (storage_slot, parsed_url, operation) = parse_request_url(
+            &warehouse,
             &decoded_url,
-            s3_url_style_detection(&warehouse)?,
             &request_method,
             request_body.as_deref(),
         )?;
 
+        // Check if remote signing is enabled for the storage profile of the request
+        let (storage_profile, storage_secret_id) = warehouse.storage(storage_slot)?;
+        let storage_profile = storage_profile.clone().try_into_s3().map_err(|_| {
+            IcebergErrorResponse::from(ErrorModel::bad_request(
+                "Remote signing is only supported for S3 storage",
+                "UnsupportedStorageType",
+                None,
+            ))
+        })?;
+        if !storage_profile.remote_signing_enabled {
+            return Err(IcebergErrorResponse::from(ErrorModel::forbidden(
+                "Remote signing is disabled for this storage profile",
+                "RemoteSigningDisabled",
+                None,
+            )));
+        }
+
         let first_location = parsed_url.locations.first().ok_or_else(|| {
             ErrorModel::internal(
                 "Request URI does not contain a location",
@@ -220,17 +231,11 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
             e
         };
 
-        let storage_profile = warehouse
-            .storage_profile
-            .clone()
-            .try_into_s3()
-            .map_err(|e| extend_err(IcebergErrorResponse::from(e)))?;
-
         validate_region(&request_region, &storage_profile).map_err(extend_err)?;
         validate_uri(&parsed_url, &location).map_err(extend_err)?;
 
         // If all is good, we need the storage secret
-        let storage_secret = if let Some(storage_secret_id) = warehouse.storage_secret_id {
+        let storage_secret = if let Some(storage_secret_id) = storage_secret_id {
             Some(
                 state
                     .v1_state
@@ -264,10 +269,41 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
     }
 }
 
-fn s3_url_style_detection(
+/// Parse the request URL with the URL style of the storage profile it points to.
+///
+/// Locations below the base location of the secondary storage profile belong to it,
+/// all other requests are parsed with the primary storage profile.
+fn parse_request_url(
     warehouse: &ResolvedWarehouse,
+    uri: &url::Url,
+    method: &http::Method,
+    body: Option<&str>,
+) -> Result<(StorageProfileSlot, s3_utils::ParsedSignRequest, Operation)> {
+    if let Some(secondary) = &warehouse.secondary_storage
+        && let StorageProfile::S3(s3_profile) = &secondary.storage_profile
+        && let Ok((parsed_url, operation)) =
+            s3_utils::parse_s3_url(uri, s3_profile.remote_signing_url_style, method, body)
+        && parsed_url.locations.first().is_some_and(|location| {
+            secondary
+                .storage_profile
+                .is_allowed_location(location.location())
+        })
+    {
+        return Ok((StorageProfileSlot::Secondary, parsed_url, operation));
+    }
+
+    let (parsed_url, operation) = s3_utils::parse_s3_url(
+        uri,
+        s3_url_style_detection(&warehouse.storage_profile)?,
+        method,
+        body,
+    )?;
+    Ok((StorageProfileSlot::Primary, parsed_url, operation))
+}
+
+fn s3_url_style_detection(
+    storage_profile: &StorageProfile,
 ) -> Result<S3UrlStyleDetectionMode, IcebergErrorResponse> {
-    let storage_profile = &warehouse.storage_profile;
     if let StorageProfile::S3(s3_profile) = storage_profile {
         return Ok(s3_profile.remote_signing_url_style);
     }
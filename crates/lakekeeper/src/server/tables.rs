@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet, hash_map::Entry},
     str::FromStr as _,
     sync::Arc,
 };
@@ -14,7 +14,9 @@ use iceberg::{
     },
 };
 use iceberg_ext::{
-    catalog::rest::{IcebergErrorResponse, LoadCredentialsResponse, StorageCredential},
+    catalog::rest::{
+        ETag, IcebergErrorResponse, LoadCredentialsResponse, StorageCredential, TableETag,
+    },
     configs::ParseFromStr,
 };
 use itertools::Itertools;
@@ -22,15 +24,23 @@ use lakekeeper_io::Location;
 use serde::Serialize;
 use uuid::Uuid;
 pub mod authorize_load;
+pub(crate) mod compact_metadata;
 pub mod create_table;
 pub mod load_table;
 mod rename_table;
+mod scan_planning;
+pub(crate) mod storage_usage;
 
 pub(crate) use authorize_load::*;
 
 use super::{
     CatalogServer,
-    commit_tables::{apply_commit, ensure_format_version_upgrades_allowed},
+    commit_tables::{
+        apply_commit, ensure_file_format_updates_allowed, ensure_format_version_upgrades_allowed,
+        ensure_metadata_location_in_table_location, ensure_partition_specs_allowed,
+        ensure_partition_statistics_files_exist, ensure_property_updates_within_limits,
+        ensure_schema_evolution_allowed, prune_metadata_log, snapshots_exceeding_limit,
+    },
     io::{delete_file, read_metadata_file, write_file},
     maybe_get_secret,
     namespace::validate_namespace_ident,
@@ -64,9 +74,10 @@ use crate::{
     service::{
         AuthZTableInfo, CONCURRENT_UPDATE_ERROR_TYPE, CachePolicy, CatalogIdempotencyOps,
         CatalogNamespaceOps, CatalogStore, CatalogTableOps, CatalogTabularOps, CatalogWarehouseOps,
-        NamedEntity, ResolvedWarehouse, State, TableCommit, TableCreation, TableId, TableIdentOrId,
-        TableInfo, TabularId, TabularIdentBorrowed, TabularInfo, TabularListFlags, TabularNotFound,
-        Transaction, WarehouseStatus,
+        CommitTableTransactionError, IfMatchPreconditionFailed, NamedEntity, ResolvedWarehouse,
+        State, StorageProfileSlot, TableCommit, TableCreation, TableId, TableIdentOrId, TableInfo,
+        TabularId, TabularIdentBorrowed, TabularInfo, TabularListFlags, TabularNotFound,
+        Transaction, WarehouseQuotaEntity, WarehouseStatus,
         authz::{
             ActionOnTableOrView, AuthZCannotSeeNamespace, AuthZCannotSeeTable, AuthZCannotSeeView,
             AuthZError, AuthZTableActionForbidden, AuthZTableOps, AuthorizationCountMismatch,
@@ -77,7 +88,7 @@ use crate::{
         build_namespace_hierarchy,
         contract_verification::{ContractVerification, ContractVerificationOutcome},
         events::{
-            APIEventCommitContext, APIEventContext, CommitTransactionEvent,
+            APIEventContext, CommitTransactionEvent,
             context::{ResolvedNamespace, ResolvedTable},
         },
         idempotency::{IdempotencyCheck, IdempotencyInfo},
@@ -334,13 +345,14 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
             .map_err(|e| event_ctx.emit_early_authz_failure(e))?;
 
         // ------------------- BUSINESS LOGIC -------------------
-        let storage_profile = &warehouse.storage_profile;
+        // Registered tables are stored on the storage profile their metadata lives on.
+        let storage_profile_slot = warehouse.storage_slot_of_location(&metadata_location);
+        let (storage_profile, storage_secret_id) = warehouse.storage(storage_profile_slot)?;
 
         require_active_warehouse(warehouse.status)?;
         storage_profile.require_allowed_location(&metadata_location)?;
 
-        let storage_secret =
-            maybe_get_secret(warehouse.storage_secret_id, &state.v1_state.secrets).await?;
+        let storage_secret = maybe_get_secret(storage_secret_id, &state.v1_state.secrets).await?;
         let storage_secret_ref = storage_secret.as_deref();
         let file_io = storage_profile.file_io(storage_secret_ref).await?;
         let table_metadata = read_metadata_file(&file_io, &metadata_location).await?;
@@ -446,6 +458,13 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
 
         let tabular_id = TableId::from(table_metadata.uuid());
 
+        C::ensure_warehouse_quota(
+            &warehouse,
+            WarehouseQuotaEntity::Table,
+            1,
+            t_write.transaction(),
+        )
+        .await?;
         let (table_info, staged_table_id) = C::create_table(
             TableCreation {
                 warehouse_id: warehouse.warehouse_id,
@@ -453,6 +472,7 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
                 table_ident: &table_ident,
                 table_metadata: &table_metadata,
                 metadata_location: Some(&metadata_location),
+                storage_profile_slot,
             },
             t_write.transaction(),
         )
@@ -461,6 +481,7 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         let config = storage_profile
             .generate_table_config(
                 DataAccess::not_specified().into(),
+                warehouse.settings.credential_mode,
                 storage_secret_ref,
                 &table_location,
                 StoragePermissions::ReadWriteDelete,
@@ -613,13 +634,14 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         let warehouse = &event_ctx.resolved().warehouse;
         let tabular_info = &*event_ctx.resolved().table;
 
-        let storage_secret =
-            maybe_get_secret(warehouse.storage_secret_id, &state.v1_state.secrets).await?;
+        let (storage_profile, storage_secret_id) =
+            warehouse.storage(warehouse.storage_slot_of_location(&tabular_info.location))?;
+        let storage_secret = maybe_get_secret(storage_secret_id, &state.v1_state.secrets).await?;
         let storage_secret_ref = storage_secret.as_deref();
-        let storage_config = warehouse
-            .storage_profile
+        let storage_config = storage_profile
             .generate_table_config(
                 data_access.into(),
+                warehouse.settings.credential_mode,
                 storage_secret_ref,
                 &parse_location(
                     tabular_info.location.as_str(),
@@ -647,9 +669,15 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
 
     /// Commit updates to a table
     #[allow(clippy::too_many_lines)]
+    #[tracing::instrument(
+        name = "commit_table",
+        skip_all,
+        fields(table = ?parameters.table, request_id = %request_metadata.request_id())
+    )]
     async fn commit_table(
         parameters: TableParameters,
         mut request: CommitTableRequest,
+        if_match: Vec<ETag>,
         state: ApiContext<State<A, C, S>>,
         request_metadata: RequestMetadata,
     ) -> Result<CommitTableResponse> {
@@ -666,11 +694,12 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
             endpoint: EndpointFlat::CatalogV1UpdateTable,
             http_status: StatusCode::OK,
         });
-        let result = commit_tables_with_authz(
+        let result = commit_tables_with_authz_if_match(
             parameters.prefix.clone(),
             CommitTransactionRequest {
                 table_changes: vec![request],
             },
+            &if_match,
             state.clone(),
             request_metadata.clone(),
             idempotency.as_ref(),
@@ -769,6 +798,7 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         let (event_ctx, (warehouse, _ns, table_info)) = event_ctx.emit_authz(authz_result)?;
 
         let table_id = table_info.table_id();
+        let namespace_id = table_info.namespace_id;
         let event_ctx = event_ctx.resolve(ResolvedTable {
             warehouse: warehouse.clone(),
             table: Arc::new(table_info),
@@ -788,7 +818,9 @@ impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         let delete_profile = if force {
             TabularDeleteProfile::Hard {}
         } else {
-            warehouse.tabular_delete_profile
+            C::get_namespace_delete_profile(warehouse_id, namespace_id, t.transaction())
+                .await?
+                .unwrap_or(warehouse.tabular_delete_profile)
         };
         let project_id = &warehouse.project_id;
 
@@ -1230,32 +1262,41 @@ fn commit_tables_validate(request: &CommitTransactionRequest) -> Result<()> {
     Ok(())
 }
 
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 /// Commit updates to multiple tables without authorization checks
 ///
+/// `table_infos` contains the resolved table of every identifier in `request`.
+/// If `max_metadata_log_entries` is set, the metadata log of every table is
+/// pruned to at most this many entries in the same commit.
+///
 /// # Errors
 /// Returns an error if the commit fails or if a DB error occurs.
 /// This function will retry on concurrent update errors up to a maximum number of retries.
 async fn commit_tables_inner<C: CatalogStore, A: Authorizer, S: SecretStore>(
     warehouse: Arc<ResolvedWarehouse>,
     request: CommitTransactionRequest,
-    event_ctx: APIEventCommitContext,
+    if_match: &[ETag],
+    table_infos: &HashMap<TableIdent, Arc<TableInfo>>,
+    request_metadata: Arc<RequestMetadata>,
     state: ApiContext<State<A, C, S>>,
     idempotency: Option<&IdempotencyInfo>,
+    max_metadata_log_entries: Option<usize>,
 ) -> Result<Arc<Vec<CommitContext>>> {
     let include_deleted = false;
-    let warehouse_id = event_ctx.user_provided_entity().warehouse_id;
+    let warehouse_id = warehouse.warehouse_id;
 
     // Start the retry loop
     let mut attempt = 0;
     loop {
         let result = try_commit_tables::<C, A, S>(
             &request,
+            if_match,
             &warehouse,
-            &event_ctx,
+            table_infos,
             &state,
             include_deleted,
             idempotency,
+            max_metadata_log_entries,
         )
         .await;
 
@@ -1268,7 +1309,7 @@ async fn commit_tables_inner<C: CatalogStore, A: Authorizer, S: SecretStore>(
                         warehouse_id,
                         request: Arc::new(request),
                         commits: commits.clone(),
-                        request_metadata: event_ctx.request_metadata_arc(),
+                        request_metadata,
                     })
                     .await;
                 return Ok(commits);
@@ -1280,7 +1321,7 @@ async fn commit_tables_inner<C: CatalogStore, A: Authorizer, S: SecretStore>(
                 attempt += 1;
                 tracing::info!(
                     warehouse_id = %warehouse_id,
-                    n_tables = %event_ctx.user_provided_entity().tables.len(),
+                    n_tables = %table_infos.len(),
                     attempt = attempt,
                     max_attempts = MAX_RETRIES_ON_CONCURRENT_UPDATE,
                     "Concurrent update detected, retrying commit operation"
@@ -1297,7 +1338,7 @@ async fn commit_tables_inner<C: CatalogStore, A: Authorizer, S: SecretStore>(
                 if attempt > 0 {
                     tracing::warn!(
                         warehouse_id = %warehouse_id,
-                        n_tables = %event_ctx.user_provided_entity().tables.len(),
+                        n_tables = %table_infos.len(),
                         attempt = attempt,
                         "Table commit operation failed after {} attempts. Operation was retried due to concurrent updates. {e}",
                         attempt + 1
@@ -1322,6 +1363,25 @@ pub async fn commit_tables_with_authz<C: CatalogStore, A: Authorizer + Clone, S:
     state: ApiContext<State<A, C, S>>,
     request_metadata: RequestMetadata,
     idempotency: Option<&IdempotencyInfo>,
+) -> Result<CommitTablesResult> {
+    commit_tables_with_authz_if_match(prefix, request, &[], state, request_metadata, idempotency)
+        .await
+}
+
+/// Like [`commit_tables_with_authz`], but only commits if the current metadata of
+/// every table in `request` matches one of the `If-Match` values in `if_match`.
+/// An empty `if_match` commits unconditionally.
+async fn commit_tables_with_authz_if_match<
+    C: CatalogStore,
+    A: Authorizer + Clone,
+    S: SecretStore,
+>(
+    prefix: Option<Prefix>,
+    request: CommitTransactionRequest,
+    if_match: &[ETag],
+    state: ApiContext<State<A, C, S>>,
+    request_metadata: RequestMetadata,
+    idempotency: Option<&IdempotencyInfo>,
 ) -> Result<CommitTablesResult> {
     // ------------------- VALIDATIONS -------------------
     let warehouse_id = require_warehouse_id(prefix.as_ref())?;
@@ -1392,8 +1452,17 @@ pub async fn commit_tables_with_authz<C: CatalogStore, A: Authorizer + Clone, S:
     let event_ctx = event_ctx.resolve(table_infos);
 
     // ------------------- BUSINESS LOGIC -------------------
-    let commits =
-        commit_tables_inner::<C, _, _>(warehouse, request, event_ctx, state, idempotency).await?;
+    let commits = commit_tables_inner::<C, _, _>(
+        warehouse,
+        request,
+        if_match,
+        event_ctx.resolved(),
+        event_ctx.request_metadata_arc(),
+        state,
+        idempotency,
+        None,
+    )
+    .await?;
     Ok(CommitTablesResult::Committed(commits))
 }
 
@@ -1520,14 +1589,16 @@ async fn commit_tables_authz<'a, A: Authorizer + Clone, C: CatalogStore>(
 }
 
 // Extract the core commit logic to a separate function for retry purposes
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     request: &CommitTransactionRequest,
+    if_match: &[ETag],
     warehouse: &ResolvedWarehouse,
-    event_ctx: &APIEventCommitContext,
+    table_infos: &HashMap<TableIdent, Arc<TableInfo>>,
     state: &ApiContext<State<A, C, S>>,
     include_deleted: bool,
     idempotency: Option<&IdempotencyInfo>,
+    max_metadata_log_entries: Option<usize>,
 ) -> Result<Arc<Vec<CommitContext>>> {
     let warehouse_id = warehouse.warehouse_id;
     let mut transaction = C::Transaction::begin_write(state.v1_state.catalog.clone()).await?;
@@ -1535,7 +1606,7 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
     // Load old metadata
     let previous_metadatas = C::load_tables(
         warehouse_id,
-        event_ctx.resolved().values().map(|ti| ti.table_id()),
+        table_infos.values().map(|ti| ti.table_id()),
         include_deleted,
         &LoadTableFilters::default(),
         transaction.transaction(),
@@ -1548,7 +1619,7 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
 
     transaction.commit().await?;
 
-    let mut expired_metadata_logs: Vec<MetadataLog> = vec![];
+    let mut expired_metadata_logs: Vec<(StorageProfileSlot, MetadataLog)> = vec![];
 
     // Apply changes
     let commits = request
@@ -1562,8 +1633,7 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
                         "ChangeWithoutIdentifier",
                         None,
                     ))?;
-            let table_info = event_ctx
-                .resolved()
+            let table_info = table_infos
                 .get(table_ident)
                 .ok_or_else(|| {
                     ErrorModel::internal(
@@ -1579,25 +1649,76 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
                     TabularNotFound::new(warehouse_id, TableIdentOrId::from(table_ident.clone()))
                         .append_detail("Table metadata not returned from table load".to_string())
                 })?;
+            if !if_match_satisfied(if_match, previous_table_metadata.metadata_location.as_ref()) {
+                return Err(IfMatchPreconditionFailed::new(
+                    warehouse_id,
+                    TableIdentOrId::from(table_ident.clone()),
+                )
+                .into());
+            }
             ensure_format_version_upgrades_allowed(
                 &change.updates,
                 &warehouse.allowed_format_versions,
             )?;
+            ensure_partition_specs_allowed(
+                &change.updates,
+                warehouse.settings.partition_transform_policy.as_ref(),
+            )?;
+            ensure_file_format_updates_allowed(
+                &change.updates,
+                warehouse.settings.file_format_policy.as_ref(),
+            )?;
+            let mut updates = change.updates.clone();
+            let mut build_result = apply_commit(
+                previous_table_metadata.table_metadata.clone(),
+                previous_table_metadata.metadata_location.as_ref(),
+                &change.requirements,
+                updates.clone(),
+            )?;
+            // Expire the oldest snapshots in the same commit if it exceeds the warehouse limit.
+            if let Some(max_snapshots) = warehouse.settings.max_snapshots_per_table {
+                let snapshot_ids = snapshots_exceeding_limit(&build_result.metadata, max_snapshots);
+                if !snapshot_ids.is_empty() {
+                    updates.push(TableUpdate::RemoveSnapshots { snapshot_ids });
+                    build_result = apply_commit(
+                        previous_table_metadata.table_metadata.clone(),
+                        previous_table_metadata.metadata_location.as_ref(),
+                        &change.requirements,
+                        updates.clone(),
+                    )?;
+                }
+            }
+            if let Some(max_entries) = max_metadata_log_entries {
+                build_result = prune_metadata_log(build_result, max_entries)?;
+            }
             let TableMetadataBuildResult {
                 metadata: new_metadata,
                 changes: _,
                 expired_metadata_logs: mut this_expired,
-            } = apply_commit(
-                previous_table_metadata.table_metadata.clone(),
-                previous_table_metadata.metadata_location.as_ref(),
-                &change.requirements,
-                change.updates.clone(),
+            } = build_result;
+            ensure_schema_evolution_allowed(
+                &previous_table_metadata.table_metadata,
+                &new_metadata,
+                warehouse.settings.schema_evolution_policy,
+            )?;
+            ensure_property_updates_within_limits(
+                &change.updates,
+                &previous_table_metadata.table_metadata,
+                &new_metadata,
+                warehouse.settings.max_table_properties,
+                warehouse.settings.max_table_properties_size_bytes,
             )?;
 
             let number_expired_metadata_log_entries = this_expired.len();
+            let storage_profile_slot = previous_table_metadata.storage_profile_slot;
+            let (storage_profile, _) = warehouse.storage(storage_profile_slot)?;
 
             if delete_after_commit_enabled(new_metadata.properties()) {
-                expired_metadata_logs.extend(this_expired);
+                expired_metadata_logs.extend(
+                    this_expired
+                        .drain(..)
+                        .map(|expired| (storage_profile_slot, expired)),
+                );
             } else {
                 this_expired.clear();
             }
@@ -1611,17 +1732,21 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
             let new_table_location =
                 parse_location(new_metadata.location(), StatusCode::INTERNAL_SERVER_ERROR)?;
             if new_metadata.location() != previous_table_metadata.table_metadata.location() {
-                warehouse
-                    .storage_profile
-                    .require_allowed_location(&new_table_location)?;
+                storage_profile.require_allowed_location(&new_table_location)?;
             }
             let new_compression_codec = CompressionCodec::try_from_metadata(&new_metadata)?;
-            let new_metadata_location = warehouse.storage_profile.default_metadata_location(
+            let new_metadata_location = storage_profile.default_metadata_location(
                 &new_table_location,
                 &new_compression_codec,
                 Uuid::now_v7(),
                 next_metadata_count,
             );
+            if warehouse.settings.require_metadata_in_table_location {
+                ensure_metadata_location_in_table_location(
+                    &new_table_location,
+                    &new_metadata_location,
+                )?;
+            }
 
             let number_added_metadata_log_entries = (new_metadata.metadata_log().len()
                 + number_expired_metadata_log_entries)
@@ -1632,8 +1757,9 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
                 new_metadata_location,
                 table_info,
                 new_compression_codec,
+                storage_profile_slot,
                 previous_metadata_location: previous_table_metadata.metadata_location,
-                updates: Arc::new(change.updates.clone()),
+                updates: Arc::new(updates),
                 previous_metadata: Arc::new(previous_table_metadata.table_metadata),
                 number_expired_metadata_log_entries,
                 number_added_metadata_log_entries,
@@ -1656,21 +1782,34 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
         .collect::<Result<Vec<()>, ErrorModel>>()?;
 
     // We don't commit the transaction yet, first we need to write the metadata file.
-    let storage_secret =
-        maybe_get_secret(warehouse.storage_secret_id, &state.v1_state.secrets).await?;
-    let storage_secret_ref = storage_secret.as_deref();
+    // Tables of a transaction may be stored on different storage profiles of the warehouse.
+    let mut file_ios = HashMap::new();
+    for slot in commits.iter().map(|c| c.storage_profile_slot) {
+        if let Entry::Vacant(entry) = file_ios.entry(slot) {
+            let (storage_profile, storage_secret_id) = warehouse.storage(slot)?;
+            let storage_secret =
+                maybe_get_secret(storage_secret_id, &state.v1_state.secrets).await?;
+            entry.insert(storage_profile.file_io(storage_secret.as_deref()).await?);
+        }
+    }
+    let file_ios = &file_ios;
 
-    // Write metadata files
-    let file_io = warehouse
-        .storage_profile
-        .file_io(storage_secret_ref)
+    if warehouse.settings.validate_partition_statistics_files {
+        futures::future::try_join_all(commits.iter().map(|commit| {
+            ensure_partition_statistics_files_exist(
+                &file_ios[&commit.storage_profile_slot],
+                &commit.updates,
+            )
+        }))
         .await?;
+    }
 
+    // Write metadata files
     let write_futures: Vec<_> = commits
         .iter()
         .map(|commit| {
             write_file(
-                &file_io,
+                &file_ios[&commit.storage_profile_slot],
                 &commit.new_metadata_location,
                 &commit.new_metadata,
                 commit.new_compression_codec,
@@ -1682,12 +1821,20 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
     // Make changes in DB
     let transaction_result = async {
         let mut transaction = C::Transaction::begin_write(state.v1_state.catalog.clone()).await?;
-        C::commit_table_transaction(
+        let commit_result = C::commit_table_transaction(
             warehouse_id,
             commits.iter().map(CommitContext::commit),
             transaction.transaction(),
         )
-        .await?;
+        .await;
+        // The table changed after the precondition was checked. Retrying would
+        // rebase the commit onto metadata the client has not seen.
+        if !if_match.is_empty()
+            && let Err(CommitTableTransactionError::ConcurrentUpdateError(e)) = commit_result
+        {
+            return Err(IfMatchPreconditionFailed::from(e).into());
+        }
+        commit_result?;
 
         // Insert idempotency key in the same transaction.
         if let Some(info) = idempotency
@@ -1714,7 +1861,12 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
         let delete_result = futures::future::join_all(
             commits
                 .iter()
-                .map(|commit| delete_file(&file_io, &commit.new_metadata_location))
+                .map(|commit| {
+                    delete_file(
+                        &file_ios[&commit.storage_profile_slot],
+                        &commit.new_metadata_location,
+                    )
+                })
                 .collect::<Vec<_>>(),
         )
         .await;
@@ -1730,7 +1882,7 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
     // Delete files in parallel - if one delete fails, we still want to delete the rest
     let expired_locations = expired_metadata_logs
         .into_iter()
-        .filter_map(|expired_metadata_log| {
+        .filter_map(|(slot, expired_metadata_log)| {
             Location::parse_value(&expired_metadata_log.metadata_file)
                 .map_err(|e| {
                     tracing::warn!(
@@ -1740,12 +1892,13 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
                     );
                 })
                 .ok()
+                .map(|location| (slot, location))
         })
         .collect::<Vec<_>>();
     let _ = futures::future::join_all(
         expired_locations
             .iter()
-            .map(|location| delete_file(&file_io, location))
+            .map(|(slot, location)| delete_file(&file_ios[slot], location))
             .collect::<Vec<_>>(),
     )
     .await
@@ -1758,6 +1911,27 @@ async fn try_commit_tables<C: CatalogStore, A: Authorizer + Clone, S: SecretStor
     Ok(Arc::new(commits))
 }
 
+/// Whether the current `metadata_location` of a table matches one of the values
+/// of an `If-Match` header. A value matches if it is `*`, the metadata location
+/// itself, or the `ETag` returned by `loadTable` for this metadata location.
+/// An empty `if_match` always matches.
+fn if_match_satisfied(if_match: &[ETag], metadata_location: Option<&Location>) -> bool {
+    if if_match.is_empty() {
+        return true;
+    }
+    let metadata_location = metadata_location.map(Location::as_str);
+    let current = metadata_location.map(|l| TableETag::new(l, None));
+
+    if_match.iter().any(|value| {
+        let value = value.as_str();
+        value == "*"
+            || Some(value) == metadata_location
+            || TableETag::parse(value)
+                .zip(current.as_ref())
+                .is_some_and(|(parsed, current)| parsed.metadata_hash() == current.metadata_hash())
+    })
+}
+
 pub fn extract_count_from_metadata_location(location: &Location) -> Option<usize> {
     let last_segment = location
         .as_str()
@@ -1784,6 +1958,7 @@ pub struct CommitContext {
     pub previous_metadata_location: Option<Location>,
     pub updates: Arc<Vec<TableUpdate>>,
     pub new_compression_codec: CompressionCodec,
+    pub storage_profile_slot: StorageProfileSlot,
     pub number_expired_metadata_log_entries: usize,
     pub number_added_metadata_log_entries: usize,
     pub table_info: Arc<TableInfo>,
@@ -1910,13 +2085,25 @@ pub fn calculate_diffs(
         .statistics_iter()
         .map(|s| s.snapshot_id)
         .collect::<XXHashSet<_>>();
+    // A `set-statistics` update for a snapshot that already has statistics
+    // replaces them, so it is persisted as a removal followed by an insertion.
+    let replaced_stats = old_stats
+        .intersection(&new_stats)
+        .copied()
+        .filter(|id| {
+            previous_metadata.statistics_for_snapshot(*id)
+                != new_metadata.statistics_for_snapshot(*id)
+        })
+        .collect::<Vec<_>>();
     let removed_stats = old_stats
         .difference(&new_stats)
         .copied()
+        .chain(replaced_stats.iter().copied())
         .collect::<Vec<_>>();
     let added_stats = new_stats
         .difference(&old_stats)
         .copied()
+        .chain(replaced_stats)
         .collect::<Vec<_>>();
 
     let old_partition_stats = previous_metadata
@@ -1927,13 +2114,23 @@ pub fn calculate_diffs(
         .partition_statistics_iter()
         .map(|s| s.snapshot_id)
         .collect::<XXHashSet<_>>();
+    let replaced_partition_stats = old_partition_stats
+        .intersection(&new_partition_stats)
+        .copied()
+        .filter(|id| {
+            previous_metadata.partition_statistics_for_snapshot(*id)
+                != new_metadata.partition_statistics_for_snapshot(*id)
+        })
+        .collect::<Vec<_>>();
     let removed_partition_stats = old_partition_stats
         .difference(&new_partition_stats)
         .copied()
+        .chain(replaced_partition_stats.iter().copied())
         .collect::<Vec<_>>();
     let added_partition_stats = new_partition_stats
         .difference(&old_partition_stats)
         .copied()
+        .chain(replaced_partition_stats)
         .collect::<Vec<_>>();
 
     let old_encryption_keys = previous_metadata
@@ -2311,6 +2508,31 @@ mod unit_tests {
         assert!(validate_table_properties(properties.iter()).is_ok());
     }
 
+    #[test]
+    fn test_if_match_satisfied() {
+        let current = "s3://bucket/table/metadata/00001-a.metadata.json";
+        let other = "s3://bucket/table/metadata/00000-b.metadata.json";
+        let location = Location::from_str(current).unwrap();
+        let satisfied = |values: &[&str]| {
+            let if_match = values.iter().map(|v| ETag::from(*v)).collect::<Vec<_>>();
+            if_match_satisfied(&if_match, Some(&location))
+        };
+
+        assert!(satisfied(&[]));
+        assert!(satisfied(&["*"]));
+        assert!(satisfied(&[current]));
+        assert!(satisfied(&[other, current]));
+        assert!(!satisfied(&[other]));
+
+        let etag = TableETag::new(current, None).into_etag();
+        assert!(satisfied(&[etag.as_str().trim_matches('"')]));
+        let stale_etag = TableETag::new(other, None).into_etag();
+        assert!(!satisfied(&[stale_etag.as_str().trim_matches('"')]));
+
+        // Staged tables have no metadata location to match against.
+        assert!(!if_match_satisfied(&[ETag::from(current)], None));
+    }
+
     #[test]
     fn test_extract_count_from_metadata_location() {
         let location = Location::from_str("s3://path/to/table/metadata/00000-d0407fb2-1112-4944-bb88-c68ae697e2b4.gz.metadata.json").unwrap();
@@ -0,0 +1,159 @@
+//! Rewrites the metadata of a single table so that its metadata log and
+//! snapshots stay within the retention limits of the metadata log compaction
+//! queue. The rewrite is committed through the regular table commit path as a
+//! metadata-log update, including its retries on concurrent updates and the
+//! commit event.
+
+use std::{collections::HashMap, sync::Arc};
+
+use iceberg::{TableRequirement, TableUpdate};
+use uuid::Uuid;
+
+use super::commit_tables_inner;
+use crate::{
+    api::{
+        ApiContext,
+        iceberg::v1::{
+            CommitTableRequest, CommitTransactionRequest, Result, tables::LoadTableFilters,
+        },
+    },
+    request_metadata::RequestMetadata,
+    service::{
+        CatalogStore, CatalogTableOps, CatalogTabularOps, ResolvedWarehouse, State, TableId,
+        TabularListFlags, Transaction,
+        authz::Authorizer,
+        secrets::SecretStore,
+        tasks::metadata_log_compaction_queue::{
+            MetadataLogCompactionQueueConfig, MetadataRetention,
+        },
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompactionOutcome {
+    /// The table was dropped since the task was scheduled.
+    TableNotFound,
+    /// Nothing exceeds the retention limits, no new metadata was written.
+    UpToDate,
+    Compacted {
+        removed_metadata_log_entries: usize,
+        removed_snapshots: usize,
+    },
+}
+
+/// Compact the metadata log of `table_id` and remove its expired snapshots.
+///
+/// Expired snapshots are only removed from the table metadata; their data and
+/// manifest files are left in place. Metadata files dropped from the log are
+/// deleted if `write.metadata.delete-after-commit.enabled` is set.
+pub(crate) async fn compact_table_metadata<
+    C: CatalogStore,
+    A: Authorizer + Clone,
+    S: SecretStore,
+>(
+    warehouse: Arc<ResolvedWarehouse>,
+    table_id: TableId,
+    config: Option<&MetadataLogCompactionQueueConfig>,
+    state: ApiContext<State<A, C, S>>,
+) -> Result<CompactionOutcome> {
+    let warehouse_id = warehouse.warehouse_id;
+    let catalog_state = state.v1_state.catalog.clone();
+
+    let Some(table_info) = C::get_table_info(
+        warehouse_id,
+        table_id,
+        TabularListFlags::active(),
+        catalog_state.clone(),
+    )
+    .await?
+    else {
+        return Ok(CompactionOutcome::TableNotFound);
+    };
+
+    let mut transaction = C::Transaction::begin_read(catalog_state).await?;
+    let previous = C::load_tables(
+        warehouse_id,
+        [table_id],
+        false,
+        &LoadTableFilters::default(),
+        transaction.transaction(),
+    )
+    .await?;
+    transaction.commit().await?;
+
+    let Some(previous) = previous.into_iter().next() else {
+        return Ok(CompactionOutcome::TableNotFound);
+    };
+    // Staged tables have no metadata file and therefore no metadata log.
+    if previous.metadata_location.is_none() {
+        return Ok(CompactionOutcome::UpToDate);
+    }
+    let previous_metadata = previous.table_metadata;
+
+    let retention = MetadataRetention::resolve(config, previous_metadata.properties());
+    let expired_snapshot_ids =
+        retention.expired_snapshot_ids(&previous_metadata, chrono::Utc::now().timestamp_millis());
+    let excess_metadata_log_entries = previous_metadata
+        .metadata_log()
+        .len()
+        .saturating_sub(retention.max_metadata_log_entries);
+    if expired_snapshot_ids.is_empty() && excess_metadata_log_entries == 0 {
+        return Ok(CompactionOutcome::UpToDate);
+    }
+
+    let (requirements, updates) = if expired_snapshot_ids.is_empty() {
+        (vec![], vec![])
+    } else {
+        // The expired snapshots were computed from `previous_metadata`. A ref
+        // moved to one of them concurrently must fail the commit instead of
+        // losing its snapshot when the updates are replayed on a retry.
+        let requirements = std::iter::once(TableRequirement::UuidMatch {
+            uuid: previous_metadata.uuid(),
+        })
+        .chain(previous_metadata.refs().iter().map(|(name, reference)| {
+            TableRequirement::RefSnapshotIdMatch {
+                r#ref: name.clone(),
+                snapshot_id: Some(reference.snapshot_id),
+            }
+        }))
+        .collect();
+        let updates = vec![TableUpdate::RemoveSnapshots {
+            snapshot_ids: expired_snapshot_ids,
+        }];
+        (requirements, updates)
+    };
+    let table_ident = table_info.tabular_ident.clone();
+    let request = CommitTransactionRequest {
+        table_changes: vec![CommitTableRequest {
+            identifier: Some(table_ident.clone()),
+            requirements,
+            updates,
+        }],
+    };
+    let table_infos = HashMap::from([(table_ident, Arc::new(table_info))]);
+
+    let commits = commit_tables_inner::<C, A, S>(
+        warehouse,
+        request,
+        &[],
+        &table_infos,
+        Arc::new(RequestMetadata::new_lakekeeper_internal(Uuid::now_v7())),
+        state,
+        None,
+        Some(retention.max_metadata_log_entries),
+    )
+    .await?;
+
+    Ok(commits
+        .first()
+        .map_or(CompactionOutcome::UpToDate, |commit| {
+            CompactionOutcome::Compacted {
+                removed_metadata_log_entries: commit.number_expired_metadata_log_entries,
+                removed_snapshots: commit
+                    .previous_metadata
+                    .snapshots()
+                    .count()
+                    .saturating_sub(commit.new_metadata.snapshots().count()),
+            }
+        }))
+}
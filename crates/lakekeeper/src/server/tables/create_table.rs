@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use http::StatusCode;
 use iceberg::spec::{
@@ -14,23 +14,34 @@ use super::{
     validate_table_properties,
 };
 use crate::{
-    WarehouseId,
+    CONFIG, SoftDeletedTableNameConflict, WarehouseId,
     api::{
         endpoints::EndpointFlat,
         iceberg::v1::{
             ApiContext, CreateTableRequest, ErrorModel, LoadTableResult, NamespaceParameters,
             Result, TableIdent, TableParameters, tables::DataAccessMode,
         },
+        management::v1::namespace::NamespaceTableDefaults,
     },
     request_metadata::RequestMetadata,
     server::{
-        compression_codec::CompressionCodec, tables::validate_table_or_view_ident_creation,
-        tabular::determine_tabular_location,
+        commit_tables::ensure_metadata_location_in_table_location,
+        compression_codec::CompressionCodec,
+        tables::validate_table_or_view_ident_creation,
+        tabular::{
+            determine_storage_profile_slot, determine_tabular_location,
+            namespace_storage_profile_slot,
+        },
     },
     service::{
-        AllowedFormatVersions, CachePolicy, CatalogIdempotencyOps, CatalogStore, CatalogTableOps,
-        State, TableCreation, TableId, TabularId, Transaction,
-        authz::{Authorizer, AuthzNamespaceOps, CatalogNamespaceAction},
+        AllowedFormatVersions, CachePolicy, CatalogIdempotencyOps, CatalogNamespaceOps,
+        CatalogStore, CatalogTableOps, CatalogTabularOps, CatalogWarehouseOps, FileFormatPolicy,
+        GenericTableId, NamedEntity, PartitionTransformPolicy, State, TableCreation, TableId,
+        TabularId, TabularListFlags, Transaction, ViewId, WarehouseQuotaEntity, WriteFileFormat,
+        authz::{
+            AuthZTableOps, Authorizer, AuthzNamespaceOps, CatalogNamespaceAction,
+            CatalogTableAction, RequireTableActionError,
+        },
         events::{
             APIEventContext,
             context::{ResolvedNamespace, UserProvidedNamespace},
@@ -38,6 +49,10 @@ use crate::{
         idempotency::{IdempotencyInfo, IdempotencyKey},
         secrets::SecretStore,
         storage::{StoragePermissions, ValidationError, credential_revalidate_after_ms},
+        tasks::{
+            ScheduleTaskMetadata, TaskEntity, WarehouseTaskEntityId,
+            tabular_purge_queue::{TabularPurgePayload, TabularPurgeTask},
+        },
     },
 };
 
@@ -106,14 +121,59 @@ impl<A: Authorizer> TableCreationGuard<A> {
     }
 }
 
-/// Load a table from the catalog
+/// Create a table in the given namespace.
 pub(super) async fn create_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+    parameters: NamespaceParameters,
+    request: CreateTableRequest,
+    data_access: impl Into<DataAccessMode> + Send,
+    state: ApiContext<State<A, C, S>>,
+    request_metadata: RequestMetadata,
+) -> Result<LoadTableResult> {
+    create_table_with_policy(
+        parameters,
+        request,
+        data_access,
+        state,
+        request_metadata,
+        CONFIG.soft_deleted_table_name_conflict,
+    )
+    .await
+}
+
+/// Create a table, handling a soft-deleted table with the same name according
+/// to `soft_deleted_name_conflict` instead of the configured policy.
+#[cfg(any(test, feature = "test-utils"))]
+pub async fn create_table_with_soft_deleted_name_conflict<
+    C: CatalogStore,
+    A: Authorizer + Clone,
+    S: SecretStore,
+>(
+    parameters: NamespaceParameters,
+    request: CreateTableRequest,
+    data_access: impl Into<DataAccessMode> + Send,
+    state: ApiContext<State<A, C, S>>,
+    request_metadata: RequestMetadata,
+    soft_deleted_name_conflict: SoftDeletedTableNameConflict,
+) -> Result<LoadTableResult> {
+    create_table_with_policy(
+        parameters,
+        request,
+        data_access,
+        state,
+        request_metadata,
+        soft_deleted_name_conflict,
+    )
+    .await
+}
+
+async fn create_table_with_policy<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     parameters: NamespaceParameters,
     // mut because we need to change location
     request: CreateTableRequest,
     data_access: impl Into<DataAccessMode> + Send,
     state: ApiContext<State<A, C, S>>,
     request_metadata: RequestMetadata,
+    soft_deleted_name_conflict: SoftDeletedTableNameConflict,
 ) -> Result<LoadTableResult> {
     let warehouse_id = require_warehouse_id(parameters.prefix.as_ref())?;
 
@@ -141,7 +201,7 @@ pub(super) async fn create_table<C: CatalogStore, A: Authorizer + Clone, S: Secr
 
     // ------------------- AUTHZ + BUSINESS LOGIC -------------------
     let authorizer = state.v1_state.authz.clone();
-    let table_id = TableId::from(Uuid::now_v7());
+    let table_id = table_id_from_request(request.table_uuid)?;
 
     let mut guard = TableCreationGuard::new(authorizer.clone(), warehouse_id, table_id);
 
@@ -152,6 +212,7 @@ pub(super) async fn create_table<C: CatalogStore, A: Authorizer + Clone, S: Secr
         state,
         request_metadata,
         idempotency_key.as_ref(),
+        soft_deleted_name_conflict,
         &mut guard,
     )
     .await
@@ -167,8 +228,26 @@ pub(super) async fn create_table<C: CatalogStore, A: Authorizer + Clone, S: Secr
     }
 }
 
+/// Use the id supplied by the client, e.g. to preserve table ids when migrating
+/// from another catalog, or generate a new one.
+fn table_id_from_request(table_uuid: Option<Uuid>) -> Result<TableId> {
+    let Some(table_uuid) = table_uuid else {
+        return Ok(TableId::from(Uuid::now_v7()));
+    };
+
+    match table_uuid.get_version() {
+        Some(uuid::Version::Random | uuid::Version::SortRand) => Ok(TableId::from(table_uuid)),
+        _ => Err(ErrorModel::bad_request(
+            format!("Table UUID `{table_uuid}` must be a version 4 or version 7 UUID"),
+            "InvalidTableUuid",
+            None,
+        )
+        .into()),
+    }
+}
+
 /// Inner function that performs the actual table creation logic
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_lines, clippy::too_many_arguments)]
 async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     parameters: NamespaceParameters,
     // mut because we need to change location
@@ -177,6 +256,7 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
     state: ApiContext<State<A, C, S>>,
     request_metadata: RequestMetadata,
     idempotency_key: Option<&IdempotencyKey>,
+    soft_deleted_name_conflict: SoftDeletedTableNameConflict,
     guard: &mut TableCreationGuard<A>,
 ) -> Result<LoadTableResult> {
     let data_access = data_access.into();
@@ -233,11 +313,78 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
     });
     let warehouse = &event_ctx.resolved().warehouse;
 
+    // A soft-deleted table with the same name does not block creation on the
+    // database level, but can no longer be undropped once the name is taken.
+    let soft_deleted_table = match soft_deleted_name_conflict {
+        SoftDeletedTableNameConflict::Allow => None,
+        SoftDeletedTableNameConflict::Purge | SoftDeletedTableNameConflict::Reject => {
+            C::get_table_info(
+                warehouse_id,
+                table.clone(),
+                TabularListFlags::only_deleted(),
+                state.v1_state.catalog.clone(),
+            )
+            .await?
+        }
+    };
+    if let Some(soft_deleted_table) = &soft_deleted_table {
+        if soft_deleted_name_conflict == SoftDeletedTableNameConflict::Reject {
+            return Err(ErrorModel::conflict(
+                format!(
+                    "A dropped table named `{}` is pending deletion in this namespace. Undrop it or wait until it expires before creating a new table with this name.",
+                    table.name
+                ),
+                "SoftDeletedTableExists",
+                None,
+            )
+            .into());
+        }
+        authorizer
+            .require_table_action(
+                &request_metadata,
+                warehouse,
+                &ns_hierarchy,
+                table.clone(),
+                Ok::<_, RequireTableActionError>(Some(soft_deleted_table.clone())),
+                CatalogTableAction::Drop {
+                    force: true,
+                    purge: true,
+                },
+            )
+            .await?;
+    }
+
     // ------------------- BUSINESS LOGIC -------------------
     let table_id = guard.table_id();
     let tabular_id = TabularId::Table(table_id);
 
-    let storage_profile = &warehouse.storage_profile;
+    // Generated ids are unique. Supplied ids must not be used by any tabular,
+    // including soft-deleted ones, as tabulars of all types share their ids.
+    if request.table_uuid.is_some() {
+        let existing = C::get_tabular_infos_by_id(
+            warehouse_id,
+            &[
+                tabular_id,
+                TabularId::View(ViewId::from(*table_id)),
+                TabularId::GenericTable(GenericTableId::from(*table_id)),
+            ],
+            TabularListFlags::all(),
+            state.v1_state.catalog.clone(),
+        )
+        .await?;
+        if !existing.is_empty() {
+            return Err(ErrorModel::conflict(
+                format!("A table, view or generic table with id `{table_id}` already exists"),
+                "TableUuidAlreadyExists",
+                None,
+            )
+            .into());
+        }
+    }
+
+    let storage_profile_slot =
+        determine_storage_profile_slot(&ns_hierarchy, request.properties.as_ref())?;
+    let (storage_profile, storage_secret_id) = warehouse.storage(storage_profile_slot)?;
 
     let table_location = determine_tabular_location(
         &ns_hierarchy,
@@ -245,8 +392,23 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
         tabular_id,
         &table,
         storage_profile,
+        namespace_storage_profile_slot(&ns_hierarchy.namespace) == storage_profile_slot,
+        warehouse.settings.require_location_in_warehouse,
     )?;
 
+    // Tables without a partition spec or sort order inherit the defaults of their namespace.
+    if NamespaceTableDefaults::applies_to(&request) {
+        let mut t = C::Transaction::begin_read(state.v1_state.catalog.clone()).await?;
+        let table_defaults = C::get_namespace_table_defaults(
+            warehouse_id,
+            ns_hierarchy.namespace_id(),
+            t.transaction(),
+        )
+        .await?;
+        t.commit().await?;
+        table_defaults.apply_to(&mut request);
+    }
+
     // Update the request for event
     request.location = Some(table_location.to_string());
     let request = request; // Make it non-mutable again for our sanity
@@ -264,14 +426,75 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
         ))
     };
 
+    if warehouse.settings.require_metadata_in_table_location
+        && let Some(metadata_location) = &metadata_location
+    {
+        ensure_metadata_location_in_table_location(&table_location, metadata_location)?;
+    }
+
+    if let (Some(policy), Some(partition_spec)) = (
+        warehouse.settings.partition_transform_policy.as_ref(),
+        request.partition_spec.as_ref(),
+    ) {
+        ensure_partition_transforms_allowed(partition_spec, policy)?;
+    }
+
+    if let Some(policy) = warehouse.settings.file_format_policy.as_ref() {
+        ensure_file_format_allowed(
+            request
+                .properties
+                .as_ref()
+                .and_then(|properties| properties.get(WriteFileFormat::PROPERTY))
+                .map(String::as_str),
+            policy,
+        )?;
+    }
+
     let table_metadata = create_table_request_into_table_metadata(
         table_id,
         request.clone(),
         &warehouse.allowed_format_versions,
         warehouse.default_format_version,
     )?;
+    ensure_table_properties_within_limits(
+        table_metadata.properties(),
+        warehouse.settings.max_table_properties,
+        warehouse.settings.max_table_properties_size_bytes,
+    )?;
 
     let mut t = C::Transaction::begin_write(state.v1_state.catalog).await?;
+    let purged_table_id = if let Some(soft_deleted_table) = soft_deleted_table {
+        let purged_table_id = soft_deleted_table.table_id();
+        let location =
+            C::drop_tabular(warehouse_id, purged_table_id, true, t.transaction()).await?;
+        TabularPurgeTask::schedule_task::<C>(
+            ScheduleTaskMetadata {
+                project_id: warehouse.project_id.clone(),
+                parent_task_id: None,
+                scheduled_for: None,
+                entity: TaskEntity::EntityInWarehouse {
+                    entity_name: soft_deleted_table.tabular_ident.into_name_parts(),
+                    warehouse_id,
+                    entity_id: WarehouseTaskEntityId::Table {
+                        table_id: purged_table_id,
+                    },
+                },
+            },
+            TabularPurgePayload {
+                tabular_location: location.to_string(),
+            },
+            t.transaction(),
+        )
+        .await?;
+        tracing::debug!(
+            "Dropped soft-deleted table '{purged_table_id}' to create table '{table_id}', queued purge task."
+        );
+        Some(purged_table_id)
+    } else {
+        None
+    };
+
+    C::ensure_warehouse_quota(&warehouse, WarehouseQuotaEntity::Table, 1, t.transaction()).await?;
     let (table_info, staged_table_id) = C::create_table(
         TableCreation {
             warehouse_id: warehouse.warehouse_id,
@@ -279,6 +502,7 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
             table_ident: &table,
             table_metadata: &table_metadata,
             metadata_location: metadata_location.as_ref(),
+            storage_profile_slot,
         },
         t.transaction(),
     )
@@ -286,7 +510,7 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
     let table_metadata = Arc::new(table_metadata);
 
     // We don't commit the transaction yet, first we need to write the metadata file.
-    let storage_secret = if let Some(secret_id) = warehouse.storage_secret_id {
+    let storage_secret = if let Some(secret_id) = storage_secret_id {
         let secret_state = state.v1_state.secrets;
         Some(
             secret_state
@@ -327,6 +551,7 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
     let config = storage_profile
         .generate_table_config(
             data_access,
+            warehouse.settings.credential_mode,
             storage_secret_ref,
             &table_location,
             StoragePermissions::ReadWriteDelete,
@@ -390,6 +615,19 @@ async fn create_table_inner<C: CatalogStore, A: Authorizer + Clone, S: SecretSto
     // Commit transaction
     t.commit().await?;
 
+    // The purged soft-deleted table is gone from the catalog, clean up the authorizer
+    if let Some(purged_table_id) = purged_table_id {
+        authorizer
+            .delete_table(warehouse_id, purged_table_id)
+            .await
+            .inspect_err(|e| {
+                tracing::warn!(
+                    "Failed to delete purged table '{purged_table_id}' from authorizer: {e}"
+                );
+            })
+            .ok();
+    }
+
     // If a staged table was overwritten, delete it from authorizer
     if let Some(staged_table_id) = staged_table_id {
         authorizer
@@ -427,6 +665,8 @@ pub fn create_table_request_into_table_metadata(
         // otherwise, it is the location of the metadata file.
         stage_create: _,
         mut properties,
+        // Already used as `table_id`.
+        table_uuid: _,
     } = request;
 
     let location = location.ok_or_else(|| {
@@ -509,3 +749,112 @@ pub(crate) fn ensure_format_version_allowed(
     )
     .into())
 }
+
+/// Reject a partition spec that uses a transform not permitted by the warehouse
+/// partition transform policy.
+pub(crate) fn ensure_partition_transforms_allowed(
+    partition_spec: &UnboundPartitionSpec,
+    policy: &PartitionTransformPolicy,
+) -> Result<()> {
+    let Some(field) = partition_spec
+        .fields()
+        .iter()
+        .find(|field| !policy.allows(field.transform))
+    else {
+        return Ok(());
+    };
+
+    let allowed = policy
+        .allowed_transforms()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let bucket_limit = policy
+        .max_bucket_count()
+        .map(|max| format!(" Maximum bucket count: {max}."))
+        .unwrap_or_default();
+    Err(ErrorModel::bad_request(
+        format!(
+            "Partition transform '{}' of field '{}' is not allowed in this warehouse. Allowed transforms: [{allowed}].{bucket_limit}",
+            field.transform, field.name
+        ),
+        "PartitionTransformNotAllowed",
+        None,
+    )
+    .into())
+}
+
+/// Reject a `write.format.default` value that is not permitted by the warehouse
+/// file format policy. `None` stands for an unset property.
+pub(crate) fn ensure_file_format_allowed(
+    value: Option<&str>,
+    policy: &FileFormatPolicy,
+) -> Result<()> {
+    if policy.allows(value) {
+        return Ok(());
+    }
+
+    let allowed = policy
+        .allowed_file_formats()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let message = match value {
+        Some(value) => format!(
+            "File format '{value}' of table property '{}' is not allowed in this warehouse. Allowed file formats: [{allowed}]",
+            WriteFileFormat::PROPERTY
+        ),
+        None => format!(
+            "Table property '{}' is not set and the default file format '{}' is not allowed in this warehouse. Allowed file formats: [{allowed}]",
+            WriteFileFormat::PROPERTY,
+            WriteFileFormat::DEFAULT
+        ),
+    };
+    Err(ErrorModel::bad_request(message, "FileFormatNotAllowed", None).into())
+}
+
+/// Total size in bytes of the keys and values of table properties.
+pub(crate) fn table_properties_size(properties: &HashMap<String, String>) -> usize {
+    properties
+        .iter()
+        .map(|(key, value)| key.len() + value.len())
+        .sum()
+}
+
+/// Reject table properties that exceed the warehouse limits on the number of
+/// properties or on the total size in bytes of their keys and values.
+pub(crate) fn ensure_table_properties_within_limits(
+    properties: &HashMap<String, String>,
+    max_properties: Option<u32>,
+    max_properties_size_bytes: Option<u32>,
+) -> Result<()> {
+    if let Some(max) = max_properties
+        && properties.len() > usize::try_from(max).unwrap_or(usize::MAX)
+    {
+        return Err(ErrorModel::bad_request(
+            format!(
+                "Table has {} properties, which exceeds the maximum of {max} properties allowed in this warehouse",
+                properties.len()
+            ),
+            "TablePropertiesLimitExceeded",
+            None,
+        )
+        .into());
+    }
+    if let Some(max) = max_properties_size_bytes {
+        let size = table_properties_size(properties);
+        if size > usize::try_from(max).unwrap_or(usize::MAX) {
+            return Err(ErrorModel::bad_request(
+                format!(
+                    "Table properties have a total size of {size} bytes, which exceeds the maximum of {max} bytes allowed in this warehouse"
+                ),
+                "TablePropertiesLimitExceeded",
+                None,
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
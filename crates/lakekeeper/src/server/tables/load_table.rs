@@ -1,14 +1,19 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use http::StatusCode;
-use iceberg_ext::catalog::rest::{ETag, StorageCredential, TableETag};
+use iceberg_ext::catalog::rest::{
+    ETag, ErrorModel, IcebergErrorResponse, StorageCredential, TableETag,
+};
 
 use crate::{
     WarehouseId,
     api::iceberg::v1::{
         ApiContext, LoadTableResult, LoadTableResultOrNotModified, Result, TableIdent,
         TableParameters,
-        tables::{LoadTableFilters, LoadTableRequest},
+        tables::{DataAccessMode, LoadTableFilters, LoadTableRequest},
     },
     request_metadata::RequestMetadata,
     server::{
@@ -35,6 +40,11 @@ use crate::{
 /// May panic if internal invariants are violated (e.g., an entry expected to
 /// exist in a pre-resolved map is missing).
 #[allow(clippy::too_many_lines)]
+#[tracing::instrument(
+    name = "load_table",
+    skip_all,
+    fields(table = ?parameters.table, request_id = %request_metadata.request_id())
+)]
 pub async fn load_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     parameters: TableParameters,
     request: LoadTableRequest,
@@ -46,6 +56,7 @@ pub async fn load_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
         filters,
         etags,
         referenced_by,
+        read_after_write,
     } = request;
 
     // ------------------- VALIDATIONS -------------------
@@ -98,34 +109,51 @@ pub async fn load_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     // wildcard). Not the raw `vended-credentials` flag, since backends vend
     // expiring credentials even for the default request (S3 auto-promotes;
     // GCS/Azure vend for any delegated access).
-    let vends_credentials = storage_permissions.is_some()
-        && event_ctx
-            .resolved()
-            .warehouse
+    // For read-after-write loads the check is deferred until the metadata
+    // location has been read from the primary, as the table info may be stale.
+    // The storage profile slot of the table is only known after loading it, so
+    // a secondary storage profile of the warehouse is considered as well.
+    let vends_credentials = storage_permissions.is_some() && {
+        let warehouse = &event_ctx.resolved().warehouse;
+        warehouse
             .storage_profile
-            .vends_expiring_credentials(data_access);
-    if let Some(etag) = match_not_modified(
-        &etags,
-        event_ctx
-            .resolved()
-            .table
-            .metadata_location
-            .as_ref()
-            .map(lakekeeper_io::Location::as_str),
-        now_epoch_ms(),
-        vends_credentials,
-    ) {
+            .vends_expiring_credentials(data_access)
+            || warehouse
+                .secondary_storage
+                .as_ref()
+                .is_some_and(|s| s.storage_profile.vends_expiring_credentials(data_access))
+    };
+    if !read_after_write
+        && let Some(etag) = match_not_modified(
+            &etags,
+            event_ctx
+                .resolved()
+                .table
+                .metadata_location
+                .as_ref()
+                .map(lakekeeper_io::Location::as_str),
+            now_epoch_ms(),
+            vends_credentials,
+        )
+    {
         return Ok(LoadTableResultOrNotModified::NotModifiedResponse(etag));
     }
 
     // ------------------- BUSINESS LOGIC -------------------
-    let mut t = C::Transaction::begin_read(catalog_state.clone()).await?;
+    // Write transactions are served by the primary, which trades the offloading
+    // of read replicas for freshness.
+    let mut t = if read_after_write {
+        C::Transaction::begin_write(catalog_state.clone()).await?
+    } else {
+        C::Transaction::begin_read(catalog_state.clone()).await?
+    };
     let CatalogLoadTableResult {
         table_id: _,
         namespace_id: _,
         table_metadata,
         metadata_location,
         warehouse_version,
+        storage_profile_slot,
     } = load_table_inner::<C>(
         warehouse_id,
         event_ctx.resolved().table.table_id(),
@@ -137,6 +165,19 @@ pub async fn load_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     .await?;
     t.commit().await?;
 
+    if read_after_write
+        && let Some(etag) = match_not_modified(
+            &etags,
+            metadata_location
+                .as_ref()
+                .map(lakekeeper_io::Location::as_str),
+            now_epoch_ms(),
+            vends_credentials,
+        )
+    {
+        return Ok(LoadTableResultOrNotModified::NotModifiedResponse(etag));
+    }
+
     // Refetch warehouse if version is stale
     if event_ctx.resolved().warehouse.version < warehouse_version {
         let warehouse = C::get_warehouse_by_id_cache_aware(
@@ -157,14 +198,14 @@ pub async fn load_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
         parse_location(table_metadata.location(), StatusCode::INTERNAL_SERVER_ERROR)?;
 
     let storage_config = if let Some(storage_permissions) = storage_permissions {
-        let storage_secret =
-            maybe_get_secret(warehouse.storage_secret_id, &state.v1_state.secrets).await?;
+        let (storage_profile, storage_secret_id) = warehouse.storage(storage_profile_slot)?;
+        let storage_secret = maybe_get_secret(storage_secret_id, &state.v1_state.secrets).await?;
         let storage_secret_ref = storage_secret.as_deref();
         Some(
-            warehouse
-                .storage_profile
+            storage_profile
                 .generate_table_config(
                     data_access,
+                    warehouse.settings.credential_mode,
                     storage_secret_ref,
                     &table_location,
                     storage_permissions,
@@ -208,11 +249,229 @@ pub async fn load_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     ))
 }
 
+/// Maximum number of tables that can be loaded with a single [`load_tables_batch`] call.
+pub(crate) const MAX_TABLES_PER_LOAD_BATCH: usize = 100;
+
+/// Load multiple tables of a warehouse at once.
+///
+/// Each table is authorized exactly as for [`load_table`], but the metadata of
+/// all tables is read with a single [`CatalogTableOps::load_tables`] call.
+/// The request fails if any of the tables cannot be loaded. Results are
+/// returned in the order of `tables`.
+pub async fn load_tables_batch<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+    warehouse_id: WarehouseId,
+    tables: Vec<TableIdent>,
+    data_access: DataAccessMode,
+    state: ApiContext<State<A, C, S>>,
+    request_metadata: RequestMetadata,
+) -> Result<Vec<LoadTableResult>> {
+    // ------------------- VALIDATIONS -------------------
+    if tables.len() > MAX_TABLES_PER_LOAD_BATCH {
+        return Err(ErrorModel::bad_request(
+            format!(
+                "Too many tables requested: {}. Maximum allowed is {MAX_TABLES_PER_LOAD_BATCH}",
+                tables.len()
+            ),
+            "TooManyTables",
+            None,
+        )
+        .into());
+    }
+    {
+        let mut seen = HashSet::with_capacity(tables.len());
+        for table in &tables {
+            validate_table_or_view_ident(table)?;
+            if !seen.insert(table) {
+                return Err(ErrorModel::bad_request(
+                    format!("Table {table:?} is requested more than once"),
+                    "DuplicateTableIdent",
+                    None,
+                )
+                .into());
+            }
+        }
+    }
+    if tables.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // ------------------- AUTHZ -------------------
+    let authorizer = state.v1_state.authz;
+    let catalog_state = state.v1_state.catalog;
+    let request_metadata = Arc::new(request_metadata);
+
+    let mut event_ctxs = futures::future::try_join_all(tables.into_iter().map(|table| {
+        let event_ctx = APIEventContext::for_table(
+            request_metadata.clone(),
+            state.v1_state.events.clone(),
+            warehouse_id,
+            table.clone(),
+            CatalogTableAction::GetMetadata,
+        );
+        let authorizer = authorizer.clone();
+        let catalog_state = catalog_state.clone();
+        async move {
+            let authz_result = authorize_load_table::<C, A>(
+                event_ctx.request_metadata(),
+                table,
+                warehouse_id,
+                TabularListFlags::active(),
+                authorizer,
+                catalog_state,
+                None,
+            )
+            .await;
+            let (event_ctx, (warehouse, table_info, storage_permissions)) =
+                event_ctx.emit_authz(authz_result)?;
+            Result::<_, IcebergErrorResponse>::Ok(event_ctx.resolve(ResolvedTable {
+                warehouse,
+                table: Arc::new(table_info),
+                storage_permissions,
+            }))
+        }
+    }))
+    .await?;
+
+    // ------------------- BUSINESS LOGIC -------------------
+    let table_ids = event_ctxs
+        .iter()
+        .map(|event_ctx| event_ctx.resolved().table.table_id())
+        .collect::<Vec<_>>();
+    let mut t = C::Transaction::begin_read(catalog_state.clone()).await?;
+    let mut loaded_tables = C::load_tables(
+        warehouse_id,
+        table_ids,
+        false,
+        &LoadTableFilters::default(),
+        t.transaction(),
+    )
+    .await?
+    .into_iter()
+    .map(|r| (r.table_id, r))
+    .collect::<HashMap<_, _>>();
+    t.commit().await?;
+
+    // All tables live in the same warehouse, so it is refetched at most once.
+    let mut warehouse = event_ctxs[0].resolved().warehouse.clone();
+    let max_warehouse_version = loaded_tables.values().map(|r| r.warehouse_version).max();
+    if let Some(warehouse_version) = max_warehouse_version
+        && warehouse.version < warehouse_version
+    {
+        let fresh_warehouse = C::get_warehouse_by_id_cache_aware(
+            warehouse_id,
+            WarehouseStatus::active(),
+            CachePolicy::RequireMinimumVersion(*warehouse_version),
+            catalog_state.clone(),
+        )
+        .await;
+        warehouse = authorizer
+            .require_warehouse_presence(warehouse_id, fresh_warehouse)
+            .map_err(authz_to_error_no_audit)?;
+    }
+
+    let mut needed_slots = HashSet::new();
+
+    let mut loaded = Vec::with_capacity(event_ctxs.len());
+    for event_ctx in &mut event_ctxs {
+        event_ctx.resolved_mut().warehouse = warehouse.clone();
+        let table_info = &event_ctx.resolved().table;
+        let result = loaded_tables
+            .remove(&table_info.table_id())
+            .ok_or_else(|| {
+                TabularNotFound::new(
+                    warehouse_id,
+                    TableIdentOrId::from(table_info.table_ident().clone()),
+                )
+                .append_detail("Table metadata not returned from table load".to_string())
+            })?;
+        require_not_staged(
+            warehouse_id,
+            table_info.table_ident().clone(),
+            result.metadata_location.as_ref(),
+        )?;
+        if event_ctx.resolved().storage_permissions.is_some() {
+            needed_slots.insert(result.storage_profile_slot);
+        }
+        loaded.push(result);
+    }
+
+    // Secrets are fetched once per storage profile slot used by the tables.
+    let mut storage_secrets = HashMap::with_capacity(needed_slots.len());
+    for slot in needed_slots {
+        let (_, storage_secret_id) = warehouse.storage(slot)?;
+        let storage_secret = maybe_get_secret(storage_secret_id, &state.v1_state.secrets).await?;
+        storage_secrets.insert(slot, storage_secret);
+    }
+    let storage_secrets = &storage_secrets;
+    let results = futures::future::try_join_all(event_ctxs.into_iter().zip(loaded).map(
+        |(event_ctx, loaded)| async move {
+            let CatalogLoadTableResult {
+                table_metadata,
+                metadata_location,
+                storage_profile_slot,
+                ..
+            } = loaded;
+            let resolved = event_ctx.resolved();
+            let table_location =
+                parse_location(table_metadata.location(), StatusCode::INTERNAL_SERVER_ERROR)?;
+            let storage_config = if let Some(storage_permissions) = resolved.storage_permissions {
+                let (storage_profile, _) = resolved.warehouse.storage(storage_profile_slot)?;
+                let storage_secret_ref = storage_secrets
+                    .get(&storage_profile_slot)
+                    .and_then(Option::as_deref);
+                Some(
+                    storage_profile
+                        .generate_table_config(
+                            data_access,
+                            resolved.warehouse.settings.credential_mode,
+                            storage_secret_ref,
+                            &table_location,
+                            storage_permissions,
+                            event_ctx.request_metadata(),
+                            &*resolved.table,
+                        )
+                        .await?,
+                )
+            } else {
+                None
+            };
+
+            let storage_credentials = storage_config.as_ref().and_then(|c| {
+                (!c.creds.inner().is_empty()).then(|| {
+                    vec![StorageCredential {
+                        prefix: table_location.to_string(),
+                        config: c.creds.clone().into(),
+                    }]
+                })
+            });
+            let credentials_revalidate_after_ms = storage_config
+                .as_ref()
+                .and_then(|c| c.credentials_expiration_ms)
+                .map(credential_revalidate_after_ms);
+
+            let metadata_ref = Arc::new(table_metadata);
+            let metadata_location_ref = metadata_location.map(Arc::new);
+            event_ctx.emit_table_loaded_async(metadata_ref.clone(), metadata_location_ref.clone());
+
+            Result::<_, IcebergErrorResponse>::Ok(LoadTableResult {
+                metadata_location: metadata_location_ref.as_ref().map(ToString::to_string),
+                metadata: metadata_ref,
+                config: storage_config.map(|c| c.config.into()),
+                storage_credentials,
+                credentials_revalidate_after_ms,
+            })
+        },
+    ))
+    .await?;
+
+    Ok(results)
+}
+
 /// Load a table from the catalog, ensuring that it is not staged
 ///
 /// # Errors
 /// Returns an error if the table is staged, if it cannot be found, or if a DB error occurs.
-async fn load_table_inner<C: CatalogStore>(
+pub(super) async fn load_table_inner<C: CatalogStore>(
     warehouse_id: WarehouseId,
     table_id: TableId,
     table_ident: &TableIdent,
@@ -0,0 +1,480 @@
+//! Synchronous, stateless server-side scan planning.
+//!
+//! `planTableScan` reads the manifest list of the scanned snapshot and returns
+//! one plan task per data manifest. `fetchScanTasks` reads the manifest of a
+//! plan task and returns a file scan task for every live data file in it.
+//! Filters are not evaluated yet, so no residual is returned and clients apply
+//! the original filter themselves.
+use std::sync::Arc;
+
+use base64::Engine as _;
+use http::StatusCode;
+use iceberg::spec::{
+    DataContentType, DataFile, Manifest, ManifestContentType, ManifestFile, ManifestList, Struct,
+    TableMetadata,
+};
+use iceberg_ext::catalog::rest::{
+    CompletedPlanningResult, ContentFile, ContentFileType, ErrorModel, FetchScanTasksRequest,
+    FetchScanTasksResult, FileScanTask, PlanTableScanRequest, PlanTableScanResult, ScanTasks,
+    StorageCredential,
+};
+use lakekeeper_io::StorageBackend;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    authorize_load_table, load_table::load_table_inner, parse_location,
+    validate_table_or_view_ident,
+};
+use crate::{
+    api::iceberg::v1::{
+        ApiContext, Result, TableParameters,
+        tables::{DataAccessMode, LoadTableFilters},
+    },
+    request_metadata::RequestMetadata,
+    server::{
+        CatalogServer, compression_codec::CompressionCodec, io::read_file, maybe_get_secret,
+        require_warehouse_id,
+    },
+    service::{
+        AuthZTableInfo as _, CatalogStore, ResolvedWarehouse, State, TableInfo, TabularListFlags,
+        Transaction,
+        authz::{AuthZTableActionForbidden, Authorizer, CatalogTableAction},
+        events::{APIEventContext, context::ResolvedTable},
+        secrets::SecretStore,
+        storage::StoragePermissions,
+    },
+};
+
+/// Opaque plan task handed to clients. The manifest path is validated against
+/// the snapshot's manifest list before it is read.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PlanTask {
+    snapshot_id: i64,
+    manifest_path: String,
+}
+
+impl PlanTask {
+    fn encode(&self) -> Result<String> {
+        let json = serde_json::to_vec(self).map_err(|e| {
+            ErrorModel::internal(
+                "Failed to serialize plan task",
+                "PlanTaskSerializationError",
+                Some(Box::new(e)),
+            )
+        })?;
+        Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json))
+    }
+
+    fn decode(plan_task: &str) -> Result<Self> {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(plan_task)
+            .ok()
+            .and_then(|json| serde_json::from_slice(&json).ok())
+            .ok_or_else(|| {
+                ErrorModel::bad_request(
+                    "Plan task was not issued by this catalog",
+                    "InvalidPlanTask",
+                    None,
+                )
+                .into()
+            })
+    }
+}
+
+struct LoadedTable {
+    warehouse: Arc<ResolvedWarehouse>,
+    table_info: TableInfo,
+    storage_permissions: StoragePermissions,
+    metadata: TableMetadata,
+}
+
+#[async_trait::async_trait]
+impl<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
+    crate::api::iceberg::v1::scan_planning::Service<State<A, C, S>> for CatalogServer<C, A, S>
+{
+    async fn plan_table_scan(
+        parameters: TableParameters,
+        request: PlanTableScanRequest,
+        data_access: DataAccessMode,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<PlanTableScanResult> {
+        // ------------------- VALIDATIONS -------------------
+        if request.start_snapshot_id.is_some() || request.end_snapshot_id.is_some() {
+            return Err(ErrorModel::not_implemented(
+                "Incremental scan planning is not supported",
+                "IncrementalScanPlanningNotSupported",
+                None,
+            )
+            .into());
+        }
+
+        let LoadedTable {
+            warehouse,
+            table_info,
+            storage_permissions,
+            metadata,
+        } = authorize_and_load_table(parameters, &state, &request_metadata).await?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let snapshot = match request.snapshot_id {
+            Some(snapshot_id) => Some(metadata.snapshot_by_id(snapshot_id).ok_or_else(|| {
+                ErrorModel::not_found(
+                    format!("Snapshot {snapshot_id} does not exist"),
+                    "NoSuchSnapshotException",
+                    None,
+                )
+            })?),
+            None => metadata.current_snapshot(),
+        };
+
+        let table_location =
+            parse_location(metadata.location(), StatusCode::INTERNAL_SERVER_ERROR)?;
+        let (storage_profile, storage_secret_id) =
+            warehouse.storage(warehouse.storage_slot_of_location(&table_location))?;
+        let storage_secret = maybe_get_secret(storage_secret_id, &state.v1_state.secrets).await?;
+        let storage_secret_ref = storage_secret.as_deref();
+
+        let plan_tasks = if let Some(snapshot) = snapshot {
+            let file_io = storage_profile.file_io(storage_secret_ref).await?;
+            read_data_manifests(&file_io, snapshot.manifest_list(), &metadata)
+                .await?
+                .into_iter()
+                .map(|manifest| {
+                    PlanTask {
+                        snapshot_id: snapshot.snapshot_id(),
+                        manifest_path: manifest.manifest_path,
+                    }
+                    .encode()
+                })
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            vec![]
+        };
+
+        let storage_config = storage_profile
+            .generate_table_config(
+                data_access,
+                warehouse.settings.credential_mode,
+                storage_secret_ref,
+                &table_location,
+                storage_permissions,
+                &request_metadata,
+                &table_info,
+            )
+            .await?;
+        let storage_credentials = (!storage_config.creds.inner().is_empty()).then(|| {
+            vec![StorageCredential {
+                prefix: table_location.to_string(),
+                config: storage_config.creds.into(),
+            }]
+        });
+
+        Ok(PlanTableScanResult::Completed(CompletedPlanningResult {
+            plan_id: uuid::Uuid::now_v7().to_string(),
+            scan_tasks: ScanTasks {
+                plan_tasks,
+                ..Default::default()
+            },
+            storage_credentials,
+        }))
+    }
+
+    async fn fetch_scan_tasks(
+        parameters: TableParameters,
+        request: FetchScanTasksRequest,
+        state: ApiContext<State<A, C, S>>,
+        request_metadata: RequestMetadata,
+    ) -> Result<FetchScanTasksResult> {
+        // ------------------- VALIDATIONS -------------------
+        let plan_task = PlanTask::decode(&request.plan_task)?;
+
+        let LoadedTable {
+            warehouse,
+            table_info: _,
+            storage_permissions: _,
+            metadata,
+        } = authorize_and_load_table(parameters, &state, &request_metadata).await?;
+
+        // ------------------- BUSINESS LOGIC -------------------
+        let invalid_plan_task = || {
+            ErrorModel::bad_request(
+                "Plan task does not belong to this table",
+                "InvalidPlanTask",
+                None,
+            )
+        };
+        let snapshot = metadata
+            .snapshot_by_id(plan_task.snapshot_id)
+            .ok_or_else(invalid_plan_task)?;
+
+        let table_location =
+            parse_location(metadata.location(), StatusCode::INTERNAL_SERVER_ERROR)?;
+        let (storage_profile, storage_secret_id) =
+            warehouse.storage(warehouse.storage_slot_of_location(&table_location))?;
+        let storage_secret = maybe_get_secret(storage_secret_id, &state.v1_state.secrets).await?;
+        let file_io = storage_profile.file_io(storage_secret.as_deref()).await?;
+        let manifest_file = read_data_manifests(&file_io, snapshot.manifest_list(), &metadata)
+            .await?
+            .into_iter()
+            .find(|manifest| manifest.manifest_path == plan_task.manifest_path)
+            .ok_or_else(invalid_plan_task)?;
+
+        let manifest = read_manifest(&file_io, &manifest_file).await?;
+
+        let partition_spec = metadata
+            .partition_spec_by_id(manifest_file.partition_spec_id)
+            .ok_or_else(|| {
+                ErrorModel::internal(
+                    format!(
+                        "Partition spec {} of manifest {} not found in table metadata",
+                        manifest_file.partition_spec_id, manifest_file.manifest_path
+                    ),
+                    "PartitionSpecNotFound",
+                    None,
+                )
+            })?;
+        let schema = snapshot
+            .schema_id()
+            .and_then(|schema_id| metadata.schema_by_id(schema_id))
+            .unwrap_or_else(|| metadata.current_schema());
+        let partition_type = partition_spec.partition_type(schema).map_err(|e| {
+            ErrorModel::internal(
+                "Failed to determine partition type",
+                "PartitionTypeError",
+                Some(Box::new(e)),
+            )
+        })?;
+        let partition_field_types = partition_type
+            .fields()
+            .iter()
+            .map(|field| field.field_type.as_ref())
+            .collect::<Vec<_>>();
+
+        let mut file_scan_tasks = Vec::with_capacity(manifest.entries().len());
+        for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+            file_scan_tasks.push(FileScanTask {
+                data_file: content_file(
+                    entry.data_file(),
+                    manifest_file.partition_spec_id,
+                    &partition_field_types,
+                )?,
+                delete_file_references: None,
+                residual_filter: None,
+            });
+        }
+
+        Ok(FetchScanTasksResult {
+            scan_tasks: ScanTasks {
+                file_scan_tasks,
+                ..Default::default()
+            },
+        })
+    }
+}
+
+/// Authorize reading the data of a table and load its metadata.
+async fn authorize_and_load_table<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+    parameters: TableParameters,
+    state: &ApiContext<State<A, C, S>>,
+    request_metadata: &RequestMetadata,
+) -> Result<LoadedTable> {
+    let TableParameters { prefix, table } = parameters;
+    let warehouse_id = require_warehouse_id(prefix.as_ref())?;
+    validate_table_or_view_ident(&table)?;
+
+    // ------------------- AUTHZ -------------------
+    let event_ctx = APIEventContext::for_table(
+        Arc::new(request_metadata.clone()),
+        state.v1_state.events.clone(),
+        warehouse_id,
+        table.clone(),
+        CatalogTableAction::ReadData,
+    );
+
+    let authz_result = match authorize_load_table::<C, A>(
+        event_ctx.request_metadata(),
+        table.clone(),
+        warehouse_id,
+        TabularListFlags::active(),
+        state.v1_state.authz.clone(),
+        state.v1_state.catalog.clone(),
+        None,
+    )
+    .await
+    {
+        Err(e) => Err(e),
+        Ok((_, _, None)) => Err(AuthZTableActionForbidden::new(
+            warehouse_id,
+            table.clone(),
+            &CatalogTableAction::ReadData,
+        )
+        .into()),
+        Ok((a, b, Some(c))) => Ok((a, b, c)),
+    };
+    let (event_ctx, (warehouse, table_info, storage_permissions)) =
+        event_ctx.emit_authz(authz_result)?;
+    let event_ctx = event_ctx.resolve(ResolvedTable {
+        warehouse,
+        table: Arc::new(table_info),
+        storage_permissions: Some(storage_permissions),
+    });
+    let ResolvedTable {
+        warehouse,
+        table: table_info,
+        ..
+    } = event_ctx.resolved().clone();
+
+    // ------------------- LOAD -------------------
+    let mut t = C::Transaction::begin_read(state.v1_state.catalog.clone()).await?;
+    let loaded = load_table_inner::<C>(
+        warehouse_id,
+        table_info.table_id(),
+        table_info.table_ident(),
+        false,
+        &LoadTableFilters::default(),
+        &mut t,
+    )
+    .await?;
+    t.commit().await?;
+
+    Ok(LoadedTable {
+        warehouse,
+        table_info: Arc::unwrap_or_clone(table_info),
+        storage_permissions,
+        metadata: loaded.table_metadata,
+    })
+}
+
+/// Read the manifest list of a snapshot and return all of its manifests.
+pub(super) async fn read_manifest_list(
+    file_io: &StorageBackend,
+    manifest_list: &str,
+    metadata: &TableMetadata,
+) -> Result<Vec<ManifestFile>> {
+    let location = parse_location(manifest_list, StatusCode::INTERNAL_SERVER_ERROR)?;
+    let bytes = read_file(file_io, &location, CompressionCodec::None).await?;
+    let manifest_list = ManifestList::parse_with_version(&bytes, metadata.format_version())
+        .map_err(|e| {
+            ErrorModel::internal(
+                format!("Failed to parse manifest list {manifest_list}"),
+                "ManifestListParseError",
+                Some(Box::new(e)),
+            )
+        })?;
+    Ok(manifest_list.entries().to_vec())
+}
+
+/// Read and parse a manifest of a manifest list.
+pub(super) async fn read_manifest(
+    file_io: &StorageBackend,
+    manifest_file: &ManifestFile,
+) -> Result<Manifest> {
+    let manifest_location = parse_location(
+        &manifest_file.manifest_path,
+        StatusCode::INTERNAL_SERVER_ERROR,
+    )?;
+    let manifest_bytes = read_file(file_io, &manifest_location, CompressionCodec::None).await?;
+    Manifest::parse_avro(&manifest_bytes).map_err(|e| {
+        ErrorModel::internal(
+            format!("Failed to parse manifest {}", manifest_file.manifest_path),
+            "ManifestParseError",
+            Some(Box::new(e)),
+        )
+        .into()
+    })
+}
+
+/// Read the manifest list of a snapshot and return its data manifests.
+///
+/// Delete manifests are not supported yet. As applying them requires matching
+/// delete files to data files, snapshots containing any are rejected.
+async fn read_data_manifests(
+    file_io: &StorageBackend,
+    manifest_list: &str,
+    metadata: &TableMetadata,
+) -> Result<Vec<ManifestFile>> {
+    let manifests = read_manifest_list(file_io, manifest_list, metadata).await?;
+    if manifests
+        .iter()
+        .any(|manifest| matches!(manifest.content, ManifestContentType::Deletes))
+    {
+        return Err(ErrorModel::not_implemented(
+            "Scan planning is not supported for snapshots with delete files",
+            "ScanPlanningDeletesNotSupported",
+            None,
+        )
+        .into());
+    }
+    Ok(manifests)
+}
+
+fn content_file(
+    data_file: &DataFile,
+    spec_id: i32,
+    partition_field_types: &[&iceberg::spec::Type],
+) -> Result<ContentFile> {
+    let content = match data_file.content_type() {
+        DataContentType::Data => ContentFileType::Data,
+        DataContentType::PositionDeletes => ContentFileType::PositionDeletes,
+        DataContentType::EqualityDeletes => ContentFileType::EqualityDeletes,
+    };
+
+    Ok(ContentFile {
+        content,
+        file_path: data_file.file_path().to_string(),
+        file_format: data_file.file_format().to_string().to_lowercase(),
+        spec_id,
+        partition: partition_values(data_file.partition(), partition_field_types)?,
+        file_size_in_bytes: data_file.file_size_in_bytes(),
+        record_count: data_file.record_count(),
+    })
+}
+
+/// Serialize partition values in the order of the partition spec fields.
+fn partition_values(
+    partition: &Struct,
+    partition_field_types: &[&iceberg::spec::Type],
+) -> Result<Vec<serde_json::Value>> {
+    partition
+        .iter()
+        .zip(partition_field_types)
+        .map(|(value, field_type)| {
+            value
+                .cloned()
+                .map_or(Ok(serde_json::Value::Null), |literal| {
+                    literal.try_into_json(field_type)
+                })
+                .map_err(|e| {
+                    ErrorModel::internal(
+                        "Failed to serialize partition value",
+                        "PartitionValueSerializationError",
+                        Some(Box::new(e)),
+                    )
+                    .into()
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_task_round_trip() {
+        let plan_task = PlanTask {
+            snapshot_id: 42,
+            manifest_path: "s3://bucket/table/metadata/manifest-1.avro".to_string(),
+        };
+        let encoded = plan_task.encode().unwrap();
+        assert_eq!(PlanTask::decode(&encoded).unwrap(), plan_task);
+    }
+
+    #[test]
+    fn test_foreign_plan_task_is_rejected() {
+        let err = PlanTask::decode("not-a-plan-task").unwrap_err();
+        assert_eq!(err.error.r#type, "InvalidPlanTask");
+    }
+}
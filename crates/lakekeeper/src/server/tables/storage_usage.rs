@@ -0,0 +1,112 @@
+//! Storage usage of a table, i.e. the total size of the data and delete files
+//! referenced by its current snapshot.
+//!
+//! Snapshot summaries written by most engines already contain the totals.
+//! If they are missing, the manifests of the snapshot are read and the sizes
+//! of all live files are summed up. Results are cached per snapshot for a
+//! short time to avoid reading the manifests on every request.
+use std::{sync::LazyLock, time::Duration};
+
+use http::StatusCode;
+use iceberg::spec::{Snapshot, TableMetadata};
+
+use super::{
+    parse_location,
+    scan_planning::{read_manifest, read_manifest_list},
+};
+use crate::{
+    WarehouseId,
+    api::{iceberg::v1::Result, management::v1::table::TableStorageUsageResponse},
+    server::maybe_get_secret,
+    service::{ResolvedWarehouse, TableId, secrets::SecretStore},
+};
+
+const TOTAL_FILES_SIZE_PROP: &str = "total-files-size";
+const TOTAL_DATA_FILES_PROP: &str = "total-data-files";
+const TOTAL_DELETE_FILES_PROP: &str = "total-delete-files";
+
+const STORAGE_USAGE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Storage usage by warehouse, table and snapshot.
+static STORAGE_USAGE_CACHE: LazyLock<
+    moka::future::Cache<(WarehouseId, TableId, i64), TableStorageUsageResponse>,
+> = LazyLock::new(|| {
+    moka::future::Cache::builder()
+        .max_capacity(10000)
+        .time_to_live(STORAGE_USAGE_CACHE_TTL)
+        .build()
+});
+
+/// Storage usage of the current snapshot of a table.
+pub(crate) async fn table_storage_usage<S: SecretStore>(
+    warehouse: &ResolvedWarehouse,
+    table_id: TableId,
+    metadata: &TableMetadata,
+    secrets: &S,
+) -> Result<TableStorageUsageResponse> {
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(TableStorageUsageResponse {
+            snapshot_id: None,
+            total_bytes: 0,
+            file_count: 0,
+        });
+    };
+
+    let cache_key = (warehouse.warehouse_id, table_id, snapshot.snapshot_id());
+    if let Some(usage) = STORAGE_USAGE_CACHE.get(&cache_key).await {
+        return Ok(usage);
+    }
+
+    let usage = match usage_from_summary(snapshot) {
+        Some(usage) => usage,
+        None => usage_from_manifests(warehouse, snapshot, metadata, secrets).await?,
+    };
+    STORAGE_USAGE_CACHE.insert(cache_key, usage.clone()).await;
+    Ok(usage)
+}
+
+/// Storage usage as recorded in the snapshot summary, if all totals are present.
+fn usage_from_summary(snapshot: &Snapshot) -> Option<TableStorageUsageResponse> {
+    let properties = &snapshot.summary().additional_properties;
+    let total = |key: &str| {
+        properties
+            .get(key)
+            .and_then(|value| value.parse::<u64>().ok())
+    };
+
+    Some(TableStorageUsageResponse {
+        snapshot_id: Some(snapshot.snapshot_id()),
+        total_bytes: total(TOTAL_FILES_SIZE_PROP)?,
+        file_count: total(TOTAL_DATA_FILES_PROP)?.checked_add(total(TOTAL_DELETE_FILES_PROP)?)?,
+    })
+}
+
+/// Sum up the sizes of all live data and delete files in the manifests of a snapshot.
+async fn usage_from_manifests<S: SecretStore>(
+    warehouse: &ResolvedWarehouse,
+    snapshot: &Snapshot,
+    metadata: &TableMetadata,
+    secrets: &S,
+) -> Result<TableStorageUsageResponse> {
+    let table_location = parse_location(metadata.location(), StatusCode::INTERNAL_SERVER_ERROR)?;
+    let (storage_profile, storage_secret_id) =
+        warehouse.storage(warehouse.storage_slot_of_location(&table_location))?;
+    let storage_secret = maybe_get_secret(storage_secret_id, secrets).await?;
+    let file_io = storage_profile.file_io(storage_secret.as_deref()).await?;
+
+    let mut total_bytes = 0_u64;
+    let mut file_count = 0_u64;
+    for manifest_file in read_manifest_list(&file_io, snapshot.manifest_list(), metadata).await? {
+        let manifest = read_manifest(&file_io, &manifest_file).await?;
+        for entry in manifest.entries().iter().filter(|entry| entry.is_alive()) {
+            total_bytes = total_bytes.saturating_add(entry.data_file().file_size_in_bytes());
+            file_count = file_count.saturating_add(1);
+        }
+    }
+
+    Ok(TableStorageUsageResponse {
+        snapshot_id: Some(snapshot.snapshot_id()),
+        total_bytes,
+        file_count,
+    })
+}
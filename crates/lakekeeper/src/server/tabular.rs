@@ -1,7 +1,8 @@
 use crate::{
+    CONFIG,
     server::tables::parse_location,
     service::{
-        NamespaceHierarchy, TabularId,
+        NamespaceHierarchy, NamespaceWithParent, StorageProfileSlot, TabularId,
         storage::{
             StorageProfile,
             storage_layout::{NamespaceNameContext, NamespacePath, TabularNameContext},
@@ -9,12 +10,88 @@ use crate::{
     },
 };
 
+/// Require that a derived default `location` lies below the base location of
+/// the warehouse's storage profile, so that credentials are never vended for
+/// storage outside of the warehouse.
+fn ensure_location_in_warehouse(
+    storage_profile: &StorageProfile,
+    location: &Location,
+) -> Result<(), ErrorModel> {
+    if storage_profile.is_allowed_location(location) {
+        return Ok(());
+    }
+
+    let base_location = storage_profile
+        .base_location()
+        .ok()
+        .map_or(String::new(), |l| l.to_string());
+    Err(ErrorModel::bad_request(
+        format!(
+            "Location {location} is outside of the warehouse storage root {base_location}. This warehouse requires tabulars to be located below its storage root."
+        ),
+        "LocationOutsideWarehouse",
+        None,
+    ))
+}
+
+/// Parse the [`StorageProfileSlot::PROPERTY`] property of `properties`, if set.
+pub(crate) fn parse_storage_profile_slot(
+    properties: Option<&HashMap<String, String>>,
+) -> Result<Option<StorageProfileSlot>, ErrorModel> {
+    properties
+        .and_then(|p| p.get(StorageProfileSlot::PROPERTY))
+        .map(|value| {
+            StorageProfileSlot::from_str(value).map_err(|_| {
+                ErrorModel::bad_request(
+                    format!(
+                        "Invalid value `{value}` for property `{}`. Expected one of: {}",
+                        StorageProfileSlot::PROPERTY,
+                        StorageProfileSlot::iter().join(", ")
+                    ),
+                    "InvalidStorageProfileSlot",
+                    None,
+                )
+            })
+        })
+        .transpose()
+}
+
+/// Storage profile slot of an existing namespace.
+///
+/// The property is validated when the namespace is created and cannot be
+/// changed afterwards.
+pub(crate) fn namespace_storage_profile_slot(
+    namespace: &NamespaceWithParent,
+) -> StorageProfileSlot {
+    parse_storage_profile_slot(namespace.properties())
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Storage profile slot of a new tabular. The property of the tabular takes
+/// precedence over the slot of its namespace.
+pub(crate) fn determine_storage_profile_slot(
+    namespace_hierarchy: &NamespaceHierarchy,
+    properties: Option<&HashMap<String, String>>,
+) -> Result<StorageProfileSlot, ErrorModel> {
+    Ok(parse_storage_profile_slot(properties)?
+        .unwrap_or_else(|| namespace_storage_profile_slot(&namespace_hierarchy.namespace)))
+}
+
+/// Determine the location of a new tabular.
+///
+/// The location of the namespace is only used as a base if
+/// `inherit_namespace_location` is set, which is the case if the tabular is
+/// stored on the same storage profile as its namespace.
 pub(super) fn determine_tabular_location(
     namespace_hierarchy: &NamespaceHierarchy,
     request_table_location: Option<String>,
     table_id: TabularId,
     table_ident: &TableIdent,
     storage_profile: &StorageProfile,
+    inherit_namespace_location: bool,
+    require_location_in_warehouse: bool,
 ) -> Result<Location, ErrorModel> {
     let namespace = &namespace_hierarchy.namespace;
     let request_table_location = request_table_location
@@ -29,15 +106,19 @@ pub(super) fn determine_tabular_location(
             namespace.namespace.properties.clone().unwrap_or_default(),
         );
 
-        let namespace_location = if let Some(location) = namespace_props.get_location() {
+        let mut namespace_name_contexts = vec![NamespaceNameContext::try_from(namespace)?];
+        for ancestor in &namespace_hierarchy.parents {
+            namespace_name_contexts.push(NamespaceNameContext::try_from(ancestor)?);
+        }
+        namespace_name_contexts.reverse();
+        let namespace_path = NamespacePath::new(namespace_name_contexts);
+
+        let namespace_location = if let Some(location) = namespace_props
+            .get_location()
+            .filter(|_| inherit_namespace_location)
+        {
             location
         } else {
-            let mut namespace_name_contexts = vec![NamespaceNameContext::try_from(namespace)?];
-            for ancestor in &namespace_hierarchy.parents {
-                namespace_name_contexts.push(NamespaceNameContext::try_from(ancestor)?);
-            }
-            namespace_name_contexts.reverse();
-            let namespace_path = NamespacePath::new(namespace_name_contexts);
             storage_profile
                 .default_namespace_location(&namespace_path)
                 .map_err(|e| {
@@ -54,10 +135,21 @@ pub(super) fn determine_tabular_location(
             uuid: *table_id,
         };
 
-        storage_profile.default_tabular_location(&namespace_location, &table_name_context)
+        let location = storage_profile.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &table_name_context,
+        );
+        // Default locations derive from the namespace location, which may
+        // predate a change of the storage profile.
+        if require_location_in_warehouse {
+            ensure_location_in_warehouse(storage_profile, &location)?;
+        }
+        location
     };
-    // all locations are without a trailing slash
-    location.without_trailing_slash();
+    CONFIG
+        .tabular_location_trailing_slash
+        .canonicalize(&mut location);
     Ok(location)
 }
 
@@ -177,8 +269,12 @@ macro_rules! list_entities {
     };
 }
 
+use std::{collections::HashMap, str::FromStr as _};
+
 use http::StatusCode;
 use iceberg::TableIdent;
 use iceberg_ext::{catalog::rest::ErrorModel, configs::namespace::NamespaceProperties};
+use itertools::Itertools as _;
 use lakekeeper_io::Location;
 pub(crate) use list_entities;
+use strum::IntoEnumIterator as _;
@@ -34,7 +34,7 @@ use crate::{
     service::{
         AuthZViewInfo, CONCURRENT_UPDATE_ERROR_TYPE, CatalogIdempotencyOps, CatalogStore,
         CatalogView, CatalogViewOps, InternalParseLocationError, State, TabularListFlags,
-        Transaction, ViewCommit, ViewId, ViewInfo,
+        Transaction, ViewCommit, ViewId, ViewInfo, WarehouseCredentialMode,
         authz::{AuthZViewOps, Authorizer, CatalogViewAction},
         contract_verification::ContractVerification,
         events::{APIEventContext, ViewEventTransition, context::ResolvedView},
@@ -142,6 +142,7 @@ pub async fn commit_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
                 view_info: &event_ctx.resolved().view,
                 storage_profile,
                 storage_secret_id,
+                credential_mode: warehouse.settings.credential_mode,
                 request: request.as_ref(),
                 data_access,
             },
@@ -183,6 +184,7 @@ struct CommitViewContext<'a> {
     view_info: &'a ViewInfo,
     storage_profile: &'a StorageProfile,
     storage_secret_id: Option<SecretId>,
+    credential_mode: WarehouseCredentialMode,
     request: &'a CommitViewRequest,
     data_access: DataAccessMode,
 }
@@ -292,6 +294,7 @@ async fn try_commit_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         .storage_profile
         .generate_table_config(
             ctx.data_access,
+            ctx.credential_mode,
             storage_secret_ref,
             &new_view.metadata_location,
             StoragePermissions::ReadWriteDelete,
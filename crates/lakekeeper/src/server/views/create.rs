@@ -14,12 +14,12 @@ use crate::{
         io::write_file,
         maybe_get_secret, require_warehouse_id,
         tables::{require_active_warehouse, validate_table_or_view_ident},
-        tabular::determine_tabular_location,
+        tabular::{determine_tabular_location, namespace_storage_profile_slot},
         views::{commit::validate_trusted_engine_properties_on_create, validate_view_properties},
     },
     service::{
-        CachePolicy, CatalogStore, CatalogViewOps, Result, SecretStore, State, TabularId,
-        Transaction, ViewId,
+        CachePolicy, CatalogStore, CatalogViewOps, Result, SecretStore, State, StorageProfileSlot,
+        TabularId, Transaction, ViewId,
         authz::{Authorizer, AuthzNamespaceOps, CatalogNamespaceAction},
         events::{
             APIEventContext,
@@ -108,6 +108,9 @@ pub async fn create_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         view_id,
         &view,
         &warehouse.storage_profile,
+        // Views and generic tables are always stored on the primary storage profile.
+        namespace_storage_profile_slot(&ns_hierarchy.namespace) == StorageProfileSlot::Primary,
+        warehouse.settings.require_location_in_warehouse,
     )?;
 
     // Update the request for event
@@ -182,6 +185,7 @@ pub async fn create_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>
         .storage_profile
         .generate_table_config(
             data_access,
+            warehouse.settings.credential_mode,
             storage_secret_ref,
             &view_location,
             StoragePermissions::Read,
@@ -13,8 +13,9 @@ use crate::{
     request_metadata::RequestMetadata,
     server::{require_warehouse_id, tables::validate_table_or_view_ident},
     service::{
-        AuthZViewInfo as _, CatalogIdempotencyOps, CatalogStore, CatalogTabularOps, NamedEntity,
-        Result, SecretStore, State, TabularId, TabularListFlags, Transaction,
+        AuthZViewInfo as _, CatalogIdempotencyOps, CatalogNamespaceOps, CatalogStore,
+        CatalogTabularOps, NamedEntity, Result, SecretStore, State, TabularId, TabularListFlags,
+        Transaction,
         authz::{AuthZViewOps, Authorizer, CatalogViewAction},
         contract_verification::ContractVerification,
         events::{APIEventContext, context::ResolvedView},
@@ -79,6 +80,7 @@ pub async fn drop_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     let (event_ctx, (warehouse, _namespace, view_info)) = event_ctx.emit_authz(authz_context)?;
 
     let view_id = view_info.view_id();
+    let namespace_id = view_info.namespace_id;
     let event_ctx = event_ctx.resolve(ResolvedView {
         warehouse: warehouse.clone(),
         view: Arc::new(view_info),
@@ -97,7 +99,9 @@ pub async fn drop_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
     let delete_profile = if force {
         TabularDeleteProfile::Hard {}
     } else {
-        warehouse.tabular_delete_profile
+        C::get_namespace_delete_profile(warehouse_id, namespace_id, t.transaction())
+            .await?
+            .unwrap_or(warehouse.tabular_delete_profile)
     };
     let project_id = &warehouse.project_id;
 
@@ -114,6 +114,7 @@ pub async fn load_view<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
         .storage_profile
         .generate_table_config(
             data_access,
+            warehouse.settings.credential_mode,
             storage_secret_ref,
             &view_location,
             storage_permissions.unwrap_or(StoragePermissions::Read),
@@ -0,0 +1,231 @@
+//! Cache of successful token authentications.
+//!
+//! Validating a bearer token may require a round-trip to the identity provider
+//! (e.g. a Kubernetes `TokenReview`) and always requires signature verification.
+//! Clients typically send the same token for many consecutive requests, so the
+//! resulting [`limes::Authentication`] is cached keyed by the SHA-256 hash of
+//! the token. The raw token is never stored.
+//!
+//! Entries expire after `LAKEKEEPER__CACHE__AUTHENTICATION__TIME_TO_LIVE_SECS`
+//! or when the token's `exp` claim is reached, whichever comes first. A token
+//! revoked at the identity provider may therefore still be accepted for up to
+//! the configured TTL. Failed authentications are never cached.
+
+use std::{
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
+
+use axum_prometheus::metrics;
+use moka::{Expiry, future::Cache};
+use sha2::{Digest as _, Sha256};
+
+use crate::{
+    CONFIG,
+    service::{
+        authn::decode_jwt_claims,
+        cache_metrics::{
+            METRIC_CACHE_HITS_TOTAL, METRIC_CACHE_MISSES_TOTAL, METRIC_CACHE_SIZE,
+            METRICS_INITIALIZED,
+        },
+    },
+};
+
+type TokenHash = [u8; 32];
+
+pub(crate) static AUTHENTICATION_CACHE: LazyLock<TokenCache<limes::Authentication>> =
+    LazyLock::new(|| {
+        TokenCache::new(
+            CONFIG.cache.authentication.capacity,
+            Duration::from_secs(CONFIG.cache.authentication.time_to_live_secs),
+        )
+    });
+
+/// Authenticate `token` with `authenticate`, or return the cached result of a
+/// previous successful authentication of the same token.
+pub(crate) async fn authenticate_cached<E, Fut>(
+    token: &str,
+    authenticate: impl FnOnce() -> Fut,
+) -> Result<limes::Authentication, E>
+where
+    Fut: Future<Output = Result<limes::Authentication, E>>,
+{
+    if !CONFIG.cache.authentication.enabled {
+        return authenticate().await;
+    }
+    AUTHENTICATION_CACHE
+        .get_or_authenticate(token, authenticate)
+        .await
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CachedAuthentication<V> {
+    value: V,
+    expires_in: Duration,
+}
+
+/// Expires each entry after its own `expires_in`, which is bounded by the
+/// configured TTL and the token's `exp` claim.
+#[derive(Debug, Clone, Copy)]
+struct TokenExpiry;
+
+impl<V> Expiry<TokenHash, CachedAuthentication<V>> for TokenExpiry {
+    fn expire_after_create(
+        &self,
+        _key: &TokenHash,
+        value: &CachedAuthentication<V>,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(value.expires_in)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct TokenCache<V> {
+    cache: Cache<TokenHash, CachedAuthentication<V>>,
+    time_to_live: Duration,
+}
+
+impl<V: Clone + Send + Sync + 'static> TokenCache<V> {
+    pub(crate) fn new(capacity: u64, time_to_live: Duration) -> Self {
+        Self {
+            cache: Cache::builder()
+                .max_capacity(capacity)
+                .expire_after(TokenExpiry)
+                .build(),
+            time_to_live,
+        }
+    }
+
+    pub(crate) async fn get_or_authenticate<E, Fut>(
+        &self,
+        token: &str,
+        authenticate: impl FnOnce() -> Fut,
+    ) -> Result<V, E>
+    where
+        Fut: Future<Output = Result<V, E>>,
+    {
+        let key: TokenHash = Sha256::digest(token.as_bytes()).into();
+        let () = &*METRICS_INITIALIZED;
+
+        if let Some(cached) = self.cache.get(&key).await {
+            metrics::counter!(METRIC_CACHE_HITS_TOTAL, "cache_type" => "authentication")
+                .increment(1);
+            return Ok(cached.value);
+        }
+        metrics::counter!(METRIC_CACHE_MISSES_TOTAL, "cache_type" => "authentication").increment(1);
+
+        let value = authenticate().await?;
+        if let Some(expires_in) = self.expires_in(token) {
+            self.cache
+                .insert(
+                    key,
+                    CachedAuthentication {
+                        value: value.clone(),
+                        expires_in,
+                    },
+                )
+                .await;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        metrics::gauge!(METRIC_CACHE_SIZE, "cache_type" => "authentication")
+            .set(self.cache.entry_count() as f64);
+        Ok(value)
+    }
+
+    /// Time until a cached authentication of `token` expires. `None` if the
+    /// token is already expired and must not be cached.
+    fn expires_in(&self, token: &str) -> Option<Duration> {
+        let Some(exp) = decode_jwt_claims(token)
+            .and_then(|claims| claims.get("exp").and_then(serde_json::Value::as_i64))
+        else {
+            return Some(self.time_to_live);
+        };
+        let remaining = exp.checked_sub(chrono::Utc::now().timestamp())?;
+        let remaining = Duration::from_secs(u64::try_from(remaining).ok().filter(|s| *s > 0)?);
+        Some(remaining.min(self.time_to_live))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use base64::Engine as _;
+
+    use super::*;
+
+    fn token(exp: i64) -> String {
+        let encode = |value: serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value.to_string())
+        };
+        format!(
+            "{}.{}.signature",
+            encode(serde_json::json!({"alg": "RS256", "typ": "JWT"})),
+            encode(serde_json::json!({"sub": "alice", "exp": exp}))
+        )
+    }
+
+    async fn authenticate(
+        cache: &TokenCache<String>,
+        calls: &AtomicU32,
+        token: &str,
+    ) -> Result<String, ()> {
+        cache
+            .get_or_authenticate(token, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(format!("authenticated-{}", calls.load(Ordering::SeqCst)))
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_requests_within_ttl_authenticate_once() {
+        let cache = TokenCache::new(100, Duration::from_mins(1));
+        let calls = AtomicU32::new(0);
+        let token = token(chrono::Utc::now().timestamp() + 3600);
+
+        let first = authenticate(&cache, &calls, &token).await.unwrap();
+        let second = authenticate(&cache, &calls, &token).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first, second);
+
+        // A different token is authenticated separately
+        authenticate(&cache, &calls, "opaque-token").await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_authentication_is_not_cached() {
+        let cache = TokenCache::<String>::new(100, Duration::from_mins(1));
+        let calls = AtomicU32::new(0);
+        for _ in 0..2 {
+            let result: Result<String, ()> = cache
+                .get_or_authenticate("invalid", || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err(())
+                })
+                .await;
+            assert!(result.is_err());
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_expiry_respects_token_exp() {
+        let cache = TokenCache::<String>::new(100, Duration::from_mins(1));
+        let now = chrono::Utc::now().timestamp();
+
+        assert_eq!(
+            cache.expires_in(&token(now + 3600)),
+            Some(Duration::from_mins(1))
+        );
+        let expires_in = cache.expires_in(&token(now + 10)).unwrap();
+        assert!(expires_in <= Duration::from_secs(10));
+        assert_eq!(cache.expires_in(&token(now - 10)), None);
+        assert_eq!(
+            cache.expires_in("opaque-token"),
+            Some(Duration::from_mins(1))
+        );
+    }
+}
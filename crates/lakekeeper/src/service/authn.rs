@@ -29,6 +29,7 @@ use crate::{
         admission::{AdmissionContext, AdmissionGates, AdmissionRejection},
         authz::InstanceAdminMembership,
         events::EventDispatcher,
+        group_roles::{GroupRoleMapping, extract_token_groups},
     },
 };
 
@@ -72,6 +73,9 @@ pub(crate) struct AuthMiddlewareState<
     /// request after actor/instance-admin resolution and before the request
     /// reaches any handler. Empty by default (admits everything).
     pub admission_gates: AdmissionGates,
+    /// Mapping of token groups to roles assumed implicitly if no role is
+    /// assumed via [`ASSUME_ROLE_BY_ID_HEADER`]. Reloaded in the background.
+    pub group_role_mapping: Arc<GroupRoleMapping>,
 }
 
 #[derive(Hash, Debug, Clone, PartialEq, Eq)]
@@ -425,23 +429,28 @@ pub(crate) async fn auth_middleware_fn<
     mut request: Request,
     next: Next,
 ) -> Response {
-    use crate::service::authz::AuthZServerOps;
+    use crate::service::{authentication_cache::authenticate_cached, authz::AuthZServerOps};
 
     let authenticator = &state.authenticator;
     let authorizer = &state.authorizer;
     let catalog_state = state.catalog_state;
     let Some(authorization) = authorization else {
-        return ErrorModel::unauthorized(
-            "Missing Authorization Header",
-            "MissingAuthorizationHeader",
-            None,
-        )
-        .into_response();
+        return match admit_anonymous(
+            &CONFIG.anonymous_access,
+            request.extensions_mut().get_mut::<RequestMetadata>(),
+        ) {
+            Ok(()) => next.run(request).await,
+            Err(e) => e.into_response(),
+        };
     };
 
     let token = authorization.token();
-    let introspection = limes::introspect::introspect(token);
-    let authentication = match authenticator.authenticate(token, &introspection).await {
+    let authentication = match authenticate_cached(token, || async move {
+        let introspection = limes::introspect::introspect(token);
+        authenticator.authenticate(token, &introspection).await
+    })
+    .await
+    {
         Ok(principal) => principal,
         Err(e) => {
             return ErrorModel::unauthorized(
@@ -462,9 +471,30 @@ pub(crate) async fn auth_middleware_fn<
         Ok(role_id) => role_id,
         Err(e) => return e.into_response(),
     };
-    let actor = match resolve_actor::<C>(user_id, role_id, catalog_state).await {
-        Ok(actor) => actor,
-        Err(e) => return e,
+    let group_role_ids = group_mapped_role_ids(
+        token,
+        CONFIG.openid_groups_claim.as_deref(),
+        &state.group_role_mapping,
+    );
+    // An explicitly assumed role takes precedence over roles mapped from groups.
+    let group_role_id = if role_id.is_none() {
+        group_role_ids
+            .as_ref()
+            .and_then(|role_ids| role_ids.first().copied())
+    } else {
+        None
+    };
+    let actor = match group_role_id {
+        Some(group_role_id) => {
+            match resolve_group_actor::<C>(user_id, group_role_id, catalog_state).await {
+                Ok(actor) => actor,
+                Err(e) => return e,
+            }
+        }
+        None => match resolve_actor::<C>(user_id, role_id, catalog_state).await {
+            Ok(actor) => actor,
+            Err(e) => return e,
+        },
     };
 
     if let Some(request_metadata) = request.extensions_mut().get_mut::<RequestMetadata>() {
@@ -477,6 +507,9 @@ pub(crate) async fn auth_middleware_fn<
         }
 
         request_metadata.set_authentication(actor.clone(), authentication.clone());
+        if let Some(group_role_ids) = group_role_ids {
+            request_metadata.set_group_roles(group_role_ids);
+        }
 
         // Instance-admin membership is only ever consulted for an authenticated
         // principal. Assumed-roles (`Actor::Role`) and anonymous callers never
@@ -552,6 +585,10 @@ pub(crate) async fn auth_middleware_fn<
             event_ctx
                 .emit_authz(authorizer.check_actor(&actor, request_metadata).await)
                 .map(|_| ())
+        } else if group_role_id.is_some() {
+            // Membership in the mapped group is the grant for the role; the
+            // identity provider is authoritative, so no assume-role check.
+            Ok(())
         } else {
             authorizer
                 .check_actor(&actor, request_metadata)
@@ -616,6 +653,18 @@ pub(crate) async fn auth_middleware_fn<
     next.run(request).await
 }
 
+/// Decode the claims of a JWT without validating it. Only use on tokens that
+/// were already validated by an authenticator.
+pub(crate) fn decode_jwt_claims(token: &str) -> Option<serde_json::Value> {
+    use base64::Engine as _;
+
+    let payload = token.split('.').nth(1)?;
+    let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload)
+        .ok()?;
+    serde_json::from_slice(&payload).ok()
+}
+
 #[cfg(feature = "router")]
 fn extract_role_id(
     headers: &HeaderMap,
@@ -667,6 +716,88 @@ async fn resolve_actor<C: super::CatalogStore>(
     }
 }
 
+/// Admit a request without `Authorization` header if its endpoint is listed in
+/// `LAKEKEEPER__ANONYMOUS_ACCESS__ALLOW`. The request is performed as the
+/// configured principal; the authorizer layer limits it to the warehouses
+/// listed for the endpoint (see [`AnonymousAccessAuthorizer`](super::authz::AnonymousAccessAuthorizer)).
+#[cfg(feature = "router")]
+fn admit_anonymous(
+    anonymous_access: &crate::config::AnonymousAccess,
+    request_metadata: Option<&mut RequestMetadata>,
+) -> Result<(), ErrorModel> {
+    let missing_header = || {
+        ErrorModel::unauthorized(
+            "Missing Authorization Header",
+            "MissingAuthorizationHeader",
+            None,
+        )
+    };
+    let Some(request_metadata) = request_metadata else {
+        return Err(missing_header());
+    };
+    let Some(endpoint) = request_metadata.matched_path().and_then(|path| {
+        api::endpoints::Endpoint::from_method_and_matched_path(
+            request_metadata.request_method(),
+            path,
+        )
+    }) else {
+        return Err(missing_header());
+    };
+    let warehouses = anonymous_access.warehouses_for(endpoint.into());
+    if warehouses.is_empty() {
+        return Err(missing_header());
+    }
+    request_metadata.set_anonymous_access(anonymous_access.principal.clone(), warehouses);
+    Ok(())
+}
+
+/// Roles mapped from the groups listed in `groups_claim` of `token`, in
+/// mapping order. `None` if no groups claim is configured.
+#[cfg(feature = "router")]
+fn group_mapped_role_ids(
+    token: &str,
+    groups_claim: Option<&str>,
+    mapping: &GroupRoleMapping,
+) -> Option<Vec<super::RoleId>> {
+    let groups_claim = groups_claim?;
+    if mapping.is_empty() {
+        return Some(vec![]);
+    }
+    Some(mapping.roles_for_groups(&extract_token_groups(token, groups_claim)))
+}
+
+/// Resolve the actor for a role mapped from the token's groups. If the role
+/// does not exist (e.g. it was deleted but is still referenced by the mapping),
+/// the request continues as the principal itself.
+#[cfg(feature = "router")]
+async fn resolve_group_actor<C: super::CatalogStore>(
+    user_id: UserId,
+    role_id: super::RoleId,
+    catalog_state: C::State,
+) -> Result<Actor, Response> {
+    use crate::service::{CatalogRoleOps, GetRoleAcrossProjectsError};
+
+    match C::get_role_by_id_across_projects_cache_aware(
+        role_id,
+        crate::service::CachePolicy::Use,
+        catalog_state,
+    )
+    .await
+    {
+        Ok(role) => Ok(Actor::Role {
+            principal: user_id,
+            assumed_role: role,
+        }),
+        Err(GetRoleAcrossProjectsError::RoleIdNotFound(_)) => {
+            tracing::warn!(
+                "Role {role_id} mapped from the groups of {user_id} does not exist. Check the group role mapping."
+            );
+            Ok(Actor::Principal(user_id))
+        }
+        Err(e) => Err(ErrorModel::from(e).into_response()),
+    }
+}
+
 #[cfg(feature = "router")]
 fn extract_and_set_token_roles(
     authentication: &limes::Authentication,
@@ -1377,4 +1508,85 @@ mod tests {
         let role_id = extract_role_id(&headers).unwrap().unwrap();
         assert_eq!(role_id, RoleId::new(this_role_id));
     }
+
+    #[test]
+    fn test_token_with_mapped_group_holds_role() {
+        use base64::Engine as _;
+
+        use crate::service::group_roles::GroupRoleRule;
+
+        let encode = |value: serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value.to_string())
+        };
+        let token = format!(
+            "{}.{}.signature",
+            encode(json!({"alg": "RS256", "typ": "JWT"})),
+            encode(json!({"sub": "alice", "realm_access": {"groups": ["analysts", "engineers"]}}))
+        );
+
+        let engineers = RoleId::new_random();
+        let mapping = GroupRoleMapping::new(vec![GroupRoleRule {
+            group: "engineers".to_string(),
+            role_id: engineers,
+        }]);
+        assert_eq!(
+            group_mapped_role_ids(&token, Some("realm_access.groups"), &mapping),
+            Some(vec![engineers])
+        );
+        // Without a configured claim, groups are ignored
+        assert_eq!(group_mapped_role_ids(&token, None, &mapping), None);
+
+        // Reloaded mappings apply to subsequent requests
+        mapping.replace(vec![GroupRoleRule {
+            group: "admins".to_string(),
+            role_id: RoleId::new_random(),
+        }]);
+        assert_eq!(
+            group_mapped_role_ids(&token, Some("realm_access.groups"), &mapping),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_admit_anonymous() {
+        use crate::{
+            WarehouseId,
+            api::{
+                RequestMetadataTestBuilder,
+                endpoints::{CatalogV1Endpoint, EndpointFlat},
+            },
+            config::{AnonymousAccess, AnonymousAccessRule},
+        };
+
+        let public = WarehouseId::new_random();
+        let anonymous_access = AnonymousAccess {
+            allow: vec![AnonymousAccessRule {
+                endpoint: EndpointFlat::CatalogV1LoadTable,
+                warehouse_id: public,
+            }],
+            ..AnonymousAccess::default()
+        };
+        let request = |endpoint: CatalogV1Endpoint| {
+            RequestMetadataTestBuilder::builder()
+                .matched_path(Arc::<str>::from(endpoint.path()))
+                .request_method(endpoint.method())
+                .build()
+        };
+
+        // A listed endpoint is performed as the configured principal
+        let mut metadata = request(CatalogV1Endpoint::LoadTable);
+        admit_anonymous(&anonymous_access, Some(&mut metadata)).unwrap();
+        assert_eq!(
+            metadata.actor(),
+            &Actor::Principal(anonymous_access.principal.clone())
+        );
+        assert_eq!(metadata.anonymous_access(), Some([public].as_slice()));
+
+        // Unlisted endpoints still require authentication
+        let mut metadata = request(CatalogV1Endpoint::ListNamespaces);
+        let err = admit_anonymous(&anonymous_access, Some(&mut metadata)).unwrap_err();
+        assert_eq!(err.code, http::StatusCode::UNAUTHORIZED.as_u16());
+        assert_eq!(metadata.actor(), &Actor::Anonymous);
+        assert_eq!(metadata.anonymous_access(), None);
+    }
 }
@@ -0,0 +1,73 @@
+//! Anonymous-access authorization: restrictions for requests without token
+//! that were admitted via `LAKEKEEPER__ANONYMOUS_ACCESS__ALLOW`.
+//!
+//! The authn middleware only admits such requests for listed endpoints and
+//! performs them as the configured principal, whose grants are decided by the
+//! pluggable resource [`Authorizer`]. On top of those grants, this layer
+//! enforces the allowlist itself, independent of the authorizer backend:
+//!
+//! * **Warehouses** — only the warehouses listed for the called endpoint are
+//!   accessible. All warehouse, namespace, table, view and generic-table
+//!   actions on other warehouses are denied.
+//! * **Read-only** — writing table or generic-table data is always denied, so
+//!   vended storage credentials never permit writes.
+//!
+//! The admitted warehouses are resolved once in authn and carried on
+//! [`RequestMetadata`]; this layer is therefore stateless.
+//!
+//! [`Authorizer`]: crate::service::authz::Authorizer
+
+use crate::{WarehouseId, request_metadata::RequestMetadata};
+
+/// The anonymous-access authority. Stateless — the admitted warehouses are
+/// resolved in authn and carried on [`RequestMetadata::anonymous_access`].
+#[derive(Debug, Clone, Copy)]
+pub struct AnonymousAccessAuthorizer;
+
+impl AnonymousAccessAuthorizer {
+    /// Whether the caller may access `warehouse_id`. Always `true` for requests
+    /// that were not admitted anonymously.
+    #[must_use]
+    pub fn allows_warehouse(metadata: &RequestMetadata, warehouse_id: WarehouseId) -> bool {
+        metadata
+            .anonymous_access()
+            .is_none_or(|warehouses| warehouses.contains(&warehouse_id))
+    }
+
+    /// Whether the caller may write table data. `false` for requests that were
+    /// admitted anonymously.
+    #[must_use]
+    pub fn allows_data_writes(metadata: &RequestMetadata) -> bool {
+        metadata.anonymous_access().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{api::RequestMetadataTestBuilder, service::UserId};
+
+    #[test]
+    fn authenticated_request_is_not_restricted() {
+        let md = RequestMetadata::test_user(UserId::new_unchecked("oidc", "alice"));
+        assert!(AnonymousAccessAuthorizer::allows_warehouse(
+            &md,
+            WarehouseId::new_random()
+        ));
+        assert!(AnonymousAccessAuthorizer::allows_data_writes(&md));
+    }
+
+    #[test]
+    fn anonymous_request_is_limited_to_listed_warehouses() {
+        let public = WarehouseId::new_random();
+        let md = RequestMetadataTestBuilder::builder()
+            .anonymous_access(vec![public])
+            .build();
+        assert!(AnonymousAccessAuthorizer::allows_warehouse(&md, public));
+        assert!(!AnonymousAccessAuthorizer::allows_warehouse(
+            &md,
+            WarehouseId::new_random()
+        ));
+        assert!(!AnonymousAccessAuthorizer::allows_data_writes(&md));
+    }
+}
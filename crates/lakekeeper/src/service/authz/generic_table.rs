@@ -13,11 +13,11 @@ use crate::{
         ResolvedWarehouse, SerializationError, TabularId, TabularListFlags, TabularNotFound,
         UnexpectedTabularInResponse, ViewOrTableInfo,
         authz::{
-            ActionOnGenericTable, AuthZError, AuthorizationBackendUnavailable,
-            AuthorizationCountMismatch, AuthorizationDecision, Authorizer, AuthzBadRequest,
-            AuthzNamespaceOps, AuthzWarehouseOps, BackendUnavailableOrCountMismatch,
-            CannotInspectPermissions, CatalogAction, CatalogGenericTableAction,
-            IsAllowedActionError, MustUse, UserOrRole,
+            ActionOnGenericTable, AnonymousAccessAuthorizer, AuthZError,
+            AuthorizationBackendUnavailable, AuthorizationCountMismatch, AuthorizationDecision,
+            Authorizer, AuthzBadRequest, AuthzNamespaceOps, AuthzWarehouseOps,
+            BackendUnavailableOrCountMismatch, CannotInspectPermissions, CatalogAction,
+            CatalogGenericTableAction, IsAllowedActionError, MustUse, UserOrRole,
         },
         events::{
             AuthorizationFailureReason, AuthorizationFailureSource,
@@ -383,6 +383,14 @@ pub trait AuthZGenericTableOps: Authorizer {
             ActionOnGenericTable<'_, '_, impl AuthZGenericTableInfo, A>,
         )],
     ) -> Result<MustUse<Vec<AuthorizationDecision>>, IsAllowedActionError> {
+        // Requests admitted anonymously may only access the allowlisted warehouses.
+        if !AnonymousAccessAuthorizer::allows_warehouse(metadata, warehouse.warehouse_id) {
+            return Ok(MustUse::from(vec![
+                AuthorizationDecision::deny();
+                actions.len()
+            ]));
+        }
+
         #[cfg(debug_assertions)]
         {
             let namespaces: Vec<&NamespaceWithParent> = actions.iter().map(|(ns, _)| *ns).collect();
@@ -406,6 +414,14 @@ pub trait AuthZGenericTableOps: Authorizer {
                 continue;
             }
 
+            // Requests admitted anonymously are read-only.
+            if action.action == A::from(CatalogGenericTableAction::WriteData)
+                && !AnonymousAccessAuthorizer::allows_data_writes(metadata)
+            {
+                auto_approved.push(Some(false));
+                continue;
+            }
+
             // Normalize user: if it's the actor itself, treat as None (acting as self).
             let normalized_user = if metadata.actor().to_user_or_role().as_ref() == action.user {
                 None
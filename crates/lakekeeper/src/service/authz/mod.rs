@@ -31,6 +31,8 @@ pub mod implementations;
 pub use error::*;
 mod instance_admin;
 pub use instance_admin::*;
+mod anonymous_access;
+pub use anonymous_access::*;
 mod warehouse;
 pub use implementations::allow_all::AllowAllAuthorizer;
 pub use warehouse::*;
@@ -472,8 +474,10 @@ pub enum CatalogProjectAction {
     GetTaskQueueConfig,
     GetProjectTasks,
     ControlProjectTasks,
+    ReadAuditLog,
+    DeleteEndpointStatistics,
 }
-static PROJECT_ACTION_VARIANTS: LazyLock<[CatalogProjectAction; 14]> = LazyLock::new(|| {
+static PROJECT_ACTION_VARIANTS: LazyLock<[CatalogProjectAction; 16]> = LazyLock::new(|| {
     [
         CatalogProjectAction::CreateWarehouse { name: None },
         CatalogProjectAction::Delete,
@@ -489,11 +493,13 @@ static PROJECT_ACTION_VARIANTS: LazyLock<[CatalogProjectAction; 14]> = LazyLock:
         CatalogProjectAction::GetTaskQueueConfig,
         CatalogProjectAction::GetProjectTasks,
         CatalogProjectAction::ControlProjectTasks,
+        CatalogProjectAction::ReadAuditLog,
+        CatalogProjectAction::DeleteEndpointStatistics,
     ]
 });
 impl CatalogProjectAction {
     #[must_use]
-    pub fn variants() -> &'static [CatalogProjectAction; 14] {
+    pub fn variants() -> &'static [CatalogProjectAction; 16] {
         &PROJECT_ACTION_VARIANTS
     }
 }
@@ -661,9 +667,10 @@ pub enum CatalogWarehouseAction {
     ControlAllTasks,
     SetProtection,
     SetFormatVersionPolicy,
+    ModifySettings,
     GetEndpointStatistics,
 }
-static WAREHOUSE_ACTION_VARIANTS: LazyLock<[CatalogWarehouseAction; 22]> = LazyLock::new(|| {
+static WAREHOUSE_ACTION_VARIANTS: LazyLock<[CatalogWarehouseAction; 23]> = LazyLock::new(|| {
     [
         CatalogWarehouseAction::CreateNamespace {
             name: None,
@@ -689,12 +696,13 @@ static WAREHOUSE_ACTION_VARIANTS: LazyLock<[CatalogWarehouseAction; 22]> = LazyL
         CatalogWarehouseAction::ControlAllTasks,
         CatalogWarehouseAction::SetProtection,
         CatalogWarehouseAction::SetFormatVersionPolicy,
+        CatalogWarehouseAction::ModifySettings,
         CatalogWarehouseAction::GetEndpointStatistics,
     ]
 });
 impl CatalogWarehouseAction {
     #[must_use]
-    pub fn variants() -> &'static [CatalogWarehouseAction; 22] {
+    pub fn variants() -> &'static [CatalogWarehouseAction; 23] {
         &WAREHOUSE_ACTION_VARIANTS
     }
 
@@ -717,7 +725,8 @@ impl CatalogWarehouseAction {
             | CatalogWarehouseAction::Rename
             | CatalogWarehouseAction::ModifySoftDeletion
             | CatalogWarehouseAction::SetProtection
-            | CatalogWarehouseAction::SetFormatVersionPolicy => true,
+            | CatalogWarehouseAction::SetFormatVersionPolicy
+            | CatalogWarehouseAction::ModifySettings => true,
             // `ModifyTaskQueueConfig` is intentionally NOT locked in v1: it is an
             // operational knob (retention/expiry tuning) rather than part of the
             // storage/identity spec an operator reconciles, and its write goes
@@ -1283,6 +1292,8 @@ pub enum CatalogProjectActionKind {
     GetTaskQueueConfig,
     GetProjectTasks,
     ControlProjectTasks,
+    ReadAuditLog,
+    DeleteEndpointStatistics,
 }
 impl From<&CatalogProjectAction> for CatalogProjectActionKind {
     fn from(action: &CatalogProjectAction) -> Self {
@@ -1301,6 +1312,8 @@ impl From<&CatalogProjectAction> for CatalogProjectActionKind {
             CatalogProjectAction::GetTaskQueueConfig => Self::GetTaskQueueConfig,
             CatalogProjectAction::GetProjectTasks => Self::GetProjectTasks,
             CatalogProjectAction::ControlProjectTasks => Self::ControlProjectTasks,
+            CatalogProjectAction::ReadAuditLog => Self::ReadAuditLog,
+            CatalogProjectAction::DeleteEndpointStatistics => Self::DeleteEndpointStatistics,
         }
     }
 }
@@ -1358,6 +1371,7 @@ pub enum CatalogWarehouseActionKind {
     ControlAllTasks,
     SetProtection,
     SetFormatVersionPolicy,
+    ModifySettings,
     GetEndpointStatistics,
 }
 impl From<&CatalogWarehouseAction> for CatalogWarehouseActionKind {
@@ -1384,6 +1398,7 @@ impl From<&CatalogWarehouseAction> for CatalogWarehouseActionKind {
             CatalogWarehouseAction::ControlAllTasks => Self::ControlAllTasks,
             CatalogWarehouseAction::SetProtection => Self::SetProtection,
             CatalogWarehouseAction::SetFormatVersionPolicy => Self::SetFormatVersionPolicy,
+            CatalogWarehouseAction::ModifySettings => Self::ModifySettings,
             CatalogWarehouseAction::GetEndpointStatistics => Self::GetEndpointStatistics,
         }
     }
@@ -1819,6 +1834,16 @@ where
         namespace_id: NamespaceId,
     ) -> Result<()>;
 
+    /// Hook that is called when a namespace is moved to a different parent.
+    /// This is used to update the hierarchy of the namespace.
+    async fn move_namespace(
+        &self,
+        metadata: &RequestMetadata,
+        namespace_id: NamespaceId,
+        previous_parent: NamespaceParent,
+        new_parent: NamespaceParent,
+    ) -> Result<()>;
+
     /// Hook that is called when a new table is created.
     /// This is used to set up the initial permissions for the table.
     async fn create_table(
@@ -1908,6 +1933,7 @@ pub mod tests {
             A::ModifySoftDeletion,
             A::SetProtection,
             A::SetFormatVersionPolicy,
+            A::ModifySettings,
         ] {
             assert!(a.is_spec_mutation(), "{a:?} should be a spec mutation");
         }
@@ -2974,6 +3000,16 @@ pub mod tests {
             Ok(())
         }
 
+        async fn move_namespace(
+            &self,
+            _metadata: &RequestMetadata,
+            _namespace_id: NamespaceId,
+            _previous_parent: NamespaceParent,
+            _new_parent: NamespaceParent,
+        ) -> Result<()> {
+            Ok(())
+        }
+
         async fn create_table(
             &self,
             _metadata: &RequestMetadata,
@@ -12,11 +12,11 @@ use crate::{
         NamespaceHierarchy, NamespaceId, NamespaceIdentOrId, NamespaceNotFound,
         NamespaceWithParent, ResolvedWarehouse, SerializationError,
         authz::{
-            AuthZError, AuthorizationBackendUnavailable, AuthorizationCountMismatch,
-            AuthorizationDecision, Authorizer, AuthzBadRequest, AuthzWarehouseOps as _,
-            BackendUnavailableOrCountMismatch, CannotInspectPermissions, CatalogAction,
-            CatalogNamespaceAction, IsAllowedActionError, MustUse, RequireWarehouseActionError,
-            UserOrRole,
+            AnonymousAccessAuthorizer, AuthZError, AuthorizationBackendUnavailable,
+            AuthorizationCountMismatch, AuthorizationDecision, Authorizer, AuthzBadRequest,
+            AuthzWarehouseOps as _, BackendUnavailableOrCountMismatch, CannotInspectPermissions,
+            CatalogAction, CatalogNamespaceAction, IsAllowedActionError, MustUse,
+            RequireWarehouseActionError, UserOrRole,
         },
         events::{
             AuthorizationFailureReason, AuthorizationFailureSource, context::UserProvidedNamespace,
@@ -507,6 +507,13 @@ pub trait AuthzNamespaceOps: Authorizer {
         if metadata.actor().to_user_or_role().as_ref() == for_user {
             for_user = None;
         }
+        // Requests admitted anonymously may only access the allowlisted warehouses.
+        if !AnonymousAccessAuthorizer::allows_warehouse(metadata, warehouse.warehouse_id) {
+            return Ok(MustUse::from(vec![
+                AuthorizationDecision::deny();
+                actions.len()
+            ]));
+        }
         // First check warehouse_id for all namespaces
         let warehouse_matches: Vec<bool> = actions
             .iter()
@@ -18,12 +18,12 @@ use crate::{
         TableInfo, TabularNotFound, TaskNotFoundError, UnexpectedTabularInResponse,
         WarehouseStatus,
         authz::{
-            AuthZError, AuthZGenericTableActionForbidden, AuthZGenericTableOps,
-            AuthZViewActionForbidden, AuthZViewOps, AuthorizationBackendUnavailable,
-            AuthorizationCountMismatch, AuthorizationDecision, Authorizer, AuthzBadRequest,
-            AuthzNamespaceOps, AuthzWarehouseOps, BackendUnavailableOrCountMismatch,
-            CannotInspectPermissions, CatalogAction, CatalogTableAction, IsAllowedActionError,
-            MustUse, UserOrRole,
+            AnonymousAccessAuthorizer, AuthZError, AuthZGenericTableActionForbidden,
+            AuthZGenericTableOps, AuthZViewActionForbidden, AuthZViewOps,
+            AuthorizationBackendUnavailable, AuthorizationCountMismatch, AuthorizationDecision,
+            Authorizer, AuthzBadRequest, AuthzNamespaceOps, AuthzWarehouseOps,
+            BackendUnavailableOrCountMismatch, CannotInspectPermissions, CatalogAction,
+            CatalogTableAction, IsAllowedActionError, MustUse, UserOrRole,
         },
         catalog_store::{
             BasicTabularInfo, CachePolicy, CatalogNamespaceOps, CatalogStore, CatalogTabularOps,
@@ -929,6 +929,14 @@ pub trait AuthZTableOps: Authorizer {
             ActionOnTable<'_, '_, impl AuthZTableInfo, A>,
         )],
     ) -> Result<MustUse<Vec<AuthorizationDecision>>, IsAllowedActionError> {
+        // Requests admitted anonymously may only access the allowlisted warehouses.
+        if !AnonymousAccessAuthorizer::allows_warehouse(metadata, warehouse.warehouse_id) {
+            return Ok(MustUse::from(vec![
+                AuthorizationDecision::deny();
+                actions.len()
+            ]));
+        }
+
         #[cfg(debug_assertions)]
         {
             let namespaces: Vec<&NamespaceWithParent> = actions.iter().map(|(ns, _)| *ns).collect();
@@ -952,6 +960,14 @@ pub trait AuthZTableOps: Authorizer {
                 continue;
             }
 
+            // Requests admitted anonymously are read-only.
+            if action.action == A::from(CatalogTableAction::WriteData)
+                && !AnonymousAccessAuthorizer::allows_data_writes(metadata)
+            {
+                auto_approved.push(Some(false));
+                continue;
+            }
+
             // Normalize user: if it's the actor itself, treat as None (acting as self).
             // Call-sites like `authorize_load_tabular` legitimately pass the actor's own
             // identity in `user`; collapsing it here means `Authorizer` impls never see
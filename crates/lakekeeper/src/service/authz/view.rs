@@ -11,11 +11,11 @@ use crate::{
         ResolvedWarehouse, SerializationError, TabularNotFound, UnexpectedTabularInResponse,
         ViewId, ViewIdentOrId, ViewInfo,
         authz::{
-            ActionOnView, AuthZError, AuthorizationBackendUnavailable, AuthorizationCountMismatch,
-            AuthorizationDecision, Authorizer, AuthzBadRequest, AuthzNamespaceOps,
-            AuthzWarehouseOps, BackendUnavailableOrCountMismatch, CannotInspectPermissions,
-            CatalogAction, CatalogViewAction, IsAllowedActionError, MustUse, UserOrRole,
-            refresh_warehouse_and_namespace_if_needed,
+            ActionOnView, AnonymousAccessAuthorizer, AuthZError, AuthorizationBackendUnavailable,
+            AuthorizationCountMismatch, AuthorizationDecision, Authorizer, AuthzBadRequest,
+            AuthzNamespaceOps, AuthzWarehouseOps, BackendUnavailableOrCountMismatch,
+            CannotInspectPermissions, CatalogAction, CatalogViewAction, IsAllowedActionError,
+            MustUse, UserOrRole, refresh_warehouse_and_namespace_if_needed,
         },
         catalog_store::{
             CachePolicy, CatalogNamespaceOps, CatalogStore, CatalogTabularOps, CatalogWarehouseOps,
@@ -504,6 +504,14 @@ pub trait AuthZViewOps: Authorizer {
             ActionOnView<'_, '_, impl AuthZViewInfo, A>,
         )],
     ) -> Result<MustUse<Vec<AuthorizationDecision>>, IsAllowedActionError> {
+        // Requests admitted anonymously may only access the allowlisted warehouses.
+        if !AnonymousAccessAuthorizer::allows_warehouse(metadata, warehouse.warehouse_id) {
+            return Ok(MustUse::from(vec![
+                AuthorizationDecision::deny();
+                actions.len()
+            ]));
+        }
+
         #[cfg(debug_assertions)]
         {
             let namespaces: Vec<&NamespaceWithParent> = actions.iter().map(|(ns, _)| *ns).collect();
@@ -9,8 +9,8 @@ use crate::{
         CatalogBackendError, CatalogGetWarehouseByIdError, DatabaseIntegrityError,
         ResolvedWarehouse, WarehouseIdNotFound,
         authz::{
-            AuthorizationBackendUnavailable, AuthorizationCountMismatch, AuthorizationDecision,
-            Authorizer, AuthzBadRequest, BackendUnavailableOrCountMismatch,
+            AnonymousAccessAuthorizer, AuthorizationBackendUnavailable, AuthorizationCountMismatch,
+            AuthorizationDecision, Authorizer, AuthzBadRequest, BackendUnavailableOrCountMismatch,
             CannotInspectPermissions, CatalogAction, CatalogWarehouseAction, IsAllowedActionError,
             MustUse, UserOrRole,
         },
@@ -342,6 +342,21 @@ pub trait AuthzWarehouseOps: Authorizer {
 
             Ok(decisions)
         }
+        // Requests admitted anonymously may only access the allowlisted warehouses.
+        .map(|decisions| {
+            warehouses_with_actions
+                .iter()
+                .zip(decisions)
+                .map(|((warehouse, _), decision)| {
+                    if AnonymousAccessAuthorizer::allows_warehouse(metadata, warehouse.warehouse_id)
+                    {
+                        decision
+                    } else {
+                        AuthorizationDecision::deny()
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
         .map(MustUse::from)
     }
 }
@@ -4,7 +4,7 @@
 //! `cache_type` label (values: `"role"`, `"warehouse"`, `"namespace"`,
 //! `"secrets"`, `"stc"`, `"user_assignments"`, `"role_members"`,
 //! `"warehouse_name_to_id"`, `"role_ident_to_id"`, `"namespace_ident_to_id"`,
-//! `"shared_role_idents"`, `"shared_project_ids"`).
+//! `"shared_role_idents"`, `"shared_project_ids"`, `"authentication"`).
 
 use std::sync::LazyLock;
 
@@ -27,8 +27,11 @@ use crate::{
         },
         management::v1::{
             DeleteWarehouseQuery, TabularType,
+            audit::{QueryAuditLogRequest, QueryAuditLogResponse},
+            namespace::NamespaceTableDefaults,
             project::{EndpointStatisticsResponse, TimeWindowSelector, WarehouseFilter},
             role::UpdateRoleSourceSystemRequest,
+            table::{ListTableSnapshotsQuery, ListTableSnapshotsResponse},
             task_queue::{GetTaskQueueConfigResponse, SetTaskQueueConfigRequest},
             tasks::ListTasksRequest,
             user::{ListUsersResponse, SearchUserResponse, UserLastUpdatedWith, UserType},
@@ -76,6 +79,8 @@ pub use role_assignment::*;
 mod idempotency;
 pub(crate) mod role_assignments_cache;
 pub use idempotency::*;
+mod audit_log;
+pub use audit_log::*;
 pub mod generic_table;
 pub use generic_table::*;
 
@@ -258,8 +263,8 @@ pub struct CatalogCreateRoleRequest<'a> {
 /// Spec for creating a warehouse, passed to
 /// [`CatalogWarehouseOps::create_warehouse`](crate::service::CatalogWarehouseOps::create_warehouse).
 /// `project_id` is supplied separately (the parent scope), mirroring
-/// [`CatalogCreateRoleRequest`]. `format_version_policy` and `managed_by` default
-/// (all versions allowed; self-managed) so most callers omit them.
+/// [`CatalogCreateRoleRequest`]. `format_version_policy`, `settings` and `managed_by`
+/// default (all versions allowed; no restrictions; self-managed) so most callers omit them.
 #[derive(Debug, typed_builder::TypedBuilder)]
 pub struct CatalogCreateWarehouseRequest {
     pub warehouse_name: String,
@@ -270,6 +275,8 @@ pub struct CatalogCreateWarehouseRequest {
     #[builder(default)]
     pub format_version_policy: WarehouseFormatVersionPolicy,
     #[builder(default)]
+    pub settings: WarehouseSettings,
+    #[builder(default)]
     pub managed_by: ManagedBy,
 }
 
@@ -377,6 +384,16 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
     ) -> Result<()>;
 
+    /// Set whether warehouse names of a project are case-sensitive.
+    ///
+    /// Must fail with a conflict if names are made case-insensitive while the
+    /// project contains warehouses whose names differ only in case.
+    async fn set_project_case_sensitive_warehouse_names<'a>(
+        project_id: &ProjectId,
+        case_sensitive: bool,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> Result<()>;
+
     // ---------------- Warehouse Management ----------------
     /// Create a warehouse.
     async fn create_warehouse_impl<'a>(
@@ -408,6 +425,19 @@ where
         state: Self::State,
     ) -> std::result::Result<Vec<ResolvedWarehouse>, CatalogListWarehousesError>;
 
+    /// Return a page of warehouses in a project, ordered by `(created_at, warehouse_id)`.
+    async fn list_warehouses_paginated_impl<'a>(
+        project_id: &ProjectId,
+        // If None, return only active warehouses
+        // If Some, return only warehouses with any of the statuses in the set
+        status_filter: Option<Vec<WarehouseStatus>>,
+        pagination_query: PaginationQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> std::result::Result<
+        PaginatedMapping<WarehouseId, ResolvedWarehouse>,
+        CatalogListWarehousesError,
+    >;
+
     /// Get the warehouse metadata. Return only active warehouses.
     ///
     /// Return Ok(None) if the warehouse does not exist.
@@ -431,6 +461,22 @@ where
         state: Self::State,
     ) -> Result<WarehouseStatisticsResponse>;
 
+    /// Count the namespaces, tables and views of each warehouse in `warehouse_ids`.
+    /// Warehouses without any entities may be missing from the returned map.
+    async fn get_warehouse_entity_counts(
+        warehouse_ids: &[WarehouseId],
+        state: Self::State,
+    ) -> Result<HashMap<WarehouseId, WarehouseEntityCounts>>;
+
+    /// Current change version of a warehouse. It increases with every change of
+    /// the warehouse itself and of the namespaces and tabulars within it.
+    ///
+    /// Return Ok(None) if the warehouse does not exist.
+    async fn get_warehouse_change_version(
+        warehouse_id: WarehouseId,
+        state: Self::State,
+    ) -> Result<Option<i64>>;
+
     /// Set warehouse deletion profile
     async fn set_warehouse_deletion_profile_impl<'a>(
         warehouse_id: WarehouseId,
@@ -452,6 +498,13 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
     ) -> std::result::Result<ResolvedWarehouse, UpdateWarehouseStorageProfileError>;
 
+    async fn update_secondary_storage_profile_impl<'a>(
+        warehouse_id: WarehouseId,
+        storage_profile: StorageProfile,
+        storage_secret_id: Option<SecretId>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> std::result::Result<ResolvedWarehouse, UpdateWarehouseStorageProfileError>;
+
     async fn set_warehouse_protected_impl(
         warehouse_id: WarehouseId,
         protect: bool,
@@ -465,6 +518,33 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
     ) -> std::result::Result<ResolvedWarehouse, SetWarehouseFormatVersionPolicyError>;
 
+    /// Replace the per-warehouse policies for tables, views and access.
+    async fn set_warehouse_settings_impl(
+        warehouse_id: WarehouseId,
+        settings: &WarehouseSettings,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<ResolvedWarehouse, SetWarehouseSettingsError>;
+
+    /// Number of entities of kind `entity` in the warehouse, counting at most
+    /// `limit`. Soft-deleted tables are not counted. Locks the warehouse for the
+    /// rest of the transaction before counting.
+    async fn count_warehouse_entities_up_to_impl(
+        warehouse_id: WarehouseId,
+        entity: WarehouseQuotaEntity,
+        limit: u32,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<u64, CatalogBackendError>;
+
+    /// List the protected namespaces and active tabulars of a warehouse.
+    async fn list_protected_entities_impl(
+        warehouse_id: WarehouseId,
+        pagination_query: PaginationQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<
+        PaginatedMapping<uuid::Uuid, ProtectedEntity>,
+        ListProtectedEntitiesError,
+    >;
+
     /// Set (or clear) the managed-by marker on a warehouse.
     async fn set_warehouse_managed_by_impl<'a>(
         warehouse_id: WarehouseId,
@@ -489,6 +569,15 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
     ) -> std::result::Result<CatalogListNamespacesResponse, CatalogListNamespaceError>;
 
+    // Lists all namespaces below `query.parent` recursively, or all namespaces of the
+    // warehouse if no parent is specified. `return_uuids` and `return_protection_status` are ignored.
+    // Should only return namespaces if the warehouse is active.
+    async fn list_namespaces_recursive_impl<'a>(
+        warehouse_id: WarehouseId,
+        query: &ListNamespacesQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> std::result::Result<CatalogListNamespacesResponse, CatalogListNamespaceError>;
+
     async fn create_namespace_impl<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -547,6 +636,52 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
     ) -> std::result::Result<NamespaceWithParent, CatalogSetNamespaceProtectedError>;
 
+    async fn get_namespace_delete_profile_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<Option<TabularDeleteProfile>, CatalogGetNamespaceDeleteProfileError>;
+
+    async fn set_namespace_delete_profile_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        delete_profile: Option<&TabularDeleteProfile>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<Option<TabularDeleteProfile>, CatalogSetNamespaceDeleteProfileError>;
+
+    async fn get_namespace_table_defaults_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<NamespaceTableDefaults, CatalogGetNamespaceTableDefaultsError>;
+
+    async fn set_namespace_table_defaults_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        table_defaults: &NamespaceTableDefaults,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<NamespaceTableDefaults, CatalogSetNamespaceTableDefaultsError>;
+
+    /// Move a namespace below `new_parent` (or to the warehouse root if `None`).
+    /// All child namespaces and the namespace names of all contained tabulars
+    /// must be updated atomically.
+    async fn move_namespace_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        new_parent: Option<&NamespaceIdent>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<NamespaceMoveInfo, CatalogMoveNamespaceError>;
+
+    /// Return the most recent activity of the tabulars directly contained in
+    /// each namespace: `updated_at`, or `created_at` for tabulars that were
+    /// never updated. Soft-deleted tabulars are ignored. Namespaces without
+    /// tabulars are not part of the result.
+    async fn namespace_last_activity_impl(
+        warehouse_id: WarehouseId,
+        namespace_ids: &[NamespaceId],
+        catalog_state: Self::State,
+    ) -> std::result::Result<HashMap<NamespaceId, chrono::DateTime<chrono::Utc>>, CatalogBackendError>;
+
     // ---------------- Tabular Management ----------------
     async fn list_tabulars_impl(
         warehouse_id: WarehouseId,
@@ -563,6 +698,15 @@ where
         catalog_state: Self::State,
     ) -> std::result::Result<CatalogSearchTabularResponse, SearchTabularError>;
 
+    /// Return the tabulars of a warehouse whose summed property key and value
+    /// byte lengths exceed `max_properties_bytes`, sorted by size descending.
+    /// Soft-deleted tabulars are ignored.
+    async fn list_tabulars_exceeding_properties_size_impl(
+        warehouse_id: WarehouseId,
+        max_properties_bytes: u64,
+        catalog_state: Self::State,
+    ) -> std::result::Result<Vec<TabularPropertiesSize>, CatalogBackendError>;
+
     async fn set_tabular_protected_impl(
         warehouse_id: WarehouseId,
         tabular_id: TabularId,
@@ -570,6 +714,20 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
     ) -> std::result::Result<ViewOrTableInfo, SetTabularProtectionError>;
 
+    async fn touch_tabular_impl(
+        warehouse_id: WarehouseId,
+        tabular_id: TabularId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<chrono::DateTime<chrono::Utc>, TouchTabularError>;
+
+    async fn set_namespace_tabulars_protected_impl(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        protect: bool,
+        recursive: bool,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<TabularProtectionCounts, SetNamespaceTabularsProtectionError>;
+
     async fn get_tabular_infos_by_ident_impl(
         warehouse_id: WarehouseId,
         tabulars: &[TabularIdentBorrowed<'_>],
@@ -609,6 +767,15 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
     ) -> std::result::Result<Vec<ViewOrTableDeletionInfo>, ClearTabularDeletedAtError>;
 
+    /// Get a soft-deleted table or view by id, including its expiration task,
+    /// and lock it for the rest of the transaction.
+    /// Returns `None` if the tabular does not exist or is not soft-deleted.
+    async fn get_soft_deleted_tabular_impl(
+        warehouse_id: WarehouseId,
+        tabular_id: TabularId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<Option<ViewOrTableDeletionInfo>, GetTabularInfoError>;
+
     async fn mark_tabular_as_deleted_impl(
         warehouse_id: WarehouseId,
         tabular_id: TabularId,
@@ -651,6 +818,15 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
     ) -> std::result::Result<Vec<TableInfo>, CommitTableTransactionError>;
 
+    /// List the snapshots of a table (read pool). Paginated by
+    /// `(timestamp_ms, snapshot_id)`, newest first.
+    async fn list_table_snapshots_impl(
+        warehouse_id: WarehouseId,
+        table_id: TableId,
+        query: &ListTableSnapshotsQuery,
+        state: Self::State,
+    ) -> Result<ListTableSnapshotsResponse>;
+
     // ---------------- View Management ----------------
     async fn create_view_impl<'a>(
         warehouse_id: WarehouseId,
@@ -986,6 +1162,16 @@ where
         catalog_state: Self::State,
     ) -> Result<EndpointStatisticsResponse>;
 
+    /// Delete endpoint statistics of the project whose time bucket lies within
+    /// `[from, until)`. Unbounded ends of the range are open.
+    /// Returns the number of deleted statistic rows.
+    async fn delete_endpoint_statistics<'a>(
+        project_id: &ProjectId,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> Result<u64>;
+
     // ------------- Tasks -------------
     async fn pick_new_task_impl(
         queue_name: &TaskQueueName,
@@ -1013,6 +1199,11 @@ where
         transaction: &mut <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
     ) -> Result<()>;
 
+    async fn release_task_impl(
+        id: TaskAttemptId,
+        transaction: &mut <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()>;
+
     /// Get task details by task id.
     /// Return Ok(None) if the task does not exist.
     async fn get_task_details_impl(
@@ -1079,6 +1270,25 @@ where
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
     ) -> Result<()>;
 
+    /// Defer tasks to `run_at` by setting `scheduled_for`.
+    /// Only affects tasks in the `Scheduled` or `Stopping` state.
+    /// Must fail with a conflict, without modifying any task, if one of the
+    /// tasks already completed successfully.
+    async fn reschedule_tasks_impl(
+        task_ids: &[TaskId],
+        run_at: chrono::DateTime<chrono::Utc>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()>;
+
+    /// Requeue tasks in the final `Failed` state, keeping their id and attempt history.
+    /// The tasks are scheduled to run immediately and get their full retry budget back.
+    /// Must fail with a conflict if one of the tasks is not in the `Failed` state
+    /// or another task is active for the same entity and queue.
+    async fn requeue_tasks_impl(
+        task_ids: &[TaskId],
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()>;
+
     async fn set_task_queue_config_impl(
         project_id: ArcProjectId,
         warehouse_id: Option<WarehouseId>,
@@ -1093,6 +1303,12 @@ where
         state: Self::State,
     ) -> Result<Option<GetTaskQueueConfigResponse>>;
 
+    async fn get_task_queue_depth_impl(
+        queue_name: &TaskQueueName,
+        legacy_queue_names: &[&TaskQueueName],
+        state: Self::State,
+    ) -> Result<TaskQueueDepth>;
+
     async fn cleanup_task_logs_older_than(
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
         retention_period: Duration,
@@ -1114,4 +1330,19 @@ where
         info: &crate::service::idempotency::IdempotencyInfo,
         transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
     ) -> Result<bool>;
+
+    // ---------------- Audit Log ----------------
+    /// Append a record to the audit log (INSERT on write pool, outside any transaction).
+    async fn insert_audit_log_record_impl(
+        record: &AuditLogRecord,
+        state: Self::State,
+    ) -> Result<()>;
+
+    /// Query the audit log of a project (read pool). Paginated by
+    /// `(created_at, audit_log_id)`, newest first.
+    async fn query_audit_log_impl(
+        project_id: &ArcProjectId,
+        query: &QueryAuditLogRequest,
+        state: Self::State,
+    ) -> Result<QueryAuditLogResponse>;
 }
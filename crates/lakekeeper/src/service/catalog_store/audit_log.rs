@@ -0,0 +1,51 @@
+use http::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        endpoints::EndpointFlat,
+        management::v1::audit::{QueryAuditLogRequest, QueryAuditLogResponse},
+    },
+    service::{ArcProjectId, CatalogStore, events::OperationOutcome},
+};
+
+/// A single entry of the persistent audit trail of mutating management operations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditLogRecord {
+    pub request_id: Uuid,
+    pub project_id: Option<ArcProjectId>,
+    pub operation: EndpointFlat,
+    /// Acting principal, in the same shape as the `actor` field of audit log events.
+    pub actor: serde_json::Value,
+    /// Ids of the entities the operation targeted, keyed by path parameter name.
+    pub targets: serde_json::Value,
+    pub outcome: OperationOutcome,
+    pub http_status: StatusCode,
+}
+
+/// Audit log operations on the catalog store.
+#[allow(async_fn_in_trait)]
+pub trait CatalogAuditLogOps
+where
+    Self: CatalogStore,
+{
+    /// Append a record to the audit log. Records are never updated or deleted
+    /// by Lakekeeper.
+    async fn insert_audit_log_record(
+        record: &AuditLogRecord,
+        state: Self::State,
+    ) -> super::Result<()> {
+        Self::insert_audit_log_record_impl(record, state).await
+    }
+
+    /// Query the audit log of a project, newest entries first.
+    async fn query_audit_log(
+        project_id: &ArcProjectId,
+        query: &QueryAuditLogRequest,
+        state: Self::State,
+    ) -> super::Result<QueryAuditLogResponse> {
+        Self::query_audit_log_impl(project_id, query, state).await
+    }
+}
+
+impl<T> CatalogAuditLogOps for T where T: CatalogStore {}
@@ -155,6 +155,8 @@ use crate::service::events::impl_authorization_failure_source;
 pub enum CatalogBackendErrorType {
     Unexpected,
     ConcurrentModification,
+    /// A query was cancelled because it exceeded the configured statement timeout.
+    QueryTimeout,
 }
 
 #[derive(Debug)]
@@ -279,15 +281,21 @@ impl From<CatalogBackendError> for ErrorModel {
             source,
         } = err;
 
-        let code = match r#type {
-            CatalogBackendErrorType::Unexpected => StatusCode::SERVICE_UNAVAILABLE,
-            CatalogBackendErrorType::ConcurrentModification => StatusCode::CONFLICT,
-        }
-        .as_u16();
+        let (code, error_type) = match r#type {
+            CatalogBackendErrorType::Unexpected => {
+                (StatusCode::SERVICE_UNAVAILABLE, "CatalogBackendError")
+            }
+            CatalogBackendErrorType::ConcurrentModification => {
+                (StatusCode::CONFLICT, "CatalogBackendError")
+            }
+            CatalogBackendErrorType::QueryTimeout => {
+                (StatusCode::SERVICE_UNAVAILABLE, "QueryTimeout")
+            }
+        };
 
         ErrorModel::builder()
-            .r#type("CatalogBackendError")
-            .code(code)
+            .r#type(error_type)
+            .code(code.as_u16())
             .message(format!("Catalog backend error ({type}): {source}"))
             .stack(stack)
             .source(None)
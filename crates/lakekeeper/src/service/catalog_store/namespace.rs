@@ -10,9 +10,12 @@ use lakekeeper_io::Location;
 
 use crate::{
     WarehouseId,
-    api::iceberg::v1::{PaginatedMapping, namespace::NamespaceDropFlags},
+    api::{
+        iceberg::v1::{PaginatedMapping, namespace::NamespaceDropFlags},
+        management::v1::{namespace::NamespaceTableDefaults, warehouse::TabularDeleteProfile},
+    },
     service::{
-        BasicTabularInfo, CachePolicy, CatalogBackendError, CatalogStore,
+        BasicTabularInfo, CachePolicy, CatalogBackendError, CatalogStore, DatabaseIntegrityError,
         InternalParseLocationError, InvalidPaginationToken, ListNamespacesQuery, NamespaceId,
         SerializationError, StateOrTransaction, StateOrTransactionEnum, TableIdent, TabularId,
         Transaction, WarehouseIdNotFound,
@@ -334,6 +337,15 @@ pub struct NamespaceDropInfo {
     pub open_tasks: Vec<TaskId>,
 }
 
+#[derive(Debug)]
+pub struct NamespaceMoveInfo {
+    /// The moved namespace at its new location.
+    pub namespace: NamespaceWithParent,
+    /// Namespaces below the moved namespace that moved along with it,
+    /// with their new identifiers.
+    pub child_namespaces: Vec<(NamespaceId, NamespaceIdent)>,
+}
+
 macro_rules! define_simple_namespace_err {
     ($error_name:ident, $error_message:literal) => {
         #[derive(thiserror::Error, Debug, PartialEq)]
@@ -557,6 +569,7 @@ define_transparent_error! {
         ChildNamespaceProtected,
         ChildTabularProtected,
         NamespaceHasRunningTabularExpirations,
+        NamespaceSoftDeletionEnabled,
         InternalParseLocationError
     ]
 }
@@ -641,6 +654,22 @@ impl From<NamespaceHasRunningTabularExpirations> for ErrorModel {
     }
 }
 
+define_simple_namespace_err!(
+    NamespaceSoftDeletionEnabled,
+    "Namespace with {namespace} or one of its children overrides the delete profile with soft-deletion and force flag was not specified. Cannot recursively delete."
+);
+
+impl From<NamespaceSoftDeletionEnabled> for ErrorModel {
+    fn from(err: NamespaceSoftDeletionEnabled) -> Self {
+        ErrorModel::builder()
+            .r#type("NamespaceSoftDeletionEnabled")
+            .code(StatusCode::BAD_REQUEST.as_u16())
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
 // --------------------------- Update Properties Error ---------------------------
 define_transparent_error! {
     pub enum CatalogUpdateNamespacePropertiesError,
@@ -664,6 +693,92 @@ define_transparent_error! {
     ]
 }
 
+// --------------------------- Namespace Delete Profile Errors ---------------------------
+define_transparent_error! {
+    pub enum CatalogGetNamespaceDeleteProfileError,
+    stack_message: "Error getting Namespace delete profile in catalog",
+    variants: [
+        CatalogBackendError,
+        NamespaceNotFound,
+        DatabaseIntegrityError,
+    ]
+}
+
+define_transparent_error! {
+    pub enum CatalogSetNamespaceDeleteProfileError,
+    stack_message: "Error setting Namespace delete profile in catalog",
+    variants: [
+        CatalogBackendError,
+        NamespaceNotFound,
+        DatabaseIntegrityError,
+    ]
+}
+
+// --------------------------- Namespace Table Defaults Errors ---------------------------
+define_transparent_error! {
+    pub enum CatalogGetNamespaceTableDefaultsError,
+    stack_message: "Error getting Namespace table defaults in catalog",
+    variants: [
+        CatalogBackendError,
+        NamespaceNotFound,
+    ]
+}
+
+define_transparent_error! {
+    pub enum CatalogSetNamespaceTableDefaultsError,
+    stack_message: "Error setting Namespace table defaults in catalog",
+    variants: [
+        CatalogBackendError,
+        NamespaceNotFound,
+    ]
+}
+
+// --------------------------- Move Namespace Error ---------------------------
+define_transparent_error! {
+    pub enum CatalogMoveNamespaceError,
+    stack_message: "Error moving Namespace in catalog",
+    variants: [
+        CatalogBackendError,
+        NamespaceNotFound,
+        NamespaceAlreadyExists,
+        NamespaceMoveIntoOwnSubtree,
+        NamespaceMoveExceedsMaxDepth,
+        InvalidNamespaceIdentifier,
+    ]
+}
+
+define_simple_namespace_err!(
+    NamespaceMoveIntoOwnSubtree,
+    "Namespace with {namespace} cannot be moved into itself or one of its children."
+);
+
+impl From<NamespaceMoveIntoOwnSubtree> for ErrorModel {
+    fn from(err: NamespaceMoveIntoOwnSubtree) -> Self {
+        ErrorModel::builder()
+            .r#type("NamespaceMoveIntoOwnSubtree")
+            .code(StatusCode::BAD_REQUEST.as_u16())
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
+define_simple_namespace_err!(
+    NamespaceMoveExceedsMaxDepth,
+    "Moving namespace with {namespace} would exceed the maximum namespace depth."
+);
+
+impl From<NamespaceMoveExceedsMaxDepth> for ErrorModel {
+    fn from(err: NamespaceMoveExceedsMaxDepth) -> Self {
+        ErrorModel::builder()
+            .r#type("NamespaceMoveExceedsMaxDepth")
+            .code(StatusCode::BAD_REQUEST.as_u16())
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
 /// Input must contain full parent chain up to root namespace.
 /// Builds the full `NamespaceHierarchy` by following parent IDs using the provided lookup map.
 /// Starts from the namespace with the longest ident (deepest in hierarchy).
@@ -1180,6 +1295,26 @@ where
         Ok(list_response)
     }
 
+    /// List all namespaces below `query.parent` recursively, ordered by creation time.
+    /// If no parent is specified, all namespaces of the warehouse are listed.
+    async fn list_namespaces_recursive<'a>(
+        warehouse_id: WarehouseId,
+        query: &ListNamespacesQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> Result<CatalogListNamespacesResponse, CatalogListNamespaceError> {
+        let list_response =
+            Self::list_namespaces_recursive_impl(warehouse_id, query, transaction).await?;
+
+        let namespaces_for_cache = list_response
+            .namespaces
+            .iter()
+            .map(|(_, ns)| ns.clone())
+            .collect::<Vec<_>>();
+        namespace_cache_insert_multiple(namespaces_for_cache).await;
+
+        Ok(list_response)
+    }
+
     async fn create_namespace<'a>(
         warehouse_id: WarehouseId,
         namespace_id: NamespaceId,
@@ -1216,6 +1351,79 @@ where
     ) -> Result<NamespaceWithParent, CatalogSetNamespaceProtectedError> {
         Self::set_namespace_protected_impl(warehouse_id, namespace_id, protect, transaction).await
     }
+
+    /// Delete profile override of a namespace. `None` if the namespace uses the
+    /// delete profile of its warehouse.
+    async fn get_namespace_delete_profile(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<Option<TabularDeleteProfile>, CatalogGetNamespaceDeleteProfileError> {
+        Self::get_namespace_delete_profile_impl(warehouse_id, namespace_id, transaction).await
+    }
+
+    /// Set or clear (`None`) the delete profile override of a namespace.
+    async fn set_namespace_delete_profile(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        delete_profile: Option<&TabularDeleteProfile>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<Option<TabularDeleteProfile>, CatalogSetNamespaceDeleteProfileError> {
+        Self::set_namespace_delete_profile_impl(
+            warehouse_id,
+            namespace_id,
+            delete_profile,
+            transaction,
+        )
+        .await
+    }
+
+    /// Partition spec and sort order inherited by new tables of a namespace.
+    async fn get_namespace_table_defaults(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<NamespaceTableDefaults, CatalogGetNamespaceTableDefaultsError> {
+        Self::get_namespace_table_defaults_impl(warehouse_id, namespace_id, transaction).await
+    }
+
+    /// Replace the table defaults of a namespace. Unset fields are removed.
+    async fn set_namespace_table_defaults(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        table_defaults: &NamespaceTableDefaults,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<NamespaceTableDefaults, CatalogSetNamespaceTableDefaultsError> {
+        Self::set_namespace_table_defaults_impl(
+            warehouse_id,
+            namespace_id,
+            table_defaults,
+            transaction,
+        )
+        .await
+    }
+
+    /// Move a namespace, including its child namespaces and tabulars, below `new_parent`.
+    /// `None` moves the namespace to the root of the warehouse. The namespace keeps its name.
+    async fn move_namespace(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        new_parent: Option<&NamespaceIdent>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<NamespaceMoveInfo, CatalogMoveNamespaceError> {
+        Self::move_namespace_impl(warehouse_id, namespace_id, new_parent, transaction).await
+    }
+
+    /// Last activity per namespace, i.e. the latest update of any of its tabulars.
+    ///
+    /// Namespaces without tabulars are omitted from the result.
+    async fn namespace_last_activity(
+        warehouse_id: WarehouseId,
+        namespace_ids: &[NamespaceId],
+        catalog_state: Self::State,
+    ) -> Result<HashMap<NamespaceId, chrono::DateTime<chrono::Utc>>, CatalogBackendError> {
+        Self::namespace_last_activity_impl(warehouse_id, namespace_ids, catalog_state).await
+    }
 }
 
 impl<T> CatalogNamespaceOps for T where T: CatalogStore {}
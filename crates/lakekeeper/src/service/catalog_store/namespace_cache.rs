@@ -128,6 +128,22 @@ async fn namespace_cache_invalidate(namespace_id: NamespaceId) {
     }
 }
 
+#[allow(dead_code)] // Not required for all features
+async fn namespace_cache_invalidate_ident_mappings(warehouse_id: WarehouseId) {
+    if CONFIG.cache.namespace.enabled {
+        tracing::debug!("Invalidating namespace ident mappings of warehouse {warehouse_id}");
+        // Mappings keyed by a case variant of the stored name must not resolve
+        // once the warehouse matches names case-sensitively. Canonical mappings
+        // are dropped as well and repopulated on the next lookup.
+        for (key, _) in &*IDENT_TO_ID_CACHE {
+            if key.0 == warehouse_id {
+                IDENT_TO_ID_CACHE.invalidate(&*key).await;
+            }
+        }
+        update_cache_size_metric();
+    }
+}
+
 #[allow(dead_code)] // Only required for listeners which are behind a feature flag
 pub(super) async fn namespace_cache_insert(namespace: NamespaceWithParent) {
     if CONFIG.cache.namespace.enabled {
@@ -479,6 +495,22 @@ impl EventListener for NamespaceCacheEventListener {
         Ok(())
     }
 
+    async fn namespace_moved(&self, event: events::MoveNamespaceEvent) -> anyhow::Result<()> {
+        let events::MoveNamespaceEvent {
+            warehouse_id: _warehouse_id,
+            previous_namespace: _previous_namespace,
+            namespace,
+            child_namespaces,
+            request_metadata: _request_metadata,
+        } = event;
+        // Children are cached with their old names, which would still resolve by ident.
+        for child_namespace_id in child_namespaces.iter() {
+            namespace_cache_invalidate(*child_namespace_id).await;
+        }
+        namespace_cache_insert(namespace).await;
+        Ok(())
+    }
+
     async fn namespace_properties_updated(
         &self,
         event: events::UpdateNamespacePropertiesEvent,
@@ -505,6 +537,21 @@ impl EventListener for NamespaceCacheEventListener {
         namespace_cache_insert(updated_namespace).await;
         Ok(())
     }
+
+    async fn warehouse_settings_updated(
+        &self,
+        event: events::UpdateWarehouseSettingsEvent,
+    ) -> anyhow::Result<()> {
+        let events::UpdateWarehouseSettingsEvent {
+            request: _request,
+            updated_warehouse,
+            request_metadata: _request_metadata,
+        } = event;
+        if updated_warehouse.settings.case_sensitive_names {
+            namespace_cache_invalidate_ident_mappings(updated_warehouse.warehouse_id).await;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
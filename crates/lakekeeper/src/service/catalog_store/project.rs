@@ -6,4 +6,6 @@ pub struct GetProjectResponse {
     pub project_id: ArcProjectId,
     /// Name of the project.
     pub name: String,
+    /// Whether warehouse names in this project are case-sensitive.
+    pub case_sensitive_warehouse_names: bool,
 }
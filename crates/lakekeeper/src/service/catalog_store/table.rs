@@ -10,15 +10,19 @@ use lakekeeper_io::Location;
 
 use crate::{
     WarehouseId,
-    api::iceberg::v1::tables::LoadTableFilters,
+    api::{
+        iceberg::v1::tables::LoadTableFilters,
+        management::v1::table::{ListTableSnapshotsQuery, ListTableSnapshotsResponse},
+    },
     server::tables::TableMetadataDiffs,
     service::{
         CatalogBackendError, CatalogStore, ConcurrentUpdateError, ConversionError,
         CreateTabularError, InternalBackendErrors, InternalParseLocationError,
-        InvalidNamespaceIdentifier, LocationAlreadyTaken, NamespaceId, SerializationError, TableId,
-        TableInfo, TabularAlreadyExists, TabularNotFound, Transaction, UnexpectedTabularInResponse,
-        WarehouseVersion, define_simple_error, define_simple_tabular_err, define_transparent_error,
-        impl_error_stack_methods, impl_from_with_detail,
+        InvalidNamespaceIdentifier, LocationAlreadyTaken, NamespaceId, SerializationError,
+        StorageProfileSlot, TableId, TableInfo, TabularAlreadyExists, TabularNotFound, Transaction,
+        UnexpectedTabularInResponse, WarehouseVersion, define_simple_error,
+        define_simple_tabular_err, define_transparent_error, impl_error_stack_methods,
+        impl_from_with_detail,
     },
 };
 
@@ -29,6 +33,7 @@ pub struct LoadTableResponse {
     pub table_metadata: TableMetadata,
     pub metadata_location: Option<Location>,
     pub warehouse_version: WarehouseVersion,
+    pub storage_profile_slot: StorageProfileSlot,
 }
 
 #[derive(Debug, Clone)]
@@ -47,6 +52,7 @@ pub struct TableCreation<'c> {
     pub table_ident: &'c TableIdent,
     pub metadata_location: Option<&'c Location>,
     pub table_metadata: &'c TableMetadata,
+    pub storage_profile_slot: StorageProfileSlot,
 }
 
 define_simple_tabular_err!(
@@ -226,6 +232,17 @@ where
     ) -> Result<Vec<TableInfo>, CommitTableTransactionError> {
         Self::commit_table_transaction_impl(warehouse_id, commits, transaction).await
     }
+
+    /// List the snapshots of a table, newest first.
+    /// Reads directly from the catalog without assembling the table metadata.
+    async fn list_table_snapshots(
+        warehouse_id: WarehouseId,
+        table_id: TableId,
+        query: &ListTableSnapshotsQuery,
+        state: Self::State,
+    ) -> crate::api::Result<ListTableSnapshotsResponse> {
+        Self::list_table_snapshots_impl(warehouse_id, table_id, query, state).await
+    }
 }
 
 impl<T> CatalogTableOps for T where T: CatalogStore {}
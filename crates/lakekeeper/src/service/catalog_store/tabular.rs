@@ -13,8 +13,8 @@ use crate::{
     },
     service::{
         CatalogBackendError, CatalogStore, GenericTableId, InvalidNamespaceIdentifier,
-        InvalidPaginationToken, NamespaceId, NamespaceVersion, Result, TableId, TabularId,
-        TabularIdentBorrowed, TabularIdentOwned, Transaction, ViewId, WarehouseVersion,
+        InvalidPaginationToken, NamespaceId, NamespaceNotFound, NamespaceVersion, Result, TableId,
+        TabularId, TabularIdentBorrowed, TabularIdentOwned, Transaction, ViewId, WarehouseVersion,
         authz::{
             ActionOnGenericTable, ActionOnTable, ActionOnTableOrView, ActionOnView, UserOrRole,
         },
@@ -980,6 +980,25 @@ pub struct CatalogSearchTabularResponse {
     pub search_results: Vec<CatalogSearchTabularInfo>,
 }
 
+/// Total size of the properties of a single tabular.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabularPropertiesSize {
+    pub tabular_id: TabularId,
+    pub namespace_id: NamespaceId,
+    pub tabular_name: String,
+    /// Sum of the byte lengths of all property keys and values.
+    pub properties_bytes: u64,
+}
+
+/// Number of tabulars, by type, whose protection was set by
+/// [`CatalogTabularOps::set_namespace_tabulars_protected`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TabularProtectionCounts {
+    pub tables: u64,
+    pub views: u64,
+    pub generic_tables: u64,
+}
+
 // #[derive(Debug, Clone)]
 // pub struct UndropTabularResponse {
 //     pub table_id: TableId,
@@ -1199,6 +1218,32 @@ impl From<ConcurrentUpdateError> for ErrorModel {
     }
 }
 
+pub const PRECONDITION_FAILED_ERROR_TYPE: &str = "PreconditionFailed";
+define_simple_tabular_err!(
+    IfMatchPreconditionFailed,
+    "Tabular {tabular} does not match the `If-Match` precondition of the request"
+);
+impl From<IfMatchPreconditionFailed> for ErrorModel {
+    fn from(err: IfMatchPreconditionFailed) -> Self {
+        ErrorModel::builder()
+            .code(StatusCode::PRECONDITION_FAILED.as_u16())
+            .r#type(PRECONDITION_FAILED_ERROR_TYPE)
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
+impl From<ConcurrentUpdateError> for IfMatchPreconditionFailed {
+    fn from(err: ConcurrentUpdateError) -> Self {
+        Self {
+            warehouse_id: err.warehouse_id,
+            tabular: err.tabular,
+            stack: err.stack,
+        }
+    }
+}
+
 define_simple_tabular_err!(TabularNotFound, "Error getting tabular from catalog");
 impl From<TabularNotFound> for ErrorModel {
     fn from(err: TabularNotFound) -> Self {
@@ -1331,6 +1376,25 @@ define_transparent_error! {
     ]
 }
 
+define_transparent_error! {
+    pub enum SetNamespaceTabularsProtectionError,
+    stack_message: "Error setting protection of the tabulars of a namespace in catalog",
+    variants: [
+        CatalogBackendError,
+        NamespaceNotFound,
+    ]
+}
+
+// --------------------------- Touch Tabular ---------------------------
+define_transparent_error! {
+    pub enum TouchTabularError,
+    stack_message: "Error touching tabular in catalog",
+    variants: [
+        CatalogBackendError,
+        TabularNotFound,
+    ]
+}
+
 // --------------------------- List Tabulars ---------------------------
 define_simple_tabular_err!(
     ViewInTableList,
@@ -1528,19 +1592,30 @@ define_transparent_error! {
 
 // --------------------------- Create Tabulars ----------------
 #[derive(thiserror::Error, Debug)]
-#[error("Location '{location}' is already taken by another table or view.")]
+#[error(
+    "Location '{location}' is already taken by another tabular ({} '{conflicting_tabular_id}').",
+    conflicting_tabular_id.typ_str()
+)]
 pub struct LocationAlreadyTaken {
     location: Location,
+    conflicting_tabular_id: TabularId,
     stack: Vec<String>,
 }
 impl LocationAlreadyTaken {
     #[must_use]
-    pub fn new(location: Location) -> Self {
+    pub fn new(location: Location, conflicting_tabular_id: TabularId) -> Self {
         Self {
             location,
+            conflicting_tabular_id,
             stack: Vec::new(),
         }
     }
+
+    /// The tabular whose location conflicts with the requested location.
+    #[must_use]
+    pub fn conflicting_tabular_id(&self) -> TabularId {
+        self.conflicting_tabular_id
+    }
 }
 impl_error_stack_methods!(LocationAlreadyTaken);
 impl From<LocationAlreadyTaken> for ErrorModel {
@@ -1591,6 +1666,14 @@ where
         Self::clear_tabular_deleted_at_impl(tabular_ids, warehouse_id, transaction).await
     }
 
+    async fn get_soft_deleted_tabular(
+        warehouse_id: WarehouseId,
+        tabular_id: impl Into<TabularId> + Send,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<Option<ViewOrTableDeletionInfo>, GetTabularInfoError> {
+        Self::get_soft_deleted_tabular_impl(warehouse_id, tabular_id.into(), transaction).await
+    }
+
     async fn mark_tabular_as_deleted(
         warehouse_id: WarehouseId,
         tabular_id: impl Into<TabularId> + Send,
@@ -1609,6 +1692,24 @@ where
         Self::search_tabular_impl(warehouse_id, search_term, catalog_state).await
     }
 
+    /// Tabulars of a warehouse whose properties take up more than
+    /// `max_properties_bytes` bytes in total, largest first.
+    ///
+    /// Intended for monitoring the catalog database. Soft-deleted tabulars are
+    /// ignored.
+    async fn list_tabulars_exceeding_properties_size(
+        warehouse_id: WarehouseId,
+        max_properties_bytes: u64,
+        catalog_state: Self::State,
+    ) -> std::result::Result<Vec<TabularPropertiesSize>, CatalogBackendError> {
+        Self::list_tabulars_exceeding_properties_size_impl(
+            warehouse_id,
+            max_properties_bytes,
+            catalog_state,
+        )
+        .await
+    }
+
     async fn rename_tabular(
         warehouse_id: WarehouseId,
         source_id: impl Into<TabularId> + Send,
@@ -1819,6 +1920,37 @@ where
         Self::set_tabular_protected_impl(warehouse_id, tabular_id, protect, transaction).await
     }
 
+    /// Refresh `updated_at` of an active tabular without changing its metadata.
+    /// Like every other change of the tabular, this bumps the change version of
+    /// the warehouse. Returns the new `updated_at`.
+    async fn touch_tabular(
+        warehouse_id: WarehouseId,
+        tabular_id: TabularId,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<chrono::DateTime<chrono::Utc>, TouchTabularError> {
+        Self::touch_tabular_impl(warehouse_id, tabular_id, transaction).await
+    }
+
+    /// Set the protection of all tabulars directly contained in a namespace, and of
+    /// the tabulars in its descendants if `recursive` is set. Soft-deleted tabulars
+    /// are not changed.
+    async fn set_namespace_tabulars_protected(
+        warehouse_id: WarehouseId,
+        namespace_id: NamespaceId,
+        protect: bool,
+        recursive: bool,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<TabularProtectionCounts, SetNamespaceTabularsProtectionError> {
+        Self::set_namespace_tabulars_protected_impl(
+            warehouse_id,
+            namespace_id,
+            protect,
+            recursive,
+            transaction,
+        )
+        .await
+    }
+
     async fn list_tabulars(
         warehouse_id: WarehouseId,
         namespace_id: Option<NamespaceId>, // Filter by namespace
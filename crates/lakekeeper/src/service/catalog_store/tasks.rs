@@ -76,6 +76,14 @@ pub struct TaskDetails {
     pub message: Option<String>,
 }
 
+/// Tasks of a queue that are due but have not been picked up by a worker yet.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TaskQueueDepth {
+    pub pending: i64,
+    /// Seconds since the oldest pending task became due. `None` if no task is pending.
+    pub oldest_pending_age_seconds: Option<f64>,
+}
+
 #[derive(thiserror::Error, Debug, PartialEq)]
 #[error("Task with id `{task_id}` not found")]
 pub struct TaskNotFoundError {
@@ -185,6 +193,15 @@ where
         Self::record_task_failure_impl(id, error_details, max_retries, transaction).await
     }
 
+    /// Put a running task attempt back into the queue without recording a failure.
+    /// The attempt does not count towards the retries of the task.
+    async fn release_task(
+        id: TaskAttemptId,
+        transaction: &mut <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()> {
+        Self::release_task_impl(id, transaction).await
+    }
+
     /// Cancel scheduled tasks matching the filter.
     ///
     /// If `cancel_running_and_should_stop` is true, also cancel tasks in the `running` and `should-stop` states.
@@ -238,6 +255,30 @@ where
         Self::run_tasks_at_impl(task_ids, scheduled_for, transaction).await
     }
 
+    /// Defer tasks to `run_at` by setting `scheduled_for`.
+    /// Only affects tasks in the `Scheduled` or `Stopping` state, which includes
+    /// tasks waiting for a retry after a failed attempt.
+    /// Fails with `409 TaskAlreadySucceeded` if one of the tasks already completed successfully.
+    async fn reschedule_tasks(
+        task_ids: &[TaskId],
+        run_at: chrono::DateTime<chrono::Utc>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()> {
+        Self::reschedule_tasks_impl(task_ids, run_at, transaction).await
+    }
+
+    /// Requeue tasks that failed permanently, i.e. that exhausted their retries
+    /// or hit a permanent failure. The tasks keep their id and attempt history,
+    /// are scheduled to run immediately and get their full retry budget back.
+    /// Fails with `409 TaskNotRequeueable` if one of the tasks is not in the `Failed` state
+    /// or another task is active for the same entity and queue.
+    async fn requeue_tasks(
+        task_ids: &[TaskId],
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<()> {
+        Self::requeue_tasks_impl(task_ids, transaction).await
+    }
+
     /// Get task details by task id.
     /// Return Ok(None) if the task does not exist.
     async fn get_task_details(
@@ -361,6 +402,16 @@ where
     ) -> Result<Option<GetTaskQueueConfigResponse>> {
         Self::get_task_queue_config_impl(filter, queue_name, state).await
     }
+
+    /// Count the scheduled tasks of `queue_name` (and its legacy names) that are due,
+    /// across all projects and warehouses.
+    async fn get_task_queue_depth(
+        queue_name: &TaskQueueName,
+        legacy_queue_names: &[&TaskQueueName],
+        state: Self::State,
+    ) -> Result<TaskQueueDepth> {
+        Self::get_task_queue_depth_impl(queue_name, legacy_queue_names, state).await
+    }
 }
 
 impl<T> CatalogTaskOps for T where T: CatalogStore {}
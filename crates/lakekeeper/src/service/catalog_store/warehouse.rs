@@ -1,19 +1,23 @@
 use std::sync::Arc;
 
 use http::StatusCode;
-use iceberg::spec::FormatVersion;
-use iceberg_ext::catalog::rest::ErrorModel;
+use iceberg::spec::{FormatVersion, Transform};
+use iceberg_ext::catalog::rest::{ErrorModel, IcebergErrorResponse};
+use lakekeeper_io::Location;
 
 use super::{CatalogCreateWarehouseRequest, CatalogStore, Transaction};
 use crate::{
     ProjectId, SecretId, WarehouseId,
-    api::management::v1::{DeleteWarehouseQuery, warehouse::TabularDeleteProfile},
+    api::{
+        iceberg::v1::{PaginatedMapping, PaginationQuery},
+        management::v1::{DeleteWarehouseQuery, warehouse::TabularDeleteProfile},
+    },
     service::{
         ArcProjectId, DatabaseIntegrityError,
         authz::CatalogWarehouseAction,
         catalog_store::{
-            CatalogBackendError, define_transparent_error, impl_error_stack_methods,
-            impl_from_with_detail,
+            CatalogBackendError, InvalidPaginationToken, define_transparent_error,
+            impl_error_stack_methods, impl_from_with_detail,
             warehouse_cache::{
                 warehouse_cache_get_by_id, warehouse_cache_get_by_name,
                 warehouse_cache_get_or_load, warehouse_cache_insert,
@@ -61,10 +65,13 @@ pub enum WarehouseStatus {
 /// `self-managed` (the default) leaves the spec mutable by the warehouse's own
 /// owners through the usual grants. When set to `instance-admin`, spec changes —
 /// storage profile, credentials, delete profile, rename, status, protection,
-/// format-version policy, and deletion — are accepted only from instance
-/// administrators; other callers are rejected even when their grants would
-/// otherwise allow it. Child resources (namespaces, tables, grants), task-queue
-/// configuration, and data access are unaffected.
+/// format-version, partition-transform, file-format, metadata-location,
+/// schema-evolution, snapshot-limit, table-location, table-properties-limit and
+/// quota policies, credential mode, name case sensitivity, partition statistics
+/// validation, and deletion — are accepted only from instance administrators;
+/// other callers are rejected even when their grants would otherwise allow it.
+/// Child resources (namespaces, tables, grants), task-queue configuration, and
+/// data access are unaffected.
 #[derive(
     Debug,
     Clone,
@@ -227,6 +234,494 @@ pub struct WarehouseFormatVersionPolicy {
     pub default_format_version: Option<FormatVersion>,
 }
 
+/// Kind of an Iceberg partition transform, ignoring its parameter (the bucket
+/// count of `bucket[N]` or the width of `truncate[W]`).
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+pub enum PartitionTransformKind {
+    Identity,
+    Bucket,
+    Truncate,
+    Year,
+    Month,
+    Day,
+    Hour,
+    Void,
+}
+
+impl PartitionTransformKind {
+    /// Kind of `transform`. Returns `None` for transforms unknown to Iceberg.
+    #[must_use]
+    pub fn of(transform: Transform) -> Option<Self> {
+        match transform {
+            Transform::Identity => Some(Self::Identity),
+            Transform::Bucket(_) => Some(Self::Bucket),
+            Transform::Truncate(_) => Some(Self::Truncate),
+            Transform::Year => Some(Self::Year),
+            Transform::Month => Some(Self::Month),
+            Transform::Day => Some(Self::Day),
+            Transform::Hour => Some(Self::Hour),
+            Transform::Void => Some(Self::Void),
+            Transform::Unknown => None,
+        }
+    }
+}
+
+/// Per-warehouse policy restricting the partition transforms that may be used
+/// in partition specs of tables created in, or committed to, the warehouse.
+///
+/// Only newly created tables and newly added partition specs are validated, so
+/// tightening a policy does not retroactively block writes to existing tables.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionTransformPolicy {
+    /// Partition transforms that may be used. Must not be empty.
+    allowed_transforms: Vec<PartitionTransformKind>,
+    /// Upper bound for the number of buckets of a `bucket[N]` transform.
+    /// Unbounded when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_bucket_count: Option<u32>,
+}
+
+impl PartitionTransformPolicy {
+    /// Build a policy, deduplicating and sorting the allowed transforms.
+    ///
+    /// # Errors
+    /// Returns [`EmptyAllowedPartitionTransformsError`] if no transforms are provided.
+    pub fn try_new(
+        allowed_transforms: impl IntoIterator<Item = PartitionTransformKind>,
+        max_bucket_count: Option<u32>,
+    ) -> Result<Self, EmptyAllowedPartitionTransformsError> {
+        let mut allowed_transforms: Vec<PartitionTransformKind> =
+            allowed_transforms.into_iter().collect();
+        allowed_transforms.sort_unstable();
+        allowed_transforms.dedup();
+        if allowed_transforms.is_empty() {
+            return Err(EmptyAllowedPartitionTransformsError::new());
+        }
+        Ok(Self {
+            allowed_transforms,
+            max_bucket_count,
+        })
+    }
+
+    #[must_use]
+    pub fn allowed_transforms(&self) -> &[PartitionTransformKind] {
+        &self.allowed_transforms
+    }
+
+    #[must_use]
+    pub fn max_bucket_count(&self) -> Option<u32> {
+        self.max_bucket_count
+    }
+
+    /// Whether `transform` is permitted by this policy.
+    #[must_use]
+    pub fn allows(&self, transform: Transform) -> bool {
+        let Some(kind) = PartitionTransformKind::of(transform) else {
+            return false;
+        };
+        if !self.allowed_transforms.contains(&kind) {
+            return false;
+        }
+        match (transform, self.max_bucket_count) {
+            (Transform::Bucket(n), Some(max)) => n <= max,
+            _ => true,
+        }
+    }
+}
+
+define_simple_error!(
+    EmptyAllowedPartitionTransformsError,
+    "allowed_transforms must contain at least one partition transform."
+);
+
+impl From<EmptyAllowedPartitionTransformsError> for ErrorModel {
+    fn from(err: EmptyAllowedPartitionTransformsError) -> Self {
+        ErrorModel::builder()
+            .r#type("EmptyAllowedPartitionTransforms")
+            .code(StatusCode::BAD_REQUEST.as_u16())
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
+/// Data file format a table may write by default, as configured by the
+/// `write.format.default` table property.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+pub enum WriteFileFormat {
+    Parquet,
+    Avro,
+    Orc,
+}
+
+impl WriteFileFormat {
+    /// Table property selecting the default data file format of a table.
+    pub const PROPERTY: &'static str = "write.format.default";
+    /// Format used by Iceberg if [`Self::PROPERTY`] is not set.
+    pub const DEFAULT: Self = Self::Parquet;
+
+    /// Parse the value of [`Self::PROPERTY`]. Matching is case-insensitive, like
+    /// in the Iceberg reference implementation. Returns `None` for unknown formats.
+    #[must_use]
+    pub fn from_property_value(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "parquet" => Some(Self::Parquet),
+            "avro" => Some(Self::Avro),
+            "orc" => Some(Self::Orc),
+            _ => None,
+        }
+    }
+}
+
+/// Per-warehouse policy restricting the default data file format
+/// (`write.format.default`) of tables created in, or committed to, the warehouse.
+///
+/// Only table creation and changes of the property are validated, so tightening
+/// a policy does not retroactively block writes to existing tables.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct FileFormatPolicy {
+    /// File formats that may be used. Must not be empty.
+    allowed_file_formats: Vec<WriteFileFormat>,
+}
+
+impl FileFormatPolicy {
+    /// Build a policy, deduplicating and sorting the allowed file formats.
+    ///
+    /// # Errors
+    /// Returns [`EmptyAllowedFileFormatsError`] if no file formats are provided.
+    pub fn try_new(
+        allowed_file_formats: impl IntoIterator<Item = WriteFileFormat>,
+    ) -> Result<Self, EmptyAllowedFileFormatsError> {
+        let mut allowed_file_formats: Vec<WriteFileFormat> =
+            allowed_file_formats.into_iter().collect();
+        allowed_file_formats.sort_unstable();
+        allowed_file_formats.dedup();
+        if allowed_file_formats.is_empty() {
+            return Err(EmptyAllowedFileFormatsError::new());
+        }
+        Ok(Self {
+            allowed_file_formats,
+        })
+    }
+
+    #[must_use]
+    pub fn allowed_file_formats(&self) -> &[WriteFileFormat] {
+        &self.allowed_file_formats
+    }
+
+    /// Whether `write.format.default` may be set to `value`. `None` stands for
+    /// an unset property, which resolves to [`WriteFileFormat::DEFAULT`].
+    #[must_use]
+    pub fn allows(&self, value: Option<&str>) -> bool {
+        let format = match value {
+            Some(value) => WriteFileFormat::from_property_value(value),
+            None => Some(WriteFileFormat::DEFAULT),
+        };
+        format.is_some_and(|format| self.allowed_file_formats.contains(&format))
+    }
+}
+
+define_simple_error!(
+    EmptyAllowedFileFormatsError,
+    "allowed_file_formats must contain at least one file format."
+);
+
+impl From<EmptyAllowedFileFormatsError> for ErrorModel {
+    fn from(err: EmptyAllowedFileFormatsError) -> Self {
+        ErrorModel::builder()
+            .r#type("EmptyAllowedFileFormats")
+            .code(StatusCode::BAD_REQUEST.as_u16())
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
+/// How schema changes committed to tables of a warehouse are validated.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+pub enum SchemaEvolutionPolicy {
+    /// Any schema change accepted by the Iceberg metadata builder is allowed.
+    /// This is the default.
+    #[default]
+    Permissive,
+    /// Schema changes that could break existing readers or writers are rejected:
+    /// removing required fields, making optional fields required, and changing
+    /// field types other than the type promotions allowed by the Iceberg spec.
+    Safe,
+}
+
+/// Data access mechanisms a warehouse offers to its clients.
+///
+/// Restricts the access delegation requested by clients via the
+/// `X-Iceberg-Access-Delegation` header. Disabled mechanisms are neither
+/// advertised nor served.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+pub enum WarehouseCredentialMode {
+    /// Only vended credentials are provided. Remote signing requests are rejected.
+    Vended,
+    /// Only remote signing is provided. No credentials are vended.
+    RemoteSigning,
+    /// Both mechanisms are available. This is the default.
+    #[default]
+    Both,
+}
+
+impl WarehouseCredentialMode {
+    #[must_use]
+    pub fn allows_vended_credentials(self) -> bool {
+        matches!(self, Self::Vended | Self::Both)
+    }
+
+    #[must_use]
+    pub fn allows_remote_signing(self) -> bool {
+        matches!(self, Self::RemoteSigning | Self::Both)
+    }
+
+    /// Value of the `X-Iceberg-Access-Delegation` header clients should send,
+    /// or `None` if clients are free to choose.
+    #[must_use]
+    pub fn access_delegation(self) -> Option<&'static str> {
+        match self {
+            Self::Vended => Some("vended-credentials"),
+            Self::RemoteSigning => Some("remote-signing"),
+            Self::Both => None,
+        }
+    }
+}
+
+/// Per-warehouse policies for the tables, views and data access of a warehouse.
+///
+/// Settings are replaced as a whole. Omitted fields take their default, which
+/// imposes no restriction, except for `require-location-in-warehouse`, which
+/// defaults to `true`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case", default)]
+pub struct WarehouseSettings {
+    /// Restricts the partition transforms usable in this warehouse. `None`
+    /// allows every transform.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition_transform_policy: Option<PartitionTransformPolicy>,
+    /// Restricts the default data file format of tables in this warehouse.
+    /// `None` allows every format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_format_policy: Option<FileFormatPolicy>,
+    /// If `true`, metadata files of tables in this warehouse must be located
+    /// below the table location.
+    pub require_metadata_in_table_location: bool,
+    /// If `true`, default table locations derived from the namespace location
+    /// must be located below the storage profile's base location as well.
+    /// User-provided locations are always validated. Defaults to `true`.
+    pub require_location_in_warehouse: bool,
+    /// How schema changes committed to tables of this warehouse are validated.
+    pub schema_evolution_policy: SchemaEvolutionPolicy,
+    /// Maximum number of snapshots a table of this warehouse may retain. Commits
+    /// exceeding it expire the oldest unreferenced snapshots. Unbounded when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(minimum = 1, maximum = 2_147_483_647))]
+    pub max_snapshots_per_table: Option<u32>,
+    /// Maximum number of properties a table of this warehouse may have. Unbounded when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(minimum = 1, maximum = 2_147_483_647))]
+    pub max_table_properties: Option<u32>,
+    /// Maximum total size in bytes of the keys and values of a table's properties.
+    /// Unbounded when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(minimum = 1, maximum = 2_147_483_647))]
+    pub max_table_properties_size_bytes: Option<u32>,
+    /// Maximum number of tables the warehouse may hold. Unbounded when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(minimum = 1, maximum = 2_147_483_647))]
+    pub max_tables: Option<u32>,
+    /// Maximum number of namespaces the warehouse may hold. Unbounded when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(minimum = 1, maximum = 2_147_483_647))]
+    pub max_namespaces: Option<u32>,
+    /// If `true`, partition statistics files set by a commit must exist in
+    /// storage. Commits referencing missing files are rejected.
+    pub validate_partition_statistics_files: bool,
+    /// Data access mechanisms offered to clients of this warehouse.
+    pub credential_mode: WarehouseCredentialMode,
+    /// If `true`, namespaces and tabulars of this warehouse are looked up by
+    /// their exact name instead of ignoring case.
+    pub case_sensitive_names: bool,
+}
+
+impl Default for WarehouseSettings {
+    fn default() -> Self {
+        Self {
+            partition_transform_policy: None,
+            file_format_policy: None,
+            require_metadata_in_table_location: false,
+            require_location_in_warehouse: true,
+            schema_evolution_policy: SchemaEvolutionPolicy::default(),
+            max_snapshots_per_table: None,
+            max_table_properties: None,
+            max_table_properties_size_bytes: None,
+            max_tables: None,
+            max_namespaces: None,
+            validate_partition_statistics_files: false,
+            credential_mode: WarehouseCredentialMode::default(),
+            case_sensitive_names: false,
+        }
+    }
+}
+
+/// Type of a protected entity in a warehouse.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    strum_macros::Display,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+pub enum ProtectedEntityType {
+    Namespace,
+    Table,
+    View,
+    GenericTable,
+}
+
+/// A namespace, table, view or generic table of a warehouse that is protected
+/// from deletion.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+pub struct ProtectedEntity {
+    /// Type of the entity
+    pub entity_type: ProtectedEntityType,
+    /// ID of the namespace or tabular
+    pub id: uuid::Uuid,
+    /// Full name of the entity. For tabulars, the namespace parts followed by
+    /// the name of the tabular.
+    pub name: Vec<String>,
+    /// Creation timestamp
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Last update timestamp
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Storage profile of a warehouse that a tabular is stored on.
+///
+/// Selected when a table is created and fixed afterwards. Namespaces and tables
+/// select the secondary profile via the [`StorageProfileSlot::PROPERTY`] property.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    strum_macros::Display,
+    strum_macros::EnumString,
+    strum_macros::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+#[cfg_attr(feature = "sqlx-postgres", derive(sqlx::Type))]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "sqlx-postgres",
+    sqlx(type_name = "storage_profile_slot", rename_all = "kebab-case")
+)]
+pub enum StorageProfileSlot {
+    /// The storage profile of the warehouse. This is the default.
+    #[default]
+    Primary,
+    /// The secondary storage profile of the warehouse.
+    Secondary,
+}
+
+impl StorageProfileSlot {
+    /// Namespace and table property selecting the storage profile at creation.
+    pub const PROPERTY: &'static str = "storage_profile";
+}
+
+/// Secondary storage profile of a warehouse, next to its primary profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecondaryStorage {
+    /// Storage profile used for tabulars on the secondary storage.
+    pub storage_profile: StorageProfile,
+    /// Storage secret ID used for the secondary storage.
+    pub storage_secret_id: Option<SecretId>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ResolvedWarehouse {
     /// ID of the warehouse.
@@ -239,12 +734,18 @@ pub struct ResolvedWarehouse {
     pub storage_profile: StorageProfile,
     /// Storage secret ID used for the warehouse.
     pub storage_secret_id: Option<SecretId>,
+    /// Optional secondary storage profile that namespaces and tables may select
+    /// at creation time instead of `storage_profile`.
+    pub secondary_storage: Option<SecondaryStorage>,
     /// Whether the warehouse is active.
     pub status: WarehouseStatus,
     /// Tabular delete profile used for the warehouse.
     pub tabular_delete_profile: TabularDeleteProfile,
     /// Whether the warehouse is protected from being deleted.
     pub protected: bool,
+    /// Whether the warehouse name is matched exactly. If `false`, the name is
+    /// matched case-insensitively.
+    pub case_sensitive_name: bool,
     /// Which control plane, if any, exclusively manages this warehouse's spec.
     /// When not [`ManagedBy::SelfManaged`], spec mutations are restricted to the
     /// managing control plane (see [`ManagedBy`]).
@@ -256,6 +757,8 @@ pub struct ResolvedWarehouse {
     /// does not specify one. When `None`, resolves to `V2` if allowed, otherwise
     /// the highest allowed version. Always a member of `allowed_format_versions`.
     pub default_format_version: Option<FormatVersion>,
+    /// Per-warehouse policies for tables, views and data access.
+    pub settings: WarehouseSettings,
     /// Timestamp when the warehouse metadata was last updated.
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
     /// Version of the warehouse entity.
@@ -264,6 +767,46 @@ pub struct ResolvedWarehouse {
 }
 
 impl ResolvedWarehouse {
+    /// Whether `name`, which matched this warehouse case-insensitively, also
+    /// addresses it under the warehouse's case sensitivity.
+    #[must_use]
+    pub fn is_addressed_by(&self, name: &str) -> bool {
+        !self.case_sensitive_name || self.name == name
+    }
+
+    /// Storage profile and secret ID of `slot`.
+    ///
+    /// # Errors
+    /// Fails if `slot` is [`StorageProfileSlot::Secondary`] and the warehouse
+    /// has no secondary storage profile.
+    pub fn storage(
+        &self,
+        slot: StorageProfileSlot,
+    ) -> Result<(&StorageProfile, Option<SecretId>), SecondaryStorageProfileMissing> {
+        match slot {
+            StorageProfileSlot::Primary => Ok((&self.storage_profile, self.storage_secret_id)),
+            StorageProfileSlot::Secondary => self
+                .secondary_storage
+                .as_ref()
+                .map(|s| (&s.storage_profile, s.storage_secret_id))
+                .ok_or_else(|| SecondaryStorageProfileMissing::new(self.warehouse_id)),
+        }
+    }
+
+    /// Storage profile slot that `location` belongs to.
+    ///
+    /// Primary and secondary storage profiles may not overlap, so locations below
+    /// the base location of the secondary storage profile belong to it.
+    #[must_use]
+    pub fn storage_slot_of_location(&self, location: &Location) -> StorageProfileSlot {
+        match &self.secondary_storage {
+            Some(secondary) if secondary.storage_profile.is_allowed_location(location) => {
+                StorageProfileSlot::Secondary
+            }
+            _ => StorageProfileSlot::Primary,
+        }
+    }
+
     #[cfg(feature = "test-utils")]
     #[must_use]
     pub fn new_random() -> Self {
@@ -277,12 +820,15 @@ impl ResolvedWarehouse {
             project_id: Arc::new(ProjectId::new_random()),
             storage_profile: MemoryProfile::default().into(),
             storage_secret_id: None,
+            secondary_storage: None,
             status: WarehouseStatus::Active,
             tabular_delete_profile: TabularDeleteProfile::default(),
             protected: false,
+            case_sensitive_name: false,
             managed_by: crate::service::ManagedBy::SelfManaged,
             allowed_format_versions: AllowedFormatVersions::default(),
             default_format_version: None,
+            settings: WarehouseSettings::default(),
             updated_at: None,
             version: WarehouseVersion(0),
         }
@@ -300,19 +846,66 @@ impl ResolvedWarehouse {
             project_id: Arc::new(ProjectId::new_random()),
             storage_profile: MemoryProfile::default().into(),
             storage_secret_id: None,
+            secondary_storage: None,
             status: WarehouseStatus::Active,
             tabular_delete_profile: TabularDeleteProfile::default(),
             protected: false,
+            case_sensitive_name: false,
             managed_by: crate::service::ManagedBy::SelfManaged,
             allowed_format_versions: AllowedFormatVersions::default(),
             default_format_version: None,
+            settings: WarehouseSettings::default(),
             updated_at: None,
             version: WarehouseVersion(0),
         }
     }
 }
 
+/// Number of entities currently stored in a warehouse.
+/// Soft-deleted and staged tabulars are not counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WarehouseEntityCounts {
+    pub num_namespaces: i64,
+    pub num_tables: i64,
+    pub num_views: i64,
+}
+
 // --------------------------- GENERAL ERROR ---------------------------
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[error("Warehouse '{warehouse_id}' has no secondary storage profile")]
+pub struct SecondaryStorageProfileMissing {
+    pub warehouse_id: WarehouseId,
+    pub stack: Vec<String>,
+}
+
+impl SecondaryStorageProfileMissing {
+    #[must_use]
+    pub fn new(warehouse_id: WarehouseId) -> Self {
+        Self {
+            warehouse_id,
+            stack: Vec::new(),
+        }
+    }
+}
+impl_error_stack_methods!(SecondaryStorageProfileMissing);
+
+impl From<SecondaryStorageProfileMissing> for ErrorModel {
+    fn from(err: SecondaryStorageProfileMissing) -> Self {
+        ErrorModel::builder()
+            .r#type("SecondaryStorageProfileMissing")
+            .code(StatusCode::BAD_REQUEST.as_u16())
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
+impl From<SecondaryStorageProfileMissing> for IcebergErrorResponse {
+    fn from(err: SecondaryStorageProfileMissing) -> Self {
+        ErrorModel::from(err).into()
+    }
+}
+
 #[derive(thiserror::Error, Debug, PartialEq)]
 #[error("A warehouse with id '{warehouse_id}' does not exist")]
 pub struct WarehouseIdNotFound {
@@ -583,6 +1176,7 @@ define_transparent_error! {
     variants: [
         CatalogBackendError,
         DatabaseIntegrityError,
+        InvalidPaginationToken,
     ]
 }
 
@@ -662,6 +1256,83 @@ define_transparent_error! {
     ]
 }
 
+// ------------------------- Set Warehouse Settings Error -------------------------
+define_transparent_error! {
+    pub enum SetWarehouseSettingsError,
+    stack_message: "Error setting warehouse settings in catalog",
+    variants: [
+        CatalogBackendError,
+        WarehouseIdNotFound,
+        DatabaseIntegrityError,
+    ]
+}
+
+// --------------------------- Warehouse Quota Exceeded ---------------------------
+/// Entities whose number per warehouse can be limited by a quota.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+pub enum WarehouseQuotaEntity {
+    #[strum(serialize = "tables")]
+    Table,
+    #[strum(serialize = "namespaces")]
+    Namespace,
+}
+
+/// Returned when creating a table or namespace would exceed a quota of the
+/// warehouse. HTTP 403 with a stable `QuotaExceeded` type.
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[error(
+    "Warehouse quota exceeded: the warehouse contains {current} {entity} and may contain at most {limit} {entity}."
+)]
+pub struct QuotaExceeded {
+    pub entity: WarehouseQuotaEntity,
+    /// Number of entities in the warehouse, counted up to `limit`.
+    pub current: u64,
+    pub limit: u32,
+    pub stack: Vec<String>,
+}
+impl QuotaExceeded {
+    #[must_use]
+    pub fn new(entity: WarehouseQuotaEntity, current: u64, limit: u32) -> Self {
+        Self {
+            entity,
+            current,
+            limit,
+            stack: Vec::new(),
+        }
+    }
+}
+impl_error_stack_methods!(QuotaExceeded);
+
+impl From<QuotaExceeded> for ErrorModel {
+    fn from(err: QuotaExceeded) -> Self {
+        ErrorModel::builder()
+            .r#type("QuotaExceeded")
+            .code(StatusCode::FORBIDDEN.as_u16())
+            .message(err.to_string())
+            .stack(err.stack)
+            .build()
+    }
+}
+
+define_transparent_error! {
+    pub enum EnsureWarehouseQuotaError,
+    stack_message: "Error checking warehouse quota",
+    variants: [
+        CatalogBackendError,
+        QuotaExceeded,
+    ]
+}
+
+// ----------------- List Protected Entities Error -----------------
+define_transparent_error! {
+    pub enum ListProtectedEntitiesError,
+    stack_message: "Error listing protected entities of warehouse in catalog",
+    variants: [
+        CatalogBackendError,
+        InvalidPaginationToken,
+    ]
+}
+
 // --------------------------- Set Warehouse Managed-By Error ---------------------------
 define_transparent_error! {
     pub enum SetWarehouseManagedByError,
@@ -834,6 +1505,40 @@ where
         Ok(warehouses)
     }
 
+    /// Return a page of warehouses in a project, oldest first.
+    ///
+    /// Pages are keyed on `(created_at, warehouse_id)`, so warehouses created
+    /// while paginating never shift already returned pages.
+    async fn list_warehouses_paginated<'a>(
+        project_id: &ProjectId,
+        // If None, returns active warehouses
+        // If Some, returns warehouses with any of the statuses in the set
+        include_inactive: Option<Vec<WarehouseStatus>>,
+        pagination_query: PaginationQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> Result<PaginatedMapping<WarehouseId, Arc<ResolvedWarehouse>>, CatalogListWarehousesError>
+    {
+        let page = Self::list_warehouses_paginated_impl(
+            project_id,
+            include_inactive,
+            pagination_query,
+            transaction,
+        )
+        .await?;
+        let mut warehouses = PaginatedMapping::with_capacity(page.len());
+        for (warehouse_id, warehouse, page_token) in page.into_iter_with_page_tokens() {
+            warehouses.insert(warehouse_id, Arc::new(warehouse), page_token);
+        }
+
+        let tasks = warehouses
+            .iter()
+            .map(|(_, warehouse)| warehouse_cache_insert(warehouse.clone()))
+            .collect::<Vec<_>>();
+        futures::future::join_all(tasks).await;
+
+        Ok(warehouses)
+    }
+
     /// Get the warehouse metadata.
     ///
     /// Return Ok(None) if the warehouse does not exist.
@@ -941,8 +1646,20 @@ where
         catalog_state: Self::State,
     ) -> Result<Option<Arc<ResolvedWarehouse>>, CatalogGetWarehouseByNameError> {
         // Fast path: name → id → warehouse (records hit/miss metrics).
+        // The name index is case-insensitive, while projects may opt into
+        // case-sensitive warehouse names. For those, only exact matches are served
+        // from the cache, the database decides for names that differ in case.
         let cached_warehouse = warehouse_cache_get_by_name(warehouse_name, project_id).await;
         if let Some(warehouse) = cached_warehouse {
+            if !warehouse.is_addressed_by(warehouse_name) {
+                return load_warehouse_by_name::<Self>(
+                    warehouse_name,
+                    project_id,
+                    status_filter,
+                    catalog_state,
+                )
+                .await;
+            }
             let warehouse = Some(warehouse).filter(|w| status_filter.contains(&w.status));
             return Ok(warehouse);
         }
@@ -969,20 +1686,18 @@ where
         };
 
         // Common case: the loader primed the by-id cache → hit.
-        if let Some(warehouse) = warehouse_cache_get_by_id(warehouse_id).await {
+        if let Some(warehouse) = warehouse_cache_get_by_id(warehouse_id)
+            .await
+            .filter(|w| w.is_addressed_by(warehouse_name))
+        {
             return Ok(Some(warehouse).filter(|w| status_filter.contains(&w.status)));
         }
 
-        // Rare: evicted between prime and read. Re-load by name (same error domain)
+        // Rare: evicted between prime and read, or coalesced with a lookup for
+        // a name that differs in case. Re-load by name (same error domain)
         // rather than return a spurious not-found for a warehouse that exists.
-        let warehouse = Self::get_warehouse_by_name_impl(warehouse_name, project_id, catalog_state)
-            .await?
-            .map(Arc::new);
-        if let Some(warehouse) = warehouse.clone() {
-            warehouse_cache_insert(warehouse).await;
-        }
-
-        Ok(warehouse.filter(|w| status_filter.contains(&w.status)))
+        load_warehouse_by_name::<Self>(warehouse_name, project_id, status_filter, catalog_state)
+            .await
     }
 
     // /// Wrapper around `get_warehouse_by_name` that returns
@@ -1034,6 +1749,23 @@ where
         .map(Arc::new)
     }
 
+    /// Set the secondary storage profile of a warehouse.
+    async fn update_secondary_storage_profile<'a>(
+        warehouse_id: WarehouseId,
+        storage_profile: StorageProfile,
+        storage_secret_id: Option<SecretId>,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'a>,
+    ) -> Result<Arc<ResolvedWarehouse>, UpdateWarehouseStorageProfileError> {
+        Self::update_secondary_storage_profile_impl(
+            warehouse_id,
+            storage_profile,
+            storage_secret_id,
+            transaction,
+        )
+        .await
+        .map(Arc::new)
+    }
+
     async fn set_warehouse_protected(
         warehouse_id: WarehouseId,
         protect: bool,
@@ -1054,10 +1786,83 @@ where
             .await
             .map(Arc::new)
     }
+
+    /// Replace the per-warehouse policies for tables, views and access.
+    async fn set_warehouse_settings(
+        warehouse_id: WarehouseId,
+        settings: &WarehouseSettings,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<Arc<ResolvedWarehouse>, SetWarehouseSettingsError> {
+        Self::set_warehouse_settings_impl(warehouse_id, settings, transaction)
+            .await
+            .map(Arc::new)
+    }
+
+    /// Fail with [`QuotaExceeded`] if adding `new_entities` entities of kind `entity`
+    /// would exceed the quota of the warehouse. Succeeds without querying the
+    /// catalog if the warehouse has no quota for `entity`.
+    ///
+    /// Must be called in the transaction that creates or restores the entities.
+    /// The warehouse is locked until the transaction ends, so that concurrent
+    /// requests can't exceed the quota together.
+    async fn ensure_warehouse_quota(
+        warehouse: &ResolvedWarehouse,
+        entity: WarehouseQuotaEntity,
+        new_entities: u32,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> std::result::Result<(), EnsureWarehouseQuotaError> {
+        let limit = match entity {
+            WarehouseQuotaEntity::Table => warehouse.settings.max_tables,
+            WarehouseQuotaEntity::Namespace => warehouse.settings.max_namespaces,
+        };
+        let Some(limit) = limit.filter(|_| new_entities > 0) else {
+            return Ok(());
+        };
+        let current = Self::count_warehouse_entities_up_to_impl(
+            warehouse.warehouse_id,
+            entity,
+            limit,
+            transaction,
+        )
+        .await?;
+        if current + u64::from(new_entities) > u64::from(limit) {
+            return Err(QuotaExceeded::new(entity, current, limit).into());
+        }
+        Ok(())
+    }
+
+    /// List the protected namespaces and active tabulars of a warehouse.
+    ///
+    /// Pages are keyed on `(created_at, id)` across namespaces and tabulars.
+    async fn list_protected_entities(
+        warehouse_id: WarehouseId,
+        pagination_query: PaginationQuery,
+        transaction: <Self::Transaction as Transaction<Self::State>>::Transaction<'_>,
+    ) -> Result<PaginatedMapping<uuid::Uuid, ProtectedEntity>, ListProtectedEntitiesError> {
+        Self::list_protected_entities_impl(warehouse_id, pagination_query, transaction).await
+    }
 }
 
 impl<T> CatalogWarehouseOps for T where T: CatalogStore {}
 
+/// Load a warehouse by name from the catalog store, bypassing the cache, and
+/// refresh the cache with the result.
+async fn load_warehouse_by_name<C: CatalogStore>(
+    warehouse_name: &str,
+    project_id: &ArcProjectId,
+    status_filter: &[WarehouseStatus],
+    catalog_state: C::State,
+) -> Result<Option<Arc<ResolvedWarehouse>>, CatalogGetWarehouseByNameError> {
+    let warehouse = C::get_warehouse_by_name_impl(warehouse_name, project_id, catalog_state)
+        .await?
+        .map(Arc::new);
+    if let Some(warehouse) = warehouse.clone() {
+        warehouse_cache_insert(warehouse).await;
+    }
+
+    Ok(warehouse.filter(|w| status_filter.contains(&w.status)))
+}
+
 #[cfg(test)]
 mod allowed_format_versions_tests {
     use iceberg::spec::FormatVersion;
@@ -1116,3 +1921,90 @@ mod allowed_format_versions_tests {
         assert_eq!(allowed.resolve_default(None), FormatVersion::V3);
     }
 }
+
+#[cfg(test)]
+mod partition_transform_policy_tests {
+    use iceberg::spec::Transform;
+
+    use super::{PartitionTransformKind, PartitionTransformPolicy};
+
+    #[test]
+    fn try_new_rejects_empty() {
+        assert!(PartitionTransformPolicy::try_new([], None).is_err());
+    }
+
+    #[test]
+    fn try_new_dedups_and_sorts() {
+        let policy = PartitionTransformPolicy::try_new(
+            [
+                PartitionTransformKind::Day,
+                PartitionTransformKind::Identity,
+                PartitionTransformKind::Day,
+            ],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            policy.allowed_transforms(),
+            &[
+                PartitionTransformKind::Identity,
+                PartitionTransformKind::Day
+            ]
+        );
+    }
+
+    #[test]
+    fn allows_only_listed_transforms() {
+        let policy = PartitionTransformPolicy::try_new(
+            [
+                PartitionTransformKind::Identity,
+                PartitionTransformKind::Day,
+            ],
+            None,
+        )
+        .unwrap();
+        assert!(policy.allows(Transform::Identity));
+        assert!(policy.allows(Transform::Day));
+        assert!(!policy.allows(Transform::Bucket(16)));
+        assert!(!policy.allows(Transform::Unknown));
+    }
+
+    #[test]
+    fn max_bucket_count_is_enforced() {
+        let policy =
+            PartitionTransformPolicy::try_new([PartitionTransformKind::Bucket], Some(16)).unwrap();
+        assert!(policy.allows(Transform::Bucket(16)));
+        assert!(!policy.allows(Transform::Bucket(17)));
+    }
+}
+
+#[cfg(test)]
+mod file_format_policy_tests {
+    use super::{FileFormatPolicy, WriteFileFormat};
+
+    #[test]
+    fn empty_policy_is_rejected() {
+        assert!(FileFormatPolicy::try_new([]).is_err());
+    }
+
+    #[test]
+    fn allows_only_listed_formats() {
+        let policy =
+            FileFormatPolicy::try_new([WriteFileFormat::Orc, WriteFileFormat::Parquet]).unwrap();
+        assert_eq!(
+            policy.allowed_file_formats(),
+            &[WriteFileFormat::Parquet, WriteFileFormat::Orc]
+        );
+        assert!(policy.allows(Some("parquet")));
+        assert!(policy.allows(Some("ORC")));
+        assert!(!policy.allows(Some("avro")));
+        assert!(!policy.allows(Some("csv")));
+        // An unset property resolves to parquet
+        assert!(policy.allows(None));
+        assert!(
+            !FileFormatPolicy::try_new([WriteFileFormat::Avro])
+                .unwrap()
+                .allows(None)
+        );
+    }
+}
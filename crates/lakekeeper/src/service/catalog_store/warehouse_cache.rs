@@ -425,6 +425,19 @@ impl EventListener for WarehouseCacheEventListener {
         Ok(())
     }
 
+    async fn warehouse_settings_updated(
+        &self,
+        event: events::UpdateWarehouseSettingsEvent,
+    ) -> anyhow::Result<()> {
+        let events::UpdateWarehouseSettingsEvent {
+            request: _request,
+            updated_warehouse,
+            request_metadata: _request_metadata,
+        } = event;
+        warehouse_cache_insert(updated_warehouse).await;
+        Ok(())
+    }
+
     async fn warehouse_storage_updated(
         &self,
         event: events::UpdateWarehouseStorageEvent,
@@ -438,6 +451,19 @@ impl EventListener for WarehouseCacheEventListener {
         Ok(())
     }
 
+    async fn warehouse_secondary_storage_updated(
+        &self,
+        event: events::UpdateWarehouseSecondaryStorageEvent,
+    ) -> anyhow::Result<()> {
+        let events::UpdateWarehouseSecondaryStorageEvent {
+            request: _request,
+            updated_warehouse,
+            request_metadata: _request_metadata,
+        } = event;
+        warehouse_cache_insert(updated_warehouse).await;
+        Ok(())
+    }
+
     async fn warehouse_storage_credential_updated(
         &self,
         event: events::UpdateWarehouseStorageCredentialEvent,
@@ -478,12 +504,15 @@ mod tests {
             project_id,
             storage_profile: MemoryProfile::default().into(),
             storage_secret_id: None,
+            secondary_storage: None,
             status: WarehouseStatus::Active,
             tabular_delete_profile: TabularDeleteProfile::Hard {},
             protected: false,
+            case_sensitive_name: false,
             managed_by: crate::service::ManagedBy::SelfManaged,
             allowed_format_versions: crate::service::AllowedFormatVersions::default(),
             default_format_version: None,
+            settings: crate::service::WarehouseSettings::default(),
             updated_at,
             version: version.into(),
         })
@@ -122,6 +122,73 @@ pub enum FlushMode {
     Manual,
 }
 
+/// Fraction of requests counted by the tracker.
+///
+/// With a rate below `1.0`, only a random sample of requests is counted and each
+/// sampled request is weighted with the inverse of the rate, so that the recorded
+/// counts approximate the true counts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleRates {
+    default_rate: f64,
+    project_rates: HashMap<String, f64>,
+}
+
+impl Default for SampleRates {
+    fn default() -> Self {
+        Self {
+            default_rate: 1.0,
+            project_rates: HashMap::new(),
+        }
+    }
+}
+
+impl SampleRates {
+    /// Create sample rates from a default rate and per-project overrides.
+    ///
+    /// # Panics
+    /// If any rate is not within `(0, 1]`.
+    #[must_use]
+    pub fn new(default_rate: f64, project_rates: HashMap<String, f64>) -> Self {
+        for (project, rate) in std::iter::once(("default", &default_rate))
+            .chain(project_rates.iter().map(|(k, v)| (k.as_str(), v)))
+        {
+            assert!(
+                *rate > 0.0 && *rate <= 1.0,
+                "Invalid endpoint statistics sample rate {rate} for '{project}': must be within (0, 1]"
+            );
+        }
+        Self {
+            default_rate,
+            project_rates,
+        }
+    }
+
+    #[must_use]
+    pub fn rate(&self, project: &str) -> f64 {
+        self.project_rates
+            .get(project)
+            .copied()
+            .unwrap_or(self.default_rate)
+    }
+
+    /// Weight to add for a request of `project`, or `None` if the request is not sampled.
+    fn sample(&self, project: &str) -> Option<i64> {
+        let rate = self.rate(project);
+        if rate >= 1.0 {
+            return Some(1);
+        }
+        if fastrand::f64() >= rate {
+            return None;
+        }
+        // Round the inverse rate stochastically so that the weight is unbiased
+        // for rates whose inverse is not an integer.
+        let inverse = 1.0 / rate;
+        #[allow(clippy::cast_possible_truncation)]
+        let weight = inverse.floor() as i64 + i64::from(fastrand::f64() < inverse.fract());
+        Some(weight)
+    }
+}
+
 #[derive(Debug)]
 pub struct EndpointStatisticsTracker {
     rcv: tokio::sync::mpsc::Receiver<EndpointStatisticsMessage>,
@@ -129,6 +196,7 @@ pub struct EndpointStatisticsTracker {
     statistic_sinks: Vec<Arc<dyn EndpointStatisticsSink>>,
     flush_interval: Duration,
     flush_mode: FlushMode,
+    sample_rates: SampleRates,
 }
 
 #[derive(Debug)]
@@ -151,9 +219,17 @@ impl EndpointStatisticsTracker {
             statistic_sinks: stat_sinks,
             flush_interval,
             flush_mode,
+            sample_rates: SampleRates::default(),
         }
     }
 
+    /// Only count a sample of the requests, see [`SampleRates`].
+    #[must_use]
+    pub fn with_sample_rates(mut self, sample_rates: SampleRates) -> Self {
+        self.sample_rates = sample_rates;
+        self
+    }
+
     pub async fn run(mut self) {
         let mut last_update = tokio::time::Instant::now();
         loop {
@@ -281,6 +357,9 @@ impl EndpointStatisticsTracker {
             tracing::debug!("No project specified, request not counted.");
             return;
         };
+        let Some(weight) = self.sample_rates.sample(&project) else {
+            return;
+        };
 
         self.endpoint_statistics
             .entry(project)
@@ -293,7 +372,7 @@ impl EndpointStatisticsTracker {
                 warehouse_name: query_params.get("warehouse").cloned(),
             })
             .or_insert_with(|| AtomicI64::new(0))
-            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            .fetch_add(weight, std::sync::atomic::Ordering::Relaxed);
     }
 
     fn maybe_get_warehouse_ident(path_params: &HashMap<String, String>) -> Option<WarehouseId> {
@@ -334,3 +413,64 @@ pub trait EndpointStatisticsSink: Debug + Send + Sync + 'static {
 
     fn sink_id(&self) -> &'static str;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ProjectId, api::RequestMetadataTestBuilder};
+
+    fn project_id() -> ArcProjectId {
+        Arc::new(ProjectId::from(Uuid::nil()))
+    }
+
+    fn recorded_count(sample_rates: SampleRates, calls: i64) -> i64 {
+        let (_tx, rx) = tokio::sync::mpsc::channel(1);
+        let mut tracker =
+            EndpointStatisticsTracker::new(rx, vec![], Duration::from_secs(30), FlushMode::Manual)
+                .with_sample_rates(sample_rates);
+        let request_metadata = RequestMetadataTestBuilder::builder()
+            .project_id(project_id())
+            .matched_path(Some(Arc::from("/management/v1/info")))
+            .request_method(http::Method::GET)
+            .build();
+        for _ in 0..calls {
+            tracker.process_endpoint_called(
+                &request_metadata,
+                StatusCode::OK,
+                &HashMap::new(),
+                &HashMap::new(),
+            );
+        }
+        tracker
+            .endpoint_statistics
+            .into_values()
+            .flat_map(|stats| stats.into_consumable().into_values())
+            .sum()
+    }
+
+    #[test]
+    fn test_sampled_counts_approximate_true_count() {
+        let calls = 10_000;
+        let recorded = recorded_count(SampleRates::new(0.1, HashMap::new()), calls);
+        // The standard deviation of the recorded count is 300.
+        assert!(
+            (recorded - calls).abs() < calls * 15 / 100,
+            "Recorded {recorded} for {calls} calls"
+        );
+    }
+
+    #[test]
+    fn test_project_sample_rate_overrides_default() {
+        let project_rates = HashMap::from([(project_id().to_string(), 1.0)]);
+        assert_eq!(
+            recorded_count(SampleRates::new(0.1, project_rates), 100),
+            100
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be within (0, 1]")]
+    fn test_zero_sample_rate_is_rejected() {
+        let _ = SampleRates::new(0.0, HashMap::new());
+    }
+}
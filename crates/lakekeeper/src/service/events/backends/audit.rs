@@ -2,15 +2,22 @@ use std::fmt::Display;
 
 use valuable::{Listable, Mappable, Valuable, Value, Visit};
 
-use crate::service::{
-    authn::{Actor, InternalActor},
-    authz::{ActionDescriptor, ContextValue, DeterminingFactor, UserOrRoleId},
-    events::{
-        Authorization, AuthorizationFailedEvent, AuthorizationSucceededEvent, EventListener,
-        context::EntityDescriptor,
+use crate::{
+    api::endpoints::EndpointFlat,
+    service::{
+        authn::{Actor, InternalActor},
+        authz::{ActionDescriptor, ContextValue, DeterminingFactor, UserOrRoleId},
+        events::{
+            Authorization, AuthorizationFailedEvent, AuthorizationSucceededEvent, EventListener,
+            ManagementOperationEvent, context::EntityDescriptor,
+        },
     },
 };
 
+/// Tracing target of the management operation audit trail. Route this target
+/// to a dedicated sink to retain audit records separately from other logs.
+pub const AUDIT_TRACING_TARGET: &str = "lakekeeper::audit";
+
 /// Newtype around `Vec<Authorization>` so we can implement `Valuable` /
 /// `Listable` for it without an orphan-rule violation. Borrowed because the
 /// audit emit path holds the Vec via `Arc`.
@@ -240,6 +247,25 @@ impl EventListener for AuditEventListener {
         }
         Ok(())
     }
+
+    async fn management_operation_completed(
+        &self,
+        event: ManagementOperationEvent,
+    ) -> anyhow::Result<()> {
+        tracing::info!(
+            target: AUDIT_TRACING_TARGET,
+            event_source = "audit",
+            operation = %EndpointFlat::from(event.endpoint),
+            http_route = event.endpoint.as_http_route(),
+            actor = tracing::field::valuable(&event.request_metadata.internal_actor().as_value()),
+            targets = tracing::field::valuable(&event.targets.as_value()),
+            outcome = event.outcome().as_str(),
+            http_status = event.http_status.as_u16(),
+            request_id = %event.request_metadata.request_id(),
+            "Management operation completed"
+        );
+        Ok(())
+    }
 }
 
 impl Valuable for EntityDescriptor {
@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+use serde_json::json;
+
+use crate::{
+    api::endpoints::EndpointFlat,
+    service::{
+        AuditLogRecord, CatalogAuditLogOps, CatalogStore,
+        authn::{Actor, InternalActor},
+        events::{EventListener, ManagementOperationEvent},
+    },
+};
+
+/// Persists management operation audit events through the catalog store, e.g.
+/// into the Postgres `audit_log` table.
+///
+/// Registered when `LAKEKEEPER__AUDIT__POSTGRES__ENABLED` is set. Runs in
+/// addition to the tracing based [`AuditEventListener`](super::audit::AuditEventListener).
+pub struct CatalogAuditLogListener<C: CatalogStore> {
+    catalog_state: C::State,
+}
+
+impl<C: CatalogStore> CatalogAuditLogListener<C> {
+    #[must_use]
+    pub fn new(catalog_state: C::State) -> Self {
+        Self { catalog_state }
+    }
+}
+
+impl<C: CatalogStore> std::fmt::Debug for CatalogAuditLogListener<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CatalogAuditLogListener").finish()
+    }
+}
+
+impl<C: CatalogStore> Display for CatalogAuditLogListener<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CatalogAuditLogListener")
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: CatalogStore> EventListener for CatalogAuditLogListener<C> {
+    async fn management_operation_completed(
+        &self,
+        event: ManagementOperationEvent,
+    ) -> anyhow::Result<()> {
+        let record = audit_log_record(&event);
+        C::insert_audit_log_record(&record, self.catalog_state.clone())
+            .await
+            .map_err(|e| anyhow::Error::new(e.error))
+    }
+}
+
+fn audit_log_record(event: &ManagementOperationEvent) -> AuditLogRecord {
+    AuditLogRecord {
+        request_id: event.request_metadata.request_id(),
+        project_id: event.request_metadata.preferred_project_id(),
+        operation: EndpointFlat::from(event.endpoint),
+        actor: actor_json(event.request_metadata.internal_actor()),
+        targets: json!(*event.targets),
+        outcome: event.outcome(),
+        http_status: event.http_status,
+    }
+}
+
+/// Same shape as the `Valuable` representation used for the `actor` field of
+/// audit tracing events, so both sinks can be queried alike.
+fn actor_json(actor: &InternalActor) -> serde_json::Value {
+    match actor {
+        InternalActor::LakekeeperInternal => json!({ "actor_type": "lakekeeper-internal" }),
+        InternalActor::External(Actor::Anonymous) => json!({ "actor_type": "anonymous" }),
+        InternalActor::External(Actor::Principal(user_id)) => json!({
+            "actor_type": "principal",
+            "principal": user_id.to_string(),
+        }),
+        InternalActor::External(Actor::Role {
+            principal,
+            assumed_role,
+        }) => json!({
+            "actor_type": "assumed-role",
+            "principal": principal.to_string(),
+            "assumed_role": {
+                "role_id": assumed_role.id.to_string(),
+                "provider_id": assumed_role.provider_id().to_string(),
+                "source_id": assumed_role.source_id().to_string(),
+            },
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use http::StatusCode;
+
+    use super::*;
+    use crate::{
+        api::{
+            RequestMetadata,
+            endpoints::{Endpoint, ManagementV1Endpoint},
+        },
+        service::{UserId, events::OperationOutcome},
+    };
+
+    #[test]
+    fn record_carries_actor_targets_and_outcome() {
+        let user_id = UserId::new_unchecked("oidc", "alice");
+        let event = ManagementOperationEvent {
+            endpoint: Endpoint::from(ManagementV1Endpoint::DeleteWarehouse),
+            targets: Arc::new(BTreeMap::from([(
+                "warehouse_id".to_string(),
+                "0192e6ad-7a1c-7c41-a1f2-4b3a5e1a2c3d".to_string(),
+            )])),
+            http_status: StatusCode::NO_CONTENT,
+            request_metadata: Arc::new(RequestMetadata::test_user(user_id.clone())),
+        };
+
+        let record = audit_log_record(&event);
+        assert_eq!(record.operation, EndpointFlat::ManagementV1DeleteWarehouse);
+        assert_eq!(record.outcome, OperationOutcome::Success);
+        assert_eq!(
+            record.actor,
+            json!({ "actor_type": "principal", "principal": user_id.to_string() })
+        );
+        assert_eq!(
+            record.targets,
+            json!({ "warehouse_id": "0192e6ad-7a1c-7c41-a1f2-4b3a5e1a2c3d" })
+        );
+    }
+}
@@ -547,6 +547,18 @@ impl APIEventActions for WarehouseActionSearchTabulars {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct WarehouseActionListViews {}
+impl APIEventActions for WarehouseActionListViews {
+    fn event_actions(&self) -> Vec<ActionDescriptor> {
+        vec![
+            ActionDescriptor::builder()
+                .action_name("list_views")
+                .build(),
+        ]
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct IntrospectPermissions {}
 impl APIEventActions for IntrospectPermissions {
@@ -202,6 +202,10 @@ impl EventDispatcher {
         dispatch_event!(self, tabular_undropped, event);
     }
 
+    pub(crate) async fn deleted_tabular_purged(&self, event: types::PurgeDeletedTabularEvent) {
+        dispatch_event!(self, deleted_tabular_purged, event);
+    }
+
     pub(crate) async fn project_created(&self, event: types::CreateProjectEvent) {
         dispatch_event!(self, project_created, event);
     }
@@ -240,6 +244,13 @@ impl EventDispatcher {
         dispatch_event!(self, warehouse_format_version_policy_updated, event);
     }
 
+    pub(crate) async fn warehouse_settings_updated(
+        &self,
+        event: types::UpdateWarehouseSettingsEvent,
+    ) {
+        dispatch_event!(self, warehouse_settings_updated, event);
+    }
+
     pub(crate) async fn warehouse_storage_updated(
         &self,
         event: types::UpdateWarehouseStorageEvent,
@@ -247,6 +258,13 @@ impl EventDispatcher {
         dispatch_event!(self, warehouse_storage_updated, event);
     }
 
+    pub(crate) async fn warehouse_secondary_storage_updated(
+        &self,
+        event: types::UpdateWarehouseSecondaryStorageEvent,
+    ) {
+        dispatch_event!(self, warehouse_secondary_storage_updated, event);
+    }
+
     pub(crate) async fn warehouse_storage_credential_updated(
         &self,
         event: types::UpdateWarehouseStorageCredentialEvent,
@@ -258,6 +276,13 @@ impl EventDispatcher {
         dispatch_event!(self, task_queue_config_set, event);
     }
 
+    pub(crate) async fn namespace_delete_profile_set(
+        &self,
+        event: types::SetNamespaceDeleteProfileEvent,
+    ) {
+        dispatch_event!(self, namespace_delete_profile_set, event);
+    }
+
     pub(crate) async fn namespace_protection_set(&self, event: types::SetNamespaceProtectionEvent) {
         dispatch_event!(self, namespace_protection_set, event);
     }
@@ -270,6 +295,10 @@ impl EventDispatcher {
         dispatch_event!(self, namespace_dropped, event);
     }
 
+    pub(crate) async fn namespace_moved(&self, event: types::MoveNamespaceEvent) {
+        dispatch_event!(self, namespace_moved, event);
+    }
+
     pub(crate) async fn namespace_properties_updated(
         &self,
         event: types::UpdateNamespacePropertiesEvent,
@@ -285,6 +314,15 @@ impl EventDispatcher {
         dispatch_event!(self, authorization_succeeded, event);
     }
 
+    // ===== Management Audit Events =====
+
+    pub(crate) async fn management_operation_completed(
+        &self,
+        event: types::ManagementOperationEvent,
+    ) {
+        dispatch_event!(self, management_operation_completed, event);
+    }
+
     // ===== Role Events =====
 
     pub(crate) async fn role_created(&self, event: types::CreateRoleEvent) {
@@ -453,6 +491,14 @@ pub trait EventListener: Send + Sync + Debug + Display {
         Ok(())
     }
 
+    /// Invoked after a soft-deleted table or view has been successfully purged
+    async fn deleted_tabular_purged(
+        &self,
+        _event: types::PurgeDeletedTabularEvent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     // ===== Project Events =====
 
     /// Invoked after a project has been successfully created
@@ -509,6 +555,14 @@ pub trait EventListener: Send + Sync + Debug + Display {
         Ok(())
     }
 
+    /// Invoked after warehouse settings have been successfully updated
+    async fn warehouse_settings_updated(
+        &self,
+        _event: types::UpdateWarehouseSettingsEvent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Invoked after warehouse storage configuration has been successfully updated
     async fn warehouse_storage_updated(
         &self,
@@ -517,6 +571,14 @@ pub trait EventListener: Send + Sync + Debug + Display {
         Ok(())
     }
 
+    /// Invoked after the secondary storage configuration of a warehouse has been successfully updated
+    async fn warehouse_secondary_storage_updated(
+        &self,
+        _event: types::UpdateWarehouseSecondaryStorageEvent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Invoked after warehouse storage credentials have been successfully updated
     async fn warehouse_storage_credential_updated(
         &self,
@@ -533,6 +595,14 @@ pub trait EventListener: Send + Sync + Debug + Display {
         Ok(())
     }
 
+    /// Invoked after the delete profile override of a namespace has been successfully set or cleared
+    async fn namespace_delete_profile_set(
+        &self,
+        _event: types::SetNamespaceDeleteProfileEvent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     // ===== Namespace Events =====
 
     /// Invoked after namespace protection status has been successfully changed
@@ -553,6 +623,11 @@ pub trait EventListener: Send + Sync + Debug + Display {
         Ok(())
     }
 
+    /// Invoked after a namespace has been successfully moved to a different parent
+    async fn namespace_moved(&self, _event: types::MoveNamespaceEvent) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Invoked after namespace properties have been successfully updated
     async fn namespace_properties_updated(
         &self,
@@ -606,6 +681,24 @@ pub trait EventListener: Send + Sync + Debug + Display {
         Ok(())
     }
 
+    // ===== Management Audit Hooks =====
+
+    /// Invoked after a mutating management API request (`POST`, `PUT`,
+    /// `PATCH` or `DELETE` below `/management/v1`) has produced a response.
+    ///
+    /// Unlike the other hooks this fires for failed requests as well; use
+    /// [`types::ManagementOperationEvent::outcome`] to tell them apart.
+    ///
+    /// # Use Cases
+    /// - Compliance audit trails
+    /// - Change tracking of warehouses, projects, roles and users
+    async fn management_operation_completed(
+        &self,
+        _event: types::ManagementOperationEvent,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     // ===== Role Events =====
 
     /// Invoked after a role has been successfully created
@@ -527,6 +527,37 @@ impl EventListener for CloudEventsPublisher {
             .context("Failed to publish `undropTabulars` event")?;
         Ok(())
     }
+
+    async fn deleted_tabular_purged(
+        &self,
+        event: types::PurgeDeletedTabularEvent,
+    ) -> anyhow::Result<()> {
+        let types::PurgeDeletedTabularEvent {
+            warehouse,
+            tabular,
+            force: _force,
+            request_metadata,
+        } = event;
+        self.publish(
+            Uuid::now_v7(),
+            "purgeDeletedTabular",
+            serde_json::Value::Null,
+            EventMetadata {
+                tabular_id: tabular.tabular_id(),
+                warehouse_id: warehouse.warehouse_id,
+                name: tabular.tabular_ident().name.clone(),
+                namespace: tabular.tabular_ident().namespace.to_url_string(),
+                prefix: warehouse.warehouse_id.to_string(),
+                num_events: 1,
+                sequence_number: 0,
+                trace_id: request_metadata.request_id(),
+                actor: serialize_actor(&request_metadata)?,
+            },
+        )
+        .await
+        .context("Failed to publish `purgeDeletedTabular` event")?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,55 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use http::StatusCode;
+use serde::Serialize;
+
+use crate::api::{RequestMetadata, endpoints::Endpoint};
+
+/// Whether an audited management operation succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum_macros::EnumString)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum OperationOutcome {
+    Success,
+    Failure,
+}
+
+impl OperationOutcome {
+    /// Any non-2xx response counts as a failure, including authorization denials.
+    #[must_use]
+    pub fn from_status(status: StatusCode) -> Self {
+        if status.is_success() {
+            Self::Success
+        } else {
+            Self::Failure
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+        }
+    }
+}
+
+/// Event emitted after every mutating management API request has produced a
+/// response, regardless of whether it succeeded.
+#[derive(Clone, Debug)]
+pub struct ManagementOperationEvent {
+    pub endpoint: Endpoint,
+    /// Path parameters of the request (e.g. `warehouse_id`, `role_id`) plus
+    /// the `project_id` the request was scoped to, if any.
+    pub targets: Arc<BTreeMap<String, String>>,
+    pub http_status: StatusCode,
+    pub request_metadata: Arc<RequestMetadata>,
+}
+
+impl ManagementOperationEvent {
+    #[must_use]
+    pub fn outcome(&self) -> OperationOutcome {
+        OperationOutcome::from_status(self.http_status)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod authorization;
 pub mod generic_table;
+pub mod management;
 pub mod namespace;
 pub mod project;
 pub mod role;
@@ -12,6 +13,7 @@ pub mod warehouse;
 // Re-export all event types for convenience
 pub use authorization::*;
 pub use generic_table::*;
+pub use management::*;
 pub use namespace::*;
 pub use project::*;
 pub use role::*;
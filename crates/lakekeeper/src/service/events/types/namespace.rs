@@ -11,7 +11,7 @@ use crate::{
     WarehouseId,
     api::{RequestMetadata, iceberg::v1::DataAccessMode},
     service::{
-        NamespaceWithParent, ResolvedWarehouse,
+        NamespaceId, NamespaceMoveInfo, NamespaceWithParent, ResolvedWarehouse,
         authz::{CatalogNamespaceAction, CatalogWarehouseAction},
         events::{
             APIEventContext, AuthorizationFailureSource,
@@ -57,6 +57,16 @@ pub struct UpdateNamespacePropertiesEvent {
     pub request_metadata: Arc<RequestMetadata>,
 }
 
+/// Event emitted when a namespace is moved to a different parent
+#[derive(Clone, Debug)]
+pub struct MoveNamespaceEvent {
+    pub warehouse_id: WarehouseId,
+    pub previous_namespace: NamespaceWithParent,
+    pub namespace: NamespaceWithParent,
+    pub child_namespaces: Arc<Vec<NamespaceId>>,
+    pub request_metadata: Arc<RequestMetadata>,
+}
+
 /// Event emitted when Namespace metadata is loaded
 #[derive(Clone, Debug)]
 pub struct NamespaceMetadataLoadedEvent {
@@ -244,6 +254,28 @@ impl
         });
     }
 
+    pub(crate) fn emit_namespace_moved_async(self, move_info: NamespaceMoveInfo) {
+        let NamespaceMoveInfo {
+            namespace,
+            child_namespaces,
+        } = move_info;
+        let ResolvedNamespace {
+            warehouse,
+            namespace: previous_namespace,
+        } = self.resolved_entity.data;
+        let event = MoveNamespaceEvent {
+            warehouse_id: warehouse.warehouse_id,
+            previous_namespace,
+            namespace,
+            child_namespaces: Arc::new(child_namespaces.into_iter().map(|(id, _)| id).collect()),
+            request_metadata: self.request_metadata,
+        };
+        let dispatcher = self.dispatcher;
+        tokio::spawn(async move {
+            let () = dispatcher.namespace_moved(event).await;
+        });
+    }
+
     /// Emit `table_created` event
     pub(crate) fn emit_table_created_async(
         self,
@@ -22,6 +22,15 @@ pub struct UndropTabularEvent {
     pub request_metadata: Arc<RequestMetadata>,
 }
 
+/// Event emitted when a soft-deleted table or view is purged
+#[derive(Clone, Debug)]
+pub struct PurgeDeletedTabularEvent {
+    pub warehouse: Arc<ResolvedWarehouse>,
+    pub tabular: Arc<ViewOrTableInfo>,
+    pub force: bool,
+    pub request_metadata: Arc<RequestMetadata>,
+}
+
 impl
     APIEventContext<
         UserProvidedTabularsIDs,
@@ -47,4 +56,16 @@ impl
             let () = dispatcher.tabular_undropped(event).await;
         });
     }
+    pub(crate) fn emit_deleted_tabular_purged(self, tabular: Arc<ViewOrTableInfo>, force: bool) {
+        let event = super::PurgeDeletedTabularEvent {
+            warehouse: self.resolved_entity.data,
+            tabular,
+            force,
+            request_metadata: self.request_metadata,
+        };
+        let dispatcher = self.dispatcher;
+        tokio::spawn(async move {
+            let () = dispatcher.deleted_tabular_purged(event).await;
+        });
+    }
 }
@@ -5,16 +5,17 @@ use crate::{
     api::{
         RequestMetadata,
         management::v1::{
+            namespace::SetNamespaceDeleteProfileRequest,
             task_queue::SetTaskQueueConfigRequest,
             warehouse::{
                 RenameWarehouseRequest, UpdateWarehouseCredentialRequest,
                 UpdateWarehouseDeleteProfileRequest, UpdateWarehouseFormatVersionPolicyRequest,
-                UpdateWarehouseStorageRequest,
+                UpdateWarehouseSettingsRequest, UpdateWarehouseStorageRequest,
             },
         },
     },
     service::{
-        ResolvedWarehouse,
+        NamespaceId, ResolvedWarehouse,
         authz::CatalogWarehouseAction,
         events::{
             APIEventContext,
@@ -73,6 +74,14 @@ pub struct UpdateWarehouseFormatVersionPolicyEvent {
     pub request_metadata: Arc<RequestMetadata>,
 }
 
+/// Event emitted when warehouse settings are updated
+#[derive(Clone, Debug)]
+pub struct UpdateWarehouseSettingsEvent {
+    pub request: Arc<UpdateWarehouseSettingsRequest>,
+    pub updated_warehouse: Arc<ResolvedWarehouse>,
+    pub request_metadata: Arc<RequestMetadata>,
+}
+
 /// Event emitted when warehouse storage configuration is updated
 #[derive(Clone, Debug)]
 pub struct UpdateWarehouseStorageEvent {
@@ -81,6 +90,14 @@ pub struct UpdateWarehouseStorageEvent {
     pub request_metadata: Arc<RequestMetadata>,
 }
 
+/// Event emitted when the secondary storage configuration of a warehouse is updated
+#[derive(Clone, Debug)]
+pub struct UpdateWarehouseSecondaryStorageEvent {
+    pub request: Arc<UpdateWarehouseStorageRequest>,
+    pub updated_warehouse: Arc<ResolvedWarehouse>,
+    pub request_metadata: Arc<RequestMetadata>,
+}
+
 /// Event emitted when warehouse storage credentials are updated
 #[derive(Clone, Debug)]
 pub struct UpdateWarehouseStorageCredentialEvent {
@@ -99,6 +116,15 @@ pub struct SetTaskQueueConfigEvent {
     pub request_metadata: Arc<RequestMetadata>,
 }
 
+/// Event emitted when the delete profile override of a namespace is set or cleared
+#[derive(Clone, Debug)]
+pub struct SetNamespaceDeleteProfileEvent {
+    pub warehouse: Arc<ResolvedWarehouse>,
+    pub namespace_id: NamespaceId,
+    pub request: Arc<SetNamespaceDeleteProfileRequest>,
+    pub request_metadata: Arc<RequestMetadata>,
+}
+
 impl
     APIEventContext<
         WarehouseId,
@@ -189,6 +215,23 @@ impl
         });
     }
 
+    /// Emit warehouse settings updated event
+    pub(crate) fn emit_warehouse_settings_updated(
+        self,
+        request: Arc<UpdateWarehouseSettingsRequest>,
+        updated_warehouse: Arc<ResolvedWarehouse>,
+    ) {
+        let event = UpdateWarehouseSettingsEvent {
+            request,
+            updated_warehouse,
+            request_metadata: self.request_metadata,
+        };
+        let dispatcher = self.dispatcher;
+        tokio::spawn(async move {
+            let () = dispatcher.warehouse_settings_updated(event).await;
+        });
+    }
+
     /// Emit warehouse storage updated event
     pub(crate) fn emit_warehouse_storage_updated(
         self,
@@ -206,6 +249,23 @@ impl
         });
     }
 
+    /// Emit warehouse secondary storage updated event
+    pub(crate) fn emit_warehouse_secondary_storage_updated(
+        self,
+        request: Arc<UpdateWarehouseStorageRequest>,
+        updated_warehouse: Arc<ResolvedWarehouse>,
+    ) {
+        let event = UpdateWarehouseSecondaryStorageEvent {
+            request,
+            updated_warehouse,
+            request_metadata: self.request_metadata,
+        };
+        let dispatcher = self.dispatcher;
+        tokio::spawn(async move {
+            let () = dispatcher.warehouse_secondary_storage_updated(event).await;
+        });
+    }
+
     /// Emit warehouse storage credential updated event
     pub(crate) fn emit_warehouse_storage_credential_updated(
         self,
@@ -242,6 +302,24 @@ impl
             let () = dispatcher.task_queue_config_set(event).await;
         });
     }
+
+    /// Emit namespace delete profile set event
+    pub(crate) fn emit_namespace_delete_profile_set(
+        self,
+        namespace_id: NamespaceId,
+        request: Arc<SetNamespaceDeleteProfileRequest>,
+    ) {
+        let event = SetNamespaceDeleteProfileEvent {
+            warehouse: self.resolved_entity.data,
+            namespace_id,
+            request,
+            request_metadata: self.request_metadata,
+        };
+        let dispatcher = self.dispatcher;
+        tokio::spawn(async move {
+            let () = dispatcher.namespace_delete_profile_set(event).await;
+        });
+    }
 }
 
 // The managed-by marker is set via an instance-admin action (not a
@@ -0,0 +1,315 @@
+//! Mapping of identity provider groups to Lakekeeper roles.
+//!
+//! When `LAKEKEEPER__OPENID_GROUPS_CLAIM` and
+//! `LAKEKEEPER__OPENID_GROUP_ROLE_MAPPING_FILE` are configured, the groups
+//! listed in the claim of an authenticated token are matched against the rules
+//! of the mapping file. If a rule matches and the request does not assume a
+//! role explicitly via the `x-assume-role` header, the request is performed as
+//! [`Actor::Role`](crate::service::Actor::Role) with the mapped role. Group
+//! membership in the identity provider is the grant: no additional assume-role
+//! check is performed for mapped roles.
+//!
+//! The mapping file is a JSON array of rules, evaluated in order; the first rule
+//! whose group is contained in the token wins:
+//!
+//! ```json
+//! [
+//!   {"group": "data-engineers", "role-id": "01937a7b-2f1a-7c3e-9d4a-3b5c6d7e8f90"},
+//!   {"group": "analysts", "role-id": "01937a7b-2f1a-7c3e-9d4a-3b5c6d7e8f91"}
+//! ]
+//! ```
+//!
+//! The file is polled every `LAKEKEEPER__OPENID_GROUP_ROLE_MAPPING_RELOAD_INTERVAL`
+//! and swapped in atomically when its content changes, so the mapping can be
+//! updated without restarting the server. A file that fails to parse is logged
+//! and the previous mapping stays in effect.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CONFIG, CancellationToken,
+    service::{RoleId, authn::decode_jwt_claims},
+};
+
+/// A single rule of the group to role mapping.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct GroupRoleRule {
+    /// Value of the groups claim that must be present in the token.
+    pub group: String,
+    /// Role the request is performed as if the group is present.
+    pub role_id: RoleId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GroupRoleMappingError {
+    #[error("Failed to read group role mapping file `{path}`: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse group role mapping file `{path}`: {source}")]
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Reloadable, ordered set of [`GroupRoleRule`]s.
+///
+/// Shared between the authentication middleware and the background task
+/// reloading the mapping file. Reads take a short read lock and clone the
+/// current rules, so a reload never blocks request processing for long.
+#[derive(Debug, Default)]
+pub struct GroupRoleMapping {
+    rules: RwLock<Arc<[GroupRoleRule]>>,
+}
+
+impl GroupRoleMapping {
+    #[must_use]
+    pub fn new(rules: Vec<GroupRoleRule>) -> Self {
+        Self {
+            rules: RwLock::new(rules.into()),
+        }
+    }
+
+    /// Load the mapping from `LAKEKEEPER__OPENID_GROUP_ROLE_MAPPING_FILE`.
+    /// Returns an empty mapping if no file is configured.
+    ///
+    /// # Errors
+    /// Fails if the configured file cannot be read or parsed.
+    pub fn from_config() -> Result<Self, GroupRoleMappingError> {
+        match &CONFIG.openid_group_role_mapping_file {
+            Some(path) => Ok(Self::new(read_rules(path)?)),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Current rules in evaluation order.
+    #[must_use]
+    pub fn rules(&self) -> Arc<[GroupRoleRule]> {
+        self.rules
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules().is_empty()
+    }
+
+    /// Replace all rules. Returns `true` if the rules changed.
+    pub fn replace(&self, rules: Vec<GroupRoleRule>) -> bool {
+        let mut current = self
+            .rules
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if **current == *rules {
+            return false;
+        }
+        *current = rules.into();
+        true
+    }
+
+    /// Re-read the rules from `path`. Returns `true` if the rules changed.
+    ///
+    /// # Errors
+    /// Fails if the file cannot be read or parsed. The current rules are kept.
+    pub fn reload_from_file(&self, path: &Path) -> Result<bool, GroupRoleMappingError> {
+        Ok(self.replace(read_rules(path)?))
+    }
+
+    /// Role of the first rule whose group is contained in `groups`.
+    #[must_use]
+    pub fn role_for_groups(&self, groups: &[String]) -> Option<RoleId> {
+        self.roles_for_groups(groups).first().copied()
+    }
+
+    /// Roles of all rules whose group is contained in `groups`, in rule order
+    /// and without duplicates.
+    #[must_use]
+    pub fn roles_for_groups(&self, groups: &[String]) -> Vec<RoleId> {
+        let mut role_ids = Vec::new();
+        for rule in self.rules().iter() {
+            if groups.contains(&rule.group) && !role_ids.contains(&rule.role_id) {
+                role_ids.push(rule.role_id);
+            }
+        }
+        role_ids
+    }
+
+    /// Periodically reload the rules from `path` until `cancellation_token` is cancelled.
+    pub async fn reload_periodically(
+        self: Arc<Self>,
+        path: PathBuf,
+        interval: Duration,
+        cancellation_token: CancellationToken,
+    ) {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately; the rules were loaded at startup.
+        interval.tick().await;
+        loop {
+            tokio::select! {
+                () = cancellation_token.cancelled() => return,
+                _ = interval.tick() => {}
+            }
+            match self.reload_from_file(&path) {
+                Ok(true) => tracing::info!(
+                    rules = self.rules().len(),
+                    "Reloaded group role mapping from `{}`",
+                    path.display()
+                ),
+                Ok(false) => {}
+                Err(e) => tracing::error!("{e}. Keeping the previous group role mapping."),
+            }
+        }
+    }
+}
+
+fn read_rules(path: &Path) -> Result<Vec<GroupRoleRule>, GroupRoleMappingError> {
+    let content = std::fs::read(path).map_err(|source| GroupRoleMappingError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    serde_json::from_slice(&content).map_err(|source| GroupRoleMappingError::Parse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Extract the groups listed in `claim` of a JWT. The claim supports nested
+/// paths using dot notation, e.g. `realm_access.groups`, and may contain an
+/// array of strings or a single string.
+///
+/// The token must already be validated by the authenticator; its signature is
+/// not checked here. Tokens that are not JWTs or lack the claim have no groups.
+#[must_use]
+#[cfg_attr(not(feature = "router"), allow(dead_code))]
+pub(crate) fn extract_token_groups(token: &str, claim: &str) -> Vec<String> {
+    let Some(claims) = decode_jwt_claims(token) else {
+        return vec![];
+    };
+
+    match claim
+        .split('.')
+        .try_fold(&claims, |value, key| value.get(key))
+    {
+        Some(serde_json::Value::String(group)) => vec![group.clone()],
+        Some(serde_json::Value::Array(groups)) => groups
+            .iter()
+            .filter_map(|group| group.as_str().map(ToString::to_string))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine as _;
+
+    use super::*;
+
+    fn token(claims: &serde_json::Value) -> String {
+        let encode = |value: &serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value.to_string())
+        };
+        format!(
+            "{}.{}.signature",
+            encode(&serde_json::json!({"alg": "RS256", "typ": "JWT"})),
+            encode(claims)
+        )
+    }
+
+    #[test]
+    fn test_extract_token_groups() {
+        let token = token(&serde_json::json!({
+            "sub": "alice",
+            "groups": ["engineers", "analysts", 42],
+            "realm_access": {"groups": "admins"}
+        }));
+
+        assert_eq!(
+            extract_token_groups(&token, "groups"),
+            vec!["engineers".to_string(), "analysts".to_string()]
+        );
+        assert_eq!(
+            extract_token_groups(&token, "realm_access.groups"),
+            vec!["admins".to_string()]
+        );
+        assert!(extract_token_groups(&token, "missing").is_empty());
+        assert!(extract_token_groups("not-a-jwt", "groups").is_empty());
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let engineers = RoleId::new_random();
+        let analysts = RoleId::new_random();
+        let mapping = GroupRoleMapping::new(vec![
+            GroupRoleRule {
+                group: "engineers".to_string(),
+                role_id: engineers,
+            },
+            GroupRoleRule {
+                group: "analysts".to_string(),
+                role_id: analysts,
+            },
+        ]);
+
+        let groups = |groups: &[&str]| groups.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            mapping.role_for_groups(&groups(&["analysts", "engineers"])),
+            Some(engineers)
+        );
+        assert_eq!(
+            mapping.role_for_groups(&groups(&["analysts"])),
+            Some(analysts)
+        );
+        assert_eq!(mapping.role_for_groups(&groups(&["other"])), None);
+        assert_eq!(
+            mapping.roles_for_groups(&groups(&["analysts", "engineers"])),
+            vec![engineers, analysts]
+        );
+        assert_eq!(
+            GroupRoleMapping::default().role_for_groups(&groups(&["analysts"])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reload_from_file() {
+        let role_id = RoleId::new_random();
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(
+            file.path(),
+            format!(r#"[{{"group": "engineers", "role-id": "{role_id}"}}]"#),
+        )
+        .unwrap();
+
+        let mapping = GroupRoleMapping::default();
+        assert!(mapping.reload_from_file(file.path()).unwrap());
+        assert!(!mapping.reload_from_file(file.path()).unwrap());
+        assert_eq!(
+            mapping.role_for_groups(&["engineers".to_string()]),
+            Some(role_id)
+        );
+
+        // Invalid content keeps the previous mapping
+        std::fs::write(file.path(), "not json").unwrap();
+        assert!(matches!(
+            mapping.reload_from_file(file.path()),
+            Err(GroupRoleMappingError::Parse { .. })
+        ));
+        assert_eq!(
+            mapping.role_for_groups(&["engineers".to_string()]),
+            Some(role_id)
+        );
+    }
+}
@@ -96,6 +96,9 @@ impl Health {
 #[derive(Clone)]
 pub struct ServiceHealthProvider {
     providers: Vec<(&'static str, Arc<dyn HealthExt + Sync + Send>)>,
+    /// Checks that only gate readiness (`/readyz`), such as "all migrations
+    /// are applied". They are not part of `/health`.
+    readiness_providers: Vec<(&'static str, Arc<dyn HealthExt + Sync + Send>)>,
     check_frequency_seconds: u64,
 }
 
@@ -110,6 +113,14 @@ impl std::fmt::Debug for ServiceHealthProvider {
                     .map(|(name, _)| *name)
                     .collect::<Vec<_>>(),
             )
+            .field(
+                "readiness_providers",
+                &self
+                    .readiness_providers
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>(),
+            )
             .field("check_frequency_seconds", &self.check_frequency_seconds)
             .finish()
     }
@@ -123,17 +134,30 @@ impl ServiceHealthProvider {
     ) -> Self {
         Self {
             providers,
+            readiness_providers: Vec::new(),
             check_frequency_seconds,
         }
     }
 
+    /// Register additional checks that must pass before the server reports
+    /// itself ready. They are refreshed alongside the regular providers.
+    #[must_use]
+    pub fn with_readiness_providers(
+        mut self,
+        readiness_providers: Vec<(&'static str, Arc<dyn HealthExt + Sync + Send>)>,
+    ) -> Self {
+        self.readiness_providers = readiness_providers;
+        self
+    }
+
     pub fn spawn_update_health_checks<T: Send + 'static>(
         &self,
         join_set: &mut JoinSet<Result<(), T>>,
         cancellation_token: &CancellationToken,
     ) -> Vec<(String, AbortHandle)> {
-        let mut abort_handles = Vec::with_capacity(self.providers.len());
-        for (service_name, provider) in &self.providers {
+        let mut abort_handles =
+            Vec::with_capacity(self.providers.len() + self.readiness_providers.len());
+        for (service_name, provider) in self.providers.iter().chain(&self.readiness_providers) {
             let provider = provider.clone();
             let service_name_cloned = (*service_name).to_string();
             let check_frequency_seconds = self.check_frequency_seconds;
@@ -157,9 +181,28 @@ impl ServiceHealthProvider {
     }
 
     pub async fn collect_health(&self) -> HealthState {
+        Self::collect(&self.providers).await
+    }
+
+    /// Like [`collect_health`](Self::collect_health), but additionally
+    /// includes the readiness providers. The result is only healthy once all
+    /// services are healthy and all readiness checks passed.
+    pub async fn collect_readiness(&self) -> HealthState {
+        let providers = self
+            .providers
+            .iter()
+            .chain(&self.readiness_providers)
+            .cloned()
+            .collect::<Vec<_>>();
+        Self::collect(&providers).await
+    }
+
+    async fn collect(
+        providers: &[(&'static str, Arc<dyn HealthExt + Sync + Send>)],
+    ) -> HealthState {
         let mut services = HashMap::new();
         let mut all_healthy = true;
-        for (name, provider) in &self.providers {
+        for (name, provider) in providers {
             let provider_health = provider.health().await;
             all_healthy = all_healthy
                 && provider_health
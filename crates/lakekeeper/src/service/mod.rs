@@ -1,4 +1,6 @@
 pub mod admission;
+#[cfg(feature = "router")]
+pub(crate) mod authentication_cache;
 pub mod authn;
 pub mod authz;
 pub(crate) mod cache_metrics;
@@ -7,9 +9,11 @@ mod catalog_store;
 pub mod contract_verification;
 pub mod endpoint_statistics;
 pub mod events;
+pub mod group_roles;
 pub mod health;
 pub mod idempotency;
 pub mod maintenance;
+pub mod rate_limit;
 pub mod secrets;
 pub mod storage;
 pub mod task_configs;
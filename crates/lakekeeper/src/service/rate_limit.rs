@@ -0,0 +1,227 @@
+//! Per-project request rate limiting.
+//!
+//! A [`RateLimiter`] decides, once per request, whether the [`RateLimitKey`]
+//! the request is attributed to may issue another request right now. Requests
+//! are attributed to the project resolved into
+//! [`RequestMetadata::preferred_project_id`] if that project exists. As the
+//! project id is supplied by the client, requests naming a project that does
+//! not exist are attributed to their principal instead, so that made-up
+//! project ids cannot be used to obtain fresh budgets. Requests without a
+//! project (and no default project configured) are never limited.
+//!
+//! The default implementation, [`InMemoryRateLimiter`], keeps a token bucket
+//! per key in a bounded in-memory cache. Limits are therefore enforced per
+//! replica; deployments that need a cluster-wide limit can provide their own
+//! [`RateLimiter`] backed by a shared store.
+//!
+//! [`RequestMetadata::preferred_project_id`]: crate::request_metadata::RequestMetadata::preferred_project_id
+
+use std::{
+    num::NonZeroU32,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use moka::future::Cache;
+
+use crate::{ProjectId, config::RateLimitConfig, service::UserId};
+
+/// Budget a request is counted against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, strum_macros::Display)]
+pub enum RateLimitKey {
+    /// An existing project the request is scoped to.
+    #[strum(to_string = "Project({0})")]
+    Project(ProjectId),
+    /// The authenticated principal of a request naming an unknown project.
+    #[strum(to_string = "Principal({0})")]
+    Principal(UserId),
+    /// Unauthenticated requests naming an unknown project share one budget.
+    Anonymous,
+}
+
+/// Outcome of a [`RateLimiter::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    /// The request may proceed.
+    Allowed,
+    /// The key exceeded its limit. The request is rejected with
+    /// `429 Too Many Requests` and a `Retry-After` header derived from
+    /// `retry_after`.
+    Limited { retry_after: Duration },
+}
+
+/// Decides whether a [`RateLimitKey`] may issue another request.
+///
+/// `check` runs on the hot path of every project-scoped request and should
+/// not block for long. Implementations depending on an external store own the
+/// fail-open vs fail-closed policy when that store is unreachable.
+#[async_trait]
+pub trait RateLimiter: std::fmt::Debug + Send + Sync {
+    /// Consume one request from the budget of `key`.
+    async fn check(&self, key: &RateLimitKey) -> RateLimitDecision;
+}
+
+/// Maximum number of keys with a tracked bucket. When exceeded, the buckets
+/// of the least recently active keys are evicted first.
+const MAX_TRACKED_KEYS: u64 = 100_000;
+
+/// Token bucket rate limiter holding one bucket per key in memory.
+///
+/// Each bucket holds up to `burst` tokens and is refilled at
+/// `requests_per_second`. Every request consumes one token. Buckets expire
+/// once they have been idle long enough to be full again, so an expired
+/// key starts over with exactly the budget it would have had.
+#[derive(Debug)]
+pub struct InMemoryRateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    buckets: Cache<RateLimitKey, Arc<Mutex<TokenBucket>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl InMemoryRateLimiter {
+    #[must_use]
+    pub fn new(requests_per_second: NonZeroU32, burst: NonZeroU32) -> Self {
+        let requests_per_second = f64::from(requests_per_second.get());
+        let burst = f64::from(burst.get());
+        let refill_time = Duration::from_secs_f64(burst / requests_per_second);
+        Self {
+            requests_per_second,
+            burst,
+            buckets: Cache::builder()
+                .max_capacity(MAX_TRACKED_KEYS)
+                .time_to_idle(refill_time.max(Duration::from_secs(1)))
+                .build(),
+        }
+    }
+
+    #[must_use]
+    pub fn from_config(config: &RateLimitConfig) -> Self {
+        Self::new(config.requests_per_second, config.burst)
+    }
+
+    async fn check_at(&self, key: &RateLimitKey, now: Instant) -> RateLimitDecision {
+        let bucket = self
+            .buckets
+            .get_with_by_ref(key, async {
+                Arc::new(Mutex::new(TokenBucket {
+                    tokens: self.burst,
+                    updated_at: now,
+                }))
+            })
+            .await;
+        let mut bucket = bucket
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let elapsed = now.saturating_duration_since(bucket.updated_at);
+        bucket.tokens =
+            (bucket.tokens + elapsed.as_secs_f64() * self.requests_per_second).min(self.burst);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            RateLimitDecision::Allowed
+        } else {
+            RateLimitDecision::Limited {
+                retry_after: Duration::from_secs_f64(
+                    (1.0 - bucket.tokens) / self.requests_per_second,
+                ),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimiter for InMemoryRateLimiter {
+    async fn check(&self, key: &RateLimitKey) -> RateLimitDecision {
+        self.check_at(key, Instant::now()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(requests_per_second: u32, burst: u32) -> InMemoryRateLimiter {
+        InMemoryRateLimiter::new(
+            NonZeroU32::new(requests_per_second).unwrap(),
+            NonZeroU32::new(burst).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_bucket_refills_over_time() {
+        let limiter = limiter(2, 2);
+        let project = RateLimitKey::Project(ProjectId::new_random());
+        let start = Instant::now();
+
+        assert_eq!(
+            limiter.check_at(&project, start).await,
+            RateLimitDecision::Allowed
+        );
+        assert_eq!(
+            limiter.check_at(&project, start).await,
+            RateLimitDecision::Allowed
+        );
+        assert_eq!(
+            limiter.check_at(&project, start).await,
+            RateLimitDecision::Limited {
+                retry_after: Duration::from_millis(500)
+            }
+        );
+
+        // One token is refilled after half a second.
+        let later = start + Duration::from_millis(500);
+        assert_eq!(
+            limiter.check_at(&project, later).await,
+            RateLimitDecision::Allowed
+        );
+        assert!(matches!(
+            limiter.check_at(&project, later).await,
+            RateLimitDecision::Limited { .. }
+        ));
+
+        // The bucket never holds more than `burst` tokens.
+        let much_later = later + Duration::from_secs(60);
+        assert_eq!(
+            limiter.check_at(&project, much_later).await,
+            RateLimitDecision::Allowed
+        );
+        assert_eq!(
+            limiter.check_at(&project, much_later).await,
+            RateLimitDecision::Allowed
+        );
+        assert!(matches!(
+            limiter.check_at(&project, much_later).await,
+            RateLimitDecision::Limited { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_projects_have_separate_buckets() {
+        let limiter = limiter(1, 1);
+        let project_1 = RateLimitKey::Project(ProjectId::new_random());
+        let project_2 = RateLimitKey::Project(ProjectId::new_random());
+        let now = Instant::now();
+
+        assert_eq!(
+            limiter.check_at(&project_1, now).await,
+            RateLimitDecision::Allowed
+        );
+        assert!(matches!(
+            limiter.check_at(&project_1, now).await,
+            RateLimitDecision::Limited { .. }
+        ));
+        assert_eq!(
+            limiter.check_at(&project_2, now).await,
+            RateLimitDecision::Allowed
+        );
+    }
+}
@@ -1038,7 +1038,11 @@ pub(crate) mod test {
         };
         let namespace_location = sp.default_namespace_location(&namespace_path).unwrap();
 
-        let location = sp.default_tabular_location(&namespace_location, &tabular_name_context);
+        let location = sp.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &tabular_name_context,
+        );
         // Default layout is flat: no namespace directory under the base location.
         assert_eq!(
             location.to_string(),
@@ -1051,7 +1055,11 @@ pub(crate) mod test {
         let sp: StorageProfile = profile.into();
 
         let namespace_location = sp.default_namespace_location(&namespace_path).unwrap();
-        let location = sp.default_tabular_location(&namespace_location, &tabular_name_context);
+        let location = sp.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &tabular_name_context,
+        );
         assert_eq!(
             location.to_string(),
             format!("abfss://filesystem@account.blob.com/{tabular_uuid}")
@@ -39,7 +39,8 @@ use crate::{
     request_metadata::RequestMetadata,
     server::{compression_codec::CompressionCodec, io::list_location},
     service::{
-        BasicTabularInfo, NamespaceVersion, TabularId, TabularInfo, WarehouseVersion,
+        BasicTabularInfo, NamespaceVersion, TabularId, TabularInfo, WarehouseCredentialMode,
+        WarehouseVersion,
         storage::{
             error::UnexpectedStorageType,
             storage_layout::{
@@ -365,12 +366,16 @@ impl StorageProfile {
 
     /// Generate the table config for the storage profile.
     ///
+    /// Only the data access mechanisms allowed by the warehouse's `credential_mode`
+    /// are provided, independent of what the client requested.
+    ///
     /// # Errors
     /// Fails if the underlying storage profile's generation fails.
     #[allow(clippy::too_many_arguments)]
     pub async fn generate_table_config(
         &self,
         data_access: DataAccessMode,
+        credential_mode: WarehouseCredentialMode,
         secret: Option<&StorageCredential>,
         table_location: &Location,
         storage_permissions: StoragePermissions,
@@ -383,12 +388,19 @@ impl StorageProfile {
             warehouse_id: tabular_info.warehouse_id(),
             tabular_id: tabular_info.tabular_id(),
         };
+        // Remote signing is S3-only, other storages can only vend credentials.
+        let vended_data_access = if credential_mode.allows_vended_credentials() {
+            data_access
+        } else {
+            DataAccessMode::ClientManaged
+        };
 
         match self {
             StorageProfile::S3(profile) => {
                 profile
                     .generate_table_config(
                         data_access,
+                        credential_mode,
                         secret
                             .map(|s| s.try_to_s3())
                             .transpose()
@@ -402,7 +414,7 @@ impl StorageProfile {
             StorageProfile::Adls(profile) => {
                 profile
                     .generate_table_config(
-                        data_access,
+                        vended_data_access,
                         secret
                             .ok_or_else(|| CredentialsError::MissingCredential("adls".to_string()))?
                             .try_to_az()
@@ -416,7 +428,7 @@ impl StorageProfile {
             StorageProfile::OneLake(profile) => {
                 profile
                     .generate_table_config(
-                        data_access,
+                        vended_data_access,
                         secret
                             .ok_or_else(|| {
                                 CredentialsError::MissingCredential("onelake".to_string())
@@ -432,7 +444,7 @@ impl StorageProfile {
             StorageProfile::Gcs(profile) => {
                 profile
                     .generate_table_config(
-                        data_access,
+                        vended_data_access,
                         secret
                             .map(|s| s.try_to_gcs())
                             .transpose()
@@ -475,7 +487,7 @@ impl StorageProfile {
             name: "test_tabular".to_string(),
             uuid: Uuid::now_v7(),
         };
-        let _ = self.default_tabular_location(&ns_location, &tabular_name_context);
+        let _ = self.default_tabular_location(&ns_location, &namespace_path, &tabular_name_context);
 
         // ------------- Profile specific validations -------------
         match self {
@@ -528,7 +540,7 @@ impl StorageProfile {
         };
         let ns_location = self.default_namespace_location(&namespace_path)?;
         let test_location = location.map_or_else(
-            || self.default_tabular_location(&ns_location, &tabular_name_context),
+            || self.default_tabular_location(&ns_location, &namespace_path, &tabular_name_context),
             std::borrow::ToOwned::to_owned,
         );
         tracing::debug!("Validating direct read/write access to {test_location}");
@@ -635,6 +647,7 @@ impl StorageProfile {
                     vended_credentials: true,
                 }
                 .into(),
+                WarehouseCredentialMode::Vended,
                 credential,
                 &sub_location,
                 StoragePermissions::ReadWriteDelete,
@@ -987,14 +1000,15 @@ impl StorageProfile {
     pub fn default_tabular_location(
         &self,
         namespace_location: &Location,
+        namespace_path: &NamespacePath,
         tabular_name_context: &TabularNameContext,
     ) -> Location {
         let mut location = namespace_location.clone();
 
         let layout = self.layout().unwrap_or_else(|| &DEFAULT_LAYOUT);
 
-        let segment = layout.render_tabular_segment(tabular_name_context);
-        location.without_trailing_slash().push(&segment);
+        let segments = layout.render_tabular_path(namespace_path, tabular_name_context);
+        location.without_trailing_slash().extend(segments);
         location
     }
 
@@ -1331,18 +1345,78 @@ mod tests {
         let target_location = format!("s3://my-bucket/subfolder/{tabular_uuid}");
 
         let namespace_location = profile.default_namespace_location(&namespace_path).unwrap();
-        let tabular_location =
-            profile.default_tabular_location(&namespace_location, &tabular_name_context);
+        let tabular_location = profile.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &tabular_name_context,
+        );
         assert_eq!(tabular_location.to_string(), target_location);
 
         let mut namespace_location_without_slash = namespace_location.clone();
         namespace_location_without_slash.without_trailing_slash();
-        let tabular_location_trailing = profile
-            .default_tabular_location(&namespace_location_without_slash, &tabular_name_context);
+        let tabular_location_trailing = profile.default_tabular_location(
+            &namespace_location_without_slash,
+            &namespace_path,
+            &tabular_name_context,
+        );
         assert!(!namespace_location_without_slash.to_string().ends_with('/'));
         assert_eq!(tabular_location_trailing.to_string(), target_location);
     }
 
+    #[test]
+    fn test_default_locations_with_location_template() {
+        let ns_uuid = uuid::uuid!("00000000-0000-0000-0000-000000000001");
+        let tabular_uuid = uuid::uuid!("00000000-0000-0000-0000-000000000002");
+        let namespace_path = NamespacePath::new(vec![
+            NamespaceNameContext {
+                name: "sales".to_string(),
+                uuid: uuid::uuid!("00000000-0000-0000-0000-000000000003"),
+            },
+            NamespaceNameContext {
+                name: "eu west".to_string(),
+                uuid: ns_uuid,
+            },
+        ]);
+        let tabular_name_context = TabularNameContext {
+            name: "orders".to_string(),
+            uuid: tabular_uuid,
+        };
+
+        for (template, expected_fs_location) in [
+            (
+                "{namespace}/{name}-{uuid}",
+                format!("my-bucket/subfolder/sales/eu%20west/orders-{tabular_uuid}"),
+            ),
+            (
+                "tables/{namespace-uuid}/{uuid}",
+                format!("my-bucket/subfolder/tables/{ns_uuid}/{tabular_uuid}"),
+            ),
+        ] {
+            let profile = StorageProfile::S3(
+                S3Profile::builder()
+                    .bucket("my-bucket".to_string())
+                    .region("us-east-1".to_string())
+                    .key_prefix("subfolder".to_string())
+                    .sts_enabled(false)
+                    .flavor(S3Flavor::Aws)
+                    .storage_layout(StorageLayout::try_new_template(template.to_string()).unwrap())
+                    .build(),
+            );
+
+            // Namespaces don't get own directories, the template renders the namespace.
+            let namespace_location = profile.default_namespace_location(&namespace_path).unwrap();
+            assert_eq!(namespace_location.to_string(), "s3://my-bucket/subfolder");
+
+            let tabular_location = profile.default_tabular_location(
+                &namespace_location,
+                &namespace_path,
+                &tabular_name_context,
+            );
+            assert_eq!(tabular_location.scheme(), "s3");
+            assert_eq!(tabular_location.authority_and_path(), expected_fs_location);
+        }
+    }
+
     #[test]
     fn test_redact_s3_access_key() {
         let secrets: StorageCredential = S3Credential::AccessKey(S3AccessKeyCredential {
@@ -1760,6 +1834,7 @@ mod tests {
                     remote_signing: false,
                 }
                 .into(),
+                WarehouseCredentialMode::Both,
                 Some(cred),
                 &table_location1,
                 StoragePermissions::ReadWriteDelete,
@@ -1776,6 +1851,7 @@ mod tests {
                     remote_signing: false,
                 }
                 .into(),
+                WarehouseCredentialMode::Both,
                 Some(cred),
                 &table_location2,
                 StoragePermissions::ReadWriteDelete,
@@ -41,7 +41,7 @@ use crate::{
     },
     request_metadata::RequestMetadata,
     service::{
-        BasicTabularInfo,
+        BasicTabularInfo, WarehouseCredentialMode,
         storage::{
             StoragePermissions, TableConfig,
             cache::{CachedStc, S3_STC_CACHE, STCCacheKey, get_or_load_stc},
@@ -461,6 +461,7 @@ impl S3Profile {
     pub async fn generate_table_config(
         &self,
         data_access: DataAccessMode,
+        credential_mode: WarehouseCredentialMode,
         s3_credential: Option<&S3Credential>,
         stc_request: super::ShortTermCredentialsRequest,
         tabular_info: &impl BasicTabularInfo,
@@ -471,20 +472,23 @@ impl S3Profile {
                 mut vended_credentials,
                 mut remote_signing,
             }) => {
-                if remote_signing && !self.remote_signing_enabled {
+                let remote_signing_enabled =
+                    self.remote_signing_enabled && credential_mode.allows_remote_signing();
+                if remote_signing && !remote_signing_enabled {
                     tracing::debug!(
                         "Remote signing is explicitly requested but is disabled for this S3 warehouse."
                     );
                     remote_signing = false;
                 }
-                let can_use_vended_credentials = self.sts_enabled
-                    || matches!(
-                        s3_credential,
-                        Some(S3Credential::CloudflareR2(..) | S3Credential::AliyunOss(..))
-                    );
+                let can_use_vended_credentials = credential_mode.allows_vended_credentials()
+                    && (self.sts_enabled
+                        || matches!(
+                            s3_credential,
+                            Some(S3Credential::CloudflareR2(..) | S3Credential::AliyunOss(..))
+                        ));
                 if vended_credentials && !(can_use_vended_credentials) {
                     tracing::debug!(
-                        "vended_credentials is explicitly requested but STS is disabled for this S3 warehouse and the credential type is not Cloudflare R2 or Alibaba Cloud OSS."
+                        "vended_credentials is explicitly requested but the credential mode of the warehouse does not allow it, or STS is disabled for this S3 warehouse and the credential type is not Cloudflare R2 or Alibaba Cloud OSS."
                     );
                     vended_credentials = false;
                 }
@@ -492,7 +496,7 @@ impl S3Profile {
                 // If neither method was explicitly requested or both were disabled,
                 // prefer vended credentials for wider compatibility, then fall back to remote signing
                 if !vended_credentials && !remote_signing {
-                    match (can_use_vended_credentials, self.remote_signing_enabled) {
+                    match (can_use_vended_credentials, remote_signing_enabled) {
                         (true, _) => vended_credentials = true,
                         (false, true) => remote_signing = true,
                         (false, false) => tracing::debug!(
@@ -2373,7 +2377,11 @@ pub(crate) mod test {
         };
         let namespace_location = sp.default_namespace_location(&namespace_path).unwrap();
 
-        let location = sp.default_tabular_location(&namespace_location, &tabular_name_context);
+        let location = sp.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &tabular_name_context,
+        );
         // Default layout is flat: no namespace directory under the base location.
         assert_eq!(
             location.to_string(),
@@ -2385,7 +2393,11 @@ pub(crate) mod test {
         let sp: StorageProfile = profile.into();
 
         let namespace_location = sp.default_namespace_location(&namespace_path).unwrap();
-        let location = sp.default_tabular_location(&namespace_location, &tabular_name_context);
+        let location = sp.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &tabular_name_context,
+        );
         assert_eq!(
             location.to_string(),
             format!("s3://test-bucket/{tabular_uuid}")
@@ -2419,13 +2431,22 @@ pub(crate) mod test {
         // Prefix should be ignored as we specify the namespace_location explicitly.
         // Tabular locations should not have a trailing slash, otherwise pyiceberg fails.
         let expected = format!("s3://test-bucket/foo/{tabular_uuid}");
+        let namespace_path = NamespacePath::default();
 
-        let location = profile.default_tabular_location(&namespace_location, &tabular_name_context);
+        let location = profile.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &tabular_name_context,
+        );
 
         assert_eq!(location.to_string(), expected);
 
         let namespace_location = Location::from_str("s3://test-bucket/foo").unwrap();
-        let location = profile.default_tabular_location(&namespace_location, &tabular_name_context);
+        let location = profile.default_tabular_location(
+            &namespace_location,
+            &namespace_path,
+            &tabular_name_context,
+        );
         assert_eq!(location.to_string(), expected);
     }
 
@@ -3022,6 +3043,7 @@ pub(crate) mod test {
         test_block_on(
             profile.generate_table_config(
                 DataAccessMode::ClientManaged,
+                WarehouseCredentialMode::Both,
                 None,
                 stc_request,
                 &tabular_info,
@@ -3484,3 +3506,207 @@ mod is_overlapping_location_tests {
         assert!(profile3.is_overlapping_location(&profile4));
     }
 }
+
+#[cfg(test)]
+mod assume_role_tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{
+        Router,
+        body::Bytes,
+        extract::State,
+        http::{HeaderMap, Method, StatusCode, header::CONTENT_TYPE},
+        response::IntoResponse,
+    };
+    use lakekeeper_io::LakekeeperStorage as _;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    const BASE_ACCESS_KEY_ID: &str = "AKIABASEEXAMPLE";
+    const ASSUMED_ACCESS_KEY_ID: &str = "ASIAASSUMEDEXAMPLE";
+    const ASSUMED_SESSION_TOKEN: &str = "assumed-session-token";
+    const ROLE_ARN: &str = "arn:aws:iam::210987654321:role/cross-account-bucket-access";
+    const EXTERNAL_ID: &str = "lakekeeper-external-id";
+
+    #[derive(Debug, Clone)]
+    struct RecordedRequest {
+        method: Method,
+        headers: HeaderMap,
+        body: Bytes,
+    }
+
+    impl RecordedRequest {
+        fn is_assume_role(&self) -> bool {
+            self.method == Method::POST
+                && self.form().get("Action").map(String::as_str) == Some("AssumeRole")
+        }
+
+        fn form(&self) -> HashMap<String, String> {
+            url::form_urlencoded::parse(&self.body)
+                .into_owned()
+                .collect()
+        }
+
+        fn signed_with(&self, access_key_id: &str) -> bool {
+            self.headers
+                .get("authorization")
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|v| v.contains(&format!("Credential={access_key_id}/")))
+        }
+    }
+
+    type Recorded = Arc<Mutex<Vec<RecordedRequest>>>;
+
+    async fn handle(
+        State(recorded): State<Recorded>,
+        method: Method,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> impl IntoResponse {
+        let request = RecordedRequest {
+            method,
+            headers,
+            body,
+        };
+        let is_assume_role = request.is_assume_role();
+        recorded.lock().unwrap().push(request);
+
+        if is_assume_role {
+            let body = format!(
+                r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>{ASSUMED_ACCESS_KEY_ID}</AccessKeyId>
+      <SecretAccessKey>assumed-secret-access-key</SecretAccessKey>
+      <SessionToken>{ASSUMED_SESSION_TOKEN}</SessionToken>
+      <Expiration>2099-01-01T00:00:00Z</Expiration>
+    </Credentials>
+    <AssumedRoleUser>
+      <AssumedRoleId>AROAEXAMPLE:lakekeeper</AssumedRoleId>
+      <Arn>arn:aws:sts::210987654321:assumed-role/cross-account-bucket-access/lakekeeper</Arn>
+    </AssumedRoleUser>
+  </AssumeRoleResult>
+  <ResponseMetadata>
+    <RequestId>00000000-0000-0000-0000-000000000000</RequestId>
+  </ResponseMetadata>
+</AssumeRoleResponse>"#
+            );
+            (StatusCode::OK, [(CONTENT_TYPE, "text/xml")], body).into_response()
+        } else {
+            StatusCode::OK.into_response()
+        }
+    }
+
+    /// Serves STS `AssumeRole` and accepts every S3 request on the same endpoint.
+    async fn spawn_mock_s3_and_sts() -> (url::Url, Recorded) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock s3 server");
+        let addr = listener.local_addr().expect("mock s3 server addr");
+        let recorded = Recorded::default();
+        let app = Router::new().fallback(handle).with_state(recorded.clone());
+        tokio::spawn(async move {
+            axum::serve(listener, app)
+                .await
+                .expect("mock s3 server failed");
+        });
+
+        (
+            url::Url::parse(&format!("http://{addr}")).expect("mock s3 server url"),
+            recorded,
+        )
+    }
+
+    fn cross_account_profile(endpoint: url::Url) -> S3Profile {
+        S3Profile::builder()
+            .bucket("cross-account-bucket".to_string())
+            .region("us-east-1".to_string())
+            .endpoint(endpoint)
+            .path_style_access(true)
+            .assume_role_arn(ROLE_ARN.to_string())
+            .sts_enabled(true)
+            .flavor(S3Flavor::Aws)
+            .build()
+    }
+
+    fn base_credential() -> S3Credential {
+        S3Credential::AccessKey(S3AccessKeyCredential {
+            access_key_id: BASE_ACCESS_KEY_ID.to_string(),
+            secret_access_key: "base-secret-access-key".to_string(),
+            external_id: Some(EXTERNAL_ID.to_string()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_bucket_probe_uses_assumed_role_credentials() {
+        let (endpoint, recorded) = spawn_mock_s3_and_sts().await;
+        let profile = cross_account_profile(endpoint);
+
+        let io = profile
+            .lakekeeper_io(Some(&base_credential()))
+            .await
+            .unwrap();
+        io.write(
+            "s3://cross-account-bucket/probe/test",
+            Bytes::from_static(b"probe"),
+        )
+        .await
+        .unwrap();
+
+        let recorded = recorded.lock().unwrap().clone();
+        let (assume_role, s3): (Vec<_>, Vec<_>) = recorded
+            .into_iter()
+            .partition(RecordedRequest::is_assume_role);
+
+        // The role is assumed with the static base credentials ...
+        assert!(!assume_role.is_empty());
+        for request in &assume_role {
+            assert!(request.signed_with(BASE_ACCESS_KEY_ID));
+            let form = request.form();
+            assert_eq!(form.get("RoleArn").map(String::as_str), Some(ROLE_ARN));
+            assert_eq!(
+                form.get("ExternalId").map(String::as_str),
+                Some(EXTERNAL_ID)
+            );
+        }
+
+        // ... and only the assumed-role credentials touch the bucket.
+        assert!(!s3.is_empty());
+        for request in &s3 {
+            assert!(request.signed_with(ASSUMED_ACCESS_KEY_ID));
+            assert!(!request.signed_with(BASE_ACCESS_KEY_ID));
+            assert_eq!(
+                request
+                    .headers
+                    .get("x-amz-security-token")
+                    .and_then(|v| v.to_str().ok()),
+                Some(ASSUMED_SESSION_TOKEN)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_vended_credentials_assume_cross_account_role() {
+        let (endpoint, recorded) = spawn_mock_s3_and_sts().await;
+        let profile = cross_account_profile(endpoint);
+        let auth = S3Auth::try_from(base_credential()).unwrap();
+
+        // Without an explicit `sts-role-arn`, vended credentials use `assume-role-arn`.
+        let credentials = profile
+            .assume_role_with_sts(Some(&auth), profile.assume_role_arn.as_deref(), None)
+            .await
+            .unwrap();
+        assert_eq!(credentials.access_key_id(), ASSUMED_ACCESS_KEY_ID);
+
+        let recorded = recorded.lock().unwrap().clone();
+        assert_eq!(recorded.len(), 1);
+        assert!(recorded[0].signed_with(BASE_ACCESS_KEY_ID));
+        let form = recorded[0].form();
+        assert_eq!(form.get("RoleArn").map(String::as_str), Some(ROLE_ARN));
+        assert_eq!(
+            form.get("ExternalId").map(String::as_str),
+            Some(EXTERNAL_ID)
+        );
+    }
+}
@@ -193,8 +193,117 @@ impl<'de> Deserialize<'de> for StorageLayoutFlat {
     }
 }
 
+/// A single template describing the path of a tabular below its namespace location.
+///
+/// Namespaces are not given own directories; the namespace location defaults to the base
+/// location. For a tabular `my_tabular` (uuid `…003`) in `grandparent_ns` / `parent_ns` the
+/// template `"{namespace}/{name}-{uuid}"` renders to
+/// `<base>/grandparent_ns/parent_ns/my_tabular-…003`.
+/// The template must contain `{uuid}` to avoid collisions between tabulars with the same name.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "open-api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "open-api", schema(
+    example = json!({"location-template": "{namespace}/{name}-{uuid}"})
+))]
+#[serde(rename_all = "kebab-case")]
+pub struct StorageLayoutLocationTemplate {
+    /// Template for the path of a tabular below its namespace location.
+    /// Supported placeholders are `{namespace}` (all namespace levels separated by `/`),
+    /// `{namespace-uuid}` (UUID of the direct parent namespace), `{name}` and `{uuid}`
+    /// (name and UUID of the tabular). Names are percent-encoded.
+    /// Must contain `{uuid}`.
+    pub location_template: String,
+}
+
+impl StorageLayoutLocationTemplate {
+    pub fn try_new(location_template: String) -> Result<Self, StorageLayoutError> {
+        if !location_template.contains("{uuid}") {
+            return Err(StorageLayoutError::InvalidTemplate(format!(
+                "For the 'template' layout, the location template '{location_template}' must contain the {{uuid}} placeholder to prevent path collisions."
+            )));
+        }
+
+        let mut rest = location_template.as_str();
+        while let Some(start) = rest.find('{') {
+            let Some(len) = rest[start..].find('}') else {
+                return Err(StorageLayoutError::InvalidTemplate(format!(
+                    "The location template '{location_template}' contains an unclosed placeholder."
+                )));
+            };
+            let placeholder = &rest[start..=start + len];
+            if !LOCATION_TEMPLATE_PARAMETERS.contains(&placeholder) {
+                return Err(StorageLayoutError::InvalidTemplate(format!(
+                    "The location template '{location_template}' contains the unknown placeholder '{placeholder}'. Supported placeholders are: {}.",
+                    LOCATION_TEMPLATE_PARAMETERS.join(", ")
+                )));
+            }
+            rest = &rest[start + len + 1..];
+        }
+
+        if location_template
+            .split('/')
+            .any(|segment| segment == "." || segment == "..")
+        {
+            return Err(StorageLayoutError::InvalidTemplate(format!(
+                "The location template '{location_template}' must not contain '.' or '..' segments."
+            )));
+        }
+
+        Ok(Self { location_template })
+    }
+
+    /// Render the path segments of a tabular below its namespace location.
+    #[must_use]
+    pub fn render(
+        &self,
+        namespace_path: &NamespacePath,
+        context: &TabularNameContext,
+    ) -> Vec<String> {
+        let namespace = namespace_path
+            .into_iter()
+            .map(|ns| encode_path_segment(&ns.name).into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+        let namespace_uuid = namespace_path
+            .namespace()
+            .map(|ns| ns.uuid.to_string())
+            .unwrap_or_default();
+        let name = context.get_name();
+
+        self.location_template
+            .replace("{namespace-uuid}", &namespace_uuid)
+            .replace("{namespace}", &namespace)
+            .replace("{name}", &encode_path_segment(&name))
+            .replace("{uuid}", &context.get_uuid().to_string())
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for StorageLayoutLocationTemplate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct StorageLayoutLocationTemplateHelper {
+            location_template: String,
+        }
+
+        let helper = StorageLayoutLocationTemplateHelper::deserialize(deserializer)?;
+        StorageLayoutLocationTemplate::try_new(helper.location_template)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 const TEMPLATE_PARAMETERS: [&str; 2] = ["{uuid}", "{name}"];
 
+const LOCATION_TEMPLATE_PARAMETERS: [&str; 4] =
+    ["{namespace}", "{namespace-uuid}", "{name}", "{uuid}"];
+
 fn has_template_parameter(template: &str) -> bool {
     TEMPLATE_PARAMETERS
         .iter()
@@ -211,6 +320,8 @@ fn has_template_parameter(template: &str) -> bool {
 /// - `full-hierarchy`: one directory per namespace level, one per tabular.
 /// - `tabular-only`: no namespace directories; all tabulars are placed directly under the base
 ///   location, with a configurable tabular template (which must contain `{uuid}`).
+/// - `template`: no namespace directories; the full path of a tabular below its namespace
+///   location is rendered from a single `location-template` (which must contain `{uuid}`).
 ///
 /// Segment templates may use `{uuid}` and `{name}` as placeholders.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize, Default, derive_more::From)]
@@ -228,6 +339,8 @@ pub enum StorageLayout {
     Parent(StorageLayoutParentNamespaceAndTabular),
     #[serde(rename = "full-hierarchy")]
     Full(StorageLayoutFullHierarchy),
+    #[serde(rename = "template")]
+    Template(StorageLayoutLocationTemplate),
 }
 
 impl StorageLayout {
@@ -250,13 +363,17 @@ impl StorageLayout {
         StorageLayoutFullHierarchy::try_new(namespace_template, tabular_template).map(Self::Full)
     }
 
+    pub fn try_new_template(location_template: String) -> Result<Self, StorageLayoutError> {
+        StorageLayoutLocationTemplate::try_new(location_template).map(Self::Template)
+    }
+
     #[must_use]
     pub fn tabular_template(&self) -> &StorageLayoutTabularTemplate {
         match self {
             StorageLayout::Flat(template) => &template.tabular,
             StorageLayout::Parent(template) => &template.tabular,
             StorageLayout::Full(template) => &template.tabular,
-            StorageLayout::Default => &DEFAULT_TABULAR_TEMPLATE,
+            StorageLayout::Default | StorageLayout::Template(_) => &DEFAULT_TABULAR_TEMPLATE,
         }
     }
 
@@ -265,12 +382,29 @@ impl StorageLayout {
         self.tabular_template().render(context)
     }
 
+    /// Render the path segments of a tabular below its namespace location.
+    #[must_use]
+    pub fn render_tabular_path(
+        &self,
+        namespace_path: &NamespacePath,
+        context: &TabularNameContext,
+    ) -> Vec<String> {
+        match self {
+            StorageLayout::Template(layout) => layout.render(namespace_path, context),
+            StorageLayout::Default
+            | StorageLayout::Flat(_)
+            | StorageLayout::Parent(_)
+            | StorageLayout::Full(_) => vec![self.render_tabular_segment(context)],
+        }
+    }
+
     #[must_use]
     pub fn render_namespace_path(&self, path_context: &NamespacePath) -> Vec<String> {
         match self {
             // Flat layouts — including the default since 0.13 — place tabulars
             // directly under the base location, so no namespace directories are emitted.
-            StorageLayout::Flat(_) | StorageLayout::Default => vec![],
+            // Template layouts render the namespace as part of the tabular path.
+            StorageLayout::Flat(_) | StorageLayout::Default | StorageLayout::Template(_) => vec![],
             StorageLayout::Parent(layout) => {
                 render_parent_namespace_path(path_context, &layout.namespace)
             }
@@ -1130,4 +1264,74 @@ mod tests {
             "Expected deserialization to fail for full-hierarchy layout without at least one template parameter in namespace template"
         );
     }
+
+    #[test]
+    fn test_storage_layout_render_tabular_path_in_template_layout() {
+        let layout =
+            StorageLayout::try_new_template("data/{namespace}/{name}-{uuid}".to_string()).unwrap();
+        let grand_parent_namespace = NamespaceNameContext {
+            name: "grand parent".to_string(),
+            uuid: Uuid::now_v7(),
+        };
+        let parent_namespace = NamespaceNameContext {
+            name: "parent/ns".to_string(),
+            uuid: Uuid::now_v7(),
+        };
+        let path = NamespacePath::new(vec![grand_parent_namespace, parent_namespace]);
+        let context = TabularNameContext {
+            name: "my_tabular".to_string(),
+            uuid: Uuid::now_v7(),
+        };
+
+        assert!(layout.render_namespace_path(&path).is_empty());
+        assert_eq!(
+            layout.render_tabular_path(&path, &context),
+            vec![
+                "data".to_string(),
+                "grand%20parent".to_string(),
+                "parent%2Fns".to_string(),
+                format!("my_tabular-{}", context.uuid),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_storage_layout_template_requires_uuid_and_known_placeholders() {
+        for template in [
+            "{namespace}/{name}",
+            "{namespace}/{table}-{uuid}",
+            "{namespace}/{uuid",
+            "../{uuid}",
+        ] {
+            let err = StorageLayout::try_new_template(template.to_string())
+                .expect_err("Expected invalid template to be rejected");
+            assert!(matches!(err, StorageLayoutError::InvalidTemplate(_)));
+        }
+    }
+
+    #[test]
+    fn test_storage_layout_deserialization_of_template_layout() {
+        let json = r#"
+        {
+            "type": "template",
+            "location-template": "{namespace-uuid}/{uuid}"
+        }
+        "#;
+
+        let layout: StorageLayout =
+            serde_json::from_str(json).expect("Failed to deserialize StorageLayout");
+        let StorageLayout::Template(template) = &layout else {
+            panic!("Expected template storage layout");
+        };
+        assert_eq!(template.location_template, "{namespace-uuid}/{uuid}");
+
+        let json = r#"
+        {
+            "type": "template",
+            "location-template": "{namespace}/{name}"
+        }
+        "#;
+        serde_json::from_str::<StorageLayout>(json)
+            .expect_err("Template layout without {uuid} must be rejected");
+    }
 }
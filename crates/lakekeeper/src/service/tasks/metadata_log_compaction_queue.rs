@@ -0,0 +1,624 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    sync::LazyLock,
+    time::Duration,
+};
+
+use iceberg::spec::{SnapshotRetention, TableMetadata, TableProperties};
+use serde::{Deserialize, Serialize};
+use tracing::Instrument;
+#[cfg(feature = "open-api")]
+use utoipa::{PartialSchema, ToSchema};
+
+use super::{
+    FailureKind, SpecializedTask, TaskConfig, TaskData, TaskExecutionDetails, WarehouseTaskEntityId,
+};
+use crate::{
+    CONFIG, CancellationToken,
+    api::{ApiContext, ErrorModel, Result},
+    server::tables::compact_metadata::{CompactionOutcome, compact_table_metadata},
+    service::{
+        CatalogStore, CatalogWarehouseOps, SecretStore, State, WarehouseIdNotFound,
+        WarehouseStatus,
+        authz::Authorizer,
+        tasks::{TaskEntity, TaskQueueName},
+    },
+};
+
+const QN_STR: &str = "metadata_log_compaction";
+const UNEXPECTED_TASK_SCOPE_ERROR_TYPE: &str = "UnexpectedTaskScopeForMetadataLogCompaction";
+pub static QUEUE_NAME: LazyLock<TaskQueueName> = LazyLock::new(|| QN_STR.into());
+#[cfg(feature = "open-api")]
+pub(crate) static API_CONFIG: LazyLock<super::QueueApiConfig> =
+    LazyLock::new(|| super::QueueApiConfig {
+        queue_name: &QUEUE_NAME,
+        utoipa_type_name: MetadataLogCompactionQueueConfig::name(),
+        utoipa_schema: MetadataLogCompactionQueueConfig::schema(),
+        scope: super::QueueScope::Warehouse,
+        user_scheduling: super::UserScheduling::Enabled {
+            payload_schema: None,
+        },
+    });
+
+const DEFAULT_MAX_METADATA_LOG_ENTRIES: usize = 100;
+const DEFAULT_MAX_SNAPSHOT_AGE_MS: i64 = 5 * 24 * 60 * 60 * 1000;
+const DEFAULT_MIN_SNAPSHOTS_TO_KEEP: usize = 1;
+
+const PROPERTY_MAX_SNAPSHOT_AGE_MS: &str = "history.expire.max-snapshot-age-ms";
+const PROPERTY_MIN_SNAPSHOTS_TO_KEEP: &str = "history.expire.min-snapshots-to-keep";
+const PROPERTY_GC_ENABLED: &str = "gc.enabled";
+
+pub type MetadataLogCompactionTask = SpecializedTask<
+    MetadataLogCompactionQueueConfig,
+    MetadataLogCompactionPayload,
+    MetadataLogCompactionExecutionDetails,
+>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetadataLogCompactionPayload {}
+
+impl MetadataLogCompactionPayload {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl TaskData for MetadataLogCompactionPayload {}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "open-api", derive(ToSchema))]
+#[serde(rename_all = "kebab-case")]
+/// Warehouse-specific configuration for the metadata log compaction queue.
+/// Each parameter can be overridden on a per-table level with the table property named in its description.
+pub struct MetadataLogCompactionQueueConfig {
+    /// Maximum number of previous metadata files to keep in the metadata log.
+    /// If not set, defaults to `100`.
+    /// Can be overridden on a per-table level with the table property `write.metadata.previous-versions-max`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(default = 100))]
+    pub max_metadata_log_entries: Option<usize>,
+    /// Maximum age of a snapshot in milliseconds before it is expired.
+    /// Snapshots still referenced by a branch or tag are never expired.
+    /// If not set, defaults to `432000000` (5 days).
+    /// Can be overridden on a per-table level with the table property `history.expire.max-snapshot-age-ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(default = 432_000_000))]
+    pub max_snapshot_age_ms: Option<i64>,
+    /// Minimum number of snapshots to keep on each branch, regardless of their age.
+    /// If not set, defaults to `1`.
+    /// Can be overridden on a per-table level with the table property `history.expire.min-snapshots-to-keep`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "open-api", schema(default = 1))]
+    pub min_snapshots_to_keep: Option<usize>,
+}
+
+impl TaskConfig for MetadataLogCompactionQueueConfig {
+    fn queue_name() -> &'static TaskQueueName {
+        &QUEUE_NAME
+    }
+
+    fn max_time_since_last_heartbeat() -> chrono::Duration {
+        chrono::Duration::seconds(300)
+    }
+
+    fn check_schedule_eligibility(
+        _config: &Self,
+        _entity_properties: &HashMap<String, String>,
+        entity: WarehouseTaskEntityId,
+    ) -> Result<(), ErrorModel> {
+        match entity {
+            WarehouseTaskEntityId::Table { .. } => Ok(()),
+            WarehouseTaskEntityId::View { .. } | WarehouseTaskEntityId::GenericTable { .. } => {
+                Err(ErrorModel::bad_request(
+                    format!(
+                        "Queue `{QN_STR}` only accepts tables, got entity of type `{}`.",
+                        entity.entity_type()
+                    ),
+                    "UnsupportedTaskEntity",
+                    None,
+                ))
+            }
+        }
+    }
+
+    /// A task with a non-table scope or a missing warehouse or table
+    /// cannot succeed on retry.
+    fn classify_failure(error: &ErrorModel) -> FailureKind {
+        if error.r#type == UNEXPECTED_TASK_SCOPE_ERROR_TYPE
+            || error.code == http::StatusCode::NOT_FOUND.as_u16()
+        {
+            FailureKind::Permanent
+        } else {
+            FailureKind::Transient
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MetadataLogCompactionExecutionDetails {}
+
+impl TaskExecutionDetails for MetadataLogCompactionExecutionDetails {}
+
+/// Retention limits for a single table. Each limit is taken from the table
+/// properties if set, then from the queue config, then from the Iceberg default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MetadataRetention {
+    pub(crate) max_metadata_log_entries: usize,
+    pub(crate) max_snapshot_age_ms: i64,
+    pub(crate) min_snapshots_to_keep: usize,
+    /// `false` for tables with `gc.enabled=false`. Their snapshots are kept,
+    /// only the metadata log is compacted.
+    pub(crate) expire_snapshots: bool,
+}
+
+impl MetadataRetention {
+    pub(crate) fn resolve(
+        config: Option<&MetadataLogCompactionQueueConfig>,
+        properties: &HashMap<String, String>,
+    ) -> Self {
+        Self {
+            max_metadata_log_entries: parse_property(
+                properties,
+                TableProperties::PROPERTY_METADATA_PREVIOUS_VERSIONS_MAX,
+            )
+            .or_else(|| config.and_then(|c| c.max_metadata_log_entries))
+            .unwrap_or(DEFAULT_MAX_METADATA_LOG_ENTRIES),
+            max_snapshot_age_ms: parse_property(properties, PROPERTY_MAX_SNAPSHOT_AGE_MS)
+                .or_else(|| config.and_then(|c| c.max_snapshot_age_ms))
+                .unwrap_or(DEFAULT_MAX_SNAPSHOT_AGE_MS),
+            min_snapshots_to_keep: parse_property(properties, PROPERTY_MIN_SNAPSHOTS_TO_KEEP)
+                .or_else(|| config.and_then(|c| c.min_snapshots_to_keep))
+                .unwrap_or(DEFAULT_MIN_SNAPSHOTS_TO_KEEP),
+            expire_snapshots: properties
+                .get(PROPERTY_GC_ENABLED)
+                .is_none_or(|v| !v.eq_ignore_ascii_case("false")),
+        }
+    }
+
+    /// Ids of the snapshots of `metadata` that are past their retention at `now_ms`.
+    ///
+    /// Every snapshot a branch or tag points to is kept. Walking back from a
+    /// branch head, ancestors are kept while the branch holds fewer than its
+    /// minimum number of snapshots or while they are younger than its maximum
+    /// age. Branch-level retention settings take precedence over the table-level
+    /// limits. All other snapshots older than the maximum age are expired.
+    pub(crate) fn expired_snapshot_ids(&self, metadata: &TableMetadata, now_ms: i64) -> Vec<i64> {
+        if !self.expire_snapshots {
+            return vec![];
+        }
+
+        let mut retained = HashSet::new();
+        retained.extend(metadata.current_snapshot_id());
+        for reference in metadata.refs().values() {
+            retained.insert(reference.snapshot_id);
+            let SnapshotRetention::Branch {
+                min_snapshots_to_keep,
+                max_snapshot_age_ms,
+                max_ref_age_ms: _,
+            } = &reference.retention
+            else {
+                continue;
+            };
+            let min_snapshots_to_keep = min_snapshots_to_keep
+                .and_then(|v| usize::try_from(v).ok())
+                .unwrap_or(self.min_snapshots_to_keep);
+            let cutoff_ms =
+                now_ms.saturating_sub(max_snapshot_age_ms.unwrap_or(self.max_snapshot_age_ms));
+
+            let mut kept = 0;
+            let mut next = Some(reference.snapshot_id);
+            while let Some(snapshot) = next.and_then(|id| metadata.snapshot_by_id(id)) {
+                if kept >= min_snapshots_to_keep && snapshot.timestamp_ms() < cutoff_ms {
+                    break;
+                }
+                retained.insert(snapshot.snapshot_id());
+                kept += 1;
+                next = snapshot.parent_snapshot_id();
+            }
+        }
+
+        let cutoff_ms = now_ms.saturating_sub(self.max_snapshot_age_ms);
+        let mut expired = metadata
+            .snapshots()
+            .filter(|s| !retained.contains(&s.snapshot_id()) && s.timestamp_ms() < cutoff_ms)
+            .map(|s| s.snapshot_id())
+            .collect::<Vec<_>>();
+        expired.sort_unstable();
+        expired
+    }
+}
+
+fn parse_property<T: FromStr>(properties: &HashMap<String, String>, key: &str) -> Option<T> {
+    let value = properties.get(key)?;
+    value
+        .parse()
+        .inspect_err(|_| {
+            tracing::warn!("Ignoring invalid value `{value}` of table property `{key}`");
+        })
+        .ok()
+}
+
+pub(crate) async fn metadata_log_compaction_worker<
+    C: CatalogStore,
+    A: Authorizer + Clone,
+    S: SecretStore,
+>(
+    state: ApiContext<State<A, C, S>>,
+    poll_interval: Duration,
+    cancellation_token: CancellationToken,
+) {
+    let catalog_state = state.v1_state.catalog.clone();
+    loop {
+        let task = MetadataLogCompactionTask::poll_for_new_task::<C>(
+            catalog_state.clone(),
+            &poll_interval,
+            cancellation_token.clone(),
+        )
+        .await;
+
+        let Some(task) = task else {
+            tracing::info!("Graceful shutdown: exiting `{QN_STR}` worker");
+            return;
+        };
+
+        let span = if let Some((warehouse_id, entity_id, entity_name)) =
+            task.task_metadata.warehouse_task_sub_entity()
+        {
+            let entity_id_uuid = entity_id.as_uuid();
+            let entity_type = entity_id.entity_type().to_string();
+            let entity_name = entity_name.join(".");
+            tracing::debug_span!(
+                QN_STR,
+                warehouse_id = %warehouse_id,
+                entity_type = %entity_type,
+                entity_id = %entity_id_uuid,
+                entity_name = %entity_name,
+                attempt = %task.attempt(),
+                task_id = %task.task_id(),
+            )
+        } else {
+            tracing::debug_span!(
+                QN_STR,
+                entity_type = "Not Specified",
+                attempt = %task.attempt(),
+                task_id = %task.task_id(),
+            )
+        };
+
+        task.run_or_release::<C>(
+            catalog_state.clone(),
+            &cancellation_token,
+            CONFIG.task_drain_timeout,
+            instrumented_compaction::<C, A, S>(state.clone(), &task).instrument(span.or_current()),
+        )
+        .await;
+    }
+}
+
+async fn instrumented_compaction<C: CatalogStore, A: Authorizer + Clone, S: SecretStore>(
+    state: ApiContext<State<A, C, S>>,
+    task: &MetadataLogCompactionTask,
+) {
+    let catalog_state = state.v1_state.catalog.clone();
+    match compact::<C, A, S>(task, state).await {
+        Ok(details) => {
+            tracing::info!("Task of `{QN_STR}` worker exited successfully. {details}");
+            task.record_success::<C>(catalog_state, Some(&details))
+                .await;
+        }
+        Err(err) => {
+            tracing::error!("Error in `{QN_STR}` worker. Failed to compact table metadata. {err}");
+            let detail = format!("Failed to compact table metadata.\nError: {}", err.error);
+            task.record_error::<C>(catalog_state, &detail, &err.error)
+                .await;
+        }
+    }
+}
+
+async fn compact<C, A, S>(
+    task: &MetadataLogCompactionTask,
+    state: ApiContext<State<A, C, S>>,
+) -> Result<String>
+where
+    C: CatalogStore,
+    A: Authorizer + Clone,
+    S: SecretStore,
+{
+    let (warehouse_id, table_id) = match &task.task_metadata.entity {
+        TaskEntity::EntityInWarehouse {
+            warehouse_id,
+            entity_id: WarehouseTaskEntityId::Table { table_id },
+            entity_name: _,
+        } => (*warehouse_id, *table_id),
+        TaskEntity::EntityInWarehouse { .. }
+        | TaskEntity::Warehouse { .. }
+        | TaskEntity::Project => {
+            return Err(ErrorModel::internal(
+                format!("Unexpected task scope for `{QN_STR}` task. Task must have a table scope."),
+                UNEXPECTED_TASK_SCOPE_ERROR_TYPE,
+                None,
+            )
+            .into());
+        }
+    };
+
+    let warehouse = C::get_warehouse_by_id(
+        warehouse_id,
+        WarehouseStatus::active(),
+        state.v1_state.catalog.clone(),
+    )
+    .await
+    .map_err(ErrorModel::from)
+    .and_then(|w| w.ok_or_else(|| WarehouseIdNotFound::new(warehouse_id).into()))
+    .map_err(|e| {
+        e.append_detail(format!(
+            "Failed to get warehouse {warehouse_id} for Metadata Log Compaction task."
+        ))
+    })?;
+
+    let outcome =
+        compact_table_metadata::<C, A, S>(warehouse, table_id, task.config.as_ref(), state).await?;
+
+    Ok(match outcome {
+        CompactionOutcome::TableNotFound => {
+            tracing::warn!(
+                "Table {table_id} in warehouse {warehouse_id} no longer exists, nothing to compact."
+            );
+            "Table no longer exists".to_string()
+        }
+        CompactionOutcome::UpToDate => "Table metadata within retention limits".to_string(),
+        CompactionOutcome::Compacted {
+            removed_metadata_log_entries,
+            removed_snapshots,
+        } => format!(
+            "Removed {removed_metadata_log_entries} metadata log entries and {removed_snapshots} expired snapshots"
+        ),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use iceberg::spec::{
+        FormatVersion, MAIN_BRANCH, NestedField, Operation, PrimitiveType, Schema, Snapshot,
+        SnapshotReference, SortOrder, Summary, UnboundPartitionSpec,
+    };
+    use iceberg_ext::spec::TableMetadataBuilder;
+
+    use super::*;
+
+    const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+    fn snapshot(snapshot_id: i64, parent: Option<i64>, timestamp_ms: i64) -> Snapshot {
+        Snapshot::builder()
+            .with_snapshot_id(snapshot_id)
+            .with_parent_snapshot_id(parent)
+            .with_timestamp_ms(timestamp_ms)
+            .with_sequence_number(snapshot_id)
+            .with_schema_id(0)
+            .with_manifest_list(format!(
+                "s3://bucket/table/metadata/snap-{snapshot_id}.avro"
+            ))
+            .with_summary(Summary {
+                operation: Operation::Append,
+                additional_properties: HashMap::new(),
+            })
+            .build()
+    }
+
+    /// Table with a `main` branch of five snapshots taken one day apart and a
+    /// `tagged` tag pointing at the oldest one. Returns the metadata and a
+    /// point in time at which the snapshots are 10, 9, 8, 7 and 6 days old.
+    fn metadata_with_history(main_min_snapshots_to_keep: Option<i32>) -> (TableMetadata, i64) {
+        let schema = Schema::builder()
+            .with_fields(vec![
+                NestedField::required(1, "id", iceberg::spec::Type::Primitive(PrimitiveType::Int))
+                    .into(),
+            ])
+            .build()
+            .unwrap();
+        let mut builder = TableMetadataBuilder::new(
+            schema,
+            UnboundPartitionSpec::builder().build(),
+            SortOrder::unsorted_order(),
+            "s3://bucket/table".to_string(),
+            FormatVersion::V2,
+            HashMap::new(),
+        )
+        .unwrap();
+        let first_ms = chrono::Utc::now().timestamp_millis();
+        for id in 1..=5 {
+            builder = builder
+                .add_snapshot(snapshot(
+                    id,
+                    (id > 1).then_some(id - 1),
+                    first_ms + (id - 1) * DAY_MS,
+                ))
+                .unwrap()
+                .set_ref(
+                    MAIN_BRANCH,
+                    SnapshotReference {
+                        snapshot_id: id,
+                        retention: SnapshotRetention::Branch {
+                            min_snapshots_to_keep: main_min_snapshots_to_keep,
+                            max_snapshot_age_ms: None,
+                            max_ref_age_ms: None,
+                        },
+                    },
+                )
+                .unwrap();
+        }
+        let metadata = builder
+            .set_ref(
+                "tagged",
+                SnapshotReference {
+                    snapshot_id: 1,
+                    retention: SnapshotRetention::Tag {
+                        max_ref_age_ms: None,
+                    },
+                },
+            )
+            .unwrap()
+            .build()
+            .unwrap()
+            .metadata;
+        (metadata, first_ms + 10 * DAY_MS)
+    }
+
+    fn retention(max_snapshot_age_ms: i64, min_snapshots_to_keep: usize) -> MetadataRetention {
+        MetadataRetention {
+            max_metadata_log_entries: DEFAULT_MAX_METADATA_LOG_ENTRIES,
+            max_snapshot_age_ms,
+            min_snapshots_to_keep,
+            expire_snapshots: true,
+        }
+    }
+
+    #[test]
+    fn test_parsing_metadata_log_compaction_config_from_json() {
+        let config: MetadataLogCompactionQueueConfig = serde_json::from_str(
+            r#"{"max-metadata-log-entries":10,"max-snapshot-age-ms":3600000,"min-snapshots-to-keep":3}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            MetadataLogCompactionQueueConfig {
+                max_metadata_log_entries: Some(10),
+                max_snapshot_age_ms: Some(3_600_000),
+                min_snapshots_to_keep: Some(3),
+            }
+        );
+
+        let empty: MetadataLogCompactionQueueConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty, MetadataLogCompactionQueueConfig::default());
+    }
+
+    #[test]
+    fn test_retention_prefers_table_properties_over_queue_config() {
+        let config = MetadataLogCompactionQueueConfig {
+            max_metadata_log_entries: Some(10),
+            max_snapshot_age_ms: Some(DAY_MS),
+            min_snapshots_to_keep: Some(3),
+        };
+
+        let from_config = MetadataRetention::resolve(Some(&config), &HashMap::new());
+        assert_eq!(
+            from_config,
+            MetadataRetention {
+                max_metadata_log_entries: 10,
+                max_snapshot_age_ms: DAY_MS,
+                min_snapshots_to_keep: 3,
+                expire_snapshots: true,
+            }
+        );
+
+        let properties = HashMap::from([
+            (
+                TableProperties::PROPERTY_METADATA_PREVIOUS_VERSIONS_MAX.to_string(),
+                "20".to_string(),
+            ),
+            (PROPERTY_MAX_SNAPSHOT_AGE_MS.to_string(), "1000".to_string()),
+            (
+                PROPERTY_MIN_SNAPSHOTS_TO_KEEP.to_string(),
+                "not-a-number".to_string(),
+            ),
+            (PROPERTY_GC_ENABLED.to_string(), "FALSE".to_string()),
+        ]);
+        let from_properties = MetadataRetention::resolve(Some(&config), &properties);
+        assert_eq!(
+            from_properties,
+            MetadataRetention {
+                max_metadata_log_entries: 20,
+                max_snapshot_age_ms: 1000,
+                min_snapshots_to_keep: 3,
+                expire_snapshots: false,
+            }
+        );
+
+        let defaults = MetadataRetention::resolve(None, &HashMap::new());
+        assert_eq!(
+            defaults,
+            MetadataRetention {
+                max_metadata_log_entries: DEFAULT_MAX_METADATA_LOG_ENTRIES,
+                max_snapshot_age_ms: DEFAULT_MAX_SNAPSHOT_AGE_MS,
+                min_snapshots_to_keep: DEFAULT_MIN_SNAPSHOTS_TO_KEEP,
+                expire_snapshots: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_expired_snapshots_keep_referenced_and_recent_snapshots() {
+        let (metadata, now_ms) = metadata_with_history(None);
+
+        // No snapshot is older than 11 days.
+        assert_eq!(
+            retention(11 * DAY_MS, 1).expired_snapshot_ids(&metadata, now_ms),
+            Vec::<i64>::new()
+        );
+        // Snapshots 1-3 are older than 7.5 days; the tagged snapshot 1 is kept.
+        assert_eq!(
+            retention(7 * DAY_MS + DAY_MS / 2, 1).expired_snapshot_ids(&metadata, now_ms),
+            vec![2, 3]
+        );
+        // Main keeps its three newest snapshots regardless of age.
+        assert_eq!(
+            retention(DAY_MS, 3).expired_snapshot_ids(&metadata, now_ms),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn test_expired_snapshots_respect_branch_retention_and_gc_enabled() {
+        let (metadata, now_ms) = metadata_with_history(Some(4));
+        assert_eq!(
+            retention(DAY_MS, 1).expired_snapshot_ids(&metadata, now_ms),
+            Vec::<i64>::new()
+        );
+
+        let (metadata, now_ms) = metadata_with_history(None);
+        let gc_disabled = MetadataRetention {
+            expire_snapshots: false,
+            ..retention(DAY_MS, 1)
+        };
+        assert!(
+            gc_disabled
+                .expired_snapshot_ids(&metadata, now_ms)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_classify_failure() {
+        let classify = MetadataLogCompactionQueueConfig::classify_failure;
+        assert_eq!(
+            classify(&ErrorModel::internal(
+                "unexpected scope",
+                UNEXPECTED_TASK_SCOPE_ERROR_TYPE,
+                None
+            )),
+            FailureKind::Permanent
+        );
+        assert_eq!(
+            classify(&ErrorModel::not_found(
+                "warehouse not found",
+                "WarehouseNotFound",
+                None
+            )),
+            FailureKind::Permanent
+        );
+        assert_eq!(
+            classify(&ErrorModel::conflict(
+                "concurrent commit",
+                "CommitFailedException",
+                None
+            )),
+            FailureKind::Transient
+        );
+        assert_eq!(
+            classify(&ErrorModel::internal("io error", "IoError", None)),
+            FailureKind::Transient
+        );
+    }
+}
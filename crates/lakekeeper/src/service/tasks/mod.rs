@@ -25,6 +25,8 @@ pub use task_registry::{
     QueueApiConfig, QueueRegistration, QueueScope, RegisteredTaskQueues, ScheduleEligibilityFn,
     TaskQueueRegistry, UserScheduling, ValidatorFn,
 };
+pub mod metadata_log_compaction_queue;
+pub mod queue_metrics;
 pub mod tabular_expiration_queue;
 pub mod tabular_purge_queue;
 pub mod task_log_cleanup_queue;
@@ -38,6 +40,7 @@ const DEFAULT_MAX_RETRIES: i32 = 5;
 pub static BUILT_IN_API_CONFIGS: std::sync::LazyLock<Vec<QueueApiConfig>> =
     std::sync::LazyLock::new(|| {
         vec![
+            metadata_log_compaction_queue::API_CONFIG.clone(),
             tabular_expiration_queue::API_CONFIG.clone(),
             tabular_purge_queue::API_CONFIG.clone(),
         ]
@@ -60,10 +63,12 @@ mod built_in_schedulable_pin_test {
 
     /// Pin the set of OSS queues that opt in to `task-queue/{name}/schedule`.
     ///
-    /// **OSS has zero schedulable queues.** Destructive (`tabular_purge`) and
-    /// lifecycle-managed (`soft_deletion`) queues intentionally stay
-    /// opted out so they can't be enqueued out-of-band; `task_log_cleanup` is
-    /// project-scoped and not meaningful to trigger manually.
+    /// **OSS has one schedulable queue**, `metadata_log_compaction`, which only
+    /// rewrites table metadata within its retention limits. Destructive
+    /// (`tabular_purge`) and lifecycle-managed (`soft_deletion`) queues
+    /// intentionally stay opted out so they can't be enqueued out-of-band;
+    /// `task_log_cleanup` is project-scoped and not meaningful to trigger
+    /// manually.
     ///
     /// Enterprise has its own pin test for `expire_snapshots` and
     /// `remove_orphan_files`. If a new OSS queue legitimately needs to be
@@ -78,7 +83,7 @@ mod built_in_schedulable_pin_test {
             .map(|c| c.queue_name.as_str())
             .collect();
         names.sort_unstable();
-        let expected: Vec<&str> = vec![];
+        let expected: Vec<&str> = vec!["metadata_log_compaction"];
         assert_eq!(
             names, expected,
             "OSS schedulable-queue set changed; review the security \
@@ -239,6 +244,19 @@ pub trait TaskConfig:
     ) -> Result<(), ErrorModel> {
         Ok(())
     }
+
+    /// Decide whether an error returned by the worker may go away on retry.
+    ///
+    /// Used by [`SpecializedTask::record_error`]. A [`FailureKind::Permanent`]
+    /// failure skips the remaining retries: the task fails immediately and can
+    /// be requeued by an operator once the cause is fixed.
+    ///
+    /// Default: every failure is transient.
+    #[must_use]
+    #[allow(unused_variables)]
+    fn classify_failure(error: &ErrorModel) -> FailureKind {
+        FailureKind::Transient
+    }
 }
 
 #[cfg(not(feature = "open-api"))]
@@ -268,6 +286,23 @@ pub trait TaskConfig: Serialize + DeserializeOwned + Clone + Send + Sync {
     ) -> Result<(), ErrorModel> {
         Ok(())
     }
+
+    /// See the `open-api`-enabled trait for full documentation.
+    #[must_use]
+    #[allow(unused_variables)]
+    fn classify_failure(error: &ErrorModel) -> FailureKind {
+        FailureKind::Transient
+    }
+}
+
+/// Classification of a failed task attempt, see [`TaskConfig::classify_failure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// The attempt may succeed when retried. The task is retried until
+    /// [`TaskConfig::max_retries`] is reached.
+    Transient,
+    /// Retrying cannot succeed. The task fails immediately.
+    Permanent,
 }
 
 /// Task Payload.
@@ -946,14 +981,36 @@ impl<Q: TaskConfig, D: TaskData, E: TaskExecutionDetails> SpecializedTask<Q, D,
         }
     }
 
+    /// Refresh the pending-task gauges of this queue from the catalog.
+    ///
+    /// # Errors
+    /// Returns an error if the queue depth cannot be fetched from the catalog.
+    pub async fn report_queue_depth<C: CatalogStore>(
+        catalog_state: C::State,
+    ) -> crate::api::Result<()> {
+        let depth =
+            C::get_task_queue_depth(Q::queue_name(), &Q::legacy_queue_names(), catalog_state)
+                .await?;
+        queue_metrics::record_queue_depth(Q::queue_name(), &depth);
+        Ok(())
+    }
+
     /// Continuously poll for a new task in the queue until a task is found.
     /// Returns None if cancellation is requested.
+    ///
+    /// Refreshes the queue depth metrics before every poll.
     pub async fn poll_for_new_task<C: CatalogStore>(
         catalog_state: C::State,
         poll_interval: &Duration,
         cancellation_token: tokio_util::sync::CancellationToken,
     ) -> Option<Self> {
         loop {
+            if let Err(e) = Self::report_queue_depth::<C>(catalog_state.clone()).await {
+                tracing::warn!(
+                    "Failed to refresh queue depth metrics for queue `{}`: {e}",
+                    Q::queue_name()
+                );
+            }
             tokio::select! {
                 () = cancellation_token.cancelled() => {
                     tracing::info!("Graceful shutdown requested for queue `{}`", Q::queue_name());
@@ -1067,10 +1124,48 @@ impl<Q: TaskConfig, D: TaskData, E: TaskExecutionDetails> SpecializedTask<Q, D,
     }
 
     /// Records an failure for a task in the catalog, updating its status and retry count.
+    /// The failure is treated as transient.
     ///
     /// Does not return an error, but logs it.
     pub async fn record_failure<C: CatalogStore>(&self, catalog_state: C::State, error: &str) {
-        let max_retries = Q::max_retries();
+        self.record_failure_of_kind::<C>(catalog_state, error, FailureKind::Transient)
+            .await;
+    }
+
+    /// Records a failure caused by `error`, classified by [`TaskConfig::classify_failure`].
+    /// Permanent failures are not retried.
+    ///
+    /// Does not return an error, but logs it.
+    pub async fn record_error<C: CatalogStore>(
+        &self,
+        catalog_state: C::State,
+        details: &str,
+        error: &ErrorModel,
+    ) {
+        let kind = Q::classify_failure(error);
+        if kind == FailureKind::Permanent {
+            tracing::info!(
+                "Task {} in queue '{}' failed permanently and will not be retried.",
+                self.id,
+                Self::queue_name(),
+            );
+        }
+        self.record_failure_of_kind::<C>(catalog_state, details, kind)
+            .await;
+    }
+
+    async fn record_failure_of_kind<C: CatalogStore>(
+        &self,
+        catalog_state: C::State,
+        error: &str,
+        kind: FailureKind,
+    ) {
+        self.record_processing_duration(queue_metrics::TaskOutcomeLabel::Failure);
+        // With zero retries left, the store moves the task to its final `failed` state.
+        let max_retries = match kind {
+            FailureKind::Transient => Q::max_retries(),
+            FailureKind::Permanent => 0,
+        };
 
         let status = Status::Failure(error, max_retries);
 
@@ -1108,6 +1203,13 @@ impl<Q: TaskConfig, D: TaskData, E: TaskExecutionDetails> SpecializedTask<Q, D,
         }
     }
 
+    fn record_processing_duration(&self, outcome: queue_metrics::TaskOutcomeLabel) {
+        if let Some(picked_up_at) = self.picked_up_at {
+            let elapsed = (Utc::now() - picked_up_at).to_std().unwrap_or_default();
+            queue_metrics::record_processing_duration(Self::queue_name(), outcome, elapsed);
+        }
+    }
+
     /// Record success.
     ///
     /// Records the success of a task in the catalog, updating its status.
@@ -1117,6 +1219,7 @@ impl<Q: TaskConfig, D: TaskData, E: TaskExecutionDetails> SpecializedTask<Q, D,
         catalog_state: C::State,
         details: Option<&str>,
     ) {
+        self.record_processing_duration(queue_metrics::TaskOutcomeLabel::Success);
         let status = Status::Success(details);
 
         for attempt in 1..=5 {
@@ -1163,6 +1266,7 @@ impl<Q: TaskConfig, D: TaskData, E: TaskExecutionDetails> SpecializedTask<Q, D,
         transaction: <C::Transaction as Transaction<C::State>>::Transaction<'_>,
         details: Option<&str>,
     ) {
+        self.record_processing_duration(queue_metrics::TaskOutcomeLabel::Success);
         let status = Status::Success(details);
 
         match self
@@ -1187,6 +1291,63 @@ impl<Q: TaskConfig, D: TaskData, E: TaskExecutionDetails> SpecializedTask<Q, D,
         }
     }
 
+    /// Process this task with `work`, draining gracefully on shutdown.
+    ///
+    /// Once `cancellation_token` is cancelled, `work` gets up to `drain_timeout`
+    /// to finish. If it does not, `work` is dropped — rolling back any open
+    /// transaction — and the task is released back to the queue without being
+    /// recorded as failed, so that it is picked up again after the restart.
+    ///
+    /// Returns `true` if `work` ran to completion.
+    pub async fn run_or_release<C: CatalogStore>(
+        &self,
+        catalog_state: C::State,
+        cancellation_token: &tokio_util::sync::CancellationToken,
+        drain_timeout: Duration,
+        work: impl Future<Output = ()>,
+    ) -> bool {
+        let drained = async {
+            cancellation_token.cancelled().await;
+            tokio::time::sleep(drain_timeout).await;
+        };
+
+        tokio::select! {
+            () = work => true,
+            () = drained => {
+                tracing::warn!(
+                    "Task {} in queue '{}' did not finish within the shutdown drain period of {}s. Releasing it back to the queue.",
+                    self.id,
+                    Self::queue_name(),
+                    drain_timeout.as_secs_f64(),
+                );
+                self.release::<C>(catalog_state).await;
+                false
+            }
+        }
+    }
+
+    /// Release the task back to the queue without consuming an attempt.
+    ///
+    /// Does not return an error, but logs it. If releasing fails, the task is
+    /// picked up again once its heartbeat times out.
+    async fn release<C: CatalogStore>(&self, catalog_state: C::State) {
+        let result = async {
+            let mut trx = C::Transaction::begin_write(catalog_state).await?;
+            C::release_task(self.id, &mut trx.transaction()).await?;
+            trx.commit().await
+        }
+        .await;
+
+        if let Err(e) = result {
+            tracing::error!(
+                "Failed to release task {} in queue '{}': {}",
+                self.id,
+                Self::queue_name(),
+                e.error
+            );
+        }
+    }
+
     async fn report_deserialization_failure<C: CatalogStore>(
         catalog_state: C::State,
         id: TaskAttemptId,
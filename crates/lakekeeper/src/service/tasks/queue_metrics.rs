@@ -0,0 +1,76 @@
+//! Prometheus metrics for the task queues.
+//!
+//! The queue depth gauges are refreshed by every worker each time it polls its
+//! queue, so they lag at most one poll interval behind. All metrics carry the
+//! canonical queue name in the `queue` label; tasks still enqueued under a
+//! legacy queue name are counted towards the canonical queue.
+
+use std::{sync::LazyLock, time::Duration};
+
+use axum_prometheus::metrics;
+
+use crate::service::{TaskQueueDepth, tasks::TaskQueueName};
+
+pub const METRIC_TASK_QUEUE_PENDING_TASKS: &str = "lakekeeper_task_queue_pending_tasks";
+pub const METRIC_TASK_QUEUE_OLDEST_PENDING_TASK_AGE_SECONDS: &str =
+    "lakekeeper_task_queue_oldest_pending_task_age_seconds";
+pub const METRIC_TASK_PROCESSING_DURATION_SECONDS: &str =
+    "lakekeeper_task_processing_duration_seconds";
+pub const METRIC_QUEUE_LABEL: &str = "queue";
+pub const METRIC_OUTCOME_LABEL: &str = "outcome";
+
+static METRICS_INITIALIZED: LazyLock<()> = LazyLock::new(|| {
+    metrics::describe_gauge!(
+        METRIC_TASK_QUEUE_PENDING_TASKS,
+        "Number of tasks that are due but not yet picked up, by queue"
+    );
+    metrics::describe_gauge!(
+        METRIC_TASK_QUEUE_OLDEST_PENDING_TASK_AGE_SECONDS,
+        "Seconds since the oldest pending task of the queue became due; 0 if none is pending"
+    );
+    metrics::describe_histogram!(
+        METRIC_TASK_PROCESSING_DURATION_SECONDS,
+        "Duration from pick-up until a task attempt is recorded as success or failure, by queue and outcome"
+    );
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TaskOutcomeLabel {
+    Success,
+    Failure,
+}
+
+impl TaskOutcomeLabel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn record_queue_depth(queue_name: &TaskQueueName, depth: &TaskQueueDepth) {
+    let () = *METRICS_INITIALIZED;
+    metrics::gauge!(METRIC_TASK_QUEUE_PENDING_TASKS, METRIC_QUEUE_LABEL => queue_name.to_string())
+        .set(depth.pending as f64);
+    metrics::gauge!(
+        METRIC_TASK_QUEUE_OLDEST_PENDING_TASK_AGE_SECONDS,
+        METRIC_QUEUE_LABEL => queue_name.to_string()
+    )
+    .set(depth.oldest_pending_age_seconds.unwrap_or(0.0).max(0.0));
+}
+
+pub(crate) fn record_processing_duration(
+    queue_name: &TaskQueueName,
+    outcome: TaskOutcomeLabel,
+    duration: Duration,
+) {
+    let () = *METRICS_INITIALIZED;
+    metrics::histogram!(
+        METRIC_TASK_PROCESSING_DURATION_SECONDS,
+        METRIC_QUEUE_LABEL => queue_name.to_string(),
+        METRIC_OUTCOME_LABEL => outcome.as_str(),
+    )
+    .record(duration.as_secs_f64());
+}
@@ -7,14 +7,14 @@ use utoipa::{PartialSchema, ToSchema};
 
 use super::{TaskConfig, TaskExecutionDetails, WarehouseTaskEntityId};
 use crate::{
-    CancellationToken,
+    CONFIG, CancellationToken,
     api::{ErrorModel, Result, management::v1::DeleteKind},
     service::{
         CatalogStore, CatalogTabularOps, DropTabularError, Transaction,
         authz::Authorizer,
         tasks::{
-            ScheduleTaskMetadata, SpecializedTask, TaskData, TaskEntity, TaskQueueName,
-            tabular_purge_queue::TabularPurgePayload,
+            ScheduleTaskMetadata, SpecializedTask, TaskCheckState, TaskData, TaskEntity,
+            TaskQueueName, tabular_purge_queue::TabularPurgePayload,
         },
     },
 };
@@ -129,12 +129,31 @@ pub(crate) async fn tabular_expiration_worker<C: CatalogStore, A: Authorizer>(
             )
         };
 
-        instrumented_expire::<C, A>(catalog_state.clone(), authorizer.clone(), &task)
-            .instrument(span.or_current())
-            .await;
+        task.run_or_release::<C>(
+            catalog_state.clone(),
+            &cancellation_token,
+            CONFIG.task_drain_timeout,
+            instrumented_expire::<C, A>(catalog_state.clone(), authorizer.clone(), &task)
+                .instrument(span.or_current()),
+        )
+        .await;
     }
 }
 
+/// Expire the tabular of an already picked task, exactly as the worker does.
+/// Only intended for tests that interleave other operations with a running task.
+///
+/// # Errors
+/// Fails if the tabular cannot be dropped or the task status cannot be updated.
+#[cfg(any(test, feature = "test-utils"))]
+pub async fn expire_picked_task<C: CatalogStore, A: Authorizer>(
+    catalog_state: C::State,
+    authorizer: A,
+    task: &TabularExpirationTask,
+) -> Result<()> {
+    handle_table::<C, A>(catalog_state, authorizer, task).await
+}
+
 async fn instrumented_expire<C: CatalogStore, A: Authorizer>(
     catalog_state: C::State,
     authorizer: A,
@@ -158,7 +177,8 @@ async fn instrumented_expire<C: CatalogStore, A: Authorizer>(
                 "Failed to expire soft-deleted {entity_id_str}.\nError: {}",
                 err.error
             );
-            task.record_failure::<C>(catalog_state, &detail).await;
+            task.record_error::<C>(catalog_state, &detail, &err.error)
+                .await;
         }
     }
 }
@@ -200,7 +220,7 @@ where
             let drop_result =
                 C::drop_tabular(warehouse_id, table_id, true, trx.transaction()).await;
 
-            let location = match drop_result {
+            match drop_result {
                 Err(DropTabularError::TabularNotFound(..)) => {
                     tracing::warn!(
                         "Table with id `{table_id}` not found in catalog for `{QN_STR}` task. Skipping deletion."
@@ -215,23 +235,10 @@ where
                         .into())
                 }
                 Ok(loc) => Some(loc),
-            };
-
-            authorizer
-                .delete_table(warehouse_id, table_id)
-                .await
-                .inspect_err(|e| {
-                    tracing::error!(
-                        "Failed to delete table from authorizer in `{QN_STR}` task. {e}"
-                    );
-                })
-                .ok();
-            location
+            }
         }
         WarehouseTaskEntityId::View { view_id } => {
-            let location = match C::drop_tabular(warehouse_id, view_id, true, trx.transaction())
-                .await
-            {
+            match C::drop_tabular(warehouse_id, view_id, true, trx.transaction()).await {
                 Err(DropTabularError::TabularNotFound(..)) => {
                     tracing::warn!(
                         "View with id `{view_id}` not found in catalog for `{QN_STR}` task. Skipping deletion."
@@ -244,21 +251,10 @@ where
                     ))
                     .into()),
                 Ok(loc) => Some(loc),
-            };
-
-            authorizer
-                .delete_view(warehouse_id, view_id)
-                .await
-                .inspect_err(|e| {
-                    tracing::error!(
-                        "Failed to delete view from authorizer in `{QN_STR}` task. {e}"
-                    );
-                })
-                .ok();
-            location
+            }
         }
         WarehouseTaskEntityId::GenericTable { generic_table_id } => {
-            let location = match C::drop_tabular(
+            match C::drop_tabular(
                 warehouse_id,
                 generic_table_id,
                 true,
@@ -278,21 +274,52 @@ where
                     ))
                     .into()),
                 Ok(loc) => Some(loc),
-            };
-
-            authorizer
-                .delete_generic_table(warehouse_id, generic_table_id)
-                .await
-                .inspect_err(|e| {
-                    tracing::error!(
-                        "Failed to delete generic table from authorizer in `{QN_STR}` task. {e}"
-                    );
-                })
-                .ok();
-            location
+            }
         }
     };
 
+    // Undropping a tabular cancels this task in the same transaction that clears
+    // `deleted_at`. The drop above locked the tabular row, which serializes us with
+    // a concurrent undrop: if the task is gone by now, the tabular was restored
+    // and the drop must be rolled back.
+    if task
+        .heartbeat_in_transaction::<C>(trx.transaction(), 0.5, None)
+        .await?
+        == TaskCheckState::NotActive
+    {
+        tracing::info!(
+            "`{QN_STR}` task with id `{}` was cancelled by an undrop. Keeping the tabular.",
+            task.id
+        );
+        return Ok(());
+    }
+
+    match entity_id {
+        WarehouseTaskEntityId::Table { table_id } => authorizer
+            .delete_table(warehouse_id, table_id)
+            .await
+            .inspect_err(|e| {
+                tracing::error!("Failed to delete table from authorizer in `{QN_STR}` task. {e}");
+            })
+            .ok(),
+        WarehouseTaskEntityId::View { view_id } => authorizer
+            .delete_view(warehouse_id, view_id)
+            .await
+            .inspect_err(|e| {
+                tracing::error!("Failed to delete view from authorizer in `{QN_STR}` task. {e}");
+            })
+            .ok(),
+        WarehouseTaskEntityId::GenericTable { generic_table_id } => authorizer
+            .delete_generic_table(warehouse_id, generic_table_id)
+            .await
+            .inspect_err(|e| {
+                tracing::error!(
+                    "Failed to delete generic table from authorizer in `{QN_STR}` task. {e}"
+                );
+            })
+            .ok(),
+    };
+
     if let Some(tabular_location) = tabular_location
         && matches!(task.data.deletion_kind, DeleteKind::Purge)
     {
@@ -9,10 +9,12 @@ use utoipa::{PartialSchema, ToSchema};
 
 use super::{SpecializedTask, TaskConfig, TaskData, TaskExecutionDetails};
 use crate::{
+    CONFIG,
     api::Result,
     server::{io::remove_all, maybe_get_secret},
     service::{
-        CatalogStore, CatalogWarehouseOps, SecretStore, WarehouseIdNotFound, WarehouseStatus,
+        CatalogStore, CatalogWarehouseOps, ResolvedWarehouse, SecretStore, WarehouseIdNotFound,
+        WarehouseStatus,
         tasks::{TaskEntity, TaskQueueName},
     },
 };
@@ -109,9 +111,14 @@ pub(crate) async fn tabular_purge_worker<C: CatalogStore, S: SecretStore>(
             )
         };
 
-        instrumented_purge::<_, C>(catalog_state.clone(), &secret_state, &task)
-            .instrument(span.or_current())
-            .await;
+        task.run_or_release::<C>(
+            catalog_state.clone(),
+            &cancellation_token,
+            CONFIG.task_drain_timeout,
+            instrumented_purge::<_, C>(catalog_state.clone(), &secret_state, &task)
+                .instrument(span.or_current()),
+        )
+        .await;
     }
 }
 
@@ -138,7 +145,8 @@ async fn instrumented_purge<S: SecretStore, C: CatalogStore>(
                 "Failed to purge tabular at location `{}`.\nError: {}",
                 task.data.tabular_location, err.error
             );
-            task.record_failure::<C>(catalog_state, &detail).await;
+            task.record_error::<C>(catalog_state, &detail, &err.error)
+                .await;
         }
     }
 }
@@ -192,34 +200,46 @@ where
         )
     })?;
 
-    let secret = maybe_get_secret(warehouse.storage_secret_id, secret_state)
+    remove_tabular_location(&warehouse, &tabular_location, secret_state)
         .await
         .map_err(|e| {
             e.append_detail(format!(
-                "Failed to get storage secret for warehouse {warehouse_id} for Tabular Purge task."
+                "Failed to remove location `{tabular_location}` for Tabular Purge task."
             ))
-        })?;
-    let secret_ref = secret.as_deref();
+        })
+}
 
-    let file_io = warehouse
-        .storage_profile
-        .file_io(secret_ref)
+/// Remove all files below the location of a dropped tabular from the
+/// storage of its warehouse.
+async fn remove_tabular_location<S: SecretStore>(
+    warehouse: &ResolvedWarehouse,
+    tabular_location: &Location,
+    secret_state: &S,
+) -> Result<()> {
+    let warehouse_id = warehouse.warehouse_id;
+    let (storage_profile, storage_secret_id) =
+        warehouse.storage(warehouse.storage_slot_of_location(tabular_location))?;
+    let secret = maybe_get_secret(storage_secret_id, secret_state)
         .await
         .map_err(|e| {
-            IcebergErrorResponse::from(e).append_detail(format!(
-                "Failed to initialize IO for warehouse {warehouse_id} for Tabular Purge task."
+            e.append_detail(format!(
+                "Failed to get storage secret for warehouse {warehouse_id}."
             ))
         })?;
+    let secret_ref = secret.as_deref();
 
-    remove_all(&file_io, &tabular_location).await.map_err(|e| {
+    let file_io = storage_profile.file_io(secret_ref).await.map_err(|e| {
+        IcebergErrorResponse::from(e).append_detail(format!(
+            "Failed to initialize IO for warehouse {warehouse_id}."
+        ))
+    })?;
+
+    remove_all(&file_io, tabular_location).await.map_err(|e| {
         IcebergErrorResponse::from(ErrorModel::internal(
             "Failed to remove location.",
             "FileIOError",
             Some(Box::new(e)),
         ))
-        .append_detail(format!(
-            "Failed to remove location `{tabular_location}` for Tabular Purge task."
-        ))
     })?;
 
     Ok(())
@@ -10,7 +10,7 @@ use utoipa::{PartialSchema, ToSchema};
 use super::QueueApiConfig;
 use super::TaskQueueName;
 use crate::{
-    CancellationToken,
+    CONFIG, CancellationToken,
     api::Result,
     service::{
         CatalogStore,
@@ -145,9 +145,13 @@ pub(crate) async fn log_cleanup_worker<C: CatalogStore>(
             task_id = %task.task_id(),
         );
 
-        instrumented_cleanup::<C>(catalog_state.clone(), &task)
-            .instrument(span.or_current())
-            .await;
+        task.run_or_release::<C>(
+            catalog_state.clone(),
+            &cancellation_token,
+            CONFIG.task_drain_timeout,
+            instrumented_cleanup::<C>(catalog_state.clone(), &task).instrument(span.or_current()),
+        )
+        .await;
     }
 }
 
@@ -4,8 +4,9 @@ use tokio::sync::RwLock;
 
 use crate::{
     CONFIG, CancellationToken,
+    api::ApiContext,
     service::{
-        CatalogStore, SecretStore,
+        CatalogStore, SecretStore, State,
         authz::Authorizer,
         tasks::{
             TaskConfig, TaskQueueName, TaskQueueWorkerFn, TaskQueuesRunner,
@@ -483,6 +484,53 @@ impl TaskQueueRegistry {
         self
     }
 
+    /// Register the built-in metadata log compaction queue.
+    ///
+    /// Unlike the other built-in queues, compaction commits table metadata
+    /// through the regular table commit path, which requires the API state.
+    /// Call this once the state is built; the registration is shared with the
+    /// [`RegisteredTaskQueues`] handed out before.
+    pub async fn register_metadata_log_compaction_queue<
+        C: CatalogStore,
+        A: Authorizer + Clone,
+        S: SecretStore,
+    >(
+        &self,
+        state: ApiContext<State<A, C, S>>,
+        poll_interval: Duration,
+    ) -> &Self {
+        use super::metadata_log_compaction_queue;
+
+        self.register_queue::<
+            metadata_log_compaction_queue::MetadataLogCompactionQueueConfig,
+            metadata_log_compaction_queue::MetadataLogCompactionPayload,
+        >(QueueRegistration {
+            queue_name: &metadata_log_compaction_queue::QUEUE_NAME,
+            worker_fn: Arc::new(move |cancellation_token| {
+                let state = state.clone();
+                Box::pin(async move {
+                    metadata_log_compaction_queue::metadata_log_compaction_worker::<C, A, S>(
+                        state,
+                        poll_interval,
+                        cancellation_token,
+                    )
+                    .await;
+                })
+            }),
+            num_workers: CONFIG.task_metadata_log_compaction_workers,
+            scope: QueueScope::Warehouse,
+            #[cfg(feature = "open-api")]
+            user_scheduling: UserScheduling::Enabled {
+                payload_schema: None,
+            },
+            #[cfg(not(feature = "open-api"))]
+            user_scheduling: UserScheduling::Enabled,
+        })
+        .await;
+
+        self
+    }
+
     /// Creates [`RegisteredTaskQueues`] for use in application state
     #[must_use]
     pub fn registered_task_queues(&self) -> RegisteredTaskQueues {